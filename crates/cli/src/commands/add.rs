@@ -2,9 +2,17 @@
 //!
 //! Implements the `vtb add` command to create new tasks with all supported options.
 
-use crate::id::IdGenerator;
+use crate::estimate::parse_estimate;
+use crate::id::resolve_task_id;
+use crate::timestamp::{check_order, parse_due, parse_timestamp};
+use chrono::{DateTime, Utc};
 use clap::Args;
-use vertebrae_db::{Database, DbError, Level, Priority, Status, Task};
+use vertebrae_db::{
+    Database, DbError, IdGenerator, Level, Priority, Status, Task, level_labels, tag_settings,
+};
+
+/// `started_at` and `completed_at` for a new task
+type Timestamps = (Option<DateTime<Utc>>, Option<DateTime<Utc>>);
 
 /// Create a new task
 #[derive(Debug, Args)]
@@ -40,21 +48,51 @@ pub struct AddCommand {
     /// Mark task as needing human review before completion
     #[arg(long = "needs-review")]
     pub needs_review: bool,
+
+    /// Create the task already in_progress, started now (for backfilling)
+    #[arg(long, conflicts_with = "done")]
+    pub start: bool,
+
+    /// Create the task already done, started and completed now (for backfilling)
+    #[arg(long)]
+    pub done: bool,
+
+    /// When the work started, instead of now (with --start or --done)
+    #[arg(long, value_parser = parse_timestamp, value_name = "TIME")]
+    pub started_at: Option<DateTime<Utc>>,
+
+    /// When the work was completed, instead of now (with --done)
+    #[arg(long, value_parser = parse_timestamp, value_name = "TIME")]
+    pub completed_at: Option<DateTime<Utc>>,
+
+    /// Due date: RFC 3339, YYYY-MM-DD, today, tomorrow or +Nd/+Nw
+    #[arg(long, value_parser = parse_due, value_name = "DATE")]
+    pub due: Option<DateTime<Utc>>,
+
+    /// Estimated effort, e.g. 2h30m, 3h or 45m
+    #[arg(long, value_parser = parse_estimate, value_name = "DURATION")]
+    pub estimate: Option<u32>,
 }
 
-/// Parse a level string into a Level enum
-fn parse_level(s: &str) -> Result<Level, String> {
-    match s.to_lowercase().as_str() {
-        "epic" => Ok(Level::Epic),
-        "ticket" => Ok(Level::Ticket),
-        "task" => Ok(Level::Task),
-        _ => Err(format!(
-            "invalid level '{}'. Valid values: epic, ticket, task",
-            s
-        )),
+/// Render an optional timestamp as a SurrealQL datetime literal or `NONE`.
+fn datetime_literal(value: Option<DateTime<Utc>>) -> String {
+    match value {
+        Some(dt) => format!("d'{}'", dt.to_rfc3339()),
+        None => "NONE".to_string(),
     }
 }
 
+/// Parse a level string into a Level enum, accepting configured level names
+fn parse_level(s: &str) -> Result<Level, String> {
+    Level::parse_name(s).ok_or_else(|| {
+        format!(
+            "invalid level '{}'. Valid values: {}",
+            s,
+            level_labels().valid_names()
+        )
+    })
+}
+
 /// Parse a priority string into a Priority enum
 fn parse_priority(s: &str) -> Result<Priority, String> {
     match s.to_lowercase().as_str() {
@@ -73,6 +111,8 @@ impl AddCommand {
     /// Execute the add command.
     ///
     /// Creates a new task with the specified options and stores it in the database.
+    /// Tasks start in backlog, unless `--start` or `--done` puts them straight
+    /// into in_progress or done without going through the usual transitions.
     ///
     /// # Arguments
     ///
@@ -82,6 +122,8 @@ impl AddCommand {
     ///
     /// Returns `DbError` if:
     /// - The title is empty
+    /// - `--started-at`/`--completed-at` are given without `--start`/`--done`,
+    ///   or completed is before started
     /// - Parent task doesn't exist
     /// - Dependency tasks don't exist
     /// - Database operations fail
@@ -94,24 +136,16 @@ impl AddCommand {
             });
         }
 
-        // Validate parent exists if specified
-        if let Some(parent_id) = &self.parent
-            && !self.task_exists(db, parent_id).await?
-        {
-            return Err(DbError::InvalidPath {
-                path: std::path::PathBuf::from(parent_id),
-                reason: format!("parent task '{}' does not exist", parent_id),
-            });
-        }
+        let (started_at, completed_at) = self.backfill_timestamps(Utc::now())?;
 
-        // Validate dependencies exist
+        // Validate parent and dependencies exist, resolving shortened IDs
+        let parent = match &self.parent {
+            Some(parent_id) => Some(resolve_related(db, parent_id, "parent").await?),
+            None => None,
+        };
+        let mut depends_on = Vec::new();
         for dep_id in &self.depends_on {
-            if !self.task_exists(db, dep_id).await? {
-                return Err(DbError::InvalidPath {
-                    path: std::path::PathBuf::from(dep_id),
-                    reason: format!("dependency task '{}' does not exist", dep_id),
-                });
-            }
+            depends_on.push(resolve_related(db, dep_id, "dependency").await?);
         }
 
         // Generate unique ID with collision detection
@@ -119,7 +153,11 @@ impl AddCommand {
 
         // Create the task
         let level = self.level.clone().unwrap_or(Level::Task);
-        let mut task = Task::new(self.title.clone(), level).with_status(Status::Backlog);
+        let mut task = Task::new(self.title.clone(), level).with_status(self.initial_status());
+        task.started_at = started_at;
+        task.completed_at = completed_at;
+        task.due_at = self.due;
+        task.estimate_minutes = self.estimate;
 
         if let Some(description) = &self.description {
             task = task.with_description(description.clone());
@@ -129,8 +167,9 @@ impl AddCommand {
             task = task.with_priority(priority.clone());
         }
 
-        if !self.tags.is_empty() {
-            task = task.with_tags(self.tags.clone());
+        let tags = tag_settings().apply_all(&self.tags);
+        if !tags.is_empty() {
+            task = task.with_tags(tags);
         }
 
         if self.needs_review {
@@ -141,18 +180,52 @@ impl AddCommand {
         self.create_task(db, &id, &task).await?;
 
         // Create parent relationship if specified
-        if let Some(parent_id) = &self.parent {
+        if let Some(parent_id) = &parent {
             self.create_child_of_edge(db, &id, parent_id).await?;
         }
 
         // Create dependency relationships
-        for dep_id in &self.depends_on {
+        for dep_id in &depends_on {
             self.create_depends_on_edge(db, &id, dep_id).await?;
         }
 
         Ok(id)
     }
 
+    /// Timestamps the task is created with, given the current time.
+    ///
+    /// A task added with `--done` and only `--completed-at` starts when it
+    /// was completed, so backdating one end doesn't invert the pair.
+    fn backfill_timestamps(&self, now: DateTime<Utc>) -> Result<Timestamps, DbError> {
+        if self.started_at.is_some() && !(self.start || self.done) {
+            return Err(DbError::ValidationError {
+                message: "--started-at requires --start or --done".to_string(),
+            });
+        }
+        if self.completed_at.is_some() && !self.done {
+            return Err(DbError::ValidationError {
+                message: "--completed-at requires --done".to_string(),
+            });
+        }
+
+        let completed_at = self.done.then(|| self.completed_at.unwrap_or(now));
+        let started_at =
+            (self.start || self.done).then(|| self.started_at.or(self.completed_at).unwrap_or(now));
+        check_order(started_at, completed_at)?;
+        Ok((started_at, completed_at))
+    }
+
+    /// Status the task is created with.
+    fn initial_status(&self) -> Status {
+        if self.done {
+            Status::Done
+        } else if self.start {
+            Status::InProgress
+        } else {
+            Status::Backlog
+        }
+    }
+
     /// Check if a task with the given ID exists.
     async fn task_exists(&self, db: &Database, id: &str) -> Result<bool, DbError> {
         // Use a simple struct to avoid deserializing full Task
@@ -163,7 +236,7 @@ impl AddCommand {
         }
 
         let query = format!("SELECT id FROM task:{} LIMIT 1", id);
-        let mut result = db.client().query(&query).await?;
+        let mut result = db.query(&query).await?;
 
         let tasks: Vec<IdOnly> = result.take(0)?;
         Ok(!tasks.is_empty())
@@ -222,17 +295,26 @@ impl AddCommand {
                 status = "{}",
                 priority = {},
                 tags = {},
-                needs_human_review = {}"#,
+                needs_human_review = {},
+                started_at = {},
+                completed_at = {},
+                due_at = {},
+                estimate_minutes = {}"#,
             id,
             description_str,
             task.level.as_str(),
             task.status.as_str(),
             priority_str,
             tags_str,
-            task.needs_human_review.unwrap_or(false)
+            task.needs_human_review.unwrap_or(false),
+            datetime_literal(task.started_at),
+            datetime_literal(task.completed_at),
+            datetime_literal(task.due_at),
+            task.estimate_minutes
+                .map_or_else(|| "NONE".to_string(), |m| m.to_string())
         );
 
-        let mut query_builder = db.client().query(&query).bind(("title", title));
+        let mut query_builder = db.query(&query).bind(("title", title));
 
         if let Some(description) = &task.description {
             query_builder = query_builder.bind(("description", description.clone()));
@@ -251,7 +333,7 @@ impl AddCommand {
         parent_id: &str,
     ) -> Result<(), DbError> {
         let query = format!("RELATE task:{} -> child_of -> task:{}", child_id, parent_id);
-        db.client().query(&query).await?;
+        db.query(&query).await?;
         Ok(())
     }
 
@@ -263,11 +345,23 @@ impl AddCommand {
         dep_id: &str,
     ) -> Result<(), DbError> {
         let query = format!("RELATE task:{} -> depends_on -> task:{}", task_id, dep_id);
-        db.client().query(&query).await?;
+        db.query(&query).await?;
         Ok(())
     }
 }
 
+/// Resolve a `--parent` or `--depends-on` ID, naming its `role` in the
+/// error when no task matches.
+async fn resolve_related(db: &Database, id: &str, role: &str) -> Result<String, DbError> {
+    match resolve_task_id(db, id).await {
+        Err(DbError::NotFound { .. }) => Err(DbError::InvalidPath {
+            path: std::path::PathBuf::from(id),
+            reason: format!("{} task '{}' does not exist", role, id),
+        }),
+        resolved => resolved,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -404,6 +498,12 @@ mod tests {
             parent: None,
             depends_on: vec![],
             needs_review: false,
+            start: false,
+            done: false,
+            started_at: None,
+            completed_at: None,
+            due: None,
+            estimate: None,
         };
 
         let id = cmd.execute(&db).await.expect("Add should succeed");
@@ -433,6 +533,12 @@ mod tests {
             parent: None,
             depends_on: vec![],
             needs_review: false,
+            start: false,
+            done: false,
+            started_at: None,
+            completed_at: None,
+            due: None,
+            estimate: None,
         };
 
         let id = cmd.execute(&db).await.expect("Add should succeed");
@@ -458,6 +564,12 @@ mod tests {
             parent: None,
             depends_on: vec![],
             needs_review: false,
+            start: false,
+            done: false,
+            started_at: None,
+            completed_at: None,
+            due: None,
+            estimate: None,
         };
 
         let id = cmd.execute(&db).await.expect("Add should succeed");
@@ -483,6 +595,12 @@ mod tests {
             parent: None,
             depends_on: vec![],
             needs_review: false,
+            start: false,
+            done: false,
+            started_at: None,
+            completed_at: None,
+            due: None,
+            estimate: None,
         };
 
         let id = cmd.execute(&db).await.expect("Add should succeed");
@@ -497,6 +615,40 @@ mod tests {
         cleanup(&temp_dir);
     }
 
+    #[tokio::test]
+    async fn test_add_task_normalizes_tags() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        let cmd = AddCommand {
+            title: "Tagged task".to_string(),
+            level: None,
+            description: None,
+            priority: None,
+            tags: vec![
+                " Back End ".to_string(),
+                "back-end".to_string(),
+                "URGENT".to_string(),
+                " ".to_string(),
+            ],
+            parent: None,
+            depends_on: vec![],
+            needs_review: false,
+            start: false,
+            done: false,
+            started_at: None,
+            completed_at: None,
+            due: None,
+            estimate: None,
+        };
+
+        let id = cmd.execute(&db).await.expect("Add should succeed");
+
+        let task = get_task(&db, &id).await.expect("Task should exist in DB");
+        assert_eq!(task.tags, vec!["back-end", "urgent"]);
+
+        cleanup(&temp_dir);
+    }
+
     #[tokio::test]
     async fn test_add_task_empty_title_fails() {
         let (db, temp_dir) = setup_test_db().await;
@@ -510,6 +662,12 @@ mod tests {
             parent: None,
             depends_on: vec![],
             needs_review: false,
+            start: false,
+            done: false,
+            started_at: None,
+            completed_at: None,
+            due: None,
+            estimate: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -541,6 +699,12 @@ mod tests {
             parent: None,
             depends_on: vec![],
             needs_review: false,
+            start: false,
+            done: false,
+            started_at: None,
+            completed_at: None,
+            due: None,
+            estimate: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -572,6 +736,12 @@ mod tests {
             parent: Some("nonexistent".to_string()),
             depends_on: vec![],
             needs_review: false,
+            start: false,
+            done: false,
+            started_at: None,
+            completed_at: None,
+            due: None,
+            estimate: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -608,6 +778,12 @@ mod tests {
             parent: None,
             depends_on: vec!["nonexistent".to_string()],
             needs_review: false,
+            start: false,
+            done: false,
+            started_at: None,
+            completed_at: None,
+            due: None,
+            estimate: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -645,6 +821,12 @@ mod tests {
             parent: None,
             depends_on: vec![],
             needs_review: false,
+            start: false,
+            done: false,
+            started_at: None,
+            completed_at: None,
+            due: None,
+            estimate: None,
         };
 
         let parent_id = parent_cmd.execute(&db).await.unwrap();
@@ -659,6 +841,12 @@ mod tests {
             parent: Some(parent_id.clone()),
             depends_on: vec![],
             needs_review: false,
+            start: false,
+            done: false,
+            started_at: None,
+            completed_at: None,
+            due: None,
+            estimate: None,
         };
 
         let child_id = child_cmd.execute(&db).await.unwrap();
@@ -695,6 +883,12 @@ mod tests {
             parent: None,
             depends_on: vec![],
             needs_review: false,
+            start: false,
+            done: false,
+            started_at: None,
+            completed_at: None,
+            due: None,
+            estimate: None,
         };
 
         let dep_id = dep_cmd.execute(&db).await.unwrap();
@@ -709,6 +903,12 @@ mod tests {
             parent: None,
             depends_on: vec![dep_id.clone()],
             needs_review: false,
+            start: false,
+            done: false,
+            started_at: None,
+            completed_at: None,
+            due: None,
+            estimate: None,
         };
 
         let task_id = task_cmd.execute(&db).await.unwrap();
@@ -745,6 +945,12 @@ mod tests {
             parent: None,
             depends_on: vec![],
             needs_review: false,
+            start: false,
+            done: false,
+            started_at: None,
+            completed_at: None,
+            due: None,
+            estimate: None,
         };
         let dep1_id = dep1_cmd.execute(&db).await.unwrap();
 
@@ -757,6 +963,12 @@ mod tests {
             parent: None,
             depends_on: vec![],
             needs_review: false,
+            start: false,
+            done: false,
+            started_at: None,
+            completed_at: None,
+            due: None,
+            estimate: None,
         };
         let dep2_id = dep2_cmd.execute(&db).await.unwrap();
 
@@ -770,6 +982,12 @@ mod tests {
             parent: None,
             depends_on: vec![dep1_id.clone(), dep2_id.clone()],
             needs_review: false,
+            start: false,
+            done: false,
+            started_at: None,
+            completed_at: None,
+            due: None,
+            estimate: None,
         };
 
         let task_id = task_cmd.execute(&db).await.unwrap();
@@ -812,6 +1030,12 @@ mod tests {
             parent: None,
             depends_on: vec![],
             needs_review: false,
+            start: false,
+            done: false,
+            started_at: None,
+            completed_at: None,
+            due: None,
+            estimate: None,
         };
         let parent_id = parent_cmd
             .execute(&db)
@@ -828,6 +1052,12 @@ mod tests {
             parent: None,
             depends_on: vec![],
             needs_review: false,
+            start: false,
+            done: false,
+            started_at: None,
+            completed_at: None,
+            due: None,
+            estimate: None,
         };
         let dep_id = dep_cmd
             .execute(&db)
@@ -844,6 +1074,12 @@ mod tests {
             parent: Some(parent_id.clone()),
             depends_on: vec![dep_id.clone()],
             needs_review: false,
+            start: false,
+            done: false,
+            started_at: None,
+            completed_at: None,
+            due: None,
+            estimate: None,
         };
 
         let task_id = cmd.execute(&db).await.expect("Task should be created");
@@ -889,6 +1125,12 @@ mod tests {
             parent: None,
             depends_on: vec![],
             needs_review: false,
+            start: false,
+            done: false,
+            started_at: None,
+            completed_at: None,
+            due: None,
+            estimate: None,
         };
 
         let result = cmd.execute(&db).await.unwrap();
@@ -911,6 +1153,12 @@ mod tests {
             parent: None,
             depends_on: vec![],
             needs_review: false,
+            start: false,
+            done: false,
+            started_at: None,
+            completed_at: None,
+            due: None,
+            estimate: None,
         };
 
         let exists = cmd.task_exists(&db, "xxxxxx").await.unwrap();
@@ -933,6 +1181,12 @@ mod tests {
             parent: None,
             depends_on: vec![],
             needs_review: false,
+            start: false,
+            done: false,
+            started_at: None,
+            completed_at: None,
+            due: None,
+            estimate: None,
         };
 
         let id = cmd.execute(&db).await.unwrap();
@@ -957,6 +1211,12 @@ mod tests {
             parent: None,
             depends_on: vec![],
             needs_review: false,
+            start: false,
+            done: false,
+            started_at: None,
+            completed_at: None,
+            due: None,
+            estimate: None,
         };
 
         let id = cmd.execute(&db).await.unwrap();
@@ -992,6 +1252,12 @@ mod tests {
             parent: None,
             depends_on: vec![],
             needs_review: false,
+            start: false,
+            done: false,
+            started_at: None,
+            completed_at: None,
+            due: None,
+            estimate: None,
         };
 
         let id = cmd.execute(&db).await.unwrap();
@@ -1030,6 +1296,12 @@ mod tests {
                 parent: None,
                 depends_on: vec![],
                 needs_review: false,
+                start: false,
+                done: false,
+                started_at: None,
+                completed_at: None,
+                due: None,
+                estimate: None,
             };
 
             let id = cmd.execute(&db).await.unwrap();
@@ -1052,6 +1324,12 @@ mod tests {
             parent: None,
             depends_on: vec![],
             needs_review: true,
+            start: false,
+            done: false,
+            started_at: None,
+            completed_at: None,
+            due: None,
+            estimate: None,
         };
 
         let id = cmd.execute(&db).await.expect("Add should succeed");
@@ -1091,6 +1369,12 @@ mod tests {
             parent: None,
             depends_on: vec![],
             needs_review: false,
+            start: false,
+            done: false,
+            started_at: None,
+            completed_at: None,
+            due: None,
+            estimate: None,
         };
 
         let id = cmd.execute(&db).await.expect("Add should succeed");
@@ -1116,4 +1400,180 @@ mod tests {
 
         cleanup(&temp_dir);
     }
+
+    fn backfill_cmd(title: &str, start: bool, done: bool) -> AddCommand {
+        AddCommand {
+            title: title.to_string(),
+            level: None,
+            description: None,
+            priority: None,
+            tags: vec![],
+            parent: None,
+            depends_on: vec![],
+            needs_review: false,
+            start,
+            done,
+            started_at: None,
+            completed_at: None,
+            due: None,
+            estimate: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_add_done_sets_status_and_both_timestamps() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        let before = Utc::now();
+        let id = backfill_cmd("Shipped last week", false, true)
+            .execute(&db)
+            .await
+            .expect("Add should succeed");
+
+        let task = db.tasks().get(&id).await.unwrap().unwrap();
+        assert_eq!(task.status, Status::Done);
+        let started = task.started_at.expect("started_at should be set");
+        let completed = task.completed_at.expect("completed_at should be set");
+        assert!(started >= before - chrono::Duration::seconds(1));
+        assert!(completed >= started);
+
+        // The timeline only shows tasks with started_at set
+        #[derive(Debug, serde::Deserialize)]
+        struct IdRow {
+            id: surrealdb::sql::Thing,
+        }
+        let mut result = db
+            .client()
+            .query("SELECT id FROM task WHERE started_at != NONE")
+            .await
+            .unwrap();
+        let rows: Vec<IdRow> = result.take(0).unwrap();
+        let ids: Vec<String> = rows.iter().map(|r| r.id.id.to_raw()).collect();
+        assert_eq!(ids, vec![id]);
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_add_start_sets_in_progress_and_started_at() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        let id = backfill_cmd("Started already", true, false)
+            .execute(&db)
+            .await
+            .expect("Add should succeed");
+
+        let task = db.tasks().get(&id).await.unwrap().unwrap();
+        assert_eq!(task.status, Status::InProgress);
+        assert!(task.started_at.is_some());
+        assert!(task.completed_at.is_none());
+
+        // Plain add leaves both timestamps unset
+        let id = backfill_cmd("Plain", false, false)
+            .execute(&db)
+            .await
+            .unwrap();
+        let task = db.tasks().get(&id).await.unwrap().unwrap();
+        assert_eq!(task.status, Status::Backlog);
+        assert!(task.started_at.is_none());
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_add_with_due_date_and_estimate() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        let due = parse_timestamp("2024-06-01T17:00:00Z").unwrap();
+        let mut cmd = backfill_cmd("Has a deadline", false, false);
+        cmd.due = Some(due);
+        let id = cmd.execute(&db).await.unwrap();
+
+        let task = db.tasks().get(&id).await.unwrap().unwrap();
+        assert_eq!(task.due_at, Some(due));
+        assert_eq!(task.estimate_minutes, None);
+
+        let mut cmd = backfill_cmd("Sized", false, false);
+        cmd.estimate = Some(150);
+        let id = cmd.execute(&db).await.unwrap();
+        let task = db.tasks().get(&id).await.unwrap().unwrap();
+        assert_eq!(task.estimate_minutes, Some(150));
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_add_done_with_backdated_timestamps() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        let started = parse_timestamp("2024-03-01T09:00:00Z").unwrap();
+        let completed = parse_timestamp("2024-03-04T17:30:00Z").unwrap();
+        let mut cmd = backfill_cmd("Backfilled", false, true);
+        cmd.started_at = Some(started);
+        cmd.completed_at = Some(completed);
+        let id = cmd.execute(&db).await.expect("Add should succeed");
+
+        let task = db.tasks().get(&id).await.unwrap().unwrap();
+        assert_eq!(task.status, Status::Done);
+        assert_eq!(task.started_at, Some(started));
+        assert_eq!(task.completed_at, Some(completed));
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_add_rejects_completed_before_started() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        let mut cmd = backfill_cmd("Inverted", false, true);
+        cmd.started_at = Some(parse_timestamp("2024-03-04T00:00:00Z").unwrap());
+        cmd.completed_at = Some(parse_timestamp("2024-03-01T00:00:00Z").unwrap());
+        assert!(matches!(
+            cmd.execute(&db).await,
+            Err(DbError::ValidationError { .. })
+        ));
+
+        // Nothing was created
+        let mut result = db
+            .client()
+            .query("SELECT count() AS count FROM task GROUP ALL")
+            .await
+            .unwrap();
+        let count: Option<usize> = result.take("count").unwrap();
+        assert_eq!(count.unwrap_or(0), 0);
+
+        cleanup(&temp_dir);
+    }
+
+    #[test]
+    fn test_backfill_timestamps() {
+        let now = parse_timestamp("2024-03-10T00:00:00Z").unwrap();
+        let earlier = parse_timestamp("2024-03-01T00:00:00Z").unwrap();
+
+        // Only --completed-at: the task also starts then
+        let mut cmd = backfill_cmd("t", false, true);
+        cmd.completed_at = Some(earlier);
+        assert_eq!(
+            cmd.backfill_timestamps(now).unwrap(),
+            (Some(earlier), Some(earlier))
+        );
+
+        // --start with --started-at leaves completed_at unset
+        let mut cmd = backfill_cmd("t", true, false);
+        cmd.started_at = Some(earlier);
+        assert_eq!(cmd.backfill_timestamps(now).unwrap(), (Some(earlier), None));
+
+        // Timestamps need the matching flag
+        let mut cmd = backfill_cmd("t", false, false);
+        cmd.started_at = Some(earlier);
+        assert!(cmd.backfill_timestamps(now).is_err());
+        let mut cmd = backfill_cmd("t", true, false);
+        cmd.completed_at = Some(earlier);
+        assert!(cmd.backfill_timestamps(now).is_err());
+
+        // A start after now with only --done is still inverted
+        let mut cmd = backfill_cmd("t", false, true);
+        cmd.started_at = Some(parse_timestamp("2024-04-01T00:00:00Z").unwrap());
+        assert!(cmd.backfill_timestamps(now).is_err());
+    }
 }
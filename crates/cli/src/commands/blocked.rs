@@ -0,0 +1,290 @@
+//! Blocked command for spotting bottlenecks
+//!
+//! Implements the `vtb blocked` command, which reports every scheduled task
+//! that is waiting on incomplete dependencies, most-blocked first. Backlog
+//! tasks aren't scheduled yet, so they only show up with `--all`.
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use vertebrae_db::{Database, DbError, Status};
+
+use crate::output::{OutputFormat, Render, to_json};
+
+/// List tasks waiting on incomplete blockers, most-blocked first
+#[derive(Debug, Args)]
+pub struct BlockedCommand {
+    /// Output format: table or json
+    #[arg(long, value_enum, default_value_t = OutputFormat::Table)]
+    pub format: OutputFormat,
+
+    /// Include blocked backlog tasks (excluded by default)
+    #[arg(long)]
+    pub all: bool,
+}
+
+/// A task waiting on incomplete blockers
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BlockedTask {
+    /// Task ID
+    pub id: String,
+    /// Task title
+    pub title: String,
+    /// Current status
+    pub status: String,
+    /// Number of incomplete tasks it depends on
+    pub blocker_count: usize,
+    /// A direct incomplete blocker (the first by ID), to look at next
+    pub nearest_blocker: String,
+}
+
+/// Result of the blocked command execution
+#[derive(Debug, Serialize)]
+pub struct BlockedResult {
+    /// Blocked tasks, most blockers first
+    pub tasks: Vec<BlockedTask>,
+}
+
+/// Result from querying a task
+#[derive(Debug, Deserialize)]
+struct TaskRow {
+    id: surrealdb::sql::Thing,
+    title: String,
+    status: String,
+}
+
+impl Render for BlockedResult {
+    const COMMAND: &'static str = "blocked";
+    const FORMATS: &'static [OutputFormat] = &[OutputFormat::Table, OutputFormat::Json];
+
+    fn render_as(&self, format: OutputFormat) -> Result<String, DbError> {
+        match format {
+            OutputFormat::Json => to_json(&self.tasks),
+            _ => Ok(self.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for BlockedResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.tasks.is_empty() {
+            return write!(f, "No blocked tasks.");
+        }
+
+        let width = |header: &str, cell: fn(&BlockedTask) -> usize| {
+            self.tasks
+                .iter()
+                .map(cell)
+                .max()
+                .unwrap_or(0)
+                .max(header.len())
+        };
+        let id_width = width("ID", |t| t.id.len());
+        let status_width = width("Status", |t| t.status.len());
+        let nearest_width = width("Nearest", |t| t.nearest_blocker.len());
+
+        write!(
+            f,
+            "{:<iw$}  {:<sw$}  Blockers  {:<nw$}  Title",
+            "ID",
+            "Status",
+            "Nearest",
+            iw = id_width,
+            sw = status_width,
+            nw = nearest_width,
+        )?;
+        for task in &self.tasks {
+            write!(
+                f,
+                "\n{:<iw$}  {:<sw$}  {:>8}  {:<nw$}  {}",
+                task.id,
+                task.status,
+                task.blocker_count,
+                task.nearest_blocker,
+                task.title,
+                iw = id_width,
+                sw = status_width,
+                nw = nearest_width,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl BlockedCommand {
+    /// Execute the blocked command.
+    ///
+    /// Tasks in todo, in_progress or pending_review (plus backlog with
+    /// `--all`) with at least one incomplete blocker are reported. Two
+    /// queries cover every task.
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - Reference to the database connection
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError` if the format is unsupported or a query fails.
+    pub async fn execute(&self, db: &Database) -> Result<BlockedResult, DbError> {
+        BlockedResult::check_format(self.format)?;
+
+        let mut blockers = db.graph().get_incomplete_blockers_batch().await?;
+        if blockers.is_empty() {
+            return Ok(BlockedResult { tasks: vec![] });
+        }
+
+        let mut excluded = vec![Status::Done, Status::Rejected];
+        if !self.all {
+            excluded.push(Status::Backlog);
+        }
+        let query = format!(
+            r#"SELECT id, title, status FROM task
+               WHERE id IN [{}] AND status NOT IN [{}] AND deleted_at = NONE"#,
+            blockers
+                .keys()
+                .map(|id| format!("task:{}", id))
+                .collect::<Vec<_>>()
+                .join(", "),
+            excluded
+                .iter()
+                .map(|status| format!("\"{}\"", status.as_str()))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        let mut result = db.query(&query).await?;
+        let rows: Vec<TaskRow> = result.take(0)?;
+
+        let mut tasks: Vec<BlockedTask> = rows
+            .into_iter()
+            .filter_map(|row| {
+                let id = row.id.id.to_string();
+                let ids = blockers.remove(&id)?;
+                Some(BlockedTask {
+                    blocker_count: ids.len(),
+                    nearest_blocker: ids.into_iter().next()?,
+                    id,
+                    title: row.title,
+                    status: row.status,
+                })
+            })
+            .collect();
+        tasks.sort_by(|a, b| {
+            b.blocker_count
+                .cmp(&a.blocker_count)
+                .then_with(|| a.id.cmp(&b.id))
+        });
+
+        Ok(BlockedResult { tasks })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    async fn setup_test_db() -> (Database, std::path::PathBuf) {
+        let temp_dir = env::temp_dir().join(format!(
+            "vtb-blocked-test-{}-{:?}-{}",
+            std::process::id(),
+            std::thread::current().id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        let db = Database::connect(&temp_dir).await.unwrap();
+        db.init().await.unwrap();
+        (db, temp_dir)
+    }
+
+    fn cleanup(path: &std::path::Path) {
+        let _ = std::fs::remove_dir_all(path);
+    }
+
+    fn blocked(format: OutputFormat) -> BlockedCommand {
+        BlockedCommand { format, all: false }
+    }
+
+    #[tokio::test]
+    async fn test_blocked_sorted_by_blocker_count() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        let result = blocked(OutputFormat::Table).execute(&db).await.unwrap();
+        assert_eq!(result.to_string(), "No blocked tasks.");
+
+        db.client()
+            .query(
+                r#"CREATE task:api SET title = "API", level = "task", status = "todo";
+                   CREATE task:db SET title = "DB", level = "task", status = "in_progress";
+                   CREATE task:old SET title = "Old", level = "task", status = "done";
+                   CREATE task:ui SET title = "UI", level = "task", status = "todo";
+                   CREATE task:release SET title = "Release", level = "epic", status = "todo";
+                   CREATE task:shipped SET title = "Shipped", level = "task", status = "done";
+                   RELATE task:ui -> depends_on -> task:api;
+                   RELATE task:ui -> depends_on -> task:old;
+                   RELATE task:release -> depends_on -> task:ui;
+                   RELATE task:release -> depends_on -> task:db;
+                   RELATE task:release -> depends_on -> task:api;
+                   RELATE task:shipped -> depends_on -> task:api;
+                   RELATE task:api -> depends_on -> task:old"#,
+            )
+            .await
+            .unwrap();
+
+        let result = blocked(OutputFormat::Table).execute(&db).await.unwrap();
+        let summary: Vec<(&str, usize, &str)> = result
+            .tasks
+            .iter()
+            .map(|t| (t.id.as_str(), t.blocker_count, t.nearest_blocker.as_str()))
+            .collect();
+        // Done blockers don't count, and done tasks aren't reported
+        assert_eq!(summary, vec![("release", 3, "api"), ("ui", 1, "api")]);
+        assert_eq!(
+            result.to_string(),
+            "ID       Status  Blockers  Nearest  Title\n\
+             release  todo           3  api      Release\n\
+             ui       todo           1  api      UI"
+        );
+
+        let json = result.render(OutputFormat::Json).unwrap();
+        assert!(json.contains("\"blocker_count\": 3"), "got: {}", json);
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_blocked_skips_backlog_unless_all() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        db.client()
+            .query(
+                r#"CREATE task:api SET title = "API", level = "task", status = "todo";
+                   CREATE task:idea SET title = "Idea", level = "task", status = "backlog";
+                   CREATE task:ui SET title = "UI", level = "task", status = "todo";
+                   RELATE task:idea -> depends_on -> task:api;
+                   RELATE task:ui -> depends_on -> task:api"#,
+            )
+            .await
+            .unwrap();
+
+        let ids =
+            |result: BlockedResult| result.tasks.into_iter().map(|t| t.id).collect::<Vec<_>>();
+        let result = blocked(OutputFormat::Table).execute(&db).await.unwrap();
+        assert_eq!(ids(result), vec!["ui"]);
+
+        let all = BlockedCommand {
+            all: true,
+            ..blocked(OutputFormat::Table)
+        };
+        assert_eq!(ids(all.execute(&db).await.unwrap()), vec!["idea", "ui"]);
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_blocked_rejects_unsupported_format() {
+        let (db, temp_dir) = setup_test_db().await;
+        assert!(blocked(OutputFormat::Csv).execute(&db).await.is_err());
+        cleanup(&temp_dir);
+    }
+}
@@ -4,8 +4,11 @@
 //! recursively traversing the dependency graph.
 
 use clap::Args;
-use serde::Deserialize;
-use vertebrae_db::{Database, DbError};
+use serde::{Deserialize, Serialize};
+use vertebrae_db::{Database, DbError, GraphQueries, TreeChars, tree_chars};
+
+use crate::id::resolve_task_id;
+use crate::output::{OutputFormat, Render, level_label, to_json};
 
 /// Show all tasks blocking a given task
 #[derive(Debug, Args)]
@@ -17,10 +20,33 @@ pub struct BlockersCommand {
     /// Maximum depth to traverse (default: unlimited)
     #[arg(long, short = 'd')]
     pub depth: Option<usize>,
+
+    /// List each root-blocker -> task chain on its own line instead of a tree
+    #[arg(long)]
+    pub paths: bool,
+
+    /// Maximum number of chains to list with --paths
+    #[arg(long, default_value_t = DEFAULT_MAX_PATHS, requires = "paths")]
+    pub max_paths: usize,
+
+    /// Tree connectors: unicode or ascii (overrides [display] tree_chars)
+    #[arg(long, value_name = "CHARS")]
+    pub tree_chars: Option<TreeChars>,
+
+    /// Output format: table or json
+    #[arg(long, value_enum, default_value_t = OutputFormat::Table)]
+    pub format: OutputFormat,
+
+    /// Print the SurrealQL queries instead of running them
+    #[arg(long)]
+    pub explain: bool,
 }
 
+/// Default cap on the number of chains listed by `--paths`
+pub const DEFAULT_MAX_PATHS: usize = 100;
+
 /// A node in the blocker tree
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct BlockerNode {
     /// Task ID
     pub id: String,
@@ -35,7 +61,7 @@ pub struct BlockerNode {
 }
 
 /// Result of the blockers command execution
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct BlockersResult {
     /// The target task ID
     pub task_id: String,
@@ -45,6 +71,13 @@ pub struct BlockersResult {
     pub blockers: Vec<BlockerNode>,
     /// Total count of all blocking items
     pub total_count: usize,
+    /// Blocking chains from root blocker to the target task, when `--paths` is given
+    pub paths: Option<Vec<Vec<String>>>,
+    /// Whether `paths` was cut off at `--max-paths`
+    pub paths_truncated: bool,
+    /// Connectors used to draw the tree
+    #[serde(skip)]
+    pub tree_chars: TreeChars,
 }
 
 /// Result from querying a task
@@ -72,31 +105,67 @@ impl BlockersCommand {
     /// - The task with the given ID does not exist
     /// - Database operations fail
     pub async fn execute(&self, db: &Database) -> Result<BlockersResult, DbError> {
-        // Normalize ID to lowercase for case-insensitive lookup
-        let id = self.id.to_lowercase();
+        BlockersResult::check_format(self.format)?;
+
+        // Resolve case-insensitive and shortened IDs
+        let id = resolve_task_id(db, &self.id).await?;
 
         // Fetch the target task to verify it exists and get its title
         let task = self.fetch_task(db, &id).await?;
 
         // Build the blocker tree
-        let blockers = self.build_blocker_tree(db, &id, 0).await?;
+        let mut trail = vec![id.clone()];
+        let blockers = self.build_blocker_tree(db, &id, 0, &mut trail).await?;
 
         // Count total blockers
         let total_count = count_nodes(&blockers);
 
+        let (paths, paths_truncated) = if self.paths {
+            let (paths, truncated) = collect_blocker_paths(&blockers, &id, self.max_paths);
+            (Some(paths), truncated)
+        } else {
+            (None, false)
+        };
+
         Ok(BlockersResult {
             task_id: id,
             task_title: task.title,
             blockers,
             total_count,
+            paths,
+            paths_truncated,
+            tree_chars: self.tree_chars.unwrap_or_else(tree_chars),
         })
     }
 
+    /// Return the SurrealQL queries `execute` would run, without running them.
+    ///
+    /// The blocker query is issued once per task in the tree, starting with
+    /// the target task.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError::NotFound` or `DbError::AmbiguousId` if the ID
+    /// doesn't resolve to exactly one task.
+    pub async fn explain(&self, db: &Database) -> Result<String, DbError> {
+        let id = resolve_task_id(db, &self.id).await?;
+        let depth = match self.depth {
+            Some(depth) => format!("up to depth {}", depth),
+            None => "with no depth limit".to_string(),
+        };
+        Ok(format!(
+            "{};\n{};\n-- repeated for each blocker found, {}",
+            task_query(&id),
+            direct_blockers_query(&id),
+            depth
+        ))
+    }
+
     /// Fetch a task by ID.
     async fn fetch_task(&self, db: &Database, id: &str) -> Result<TaskRow, DbError> {
-        let query = format!("SELECT id, title, level, status FROM task:{}", id);
+        let query = task_query(id);
 
-        let mut result = db.client().query(&query).await?;
+        let mut result = db.query(&query).await?;
         let task: Option<TaskRow> = result.take(0)?;
 
         task.ok_or_else(|| DbError::NotFound {
@@ -107,12 +176,14 @@ impl BlockersCommand {
     /// Build the blocker tree recursively.
     ///
     /// Uses BFS with depth tracking to build the tree structure while respecting
-    /// the optional depth limit.
+    /// the optional depth limit. `trail` is the chain from the target task to
+    /// `task_id`, used to stop on dependency cycles left by raw edits.
     async fn build_blocker_tree(
         &self,
         db: &Database,
         task_id: &str,
         current_depth: usize,
+        trail: &mut Vec<String>,
     ) -> Result<Vec<BlockerNode>, DbError> {
         // Check depth limit
         if let Some(max_depth) = self.depth
@@ -128,10 +199,20 @@ impl BlockersCommand {
         let mut nodes = Vec::new();
         for blocker in direct_blockers {
             let blocker_id = blocker.id.id.to_string();
+            if trail.contains(&blocker_id) {
+                let cycle = GraphQueries::close_cycle(trail, &blocker_id);
+                return Err(DbError::GraphCycle {
+                    relation: "depends_on".to_string(),
+                    cycle: GraphQueries::format_cycle_path(&cycle),
+                });
+            }
 
             // Recursively get children (blockers of this blocker)
+            trail.push(blocker_id.clone());
             let children =
-                Box::pin(self.build_blocker_tree(db, &blocker_id, current_depth + 1)).await?;
+                Box::pin(self.build_blocker_tree(db, &blocker_id, current_depth + 1, trail))
+                    .await?;
+            trail.pop();
 
             nodes.push(BlockerNode {
                 id: blocker_id,
@@ -151,24 +232,85 @@ impl BlockersCommand {
         db: &Database,
         task_id: &str,
     ) -> Result<Vec<TaskRow>, DbError> {
-        // Get tasks that this task depends on via the depends_on edge
-        let query = format!(
-            "SELECT id, title, level, status FROM task WHERE <-depends_on<-task CONTAINS task:{}",
-            task_id
-        );
+        let query = direct_blockers_query(task_id);
 
-        let mut result = db.client().query(&query).await?;
+        let mut result = db.query(&query).await?;
         let blockers: Vec<TaskRow> = result.take(0)?;
 
         Ok(blockers)
     }
 }
 
+/// Query for a single task's display fields.
+fn task_query(id: &str) -> String {
+    format!("SELECT id, title, level, status FROM task:{}", id)
+}
+
+/// Query for the tasks that `task_id` depends on via the depends_on edge.
+fn direct_blockers_query(task_id: &str) -> String {
+    format!(
+        "SELECT id, title, level, status FROM task \
+         WHERE <-depends_on<-task CONTAINS task:{} AND deleted_at = NONE",
+        task_id
+    )
+}
+
 /// Count total nodes in the blocker tree
 fn count_nodes(nodes: &[BlockerNode]) -> usize {
     nodes.iter().map(|n| 1 + count_nodes(&n.children)).sum()
 }
 
+/// Enumerate every blocking chain in the tree, from root blocker to `task_id`.
+///
+/// Walks the tree depth-first; each leaf (a blocker with no further blockers,
+/// or one cut off by `--depth`) ends a chain. Since the tree repeats shared
+/// blockers under each dependent, a diamond yields one chain per route.
+///
+/// Returns the chains and whether more than `max_paths` existed.
+pub fn collect_blocker_paths(
+    nodes: &[BlockerNode],
+    task_id: &str,
+    max_paths: usize,
+) -> (Vec<Vec<String>>, bool) {
+    fn walk(
+        nodes: &[BlockerNode],
+        trail: &mut Vec<String>,
+        paths: &mut Vec<Vec<String>>,
+        max_paths: usize,
+    ) -> bool {
+        for node in nodes {
+            trail.push(node.id.clone());
+            if node.children.is_empty() {
+                if paths.len() == max_paths {
+                    return true;
+                }
+                paths.push(trail.iter().rev().cloned().collect());
+            } else if walk(&node.children, trail, paths, max_paths) {
+                return true;
+            }
+            trail.pop();
+        }
+        false
+    }
+
+    let mut paths = Vec::new();
+    let mut trail = vec![task_id.to_string()];
+    let truncated = walk(nodes, &mut trail, &mut paths, max_paths);
+    (paths, truncated)
+}
+
+impl Render for BlockersResult {
+    const COMMAND: &'static str = "blockers";
+    const FORMATS: &'static [OutputFormat] = &[OutputFormat::Table, OutputFormat::Json];
+
+    fn render_as(&self, format: OutputFormat) -> Result<String, DbError> {
+        match format {
+            OutputFormat::Json => to_json(self),
+            _ => Ok(self.to_string()),
+        }
+    }
+}
+
 impl std::fmt::Display for BlockersResult {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         if self.blockers.is_empty() {
@@ -180,10 +322,30 @@ impl std::fmt::Display for BlockersResult {
         writeln!(f, "{}", "=".repeat(50))?;
         writeln!(f)?;
 
+        if let Some(paths) = &self.paths {
+            for path in paths {
+                writeln!(f, "{}", path.join(" -> "))?;
+            }
+
+            writeln!(f)?;
+            writeln!(
+                f,
+                "Total: {} blocking path{}{}",
+                paths.len(),
+                if paths.len() == 1 { "" } else { "s" },
+                if self.paths_truncated {
+                    " (limit reached, raise --max-paths to see more)"
+                } else {
+                    ""
+                }
+            )?;
+            return Ok(());
+        }
+
         // Print the tree
         for (i, node) in self.blockers.iter().enumerate() {
             let is_last = i == self.blockers.len() - 1;
-            print_node(f, node, "", is_last)?;
+            print_node(f, node, "", is_last, self.tree_chars)?;
         }
 
         writeln!(f)?;
@@ -204,18 +366,19 @@ fn print_node(
     node: &BlockerNode,
     prefix: &str,
     is_last: bool,
+    chars: TreeChars,
 ) -> std::fmt::Result {
     // Determine the connector
     let connector = if prefix.is_empty() {
         ""
     } else if is_last {
-        "`-- "
+        chars.last_branch()
     } else {
-        "|-- "
+        chars.branch()
     };
 
     // Format fields with fixed width for alignment
-    let level_display = format!("{:8}", node.level);
+    let level_display = format!("{:8}", level_label(&node.level));
     let status_display = format!("{:12}", node.status);
 
     writeln!(
@@ -230,7 +393,7 @@ fn print_node(
     } else if is_last {
         format!("{}    ", prefix)
     } else {
-        format!("{}|   ", prefix)
+        format!("{}{}", prefix, chars.vertical())
     };
 
     // Add indent for root-level children
@@ -243,7 +406,7 @@ fn print_node(
     // Print children
     for (i, child) in node.children.iter().enumerate() {
         let child_is_last = i == node.children.len() - 1;
-        print_node(f, child, &actual_prefix, child_is_last)?;
+        print_node(f, child, &actual_prefix, child_is_last, chars)?;
     }
 
     Ok(())
@@ -311,6 +474,11 @@ mod tests {
         let cmd = BlockersCommand {
             id: "task1".to_string(),
             depth: None,
+            paths: false,
+            max_paths: DEFAULT_MAX_PATHS,
+            explain: false,
+            format: OutputFormat::Table,
+            tree_chars: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -337,6 +505,11 @@ mod tests {
         let cmd = BlockersCommand {
             id: "task1".to_string(),
             depth: None,
+            paths: false,
+            max_paths: DEFAULT_MAX_PATHS,
+            explain: false,
+            format: OutputFormat::Table,
+            tree_chars: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -350,6 +523,61 @@ mod tests {
         cleanup(&temp_dir);
     }
 
+    #[tokio::test]
+    async fn test_blockers_json_format() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        create_task(&db, "root", "Root", "task", "todo").await;
+        create_task(&db, "mid", "Middle", "task", "todo").await;
+        create_task(&db, "task1", "Target", "task", "todo").await;
+        create_depends_on(&db, "task1", "mid").await;
+        create_depends_on(&db, "mid", "root").await;
+
+        let cmd = BlockersCommand {
+            id: "task1".to_string(),
+            depth: None,
+            paths: false,
+            max_paths: DEFAULT_MAX_PATHS,
+            explain: false,
+            format: OutputFormat::Json,
+            tree_chars: None,
+        };
+        let result = cmd.execute(&db).await.unwrap();
+        let json: serde_json::Value =
+            serde_json::from_str(&result.render(cmd.format).unwrap()).unwrap();
+        assert_eq!(json["task_id"], "task1");
+        assert_eq!(json["total_count"], 2);
+        assert_eq!(json["blockers"][0]["id"], "mid");
+        assert_eq!(json["blockers"][0]["children"][0]["id"], "root");
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_blockers_rejects_unsupported_format() {
+        let (db, temp_dir) = setup_test_db().await;
+        create_task(&db, "task1", "Target", "task", "todo").await;
+
+        let cmd = BlockersCommand {
+            id: "task1".to_string(),
+            depth: None,
+            paths: false,
+            max_paths: DEFAULT_MAX_PATHS,
+            explain: false,
+            format: OutputFormat::Yaml,
+            tree_chars: None,
+        };
+        let result = cmd.execute(&db).await;
+        assert!(
+            matches!(result, Err(DbError::ValidationError { ref message })
+                if message == "--format yaml is not supported by `blockers`; use one of: table, json"),
+            "got {:?}",
+            result
+        );
+
+        cleanup(&temp_dir);
+    }
+
     #[tokio::test]
     async fn test_blockers_transitive() {
         let (db, temp_dir) = setup_test_db().await;
@@ -365,6 +593,11 @@ mod tests {
         let cmd = BlockersCommand {
             id: "task1".to_string(),
             depth: None,
+            paths: false,
+            max_paths: DEFAULT_MAX_PATHS,
+            explain: false,
+            format: OutputFormat::Table,
+            tree_chars: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -394,6 +627,11 @@ mod tests {
         let cmd = BlockersCommand {
             id: "task1".to_string(),
             depth: None,
+            paths: false,
+            max_paths: DEFAULT_MAX_PATHS,
+            explain: false,
+            format: OutputFormat::Table,
+            tree_chars: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -434,6 +672,11 @@ mod tests {
         let cmd = BlockersCommand {
             id: "task1".to_string(),
             depth: Some(1),
+            paths: false,
+            max_paths: DEFAULT_MAX_PATHS,
+            explain: false,
+            format: OutputFormat::Table,
+            tree_chars: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -449,6 +692,11 @@ mod tests {
         let cmd = BlockersCommand {
             id: "task1".to_string(),
             depth: Some(2),
+            paths: false,
+            max_paths: DEFAULT_MAX_PATHS,
+            explain: false,
+            format: OutputFormat::Table,
+            tree_chars: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -475,6 +723,11 @@ mod tests {
         let cmd = BlockersCommand {
             id: "task1".to_string(),
             depth: Some(0),
+            paths: false,
+            max_paths: DEFAULT_MAX_PATHS,
+            explain: false,
+            format: OutputFormat::Table,
+            tree_chars: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -487,6 +740,36 @@ mod tests {
         cleanup(&temp_dir);
     }
 
+    #[tokio::test]
+    async fn test_blockers_cycle_in_stored_edges_errors() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        create_task(&db, "a", "A", "task", "todo").await;
+        create_task(&db, "b", "B", "task", "todo").await;
+        create_depends_on(&db, "a", "b").await;
+        create_depends_on(&db, "b", "a").await;
+
+        let cmd = BlockersCommand {
+            id: "a".to_string(),
+            depth: None,
+            paths: false,
+            max_paths: DEFAULT_MAX_PATHS,
+            explain: false,
+            format: OutputFormat::Table,
+            tree_chars: None,
+        };
+
+        match cmd.execute(&db).await {
+            Err(DbError::GraphCycle { relation, cycle }) => {
+                assert_eq!(relation, "depends_on");
+                assert_eq!(cycle, "a -> b -> a");
+            }
+            other => panic!("Expected GraphCycle, got {:?}", other),
+        }
+
+        cleanup(&temp_dir);
+    }
+
     #[tokio::test]
     async fn test_blockers_nonexistent_task() {
         let (db, temp_dir) = setup_test_db().await;
@@ -494,6 +777,11 @@ mod tests {
         let cmd = BlockersCommand {
             id: "nonexistent".to_string(),
             depth: None,
+            paths: false,
+            max_paths: DEFAULT_MAX_PATHS,
+            explain: false,
+            format: OutputFormat::Table,
+            tree_chars: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -521,6 +809,11 @@ mod tests {
         let cmd = BlockersCommand {
             id: "TASK1".to_string(),
             depth: None,
+            paths: false,
+            max_paths: DEFAULT_MAX_PATHS,
+            explain: false,
+            format: OutputFormat::Table,
+            tree_chars: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -547,6 +840,11 @@ mod tests {
         let cmd = BlockersCommand {
             id: "task1".to_string(),
             depth: None,
+            paths: false,
+            max_paths: DEFAULT_MAX_PATHS,
+            explain: false,
+            format: OutputFormat::Table,
+            tree_chars: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -570,6 +868,115 @@ mod tests {
         cleanup(&temp_dir);
     }
 
+    #[tokio::test]
+    async fn test_blockers_paths_diamond() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        // Diamond: task1 -> (blocker1, blocker2) -> shared, plus shared -> root
+        create_task(&db, "root", "Root Blocker", "task", "todo").await;
+        create_task(&db, "shared", "Shared Blocker", "task", "todo").await;
+        create_task(&db, "blocker1", "Blocker 1", "task", "todo").await;
+        create_task(&db, "blocker2", "Blocker 2", "task", "todo").await;
+        create_task(&db, "task1", "Main Task", "task", "backlog").await;
+
+        create_depends_on(&db, "task1", "blocker1").await;
+        create_depends_on(&db, "task1", "blocker2").await;
+        create_depends_on(&db, "blocker1", "shared").await;
+        create_depends_on(&db, "blocker2", "shared").await;
+        create_depends_on(&db, "shared", "root").await;
+
+        let cmd = BlockersCommand {
+            id: "task1".to_string(),
+            depth: None,
+            paths: true,
+            max_paths: DEFAULT_MAX_PATHS,
+            explain: false,
+            format: OutputFormat::Table,
+            tree_chars: None,
+        };
+
+        let result = cmd.execute(&db).await.unwrap();
+        assert!(!result.paths_truncated);
+
+        use std::collections::HashSet;
+        let paths: HashSet<String> = result
+            .paths
+            .as_ref()
+            .unwrap()
+            .iter()
+            .map(|p| p.join(" -> "))
+            .collect();
+        let expected: HashSet<String> = [
+            "root -> shared -> blocker1 -> task1",
+            "root -> shared -> blocker2 -> task1",
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect();
+        assert_eq!(paths, expected);
+
+        let output = format!("{}", result);
+        assert!(output.contains("root -> shared -> blocker1 -> task1"));
+        assert!(output.contains("Total: 2 blocking paths"));
+        assert!(
+            !output.contains("|--"),
+            "paths mode should not print a tree"
+        );
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_blockers_paths_respects_max_paths() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        create_task(&db, "shared", "Shared Blocker", "task", "todo").await;
+        create_task(&db, "blocker1", "Blocker 1", "task", "todo").await;
+        create_task(&db, "blocker2", "Blocker 2", "task", "todo").await;
+        create_task(&db, "task1", "Main Task", "task", "backlog").await;
+
+        create_depends_on(&db, "task1", "blocker1").await;
+        create_depends_on(&db, "task1", "blocker2").await;
+        create_depends_on(&db, "blocker1", "shared").await;
+        create_depends_on(&db, "blocker2", "shared").await;
+
+        let cmd = BlockersCommand {
+            id: "task1".to_string(),
+            depth: None,
+            paths: true,
+            max_paths: 1,
+            explain: false,
+            format: OutputFormat::Table,
+            tree_chars: None,
+        };
+
+        let result = cmd.execute(&db).await.unwrap();
+        assert_eq!(result.paths.as_ref().unwrap().len(), 1);
+        assert!(result.paths_truncated);
+        assert!(format!("{}", result).contains("limit reached"));
+
+        cleanup(&temp_dir);
+    }
+
+    #[test]
+    fn test_collect_blocker_paths_uses_depth_cutoff_leaves() {
+        let tree = vec![BlockerNode {
+            id: "b1".to_string(),
+            title: "B1".to_string(),
+            level: "task".to_string(),
+            status: "todo".to_string(),
+            children: vec![],
+        }];
+
+        let (paths, truncated) = collect_blocker_paths(&tree, "t", 10);
+        assert_eq!(paths, vec![vec!["b1".to_string(), "t".to_string()]]);
+        assert!(!truncated);
+
+        let (paths, truncated) = collect_blocker_paths(&[], "t", 10);
+        assert!(paths.is_empty());
+        assert!(!truncated);
+    }
+
     #[tokio::test]
     async fn test_blockers_shows_status() {
         let (db, temp_dir) = setup_test_db().await;
@@ -584,6 +991,11 @@ mod tests {
         let cmd = BlockersCommand {
             id: "task1".to_string(),
             depth: None,
+            paths: false,
+            max_paths: DEFAULT_MAX_PATHS,
+            explain: false,
+            format: OutputFormat::Table,
+            tree_chars: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -661,6 +1073,9 @@ mod tests {
             task_title: "Test Task".to_string(),
             blockers: vec![],
             total_count: 0,
+            paths: None,
+            paths_truncated: false,
+            tree_chars: TreeChars::Unicode,
         };
 
         let output = format!("{}", result);
@@ -680,6 +1095,9 @@ mod tests {
                 children: vec![],
             }],
             total_count: 1,
+            paths: None,
+            paths_truncated: false,
+            tree_chars: TreeChars::Unicode,
         };
 
         let output = format!("{}", result);
@@ -717,6 +1135,9 @@ mod tests {
                 },
             ],
             total_count: 2,
+            paths: None,
+            paths_truncated: false,
+            tree_chars: TreeChars::Unicode,
         };
 
         let output = format!("{}", result);
@@ -724,11 +1145,128 @@ mod tests {
         assert_eq!(last_line, "Total: 2 blocking items");
     }
 
+    fn leaf(id: &str, children: Vec<BlockerNode>) -> BlockerNode {
+        BlockerNode {
+            id: id.to_string(),
+            title: format!("Task {}", id),
+            level: "task".to_string(),
+            status: "todo".to_string(),
+            children,
+        }
+    }
+
+    /// root -> {a -> {a1, a2}, b}, so every connector kind appears
+    fn branching_result(tree_chars: TreeChars) -> BlockersResult {
+        BlockersResult {
+            task_id: "target".to_string(),
+            task_title: "Target".to_string(),
+            blockers: vec![leaf(
+                "root",
+                vec![
+                    leaf("a", vec![leaf("a1", vec![]), leaf("a2", vec![])]),
+                    leaf("b", vec![]),
+                ],
+            )],
+            total_count: 5,
+            paths: None,
+            paths_truncated: false,
+            tree_chars,
+        }
+    }
+
+    fn tree_prefixes(result: &BlockersResult) -> Vec<String> {
+        result
+            .to_string()
+            .lines()
+            .skip(3)
+            .take(5)
+            .map(|line| line.split("Task ").next().unwrap().to_string())
+            .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+            .collect()
+    }
+
+    #[test]
+    fn test_blockers_tree_unicode_connectors() {
+        let result = branching_result(TreeChars::Unicode);
+        assert_eq!(
+            tree_prefixes(&result),
+            vec![
+                "root task todo",
+                "\u{251C}\u{2500}\u{2500} a task todo",
+                "\u{2502} \u{251C}\u{2500}\u{2500} a1 task todo",
+                "\u{2502} \u{2514}\u{2500}\u{2500} a2 task todo",
+                "\u{2514}\u{2500}\u{2500} b task todo",
+            ]
+        );
+        assert!(!result.to_string().contains("+--"));
+    }
+
+    #[test]
+    fn test_blockers_tree_ascii_connectors() {
+        let result = branching_result(TreeChars::Ascii);
+        assert_eq!(
+            tree_prefixes(&result),
+            vec![
+                "root task todo",
+                "+-- a task todo",
+                "| +-- a1 task todo",
+                "| \\-- a2 task todo",
+                "\\-- b task todo",
+            ]
+        );
+        assert!(result.to_string().is_ascii());
+    }
+
+    #[tokio::test]
+    async fn test_blockers_explain_lists_queries() {
+        let (db, temp_dir) = setup_test_db().await;
+        create_task(&db, "abc123xyz", "Target", "task", "todo").await;
+
+        let cmd = BlockersCommand {
+            id: "ABC123".to_string(),
+            depth: Some(2),
+            paths: false,
+            max_paths: DEFAULT_MAX_PATHS,
+            explain: true,
+            format: OutputFormat::Table,
+            tree_chars: None,
+        };
+
+        // A shortened, uppercase ID resolves to the stored one
+        let explain = cmd.explain(&db).await.unwrap();
+        let lines: Vec<&str> = explain.lines().collect();
+        assert_eq!(
+            lines,
+            vec![
+                "SELECT id, title, level, status FROM task:abc123xyz;",
+                "SELECT id, title, level, status FROM task \
+                 WHERE <-depends_on<-task CONTAINS task:abc123xyz AND deleted_at = NONE;",
+                "-- repeated for each blocker found, up to depth 2",
+            ]
+        );
+
+        let cmd = BlockersCommand {
+            id: "nope".to_string(),
+            ..cmd
+        };
+        assert!(matches!(
+            cmd.explain(&db).await,
+            Err(DbError::NotFound { .. })
+        ));
+
+        cleanup(&temp_dir);
+    }
+
     #[test]
     fn test_blockers_command_debug() {
         let cmd = BlockersCommand {
             id: "test".to_string(),
             depth: Some(5),
+            paths: false,
+            max_paths: DEFAULT_MAX_PATHS,
+            explain: false,
+            format: OutputFormat::Table,
+            tree_chars: None,
         };
         let debug_str = format!("{:?}", cmd);
         assert!(
@@ -760,6 +1298,9 @@ mod tests {
             task_title: "Test".to_string(),
             blockers: vec![],
             total_count: 0,
+            paths: None,
+            paths_truncated: false,
+            tree_chars: TreeChars::Unicode,
         };
         let debug_str = format!("{:?}", result);
         assert!(
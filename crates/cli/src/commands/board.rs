@@ -0,0 +1,390 @@
+//! Board command for a Kanban view of tasks
+//!
+//! Implements the `vtb board` command, which lays tasks out in one column
+//! per workflow status, side by side, sized to fit the terminal width.
+
+use clap::Args;
+use serde::Serialize;
+use vertebrae_db::{
+    Database, DbError, IdFormatter, Level, Status, TaskFilter, TaskSummary, id_format, tag_settings,
+};
+
+use crate::commands::list::parse_level;
+use crate::output::{OutputFormat, Render, to_json, truncate};
+
+/// Statuses shown as board columns, left to right
+pub const BOARD_STATUSES: [Status; 5] = [
+    Status::Backlog,
+    Status::Todo,
+    Status::InProgress,
+    Status::PendingReview,
+    Status::Done,
+];
+
+/// Width used when `--width` is not given and `COLUMNS` is unset
+const DEFAULT_BOARD_WIDTH: usize = 120;
+
+/// Columns never get narrower than this, even if the board overflows
+const MIN_COLUMN_WIDTH: usize = 12;
+
+/// Separator between columns
+const COLUMN_SEPARATOR: &str = " | ";
+
+/// Show tasks as a Kanban board with one column per status
+#[derive(Debug, Args)]
+pub struct BoardCommand {
+    /// Only show tasks at this level (can be specified multiple times)
+    #[arg(short, long = "level", value_parser = parse_level)]
+    pub levels: Vec<Level>,
+
+    /// Only show tasks with this tag (can be specified multiple times)
+    #[arg(short, long = "tag")]
+    pub tags: Vec<String>,
+
+    /// Total board width in characters (default: $COLUMNS, or 120)
+    #[arg(long)]
+    pub width: Option<usize>,
+
+    /// Output format: table or json
+    #[arg(long, value_enum, default_value_t = OutputFormat::Table)]
+    pub format: OutputFormat,
+}
+
+/// A task as shown on the board
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BoardCard {
+    /// Task ID
+    pub id: String,
+    /// Task title
+    pub title: String,
+}
+
+/// The cards for one status
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BoardColumn {
+    /// Status of every card in the column
+    pub status: Status,
+    /// Cards in listing order
+    pub cards: Vec<BoardCard>,
+}
+
+/// Result of the board command execution
+#[derive(Debug, Serialize)]
+pub struct BoardResult {
+    /// One column per entry in [`BOARD_STATUSES`]
+    pub columns: Vec<BoardColumn>,
+    /// Total width to render the table within
+    #[serde(skip)]
+    pub width: usize,
+}
+
+impl BoardCommand {
+    /// Execute the board command.
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - Reference to the database connection
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError` if the format is unsupported or the query fails.
+    pub async fn execute(&self, db: &Database) -> Result<BoardResult, DbError> {
+        BoardResult::check_format(self.format)?;
+
+        let mut filter = TaskFilter::new().include_done();
+        if !self.levels.is_empty() {
+            filter = filter.with_levels(self.levels.clone());
+        }
+        if !self.tags.is_empty() {
+            filter = filter.with_tags(tag_settings().apply_all(&self.tags));
+        }
+        let tasks = db.list_tasks().list(&filter).await?;
+
+        Ok(BoardResult {
+            columns: assign_columns(&tasks),
+            width: self.width.unwrap_or_else(terminal_width),
+        })
+    }
+}
+
+/// Sort tasks into one column per board status.
+///
+/// Tasks keep their relative order; statuses without a column (rejected)
+/// are left off the board.
+pub fn assign_columns(tasks: &[TaskSummary]) -> Vec<BoardColumn> {
+    BOARD_STATUSES
+        .iter()
+        .map(|status| BoardColumn {
+            status: status.clone(),
+            cards: tasks
+                .iter()
+                .filter(|task| task.status == *status)
+                .map(|task| BoardCard {
+                    id: task.id.clone(),
+                    title: task.title.clone(),
+                })
+                .collect(),
+        })
+        .collect()
+}
+
+/// Width of the terminal from `COLUMNS`, or the default.
+fn terminal_width() -> usize {
+    std::env::var("COLUMNS")
+        .ok()
+        .and_then(|cols| cols.trim().parse().ok())
+        .filter(|&cols| cols > 0)
+        .unwrap_or(DEFAULT_BOARD_WIDTH)
+}
+
+/// Width of each column when `count` columns share `width` characters.
+fn column_width(width: usize, count: usize) -> usize {
+    if count == 0 {
+        return 0;
+    }
+    let separators = COLUMN_SEPARATOR.len() * (count - 1);
+    (width.saturating_sub(separators) / count).max(MIN_COLUMN_WIDTH)
+}
+
+impl Render for BoardResult {
+    const COMMAND: &'static str = "board";
+    const FORMATS: &'static [OutputFormat] = &[OutputFormat::Table, OutputFormat::Json];
+
+    fn render_as(&self, format: OutputFormat) -> Result<String, DbError> {
+        match format {
+            OutputFormat::Json => to_json(self),
+            _ => Ok(self.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for BoardResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let width = column_width(self.width, self.columns.len());
+        let ids = IdFormatter::for_ids(
+            id_format(),
+            self.columns
+                .iter()
+                .flat_map(|c| c.cards.iter().map(|card| card.id.as_str())),
+        );
+
+        // Trailing empty cells are dropped so short columns leave no dangling separators
+        let line = |mut cells: Vec<String>, separator: &str| {
+            while cells.last().is_some_and(String::is_empty) {
+                cells.pop();
+            }
+            let cells: Vec<String> = cells
+                .iter()
+                .map(|cell| format!("{:<width$}", truncate(cell, width)))
+                .collect();
+            cells.join(separator).trim_end().to_string()
+        };
+
+        let headers = self
+            .columns
+            .iter()
+            .map(|c| format!("{} ({})", c.status.as_str(), c.cards.len()))
+            .collect();
+        writeln!(f, "{}", line(headers, COLUMN_SEPARATOR))?;
+
+        let rule = self.columns.iter().map(|_| "-".repeat(width)).collect();
+        write!(f, "{}", line(rule, "-+-"))?;
+
+        let rows = self
+            .columns
+            .iter()
+            .map(|c| c.cards.len())
+            .max()
+            .unwrap_or(0);
+        for row in 0..rows {
+            let cells = self
+                .columns
+                .iter()
+                .map(|c| match c.cards.get(row) {
+                    Some(card) => format!("{} {}", ids.format(&card.id), card.title),
+                    None => String::new(),
+                })
+                .collect();
+            write!(f, "\n{}", line(cells, COLUMN_SEPARATOR))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    /// Helper to create a test database
+    async fn setup_test_db() -> (Database, std::path::PathBuf) {
+        let temp_dir = env::temp_dir().join(format!(
+            "vtb-board-test-{}-{:?}-{}",
+            std::process::id(),
+            std::thread::current().id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+
+        let db = Database::connect(&temp_dir).await.unwrap();
+        db.init().await.unwrap();
+
+        (db, temp_dir)
+    }
+
+    /// Clean up test database
+    fn cleanup(path: &std::path::Path) {
+        let _ = std::fs::remove_dir_all(path);
+    }
+
+    fn summary(id: &str, title: &str, status: Status) -> TaskSummary {
+        TaskSummary {
+            id: id.to_string(),
+            title: title.to_string(),
+            level: Level::Task,
+            status,
+            priority: None,
+            tags: vec![],
+            needs_human_review: None,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    fn board_cmd() -> BoardCommand {
+        BoardCommand {
+            levels: vec![],
+            tags: vec![],
+            width: Some(80),
+            format: OutputFormat::Table,
+        }
+    }
+
+    fn card_ids(column: &BoardColumn) -> Vec<&str> {
+        column.cards.iter().map(|c| c.id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_assign_columns_by_status() {
+        let tasks = vec![
+            summary("a", "A", Status::Todo),
+            summary("b", "B", Status::Done),
+            summary("c", "C", Status::Todo),
+            summary("d", "D", Status::PendingReview),
+            summary("e", "E", Status::Rejected),
+        ];
+
+        let columns = assign_columns(&tasks);
+        let statuses: Vec<Status> = columns.iter().map(|c| c.status.clone()).collect();
+        assert_eq!(statuses, BOARD_STATUSES.to_vec());
+
+        assert!(columns[0].cards.is_empty());
+        assert_eq!(card_ids(&columns[1]), vec!["a", "c"]);
+        assert!(columns[2].cards.is_empty());
+        assert_eq!(card_ids(&columns[3]), vec!["d"]);
+        assert_eq!(card_ids(&columns[4]), vec!["b"]);
+
+        // Rejected tasks have no column
+        let total: usize = columns.iter().map(|c| c.cards.len()).sum();
+        assert_eq!(total, 4);
+    }
+
+    #[test]
+    fn test_column_width() {
+        // 5 columns and 4 separators of 3 characters in 80
+        assert_eq!(column_width(80, 5), 13);
+        assert_eq!(column_width(200, 5), 37);
+        assert_eq!(column_width(20, 5), MIN_COLUMN_WIDTH);
+        assert_eq!(column_width(80, 0), 0);
+    }
+
+    #[test]
+    fn test_board_display_lays_columns_side_by_side() {
+        let board = BoardResult {
+            columns: assign_columns(&[
+                summary("t1", "Write the parser", Status::Todo),
+                summary("t2", "Ship", Status::Todo),
+                summary("d1", "Done thing", Status::Done),
+            ]),
+            width: 80,
+        };
+
+        let output = board.to_string();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 4, "got:\n{}", output);
+        assert_eq!(
+            lines[0],
+            "backlog (0)   | todo (2)      | in_progres... | pending_re... | done (1)"
+        );
+        assert!(lines[1].starts_with("--------------+-"));
+
+        // Each card sits under its status header
+        let todo_col = lines[0].find("todo (2)").unwrap();
+        let done_col = lines[0].find("done (1)").unwrap();
+        assert_eq!(&lines[2][todo_col..todo_col + 13], "t1 Write t...");
+        assert_eq!(&lines[2][done_col..], "d1 Done thing");
+        assert_eq!(&lines[3][todo_col..], "t2 Ship");
+    }
+
+    #[tokio::test]
+    async fn test_board_places_each_task_in_its_status_column() {
+        let (db, temp_dir) = setup_test_db().await;
+        db.client()
+            .query(
+                r#"CREATE task:b1 SET title = "Idea", level = "task", status = "backlog";
+                   CREATE task:t1 SET title = "Next", level = "task", status = "todo";
+                   CREATE task:p1 SET title = "Doing", level = "task", status = "in_progress";
+                   CREATE task:r1 SET title = "Check", level = "ticket", status = "pending_review";
+                   CREATE task:d1 SET title = "Shipped", level = "task", status = "done";
+                   CREATE task:x1 SET title = "Dropped", level = "task", status = "rejected""#,
+            )
+            .await
+            .unwrap();
+
+        let board = board_cmd().execute(&db).await.unwrap();
+        let ids: Vec<Vec<&str>> = board.columns.iter().map(card_ids).collect();
+        assert_eq!(
+            ids,
+            vec![vec!["b1"], vec!["t1"], vec!["p1"], vec!["r1"], vec!["d1"]]
+        );
+
+        let mut cmd = board_cmd();
+        cmd.levels = vec![Level::Ticket];
+        let board = cmd.execute(&db).await.unwrap();
+        assert_eq!(card_ids(&board.columns[3]), vec!["r1"]);
+        assert_eq!(
+            board.columns.iter().map(|c| c.cards.len()).sum::<usize>(),
+            1
+        );
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_board_json_and_unsupported_format() {
+        let (db, temp_dir) = setup_test_db().await;
+        db.client()
+            .query(r#"CREATE task:t1 SET title = "Next", level = "task", status = "todo""#)
+            .await
+            .unwrap();
+
+        let mut cmd = board_cmd();
+        cmd.format = OutputFormat::Json;
+        let board = cmd.execute(&db).await.unwrap();
+        let json: serde_json::Value =
+            serde_json::from_str(&board.render(cmd.format).unwrap()).unwrap();
+        assert_eq!(json["columns"][1]["status"], "todo");
+        assert_eq!(json["columns"][1]["cards"][0]["id"], "t1");
+
+        cmd.format = OutputFormat::Csv;
+        assert!(matches!(
+            cmd.execute(&db).await,
+            Err(DbError::ValidationError { .. })
+        ));
+
+        cleanup(&temp_dir);
+    }
+}
@@ -0,0 +1,251 @@
+//! Bottlenecks command for finding the tasks that hold up the most work
+//!
+//! Implements the `vtb bottlenecks` command, which ranks open tasks by how
+//! many other tasks transitively depend on them.
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use vertebrae_db::{Database, DbError};
+
+use crate::output::{OutputFormat, Render, to_json};
+
+/// Show the open tasks that the most other tasks depend on
+#[derive(Debug, Args)]
+pub struct BottlenecksCommand {
+    /// Show the top N tasks
+    #[arg(long, value_name = "N", default_value_t = 10)]
+    pub top: usize,
+
+    /// Output format: table or json
+    #[arg(long, value_enum, default_value_t = OutputFormat::Table)]
+    pub format: OutputFormat,
+}
+
+/// A task and the work waiting on it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Bottleneck {
+    /// Task ID
+    pub id: String,
+    /// Task title
+    pub title: String,
+    /// Current status
+    pub status: String,
+    /// Number of tasks that depend on it, directly or not
+    pub impact: usize,
+}
+
+/// Result of the bottlenecks command execution
+#[derive(Debug, Serialize)]
+pub struct BottlenecksResult {
+    /// Tasks with the highest impact first
+    pub tasks: Vec<Bottleneck>,
+}
+
+/// Result from querying a task
+#[derive(Debug, Deserialize)]
+struct TaskRow {
+    id: surrealdb::sql::Thing,
+    title: String,
+    status: String,
+}
+
+impl Render for BottlenecksResult {
+    const COMMAND: &'static str = "bottlenecks";
+    const FORMATS: &'static [OutputFormat] = &[OutputFormat::Table, OutputFormat::Json];
+
+    fn render_as(&self, format: OutputFormat) -> Result<String, DbError> {
+        match format {
+            OutputFormat::Json => to_json(&self.tasks),
+            _ => Ok(self.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for BottlenecksResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.tasks.is_empty() {
+            return write!(f, "No open task has other tasks depending on it.");
+        }
+
+        let id_width = self
+            .tasks
+            .iter()
+            .map(|t| t.id.len())
+            .max()
+            .unwrap_or(0)
+            .max("ID".len());
+        let status_width = self
+            .tasks
+            .iter()
+            .map(|t| t.status.len())
+            .max()
+            .unwrap_or(0)
+            .max("Status".len());
+
+        write!(
+            f,
+            "{:<iw$}  {:<sw$}  Impact  Title",
+            "ID",
+            "Status",
+            iw = id_width,
+            sw = status_width,
+        )?;
+        for task in &self.tasks {
+            write!(
+                f,
+                "\n{:<iw$}  {:<sw$}  {:>6}  {}",
+                task.id,
+                task.status,
+                task.impact,
+                task.title,
+                iw = id_width,
+                sw = status_width,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl BottlenecksCommand {
+    /// Execute the bottlenecks command.
+    ///
+    /// Done and rejected tasks no longer hold anything up, and tasks nothing
+    /// depends on have no impact, so neither is listed.
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - Reference to the database connection
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError::GraphCycle` if the dependencies contain a cycle, or
+    /// another `DbError` if the format is unsupported or a query fails.
+    pub async fn execute(&self, db: &Database) -> Result<BottlenecksResult, DbError> {
+        BottlenecksResult::check_format(self.format)?;
+
+        let impact: Vec<(String, usize)> = db
+            .graph()
+            .blocker_impact()
+            .await?
+            .into_iter()
+            .filter(|(_, impact)| *impact > 0)
+            .collect();
+        if impact.is_empty() {
+            return Ok(BottlenecksResult { tasks: vec![] });
+        }
+
+        let query = format!(
+            r#"SELECT id, title, status FROM task
+               WHERE id IN [{}] AND status NOT IN ["done", "rejected"]"#,
+            impact
+                .iter()
+                .map(|(id, _)| format!("task:{}", id))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        let mut result = db.query(&query).await?;
+        let rows: Vec<TaskRow> = result.take(0)?;
+        let mut open: HashMap<String, TaskRow> = rows
+            .into_iter()
+            .map(|row| (row.id.id.to_string(), row))
+            .collect();
+
+        // `impact` is already ordered, highest first
+        let tasks = impact
+            .into_iter()
+            .filter_map(|(id, impact)| {
+                open.remove(&id).map(|row| Bottleneck {
+                    id,
+                    title: row.title,
+                    status: row.status,
+                    impact,
+                })
+            })
+            .take(self.top)
+            .collect();
+
+        Ok(BottlenecksResult { tasks })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    async fn setup_test_db() -> (Database, std::path::PathBuf) {
+        let temp_dir = env::temp_dir().join(format!(
+            "vtb-bottlenecks-test-{}-{:?}-{}",
+            std::process::id(),
+            std::thread::current().id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        let db = Database::connect(&temp_dir).await.unwrap();
+        db.init().await.unwrap();
+        (db, temp_dir)
+    }
+
+    fn cleanup(path: &std::path::Path) {
+        let _ = std::fs::remove_dir_all(path);
+    }
+
+    fn bottlenecks(top: usize) -> BottlenecksCommand {
+        BottlenecksCommand {
+            top,
+            format: OutputFormat::Table,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_bottlenecks_ranks_open_tasks_by_impact() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        let result = bottlenecks(10).execute(&db).await.unwrap();
+        assert_eq!(
+            result.to_string(),
+            "No open task has other tasks depending on it."
+        );
+
+        db.client()
+            .query(
+                r#"CREATE task:schema SET title = "Schema", level = "task", status = "done";
+                   CREATE task:api SET title = "API", level = "task", status = "in_progress";
+                   CREATE task:ui SET title = "UI", level = "task", status = "todo";
+                   CREATE task:docs SET title = "Docs", level = "task", status = "todo";
+                   CREATE task:release SET title = "Release", level = "epic", status = "todo";
+                   RELATE task:api -> depends_on -> task:schema;
+                   RELATE task:ui -> depends_on -> task:api;
+                   RELATE task:docs -> depends_on -> task:api;
+                   RELATE task:release -> depends_on -> task:ui;
+                   RELATE task:release -> depends_on -> task:docs"#,
+            )
+            .await
+            .unwrap();
+
+        // schema has the most dependents but is done
+        let result = bottlenecks(10).execute(&db).await.unwrap();
+        let ranked: Vec<(&str, usize)> = result
+            .tasks
+            .iter()
+            .map(|t| (t.id.as_str(), t.impact))
+            .collect();
+        assert_eq!(ranked, vec![("api", 3), ("docs", 1), ("ui", 1)]);
+        assert_eq!(
+            result.to_string(),
+            "ID    Status       Impact  Title\n\
+             api   in_progress       3  API\n\
+             docs  todo              1  Docs\n\
+             ui    todo              1  UI"
+        );
+
+        let result = bottlenecks(1).execute(&db).await.unwrap();
+        assert_eq!(result.tasks.len(), 1);
+        assert_eq!(result.tasks[0].id, "api");
+
+        cleanup(&temp_dir);
+    }
+}
@@ -0,0 +1,166 @@
+//! Clone command for duplicating tasks
+//!
+//! Implements the `vtb clone` command, which copies a task's content into a
+//! new task with a fresh ID. Dependencies are not copied, and children only
+//! with `--with-children`.
+
+use clap::Args;
+use vertebrae_db::{Database, DbError, generate_id};
+
+use crate::id::resolve_task_id;
+
+/// Copy a task (and optionally its subtree) into a new task
+#[derive(Debug, Args)]
+pub struct CloneCommand {
+    /// Task ID to clone (case-insensitive)
+    #[arg(required = true)]
+    pub id: String,
+
+    /// Also clone every descendant, under the new copy
+    #[arg(long)]
+    pub with_children: bool,
+}
+
+/// Result of the clone command execution
+#[derive(Debug)]
+pub struct CloneResult {
+    /// `(source_id, copy_id)` pairs, the cloned task first
+    pub copies: Vec<(String, String)>,
+}
+
+impl CloneResult {
+    /// ID of the new copy of the requested task
+    pub fn id(&self) -> &str {
+        self.copies.first().map_or("", |(_, copy)| copy.as_str())
+    }
+}
+
+impl std::fmt::Display for CloneResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some((source, copy)) = self.copies.first() else {
+            return Ok(());
+        };
+        write!(f, "Cloned task {} as {}", source, copy)?;
+        match self.copies.len() - 1 {
+            0 => Ok(()),
+            1 => write!(f, " (with 1 descendant)"),
+            n => write!(f, " (with {} descendants)", n),
+        }
+    }
+}
+
+impl CloneCommand {
+    /// Execute the clone command.
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - Reference to the database connection
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError` if:
+    /// - The task does not exist
+    /// - No free ID can be generated
+    /// - Database operations fail
+    pub async fn execute(&self, db: &Database) -> Result<CloneResult, DbError> {
+        // Resolve case-insensitive and shortened IDs
+        let id = resolve_task_id(db, &self.id).await?;
+        let copies = db
+            .tasks()
+            .clone_task(&id, self.with_children, generate_id)
+            .await?;
+        Ok(CloneResult { copies })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    async fn setup_test_db() -> (Database, std::path::PathBuf) {
+        let temp_dir = env::temp_dir().join(format!(
+            "vtb-clone-test-{}-{:?}-{}",
+            std::process::id(),
+            std::thread::current().id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        let db = Database::connect(&temp_dir).await.unwrap();
+        db.init().await.unwrap();
+        db.client()
+            .query(
+                r#"CREATE task:epic SET title = "Epic", level = "epic", status = "todo";
+                   CREATE task:child SET title = "Child", level = "task", status = "todo";
+                   RELATE task:child -> child_of -> task:epic"#,
+            )
+            .await
+            .unwrap();
+        (db, temp_dir)
+    }
+
+    fn cleanup(path: &std::path::Path) {
+        let _ = std::fs::remove_dir_all(path);
+    }
+
+    #[tokio::test]
+    async fn test_clone_creates_new_task() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        let cmd = CloneCommand {
+            id: "EPIC".to_string(),
+            with_children: false,
+        };
+        let result = cmd.execute(&db).await.unwrap();
+        assert_eq!(result.copies.len(), 1);
+        assert_ne!(result.id(), "epic");
+        assert_eq!(
+            result.to_string(),
+            format!("Cloned task epic as {}", result.id())
+        );
+
+        let copy = db.tasks().get(result.id()).await.unwrap().unwrap();
+        assert_eq!(copy.title, "Epic (copy)");
+        assert!(
+            db.relationships()
+                .get_children(result.id())
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_clone_with_children() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        let cmd = CloneCommand {
+            id: "epic".to_string(),
+            with_children: true,
+        };
+        let result = cmd.execute(&db).await.unwrap();
+        assert!(result.to_string().ends_with("(with 1 descendant)"));
+
+        let (source, child_copy) = &result.copies[1];
+        assert_eq!(source, "child");
+        assert_eq!(
+            db.relationships().get_parent(child_copy).await.unwrap(),
+            Some(result.id().to_string())
+        );
+
+        let err = CloneCommand {
+            id: "nope".to_string(),
+            with_children: false,
+        }
+        .execute(&db)
+        .await
+        .unwrap_err();
+        assert!(matches!(err, DbError::NotFound { .. }));
+
+        cleanup(&temp_dir);
+    }
+}
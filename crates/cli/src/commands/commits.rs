@@ -0,0 +1,421 @@
+//! Commits command for linking tasks to git history
+//!
+//! Implements the `vtb commits` command to scan commit messages since a git
+//! ref for task-ID mentions (`#<id>`) and optionally transition those tasks.
+
+use std::process::Command;
+
+use clap::Args;
+use vertebrae_db::{Database, DbError, find_project_root};
+
+use crate::commands::TransitionToCommand;
+use crate::commands::transition_to::TargetStatus;
+
+/// Record separator used in the `git log` format string
+const RECORD_SEPARATOR: char = '\u{1e}';
+
+/// Field separator used in the `git log` format string
+const FIELD_SEPARATOR: char = '\u{1f}';
+
+/// Report tasks mentioned in commit messages since a git ref
+#[derive(Debug, Args)]
+pub struct CommitsCommand {
+    /// Git ref to scan from (exclusive), e.g. a tag, branch, or commit hash
+    #[arg(long = "since-commit", required = true)]
+    pub since_commit: String,
+
+    /// Transition mentioned tasks (read-only without this flag)
+    #[arg(long)]
+    pub apply: bool,
+
+    /// Status to transition mentioned tasks to when --apply is given
+    #[arg(long, value_enum, default_value = "in_progress")]
+    pub to: TargetStatus,
+}
+
+/// A single commit read from git history
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommitInfo {
+    /// Abbreviated commit hash
+    pub hash: String,
+    /// Full commit message (subject and body)
+    pub message: String,
+}
+
+/// A task mentioned by one or more commits
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MentionedTask {
+    /// Task ID
+    pub id: String,
+    /// Task title
+    pub title: String,
+    /// Current status (before any transition)
+    pub status: String,
+    /// Hashes of the commits that mention the task
+    pub commits: Vec<String>,
+}
+
+/// Result of the commits command
+#[derive(Debug)]
+pub struct CommitsResult {
+    /// The ref the scan started from
+    pub since: String,
+    /// Number of commits scanned
+    pub commits_scanned: usize,
+    /// Existing tasks mentioned by commits
+    pub mentioned: Vec<MentionedTask>,
+    /// Mentioned IDs that don't match any task
+    pub unknown_ids: Vec<String>,
+    /// Tasks transitioned with --apply, as (id, new status)
+    pub applied: Vec<(String, String)>,
+    /// Tasks that could not be transitioned, as (id, reason)
+    pub skipped: Vec<(String, String)>,
+}
+
+impl std::fmt::Display for CommitsResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Scanned {} commit(s) since {}",
+            self.commits_scanned, self.since
+        )?;
+
+        if self.mentioned.is_empty() {
+            write!(f, "No tasks mentioned")?;
+        } else {
+            write!(f, "\nMentioned tasks:")?;
+            for task in &self.mentioned {
+                write!(
+                    f,
+                    "\n  - {} [{}] {} ({})",
+                    task.id,
+                    task.status,
+                    task.title,
+                    task.commits.join(", ")
+                )?;
+            }
+        }
+
+        if !self.unknown_ids.is_empty() {
+            write!(f, "\n\nUnknown IDs: {}", self.unknown_ids.join(", "))?;
+        }
+
+        if !self.applied.is_empty() {
+            write!(f, "\n\nTransitioned:")?;
+            for (id, status) in &self.applied {
+                write!(f, "\n  - {} -> {}", id, status)?;
+            }
+        }
+
+        if !self.skipped.is_empty() {
+            write!(f, "\n\nSkipped:")?;
+            for (id, reason) in &self.skipped {
+                write!(f, "\n  - {}: {}", id, reason)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl CommitsCommand {
+    /// Execute the commits command.
+    ///
+    /// Reads commit messages in `<since>..HEAD`, extracts task IDs mentioned
+    /// as `#<id>`, and reports which tasks exist. With `--apply`, each
+    /// mentioned task is transitioned to the `--to` status using the same
+    /// validation as `transition-to`; tasks that can't transition are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - Reference to the database connection
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError` if:
+    /// - `--since-commit` starts with `-`, so git would read it as an option
+    /// - `git log` cannot be run or fails (e.g. unknown ref)
+    /// - Database operations fail
+    pub async fn execute(&self, db: &Database) -> Result<CommitsResult, DbError> {
+        let commits = read_commits_since(&self.since_commit)?;
+
+        // Collect mentions in first-seen order, remembering which commits
+        let mut mentions: Vec<(String, Vec<String>)> = Vec::new();
+        for commit in &commits {
+            for id in parse_task_mentions(&commit.message) {
+                match mentions.iter_mut().find(|(m, _)| *m == id) {
+                    Some((_, hashes)) => {
+                        if !hashes.contains(&commit.hash) {
+                            hashes.push(commit.hash.clone());
+                        }
+                    }
+                    None => mentions.push((id, vec![commit.hash.clone()])),
+                }
+            }
+        }
+
+        let mut mentioned = Vec::new();
+        let mut unknown_ids = Vec::new();
+        for (id, hashes) in mentions {
+            match db.tasks().get(&id).await? {
+                Some(task) => mentioned.push(MentionedTask {
+                    id,
+                    title: task.title,
+                    status: task.status.as_str().to_string(),
+                    commits: hashes,
+                }),
+                None => unknown_ids.push(id),
+            }
+        }
+
+        let mut applied = Vec::new();
+        let mut skipped = Vec::new();
+        if self.apply {
+            for task in &mentioned {
+                if task.status == self.to.as_str() {
+                    continue;
+                }
+
+                let transition = TransitionToCommand {
+                    id: task.id.clone(),
+                    target: self.to,
+                    reason: None,
+                    force: false,
+                    skip_validation: false,
+                    started_at: None,
+                    completed_at: None,
+                };
+
+                match transition.execute(db).await {
+                    Ok(_) => applied.push((task.id.clone(), self.to.as_str().to_string())),
+                    Err(e) => skipped.push((task.id.clone(), e.to_string())),
+                }
+            }
+        }
+
+        Ok(CommitsResult {
+            since: self.since_commit.clone(),
+            commits_scanned: commits.len(),
+            mentioned,
+            unknown_ids,
+            applied,
+            skipped,
+        })
+    }
+}
+
+/// Read commits in `<since>..HEAD` from the project's git repository.
+fn read_commits_since(since: &str) -> Result<Vec<CommitInfo>, DbError> {
+    // `--output=<file>` and friends would otherwise reach git as options
+    if since.starts_with('-') {
+        return Err(DbError::ValidationError {
+            message: format!("invalid git ref '{}': refs cannot start with '-'", since),
+        });
+    }
+
+    let mut command = Command::new("git");
+    if let Some(root) = find_project_root() {
+        command.arg("-C").arg(root);
+    }
+
+    let format = format!("--format=%h{}%B{}", FIELD_SEPARATOR, RECORD_SEPARATOR);
+    let output = command
+        .args([
+            "log",
+            &format,
+            "--end-of-options",
+            &format!("{}..HEAD", since),
+        ])
+        .output()
+        .map_err(|e| DbError::ValidationError {
+            message: format!("failed to run git: {}", e),
+        })?;
+
+    if !output.status.success() {
+        return Err(DbError::ValidationError {
+            message: format!(
+                "git log failed for '{}': {}",
+                since,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ),
+        });
+    }
+
+    Ok(parse_git_log_output(&String::from_utf8_lossy(
+        &output.stdout,
+    )))
+}
+
+/// Parse `git log` output produced with the hash/message separators.
+///
+/// Records are separated by `RECORD_SEPARATOR`, and each record holds
+/// the abbreviated hash and message separated by `FIELD_SEPARATOR`.
+pub fn parse_git_log_output(output: &str) -> Vec<CommitInfo> {
+    output
+        .split(RECORD_SEPARATOR)
+        .filter_map(|record| {
+            let (hash, message) = record.split_once(FIELD_SEPARATOR)?;
+            let hash = hash.trim();
+            if hash.is_empty() {
+                return None;
+            }
+            Some(CommitInfo {
+                hash: hash.to_string(),
+                message: message.trim().to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Extract task IDs mentioned as `#<id>` in a commit message.
+///
+/// An ID is the run of ASCII alphanumerics or underscores after `#`. The `#`
+/// must start a word, so `C#` or `foo#bar` don't count. IDs are lowercased
+/// and deduplicated, keeping first-seen order.
+pub fn parse_task_mentions(message: &str) -> Vec<String> {
+    let mut ids: Vec<String> = Vec::new();
+    let chars: Vec<char> = message.chars().collect();
+
+    for (i, &c) in chars.iter().enumerate() {
+        if c != '#' {
+            continue;
+        }
+        if i > 0 && (chars[i - 1].is_alphanumeric() || chars[i - 1] == '_') {
+            continue;
+        }
+
+        let id: String = chars[i + 1..]
+            .iter()
+            .take_while(|ch| ch.is_ascii_alphanumeric() || **ch == '_')
+            .collect::<String>()
+            .to_lowercase();
+
+        if !id.is_empty() && !ids.contains(&id) {
+            ids.push(id);
+        }
+    }
+
+    ids
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_task_mentions_single() {
+        assert_eq!(parse_task_mentions("Fix login #a1b2c3"), vec!["a1b2c3"]);
+    }
+
+    #[test]
+    fn test_parse_task_mentions_multiple_and_dedup() {
+        let ids = parse_task_mentions("Refs #abc123, #def456\n\nAlso #abc123 again");
+        assert_eq!(ids, vec!["abc123", "def456"]);
+    }
+
+    #[test]
+    fn test_parse_task_mentions_lowercases() {
+        assert_eq!(parse_task_mentions("Closes #ABC123"), vec!["abc123"]);
+    }
+
+    #[test]
+    fn test_parse_task_mentions_punctuation_terminates_id() {
+        assert_eq!(
+            parse_task_mentions("(#abc123) and #def456."),
+            vec!["abc123", "def456"]
+        );
+    }
+
+    #[test]
+    fn test_parse_task_mentions_ignores_mid_word_hash() {
+        assert!(parse_task_mentions("Port to C# and foo#bar").is_empty());
+    }
+
+    #[test]
+    fn test_parse_task_mentions_ignores_bare_hash() {
+        assert!(parse_task_mentions("# Heading\n## Another").is_empty());
+    }
+
+    #[test]
+    fn test_parse_task_mentions_none() {
+        assert!(parse_task_mentions("Plain commit message").is_empty());
+    }
+
+    #[test]
+    fn test_parse_git_log_output() {
+        let raw = format!(
+            "abc1234{f}Add feature #t1\n\nBody text{r}\ndef5678{f}Fix bug{r}\n",
+            f = FIELD_SEPARATOR,
+            r = RECORD_SEPARATOR
+        );
+        let commits = parse_git_log_output(&raw);
+        assert_eq!(commits.len(), 2);
+        assert_eq!(commits[0].hash, "abc1234");
+        assert_eq!(commits[0].message, "Add feature #t1\n\nBody text");
+        assert_eq!(commits[1].hash, "def5678");
+        assert_eq!(commits[1].message, "Fix bug");
+    }
+
+    #[test]
+    fn test_read_commits_since_rejects_option_like_ref() {
+        let target = std::env::temp_dir().join(format!("vtb-commits-{}", std::process::id()));
+        let since = format!("--output={}", target.display());
+
+        match read_commits_since(&since) {
+            Err(DbError::ValidationError { message }) => {
+                assert!(
+                    message.contains("cannot start with '-'"),
+                    "got: {}",
+                    message
+                )
+            }
+            other => panic!("Expected ValidationError, got {:?}", other),
+        }
+        assert!(!target.exists());
+    }
+
+    #[test]
+    fn test_parse_git_log_output_empty() {
+        assert!(parse_git_log_output("").is_empty());
+        assert!(parse_git_log_output("\n").is_empty());
+    }
+
+    #[test]
+    fn test_commits_result_display() {
+        let result = CommitsResult {
+            since: "v1.0".to_string(),
+            commits_scanned: 3,
+            mentioned: vec![MentionedTask {
+                id: "abc123".to_string(),
+                title: "Login".to_string(),
+                status: "todo".to_string(),
+                commits: vec!["1111111".to_string(), "2222222".to_string()],
+            }],
+            unknown_ids: vec!["zzz999".to_string()],
+            applied: vec![("abc123".to_string(), "in_progress".to_string())],
+            skipped: vec![],
+        };
+
+        let output = format!("{}", result);
+        assert!(output.contains("Scanned 3 commit(s) since v1.0"));
+        assert!(output.contains("abc123 [todo] Login (1111111, 2222222)"));
+        assert!(output.contains("Unknown IDs: zzz999"));
+        assert!(output.contains("abc123 -> in_progress"));
+        assert!(!output.contains("Skipped"));
+    }
+
+    #[test]
+    fn test_commits_result_display_none_mentioned() {
+        let result = CommitsResult {
+            since: "HEAD~5".to_string(),
+            commits_scanned: 5,
+            mentioned: vec![],
+            unknown_ids: vec![],
+            applied: vec![],
+            skipped: vec![],
+        };
+
+        let output = format!("{}", result);
+        assert!(output.contains("No tasks mentioned"));
+    }
+}
@@ -0,0 +1,316 @@
+//! Count command for quick task distributions
+//!
+//! Implements the `vtb count --by <field>` command, which groups every task
+//! by one field and prints how many tasks share each value. Tags are unnested
+//! first, so a task counts once for each of its tags.
+
+use clap::{Args, ValueEnum};
+use serde::{Deserialize, Serialize};
+use vertebrae_db::{Database, DbError};
+
+use crate::output::{OutputFormat, Render, to_json};
+
+/// Label for tasks with no value in the counted field
+const NONE_LABEL: &str = "(none)";
+
+/// Field to group tasks by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum CountField {
+    /// Workflow status
+    Status,
+    /// Hierarchy level
+    Level,
+    /// Priority, with unprioritized tasks counted as `(none)`
+    Priority,
+    /// Each tag; untagged tasks are not counted
+    Tag,
+}
+
+impl CountField {
+    /// Returns the name used on the command line
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CountField::Status => "status",
+            CountField::Level => "level",
+            CountField::Priority => "priority",
+            CountField::Tag => "tag",
+        }
+    }
+
+    /// The aggregation query for this field, skipping tasks in the trash.
+    fn query(&self) -> String {
+        match self {
+            // Unnest in a subquery so the grouping sees one row per tag
+            CountField::Tag => "SELECT tag AS value, count() AS count \
+                                FROM (SELECT tags AS tag FROM task \
+                                      WHERE deleted_at = NONE SPLIT tag) \
+                                WHERE tag != NONE GROUP BY value"
+                .to_string(),
+            field => format!(
+                "SELECT {} AS value, count() AS count FROM task \
+                 WHERE deleted_at = NONE GROUP BY value",
+                field.as_str()
+            ),
+        }
+    }
+}
+
+/// Count tasks grouped by a field
+#[derive(Debug, Args)]
+pub struct CountCommand {
+    /// Field to group by: status, level, priority or tag
+    #[arg(long, value_enum)]
+    pub by: CountField,
+
+    /// Output format: table or json
+    #[arg(long, value_enum, default_value_t = OutputFormat::Table)]
+    pub format: OutputFormat,
+}
+
+/// Number of tasks sharing one value
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValueCount {
+    /// The field value, or `None` for tasks without one
+    pub value: Option<String>,
+    /// Number of tasks with this value
+    pub count: usize,
+}
+
+/// Result of the count command execution
+#[derive(Debug, Serialize)]
+pub struct CountResult {
+    /// The field tasks were grouped by
+    pub by: String,
+    /// One entry per distinct value, largest count first
+    pub counts: Vec<ValueCount>,
+}
+
+impl Render for CountResult {
+    const COMMAND: &'static str = "count";
+    const FORMATS: &'static [OutputFormat] = &[OutputFormat::Table, OutputFormat::Json];
+
+    fn render_as(&self, format: OutputFormat) -> Result<String, DbError> {
+        match format {
+            OutputFormat::Json => to_json(self),
+            _ => Ok(self.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for CountResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.counts.is_empty() {
+            return write!(f, "No tasks found.");
+        }
+
+        let labels: Vec<&str> = self
+            .counts
+            .iter()
+            .map(|c| c.value.as_deref().unwrap_or(NONE_LABEL))
+            .collect();
+        let width = labels
+            .iter()
+            .map(|l| l.chars().count())
+            .chain([self.by.len()])
+            .max()
+            .unwrap_or(0);
+
+        write!(
+            f,
+            "{:<width$}  COUNT",
+            self.by.to_uppercase(),
+            width = width
+        )?;
+        for (label, count) in labels.iter().zip(&self.counts) {
+            write!(f, "\n{:<width$}  {:>5}", label, count.count, width = width)?;
+        }
+        Ok(())
+    }
+}
+
+impl CountCommand {
+    /// Execute the count command.
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - Reference to the database connection
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError` if the format is unsupported or the query fails.
+    pub async fn execute(&self, db: &Database) -> Result<CountResult, DbError> {
+        CountResult::check_format(self.format)?;
+
+        let mut result = db.query(self.by.query()).await?;
+        let mut counts: Vec<ValueCount> = result.take(0)?;
+        counts.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.value.cmp(&b.value)));
+
+        Ok(CountResult {
+            by: self.by.as_str().to_string(),
+            counts,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    async fn setup_test_db() -> (Database, std::path::PathBuf) {
+        let temp_dir = env::temp_dir().join(format!(
+            "vtb-count-test-{}-{:?}-{}",
+            std::process::id(),
+            std::thread::current().id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        let db = Database::connect(&temp_dir).await.unwrap();
+        db.init().await.unwrap();
+        (db, temp_dir)
+    }
+
+    fn cleanup(path: &std::path::Path) {
+        let _ = std::fs::remove_dir_all(path);
+    }
+
+    /// Five tasks across three statuses, with overlapping tags
+    async fn seed(db: &Database) {
+        db.client()
+            .query(
+                r#"CREATE task:t1 SET title = "One", level = "task", status = "todo",
+                       priority = "high", tags = ["api", "backend"];
+                   CREATE task:t2 SET title = "Two", level = "task", status = "todo",
+                       tags = ["api"];
+                   CREATE task:t3 SET title = "Three", level = "ticket", status = "done",
+                       priority = "high", tags = ["backend"];
+                   CREATE task:t4 SET title = "Four", level = "epic", status = "in_progress",
+                       tags = ["api"];
+                   CREATE task:t5 SET title = "Five", level = "task", status = "todo""#,
+            )
+            .await
+            .unwrap();
+    }
+
+    fn count(value: Option<&str>, count: usize) -> ValueCount {
+        ValueCount {
+            value: value.map(str::to_string),
+            count,
+        }
+    }
+
+    async fn count_by(db: &Database, by: CountField) -> CountResult {
+        CountCommand {
+            by,
+            format: OutputFormat::Table,
+        }
+        .execute(db)
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_count_by_status() {
+        let (db, temp_dir) = setup_test_db().await;
+        seed(&db).await;
+
+        let result = count_by(&db, CountField::Status).await;
+        assert_eq!(
+            result.counts,
+            vec![
+                count(Some("todo"), 3),
+                count(Some("done"), 1),
+                count(Some("in_progress"), 1),
+            ]
+        );
+        assert_eq!(
+            result.to_string(),
+            "STATUS       COUNT\ntodo             3\ndone             1\nin_progress      1"
+        );
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_count_by_tag_unnests_tags() {
+        let (db, temp_dir) = setup_test_db().await;
+        seed(&db).await;
+
+        let result = count_by(&db, CountField::Tag).await;
+        assert_eq!(
+            result.counts,
+            vec![count(Some("api"), 3), count(Some("backend"), 2)]
+        );
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_count_by_priority_counts_missing_values() {
+        let (db, temp_dir) = setup_test_db().await;
+        seed(&db).await;
+
+        let result = count_by(&db, CountField::Priority).await;
+        assert_eq!(result.counts, vec![count(None, 3), count(Some("high"), 2)]);
+        assert!(result.to_string().contains("(none)"));
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_count_skips_trashed_tasks() {
+        let (db, temp_dir) = setup_test_db().await;
+        seed(&db).await;
+        db.query("UPDATE task:t1 SET deleted_at = time::now()")
+            .await
+            .unwrap();
+
+        let result = count_by(&db, CountField::Status).await;
+        assert_eq!(
+            result.counts,
+            vec![
+                count(Some("todo"), 2),
+                count(Some("done"), 1),
+                count(Some("in_progress"), 1),
+            ]
+        );
+
+        let result = count_by(&db, CountField::Tag).await;
+        assert_eq!(
+            result.counts,
+            vec![count(Some("api"), 2), count(Some("backend"), 1)]
+        );
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_count_json_and_empty() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        let result = count_by(&db, CountField::Level).await;
+        assert!(result.counts.is_empty());
+        assert_eq!(result.to_string(), "No tasks found.");
+
+        seed(&db).await;
+        let result = count_by(&db, CountField::Level).await;
+        let json: serde_json::Value =
+            serde_json::from_str(&result.render(OutputFormat::Json).unwrap()).unwrap();
+        assert_eq!(json["by"], "level");
+        assert_eq!(json["counts"][0]["value"], "task");
+        assert_eq!(json["counts"][0]["count"], 3);
+
+        let err = CountCommand {
+            by: CountField::Level,
+            format: OutputFormat::Csv,
+        }
+        .execute(&db)
+        .await
+        .unwrap_err();
+        assert!(matches!(err, DbError::ValidationError { .. }));
+
+        cleanup(&temp_dir);
+    }
+}
@@ -5,6 +5,7 @@
 //! implementations that prove the desired functionality works.
 
 use crate::commands::r#ref::{ParsedFileRef, parse_file_ref};
+use crate::id::resolve_task_id;
 use clap::Args;
 use serde::Deserialize;
 use std::path::Path;
@@ -134,8 +135,8 @@ impl CriterionRefCommand {
     /// - The file specification is invalid
     /// - Database operations fail
     pub async fn execute(&self, db: &Database) -> Result<CriterionRefResult, DbError> {
-        // Normalize ID to lowercase for case-insensitive lookup
-        let id = self.id.to_lowercase();
+        // Resolve case-insensitive and shortened IDs
+        let id = resolve_task_id(db, &self.id).await?;
 
         // Validate index is positive
         if self.index == 0 {
@@ -213,7 +214,7 @@ impl CriterionRefCommand {
         }
 
         let query = format!("SELECT sections FROM task:{}", id);
-        let mut result = db.client().query(&query).await?;
+        let mut result = db.query(&query).await?;
         let task: Option<TaskRow> = result.take(0)?;
 
         match task {
@@ -262,7 +263,7 @@ impl CriterionRefCommand {
             "UPDATE task:{} SET sections[{}].refs = array::append(sections[{}].refs ?? [], {})",
             id, section_index, section_index, ref_obj
         );
-        db.client().query(&query).await?;
+        db.query(&query).await?;
 
         Ok(())
     }
@@ -270,7 +271,7 @@ impl CriterionRefCommand {
     /// Update the task's updated_at timestamp.
     async fn update_timestamp(&self, db: &Database, id: &str) -> Result<(), DbError> {
         let query = format!("UPDATE task:{} SET updated_at = time::now()", id);
-        db.client().query(&query).await?;
+        db.query(&query).await?;
         Ok(())
     }
 }
@@ -0,0 +1,201 @@
+//! Critical path command for finding the longest dependency chain
+//!
+//! Implements the `vtb critical-path` command, which shows the longest chain
+//! of `depends_on` edges starting from a task: the sequence of work that has
+//! to happen, one after another, before the task can be done.
+
+use crate::id::resolve_task_id;
+use clap::Args;
+use serde::Deserialize;
+use std::collections::HashMap;
+use vertebrae_db::{Database, DbError};
+
+/// Show the longest dependency chain below a task
+#[derive(Debug, Args)]
+pub struct CriticalPathCommand {
+    /// Task ID to start from (case-insensitive)
+    #[arg(required = true)]
+    pub id: String,
+}
+
+/// A task on the critical path
+#[derive(Debug, Clone)]
+pub struct ChainTask {
+    /// Task ID
+    pub id: String,
+    /// Task title
+    pub title: String,
+    /// Current status
+    pub status: String,
+}
+
+/// Result of the critical-path command execution
+#[derive(Debug)]
+pub struct CriticalPathResult {
+    /// The chain, starting with the requested task
+    pub path: Vec<ChainTask>,
+}
+
+/// Result from querying a task
+#[derive(Debug, Deserialize)]
+struct TaskRow {
+    id: surrealdb::sql::Thing,
+    title: String,
+    status: String,
+}
+
+impl CriticalPathCommand {
+    /// Execute the critical-path command.
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - Reference to the database connection
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError` if:
+    /// - The task does not exist
+    /// - The dependency graph below it contains a cycle
+    /// - Database operations fail
+    pub async fn execute(&self, db: &Database) -> Result<CriticalPathResult, DbError> {
+        let id = resolve_task_id(db, &self.id).await?;
+        db.tasks().get_or_err(&id).await?;
+
+        let ids = db.graph().critical_path(&id).await?;
+
+        let query = format!(
+            "SELECT id, title, status FROM task WHERE id IN [{}]",
+            ids.iter()
+                .map(|id| format!("task:{}", id))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        let mut result = db.query(&query).await?;
+        let rows: Vec<TaskRow> = result.take(0)?;
+        let mut tasks: HashMap<String, TaskRow> = rows
+            .into_iter()
+            .map(|row| (row.id.id.to_string(), row))
+            .collect();
+
+        let path = ids
+            .into_iter()
+            .map(|id| match tasks.remove(&id) {
+                Some(row) => ChainTask {
+                    id,
+                    title: row.title,
+                    status: row.status,
+                },
+                None => ChainTask {
+                    id,
+                    title: "Unknown".to_string(),
+                    status: "unknown".to_string(),
+                },
+            })
+            .collect();
+
+        Ok(CriticalPathResult { path })
+    }
+}
+
+impl std::fmt::Display for CriticalPathResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some(start) = self.path.first() else {
+            return Ok(());
+        };
+        let edges = self.path.len() - 1;
+        if edges == 0 {
+            return write!(f, "{} \"{}\" has no dependencies", start.id, start.title);
+        }
+
+        writeln!(
+            f,
+            "Critical path from {} ({} dependenc{} deep):",
+            start.id,
+            edges,
+            if edges == 1 { "y" } else { "ies" }
+        )?;
+        writeln!(f)?;
+        for (i, task) in self.path.iter().enumerate() {
+            if i > 0 {
+                writeln!(f, "   \u{2193} depends on")?;
+            }
+            writeln!(f, "{:<8}  [{}] \"{}\"", task.id, task.status, task.title)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    async fn setup_test_db() -> (Database, std::path::PathBuf) {
+        let temp_dir = env::temp_dir().join(format!(
+            "vtb-critical-path-test-{}-{:?}-{}",
+            std::process::id(),
+            std::thread::current().id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        let db = Database::connect(&temp_dir).await.unwrap();
+        db.init().await.unwrap();
+        (db, temp_dir)
+    }
+
+    fn cleanup(path: &std::path::Path) {
+        let _ = std::fs::remove_dir_all(path);
+    }
+
+    fn critical_path(id: &str) -> CriticalPathCommand {
+        CriticalPathCommand { id: id.to_string() }
+    }
+
+    #[tokio::test]
+    async fn test_critical_path_follows_longest_chain() {
+        let (db, temp_dir) = setup_test_db().await;
+        db.client()
+            .query(
+                r#"CREATE task:ship SET title = "Ship", level = "task", status = "todo";
+                   CREATE task:docs SET title = "Docs", level = "task", status = "todo";
+                   CREATE task:api SET title = "API", level = "task", status = "in_progress";
+                   CREATE task:schema SET title = "Schema", level = "task", status = "done";
+                   RELATE task:ship -> depends_on -> task:docs;
+                   RELATE task:ship -> depends_on -> task:api;
+                   RELATE task:api -> depends_on -> task:schema"#,
+            )
+            .await
+            .unwrap();
+
+        let result = critical_path("SHIP").execute(&db).await.unwrap();
+        let ids: Vec<&str> = result.path.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(ids, vec!["ship", "api", "schema"]);
+        assert_eq!(result.path[1].status, "in_progress");
+
+        let output = result.to_string();
+        assert!(output.starts_with("Critical path from ship (2 dependencies deep):"));
+        assert!(output.contains("api       [in_progress] \"API\""));
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_critical_path_without_dependencies_and_missing_task() {
+        let (db, temp_dir) = setup_test_db().await;
+        db.client()
+            .query(r#"CREATE task:solo SET title = "Solo", level = "task", status = "todo""#)
+            .await
+            .unwrap();
+
+        let result = critical_path("solo").execute(&db).await.unwrap();
+        assert_eq!(result.path.len(), 1);
+        assert_eq!(result.to_string(), "solo \"Solo\" has no dependencies");
+
+        let err = critical_path("nope").execute(&db).await.unwrap_err();
+        assert!(matches!(err, DbError::NotFound { .. }));
+
+        cleanup(&temp_dir);
+    }
+}
@@ -1,17 +1,22 @@
-//! Delete command for removing tasks
+//! Delete command for trashing or removing tasks
 //!
 //! Implements the `vtb delete` command to remove tasks with proper handling
-//! of children and dependencies.
+//! of children and dependencies. By default tasks are moved to the trash by
+//! setting `deleted_at`, keeping their edges so `vtb restore` can bring them
+//! back; `--hard` removes them for good.
 
+use chrono::Utc;
 use clap::Args;
 use serde::Deserialize;
-use std::io::{self, Write};
 use vertebrae_db::{Database, DbError};
 
-/// Delete a task with optional cascade behavior
-#[derive(Debug, Args)]
+use crate::confirm::Prompter;
+use crate::id::resolve_task_id;
+
+/// Move a task to the trash, or delete it permanently with --hard
+#[derive(Debug, Clone, Args)]
 pub struct DeleteCommand {
-    /// Task ID to delete (case-insensitive)
+    /// Task ID to delete (case-insensitive; `-` reads IDs from stdin)
     #[arg(required = true)]
     pub id: String,
 
@@ -19,8 +24,12 @@ pub struct DeleteCommand {
     #[arg(long)]
     pub cascade: bool,
 
-    /// Skip confirmation prompt
-    #[arg(short, long)]
+    /// Remove the task permanently instead of moving it to the trash
+    #[arg(long)]
+    pub hard: bool,
+
+    /// Skip confirmation prompts (required when stdin is not a terminal)
+    #[arg(short, long, visible_aliases = ["yes", "assume-yes"], short_alias = 'y')]
     pub force: bool,
 }
 
@@ -41,13 +50,17 @@ struct TaskInfo {
     #[allow(dead_code)]
     id: surrealdb::sql::Thing,
     title: String,
+    #[serde(default)]
+    deleted_at: Option<surrealdb::sql::Datetime>,
 }
 
 impl DeleteCommand {
     /// Execute the delete command.
     ///
-    /// Deletes the task with the given ID, handling children and dependencies
-    /// according to the specified options.
+    /// Moves the task with the given ID to the trash, or removes it
+    /// permanently with `--hard`, handling children and dependencies
+    /// according to the specified options. Trashed tasks come back with
+    /// `vtb restore`.
     ///
     /// # Arguments
     ///
@@ -57,13 +70,31 @@ impl DeleteCommand {
     ///
     /// Returns `DbError` if:
     /// - The task with the given ID does not exist
+    /// - Confirmation is needed but stdin is not a terminal
     /// - Database operations fail
     pub async fn execute(&self, db: &Database) -> Result<String, DbError> {
-        // Normalize ID to lowercase for case-insensitive lookup
-        let id = self.id.to_lowercase();
+        self.execute_with(db, Prompter::from_terminal()).await
+    }
+
+    /// Execute the delete command, asking `prompter` for any confirmations.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`DeleteCommand::execute`].
+    pub async fn execute_with(&self, db: &Database, prompter: Prompter) -> Result<String, DbError> {
+        // Resolve case-insensitive and shortened IDs
+        let id = resolve_task_id(db, &self.id).await?;
 
         // Verify task exists
         let task_info = self.fetch_task_info(db, &id).await?;
+        if !self.hard && task_info.deleted_at.is_some() {
+            return Err(DbError::ValidationError {
+                message: format!(
+                    "Task '{}' is already in the trash; use --hard to remove it permanently",
+                    id
+                ),
+            });
+        }
 
         // Get children count
         let children = self.fetch_children_ids(db, &id).await?;
@@ -82,7 +113,7 @@ impl DeleteCommand {
                 ChildAction::Orphan
             } else {
                 // Interactive: ask user
-                self.prompt_child_action(children_count)?
+                self.prompt_child_action(prompter, children_count)?
             }
         } else {
             // No children, no action needed
@@ -94,41 +125,94 @@ impl DeleteCommand {
             return Ok("Deletion cancelled".to_string());
         }
 
-        // If not --force and task blocks others, warn and confirm
-        if !self.force && blocks_count > 0 && !self.confirm_blocking(blocks_count)? {
-            return Ok("Deletion cancelled".to_string());
-        }
+        if !self.force {
+            // --cascade picks the action up front, but still confirm the blast radius
+            if self.cascade && children_count > 0 {
+                let descendants = self.collect_all_descendants(db, &id).await?;
+                let question = format!(
+                    "Delete task '{}' and {} {}?",
+                    task_info.title,
+                    descendants.len(),
+                    if descendants.len() == 1 {
+                        "descendant"
+                    } else {
+                        "descendants"
+                    }
+                );
+                if !prompter.confirm(&question)? {
+                    return Ok("Deletion cancelled".to_string());
+                }
+            }
 
-        // If not --force and no children, just confirm deletion
-        if !self.force && children_count == 0 && !self.confirm_delete(&task_info.title)? {
-            return Ok("Deletion cancelled".to_string());
+            // Task blocks others: warn and confirm
+            if blocks_count > 0 && !self.confirm_blocking(prompter, blocks_count)? {
+                return Ok("Deletion cancelled".to_string());
+            }
+
+            // No children: just confirm deletion
+            if children_count == 0
+                && !prompter.confirm(&format!("Delete task '{}'?", task_info.title))?
+            {
+                return Ok("Deletion cancelled".to_string());
+            }
         }
 
         // Perform the deletion
         let deleted_count = match child_action {
-            ChildAction::Cascade => self.cascade_delete(db, &id).await?,
+            ChildAction::Cascade if self.hard => self.cascade_delete(db, &id).await?,
+            ChildAction::Cascade => {
+                let mut ids = vec![id.clone()];
+                ids.extend(self.collect_all_descendants(db, &id).await?);
+                self.move_to_trash(db, &ids).await?;
+                ids.len()
+            }
             ChildAction::Orphan => {
                 self.orphan_children(db, &id).await?;
-                self.delete_single_task(db, &id).await?;
+                if self.hard {
+                    self.delete_single_task(db, &id).await?;
+                } else {
+                    self.move_to_trash(db, std::slice::from_ref(&id)).await?;
+                }
                 1
             }
             ChildAction::Cancel => unreachable!(), // Already handled above
         };
 
-        if deleted_count == 1 {
-            Ok(format!("Deleted task: {}", id))
-        } else {
-            Ok(format!(
-                "Deleted {} tasks (including children)",
-                deleted_count
-            ))
-        }
+        Ok(match (self.hard, deleted_count) {
+            (true, 1) => format!("Deleted task: {}", id),
+            (true, n) => format!("Deleted {} tasks (including children)", n),
+            (false, 1) => format!(
+                "Moved task {} to the trash (undo with `vtb restore {}`)",
+                id, id
+            ),
+            (false, n) => format!(
+                "Moved {} tasks to the trash, including children (undo with `vtb restore {}`)",
+                n, id
+            ),
+        })
+    }
+
+    /// Mark tasks as deleted, all with the same `deleted_at`.
+    ///
+    /// Their edges stay in place; the shared timestamp is how `vtb restore`
+    /// tells a cascade apart from children that were deleted separately.
+    async fn move_to_trash(&self, db: &Database, ids: &[String]) -> Result<(), DbError> {
+        let query = format!(
+            "UPDATE task SET deleted_at = d'{}', updated_at = time::now() WHERE id IN [{}]",
+            Utc::now().to_rfc3339(),
+            ids.iter()
+                .map(|id| format!("task:{}", id))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        db.query(&query).await?.check()?;
+        Ok(())
     }
 
     /// Fetch basic task info to verify existence and get title.
     async fn fetch_task_info(&self, db: &Database, id: &str) -> Result<TaskInfo, DbError> {
-        let query = format!("SELECT id, title FROM task:{} LIMIT 1", id);
-        let mut result = db.client().query(&query).await?;
+        let query = format!("SELECT id, title, deleted_at FROM task:{} LIMIT 1", id);
+        let mut result = db.query(&query).await?;
         let task: Option<TaskInfo> = result.take(0)?;
 
         task.ok_or_else(|| DbError::NotFound {
@@ -143,13 +227,15 @@ impl DeleteCommand {
             id: surrealdb::sql::Thing,
         }
 
-        // Children are tasks that have a child_of edge pointing to this task
+        // Children are tasks that have a child_of edge pointing to this task;
+        // those already in the trash only matter when removing for good
         let query = format!(
-            "SELECT id FROM task WHERE ->child_of->task CONTAINS task:{}",
-            id
+            "SELECT id FROM task WHERE ->child_of->task CONTAINS task:{}{}",
+            id,
+            self.live_only()
         );
 
-        let mut result = db.client().query(&query).await?;
+        let mut result = db.query(&query).await?;
         let rows: Vec<IdRow> = result.take(0)?;
 
         Ok(rows.into_iter().map(|r| r.id.id.to_string()).collect())
@@ -164,16 +250,26 @@ impl DeleteCommand {
 
         // Tasks that depend on this task (this task blocks them)
         let query = format!(
-            "SELECT id FROM task WHERE ->depends_on->task CONTAINS task:{}",
-            id
+            "SELECT id FROM task WHERE ->depends_on->task CONTAINS task:{}{}",
+            id,
+            self.live_only()
         );
 
-        let mut result = db.client().query(&query).await?;
+        let mut result = db.query(&query).await?;
         let rows: Vec<IdRow> = result.take(0)?;
 
         Ok(rows.into_iter().map(|r| r.id.id.to_string()).collect())
     }
 
+    /// Extra condition limiting a soft delete to tasks not yet in the trash.
+    fn live_only(&self) -> &'static str {
+        if self.hard {
+            ""
+        } else {
+            " AND deleted_at = NONE"
+        }
+    }
+
     /// Recursively collect all descendant IDs (children, grandchildren, etc.)
     async fn collect_all_descendants(
         &self,
@@ -214,7 +310,7 @@ impl DeleteCommand {
         // Delete tasks
         for task_id in &all_ids {
             let query = format!("DELETE task:{}", task_id);
-            db.client().query(&query).await?;
+            db.query(&query).await?;
         }
 
         Ok(all_ids.len())
@@ -222,9 +318,18 @@ impl DeleteCommand {
 
     /// Make children of a task into root tasks (orphan them).
     async fn orphan_children(&self, db: &Database, id: &str) -> Result<(), DbError> {
-        // Delete child_of edges where children point to this task
-        let query = format!("DELETE child_of WHERE out = task:{}", id);
-        db.client().query(&query).await?;
+        // Delete child_of edges where children point to this task; children
+        // already in the trash keep theirs so they restore into place
+        let query = format!(
+            "DELETE child_of WHERE out = task:{}{}",
+            id,
+            if self.hard {
+                ""
+            } else {
+                " AND in.deleted_at = NONE"
+            }
+        );
+        db.query(&query).await?;
         Ok(())
     }
 
@@ -235,7 +340,7 @@ impl DeleteCommand {
 
         // Delete the task
         let query = format!("DELETE task:{}", id);
-        db.client().query(&query).await?;
+        db.query(&query).await?;
 
         Ok(())
     }
@@ -244,26 +349,30 @@ impl DeleteCommand {
     async fn delete_all_edges(&self, db: &Database, id: &str) -> Result<(), DbError> {
         // Delete child_of edges where this task is the child (out direction)
         let query = format!("DELETE child_of WHERE in = task:{}", id);
-        db.client().query(&query).await?;
+        db.query(&query).await?;
 
         // Delete child_of edges where this task is the parent (in direction)
         let query = format!("DELETE child_of WHERE out = task:{}", id);
-        db.client().query(&query).await?;
+        db.query(&query).await?;
 
         // Delete depends_on edges where this task depends on something
         let query = format!("DELETE depends_on WHERE in = task:{}", id);
-        db.client().query(&query).await?;
+        db.query(&query).await?;
 
         // Delete depends_on edges where something depends on this task
         let query = format!("DELETE depends_on WHERE out = task:{}", id);
-        db.client().query(&query).await?;
+        db.query(&query).await?;
 
         Ok(())
     }
 
     /// Prompt user for action when task has children.
-    fn prompt_child_action(&self, children_count: usize) -> Result<ChildAction, DbError> {
-        print!(
+    fn prompt_child_action(
+        &self,
+        prompter: Prompter,
+        children_count: usize,
+    ) -> Result<ChildAction, DbError> {
+        let answer = prompter.ask(&format!(
             "Task has {} {}. [C]ascade delete / [O]rphan / [A]bort? ",
             children_count,
             if children_count == 1 {
@@ -271,68 +380,22 @@ impl DeleteCommand {
             } else {
                 "children"
             }
-        );
-        io::stdout().flush().map_err(|e| DbError::InvalidPath {
-            path: std::path::PathBuf::from("stdout"),
-            reason: format!("Failed to flush stdout: {}", e),
-        })?;
-
-        let mut input = String::new();
-        io::stdin()
-            .read_line(&mut input)
-            .map_err(|e| DbError::InvalidPath {
-                path: std::path::PathBuf::from("stdin"),
-                reason: format!("Failed to read input: {}", e),
-            })?;
-
-        match input.trim().to_lowercase().as_str() {
+        ))?;
+
+        match answer.as_str() {
             "c" | "cascade" => Ok(ChildAction::Cascade),
             "o" | "orphan" => Ok(ChildAction::Orphan),
-            "a" | "abort" | "" => Ok(ChildAction::Cancel),
             _ => Ok(ChildAction::Cancel),
         }
     }
 
     /// Confirm deletion when task blocks other tasks.
-    fn confirm_blocking(&self, blocks_count: usize) -> Result<bool, DbError> {
-        print!(
-            "This task blocks {} other {}. Continue? [y/N] ",
+    fn confirm_blocking(&self, prompter: Prompter, blocks_count: usize) -> Result<bool, DbError> {
+        prompter.confirm(&format!(
+            "This task blocks {} other {}. Continue?",
             blocks_count,
             if blocks_count == 1 { "task" } else { "tasks" }
-        );
-        io::stdout().flush().map_err(|e| DbError::InvalidPath {
-            path: std::path::PathBuf::from("stdout"),
-            reason: format!("Failed to flush stdout: {}", e),
-        })?;
-
-        let mut input = String::new();
-        io::stdin()
-            .read_line(&mut input)
-            .map_err(|e| DbError::InvalidPath {
-                path: std::path::PathBuf::from("stdin"),
-                reason: format!("Failed to read input: {}", e),
-            })?;
-
-        Ok(matches!(input.trim().to_lowercase().as_str(), "y" | "yes"))
-    }
-
-    /// Confirm simple deletion.
-    fn confirm_delete(&self, title: &str) -> Result<bool, DbError> {
-        print!("Delete task '{}'? [y/N] ", title);
-        io::stdout().flush().map_err(|e| DbError::InvalidPath {
-            path: std::path::PathBuf::from("stdout"),
-            reason: format!("Failed to flush stdout: {}", e),
-        })?;
-
-        let mut input = String::new();
-        io::stdin()
-            .read_line(&mut input)
-            .map_err(|e| DbError::InvalidPath {
-                path: std::path::PathBuf::from("stdin"),
-                reason: format!("Failed to read input: {}", e),
-            })?;
-
-        Ok(matches!(input.trim().to_lowercase().as_str(), "y" | "yes"))
+        ))
     }
 }
 
@@ -454,6 +517,91 @@ mod tests {
         let _ = std::fs::remove_dir_all(path);
     }
 
+    /// Helper to read a task's `deleted_at`, if the row still exists
+    async fn deleted_at(db: &Database, id: &str) -> Option<String> {
+        let task = db.tasks().get(id).await.unwrap().expect("task row exists");
+        task.deleted_at.map(|at| at.to_rfc3339())
+    }
+
+    #[tokio::test]
+    async fn test_soft_delete_keeps_row_and_edges() {
+        let (db, temp_dir) = setup_test_db().await;
+        create_task(&db, "parent", "Parent", "epic", "todo").await;
+        create_task(&db, "child", "Child", "task", "todo").await;
+        create_task(&db, "grandchild", "Grandchild", "task", "todo").await;
+        create_task(&db, "dependent", "Dependent", "task", "todo").await;
+        create_child_of(&db, "child", "parent").await;
+        create_child_of(&db, "grandchild", "child").await;
+        create_depends_on(&db, "dependent", "child").await;
+
+        let cmd = DeleteCommand {
+            id: "parent".to_string(),
+            cascade: true,
+            hard: false,
+            force: true,
+        };
+        let output = cmd.execute(&db).await.unwrap();
+        assert!(
+            output.starts_with("Moved 3 tasks to the trash"),
+            "got: {}",
+            output
+        );
+
+        // Rows and edges stay; the whole cascade shares one timestamp
+        let at = deleted_at(&db, "parent").await;
+        assert!(at.is_some());
+        assert_eq!(deleted_at(&db, "child").await, at);
+        assert_eq!(deleted_at(&db, "grandchild").await, at);
+        assert_eq!(deleted_at(&db, "dependent").await, None);
+        assert!(child_of_exists(&db, "grandchild", "child").await);
+        assert!(depends_on_exists(&db, "dependent", "child").await);
+
+        // Deleting again needs --hard
+        let err = cmd.execute(&db).await.unwrap_err();
+        assert!(
+            err.to_string().contains("already in the trash"),
+            "got: {}",
+            err
+        );
+        let hard = DeleteCommand { hard: true, ..cmd };
+        assert_eq!(
+            hard.execute(&db).await.unwrap(),
+            "Deleted 3 tasks (including children)"
+        );
+        assert!(!task_exists(&db, "grandchild").await);
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_soft_delete_orphans_only_live_children() {
+        let (db, temp_dir) = setup_test_db().await;
+        create_task(&db, "parent", "Parent", "epic", "todo").await;
+        create_task(&db, "live", "Live", "task", "todo").await;
+        create_task(&db, "trashed", "Trashed", "task", "todo").await;
+        create_child_of(&db, "live", "parent").await;
+        create_child_of(&db, "trashed", "parent").await;
+
+        let delete = |id: &str| DeleteCommand {
+            id: id.to_string(),
+            cascade: false,
+            hard: false,
+            force: true,
+        };
+        assert_eq!(
+            delete("trashed").execute(&db).await.unwrap(),
+            "Moved task trashed to the trash (undo with `vtb restore trashed`)"
+        );
+        delete("parent").execute(&db).await.unwrap();
+
+        assert!(deleted_at(&db, "parent").await.is_some());
+        assert_eq!(deleted_at(&db, "live").await, None);
+        assert!(!child_of_exists(&db, "live", "parent").await);
+        assert!(child_of_exists(&db, "trashed", "parent").await);
+
+        cleanup(&temp_dir);
+    }
+
     #[tokio::test]
     async fn test_delete_simple_task_with_force() {
         let (db, temp_dir) = setup_test_db().await;
@@ -465,6 +613,7 @@ mod tests {
             id: "abc123".to_string(),
             cascade: false,
             force: true,
+            hard: true,
         };
 
         let result = cmd.execute(&db).await;
@@ -484,6 +633,7 @@ mod tests {
             id: "nonexistent".to_string(),
             cascade: false,
             force: true,
+            hard: true,
         };
 
         let result = cmd.execute(&db).await;
@@ -512,6 +662,7 @@ mod tests {
             id: "ABC123".to_string(), // Uppercase
             cascade: false,
             force: true,
+            hard: true,
         };
 
         let result = cmd.execute(&db).await;
@@ -554,6 +705,7 @@ mod tests {
             id: "parent1".to_string(),
             cascade: true,
             force: true,
+            hard: true,
         };
 
         let result = cmd.execute(&db).await;
@@ -600,6 +752,7 @@ mod tests {
             id: "parent1".to_string(),
             cascade: false,
             force: true,
+            hard: true,
         };
 
         let result = cmd.execute(&db).await;
@@ -635,6 +788,7 @@ mod tests {
             id: "blocker".to_string(),
             cascade: false,
             force: true,
+            hard: true,
         };
 
         let result = cmd.execute(&db).await;
@@ -669,6 +823,7 @@ mod tests {
             id: "main".to_string(),
             cascade: false,
             force: true,
+            hard: true,
         };
 
         let result = cmd.execute(&db).await;
@@ -700,6 +855,7 @@ mod tests {
             id: "child".to_string(),
             cascade: false,
             force: true,
+            hard: true,
         };
 
         let result = cmd.execute(&db).await;
@@ -733,6 +889,7 @@ mod tests {
             id: "root".to_string(),
             cascade: true,
             force: true,
+            hard: true,
         };
 
         let result = cmd.execute(&db).await;
@@ -780,6 +937,7 @@ mod tests {
             id: "info1".to_string(),
             cascade: false,
             force: true,
+            hard: true,
         };
 
         let info = cmd.fetch_task_info(&db, "info1").await;
@@ -804,6 +962,7 @@ mod tests {
             id: "parent".to_string(),
             cascade: false,
             force: true,
+            hard: true,
         };
 
         let children = cmd.fetch_children_ids(&db, "parent").await;
@@ -830,6 +989,7 @@ mod tests {
             id: "blocker".to_string(),
             cascade: false,
             force: true,
+            hard: true,
         };
 
         let dependents = cmd.fetch_blocked_tasks(&db, "blocker").await;
@@ -867,6 +1027,7 @@ mod tests {
             id: "parent".to_string(),
             cascade: true,
             force: true,
+            hard: true,
         };
 
         let descendants = cmd.collect_all_descendants(&db, "parent").await;
@@ -885,6 +1046,7 @@ mod tests {
             id: "test".to_string(),
             cascade: true,
             force: false,
+            hard: true,
         };
         let debug_str = format!("{:?}", cmd);
         assert!(
@@ -903,6 +1065,7 @@ mod tests {
             id: "lonely".to_string(),
             cascade: false,
             force: true,
+            hard: true,
         };
 
         let result = cmd.execute(&db).await;
@@ -928,6 +1091,7 @@ mod tests {
             id: "level1".to_string(),
             cascade: true,
             force: true,
+            hard: true,
         };
 
         let result = cmd.execute(&db).await;
@@ -952,6 +1116,7 @@ mod tests {
             id: "target".to_string(),
             cascade: false,
             force: true,
+            hard: true,
         };
 
         let result = cmd.execute(&db).await;
@@ -962,4 +1127,75 @@ mod tests {
 
         cleanup(&temp_dir);
     }
+
+    #[tokio::test]
+    async fn test_delete_without_force_requires_terminal() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        create_task(&db, "keep", "Keep Me", "task", "todo").await;
+
+        let cmd = DeleteCommand {
+            id: "keep".to_string(),
+            cascade: false,
+            force: false,
+            hard: true,
+        };
+
+        let result = cmd.execute_with(&db, Prompter::non_interactive()).await;
+        match result {
+            Err(DbError::ValidationError { message }) => {
+                assert!(message.contains("--yes"), "got: {}", message);
+            }
+            other => panic!("Expected ValidationError, got {:?}", other),
+        }
+        assert!(task_exists(&db, "keep").await);
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_cascade_without_force_requires_terminal() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        create_task(&db, "parent", "Parent", "ticket", "todo").await;
+        create_task(&db, "child", "Child", "task", "todo").await;
+        create_child_of(&db, "child", "parent").await;
+
+        let cmd = DeleteCommand {
+            id: "parent".to_string(),
+            cascade: true,
+            force: false,
+            hard: true,
+        };
+
+        let result = cmd.execute_with(&db, Prompter::non_interactive()).await;
+        assert!(matches!(result, Err(DbError::ValidationError { .. })));
+        assert!(task_exists(&db, "parent").await);
+        assert!(task_exists(&db, "child").await);
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_force_never_prompts() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        create_task(&db, "parent", "Parent", "ticket", "todo").await;
+        create_task(&db, "child", "Child", "task", "todo").await;
+        create_child_of(&db, "child", "parent").await;
+
+        let cmd = DeleteCommand {
+            id: "parent".to_string(),
+            cascade: true,
+            force: true,
+            hard: true,
+        };
+
+        let result = cmd.execute_with(&db, Prompter::non_interactive()).await;
+        assert!(result.is_ok());
+        assert!(!task_exists(&db, "parent").await);
+        assert!(!task_exists(&db, "child").await);
+
+        cleanup(&temp_dir);
+    }
 }
@@ -8,6 +8,7 @@
 //! - `get_cycle_path()` - Get the path forming the cycle for error messages
 //! - `format_cycle_path()` - Format the path as a human-readable string
 
+use crate::id::resolve_task_id;
 use clap::Args;
 use serde::Deserialize;
 use vertebrae_db::{Database, DbError, GraphQueries};
@@ -22,6 +23,10 @@ pub struct DependCommand {
     /// Task ID that this task depends on (the blocker)
     #[arg(long = "on", required = true)]
     pub blocker_id: String,
+
+    /// Report what would happen without creating the dependency
+    #[arg(long)]
+    pub dry_run: bool,
 }
 
 /// Result from querying a task's existence
@@ -47,10 +52,32 @@ pub struct DependResult {
     pub blocker_id: String,
     /// Whether the dependency already existed (idempotent)
     pub already_existed: bool,
+    /// Cycle the edge would form, formatted as a path (dry run only)
+    pub cycle: Option<String>,
+    /// Whether this was a dry run that made no changes
+    pub dry_run: bool,
 }
 
 impl std::fmt::Display for DependResult {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.dry_run {
+            write!(f, "Dry run: ")?;
+            if let Some(cycle) = &self.cycle {
+                return write!(
+                    f,
+                    "would reject {} -> {}, cycle detected: {}",
+                    self.task_id, self.blocker_id, cycle
+                );
+            }
+            if !self.already_existed {
+                return write!(
+                    f,
+                    "would create dependency: {} depends on {}",
+                    self.task_id, self.blocker_id
+                );
+            }
+        }
+
         if self.already_existed {
             write!(
                 f,
@@ -85,9 +112,9 @@ impl DependCommand {
     /// - Creating the dependency would form a cycle
     /// - Database operations fail
     pub async fn execute(&self, db: &Database) -> Result<DependResult, DbError> {
-        // Normalize IDs to lowercase for case-insensitive lookup
-        let task_id = self.id.to_lowercase();
-        let blocker_id = self.blocker_id.to_lowercase();
+        // Resolve case-insensitive and shortened IDs
+        let task_id = resolve_task_id(db, &self.id).await?;
+        let blocker_id = resolve_task_id(db, &self.blocker_id).await?;
 
         // Check for self-dependency
         if task_id == blocker_id {
@@ -99,44 +126,65 @@ impl DependCommand {
 
         // Validate both tasks exist
         if !self.task_exists(db, &task_id).await? {
-            return Err(DbError::InvalidPath {
-                path: std::path::PathBuf::from(&self.id),
-                reason: format!("Task '{}' not found", self.id),
+            return Err(DbError::NotFound {
+                task_id: self.id.clone(),
             });
         }
 
         if !self.task_exists(db, &blocker_id).await? {
-            return Err(DbError::InvalidPath {
-                path: std::path::PathBuf::from(&self.blocker_id),
-                reason: format!("Task '{}' not found", self.blocker_id),
+            return Err(DbError::NotFound {
+                task_id: self.blocker_id.clone(),
             });
         }
 
         // Check if dependency already exists (idempotent)
         if self.dependency_exists(db, &task_id, &blocker_id).await? {
             // Update timestamp even for existing dependency
-            self.update_timestamp(db, &task_id).await?;
+            if !self.dry_run {
+                self.update_timestamp(db, &task_id).await?;
+            }
             return Ok(DependResult {
                 task_id,
                 blocker_id,
                 already_existed: true,
+                cycle: None,
+                dry_run: self.dry_run,
             });
         }
 
         // Check for cycles using GraphQueries from the db crate
-        let graph = GraphQueries::new(db.client());
+        let graph = db.graph();
         if graph.would_create_cycle(&task_id, &blocker_id).await? {
             // Get the cycle path for a helpful error message
             let cycle_path = match graph.get_cycle_path(&task_id, &blocker_id).await? {
                 Some(path) => GraphQueries::format_cycle_path(&path),
                 None => format!("{} -> {}", blocker_id, task_id),
             };
+            if self.dry_run {
+                return Ok(DependResult {
+                    task_id,
+                    blocker_id,
+                    already_existed: false,
+                    cycle: Some(cycle_path),
+                    dry_run: true,
+                });
+            }
             return Err(DbError::InvalidPath {
                 path: std::path::PathBuf::from(&self.id),
                 reason: format!("Cycle detected: {}", cycle_path),
             });
         }
 
+        if self.dry_run {
+            return Ok(DependResult {
+                task_id,
+                blocker_id,
+                already_existed: false,
+                cycle: None,
+                dry_run: true,
+            });
+        }
+
         // Create the dependency edge
         self.create_dependency_edge(db, &task_id, &blocker_id)
             .await?;
@@ -148,13 +196,15 @@ impl DependCommand {
             task_id,
             blocker_id,
             already_existed: false,
+            cycle: None,
+            dry_run: false,
         })
     }
 
     /// Check if a task with the given ID exists.
     async fn task_exists(&self, db: &Database, id: &str) -> Result<bool, DbError> {
         let query = format!("SELECT id FROM task:{} LIMIT 1", id);
-        let mut result = db.client().query(&query).await?;
+        let mut result = db.query(&query).await?;
         let tasks: Vec<TaskExistsRow> = result.take(0)?;
         Ok(!tasks.is_empty())
     }
@@ -170,7 +220,7 @@ impl DependCommand {
             "SELECT id FROM depends_on WHERE in = task:{} AND out = task:{}",
             task_id, blocker_id
         );
-        let mut result = db.client().query(&query).await?;
+        let mut result = db.query(&query).await?;
         let edges: Vec<DependencyEdge> = result.take(0)?;
         Ok(!edges.is_empty())
     }
@@ -186,14 +236,14 @@ impl DependCommand {
             "RELATE task:{} -> depends_on -> task:{}",
             task_id, blocker_id
         );
-        db.client().query(&query).await?;
+        db.query(&query).await?;
         Ok(())
     }
 
     /// Update the updated_at timestamp for a task.
     async fn update_timestamp(&self, db: &Database, id: &str) -> Result<(), DbError> {
         let query = format!("UPDATE task:{} SET updated_at = time::now()", id);
-        db.client().query(&query).await?;
+        db.query(&query).await?;
         Ok(())
     }
 }
@@ -283,6 +333,7 @@ mod tests {
         let cmd = DependCommand {
             id: "taskb".to_string(),
             blocker_id: "taska".to_string(),
+            dry_run: false,
         };
 
         let result = cmd.execute(&db).await;
@@ -309,6 +360,7 @@ mod tests {
         let cmd = DependCommand {
             id: "taskb".to_string(),
             blocker_id: "taska".to_string(),
+            dry_run: false,
         };
 
         cmd.execute(&db).await.unwrap();
@@ -329,6 +381,7 @@ mod tests {
         let cmd = DependCommand {
             id: "taskb".to_string(),
             blocker_id: "taska".to_string(),
+            dry_run: false,
         };
 
         // Create dependency first time
@@ -353,6 +406,7 @@ mod tests {
         let cmd = DependCommand {
             id: "taska".to_string(),
             blocker_id: "taska".to_string(),
+            dry_run: false,
         };
 
         let result = cmd.execute(&db).await;
@@ -382,6 +436,7 @@ mod tests {
         let cmd1 = DependCommand {
             id: "taska".to_string(),
             blocker_id: "taskb".to_string(),
+            dry_run: false,
         };
         cmd1.execute(&db).await.unwrap();
 
@@ -389,6 +444,7 @@ mod tests {
         let cmd2 = DependCommand {
             id: "taskb".to_string(),
             blocker_id: "taska".to_string(),
+            dry_run: false,
         };
 
         let result = cmd2.execute(&db).await;
@@ -425,6 +481,7 @@ mod tests {
         let cmd1 = DependCommand {
             id: "taska".to_string(),
             blocker_id: "taskb".to_string(),
+            dry_run: false,
         };
         cmd1.execute(&db).await.unwrap();
 
@@ -432,6 +489,7 @@ mod tests {
         let cmd2 = DependCommand {
             id: "taskb".to_string(),
             blocker_id: "taskc".to_string(),
+            dry_run: false,
         };
         cmd2.execute(&db).await.unwrap();
 
@@ -439,6 +497,7 @@ mod tests {
         let cmd3 = DependCommand {
             id: "taskc".to_string(),
             blocker_id: "taska".to_string(),
+            dry_run: false,
         };
 
         let result = cmd3.execute(&db).await;
@@ -474,23 +533,15 @@ mod tests {
         let cmd = DependCommand {
             id: "taska".to_string(),
             blocker_id: "nonexistent".to_string(),
+            dry_run: false,
         };
 
         let result = cmd.execute(&db).await;
         match result {
-            Err(DbError::InvalidPath { reason, .. }) => {
-                assert!(
-                    reason.contains("not found"),
-                    "Expected 'not found' in error, got: {}",
-                    reason
-                );
-                assert!(
-                    reason.contains("nonexistent"),
-                    "Expected task ID 'nonexistent' in error, got: {}",
-                    reason
-                );
+            Err(DbError::NotFound { task_id }) => {
+                assert_eq!(task_id, "nonexistent");
             }
-            Err(other) => panic!("Expected InvalidPath error, got {:?}", other),
+            Err(other) => panic!("Expected NotFound error, got {:?}", other),
             Ok(_) => panic!("Expected error, got success"),
         }
 
@@ -506,23 +557,15 @@ mod tests {
         let cmd = DependCommand {
             id: "nonexistent".to_string(),
             blocker_id: "taska".to_string(),
+            dry_run: false,
         };
 
         let result = cmd.execute(&db).await;
         match result {
-            Err(DbError::InvalidPath { reason, .. }) => {
-                assert!(
-                    reason.contains("not found"),
-                    "Expected 'not found' in error, got: {}",
-                    reason
-                );
-                assert!(
-                    reason.contains("nonexistent"),
-                    "Expected task ID 'nonexistent' in error, got: {}",
-                    reason
-                );
+            Err(DbError::NotFound { task_id }) => {
+                assert_eq!(task_id, "nonexistent");
             }
-            Err(other) => panic!("Expected InvalidPath error, got {:?}", other),
+            Err(other) => panic!("Expected NotFound error, got {:?}", other),
             Ok(_) => panic!("Expected error, got success"),
         }
 
@@ -539,6 +582,7 @@ mod tests {
         let cmd = DependCommand {
             id: "TASKB".to_string(),         // Uppercase
             blocker_id: "TASKA".to_string(), // Uppercase
+            dry_run: false,
         };
 
         let result = cmd.execute(&db).await;
@@ -562,6 +606,7 @@ mod tests {
         let cmd1 = DependCommand {
             id: "taskc".to_string(),
             blocker_id: "taska".to_string(),
+            dry_run: false,
         };
         cmd1.execute(&db).await.unwrap();
 
@@ -569,6 +614,7 @@ mod tests {
         let cmd2 = DependCommand {
             id: "taskc".to_string(),
             blocker_id: "taskb".to_string(),
+            dry_run: false,
         };
         let result = cmd2.execute(&db).await;
         assert!(result.is_ok());
@@ -594,6 +640,7 @@ mod tests {
         DependCommand {
             id: "taskb".to_string(),
             blocker_id: "taska".to_string(),
+            dry_run: false,
         }
         .execute(&db)
         .await
@@ -603,6 +650,7 @@ mod tests {
         DependCommand {
             id: "taskc".to_string(),
             blocker_id: "taska".to_string(),
+            dry_run: false,
         }
         .execute(&db)
         .await
@@ -612,6 +660,7 @@ mod tests {
         DependCommand {
             id: "taskd".to_string(),
             blocker_id: "taskb".to_string(),
+            dry_run: false,
         }
         .execute(&db)
         .await
@@ -621,6 +670,7 @@ mod tests {
         let result = DependCommand {
             id: "taskd".to_string(),
             blocker_id: "taskc".to_string(),
+            dry_run: false,
         }
         .execute(&db)
         .await;
@@ -668,6 +718,7 @@ mod tests {
             let result = DependCommand {
                 id: from.to_string(),
                 blocker_id: to.to_string(),
+                dry_run: false,
             }
             .execute(&db)
             .await;
@@ -683,6 +734,7 @@ mod tests {
         let result = DependCommand {
             id: "taska".to_string(),
             blocker_id: "taske".to_string(),
+            dry_run: false,
         }
         .execute(&db)
         .await;
@@ -723,6 +775,8 @@ mod tests {
             task_id: "taskb".to_string(),
             blocker_id: "taska".to_string(),
             already_existed: false,
+            cycle: None,
+            dry_run: false,
         };
 
         let output = format!("{}", result);
@@ -737,6 +791,8 @@ mod tests {
             task_id: "taskb".to_string(),
             blocker_id: "taska".to_string(),
             already_existed: true,
+            cycle: None,
+            dry_run: false,
         };
 
         let output = format!("{}", result);
@@ -748,6 +804,7 @@ mod tests {
         let cmd = DependCommand {
             id: "test123".to_string(),
             blocker_id: "blocker456".to_string(),
+            dry_run: false,
         };
         let debug_str = format!("{:?}", cmd);
         assert!(
@@ -768,6 +825,7 @@ mod tests {
         let cmd = DependCommand {
             id: "taskb".to_string(),
             blocker_id: "taska".to_string(),
+            dry_run: false,
         };
 
         // Create dependency
@@ -800,4 +858,78 @@ mod tests {
 
         cleanup(&temp_dir);
     }
+
+    #[tokio::test]
+    async fn test_dry_run_reports_cycle_without_change() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        create_task(&db, "taska", "Task A").await;
+        create_task(&db, "taskb", "Task B").await;
+        create_task(&db, "taskc", "Task C").await;
+
+        // taskb -> taska, taskc -> taskb; taska -> taskc would close the loop
+        for (id, blocker) in [("taskb", "taska"), ("taskc", "taskb")] {
+            DependCommand {
+                id: id.to_string(),
+                blocker_id: blocker.to_string(),
+                dry_run: false,
+            }
+            .execute(&db)
+            .await
+            .unwrap();
+        }
+
+        let cmd = DependCommand {
+            id: "taska".to_string(),
+            blocker_id: "taskc".to_string(),
+            dry_run: true,
+        };
+        let result = cmd.execute(&db).await.unwrap();
+
+        let cycle = result
+            .cycle
+            .as_deref()
+            .expect("cycle path should be reported");
+        for id in ["taska", "taskb", "taskc"] {
+            assert!(
+                cycle.contains(id),
+                "cycle '{}' should mention {}",
+                cycle,
+                id
+            );
+        }
+        assert!(
+            result
+                .to_string()
+                .starts_with("Dry run: would reject taska -> taskc")
+        );
+        assert!(!dependency_exists(&db, "taska", "taskc").await);
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_does_not_create_edge() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        create_task(&db, "taska", "Task A").await;
+        create_task(&db, "taskb", "Task B").await;
+
+        let cmd = DependCommand {
+            id: "taskb".to_string(),
+            blocker_id: "taska".to_string(),
+            dry_run: true,
+        };
+        let result = cmd.execute(&db).await.unwrap();
+
+        assert!(result.dry_run);
+        assert!(result.cycle.is_none());
+        assert_eq!(
+            result.to_string(),
+            "Dry run: would create dependency: taskb depends on taska"
+        );
+        assert!(!dependency_exists(&db, "taskb", "taska").await);
+
+        cleanup(&temp_dir);
+    }
 }
@@ -0,0 +1,213 @@
+//! Doctor command for finding data problems
+//!
+//! Implements the `vtb doctor` command, which checks the database for
+//! inconsistencies the other commands refuse to create but raw edits or
+//! older versions may have left behind, and suggests how to fix each one.
+//! It also flags tasks that look like accidental copies of each other.
+
+use clap::Args;
+use vertebrae_db::{Database, DbError, DuplicateTitle, SelfEdge};
+
+/// Check the database for inconsistencies
+#[derive(Debug, Args)]
+pub struct DoctorCommand {}
+
+/// Result of the doctor command execution
+#[derive(Debug)]
+pub struct DoctorResult {
+    /// Edges that point from a task to itself
+    pub self_edges: Vec<SelfEdge>,
+    /// Groups of tasks whose titles match ignoring case and whitespace
+    pub duplicate_titles: Vec<DuplicateTitle>,
+}
+
+impl DoctorResult {
+    /// Whether no problems were found
+    pub fn is_healthy(&self) -> bool {
+        self.self_edges.is_empty() && self.duplicate_titles.is_empty()
+    }
+}
+
+impl std::fmt::Display for DoctorResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_healthy() {
+            return write!(f, "No problems found.");
+        }
+
+        let count = self.self_edges.len() + self.duplicate_titles.len();
+        writeln!(
+            f,
+            "Found {} problem{}:",
+            count,
+            if count == 1 { "" } else { "s" }
+        )?;
+        let problems = self
+            .self_edges
+            .iter()
+            .map(describe_self_edge)
+            .chain(self.duplicate_titles.iter().map(describe_duplicate_title));
+        for (problem, fix) in problems {
+            writeln!(f, "  - {}", problem)?;
+            writeln!(f, "    fix: {}", fix)?;
+        }
+        Ok(())
+    }
+}
+
+/// Describe a self-edge and the command that removes it.
+fn describe_self_edge(edge: &SelfEdge) -> (String, String) {
+    let id = &edge.task_id;
+    match edge.relation {
+        "child_of" => (
+            format!("task {} is its own parent (child_of)", id),
+            format!("vtb update {} --parent \"\"", id),
+        ),
+        _ => (
+            format!("task {} depends on itself ({})", id, edge.relation),
+            format!("vtb undepend {} {}", id, id),
+        ),
+    }
+}
+
+/// Describe a group of same-titled tasks and how to drop the copies.
+fn describe_duplicate_title(group: &DuplicateTitle) -> (String, String) {
+    let (keep, copies) = group
+        .task_ids
+        .split_first()
+        .expect("duplicate groups have at least two tasks");
+    (
+        format!(
+            "tasks {} share the title \"{}\"",
+            group.task_ids.join(", "),
+            group.title
+        ),
+        format!(
+            "merge them into {}, then {}",
+            keep,
+            copies
+                .iter()
+                .map(|id| format!("vtb delete {}", id))
+                .collect::<Vec<_>>()
+                .join("; ")
+        ),
+    )
+}
+
+impl DoctorCommand {
+    /// Execute the doctor command.
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - Reference to the database connection
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError` if database operations fail.
+    pub async fn execute(&self, db: &Database) -> Result<DoctorResult, DbError> {
+        let self_edges = db.relationships().find_self_edges().await?;
+        let duplicate_titles = db.tasks().find_duplicate_titles().await?;
+        Ok(DoctorResult {
+            self_edges,
+            duplicate_titles,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    /// Helper to create a test database
+    async fn setup_test_db() -> (Database, std::path::PathBuf) {
+        let temp_dir = env::temp_dir().join(format!(
+            "vtb-doctor-test-{}-{:?}-{}",
+            std::process::id(),
+            std::thread::current().id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+
+        let db = Database::connect(&temp_dir).await.unwrap();
+        db.init().await.unwrap();
+
+        (db, temp_dir)
+    }
+
+    /// Clean up test database
+    fn cleanup(path: &std::path::Path) {
+        let _ = std::fs::remove_dir_all(path);
+    }
+
+    #[tokio::test]
+    async fn test_doctor_healthy_database() {
+        let (db, temp_dir) = setup_test_db().await;
+        db.client()
+            .query(
+                r#"CREATE task:a SET title = "A", level = "task", status = "todo";
+                   CREATE task:b SET title = "B", level = "task", status = "todo";
+                   RELATE task:b -> depends_on -> task:a"#,
+            )
+            .await
+            .unwrap();
+
+        let result = DoctorCommand {}.execute(&db).await.unwrap();
+        assert!(result.is_healthy());
+        assert_eq!(result.to_string(), "No problems found.");
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_doctor_reports_self_edges() {
+        let (db, temp_dir) = setup_test_db().await;
+        db.client()
+            .query(
+                r#"CREATE task:a SET title = "A", level = "task", status = "todo";
+                   RELATE task:a -> child_of -> task:a;
+                   RELATE task:a -> depends_on -> task:a"#,
+            )
+            .await
+            .unwrap();
+
+        let result = DoctorCommand {}.execute(&db).await.unwrap();
+        assert!(!result.is_healthy());
+        assert_eq!(result.self_edges.len(), 2);
+
+        let output = result.to_string();
+        assert!(output.starts_with("Found 2 problems:"), "got:\n{}", output);
+        assert!(output.contains("task a is its own parent (child_of)"));
+        assert!(output.contains("fix: vtb update a --parent \"\""));
+        assert!(output.contains("task a depends on itself (depends_on)"));
+        assert!(output.contains("fix: vtb undepend a a"));
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_doctor_reports_duplicate_titles() {
+        let (db, temp_dir) = setup_test_db().await;
+        db.client()
+            .query(
+                r#"CREATE task:a SET title = "Write docs", level = "task", status = "todo";
+                   CREATE task:b SET title = "write   DOCS", level = "task", status = "done";
+                   CREATE task:c SET title = "Write docs site", level = "task", status = "todo""#,
+            )
+            .await
+            .unwrap();
+
+        let result = DoctorCommand {}.execute(&db).await.unwrap();
+        assert!(result.self_edges.is_empty());
+        assert_eq!(result.duplicate_titles.len(), 1);
+        assert_eq!(result.duplicate_titles[0].task_ids, vec!["a", "b"]);
+
+        let output = result.to_string();
+        assert!(output.starts_with("Found 1 problem:"), "got:\n{}", output);
+        assert!(output.contains("tasks a, b share the title \"Write docs\""));
+        assert!(output.contains("fix: merge them into a, then vtb delete b"));
+
+        cleanup(&temp_dir);
+    }
+}
@@ -1,22 +1,86 @@
-//! Export command for exporting database to JSONL format
+//! Export command for exporting the database as JSONL or CSV
 //!
-//! Implements the `vtb export` command to export all tasks and relations
+//! Implements the `vtb export` command to export tasks and relations
 //! to a JSONL (JSON Lines) file for backup or migration purposes.
+//! Filter flags narrow the export to a slice of the project, and `--compact`
+//! drops null and empty fields for smaller files. `--format csv` writes one
+//! row per task instead, for loading into a spreadsheet.
 
 use clap::Args;
 use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashSet;
 use std::io::Write;
 use std::path::PathBuf;
-use vertebrae_db::{Database, DbError, Task};
+use vertebrae_db::{
+    DEFAULT_PAGE_SIZE, Database, DbError, Level, Status, Task, TaskFilter, tag_settings,
+};
 
-/// Export database to JSONL format
+use crate::commands::list::{parse_level, parse_status};
+use crate::id::resolve_task_id;
+use crate::output::{OutputFormat, escape_csv_field};
+
+/// Export the database as JSONL, or tasks as CSV
 #[derive(Debug, Args)]
 pub struct ExportCommand {
     /// Output file path (defaults to stdout if not specified)
     #[arg(short, long)]
     pub output: Option<PathBuf>,
+
+    /// Only export tasks at this level (can be specified multiple times)
+    #[arg(short, long = "level", value_parser = parse_level)]
+    pub levels: Vec<Level>,
+
+    /// Only export tasks with this status (can be specified multiple times)
+    #[arg(short, long = "status", value_parser = parse_status)]
+    pub statuses: Vec<Status>,
+
+    /// Only export tasks with this tag (can be specified multiple times)
+    #[arg(short, long = "tag")]
+    pub tags: Vec<String>,
+
+    /// Only export tasks whose title or description contains this text
+    #[arg(long)]
+    pub search: Option<String>,
+
+    /// Only export this task and its descendants
+    #[arg(long, value_name = "ID")]
+    pub descendants: Option<String>,
+
+    /// Leave out null and empty fields instead of writing every field
+    #[arg(long)]
+    pub compact: bool,
+
+    /// Only write child_of and depends_on edges, for `vtb import --edges-only`
+    #[arg(long)]
+    pub edges_only: bool,
+
+    /// Output format: json (JSONL with tasks and relationships) or csv (tasks only)
+    #[arg(long, value_enum, default_value_t = OutputFormat::Json)]
+    pub format: OutputFormat,
 }
 
+/// Formats `vtb export` can write
+const EXPORT_FORMATS: [OutputFormat; 2] = [OutputFormat::Json, OutputFormat::Csv];
+
+/// Header row of a CSV export
+const EXPORT_CSV_HEADER: &str = "id,title,level,status,priority,tags,created_at,updated_at";
+
+/// Optional task fields written as `null` when unset, so every task record
+/// in a full export has the same keys
+const OPTIONAL_TASK_FIELDS: [&str; 10] = [
+    "description",
+    "priority",
+    "created_at",
+    "updated_at",
+    "started_at",
+    "completed_at",
+    "due_at",
+    "estimate_minutes",
+    "deleted_at",
+    "needs_human_review",
+];
+
 /// A record in the export file
 #[derive(Debug, Serialize)]
 #[serde(tag = "type")]
@@ -58,14 +122,19 @@ pub struct ExportResult {
     pub depends_on_relations: usize,
     /// Output destination
     pub destination: String,
+    /// Format the export was written in
+    pub format: OutputFormat,
 }
 
 impl std::fmt::Display for ExportResult {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "Export complete!")?;
         writeln!(f, "  Tasks: {}", self.tasks)?;
-        writeln!(f, "  Child relationships: {}", self.child_of_relations)?;
-        writeln!(f, "  Dependencies: {}", self.depends_on_relations)?;
+        // CSV exports carry no relationships
+        if self.format != OutputFormat::Csv {
+            writeln!(f, "  Child relationships: {}", self.child_of_relations)?;
+            writeln!(f, "  Dependencies: {}", self.depends_on_relations)?;
+        }
         write!(f, "  Output: {}", self.destination)
     }
 }
@@ -73,7 +142,9 @@ impl std::fmt::Display for ExportResult {
 impl ExportCommand {
     /// Execute the export command.
     ///
-    /// Exports all tasks and relationships to JSONL format.
+    /// Exports all tasks and relationships to JSONL format, or the tasks alone
+    /// to CSV. When filter flags are given, only matching tasks are exported,
+    /// along with the edges whose endpoints are both in the exported set.
     ///
     /// # Arguments
     ///
@@ -81,15 +152,34 @@ impl ExportCommand {
     ///
     /// # Errors
     ///
-    /// Returns `DbError` if database queries fail or file I/O fails.
+    /// Returns `DbError` if:
+    /// - The format is not json or csv, or `--compact` is combined with csv
+    /// - The `--descendants` task does not exist
+    /// - The search query is empty
+    /// - Database queries fail or file I/O fails
     pub async fn execute(&self, db: &Database) -> Result<ExportResult, DbError> {
+        self.check_format()?;
+        let selection = self.select_task_ids(db).await?;
+        if self.format == OutputFormat::Csv {
+            return self.export_csv(db, selection.as_ref()).await;
+        }
+        let in_scope = |id: &str| selection.as_ref().is_none_or(|ids| ids.contains(id));
+
         // Collect all records to export
         let mut records: Vec<ExportRecord> = Vec::new();
 
-        // Export all tasks using repository
-        let tasks = db.tasks().export_all().await?;
-        let task_count = tasks.len();
+        // Export tasks using repository
+        let tasks = if self.edges_only {
+            Vec::new()
+        } else {
+            db.tasks().export_all().await?
+        };
+        let mut task_count = 0;
         for (id, task) in tasks {
+            if !in_scope(&id) {
+                continue;
+            }
+            task_count += 1;
             records.push(ExportRecord::Task {
                 id,
                 task: Box::new(task),
@@ -98,15 +188,23 @@ impl ExportCommand {
 
         // Export child_of relationships using repository
         let child_of_relations = db.relationships().export_all_child_of().await?;
-        let child_of_count = child_of_relations.len();
+        let mut child_of_count = 0;
         for (child, parent) in child_of_relations {
+            if !in_scope(&child) || !in_scope(&parent) {
+                continue;
+            }
+            child_of_count += 1;
             records.push(ExportRecord::ChildOf { child, parent });
         }
 
         // Export depends_on relationships using repository
         let depends_on_relations = db.relationships().export_all_depends_on().await?;
-        let depends_on_count = depends_on_relations.len();
+        let mut depends_on_count = 0;
         for (task, blocker) in depends_on_relations {
+            if !in_scope(&task) || !in_scope(&blocker) {
+                continue;
+            }
+            depends_on_count += 1;
             records.push(ExportRecord::DependsOn { task, blocker });
         }
 
@@ -118,9 +216,169 @@ impl ExportCommand {
             child_of_relations: child_of_count,
             depends_on_relations: depends_on_count,
             destination,
+            format: self.format,
         })
     }
 
+    /// Check that the format is one export can write.
+    fn check_format(&self) -> Result<(), DbError> {
+        if !EXPORT_FORMATS.contains(&self.format) {
+            return Err(DbError::ValidationError {
+                message: format!(
+                    "--format {} is not supported by `export`; use one of: json, csv",
+                    self.format
+                ),
+            });
+        }
+        if self.format == OutputFormat::Csv {
+            let flag = if self.compact {
+                Some("--compact")
+            } else if self.edges_only {
+                Some("--edges-only")
+            } else {
+                None
+            };
+            if let Some(flag) = flag {
+                return Err(DbError::ValidationError {
+                    message: format!("{} is only supported with the json format", flag),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Write the selected tasks as CSV, one page of tasks at a time.
+    async fn export_csv(
+        &self,
+        db: &Database,
+        selection: Option<&HashSet<String>>,
+    ) -> Result<ExportResult, DbError> {
+        let (out, destination): (Box<dyn Write>, String) = match &self.output {
+            Some(path) => {
+                let file = std::fs::File::create(path).map_err(|e| DbError::InvalidPath {
+                    path: path.clone(),
+                    reason: e.to_string(),
+                })?;
+                (Box::new(file), path.display().to_string())
+            }
+            None => (Box::new(std::io::stdout().lock()), "stdout".to_string()),
+        };
+        let write_error = |source: std::io::Error| match &self.output {
+            Some(path) => DbError::InvalidPath {
+                path: path.clone(),
+                reason: source.to_string(),
+            },
+            None => DbError::Io {
+                stream: "stdout",
+                source,
+            },
+        };
+
+        let mut writer = std::io::BufWriter::new(out);
+        writeln!(writer, "{}", EXPORT_CSV_HEADER).map_err(write_error)?;
+
+        let mut task_count = 0;
+        let mut start = 0;
+        loop {
+            let page = db.tasks().export_page(start, DEFAULT_PAGE_SIZE).await?;
+            start += page.len();
+            let last_page = page.len() < DEFAULT_PAGE_SIZE;
+
+            for (id, task) in page {
+                if selection.is_some_and(|ids| !ids.contains(&id)) {
+                    continue;
+                }
+                writeln!(writer, "{}", task_csv_row(&id, &task)).map_err(write_error)?;
+                task_count += 1;
+            }
+
+            if last_page {
+                break;
+            }
+        }
+        writer.flush().map_err(write_error)?;
+
+        Ok(ExportResult {
+            tasks: task_count,
+            child_of_relations: 0,
+            depends_on_relations: 0,
+            destination,
+            format: self.format,
+        })
+    }
+
+    /// Whether any filter flag was given.
+    fn has_filters(&self) -> bool {
+        !self.levels.is_empty()
+            || !self.statuses.is_empty()
+            || !self.tags.is_empty()
+            || self.search.is_some()
+            || self.descendants.is_some()
+    }
+
+    /// Resolve the filter flags to the set of task IDs to export.
+    ///
+    /// Returns `None` when no filters are given, meaning everything is exported.
+    async fn select_task_ids(&self, db: &Database) -> Result<Option<HashSet<String>>, DbError> {
+        if !self.has_filters() {
+            return Ok(None);
+        }
+
+        if let Some(ref search) = self.search
+            && search.trim().is_empty()
+        {
+            return Err(DbError::ValidationError {
+                message: "Search query cannot be empty".to_string(),
+            });
+        }
+
+        // Done tasks are part of the project history, so always include them
+        let mut filter = TaskFilter::new()
+            .with_levels(self.levels.clone())
+            .with_statuses(self.statuses.clone())
+            .with_tags(tag_settings().apply_all(&self.tags))
+            .include_done();
+        if let Some(ref search) = self.search {
+            filter = filter.with_search(search.clone());
+        }
+
+        let mut ids: HashSet<String> = db
+            .list_tasks()
+            .list(&filter)
+            .await?
+            .into_iter()
+            .map(|task| task.id)
+            .collect();
+
+        if let Some(ref root) = self.descendants {
+            let root = resolve_task_id(db, root).await?;
+
+            let mut subtree: HashSet<String> = db
+                .graph()
+                .get_all_descendants(&root)
+                .await?
+                .into_iter()
+                .collect();
+            subtree.insert(root);
+            ids.retain(|id| subtree.contains(id));
+        }
+
+        Ok(Some(ids))
+    }
+
+    /// Serialize one record as a JSON line, in full or compact form.
+    fn record_json(&self, record: &ExportRecord) -> serde_json::Result<String> {
+        let mut value = serde_json::to_value(record)?;
+        if self.compact {
+            prune_empty(&mut value);
+        } else if let (ExportRecord::Task { .. }, Some(fields)) = (record, value.as_object_mut()) {
+            for field in OPTIONAL_TASK_FIELDS {
+                fields.entry(field).or_insert(Value::Null);
+            }
+        }
+        serde_json::to_string(&value)
+    }
+
     /// Write records to the output destination
     fn write_records(&self, records: &[ExportRecord]) -> Result<String, DbError> {
         match &self.output {
@@ -132,7 +390,7 @@ impl ExportCommand {
                 let mut writer = std::io::BufWriter::new(file);
 
                 for record in records {
-                    let json = serde_json::to_string(record).map_err(|e| DbError::InvalidPath {
+                    let json = self.record_json(record).map_err(|e| DbError::InvalidPath {
                         path: path.clone(),
                         reason: format!("JSON serialization error: {}", e),
                     })?;
@@ -147,9 +405,9 @@ impl ExportCommand {
             None => {
                 // Write to stdout
                 for record in records {
-                    let json = serde_json::to_string(record).map_err(|e| DbError::InvalidPath {
-                        path: PathBuf::from("<stdout>"),
-                        reason: format!("JSON serialization error: {}", e),
+                    let json = self.record_json(record).map_err(|e| DbError::Io {
+                        stream: "stdout",
+                        source: e.into(),
                     })?;
                     println!("{}", json);
                 }
@@ -159,9 +417,447 @@ impl ExportCommand {
     }
 }
 
+/// Format a task as a CSV row (fields in `EXPORT_CSV_HEADER` order).
+fn task_csv_row(id: &str, task: &Task) -> String {
+    let timestamp = |ts: Option<chrono::DateTime<chrono::Utc>>| {
+        ts.map(|ts| ts.to_rfc3339()).unwrap_or_default()
+    };
+    let fields = [
+        id.to_string(),
+        task.title.clone(),
+        task.level.as_str().to_string(),
+        task.status.as_str().to_string(),
+        task.priority
+            .as_ref()
+            .map(|p| p.as_str().to_string())
+            .unwrap_or_default(),
+        task.tags.join(";"),
+        timestamp(task.created_at),
+        timestamp(task.updated_at),
+    ];
+
+    fields
+        .iter()
+        .map(|f| escape_csv_field(f))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Remove null, empty-array and empty-object fields, recursively.
+fn prune_empty(value: &mut Value) {
+    match value {
+        Value::Object(fields) => {
+            fields.values_mut().for_each(prune_empty);
+            fields.retain(|_, v| !is_empty(v));
+        }
+        Value::Array(items) => items.iter_mut().for_each(prune_empty),
+        _ => {}
+    }
+}
+
+/// Whether a field carries no information.
+fn is_empty(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::Array(items) => items.is_empty(),
+        Value::Object(fields) => fields.is_empty(),
+        _ => false,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::env;
+
+    /// Helper to create a test database
+    async fn setup_test_db() -> (Database, PathBuf) {
+        let temp_dir = env::temp_dir().join(format!(
+            "vtb-export-test-{}-{:?}-{}",
+            std::process::id(),
+            std::thread::current().id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+
+        let db = Database::connect(&temp_dir).await.unwrap();
+        db.init().await.unwrap();
+
+        (db, temp_dir)
+    }
+
+    /// Helper to create a task with tags, optionally under a parent
+    async fn create_task(
+        db: &Database,
+        id: &str,
+        level: Level,
+        tags: &[&str],
+        parent: Option<&str>,
+    ) {
+        let mut task = Task::new(id, level);
+        for tag in tags {
+            task = task.with_tag(*tag);
+        }
+        db.tasks().create(id, &task).await.unwrap();
+
+        if let Some(parent) = parent {
+            let query = format!("RELATE task:{} -> child_of -> task:{}", id, parent);
+            db.client().query(&query).await.unwrap();
+        }
+    }
+
+    /// Helper to create a depends_on relationship (dependent -> blocker)
+    async fn create_depends_on(db: &Database, task_id: &str, blocker_id: &str) {
+        let query = format!(
+            "RELATE task:{} -> depends_on -> task:{}",
+            task_id, blocker_id
+        );
+        db.client().query(&query).await.unwrap();
+    }
+
+    /// Build an export command writing to `output` with no filters
+    fn export_to(output: &std::path::Path) -> ExportCommand {
+        ExportCommand {
+            output: Some(output.to_path_buf()),
+            levels: vec![],
+            statuses: vec![],
+            tags: vec![],
+            search: None,
+            descendants: None,
+            compact: false,
+            edges_only: false,
+            format: OutputFormat::Json,
+        }
+    }
+
+    /// Read back the exported records as JSON values
+    fn read_records(path: &std::path::Path) -> Vec<serde_json::Value> {
+        std::fs::read_to_string(path)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    /// IDs of the exported task records, sorted
+    fn task_ids(records: &[serde_json::Value]) -> Vec<String> {
+        let mut ids: Vec<String> = records
+            .iter()
+            .filter(|r| r["type"] == "task")
+            .map(|r| r["id"].as_str().unwrap().to_string())
+            .collect();
+        ids.sort();
+        ids
+    }
+
+    /// Two epics with children and a dependency crossing between them
+    async fn setup_two_epics(db: &Database) {
+        create_task(db, "epic1", Level::Epic, &["shared"], None).await;
+        create_task(db, "ticket1", Level::Ticket, &[], Some("epic1")).await;
+        create_task(db, "task1", Level::Task, &["backend"], Some("ticket1")).await;
+        create_task(db, "epic2", Level::Epic, &[], None).await;
+        create_task(db, "task2", Level::Task, &["backend"], Some("epic2")).await;
+
+        create_depends_on(db, "task1", "ticket1").await;
+        // Crosses out of epic1's subtree
+        create_depends_on(db, "task1", "task2").await;
+    }
+
+    #[tokio::test]
+    async fn test_export_descendants_excludes_out_of_scope_tasks_and_edges() {
+        let (db, temp_dir) = setup_test_db().await;
+        setup_two_epics(&db).await;
+
+        let output = temp_dir.join("epic1.jsonl");
+        let mut cmd = export_to(&output);
+        cmd.descendants = Some("EPIC1".to_string());
+        let result = cmd.execute(&db).await.unwrap();
+
+        let records = read_records(&output);
+        assert_eq!(task_ids(&records), vec!["epic1", "task1", "ticket1"]);
+        assert_eq!(result.tasks, 3);
+        assert_eq!(result.child_of_relations, 2);
+        // task1 -> task2 would dangle, so only task1 -> ticket1 remains
+        assert_eq!(result.depends_on_relations, 1);
+        assert!(records.iter().all(|r| r["blocker"] != "task2"));
+        assert!(records.iter().all(|r| r["parent"] != "epic2"));
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_export_filters_by_tag_and_level() {
+        let (db, temp_dir) = setup_test_db().await;
+        setup_two_epics(&db).await;
+
+        let output = temp_dir.join("backend.jsonl");
+        let mut cmd = export_to(&output);
+        cmd.tags = vec!["backend".to_string()];
+        let result = cmd.execute(&db).await.unwrap();
+
+        let records = read_records(&output);
+        assert_eq!(task_ids(&records), vec!["task1", "task2"]);
+        // Parents aren't selected, so their child_of edges are dropped
+        assert_eq!(result.child_of_relations, 0);
+        assert_eq!(result.depends_on_relations, 1);
+
+        let output = temp_dir.join("epics.jsonl");
+        let mut cmd = export_to(&output);
+        cmd.levels = vec![Level::Epic];
+        cmd.execute(&db).await.unwrap();
+        assert_eq!(task_ids(&read_records(&output)), vec!["epic1", "epic2"]);
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_export_filters_combine_with_descendants() {
+        let (db, temp_dir) = setup_test_db().await;
+        setup_two_epics(&db).await;
+
+        let output = temp_dir.join("slice.jsonl");
+        let mut cmd = export_to(&output);
+        cmd.tags = vec!["backend".to_string()];
+        cmd.descendants = Some("epic2".to_string());
+        cmd.execute(&db).await.unwrap();
+
+        assert_eq!(task_ids(&read_records(&output)), vec!["task2"]);
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_export_without_filters_includes_done_and_everything() {
+        let (db, temp_dir) = setup_test_db().await;
+        setup_two_epics(&db).await;
+        db.client()
+            .query("UPDATE task:task2 SET status = 'done'")
+            .await
+            .unwrap();
+
+        let output = temp_dir.join("all.jsonl");
+        let result = export_to(&output).execute(&db).await.unwrap();
+
+        assert_eq!(result.tasks, 5);
+        assert_eq!(result.child_of_relations, 3);
+        assert_eq!(result.depends_on_relations, 2);
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_export_compact_omits_null_fields() {
+        let (db, temp_dir) = setup_test_db().await;
+        create_task(&db, "plain", Level::Task, &[], None).await;
+
+        let output = temp_dir.join("full.jsonl");
+        export_to(&output).execute(&db).await.unwrap();
+        let full = read_records(&output);
+        assert!(full[0]["priority"].is_null());
+        assert!(full[0].as_object().unwrap().contains_key("priority"));
+        assert_eq!(full[0]["tags"], serde_json::json!([]));
+
+        let output = temp_dir.join("compact.jsonl");
+        let mut cmd = export_to(&output);
+        cmd.compact = true;
+        cmd.execute(&db).await.unwrap();
+        let compact = read_records(&output);
+        let fields = compact[0].as_object().unwrap();
+        assert!(!fields.contains_key("priority"));
+        assert!(!fields.contains_key("tags"));
+        assert_eq!(fields["id"], "plain");
+        assert_eq!(fields["title"], "plain");
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_export_csv_rows_and_quoting() {
+        let (db, temp_dir) = setup_test_db().await;
+        db.client()
+            .query(
+                r#"CREATE task:quoted SET title = 'Fix "this", now', level = "task",
+                   status = "todo", priority = "high", tags = ["a", "b"]"#,
+            )
+            .await
+            .unwrap();
+        create_task(&db, "plain", Level::Epic, &[], None).await;
+
+        let output = temp_dir.join("tasks.csv");
+        let mut cmd = export_to(&output);
+        cmd.format = OutputFormat::Csv;
+        let result = cmd.execute(&db).await.unwrap();
+        assert_eq!(result.tasks, 2);
+        assert!(!result.to_string().contains("Dependencies"));
+
+        let contents = std::fs::read_to_string(&output).unwrap();
+        let lines: Vec<&str> = contents.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], EXPORT_CSV_HEADER);
+        assert!(
+            lines[1].starts_with("plain,plain,epic,todo,,,"),
+            "got: {}",
+            lines[1]
+        );
+        assert!(
+            lines[2].starts_with("quoted,\"Fix \"\"this\"\", now\",task,todo,high,a;b,"),
+            "got: {}",
+            lines[2]
+        );
+        // created_at and updated_at are filled in by the schema
+        let created_at = lines[2].split(',').nth(7).unwrap();
+        assert!(
+            chrono::DateTime::parse_from_rfc3339(created_at).is_ok(),
+            "got: {}",
+            created_at
+        );
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_export_csv_honors_filters() {
+        let (db, temp_dir) = setup_test_db().await;
+        setup_two_epics(&db).await;
+
+        let output = temp_dir.join("backend.csv");
+        let mut cmd = export_to(&output);
+        cmd.format = OutputFormat::Csv;
+        cmd.tags = vec!["backend".to_string()];
+        let result = cmd.execute(&db).await.unwrap();
+        assert_eq!(result.tasks, 2);
+
+        let contents = std::fs::read_to_string(&output).unwrap();
+        let ids: Vec<&str> = contents
+            .lines()
+            .skip(1)
+            .map(|line| line.split(',').next().unwrap())
+            .collect();
+        assert_eq!(ids, vec!["task1", "task2"]);
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_export_edges_only_writes_scoped_edges() {
+        let (db, temp_dir) = setup_test_db().await;
+        setup_two_epics(&db).await;
+        let output = temp_dir.join("edges.jsonl");
+
+        let mut cmd = export_to(&output);
+        cmd.edges_only = true;
+        cmd.descendants = Some("epic1".to_string());
+        let result = cmd.execute(&db).await.unwrap();
+
+        assert_eq!(result.tasks, 0);
+        assert_eq!(result.child_of_relations, 2);
+        assert_eq!(result.depends_on_relations, 1);
+        let records = read_records(&output);
+        assert_eq!(records.len(), 3);
+        assert!(task_ids(&records).is_empty());
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_export_rejects_unsupported_format_options() {
+        let (db, temp_dir) = setup_test_db().await;
+        let output = temp_dir.join("out");
+
+        let mut cmd = export_to(&output);
+        cmd.format = OutputFormat::Yaml;
+        match cmd.execute(&db).await {
+            Err(DbError::ValidationError { message }) => assert_eq!(
+                message,
+                "--format yaml is not supported by `export`; use one of: json, csv"
+            ),
+            other => panic!("Expected ValidationError, got {:?}", other.map(|r| r.tasks)),
+        }
+
+        cmd.format = OutputFormat::Csv;
+        cmd.compact = true;
+        assert!(matches!(
+            cmd.execute(&db).await,
+            Err(DbError::ValidationError { .. })
+        ));
+
+        cmd.compact = false;
+        cmd.edges_only = true;
+        match cmd.execute(&db).await {
+            Err(DbError::ValidationError { message }) => assert_eq!(
+                message,
+                "--edges-only is only supported with the json format"
+            ),
+            other => panic!("Expected ValidationError, got {:?}", other.map(|r| r.tasks)),
+        }
+        assert!(!output.exists());
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_prune_empty_is_recursive() {
+        let mut value = serde_json::json!({
+            "a": null,
+            "b": [],
+            "c": {"d": null},
+            "e": [{"f": null, "g": 1}],
+            "h": false,
+            "i": ""
+        });
+        prune_empty(&mut value);
+        assert_eq!(
+            value,
+            serde_json::json!({"e": [{"g": 1}], "h": false, "i": ""})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_export_filtered_includes_done_tasks() {
+        let (db, temp_dir) = setup_test_db().await;
+        setup_two_epics(&db).await;
+        db.client()
+            .query("UPDATE task:task2 SET status = 'done'")
+            .await
+            .unwrap();
+
+        let output = temp_dir.join("epic2.jsonl");
+        let mut cmd = export_to(&output);
+        cmd.descendants = Some("epic2".to_string());
+        cmd.execute(&db).await.unwrap();
+
+        assert_eq!(task_ids(&read_records(&output)), vec!["epic2", "task2"]);
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_export_descendants_not_found() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        let mut cmd = export_to(&temp_dir.join("missing.jsonl"));
+        cmd.descendants = Some("nope".to_string());
+        let result = cmd.execute(&db).await;
+        assert!(matches!(result, Err(DbError::NotFound { task_id }) if task_id == "nope"));
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_export_rejects_empty_search() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        let mut cmd = export_to(&temp_dir.join("search.jsonl"));
+        cmd.search = Some("  ".to_string());
+        assert!(matches!(
+            cmd.execute(&db).await,
+            Err(DbError::ValidationError { .. })
+        ));
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
 
     #[test]
     fn test_export_record_task_serialization() {
@@ -213,6 +909,7 @@ mod tests {
             child_of_relations: 5,
             depends_on_relations: 3,
             destination: "backup.jsonl".to_string(),
+            format: OutputFormat::Json,
         };
 
         let output = format!("{}", result);
@@ -227,6 +924,14 @@ mod tests {
     fn test_export_command_debug() {
         let cmd = ExportCommand {
             output: Some(PathBuf::from("test.jsonl")),
+            levels: vec![],
+            statuses: vec![],
+            tags: vec![],
+            search: None,
+            descendants: None,
+            compact: false,
+            format: OutputFormat::Json,
+            edges_only: false,
         };
         let debug_str = format!("{:?}", cmd);
         assert!(debug_str.contains("ExportCommand"));
@@ -0,0 +1,464 @@
+//! Graph command for exporting the dependency graph
+//!
+//! Implements the `vtb graph` command, which prints tasks and their
+//! `depends_on` edges (and, with `--hierarchy`, `child_of` edges) for
+//! drawing with Graphviz: `vtb graph | dot -Tsvg > tasks.svg`, or as a
+//! Mermaid diagram for Markdown with `--format mermaid`. `--cluster-by-epic`
+//! boxes each epic's tasks together in the DOT output.
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use vertebrae_db::{Database, DbError, IdFormatter, id_format};
+
+use crate::id::resolve_task_id;
+use crate::output::{OutputFormat, Render, to_dot, to_json, to_mermaid};
+
+/// Export the dependency graph as Graphviz DOT or Mermaid
+#[derive(Debug, Args)]
+pub struct GraphCommand {
+    /// Only include tasks reachable from this task (case-insensitive)
+    #[arg(long, value_name = "ID")]
+    pub root: Option<String>,
+
+    /// Also include parent/child edges
+    #[arg(long)]
+    pub hierarchy: bool,
+
+    /// Output format: dot, mermaid or json
+    #[arg(long, value_enum, default_value_t = OutputFormat::Dot)]
+    pub format: OutputFormat,
+
+    /// Draw a box around each epic's tasks (dot and json formats)
+    #[arg(long)]
+    pub cluster_by_epic: bool,
+}
+
+/// Kind of relationship an edge stands for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EdgeKind {
+    /// `from` depends on `to`
+    DependsOn,
+    /// `from` is a child of `to`
+    ChildOf,
+}
+
+/// A task in the graph
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GraphNode {
+    /// Task ID
+    pub id: String,
+    /// Task title
+    pub title: String,
+    /// Current status
+    pub status: String,
+}
+
+/// An edge between two tasks in the graph
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct GraphEdge {
+    /// Relationship the edge stands for
+    pub kind: EdgeKind,
+    /// Task the edge starts at
+    pub from: String,
+    /// Task the edge points to
+    pub to: String,
+}
+
+/// A group of tasks drawn together, for `--cluster-by-epic`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GraphCluster {
+    /// The epic the tasks belong to, or `None` for tasks outside any epic
+    pub epic: Option<String>,
+    /// Label drawn on the cluster
+    pub label: String,
+    /// IDs of the tasks in the cluster, ordered by ID
+    pub members: Vec<String>,
+}
+
+/// Result of the graph command execution
+#[derive(Debug, Serialize)]
+pub struct GraphResult {
+    /// Tasks, ordered by ID
+    pub nodes: Vec<GraphNode>,
+    /// Edges, ordered by kind and then by endpoints
+    pub edges: Vec<GraphEdge>,
+    /// Epic clusters, ordered by epic ID with tasks outside any epic last;
+    /// empty unless clustering was asked for
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub clusters: Vec<GraphCluster>,
+}
+
+/// Result from querying a task
+#[derive(Debug, Deserialize)]
+struct TaskRow {
+    id: surrealdb::sql::Thing,
+    title: String,
+    status: String,
+    level: String,
+}
+
+/// Result from querying an edge
+#[derive(Debug, Deserialize)]
+struct EdgeRow {
+    #[serde(rename = "in")]
+    from: surrealdb::sql::Thing,
+    #[serde(rename = "out")]
+    to: surrealdb::sql::Thing,
+}
+
+impl GraphResult {
+    /// Formatter that shortens node IDs in labels per the configured ID format
+    pub fn id_formatter(&self) -> IdFormatter {
+        IdFormatter::for_ids(id_format(), self.nodes.iter().map(|n| n.id.as_str()))
+    }
+}
+
+impl Render for GraphResult {
+    const COMMAND: &'static str = "graph";
+    const FORMATS: &'static [OutputFormat] =
+        &[OutputFormat::Dot, OutputFormat::Mermaid, OutputFormat::Json];
+
+    fn render_as(&self, format: OutputFormat) -> Result<String, DbError> {
+        match format {
+            OutputFormat::Json => to_json(self),
+            OutputFormat::Mermaid => Ok(to_mermaid(self)),
+            _ => Ok(to_dot(self, self.id_formatter())),
+        }
+    }
+}
+
+impl GraphCommand {
+    /// Execute the graph command.
+    ///
+    /// Without `--root`, every task that has at least one edge is included.
+    /// With it, the graph holds the root, everything it depends on directly
+    /// or not, and with `--hierarchy` its descendants too. Tasks in the
+    /// trash are left out.
+    ///
+    /// With `--cluster-by-epic`, each epic that has tasks in the graph gets
+    /// a cluster holding itself and its descendants; the remaining tasks go
+    /// in one last cluster.
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - Reference to the database connection
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError::NotFound` if the root does not exist,
+    /// `DbError::ValidationError` if `--cluster-by-epic` is combined with
+    /// Mermaid output, or another `DbError` if the format is unsupported or
+    /// a query fails.
+    pub async fn execute(&self, db: &Database) -> Result<GraphResult, DbError> {
+        GraphResult::check_format(self.format)?;
+        if self.cluster_by_epic && self.format == OutputFormat::Mermaid {
+            return Err(DbError::ValidationError {
+                message: "--cluster-by-epic needs --format dot or json".to_string(),
+            });
+        }
+
+        let mut result = db
+            .query(
+                "SELECT id, title, status, level FROM task WHERE deleted_at = NONE; \
+                 SELECT in, out FROM depends_on; \
+                 SELECT in, out FROM child_of",
+            )
+            .await?;
+        let tasks: Vec<TaskRow> = result.take(0)?;
+        let depends_on: Vec<EdgeRow> = result.take(1)?;
+        let child_of: Vec<EdgeRow> = if self.hierarchy {
+            result.take(2)?
+        } else {
+            Vec::new()
+        };
+
+        let mut epics: Vec<(String, String)> = tasks
+            .iter()
+            .filter(|row| row.level == "epic")
+            .map(|row| (row.id.id.to_string(), row.title.clone()))
+            .collect();
+        epics.sort();
+
+        let mut nodes: HashMap<String, GraphNode> = tasks
+            .into_iter()
+            .map(|row| {
+                let id = row.id.id.to_string();
+                let node = GraphNode {
+                    id: id.clone(),
+                    title: row.title,
+                    status: row.status,
+                };
+                (id, node)
+            })
+            .collect();
+
+        let mut edges: Vec<GraphEdge> = depends_on
+            .into_iter()
+            .map(|row| (EdgeKind::DependsOn, row))
+            .chain(child_of.into_iter().map(|row| (EdgeKind::ChildOf, row)))
+            .map(|(kind, row)| GraphEdge {
+                kind,
+                from: row.from.id.to_string(),
+                to: row.to.id.to_string(),
+            })
+            .filter(|edge| nodes.contains_key(&edge.from) && nodes.contains_key(&edge.to))
+            .collect();
+        edges.sort();
+        edges.dedup();
+
+        let keep: HashSet<String> = match &self.root {
+            Some(root) => {
+                let root = resolve_task_id(db, root).await?;
+                if !nodes.contains_key(&root) {
+                    return Err(DbError::NotFound { task_id: root });
+                }
+                reachable_from(&root, &edges)
+            }
+            None => edges
+                .iter()
+                .flat_map(|edge| [edge.from.clone(), edge.to.clone()])
+                .collect(),
+        };
+        edges.retain(|edge| keep.contains(&edge.from) && keep.contains(&edge.to));
+        nodes.retain(|id, _| keep.contains(id));
+
+        let mut nodes: Vec<GraphNode> = nodes.into_values().collect();
+        nodes.sort_by(|a, b| a.id.cmp(&b.id));
+
+        let clusters = if self.cluster_by_epic {
+            cluster_by_epic(db, &nodes, &epics).await?
+        } else {
+            Vec::new()
+        };
+
+        Ok(GraphResult {
+            nodes,
+            edges,
+            clusters,
+        })
+    }
+}
+
+/// Group `nodes` by the epic they belong to.
+///
+/// `epics` are `(id, title)` pairs in ID order. A task under more than one
+/// epic stays in the first one's cluster, and epics with no tasks in the
+/// graph get no cluster.
+async fn cluster_by_epic(
+    db: &Database,
+    nodes: &[GraphNode],
+    epics: &[(String, String)],
+) -> Result<Vec<GraphCluster>, DbError> {
+    let graph = db.graph();
+    let ids = IdFormatter::for_ids(id_format(), nodes.iter().map(|n| n.id.as_str()));
+    let mut unclustered: HashSet<&str> = nodes.iter().map(|n| n.id.as_str()).collect();
+    let mut clusters = Vec::new();
+
+    for (epic, title) in epics {
+        let descendants = graph.get_all_descendants(epic).await?;
+        let mut members: Vec<String> = std::iter::once(epic)
+            .chain(&descendants)
+            .filter(|id| unclustered.remove(id.as_str()))
+            .cloned()
+            .collect();
+        if members.is_empty() {
+            continue;
+        }
+        members.sort();
+        clusters.push(GraphCluster {
+            epic: Some(epic.clone()),
+            label: format!("{}: {}", ids.format(epic), title),
+            members,
+        });
+    }
+
+    if !unclustered.is_empty() {
+        let mut members: Vec<String> = unclustered.into_iter().map(str::to_string).collect();
+        members.sort();
+        clusters.push(GraphCluster {
+            epic: None,
+            label: "No epic".to_string(),
+            members,
+        });
+    }
+    Ok(clusters)
+}
+
+/// Tasks reachable from `root`: its blockers, their blockers and so on,
+/// plus its descendants when `edges` include `child_of`.
+fn reachable_from(root: &str, edges: &[GraphEdge]) -> HashSet<String> {
+    let mut next: HashMap<&str, Vec<&str>> = HashMap::new();
+    for edge in edges {
+        let (from, to) = match edge.kind {
+            EdgeKind::DependsOn => (&edge.from, &edge.to),
+            // Walk down from parents to children
+            EdgeKind::ChildOf => (&edge.to, &edge.from),
+        };
+        next.entry(from.as_str()).or_default().push(to.as_str());
+    }
+
+    let mut seen = HashSet::from([root.to_string()]);
+    let mut pending = vec![root];
+    while let Some(id) = pending.pop() {
+        for &to in next.get(id).into_iter().flatten() {
+            if seen.insert(to.to_string()) {
+                pending.push(to);
+            }
+        }
+    }
+    seen
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    async fn setup_test_db() -> (Database, std::path::PathBuf) {
+        let temp_dir = env::temp_dir().join(format!(
+            "vtb-graph-test-{}-{:?}-{}",
+            std::process::id(),
+            std::thread::current().id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        let db = Database::connect(&temp_dir).await.unwrap();
+        db.init().await.unwrap();
+        db.client()
+            .query(
+                r#"CREATE task:epic SET title = "Epic", level = "epic", status = "todo";
+                   CREATE task:api SET title = "API", level = "ticket", status = "in_progress";
+                   CREATE task:schema SET title = "Schema", level = "task", status = "done";
+                   CREATE task:ui SET title = "UI", level = "ticket", status = "todo";
+                   CREATE task:docs SET title = "Docs", level = "task", status = "todo";
+                   CREATE task:lone SET title = "Lone", level = "task", status = "todo";
+                   RELATE task:api -> depends_on -> task:schema;
+                   RELATE task:ui -> depends_on -> task:api;
+                   RELATE task:api -> child_of -> task:epic;
+                   RELATE task:docs -> child_of -> task:epic"#,
+            )
+            .await
+            .unwrap();
+        (db, temp_dir)
+    }
+
+    fn cleanup(path: &std::path::Path) {
+        let _ = std::fs::remove_dir_all(path);
+    }
+
+    fn graph(root: Option<&str>, hierarchy: bool) -> GraphCommand {
+        GraphCommand {
+            root: root.map(str::to_string),
+            hierarchy,
+            format: OutputFormat::Dot,
+            cluster_by_epic: false,
+        }
+    }
+
+    fn node_ids(result: &GraphResult) -> Vec<&str> {
+        result.nodes.iter().map(|n| n.id.as_str()).collect()
+    }
+
+    #[tokio::test]
+    async fn test_graph_includes_connected_tasks() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        let result = graph(None, false).execute(&db).await.unwrap();
+        assert_eq!(node_ids(&result), vec!["api", "schema", "ui"]);
+        assert_eq!(result.edges.len(), 2);
+        assert!(
+            result
+                .edges
+                .iter()
+                .all(|edge| edge.kind == EdgeKind::DependsOn)
+        );
+
+        let result = graph(None, true).execute(&db).await.unwrap();
+        assert_eq!(
+            node_ids(&result),
+            vec!["api", "docs", "epic", "schema", "ui"]
+        );
+        assert_eq!(result.edges.len(), 4);
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_graph_root_keeps_reachable_subgraph() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        let result = graph(Some("API"), false).execute(&db).await.unwrap();
+        assert_eq!(node_ids(&result), vec!["api", "schema"]);
+        assert_eq!(
+            result.edges,
+            vec![GraphEdge {
+                kind: EdgeKind::DependsOn,
+                from: "api".to_string(),
+                to: "schema".to_string(),
+            }]
+        );
+
+        // Down the hierarchy, then along dependencies
+        let result = graph(Some("epic"), true).execute(&db).await.unwrap();
+        assert_eq!(node_ids(&result), vec!["api", "docs", "epic", "schema"]);
+
+        let err = graph(Some("nope"), false).execute(&db).await.unwrap_err();
+        assert!(matches!(err, DbError::NotFound { .. }));
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_graph_cluster_by_epic_boxes_each_epic() {
+        let (db, temp_dir) = setup_test_db().await;
+        db.client()
+            .query(
+                r#"CREATE task:mobile SET title = "Mobile", level = "epic", status = "todo";
+                   CREATE task:app SET title = "App", level = "ticket", status = "todo";
+                   RELATE task:app -> child_of -> task:mobile;
+                   RELATE task:app -> depends_on -> task:ui"#,
+            )
+            .await
+            .unwrap();
+
+        let mut cmd = graph(None, true);
+        cmd.cluster_by_epic = true;
+        let result = cmd.execute(&db).await.unwrap();
+        let members: Vec<(Option<&str>, Vec<&str>)> = result
+            .clusters
+            .iter()
+            .map(|c| {
+                let members = c.members.iter().map(String::as_str).collect();
+                (c.epic.as_deref(), members)
+            })
+            .collect();
+        assert_eq!(
+            members,
+            vec![
+                (Some("epic"), vec!["api", "docs", "epic"]),
+                (Some("mobile"), vec!["app", "mobile"]),
+                (None, vec!["schema", "ui"]),
+            ]
+        );
+
+        let dot = result.render(OutputFormat::Dot).unwrap();
+        assert_eq!(dot.matches("subgraph cluster_").count(), 3);
+        let mobile = dot.split("subgraph cluster_mobile {").nth(1).unwrap();
+        let mobile = mobile.split("    }").next().unwrap();
+        assert!(mobile.contains("label=\"mobile: Mobile\""), "dot: {}", dot);
+        assert!(mobile.contains("\"app\" [label="), "dot: {}", dot);
+        assert!(!mobile.contains("\"api\" [label="), "dot: {}", dot);
+
+        cmd.format = OutputFormat::Mermaid;
+        assert!(matches!(
+            cmd.execute(&db).await.unwrap_err(),
+            DbError::ValidationError { .. }
+        ));
+
+        cleanup(&temp_dir);
+    }
+}
@@ -1,15 +1,28 @@
-//! Import command for importing database from JSONL format
+//! Import command for importing a JSONL export or a batch of new tasks
 //!
 //! Implements the `vtb import` command to import tasks and relations
 //! from a JSONL (JSON Lines) file for restoration or migration purposes.
+//! Large imports report progress on stderr while they run; stdout only gets
+//! the final summary.
+//!
+//! With `--batch` the input is instead a JSON array, or JSON lines, of plain
+//! task objects to create, which may refer to each other (or to existing
+//! tasks) through `child_of` and `depends_on`.
 
 use clap::Args;
 use serde::Deserialize;
-use std::io::BufRead;
+use serde_json::value::RawValue;
+use std::collections::HashSet;
+use std::io::{self, BufRead, IsTerminal, Read, Write};
 use std::path::{Path, PathBuf};
-use vertebrae_db::{Database, DbError, Task};
+use vertebrae_db::{
+    BulkTask, Database, DbError, GraphQueries, IdGenerator, Level, PendingEdges, Priority, Status,
+    Task, tag_settings,
+};
+
+use crate::confirm::Prompter;
 
-/// Import database from JSONL format
+/// Import a JSONL export, or a JSON array of new tasks with --batch
 #[derive(Debug, Args)]
 pub struct ImportCommand {
     /// Input file path (reads from stdin if not specified)
@@ -19,6 +32,67 @@ pub struct ImportCommand {
     /// Skip tasks that already exist (by ID)
     #[arg(long, default_value = "false")]
     pub skip_existing: bool,
+
+    /// Overwrite existing tasks without asking (required when stdin is not a terminal)
+    #[arg(short, long, visible_aliases = ["yes", "assume-yes"], short_alias = 'y')]
+    pub force: bool,
+
+    /// Don't report progress on stderr
+    #[arg(short, long)]
+    pub quiet: bool,
+
+    /// Only apply child_of and depends_on edges between existing tasks;
+    /// task records are ignored and edges already present are skipped
+    #[arg(long, conflicts_with = "skip_existing")]
+    pub edges_only: bool,
+
+    /// Create new tasks from a JSON array or JSON lines of task objects;
+    /// nothing is created if any row is invalid
+    #[arg(long, conflicts_with_all = ["skip_existing", "edges_only"])]
+    pub batch: bool,
+}
+
+/// Records processed between progress lines
+const PROGRESS_INTERVAL: usize = 1000;
+
+/// Progress lines written while an import runs
+struct ImportProgress<'a> {
+    out: Option<&'a mut dyn Write>,
+    every: usize,
+}
+
+impl<'a> ImportProgress<'a> {
+    /// Report to `out` every `every` records, or not at all if `out` is `None`.
+    fn new(out: Option<&'a mut dyn Write>, every: usize) -> Self {
+        Self {
+            out,
+            every: every.max(1),
+        }
+    }
+
+    /// Report task progress after each `every`-th task record.
+    fn tasks(&mut self, processed: usize, imported: usize, skipped: usize) {
+        if processed.is_multiple_of(self.every) {
+            self.line(format_args!(
+                "Tasks: {} processed ({} imported, {} skipped)",
+                processed, imported, skipped
+            ));
+        }
+    }
+
+    /// Report relationship progress after each `every`-th relationship.
+    fn relationships(&mut self, created: usize) {
+        if created.is_multiple_of(self.every) {
+            self.line(format_args!("Relationships: {} created", created));
+        }
+    }
+
+    fn line(&mut self, line: std::fmt::Arguments<'_>) {
+        // Progress is best effort; a closed stderr must not fail the import
+        if let Some(out) = self.out.as_mut() {
+            let _ = writeln!(out, "{}", line);
+        }
+    }
 }
 
 /// A record in the import file
@@ -52,6 +126,68 @@ pub enum ImportRecord {
     },
 }
 
+/// A task object in a `--batch` file
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct BatchRow {
+    /// Task ID to keep; generated from the title if missing
+    #[serde(default)]
+    id: Option<String>,
+    title: String,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    level: Option<Level>,
+    #[serde(default)]
+    status: Option<Status>,
+    #[serde(default)]
+    priority: Option<Priority>,
+    #[serde(default)]
+    tags: Vec<String>,
+    /// Parent task, from this file or the database
+    #[serde(default)]
+    child_of: Option<String>,
+    /// Blocking tasks, from this file or the database
+    #[serde(default)]
+    depends_on: Vec<String>,
+}
+
+/// Whether an ID is safe to use as a task record ID
+fn is_valid_id(id: &str) -> bool {
+    !id.is_empty() && id.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// A `--batch` row with the line it starts on, or why it didn't parse
+type ParsedRow = (usize, Result<BatchRow, String>);
+
+/// Split a `--batch` input into rows, each with the line it starts on.
+///
+/// A file starting with `[` is one JSON array; anything else holds one
+/// object per line. Rows that don't parse are returned as errors so every
+/// bad row can be reported at once.
+fn parse_batch(text: &str) -> Result<Vec<ParsedRow>, String> {
+    let line_of = |offset: usize| text[..offset].matches('\n').count() + 1;
+    let parse = |json: &str| serde_json::from_str::<BatchRow>(json).map_err(|e| e.to_string());
+
+    if text.trim_start().starts_with('[') {
+        let items: Vec<&RawValue> = serde_json::from_str(text).map_err(|e| e.to_string())?;
+        return Ok(items
+            .into_iter()
+            .map(|item| {
+                let offset = item.get().as_ptr() as usize - text.as_ptr() as usize;
+                (line_of(offset), parse(item.get()))
+            })
+            .collect());
+    }
+
+    Ok(text
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| (i + 1, parse(line)))
+        .collect())
+}
+
 /// Result of the import command
 pub struct ImportResult {
     /// Number of tasks imported
@@ -62,6 +198,8 @@ pub struct ImportResult {
     pub child_of_relations: usize,
     /// Number of depends_on relations imported
     pub depends_on_relations: usize,
+    /// Number of edges skipped because they already exist (`--edges-only`)
+    pub edges_skipped: usize,
     /// Input source
     pub source: String,
 }
@@ -75,6 +213,9 @@ impl std::fmt::Display for ImportResult {
         }
         writeln!(f, "  Child relationships: {}", self.child_of_relations)?;
         writeln!(f, "  Dependencies: {}", self.depends_on_relations)?;
+        if self.edges_skipped > 0 {
+            writeln!(f, "  Edges skipped: {}", self.edges_skipped)?;
+        }
         write!(f, "  Source: {}", self.source)
     }
 }
@@ -82,7 +223,8 @@ impl std::fmt::Display for ImportResult {
 impl ImportCommand {
     /// Execute the import command.
     ///
-    /// Imports tasks and relationships from JSONL format.
+    /// Imports tasks and relationships from a JSONL export, or with
+    /// `--batch` creates the task objects of a JSON array or JSON lines.
     ///
     /// # Arguments
     ///
@@ -90,10 +232,75 @@ impl ImportCommand {
     ///
     /// # Errors
     ///
-    /// Returns `DbError` if database operations fail or file I/O fails.
+    /// Returns `DbError` if database operations fail, file I/O fails, or
+    /// overwriting existing tasks needs confirmation that can't be given.
     pub async fn execute(&self, db: &Database) -> Result<ImportResult, DbError> {
+        // Records read from stdin leave nothing to answer a prompt with
+        let prompter = if self.input.is_some() {
+            Prompter::from_terminal()
+        } else {
+            Prompter::non_interactive()
+        };
+        self.execute_with(db, prompter).await
+    }
+
+    /// Execute the import command, asking `prompter` before overwriting tasks.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`ImportCommand::execute`].
+    pub async fn execute_with(
+        &self,
+        db: &Database,
+        prompter: Prompter,
+    ) -> Result<ImportResult, DbError> {
+        // Progress is only for people watching a terminal, not for logs
+        let mut stderr = io::stderr();
+        let out: Option<&mut dyn Write> = stderr.is_terminal().then_some(&mut stderr as _);
+        self.execute_reporting(db, prompter, out, PROGRESS_INTERVAL)
+            .await
+    }
+
+    /// Execute the import command, writing a progress line to `out` every
+    /// `every` records unless `--quiet` was given.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`ImportCommand::execute`].
+    async fn execute_reporting(
+        &self,
+        db: &Database,
+        prompter: Prompter,
+        out: Option<&mut dyn Write>,
+        every: usize,
+    ) -> Result<ImportResult, DbError> {
+        if self.batch {
+            return self.import_batch(db).await;
+        }
+
+        let mut progress = ImportProgress::new(out.filter(|_| !self.quiet), every);
         let (records, source) = self.read_records()?;
 
+        if self.edges_only {
+            return self.import_edges(db, &records, source, &mut progress).await;
+        }
+
+        if !self.skip_existing && !self.force {
+            let overwrites = self.count_existing(db, &records).await?;
+            if overwrites > 0 {
+                let question = format!(
+                    "Import will overwrite {} existing {}. Continue?",
+                    overwrites,
+                    if overwrites == 1 { "task" } else { "tasks" }
+                );
+                if !prompter.confirm(&question)? {
+                    return Err(DbError::ValidationError {
+                        message: "Import cancelled; no tasks were changed".to_string(),
+                    });
+                }
+            }
+        }
+
         let mut tasks_imported = 0;
         let mut tasks_skipped = 0;
         let mut child_of_relations = 0;
@@ -106,6 +313,11 @@ impl ImportCommand {
                 if db.tasks().exists(id).await? {
                     if self.skip_existing {
                         tasks_skipped += 1;
+                        progress.tasks(
+                            tasks_imported + tasks_skipped,
+                            tasks_imported,
+                            tasks_skipped,
+                        );
                         continue;
                     }
                     // If not skipping, we'll overwrite - delete first
@@ -113,6 +325,11 @@ impl ImportCommand {
                 }
                 db.tasks().create(id, task.as_ref()).await?;
                 tasks_imported += 1;
+                progress.tasks(
+                    tasks_imported + tasks_skipped,
+                    tasks_imported,
+                    tasks_skipped,
+                );
             }
         }
 
@@ -122,10 +339,12 @@ impl ImportCommand {
                 ImportRecord::ChildOf { child, parent } => {
                     db.relationships().create_child_of(child, parent).await?;
                     child_of_relations += 1;
+                    progress.relationships(child_of_relations + depends_on_relations);
                 }
                 ImportRecord::DependsOn { task, blocker } => {
                     db.relationships().create_depends_on(task, blocker).await?;
                     depends_on_relations += 1;
+                    progress.relationships(child_of_relations + depends_on_relations);
                 }
                 ImportRecord::Task { .. } => {
                     // Already handled in first pass
@@ -138,10 +357,269 @@ impl ImportCommand {
             tasks_skipped,
             child_of_relations,
             depends_on_relations,
+            edges_skipped: 0,
+            source,
+        })
+    }
+
+    /// Apply only the edge records, between tasks that already exist.
+    ///
+    /// Every endpoint is checked before any edge is written, so a file that
+    /// names a missing task changes nothing. Edges already in the database
+    /// are skipped; a child_of edge to a different parent is an error since
+    /// a task has at most one parent.
+    async fn import_edges(
+        &self,
+        db: &Database,
+        records: &[ImportRecord],
+        source: String,
+        progress: &mut ImportProgress<'_>,
+    ) -> Result<ImportResult, DbError> {
+        let mut checked = std::collections::HashSet::new();
+        for record in records {
+            let (from, to) = match record {
+                ImportRecord::ChildOf { child, parent } => (child, parent),
+                ImportRecord::DependsOn { task, blocker } => (task, blocker),
+                ImportRecord::Task { .. } => continue,
+            };
+            for id in [from, to] {
+                if checked.insert(id.as_str()) && !db.tasks().exists(id).await? {
+                    return Err(DbError::NotFound {
+                        task_id: id.clone(),
+                    });
+                }
+            }
+        }
+
+        let mut child_of_relations = 0;
+        let mut depends_on_relations = 0;
+        let mut edges_skipped = 0;
+        for record in records {
+            match record {
+                ImportRecord::ChildOf { child, parent } => {
+                    match db.relationships().get_parent(child).await? {
+                        Some(current) if current == *parent => {
+                            edges_skipped += 1;
+                            continue;
+                        }
+                        Some(current) => {
+                            return Err(DbError::ValidationError {
+                                message: format!(
+                                    "Task '{}' already has parent '{}', not '{}'",
+                                    child, current, parent
+                                ),
+                            });
+                        }
+                        None => db.relationships().create_child_of(child, parent).await?,
+                    }
+                    child_of_relations += 1;
+                }
+                ImportRecord::DependsOn { task, blocker } => {
+                    if db.relationships().depends_on_exists(task, blocker).await? {
+                        edges_skipped += 1;
+                        continue;
+                    }
+                    db.relationships().create_depends_on(task, blocker).await?;
+                    depends_on_relations += 1;
+                }
+                ImportRecord::Task { .. } => continue,
+            }
+            progress.relationships(child_of_relations + depends_on_relations);
+        }
+
+        Ok(ImportResult {
+            tasks_imported: 0,
+            tasks_skipped: 0,
+            child_of_relations,
+            depends_on_relations,
+            edges_skipped,
+            source,
+        })
+    }
+
+    /// Create the task objects of a `--batch` input in one transaction.
+    ///
+    /// Every row is validated first: the object itself, its ID (new and
+    /// unique in the file), its references, which may name tasks later in
+    /// the file, and that its edges close no `depends_on` or `child_of`
+    /// cycle with the rows before it and the stored graph. If any row fails, the error lists each failing row by line
+    /// and nothing is created.
+    async fn import_batch(&self, db: &Database) -> Result<ImportResult, DbError> {
+        let (text, source) = self.read_text()?;
+        let path = self
+            .input
+            .clone()
+            .unwrap_or_else(|| PathBuf::from("<stdin>"));
+        let rows = parse_batch(&text).map_err(|reason| DbError::InvalidPath { path, reason })?;
+
+        let mut failures: Vec<(usize, String)> = Vec::new();
+        let mut parsed = Vec::new();
+        for (line, row) in rows {
+            match row {
+                Ok(row) => parsed.push((line, row)),
+                Err(e) => failures.push((line, e)),
+            }
+        }
+
+        // IDs given in the file, so references to them resolve and
+        // generated IDs avoid them
+        let mut file_ids = HashSet::new();
+        for (line, row) in &parsed {
+            let Some(id) = &row.id else { continue };
+            let id = id.to_lowercase();
+            if !is_valid_id(&id) {
+                failures.push((
+                    *line,
+                    format!("invalid ID '{}': use letters, digits and '_'", id),
+                ));
+            } else if !file_ids.insert(id.clone()) {
+                failures.push((*line, format!("ID '{}' appears more than once", id)));
+            } else if db.tasks().exists(&id).await? {
+                failures.push((*line, format!("task '{}' already exists", id)));
+            }
+        }
+
+        let mut tasks = Vec::new();
+        let mut lines = Vec::new();
+        for (line, row) in parsed {
+            let id = match &row.id {
+                Some(id) => id.to_lowercase(),
+                None => self.unused_id(db, &row.title, &file_ids).await?,
+            };
+            file_ids.insert(id.clone());
+
+            if row.title.trim().is_empty() {
+                failures.push((line, "title must not be empty".to_string()));
+            }
+            let parent = row.child_of.map(|p| p.to_lowercase());
+            let depends_on: Vec<String> = row.depends_on.iter().map(|d| d.to_lowercase()).collect();
+            for target in parent.iter().chain(&depends_on) {
+                if *target == id {
+                    failures.push((line, format!("task '{}' refers to itself", id)));
+                } else if !is_valid_id(target)
+                    || !(file_ids.contains(target) || db.tasks().exists(target).await?)
+                {
+                    failures.push((line, format!("unknown task '{}'", target)));
+                }
+            }
+
+            let mut task = Task::new(row.title, row.level.unwrap_or(Level::Task))
+                .with_status(row.status.unwrap_or(Status::Backlog))
+                .with_tags(tag_settings().apply_all(&row.tags));
+            task.description = row.description;
+            task.priority = row.priority;
+            tasks.push(BulkTask {
+                id,
+                task,
+                parent,
+                depends_on,
+            });
+            lines.push(line);
+        }
+
+        let graph = db.graph();
+        let mut pending = PendingEdges::new();
+        for (line, task) in lines.into_iter().zip(&tasks) {
+            if let Some(cycle) = batch_cycle(&graph, &pending, task).await? {
+                failures.push((line, cycle));
+                continue;
+            }
+            if let Some(parent) = &task.parent {
+                pending.add_child_of(&task.id, parent);
+            }
+            for blocker in &task.depends_on {
+                pending.add_depends_on(&task.id, blocker);
+            }
+        }
+
+        if !failures.is_empty() {
+            failures.sort_by_key(|(line, _)| *line);
+            let lines: Vec<String> = failures
+                .iter()
+                .map(|(line, reason)| format!("  line {}: {}", line, reason))
+                .collect();
+            return Err(DbError::ValidationError {
+                message: format!(
+                    "{} invalid {} in {}; nothing was imported:\n{}",
+                    failures.len(),
+                    if failures.len() == 1 { "row" } else { "rows" },
+                    source,
+                    lines.join("\n")
+                ),
+            });
+        }
+
+        db.tasks().bulk_create(&tasks).await?;
+        Ok(ImportResult {
+            tasks_imported: tasks.len(),
+            tasks_skipped: 0,
+            child_of_relations: tasks.iter().filter(|t| t.parent.is_some()).count(),
+            depends_on_relations: tasks.iter().map(|t| t.depends_on.len()).sum(),
+            edges_skipped: 0,
             source,
         })
     }
 
+    /// Generate an ID for a batch row that neither the database nor the
+    /// file uses.
+    async fn unused_id(
+        &self,
+        db: &Database,
+        title: &str,
+        taken: &HashSet<String>,
+    ) -> Result<String, DbError> {
+        let mut generator = IdGenerator::new(title);
+        while let Some(id) = generator.next_id() {
+            if !taken.contains(&id) && !db.tasks().exists(&id).await? {
+                return Ok(id);
+            }
+        }
+        Err(DbError::InvalidPath {
+            path: PathBuf::from("id"),
+            reason: "failed to generate unique ID after maximum retries".to_string(),
+        })
+    }
+
+    /// Read the whole input source as text
+    fn read_text(&self) -> Result<(String, String), DbError> {
+        let mut text = String::new();
+        match &self.input {
+            Some(path) => {
+                text = std::fs::read_to_string(path).map_err(|e| DbError::InvalidPath {
+                    path: path.clone(),
+                    reason: e.to_string(),
+                })?;
+                Ok((text, path.display().to_string()))
+            }
+            None => {
+                io::stdin()
+                    .read_to_string(&mut text)
+                    .map_err(|e| DbError::InvalidPath {
+                        path: PathBuf::from("<stdin>"),
+                        reason: e.to_string(),
+                    })?;
+                Ok((text, "stdin".to_string()))
+            }
+        }
+    }
+
+    /// Count task records whose ID already exists in the database.
+    async fn count_existing(
+        &self,
+        db: &Database,
+        records: &[ImportRecord],
+    ) -> Result<usize, DbError> {
+        let mut count = 0;
+        for record in records {
+            if let ImportRecord::Task { id, .. } = record
+                && db.tasks().exists(id).await?
+            {
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+
     /// Read records from the input source
     fn read_records(&self) -> Result<(Vec<ImportRecord>, String), DbError> {
         match &self.input {
@@ -193,6 +671,42 @@ impl ImportCommand {
     }
 }
 
+/// Describe the cycle `task`'s edges would close, if any.
+///
+/// Edges to the task itself are reported as self-references elsewhere and
+/// skipped here.
+async fn batch_cycle(
+    graph: &GraphQueries<'_>,
+    pending: &PendingEdges,
+    task: &BulkTask,
+) -> Result<Option<String>, DbError> {
+    if let Some(parent) = task.parent.as_ref().filter(|p| **p != task.id) {
+        let ancestors = graph.get_ancestor_chain_with(parent, pending).await?;
+        if let Some(end) = ancestors.iter().position(|id| *id == task.id) {
+            let path: Vec<String> = [task.id.clone(), parent.clone()]
+                .into_iter()
+                .chain(ancestors[..=end].iter().cloned())
+                .collect();
+            return Ok(Some(format!(
+                "child_of cycle: {}",
+                GraphQueries::format_cycle_path(&path)
+            )));
+        }
+    }
+    for blocker in task.depends_on.iter().filter(|b| **b != task.id) {
+        if let Some(path) = graph
+            .get_cycle_path_with(&task.id, blocker, pending)
+            .await?
+        {
+            return Ok(Some(format!(
+                "depends_on cycle: {}",
+                GraphQueries::format_cycle_path(&path)
+            )));
+        }
+    }
+    Ok(None)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -247,6 +761,7 @@ mod tests {
             child_of_relations: 5,
             depends_on_relations: 3,
             source: "backup.jsonl".to_string(),
+            edges_skipped: 0,
         };
 
         let output = format!("{}", result);
@@ -266,6 +781,7 @@ mod tests {
             child_of_relations: 5,
             depends_on_relations: 3,
             source: "backup.jsonl".to_string(),
+            edges_skipped: 0,
         };
 
         let output = format!("{}", result);
@@ -277,10 +793,430 @@ mod tests {
         let cmd = ImportCommand {
             input: Some(PathBuf::from("test.jsonl")),
             skip_existing: true,
+            force: false,
+            quiet: false,
+            edges_only: false,
+            batch: false,
         };
         let debug_str = format!("{:?}", cmd);
         assert!(debug_str.contains("ImportCommand"));
         assert!(debug_str.contains("test.jsonl"));
         assert!(debug_str.contains("skip_existing"));
     }
+
+    /// Helper to create a test database and a scratch directory for input files
+    async fn setup_test_db() -> (Database, PathBuf) {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "vtb-import-test-{}-{:?}-{}",
+            std::process::id(),
+            std::thread::current().id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+
+        let db = Database::connect(&temp_dir.join("db")).await.unwrap();
+        db.init().await.unwrap();
+
+        (db, temp_dir)
+    }
+
+    /// Write a JSONL file holding a single task with the given title
+    fn write_task_file(dir: &Path, name: &str, title: &str) -> PathBuf {
+        let path = dir.join(name);
+        let line = format!(
+            r#"{{"type":"task","id":"imp001","title":"{}","level":"task","status":"todo","tags":[],"sections":[],"refs":[]}}"#,
+            title
+        );
+        std::fs::write(&path, line).unwrap();
+        path
+    }
+
+    fn import_cmd(input: PathBuf, skip_existing: bool, force: bool) -> ImportCommand {
+        ImportCommand {
+            input: Some(input),
+            skip_existing,
+            force,
+            quiet: false,
+            edges_only: false,
+            batch: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_import_new_tasks_needs_no_confirmation() {
+        let (db, temp_dir) = setup_test_db().await;
+        let input = write_task_file(&temp_dir, "first.jsonl", "Original");
+
+        let result = import_cmd(input, false, false)
+            .execute_with(&db, Prompter::non_interactive())
+            .await
+            .unwrap();
+        assert_eq!(result.tasks_imported, 1);
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_import_overwrite_requires_confirmation() {
+        let (db, temp_dir) = setup_test_db().await;
+        let first = write_task_file(&temp_dir, "first.jsonl", "Original");
+        let second = write_task_file(&temp_dir, "second.jsonl", "Replacement");
+
+        import_cmd(first, false, true).execute(&db).await.unwrap();
+
+        let result = import_cmd(second.clone(), false, false)
+            .execute_with(&db, Prompter::non_interactive())
+            .await;
+        match result {
+            Err(DbError::ValidationError { message }) => {
+                assert!(message.contains("--yes"), "got: {}", message);
+            }
+            Err(other) => panic!("Expected ValidationError, got {:?}", other),
+            Ok(_) => panic!("Expected error, got success"),
+        }
+        let task = db.tasks().get("imp001").await.unwrap().unwrap();
+        assert_eq!(task.title, "Original");
+
+        import_cmd(second, false, true)
+            .execute_with(&db, Prompter::non_interactive())
+            .await
+            .unwrap();
+        let task = db.tasks().get("imp001").await.unwrap().unwrap();
+        assert_eq!(task.title, "Replacement");
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_import_skip_existing_needs_no_confirmation() {
+        let (db, temp_dir) = setup_test_db().await;
+        let first = write_task_file(&temp_dir, "first.jsonl", "Original");
+        let second = write_task_file(&temp_dir, "second.jsonl", "Replacement");
+
+        import_cmd(first, false, true).execute(&db).await.unwrap();
+
+        let result = import_cmd(second, true, false)
+            .execute_with(&db, Prompter::non_interactive())
+            .await
+            .unwrap();
+        assert_eq!(result.tasks_skipped, 1);
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    /// Three tasks, two child_of edges and one depends_on edge
+    fn write_project_file(dir: &Path) -> PathBuf {
+        let task = |id: &str| {
+            format!(
+                r#"{{"type":"task","id":"{}","title":"Task {}","level":"task","status":"todo","tags":[],"sections":[],"refs":[]}}"#,
+                id, id
+            )
+        };
+        let lines = [
+            task("p1"),
+            task("p2"),
+            task("p3"),
+            r#"{"type":"child_of","child":"p2","parent":"p1"}"#.to_string(),
+            r#"{"type":"child_of","child":"p3","parent":"p1"}"#.to_string(),
+            r#"{"type":"depends_on","task":"p3","blocker":"p2"}"#.to_string(),
+        ];
+        let path = dir.join("project.jsonl");
+        std::fs::write(&path, lines.join("\n")).unwrap();
+        path
+    }
+
+    #[tokio::test]
+    async fn test_import_reports_progress_and_matching_summary() {
+        let (db, temp_dir) = setup_test_db().await;
+        let input = write_project_file(&temp_dir);
+
+        let mut out = Vec::new();
+        let result = import_cmd(input, false, false)
+            .execute_reporting(&db, Prompter::non_interactive(), Some(&mut out), 2)
+            .await
+            .unwrap();
+
+        assert_eq!(result.tasks_imported, 3);
+        assert_eq!(result.child_of_relations, 2);
+        assert_eq!(result.depends_on_relations, 1);
+        for id in ["p1", "p2", "p3"] {
+            assert!(db.tasks().exists(id).await.unwrap());
+        }
+        assert_eq!(db.graph().get_all_descendants("p1").await.unwrap().len(), 2);
+
+        let progress = String::from_utf8(out).unwrap();
+        assert_eq!(
+            progress,
+            "Tasks: 2 processed (2 imported, 0 skipped)\nRelationships: 2 created\n"
+        );
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_import_quiet_suppresses_progress() {
+        let (db, temp_dir) = setup_test_db().await;
+        let input = write_project_file(&temp_dir);
+
+        let mut cmd = import_cmd(input, false, false);
+        cmd.quiet = true;
+        let mut out = Vec::new();
+        let result = cmd
+            .execute_reporting(&db, Prompter::non_interactive(), Some(&mut out), 1)
+            .await
+            .unwrap();
+
+        assert_eq!(result.tasks_imported, 3);
+        assert!(out.is_empty(), "got: {}", String::from_utf8_lossy(&out));
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    /// Write a JSONL file holding only the given edge lines
+    fn write_edge_file(dir: &Path, lines: &[&str]) -> PathBuf {
+        let path = dir.join("edges.jsonl");
+        std::fs::write(&path, lines.join("\n")).unwrap();
+        path
+    }
+
+    fn edges_only_cmd(input: PathBuf) -> ImportCommand {
+        let mut cmd = import_cmd(input, false, false);
+        cmd.edges_only = true;
+        cmd
+    }
+
+    #[tokio::test]
+    async fn test_import_edges_only_ignores_task_records_and_skips_duplicates() {
+        let (db, temp_dir) = setup_test_db().await;
+        let project = write_project_file(&temp_dir);
+        let mut cmd = import_cmd(project.clone(), false, false);
+        cmd.quiet = true;
+        cmd.execute(&db).await.unwrap();
+        db.client()
+            .query("UPDATE task:p2 SET title = 'Renamed'")
+            .await
+            .unwrap();
+
+        let result = edges_only_cmd(project)
+            .execute_with(&db, Prompter::non_interactive())
+            .await
+            .unwrap();
+
+        assert_eq!(result.tasks_imported, 0);
+        assert_eq!(result.child_of_relations, 0);
+        assert_eq!(result.depends_on_relations, 0);
+        assert_eq!(result.edges_skipped, 3);
+        let task = db.tasks().get("p2").await.unwrap().unwrap();
+        assert_eq!(task.title, "Renamed");
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_import_edges_only_missing_endpoint_applies_nothing() {
+        let (db, temp_dir) = setup_test_db().await;
+        db.client()
+            .query("CREATE task:e1 SET title = 'One', level = 'task', status = 'todo'; CREATE task:e2 SET title = 'Two', level = 'task', status = 'todo'")
+            .await
+            .unwrap();
+        let input = write_edge_file(
+            &temp_dir,
+            &[
+                r#"{"type":"depends_on","task":"e2","blocker":"e1"}"#,
+                r#"{"type":"child_of","child":"e2","parent":"ghost"}"#,
+            ],
+        );
+
+        match edges_only_cmd(input)
+            .execute_with(&db, Prompter::non_interactive())
+            .await
+        {
+            Err(DbError::NotFound { task_id }) => assert_eq!(task_id, "ghost"),
+            Err(other) => panic!("Expected NotFound, got {:?}", other),
+            Ok(_) => panic!("Expected error, got success"),
+        }
+        assert!(
+            !db.relationships()
+                .depends_on_exists("e2", "e1")
+                .await
+                .unwrap()
+        );
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_import_edges_only_rejects_conflicting_parent() {
+        let (db, temp_dir) = setup_test_db().await;
+        let project = write_project_file(&temp_dir);
+        let mut cmd = import_cmd(project, false, false);
+        cmd.quiet = true;
+        cmd.execute(&db).await.unwrap();
+        let input = write_edge_file(
+            &temp_dir,
+            &[r#"{"type":"child_of","child":"p3","parent":"p2"}"#],
+        );
+
+        match edges_only_cmd(input).execute(&db).await {
+            Err(DbError::ValidationError { message }) => {
+                assert!(
+                    message.contains("already has parent 'p1'"),
+                    "got: {}",
+                    message
+                );
+            }
+            Err(other) => panic!("Expected ValidationError, got {:?}", other),
+            Ok(_) => panic!("Expected error, got success"),
+        }
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    fn batch_cmd(dir: &Path, contents: &str) -> ImportCommand {
+        let path = dir.join("batch.json");
+        std::fs::create_dir_all(dir).unwrap();
+        std::fs::write(&path, contents).unwrap();
+        let mut cmd = import_cmd(path, false, false);
+        cmd.batch = true;
+        cmd
+    }
+
+    #[test]
+    fn test_parse_batch_reports_start_lines() {
+        let rows =
+            parse_batch("[\n  {\"title\": \"A\"},\n\n  {\n    \"title\": \"B\"\n  }\n]").unwrap();
+        let lines: Vec<usize> = rows.iter().map(|(line, _)| *line).collect();
+        assert_eq!(lines, vec![2, 4]);
+        assert_eq!(rows[1].1.as_ref().unwrap().title, "B");
+
+        let rows = parse_batch("{\"title\": \"A\"}\n\n{\"title\": 5}\n").unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[1].0, 3);
+        assert!(rows[1].1.is_err());
+
+        assert!(parse_batch("[{\"title\": \"A\"}").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_import_batch_resolves_later_references() {
+        let (db, temp_dir) = setup_test_db().await;
+        db.client()
+            .query(r#"CREATE task:old SET title = "Old", level = "epic", status = "todo""#)
+            .await
+            .unwrap();
+
+        let cmd = batch_cmd(
+            &temp_dir,
+            r#"{"id": "API", "title": "API", "child_of": "epic1", "depends_on": ["schema", "old"]}
+{"id": "epic1", "title": "Epic", "level": "epic", "priority": "high"}
+{"title": "Generated", "child_of": "epic1", "tags": ["x"]}
+{"id": "schema", "title": "Schema", "status": "todo"}"#,
+        );
+        let result = cmd.execute(&db).await.unwrap();
+        assert_eq!(result.tasks_imported, 4);
+        assert_eq!(result.child_of_relations, 2);
+        assert_eq!(result.depends_on_relations, 2);
+
+        let api = db.tasks().get("api").await.unwrap().unwrap();
+        assert_eq!(api.status, Status::Backlog);
+        assert_eq!(
+            db.relationships().get_parent("api").await.unwrap(),
+            Some("epic1".to_string())
+        );
+        assert_eq!(
+            db.relationships()
+                .get_children("epic1")
+                .await
+                .unwrap()
+                .len(),
+            2
+        );
+        let epic = db.tasks().get("epic1").await.unwrap().unwrap();
+        assert_eq!(epic.level, Level::Epic);
+        assert_eq!(epic.priority, Some(Priority::High));
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_import_batch_lists_invalid_rows_and_creates_nothing() {
+        let (db, temp_dir) = setup_test_db().await;
+        db.client()
+            .query(r#"CREATE task:old SET title = "Old", level = "task", status = "todo""#)
+            .await
+            .unwrap();
+
+        let cmd = batch_cmd(
+            &temp_dir,
+            r#"[
+  {"id": "good", "title": "Good"},
+  {"id": "old", "title": "Taken"},
+  {"id": "bad", "title": "Bad", "depends_on": ["ghost"]},
+  {"title": "Typo", "levle": "epic"}
+]"#,
+        );
+        let message = match cmd.execute(&db).await {
+            Err(DbError::ValidationError { message }) => message,
+            other => panic!("Expected ValidationError, got {:?}", other.err()),
+        };
+        assert!(
+            message.starts_with("3 invalid rows in "),
+            "got: {}",
+            message
+        );
+        assert!(message.contains("line 3: task 'old' already exists"));
+        assert!(message.contains("line 4: unknown task 'ghost'"));
+        assert!(message.contains("line 5: unknown field `levle`"));
+        assert!(!db.tasks().exists("good").await.unwrap());
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+    #[tokio::test]
+    async fn test_import_batch_rejects_cycles() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        let cmd = batch_cmd(
+            &temp_dir,
+            r#"{"id": "aa", "title": "A", "depends_on": ["bb"]}
+{"id": "bb", "title": "B", "depends_on": ["aa"]}
+{"id": "cc", "title": "C", "child_of": "dd"}
+{"id": "dd", "title": "D", "child_of": "cc"}
+{"id": "ee", "title": "E", "depends_on": ["aa"], "tags": [" Back End ", "back-end"]}"#,
+        );
+        let message = match cmd.execute(&db).await {
+            Err(DbError::ValidationError { message }) => message,
+            other => panic!("Expected ValidationError, got {:?}", other.err()),
+        };
+        assert!(
+            message.starts_with("2 invalid rows in "),
+            "got: {}",
+            message
+        );
+        assert!(
+            message.contains("line 2: depends_on cycle: bb -> aa -> bb"),
+            "got: {}",
+            message
+        );
+        assert!(
+            message.contains("line 4: child_of cycle: dd -> cc -> dd"),
+            "got: {}",
+            message
+        );
+        assert!(!db.tasks().exists("aa").await.unwrap());
+
+        // Without the cycles the rest imports, with tags normalized
+        let cmd = batch_cmd(
+            &temp_dir,
+            r#"{"id": "aa", "title": "A", "depends_on": ["bb"]}
+{"id": "bb", "title": "B"}
+{"id": "ee", "title": "E", "depends_on": ["aa"], "tags": [" Back End ", "back-end"]}"#,
+        );
+        assert_eq!(cmd.execute(&db).await.unwrap().tasks_imported, 3);
+        let ee = db.tasks().get("ee").await.unwrap().unwrap();
+        assert_eq!(ee.tags, vec!["back-end".to_string()]);
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
 }
@@ -0,0 +1,275 @@
+//! Info command for reporting where the database lives
+//!
+//! Implements the `vtb info` command. The database path can come from
+//! `--db`, `VTB_DB_PATH` or the project default, so this prints the one that
+//! was actually opened along with its size, contents and schema version.
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+use clap::Args;
+use serde::Deserialize;
+use vertebrae_db::{Database, DbError, SCHEMA_VERSION};
+
+/// Show the database location, size and contents
+#[derive(Debug, Args)]
+pub struct InfoCommand {}
+
+/// Result of the info command execution
+#[derive(Debug)]
+pub struct InfoResult {
+    /// Absolute path of the opened database directory
+    pub path: PathBuf,
+    /// Total size of the files under `path`, in bytes
+    pub size_bytes: u64,
+    /// Number of files under `path`
+    pub file_count: usize,
+    /// Schema version this build creates
+    pub schema_version: u32,
+    /// Number of tasks
+    pub task_count: usize,
+    /// Number of child_of edges
+    pub child_of_count: usize,
+    /// Number of depends_on edges
+    pub depends_on_count: usize,
+}
+
+impl std::fmt::Display for InfoResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Database: {}", self.path.display())?;
+        writeln!(
+            f,
+            "Size:     {} ({} file{})",
+            format_size(self.size_bytes),
+            self.file_count,
+            if self.file_count == 1 { "" } else { "s" }
+        )?;
+        writeln!(f, "Schema:   v{}", self.schema_version)?;
+        writeln!(f, "Tasks:    {}", self.task_count)?;
+        write!(
+            f,
+            "Edges:    {} child_of, {} depends_on",
+            self.child_of_count, self.depends_on_count
+        )
+    }
+}
+
+/// Result from a count query
+#[derive(Debug, Deserialize)]
+struct CountRow {
+    count: usize,
+}
+
+impl InfoCommand {
+    /// Execute the info command.
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - Reference to the database connection
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError` if the database directory can't be read or the
+    /// count queries fail.
+    pub async fn execute(&self, db: &Database) -> Result<InfoResult, DbError> {
+        let path = db
+            .path()
+            .canonicalize()
+            .unwrap_or_else(|_| db.path().to_path_buf());
+        let (size_bytes, file_count) = dir_size(&path).map_err(|e| DbError::InvalidPath {
+            path: path.clone(),
+            reason: format!("Failed to read database directory: {}", e),
+        })?;
+
+        Ok(InfoResult {
+            size_bytes,
+            file_count,
+            schema_version: SCHEMA_VERSION,
+            task_count: count_rows(db, "task").await?,
+            child_of_count: count_rows(db, "child_of").await?,
+            depends_on_count: count_rows(db, "depends_on").await?,
+            path,
+        })
+    }
+}
+
+/// Count the records in `table`.
+async fn count_rows(db: &Database, table: &str) -> Result<usize, DbError> {
+    let query = format!("SELECT count() as count FROM {} GROUP ALL", table);
+    let mut result = db.query(&query).await?;
+    let rows: Vec<CountRow> = result.take(0)?;
+    Ok(rows.first().map(|r| r.count).unwrap_or(0))
+}
+
+/// Total size in bytes and number of files under `path`, recursively.
+///
+/// Symlinks are not followed.
+fn dir_size(path: &Path) -> io::Result<(u64, usize)> {
+    let mut bytes = 0;
+    let mut files = 0;
+    for entry in std::fs::read_dir(path)? {
+        let entry = entry?;
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            let (b, f) = dir_size(&entry.path())?;
+            bytes += b;
+            files += f;
+        } else if file_type.is_file() {
+            bytes += entry.metadata()?.len();
+            files += 1;
+        }
+    }
+    Ok((bytes, files))
+}
+
+/// Render a byte count with a binary unit, e.g. `1.5 MiB`.
+fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", value, UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    fn temp_path(name: &str) -> PathBuf {
+        env::temp_dir().join(format!(
+            "vtb-{}-test-{}-{:?}-{}",
+            name,
+            std::process::id(),
+            std::thread::current().id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ))
+    }
+
+    /// Helper to create a test database
+    async fn setup_test_db() -> (Database, PathBuf) {
+        let temp_dir = temp_path("info");
+        let db = Database::connect(&temp_dir).await.unwrap();
+        db.init().await.unwrap();
+        (db, temp_dir)
+    }
+
+    /// Clean up test database
+    fn cleanup(path: &Path) {
+        let _ = std::fs::remove_dir_all(path);
+    }
+
+    #[test]
+    fn test_dir_size_counts_nested_files() {
+        let dir = temp_path("info-size");
+        std::fs::create_dir_all(dir.join("nested/deeper")).unwrap();
+        std::fs::write(dir.join("a"), vec![0u8; 100]).unwrap();
+        std::fs::write(dir.join("nested/b"), vec![0u8; 20]).unwrap();
+        std::fs::write(dir.join("nested/deeper/c"), vec![0u8; 3]).unwrap();
+        std::fs::create_dir_all(dir.join("empty")).unwrap();
+
+        assert_eq!(dir_size(&dir).unwrap(), (123, 3));
+
+        cleanup(&dir);
+    }
+
+    #[test]
+    fn test_dir_size_empty_and_missing() {
+        let dir = temp_path("info-empty");
+        std::fs::create_dir_all(&dir).unwrap();
+        assert_eq!(dir_size(&dir).unwrap(), (0, 0));
+        cleanup(&dir);
+
+        assert!(dir_size(&dir).is_err());
+    }
+
+    #[test]
+    fn test_format_size() {
+        assert_eq!(format_size(0), "0 B");
+        assert_eq!(format_size(1023), "1023 B");
+        assert_eq!(format_size(1024), "1.0 KiB");
+        assert_eq!(format_size(1536), "1.5 KiB");
+        assert_eq!(format_size(5 * 1024 * 1024), "5.0 MiB");
+        assert_eq!(format_size(3 * 1024 * 1024 * 1024), "3.0 GiB");
+    }
+
+    #[tokio::test]
+    async fn test_info_reports_path_and_counts() {
+        let (db, temp_dir) = setup_test_db().await;
+        db.client()
+            .query(
+                r#"CREATE task:a SET title = "A", level = "epic", status = "todo";
+                   CREATE task:b SET title = "B", level = "task", status = "todo";
+                   CREATE task:c SET title = "C", level = "task", status = "todo";
+                   RELATE task:b -> child_of -> task:a;
+                   RELATE task:c -> child_of -> task:a;
+                   RELATE task:c -> depends_on -> task:b"#,
+            )
+            .await
+            .unwrap();
+
+        let result = InfoCommand {}.execute(&db).await.unwrap();
+        assert_eq!(result.path, temp_dir.canonicalize().unwrap());
+        assert!(result.path.is_absolute());
+        assert_eq!(result.schema_version, SCHEMA_VERSION);
+        assert_eq!(result.task_count, 3);
+        assert_eq!(result.child_of_count, 2);
+        assert_eq!(result.depends_on_count, 1);
+        assert!(result.size_bytes > 0);
+        assert!(result.file_count > 0);
+
+        let output = result.to_string();
+        assert!(
+            output.starts_with(&format!("Database: {}\n", result.path.display())),
+            "got:\n{}",
+            output
+        );
+        assert!(output.contains("Tasks:    3"));
+        assert!(output.contains("Edges:    2 child_of, 1 depends_on"));
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_info_empty_database() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        let result = InfoCommand {}.execute(&db).await.unwrap();
+        assert_eq!(result.task_count, 0);
+        assert_eq!(result.child_of_count, 0);
+        assert_eq!(result.depends_on_count, 0);
+
+        cleanup(&temp_dir);
+    }
+
+    #[test]
+    fn test_info_result_display() {
+        let result = InfoResult {
+            path: PathBuf::from("/work/.vtb/data"),
+            size_bytes: 2048,
+            file_count: 1,
+            schema_version: 1,
+            task_count: 5,
+            child_of_count: 4,
+            depends_on_count: 0,
+        };
+        assert_eq!(
+            result.to_string(),
+            "Database: /work/.vtb/data\n\
+             Size:     2.0 KiB (1 file)\n\
+             Schema:   v1\n\
+             Tasks:    5\n\
+             Edges:    4 child_of, 0 depends_on"
+        );
+    }
+}
@@ -2,11 +2,32 @@
 //!
 //! Implements the `vtb list` command to display tasks with filtering options.
 
+use std::io::Write;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
 use clap::Args;
-use vertebrae_db::{Database, DbError, Level, Priority, Status, TaskFilter};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+use vertebrae_db::{
+    DEFAULT_PAGE_SIZE, Database, DbError, Level, LevelLabels, Priority, SectionType, SortKey,
+    Status, TagMode, TaskFilter, level_labels, list_settings, tag_settings,
+};
+
+use crate::commands::section::parse_section_type;
+use crate::output::{
+    Column, OutputFormat, Render, TaskRowWriter, format_status_summary, format_task_list,
+    format_task_table_columns, to_json, with_blocked_column,
+};
+use crate::timestamp::parse_timestamp;
+
+/// Clears the terminal and moves the cursor home before each `--watch` redraw
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
 
 /// A summary of a task for display in the list
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TaskSummary {
     /// The task ID (extracted from SurrealDB Thing)
     pub id: String,
@@ -22,6 +43,30 @@ pub struct TaskSummary {
     pub tags: Vec<String>,
     /// Whether this task needs human review
     pub needs_human_review: Option<bool>,
+    /// When the task was created
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+    /// When the task was last changed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl Render for [TaskSummary] {
+    const COMMAND: &'static str = "list";
+    const FORMATS: &'static [OutputFormat] = &[
+        OutputFormat::Table,
+        OutputFormat::Json,
+        OutputFormat::Ndjson,
+        OutputFormat::Csv,
+        OutputFormat::Yaml,
+    ];
+
+    fn render_as(&self, format: OutputFormat) -> Result<String, DbError> {
+        match format {
+            OutputFormat::Json => to_json(self),
+            _ => Ok(format_task_list(self, format)),
+        }
+    }
 }
 
 /// List tasks with optional filters
@@ -43,6 +88,10 @@ pub struct ListCommand {
     #[arg(short, long = "tag")]
     pub tags: Vec<String>,
 
+    /// How multiple --tag values combine: any (at least one tag, the default) or all (every tag)
+    #[arg(long, value_parser = parse_tag_mode, default_value = "any")]
+    pub tag_mode: TagMode,
+
     /// Show only root items (no parent)
     #[arg(long)]
     pub root: bool,
@@ -58,23 +107,127 @@ pub struct ListCommand {
     /// Search text in title and description (case-insensitive)
     #[arg(long)]
     pub search: Option<String>,
+
+    /// Show only tasks whose title matches this regular expression
+    #[arg(long, value_name = "PATTERN")]
+    pub title_regex: Option<String>,
+
+    /// Match --title-regex ignoring case
+    #[arg(long, requires = "title_regex")]
+    pub case_insensitive: bool,
+
+    /// Show only tasks with a section of this type (can be specified multiple times)
+    #[arg(long = "has-section", value_name = "TYPE", value_parser = parse_section_type)]
+    pub section_types: Vec<SectionType>,
+
+    /// Show only tasks with no sections
+    #[arg(long, conflicts_with = "section_types")]
+    pub no_sections: bool,
+
+    /// Show only tasks with code refs
+    #[arg(long, conflicts_with = "no_refs")]
+    pub has_refs: bool,
+
+    /// Show only tasks without code refs
+    #[arg(long)]
+    pub no_refs: bool,
+
+    /// Show only unfinished tasks whose due date has passed
+    #[arg(long)]
+    pub overdue: bool,
+
+    /// Show only tasks created at or after this time (RFC 3339, YYYY-MM-DD HH:MM or YYYY-MM-DD)
+    #[arg(long, value_parser = parse_timestamp, value_name = "TIME")]
+    pub created_after: Option<DateTime<Utc>>,
+
+    /// Show only tasks created before this time
+    #[arg(long, value_parser = parse_timestamp, value_name = "TIME")]
+    pub created_before: Option<DateTime<Utc>>,
+
+    /// Show only tasks changed at or after this time
+    #[arg(long, value_parser = parse_timestamp, value_name = "TIME")]
+    pub updated_after: Option<DateTime<Utc>>,
+
+    /// Also show tasks in the trash (see `vtb delete` and `vtb restore`)
+    #[arg(long)]
+    pub include_deleted: bool,
+
+    /// Output format: table, json, ndjson, csv or yaml (ndjson, csv and yaml are streamed row by row)
+    #[arg(long, value_enum, default_value_t = OutputFormat::Table)]
+    pub format: OutputFormat,
+
+    /// Append a footer with the number of listed tasks per status
+    #[arg(long)]
+    pub summary: bool,
+
+    /// Print the SurrealQL query instead of running it
+    #[arg(long)]
+    pub explain: bool,
+
+    /// Sort by these keys, comma-separated: priority, updated, created, title, status
+    /// (default: `[list] sort` in the config, or priority,updated)
+    #[arg(long, visible_alias = "sort-by", value_delimiter = ',', value_parser = parse_sort_key)]
+    pub sort: Vec<SortKey>,
+
+    /// Reverse the listing order
+    #[arg(long)]
+    pub reverse: bool,
+
+    /// Add a Blocked column with each task's number of incomplete blockers
+    #[arg(long)]
+    pub show_blocked: bool,
+
+    /// Table columns to show, comma-separated and in order:
+    /// id, level, status, blocked, priority, title, tags, review, created,
+    /// updated (default: all but blocked, created and updated)
+    #[arg(long, value_delimiter = ',', value_parser = parse_column)]
+    pub columns: Vec<Column>,
+
+    /// Wrap long titles and tags onto extra lines instead of truncating them
+    #[arg(long)]
+    pub wrap: bool,
+
+    /// Show N randomly chosen matching tasks instead of all of them (default: 1)
+    #[arg(long, value_name = "N", num_args = 0..=1, default_missing_value = "1")]
+    pub random: Option<usize>,
+
+    /// Seed for --random, to get the same sample every time
+    #[arg(long, requires = "random")]
+    pub seed: Option<u64>,
+
+    /// Show at most N tasks
+    #[arg(long, value_name = "N", conflicts_with = "random")]
+    pub limit: Option<usize>,
+
+    /// Skip the first N matching tasks (requires --limit)
+    #[arg(long, value_name = "N", conflicts_with = "random")]
+    pub offset: Option<usize>,
+
+    /// Re-run the listing every SECONDS (default: 2) and redraw it when it changes, until Ctrl-C
+    #[arg(
+        long,
+        value_name = "SECONDS",
+        num_args = 0..=1,
+        default_missing_value = "2",
+        value_parser = clap::value_parser!(u64).range(1..),
+        conflicts_with = "explain"
+    )]
+    pub watch: Option<u64>,
 }
 
-/// Parse a level string into a Level enum
-fn parse_level(s: &str) -> Result<Level, String> {
-    match s.to_lowercase().as_str() {
-        "epic" => Ok(Level::Epic),
-        "ticket" => Ok(Level::Ticket),
-        "task" => Ok(Level::Task),
-        _ => Err(format!(
-            "invalid level '{}'. Valid values: epic, ticket, task",
-            s
-        )),
-    }
+/// Parse a level string into a Level enum, accepting configured level names
+pub(crate) fn parse_level(s: &str) -> Result<Level, String> {
+    Level::parse_name(s).ok_or_else(|| {
+        format!(
+            "invalid level '{}'. Valid values: {}",
+            s,
+            level_labels().valid_names()
+        )
+    })
 }
 
 /// Parse a status string into a Status enum
-fn parse_status(s: &str) -> Result<Status, String> {
+pub(crate) fn parse_status(s: &str) -> Result<Status, String> {
     Status::parse(&s.to_lowercase()).ok_or_else(|| {
         format!(
             "invalid status '{}'. Valid values: backlog, todo, in_progress, pending_review, done, rejected",
@@ -83,6 +236,39 @@ fn parse_status(s: &str) -> Result<Status, String> {
     })
 }
 
+/// Parse a sort key name
+fn parse_sort_key(s: &str) -> Result<SortKey, String> {
+    SortKey::parse(&s.trim().to_lowercase()).ok_or_else(|| {
+        format!(
+            "invalid sort key '{}'. Valid values: {}",
+            s,
+            SortKey::ALL.map(|key| key.as_str()).join(", ")
+        )
+    })
+}
+
+/// Parse a table column name
+fn parse_column(s: &str) -> Result<Column, String> {
+    Column::parse(&s.trim().to_lowercase()).ok_or_else(|| {
+        format!(
+            "invalid column '{}'. Valid values: {}",
+            s,
+            Column::ALL.map(|column| column.as_str()).join(", ")
+        )
+    })
+}
+
+/// Parse a tag mode name
+fn parse_tag_mode(s: &str) -> Result<TagMode, String> {
+    TagMode::parse(&s.trim().to_lowercase()).ok_or_else(|| {
+        format!(
+            "invalid tag mode '{}'. Valid values: {}",
+            s,
+            TagMode::ALL.map(|mode| mode.as_str()).join(", ")
+        )
+    })
+}
+
 /// Parse a priority string into a Priority enum
 fn parse_priority(s: &str) -> Result<Priority, String> {
     match s.to_lowercase().as_str() {
@@ -97,17 +283,63 @@ fn parse_priority(s: &str) -> Result<Priority, String> {
     }
 }
 
-/// Convert repository TaskSummary to CLI TaskSummary
+/// Pick `count` of `items` at random, keeping their listing order.
+///
+/// Returns everything when there are no more than `count` items.
+fn sample<T>(items: Vec<T>, count: usize, rng: &mut StdRng) -> Vec<T> {
+    if items.len() <= count {
+        return items;
+    }
+    let mut picked = rand::seq::index::sample(rng, items.len(), count).into_vec();
+    picked.sort_unstable();
+    let mut picked = picked.into_iter().peekable();
+    items
+        .into_iter()
+        .enumerate()
+        .filter(|(index, _)| picked.next_if_eq(index).is_some())
+        .map(|(_, item)| item)
+        .collect()
+}
+
+/// What `--watch` should write for a freshly rendered `table`: the cleared
+/// screen and the table, or nothing if it matches the one already `shown`.
+fn next_frame(shown: &mut Option<String>, table: String) -> Option<String> {
+    if shown.as_ref() == Some(&table) {
+        return None;
+    }
+    let frame = format!("{}{}\n", CLEAR_SCREEN, table);
+    *shown = Some(table);
+    Some(frame)
+}
+
+/// Map an output write failure to a DbError
+fn write_error(source: std::io::Error) -> DbError {
+    DbError::Io {
+        stream: "stdout",
+        source,
+    }
+}
+
+/// Convert repository TaskSummary to CLI TaskSummary using the configured level names
 impl From<vertebrae_db::TaskSummary> for TaskSummary {
     fn from(summary: vertebrae_db::TaskSummary) -> Self {
+        TaskSummary::with_labels(summary, level_labels())
+    }
+}
+
+impl TaskSummary {
+    /// Convert a repository TaskSummary, displaying its level with the given labels
+    pub fn with_labels(summary: vertebrae_db::TaskSummary, labels: &LevelLabels) -> Self {
         TaskSummary {
             id: summary.id,
             title: summary.title,
-            level: summary.level.as_str().to_string(),
+            level: labels.label(&summary.level).to_string(),
             status: summary.status.as_str().to_string(),
             priority: summary.priority.map(|p| p.as_str().to_string()),
             tags: summary.tags,
             needs_human_review: summary.needs_human_review,
+            created_at: summary.created_at,
+            updated_at: summary.updated_at,
         }
     }
 }
@@ -130,6 +362,202 @@ impl ListCommand {
     /// - Invalid filter values are provided
     /// - Search query is empty
     pub async fn execute(&self, db: &Database) -> Result<Vec<TaskSummary>, DbError> {
+        self.validate()?;
+
+        // Build the TaskFilter from command options
+        let filter = self.build_filter();
+
+        // Use the repository to execute the query
+        let mut results = db.list_tasks().list(&filter).await?;
+
+        if let Some(count) = self.random {
+            let mut rng = match self.seed {
+                Some(seed) => StdRng::seed_from_u64(seed),
+                None => StdRng::from_os_rng(),
+            };
+            results = sample(results, count, &mut rng);
+        }
+
+        // Convert repository TaskSummary to CLI TaskSummary
+        Ok(results.into_iter().map(TaskSummary::from).collect())
+    }
+
+    /// Execute the list command, writing rows to `out` page by page.
+    ///
+    /// Used for the row-oriented formats (ndjson, csv, yaml) so memory stays
+    /// bounded by the page size rather than the size of the result set.
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - Reference to the database connection
+    /// * `out` - Destination for the formatted rows
+    ///
+    /// # Returns
+    ///
+    /// The number of tasks written.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError` if validation or a database query fails, or
+    /// `DbError::Io` if writing to `out` fails.
+    pub async fn stream<W: Write>(&self, db: &Database, out: W) -> Result<usize, DbError> {
+        self.validate()?;
+
+        let filter = self.build_filter();
+        let lister = db.list_tasks();
+        let mut stream = lister.stream(&filter, DEFAULT_PAGE_SIZE);
+        let mut writer = TaskRowWriter::new(out, self.format);
+
+        while let Some(page) = stream.next_page().await? {
+            writer
+                .write_all(page.into_iter().map(TaskSummary::from))
+                .map_err(write_error)?;
+        }
+
+        let rows = writer.rows();
+        writer.finish().map_err(write_error)?;
+        Ok(rows)
+    }
+
+    /// Run the listing and format it as `vtb list` prints it, including
+    /// the `--summary` and page footers.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError` if validation or a database query fails, or the
+    /// format isn't supported.
+    pub async fn render(&self, db: &Database) -> Result<String, DbError> {
+        let tasks = self.execute(db).await?;
+        let columns = self.table_columns();
+        let blocked = if columns.contains(&Column::Blocked) {
+            Some(db.graph().count_incomplete_blockers().await?)
+        } else {
+            None
+        };
+        let mut table = if self.format == OutputFormat::Table || self.wrap || self.show_blocked {
+            format_task_table_columns(&tasks, &columns, blocked.as_ref(), self.wrap)
+        } else {
+            tasks.render(self.format)?
+        };
+        if self.summary {
+            table.push_str("\n\n");
+            table.push_str(&format_status_summary(&tasks));
+        }
+        if self.format == OutputFormat::Table
+            && let Some(footer) = self.page_footer(db, &tasks).await?
+        {
+            table.push_str("\n\n");
+            table.push_str(&footer);
+        }
+        Ok(table)
+    }
+
+    /// Re-render the listing every `interval`, redrawing `out` from a
+    /// cleared screen whenever the output changes, until Ctrl-C.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError` if a refresh fails, or `DbError::Io` if writing to
+    /// `out` fails.
+    pub async fn watch<W: Write>(
+        &self,
+        db: &Database,
+        interval: Duration,
+        mut out: W,
+    ) -> Result<(), DbError> {
+        // Listen for Ctrl-C once, so a press during a render isn't lost
+        let ctrl_c = tokio::signal::ctrl_c();
+        tokio::pin!(ctrl_c);
+
+        let mut shown = None;
+        loop {
+            let table = tokio::select! {
+                _ = &mut ctrl_c => return Ok(()),
+                table = self.render(db) => table?,
+            };
+            if let Some(frame) = next_frame(&mut shown, table) {
+                out.write_all(frame.as_bytes()).map_err(write_error)?;
+                out.flush().map_err(write_error)?;
+            }
+            tokio::select! {
+                _ = &mut ctrl_c => return Ok(()),
+                _ = tokio::time::sleep(interval) => {}
+            }
+        }
+    }
+
+    /// Compile `--title-regex`, honoring `--case-insensitive`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError::ValidationError` if the pattern is invalid.
+    fn compile_title_regex(&self) -> Result<Option<Regex>, DbError> {
+        let Some(pattern) = &self.title_regex else {
+            return Ok(None);
+        };
+        RegexBuilder::new(pattern)
+            .case_insensitive(self.case_insensitive)
+            .build()
+            .map(Some)
+            .map_err(|e| DbError::ValidationError {
+                message: format!("Invalid --title-regex pattern '{}': {}", pattern, e),
+            })
+    }
+
+    /// Columns for the table output: `--columns`, or the default set, plus
+    /// the Blocked column with `--show-blocked`.
+    pub fn table_columns(&self) -> Vec<Column> {
+        let columns = if self.columns.is_empty() {
+            Column::DEFAULT.to_vec()
+        } else {
+            self.columns.clone()
+        };
+        if self.show_blocked {
+            with_blocked_column(&columns)
+        } else {
+            columns
+        }
+    }
+
+    /// Footer describing which page of the matches `tasks` is, e.g.
+    /// `showing 1-50 of 120`.
+    ///
+    /// Runs a separate count of every match. Returns `None` without
+    /// `--limit`, when the listing is not paged.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError` if the count query fails.
+    pub async fn page_footer(
+        &self,
+        db: &Database,
+        tasks: &[TaskSummary],
+    ) -> Result<Option<String>, DbError> {
+        if self.limit.is_none() {
+            return Ok(None);
+        }
+        let total = db.list_tasks().count(&self.build_filter()).await?;
+        let first = self.offset.unwrap_or(0) + 1;
+        Ok(Some(match tasks.len() {
+            0 => format!("showing 0 of {}", total),
+            shown => format!("showing {}-{} of {}", first, first + shown - 1, total),
+        }))
+    }
+
+    /// Return the SurrealQL query `execute` would run, without running it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError::ValidationError` if the options are invalid.
+    pub fn explain(&self, db: &Database) -> Result<String, DbError> {
+        self.validate()?;
+        Ok(db.list_tasks().explain(&self.build_filter()))
+    }
+
+    /// Validate option values that clap cannot check on its own.
+    fn validate(&self) -> Result<(), DbError> {
+        <[TaskSummary]>::check_format(self.format)?;
+
         // Validate search query is not empty
         if let Some(ref search) = self.search
             && search.trim().is_empty()
@@ -139,14 +567,52 @@ impl ListCommand {
             });
         }
 
-        // Build the TaskFilter from command options
-        let filter = self.build_filter();
+        self.compile_title_regex()?;
 
-        // Use the repository to execute the query
-        let results = db.list_tasks().list(&filter).await?;
+        if self.random == Some(0) {
+            return Err(DbError::ValidationError {
+                message: "--random must pick at least 1 task".to_string(),
+            });
+        }
 
-        // Convert repository TaskSummary to CLI TaskSummary
-        Ok(results.into_iter().map(TaskSummary::from).collect())
+        if self.show_blocked && self.format != OutputFormat::Table {
+            return Err(DbError::ValidationError {
+                message: "--show-blocked is only supported with the table format".to_string(),
+            });
+        }
+
+        if self.offset.is_some() && self.limit.is_none() {
+            return Err(DbError::ValidationError {
+                message: "--offset requires --limit".to_string(),
+            });
+        }
+
+        if self.limit == Some(0) {
+            return Err(DbError::ValidationError {
+                message: "--limit must be at least 1".to_string(),
+            });
+        }
+
+        if self.wrap && self.format != OutputFormat::Table {
+            return Err(DbError::ValidationError {
+                message: "--wrap is only supported with the table format".to_string(),
+            });
+        }
+
+        // Redrawing from a cleared screen only makes sense for a table
+        if self.watch.is_some() && self.format != OutputFormat::Table {
+            return Err(DbError::ValidationError {
+                message: "--watch is only supported with the table format".to_string(),
+            });
+        }
+
+        // A footer would corrupt machine-readable output
+        if self.summary && self.format != OutputFormat::Table {
+            return Err(DbError::ValidationError {
+                message: "--summary is only supported with the table format".to_string(),
+            });
+        }
+        Ok(())
     }
 
     /// Build a TaskFilter from the command options.
@@ -154,7 +620,19 @@ impl ListCommand {
     /// Converts the CLI arguments into a TaskFilter that can be passed
     /// to the repository layer.
     fn build_filter(&self) -> TaskFilter {
-        let mut filter = TaskFilter::new();
+        let sort = if self.sort.is_empty() {
+            &list_settings().sort
+        } else {
+            &self.sort
+        };
+        let mut filter = TaskFilter::new()
+            .with_sort(sort.iter().copied())
+            .with_reverse(self.reverse)
+            .with_offset(self.offset.unwrap_or(0));
+
+        if let Some(limit) = self.limit {
+            filter = filter.with_limit(limit);
+        }
 
         // Add level filters
         if !self.levels.is_empty() {
@@ -173,7 +651,9 @@ impl ListCommand {
 
         // Add tag filters
         if !self.tags.is_empty() {
-            filter = filter.with_tags(self.tags.clone());
+            filter = filter
+                .with_tags(tag_settings().apply_all(&self.tags))
+                .with_tag_mode(self.tag_mode);
         }
 
         // Set structural filters
@@ -185,6 +665,11 @@ impl ListCommand {
             filter = filter.children_of(parent_id);
         }
 
+        // Checked by validate, so an invalid pattern never gets here
+        if let Ok(Some(regex)) = self.compile_title_regex() {
+            filter = filter.with_title_regex(regex);
+        }
+
         // Include done items if --all is specified
         if self.all {
             filter = filter.include_done();
@@ -195,6 +680,39 @@ impl ListCommand {
             filter = filter.with_search(search);
         }
 
+        // Add documentation filters
+        for section_type in &self.section_types {
+            filter = filter.with_section_type(section_type.clone());
+        }
+
+        if self.no_sections {
+            filter = filter.without_sections();
+        }
+
+        if self.has_refs {
+            filter = filter.with_has_refs(true);
+        } else if self.no_refs {
+            filter = filter.with_has_refs(false);
+        }
+
+        if self.overdue {
+            filter = filter.overdue_only();
+        }
+
+        if let Some(time) = self.created_after {
+            filter = filter.created_after(time);
+        }
+        if let Some(time) = self.created_before {
+            filter = filter.created_before(time);
+        }
+        if let Some(time) = self.updated_after {
+            filter = filter.updated_after(time);
+        }
+
+        if self.include_deleted {
+            filter = filter.include_deleted();
+        }
+
         filter
     }
 }
@@ -202,6 +720,7 @@ impl ListCommand {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::output::format_status_summary;
     use std::env;
 
     /// Helper to create a test database
@@ -365,6 +884,31 @@ mod tests {
             children: None,
             all: false,
             search: None,
+            format: OutputFormat::Table,
+            explain: false,
+            section_types: vec![],
+            no_sections: false,
+            has_refs: false,
+            no_refs: false,
+            summary: false,
+            random: None,
+            seed: None,
+            show_blocked: false,
+            sort: vec![],
+            reverse: false,
+            wrap: false,
+            limit: None,
+            offset: None,
+            overdue: false,
+            include_deleted: false,
+            tag_mode: TagMode::Any,
+            columns: vec![],
+            title_regex: None,
+            case_insensitive: false,
+            watch: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
         };
 
         let result = cmd.execute(&db).await.unwrap();
@@ -400,6 +944,31 @@ mod tests {
             children: None,
             all: true,
             search: None,
+            format: OutputFormat::Table,
+            explain: false,
+            section_types: vec![],
+            no_sections: false,
+            has_refs: false,
+            no_refs: false,
+            summary: false,
+            random: None,
+            seed: None,
+            show_blocked: false,
+            sort: vec![],
+            reverse: false,
+            wrap: false,
+            limit: None,
+            offset: None,
+            overdue: false,
+            include_deleted: false,
+            tag_mode: TagMode::Any,
+            columns: vec![],
+            title_regex: None,
+            case_insensitive: false,
+            watch: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
         };
 
         let result = cmd.execute(&db).await.unwrap();
@@ -436,6 +1005,31 @@ mod tests {
             children: None,
             all: false,
             search: None,
+            format: OutputFormat::Table,
+            explain: false,
+            section_types: vec![],
+            no_sections: false,
+            has_refs: false,
+            no_refs: false,
+            summary: false,
+            random: None,
+            seed: None,
+            show_blocked: false,
+            sort: vec![],
+            reverse: false,
+            wrap: false,
+            limit: None,
+            offset: None,
+            overdue: false,
+            include_deleted: false,
+            tag_mode: TagMode::Any,
+            columns: vec![],
+            title_regex: None,
+            case_insensitive: false,
+            watch: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
         };
 
         let result = cmd.execute(&db).await.unwrap();
@@ -463,6 +1057,31 @@ mod tests {
             children: None,
             all: false,
             search: None,
+            format: OutputFormat::Table,
+            explain: false,
+            section_types: vec![],
+            no_sections: false,
+            has_refs: false,
+            no_refs: false,
+            summary: false,
+            random: None,
+            seed: None,
+            show_blocked: false,
+            sort: vec![],
+            reverse: false,
+            wrap: false,
+            limit: None,
+            offset: None,
+            overdue: false,
+            include_deleted: false,
+            tag_mode: TagMode::Any,
+            columns: vec![],
+            title_regex: None,
+            case_insensitive: false,
+            watch: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
         };
 
         let result = cmd.execute(&db).await.unwrap();
@@ -504,6 +1123,31 @@ mod tests {
             children: None,
             all: false,
             search: None,
+            format: OutputFormat::Table,
+            explain: false,
+            section_types: vec![],
+            no_sections: false,
+            has_refs: false,
+            no_refs: false,
+            summary: false,
+            random: None,
+            seed: None,
+            show_blocked: false,
+            sort: vec![],
+            reverse: false,
+            wrap: false,
+            limit: None,
+            offset: None,
+            overdue: false,
+            include_deleted: false,
+            tag_mode: TagMode::Any,
+            columns: vec![],
+            title_regex: None,
+            case_insensitive: false,
+            watch: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
         };
 
         let result = cmd.execute(&db).await.unwrap();
@@ -531,6 +1175,31 @@ mod tests {
             children: None,
             all: false,
             search: None,
+            format: OutputFormat::Table,
+            explain: false,
+            section_types: vec![],
+            no_sections: false,
+            has_refs: false,
+            no_refs: false,
+            summary: false,
+            random: None,
+            seed: None,
+            show_blocked: false,
+            sort: vec![],
+            reverse: false,
+            wrap: false,
+            limit: None,
+            offset: None,
+            overdue: false,
+            include_deleted: false,
+            tag_mode: TagMode::Any,
+            columns: vec![],
+            title_regex: None,
+            case_insensitive: false,
+            watch: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
         };
 
         let result = cmd.execute(&db).await.unwrap();
@@ -541,6 +1210,56 @@ mod tests {
         cleanup(&temp_dir);
     }
 
+    #[tokio::test]
+    async fn test_list_filter_by_tag_matches_normalized_form() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        create_task(&db, "task1", "Task 1", "task", "todo", None, &["back-end"]).await;
+        create_task(&db, "task2", "Task 2", "task", "todo", None, &["backend"]).await;
+
+        let cmd = ListCommand {
+            levels: vec![],
+            statuses: vec![],
+            priorities: vec![],
+            tags: vec![" Back End ".to_string()],
+            root: false,
+            children: None,
+            all: false,
+            search: None,
+            format: OutputFormat::Table,
+            explain: false,
+            section_types: vec![],
+            no_sections: false,
+            has_refs: false,
+            no_refs: false,
+            summary: false,
+            random: None,
+            seed: None,
+            show_blocked: false,
+            sort: vec![],
+            reverse: false,
+            wrap: false,
+            limit: None,
+            offset: None,
+            overdue: false,
+            include_deleted: false,
+            tag_mode: TagMode::Any,
+            columns: vec![],
+            title_regex: None,
+            case_insensitive: false,
+            watch: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
+        };
+
+        let result = cmd.execute(&db).await.unwrap();
+        let ids: Vec<&str> = result.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(ids, vec!["task1"]);
+
+        cleanup(&temp_dir);
+    }
+
     #[tokio::test]
     async fn test_list_filter_by_tag() {
         let (db, temp_dir) = setup_test_db().await;
@@ -567,6 +1286,31 @@ mod tests {
             children: None,
             all: false,
             search: None,
+            format: OutputFormat::Table,
+            explain: false,
+            section_types: vec![],
+            no_sections: false,
+            has_refs: false,
+            no_refs: false,
+            summary: false,
+            random: None,
+            seed: None,
+            show_blocked: false,
+            sort: vec![],
+            reverse: false,
+            wrap: false,
+            limit: None,
+            offset: None,
+            overdue: false,
+            include_deleted: false,
+            tag_mode: TagMode::Any,
+            columns: vec![],
+            title_regex: None,
+            case_insensitive: false,
+            watch: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
         };
 
         let result = cmd.execute(&db).await.unwrap();
@@ -594,6 +1338,16 @@ mod tests {
             "Should not contain task2 (only has frontend tag)"
         );
 
+        // With --tag-mode all, every tag must be present
+        let cmd = ListCommand {
+            tags: vec!["backend".to_string(), "api".to_string()],
+            tag_mode: TagMode::All,
+            ..cmd
+        };
+        let result = cmd.execute(&db).await.unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].id, "task3");
+
         cleanup(&temp_dir);
     }
 
@@ -618,6 +1372,31 @@ mod tests {
             children: None,
             all: false,
             search: None,
+            format: OutputFormat::Table,
+            explain: false,
+            section_types: vec![],
+            no_sections: false,
+            has_refs: false,
+            no_refs: false,
+            summary: false,
+            random: None,
+            seed: None,
+            show_blocked: false,
+            sort: vec![],
+            reverse: false,
+            wrap: false,
+            limit: None,
+            offset: None,
+            overdue: false,
+            include_deleted: false,
+            tag_mode: TagMode::Any,
+            columns: vec![],
+            title_regex: None,
+            case_insensitive: false,
+            watch: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
         };
 
         let result = cmd.execute(&db).await.unwrap();
@@ -654,6 +1433,31 @@ mod tests {
             children: Some("parent1".to_string()),
             all: false,
             search: None,
+            format: OutputFormat::Table,
+            explain: false,
+            section_types: vec![],
+            no_sections: false,
+            has_refs: false,
+            no_refs: false,
+            summary: false,
+            random: None,
+            seed: None,
+            show_blocked: false,
+            sort: vec![],
+            reverse: false,
+            wrap: false,
+            limit: None,
+            offset: None,
+            overdue: false,
+            include_deleted: false,
+            tag_mode: TagMode::Any,
+            columns: vec![],
+            title_regex: None,
+            case_insensitive: false,
+            watch: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
         };
 
         let result = cmd.execute(&db).await.unwrap();
@@ -681,6 +1485,31 @@ mod tests {
             children: Some("nonexistent".to_string()),
             all: false,
             search: None,
+            format: OutputFormat::Table,
+            explain: false,
+            section_types: vec![],
+            no_sections: false,
+            has_refs: false,
+            no_refs: false,
+            summary: false,
+            random: None,
+            seed: None,
+            show_blocked: false,
+            sort: vec![],
+            reverse: false,
+            wrap: false,
+            limit: None,
+            offset: None,
+            overdue: false,
+            include_deleted: false,
+            tag_mode: TagMode::Any,
+            columns: vec![],
+            title_regex: None,
+            case_insensitive: false,
+            watch: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
         };
 
         let result = cmd.execute(&db).await.unwrap();
@@ -704,6 +1533,31 @@ mod tests {
             children: None,
             all: false,
             search: None,
+            format: OutputFormat::Table,
+            explain: false,
+            section_types: vec![],
+            no_sections: false,
+            has_refs: false,
+            no_refs: false,
+            summary: false,
+            random: None,
+            seed: None,
+            show_blocked: false,
+            sort: vec![],
+            reverse: false,
+            wrap: false,
+            limit: None,
+            offset: None,
+            overdue: false,
+            include_deleted: false,
+            tag_mode: TagMode::Any,
+            columns: vec![],
+            title_regex: None,
+            case_insensitive: false,
+            watch: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
         };
 
         let result = cmd.execute(&db).await.unwrap();
@@ -767,6 +1621,31 @@ mod tests {
             children: None,
             all: false,
             search: None,
+            format: OutputFormat::Table,
+            explain: false,
+            section_types: vec![],
+            no_sections: false,
+            has_refs: false,
+            no_refs: false,
+            summary: false,
+            random: None,
+            seed: None,
+            show_blocked: false,
+            sort: vec![],
+            reverse: false,
+            wrap: false,
+            limit: None,
+            offset: None,
+            overdue: false,
+            include_deleted: false,
+            tag_mode: TagMode::Any,
+            columns: vec![],
+            title_regex: None,
+            case_insensitive: false,
+            watch: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
         };
 
         let result = cmd.execute(&db).await.unwrap();
@@ -794,6 +1673,31 @@ mod tests {
             children: None,
             all: false,
             search: None,
+            format: OutputFormat::Table,
+            explain: false,
+            section_types: vec![],
+            no_sections: false,
+            has_refs: false,
+            no_refs: false,
+            summary: false,
+            random: None,
+            seed: None,
+            show_blocked: false,
+            sort: vec![],
+            reverse: false,
+            wrap: false,
+            limit: None,
+            offset: None,
+            overdue: false,
+            include_deleted: false,
+            tag_mode: TagMode::Any,
+            columns: vec![],
+            title_regex: None,
+            case_insensitive: false,
+            watch: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
         };
 
         let result = cmd.execute(&db).await.unwrap();
@@ -815,6 +1719,31 @@ mod tests {
             children: None,
             all: true,
             search: Some("test query".to_string()),
+            format: OutputFormat::Table,
+            explain: false,
+            section_types: vec![],
+            no_sections: false,
+            has_refs: false,
+            no_refs: false,
+            summary: false,
+            random: None,
+            seed: None,
+            show_blocked: false,
+            sort: vec![],
+            reverse: false,
+            wrap: false,
+            limit: None,
+            offset: None,
+            overdue: false,
+            include_deleted: false,
+            tag_mode: TagMode::Any,
+            columns: vec![],
+            title_regex: None,
+            case_insensitive: false,
+            watch: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
         };
 
         let filter = cmd.build_filter();
@@ -840,6 +1769,31 @@ mod tests {
             children: Some("parent123".to_string()),
             all: false,
             search: None,
+            format: OutputFormat::Table,
+            explain: false,
+            section_types: vec![],
+            no_sections: false,
+            has_refs: false,
+            no_refs: false,
+            summary: false,
+            random: None,
+            seed: None,
+            show_blocked: false,
+            sort: vec![],
+            reverse: false,
+            wrap: false,
+            limit: None,
+            offset: None,
+            overdue: false,
+            include_deleted: false,
+            tag_mode: TagMode::Any,
+            columns: vec![],
+            title_regex: None,
+            case_insensitive: false,
+            watch: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
         };
 
         let filter = cmd.build_filter();
@@ -861,6 +1815,31 @@ mod tests {
             children: None,
             all: false,
             search: None,
+            format: OutputFormat::Table,
+            explain: false,
+            section_types: vec![],
+            no_sections: false,
+            has_refs: false,
+            no_refs: false,
+            summary: false,
+            random: None,
+            seed: None,
+            show_blocked: false,
+            sort: vec![],
+            reverse: false,
+            wrap: false,
+            limit: None,
+            offset: None,
+            overdue: false,
+            include_deleted: false,
+            tag_mode: TagMode::Any,
+            columns: vec![],
+            title_regex: None,
+            case_insensitive: false,
+            watch: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
         };
 
         let filter = cmd.build_filter();
@@ -886,6 +1865,8 @@ mod tests {
             priority: Some(Priority::Medium),
             tags: vec!["test".to_string()],
             needs_human_review: Some(true),
+            created_at: None,
+            updated_at: None,
         };
 
         let summary = TaskSummary::from(db_summary);
@@ -899,6 +1880,37 @@ mod tests {
         assert_eq!(summary.needs_human_review, Some(true));
     }
 
+    #[test]
+    fn test_task_summary_with_custom_level_labels_renders_in_table() {
+        let labels = LevelLabels {
+            epic: "initiative".to_string(),
+            ticket: "story".to_string(),
+            task: "task".to_string(),
+        };
+        let db_summary = vertebrae_db::TaskSummary {
+            id: "abc123".to_string(),
+            title: "Checkout flow".to_string(),
+            level: Level::Ticket,
+            status: Status::Todo,
+            priority: None,
+            tags: vec![],
+            needs_human_review: None,
+            created_at: None,
+            updated_at: None,
+        };
+
+        let summary = TaskSummary::with_labels(db_summary, &labels);
+        assert_eq!(summary.level, "story");
+
+        let table = crate::output::format_task_table(&[summary]);
+        assert!(table.contains("story"), "table: {}", table);
+        assert!(!table.contains("ticket"), "table: {}", table);
+
+        // The custom name parses back to the canonical variant
+        assert_eq!(labels.parse("story"), Some(Level::Ticket));
+        assert_eq!(labels.parse("ticket"), Some(Level::Ticket));
+    }
+
     #[test]
     fn test_task_summary_clone() {
         let summary = TaskSummary {
@@ -909,6 +1921,8 @@ mod tests {
             priority: Some("high".to_string()),
             tags: vec!["backend".to_string(), "urgent".to_string()],
             needs_human_review: Some(true),
+            created_at: None,
+            updated_at: None,
         };
 
         let cloned = summary.clone();
@@ -931,6 +1945,8 @@ mod tests {
             priority: Some("high".to_string()),
             tags: vec!["backend".to_string()],
             needs_human_review: Some(true),
+            created_at: None,
+            updated_at: None,
         };
 
         let debug_str = format!("{:?}", summary);
@@ -958,6 +1974,31 @@ mod tests {
             children: Some("parent123".to_string()),
             all: true,
             search: Some("test query".to_string()),
+            format: OutputFormat::Table,
+            explain: false,
+            section_types: vec![],
+            no_sections: false,
+            has_refs: false,
+            no_refs: false,
+            summary: false,
+            random: None,
+            seed: None,
+            show_blocked: false,
+            sort: vec![],
+            reverse: false,
+            wrap: false,
+            limit: None,
+            offset: None,
+            overdue: false,
+            include_deleted: false,
+            tag_mode: TagMode::Any,
+            columns: vec![],
+            title_regex: None,
+            case_insensitive: false,
+            watch: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
         };
 
         let debug_str = format!("{:?}", cmd);
@@ -1040,6 +2081,31 @@ mod tests {
             children: None,
             all: false,
             search: Some("auth".to_string()),
+            format: OutputFormat::Table,
+            explain: false,
+            section_types: vec![],
+            no_sections: false,
+            has_refs: false,
+            no_refs: false,
+            summary: false,
+            random: None,
+            seed: None,
+            show_blocked: false,
+            sort: vec![],
+            reverse: false,
+            wrap: false,
+            limit: None,
+            offset: None,
+            overdue: false,
+            include_deleted: false,
+            tag_mode: TagMode::Any,
+            columns: vec![],
+            title_regex: None,
+            case_insensitive: false,
+            watch: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
         };
 
         let result = cmd.execute(&db).await.unwrap();
@@ -1083,6 +2149,31 @@ mod tests {
             children: None,
             all: false,
             search: Some("authentication".to_string()),
+            format: OutputFormat::Table,
+            explain: false,
+            section_types: vec![],
+            no_sections: false,
+            has_refs: false,
+            no_refs: false,
+            summary: false,
+            random: None,
+            seed: None,
+            show_blocked: false,
+            sort: vec![],
+            reverse: false,
+            wrap: false,
+            limit: None,
+            offset: None,
+            overdue: false,
+            include_deleted: false,
+            tag_mode: TagMode::Any,
+            columns: vec![],
+            title_regex: None,
+            case_insensitive: false,
+            watch: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
         };
 
         let result = cmd.execute(&db).await.unwrap();
@@ -1119,6 +2210,31 @@ mod tests {
             children: None,
             all: false,
             search: Some("authentication".to_string()),
+            format: OutputFormat::Table,
+            explain: false,
+            section_types: vec![],
+            no_sections: false,
+            has_refs: false,
+            no_refs: false,
+            summary: false,
+            random: None,
+            seed: None,
+            show_blocked: false,
+            sort: vec![],
+            reverse: false,
+            wrap: false,
+            limit: None,
+            offset: None,
+            overdue: false,
+            include_deleted: false,
+            tag_mode: TagMode::Any,
+            columns: vec![],
+            title_regex: None,
+            case_insensitive: false,
+            watch: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
         };
 
         let result = cmd.execute(&db).await.unwrap();
@@ -1136,6 +2252,31 @@ mod tests {
             children: None,
             all: false,
             search: Some("AUTHENTICATION".to_string()),
+            format: OutputFormat::Table,
+            explain: false,
+            section_types: vec![],
+            no_sections: false,
+            has_refs: false,
+            no_refs: false,
+            summary: false,
+            random: None,
+            seed: None,
+            show_blocked: false,
+            sort: vec![],
+            reverse: false,
+            wrap: false,
+            limit: None,
+            offset: None,
+            overdue: false,
+            include_deleted: false,
+            tag_mode: TagMode::Any,
+            columns: vec![],
+            title_regex: None,
+            case_insensitive: false,
+            watch: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
         };
 
         let result2 = cmd2.execute(&db).await.unwrap();
@@ -1162,6 +2303,31 @@ mod tests {
             children: None,
             all: false,
             search: Some("nonexistent".to_string()),
+            format: OutputFormat::Table,
+            explain: false,
+            section_types: vec![],
+            no_sections: false,
+            has_refs: false,
+            no_refs: false,
+            summary: false,
+            random: None,
+            seed: None,
+            show_blocked: false,
+            sort: vec![],
+            reverse: false,
+            wrap: false,
+            limit: None,
+            offset: None,
+            overdue: false,
+            include_deleted: false,
+            tag_mode: TagMode::Any,
+            columns: vec![],
+            title_regex: None,
+            case_insensitive: false,
+            watch: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
         };
 
         let result = cmd.execute(&db).await.unwrap();
@@ -1198,6 +2364,31 @@ mod tests {
             children: None,
             all: false,
             search: Some("auth".to_string()),
+            format: OutputFormat::Table,
+            explain: false,
+            section_types: vec![],
+            no_sections: false,
+            has_refs: false,
+            no_refs: false,
+            summary: false,
+            random: None,
+            seed: None,
+            show_blocked: false,
+            sort: vec![],
+            reverse: false,
+            wrap: false,
+            limit: None,
+            offset: None,
+            overdue: false,
+            include_deleted: false,
+            tag_mode: TagMode::Any,
+            columns: vec![],
+            title_regex: None,
+            case_insensitive: false,
+            watch: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
         };
 
         let result = cmd.execute(&db).await.unwrap();
@@ -1226,6 +2417,31 @@ mod tests {
             children: None,
             all: false,
             search: Some("auth".to_string()),
+            format: OutputFormat::Table,
+            explain: false,
+            section_types: vec![],
+            no_sections: false,
+            has_refs: false,
+            no_refs: false,
+            summary: false,
+            random: None,
+            seed: None,
+            show_blocked: false,
+            sort: vec![],
+            reverse: false,
+            wrap: false,
+            limit: None,
+            offset: None,
+            overdue: false,
+            include_deleted: false,
+            tag_mode: TagMode::Any,
+            columns: vec![],
+            title_regex: None,
+            case_insensitive: false,
+            watch: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
         };
 
         let result = cmd.execute(&db).await.unwrap();
@@ -1251,6 +2467,31 @@ mod tests {
             children: None,
             all: false,
             search: Some("".to_string()),
+            format: OutputFormat::Table,
+            explain: false,
+            section_types: vec![],
+            no_sections: false,
+            has_refs: false,
+            no_refs: false,
+            summary: false,
+            random: None,
+            seed: None,
+            show_blocked: false,
+            sort: vec![],
+            reverse: false,
+            wrap: false,
+            limit: None,
+            offset: None,
+            overdue: false,
+            include_deleted: false,
+            tag_mode: TagMode::Any,
+            columns: vec![],
+            title_regex: None,
+            case_insensitive: false,
+            watch: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -1281,6 +2522,31 @@ mod tests {
             children: None,
             all: false,
             search: Some("   ".to_string()),
+            format: OutputFormat::Table,
+            explain: false,
+            section_types: vec![],
+            no_sections: false,
+            has_refs: false,
+            no_refs: false,
+            summary: false,
+            random: None,
+            seed: None,
+            show_blocked: false,
+            sort: vec![],
+            reverse: false,
+            wrap: false,
+            limit: None,
+            offset: None,
+            overdue: false,
+            include_deleted: false,
+            tag_mode: TagMode::Any,
+            columns: vec![],
+            title_regex: None,
+            case_insensitive: false,
+            watch: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -1315,6 +2581,31 @@ mod tests {
             children: None,
             all: false,
             search: Some("auth".to_string()),
+            format: OutputFormat::Table,
+            explain: false,
+            section_types: vec![],
+            no_sections: false,
+            has_refs: false,
+            no_refs: false,
+            summary: false,
+            random: None,
+            seed: None,
+            show_blocked: false,
+            sort: vec![],
+            reverse: false,
+            wrap: false,
+            limit: None,
+            offset: None,
+            overdue: false,
+            include_deleted: false,
+            tag_mode: TagMode::Any,
+            columns: vec![],
+            title_regex: None,
+            case_insensitive: false,
+            watch: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
         };
 
         let result = cmd.execute(&db).await.unwrap();
@@ -1345,6 +2636,31 @@ mod tests {
             children: Some("parent1".to_string()),
             all: false,
             search: Some("auth".to_string()),
+            format: OutputFormat::Table,
+            explain: false,
+            section_types: vec![],
+            no_sections: false,
+            has_refs: false,
+            no_refs: false,
+            summary: false,
+            random: None,
+            seed: None,
+            show_blocked: false,
+            sort: vec![],
+            reverse: false,
+            wrap: false,
+            limit: None,
+            offset: None,
+            overdue: false,
+            include_deleted: false,
+            tag_mode: TagMode::Any,
+            columns: vec![],
+            title_regex: None,
+            case_insensitive: false,
+            watch: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
         };
 
         let result = cmd.execute(&db).await.unwrap();
@@ -1371,6 +2687,31 @@ mod tests {
             children: None,
             all: false,
             search: Some("test\" OR 1=1 --".to_string()),
+            format: OutputFormat::Table,
+            explain: false,
+            section_types: vec![],
+            no_sections: false,
+            has_refs: false,
+            no_refs: false,
+            summary: false,
+            random: None,
+            seed: None,
+            show_blocked: false,
+            sort: vec![],
+            reverse: false,
+            wrap: false,
+            limit: None,
+            offset: None,
+            overdue: false,
+            include_deleted: false,
+            tag_mode: TagMode::Any,
+            columns: vec![],
+            title_regex: None,
+            case_insensitive: false,
+            watch: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
         };
 
         let result = cmd.execute(&db).await.unwrap();
@@ -1400,6 +2741,31 @@ mod tests {
             children: None,
             all: false,
             search: Some("auth".to_string()),
+            format: OutputFormat::Table,
+            explain: false,
+            section_types: vec![],
+            no_sections: false,
+            has_refs: false,
+            no_refs: false,
+            summary: false,
+            random: None,
+            seed: None,
+            show_blocked: false,
+            sort: vec![],
+            reverse: false,
+            wrap: false,
+            limit: None,
+            offset: None,
+            overdue: false,
+            include_deleted: false,
+            tag_mode: TagMode::Any,
+            columns: vec![],
+            title_regex: None,
+            case_insensitive: false,
+            watch: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
         };
 
         let result = cmd.execute(&db).await.unwrap();
@@ -1442,6 +2808,31 @@ mod tests {
             children: None,
             all: false,
             search: None,
+            format: OutputFormat::Table,
+            explain: false,
+            section_types: vec![],
+            no_sections: false,
+            has_refs: false,
+            no_refs: false,
+            summary: false,
+            random: None,
+            seed: None,
+            show_blocked: false,
+            sort: vec![],
+            reverse: false,
+            wrap: false,
+            limit: None,
+            offset: None,
+            overdue: false,
+            include_deleted: false,
+            tag_mode: TagMode::Any,
+            columns: vec![],
+            title_regex: None,
+            case_insensitive: false,
+            watch: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
         };
 
         let result = cmd.execute(&db).await.unwrap();
@@ -1457,4 +2848,991 @@ mod tests {
 
         cleanup(&temp_dir);
     }
+
+    #[tokio::test]
+    async fn test_list_stream_matches_buffered_output() {
+        use crate::output::format_task_list;
+
+        let (db, temp_dir) = setup_test_db().await;
+
+        create_task(&db, "task1", "Plain", "task", "todo", Some("high"), &["a"]).await;
+        create_task(&db, "task2", "Comma, 'quoted'", "ticket", "todo", None, &[]).await;
+        create_task(
+            &db,
+            "task3",
+            "Tagged",
+            "epic",
+            "in_progress",
+            None,
+            &["x", "y"],
+        )
+        .await;
+
+        for format in [OutputFormat::Ndjson, OutputFormat::Csv] {
+            let cmd = ListCommand {
+                levels: vec![],
+                statuses: vec![],
+                priorities: vec![],
+                tags: vec![],
+                root: false,
+                children: None,
+                all: false,
+                search: None,
+                format,
+                explain: false,
+                section_types: vec![],
+                no_sections: false,
+                has_refs: false,
+                no_refs: false,
+                summary: false,
+                random: None,
+                seed: None,
+                show_blocked: false,
+                sort: vec![],
+                reverse: false,
+                wrap: false,
+                limit: None,
+                offset: None,
+                overdue: false,
+                include_deleted: false,
+                tag_mode: TagMode::Any,
+                columns: vec![],
+                title_regex: None,
+                case_insensitive: false,
+                watch: None,
+                created_after: None,
+                created_before: None,
+                updated_after: None,
+            };
+
+            let buffered = format_task_list(&cmd.execute(&db).await.unwrap(), format);
+
+            let mut out = Vec::new();
+            let rows = cmd.stream(&db, &mut out).await.unwrap();
+            let streamed = String::from_utf8(out).unwrap();
+
+            assert_eq!(rows, 3);
+            assert_eq!(streamed.trim_end_matches('\n'), buffered, "{:?}", format);
+        }
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_list_stream_rejects_empty_search() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        let cmd = ListCommand {
+            levels: vec![],
+            statuses: vec![],
+            priorities: vec![],
+            tags: vec![],
+            root: false,
+            children: None,
+            all: false,
+            search: Some("  ".to_string()),
+            format: OutputFormat::Ndjson,
+            explain: false,
+            section_types: vec![],
+            no_sections: false,
+            has_refs: false,
+            no_refs: false,
+            summary: false,
+            random: None,
+            seed: None,
+            show_blocked: false,
+            sort: vec![],
+            reverse: false,
+            wrap: false,
+            limit: None,
+            offset: None,
+            overdue: false,
+            include_deleted: false,
+            tag_mode: TagMode::Any,
+            columns: vec![],
+            title_regex: None,
+            case_insensitive: false,
+            watch: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
+        };
+
+        let result = cmd.stream(&db, Vec::new()).await;
+        assert!(matches!(result, Err(DbError::ValidationError { .. })));
+
+        cleanup(&temp_dir);
+    }
+
+    /// A writer whose reader has gone away, like stdout piped into `head`.
+    struct ClosedPipe;
+
+    impl Write for ClosedPipe {
+        fn write(&mut self, _buf: &[u8]) -> std::io::Result<usize> {
+            Err(std::io::ErrorKind::BrokenPipe.into())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_stream_reports_closed_stdout_as_io_error() {
+        let (db, temp_dir) = setup_test_db().await;
+        create_task(&db, "task1", "Plain", "task", "todo", None, &[]).await;
+
+        let cmd = ListCommand {
+            levels: vec![],
+            statuses: vec![],
+            priorities: vec![],
+            tags: vec![],
+            root: false,
+            children: None,
+            all: false,
+            search: None,
+            format: OutputFormat::Ndjson,
+            explain: false,
+            section_types: vec![],
+            no_sections: false,
+            has_refs: false,
+            no_refs: false,
+            summary: false,
+            random: None,
+            seed: None,
+            show_blocked: false,
+            sort: vec![],
+            reverse: false,
+            wrap: false,
+            limit: None,
+            offset: None,
+            overdue: false,
+            include_deleted: false,
+            tag_mode: TagMode::Any,
+            columns: vec![],
+            title_regex: None,
+            case_insensitive: false,
+            watch: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
+        };
+
+        match cmd.stream(&db, ClosedPipe).await {
+            Err(err @ DbError::Io { .. }) => {
+                assert_eq!(err.kind(), "io");
+                assert!(err.to_string().starts_with("I/O error on stdout"));
+            }
+            other => panic!("Expected Io error, got {:?}", other),
+        }
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_list_explain_emits_status_predicate() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        create_task(&db, "task1", "Task 1", "task", "todo", None, &[]).await;
+
+        let cmd = ListCommand {
+            levels: vec![],
+            statuses: vec![Status::Todo],
+            priorities: vec![],
+            tags: vec![],
+            root: false,
+            children: None,
+            all: false,
+            search: None,
+            format: OutputFormat::Table,
+            explain: true,
+            section_types: vec![],
+            no_sections: false,
+            has_refs: false,
+            no_refs: false,
+            summary: false,
+            random: None,
+            seed: None,
+            show_blocked: false,
+            sort: vec![],
+            reverse: false,
+            wrap: false,
+            limit: None,
+            offset: None,
+            overdue: false,
+            include_deleted: false,
+            tag_mode: TagMode::Any,
+            columns: vec![],
+            title_regex: None,
+            case_insensitive: false,
+            watch: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
+        };
+
+        let query = cmd.explain(&db).unwrap();
+        assert!(query.starts_with("SELECT "), "got: {}", query);
+        assert!(query.contains("status = \"todo\""), "got: {}", query);
+        assert!(!query.contains("task1"));
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_list_explain_validates_options() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        let cmd = ListCommand {
+            levels: vec![],
+            statuses: vec![],
+            priorities: vec![],
+            tags: vec![],
+            root: false,
+            children: None,
+            all: false,
+            search: Some(" ".to_string()),
+            format: OutputFormat::Table,
+            explain: true,
+            section_types: vec![],
+            no_sections: false,
+            has_refs: false,
+            no_refs: false,
+            summary: false,
+            random: None,
+            seed: None,
+            show_blocked: false,
+            sort: vec![],
+            reverse: false,
+            wrap: false,
+            limit: None,
+            offset: None,
+            overdue: false,
+            include_deleted: false,
+            tag_mode: TagMode::Any,
+            columns: vec![],
+            title_regex: None,
+            case_insensitive: false,
+            watch: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
+        };
+
+        assert!(matches!(
+            cmd.explain(&db),
+            Err(DbError::ValidationError { .. })
+        ));
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_list_documentation_filters() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        create_task(&db, "bare", "Bare", "task", "todo", None, &[]).await;
+        create_task(&db, "stepped", "Stepped", "task", "todo", None, &[]).await;
+        create_task(&db, "goal", "Goal only", "task", "todo", None, &[]).await;
+        db.client()
+            .query(
+                r#"UPDATE task:stepped SET
+                    sections = [{ type: "step", content: "Do it", order: 1 }],
+                    refs = [{ path: "src/lib.rs" }];
+                UPDATE task:goal SET sections = [{ type: "goal", content: "Why" }]"#,
+            )
+            .await
+            .unwrap();
+
+        let ids = |tasks: Vec<TaskSummary>| {
+            let mut ids: Vec<String> = tasks.into_iter().map(|t| t.id).collect();
+            ids.sort();
+            ids
+        };
+
+        let mut cmd = empty_list_command();
+        cmd.no_sections = true;
+        assert_eq!(ids(cmd.execute(&db).await.unwrap()), vec!["bare"]);
+
+        let mut cmd = empty_list_command();
+        cmd.section_types = vec![SectionType::Step];
+        assert_eq!(ids(cmd.execute(&db).await.unwrap()), vec!["stepped"]);
+
+        let mut cmd = empty_list_command();
+        cmd.has_refs = true;
+        assert_eq!(ids(cmd.execute(&db).await.unwrap()), vec!["stepped"]);
+
+        let mut cmd = empty_list_command();
+        cmd.no_refs = true;
+        assert_eq!(ids(cmd.execute(&db).await.unwrap()), vec!["bare", "goal"]);
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_list_summary_counts_displayed_rows() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        create_task(&db, "t1", "One", "task", "todo", None, &["api"]).await;
+        create_task(&db, "t2", "Two", "task", "todo", None, &[]).await;
+        create_task(&db, "t3", "Three", "task", "in_progress", None, &["api"]).await;
+        create_task(&db, "t4", "Four", "task", "done", None, &["api"]).await;
+
+        // Done tasks are hidden by default, so they're left out of the summary
+        let tasks = empty_list_command().execute(&db).await.unwrap();
+        assert_eq!(
+            format_status_summary(&tasks),
+            "Total: 3 | todo 2, in_progress 1"
+        );
+
+        let mut cmd = empty_list_command();
+        cmd.all = true;
+        cmd.tags = vec!["api".to_string()];
+        let tasks = cmd.execute(&db).await.unwrap();
+        assert_eq!(
+            format_status_summary(&tasks),
+            "Total: 3 | todo 1, in_progress 1, done 1"
+        );
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_list_summary_rejects_streaming_formats() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        let mut cmd = empty_list_command();
+        cmd.summary = true;
+        cmd.format = OutputFormat::Ndjson;
+        let result = cmd.stream(&db, Vec::new()).await;
+        assert!(
+            matches!(result, Err(DbError::ValidationError { ref message }) if message.contains("--summary")),
+            "got {:?}",
+            result
+        );
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_list_rejects_unsupported_formats() {
+        let (db, temp_dir) = setup_test_db().await;
+        create_task(&db, "t1", "One", "task", "todo", None, &[]).await;
+
+        let mut cmd = empty_list_command();
+        cmd.format = OutputFormat::Markdown;
+        let result = cmd.execute(&db).await;
+        assert!(
+            matches!(result, Err(DbError::ValidationError { ref message })
+                if message.contains("not supported by `list`")),
+            "got {:?}",
+            result
+        );
+
+        cleanup(&temp_dir);
+    }
+
+    #[test]
+    fn test_list_render_dispatches_by_format() {
+        let tasks = [TaskSummary {
+            id: "t1".to_string(),
+            title: "One".to_string(),
+            level: "task".to_string(),
+            status: "todo".to_string(),
+            priority: None,
+            tags: vec![],
+            needs_human_review: None,
+            created_at: None,
+            updated_at: None,
+        }];
+
+        assert!(tasks.render(OutputFormat::Table).unwrap().starts_with("ID"));
+        assert!(
+            tasks
+                .render(OutputFormat::Csv)
+                .unwrap()
+                .starts_with("id,level")
+        );
+        let json = tasks.render(OutputFormat::Ndjson).unwrap();
+        let parsed: TaskSummary = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, tasks[0]);
+        let json = tasks.render(OutputFormat::Json).unwrap();
+        let parsed: Vec<TaskSummary> = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, tasks);
+    }
+
+    #[test]
+    fn test_list_render_json_field_names() {
+        let tasks = [
+            TaskSummary {
+                id: "t1".to_string(),
+                title: "One".to_string(),
+                level: "task".to_string(),
+                status: "todo".to_string(),
+                priority: Some("high".to_string()),
+                tags: vec!["api".to_string()],
+                needs_human_review: Some(true),
+                created_at: None,
+                updated_at: None,
+            },
+            TaskSummary {
+                id: "t2".to_string(),
+                title: "Two".to_string(),
+                level: "epic".to_string(),
+                status: "done".to_string(),
+                priority: None,
+                tags: vec![],
+                needs_human_review: None,
+                created_at: None,
+                updated_at: None,
+            },
+        ];
+
+        let json: serde_json::Value =
+            serde_json::from_str(&tasks.render(OutputFormat::Json).unwrap()).unwrap();
+        let first = json[0].as_object().unwrap();
+        assert_eq!(
+            first.keys().map(String::as_str).collect::<Vec<_>>(),
+            vec![
+                "id",
+                "title",
+                "level",
+                "status",
+                "priority",
+                "tags",
+                "needs_human_review"
+            ]
+        );
+        assert_eq!(json[0]["needs_human_review"], serde_json::Value::Bool(true));
+        assert_eq!(json[1]["needs_human_review"], serde_json::Value::Null);
+        assert_eq!(json[1]["priority"], serde_json::Value::Null);
+    }
+
+    #[test]
+    fn test_list_render_json_empty_is_empty_array() {
+        let tasks: [TaskSummary; 0] = [];
+        assert_eq!(tasks.render(OutputFormat::Json).unwrap(), "[]");
+    }
+
+    /// A list command with no filters set
+    fn empty_list_command() -> ListCommand {
+        ListCommand {
+            levels: vec![],
+            statuses: vec![],
+            priorities: vec![],
+            tags: vec![],
+            root: false,
+            children: None,
+            all: false,
+            search: None,
+            format: OutputFormat::Table,
+            explain: false,
+            section_types: vec![],
+            no_sections: false,
+            has_refs: false,
+            no_refs: false,
+            summary: false,
+            random: None,
+            seed: None,
+            show_blocked: false,
+            sort: vec![],
+            reverse: false,
+            wrap: false,
+            limit: None,
+            offset: None,
+            overdue: false,
+            include_deleted: false,
+            tag_mode: TagMode::Any,
+            columns: vec![],
+            title_regex: None,
+            case_insensitive: false,
+            watch: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_title_regex() {
+        let (db, temp_dir) = setup_test_db().await;
+        create_task(&db, "t1", "API: add auth", "task", "todo", None, &[]).await;
+        create_task(&db, "t2", "api: rate limits", "task", "todo", None, &[]).await;
+        create_task(&db, "t3", "Document the API", "task", "todo", None, &[]).await;
+
+        let regex = |pattern: &str, case_insensitive: bool| ListCommand {
+            title_regex: Some(pattern.to_string()),
+            case_insensitive,
+            ..empty_list_command()
+        };
+        let ids = |tasks: Vec<TaskSummary>| {
+            let mut ids: Vec<String> = tasks.into_iter().map(|t| t.id).collect();
+            ids.sort();
+            ids
+        };
+
+        let result = regex("^API:", false).execute(&db).await.unwrap();
+        assert_eq!(ids(result), vec!["t1"]);
+        let result = regex("^API:", true).execute(&db).await.unwrap();
+        assert_eq!(ids(result), vec!["t1", "t2"]);
+        let result = regex("^nothing$", true).execute(&db).await.unwrap();
+        assert!(result.is_empty());
+
+        match regex("fix(", false).execute(&db).await {
+            Err(DbError::ValidationError { message }) => {
+                assert!(
+                    message.starts_with("Invalid --title-regex pattern 'fix('"),
+                    "got: {}",
+                    message
+                )
+            }
+            other => panic!("Expected ValidationError, got {:?}", other),
+        }
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_list_random_is_deterministic_with_seed_and_filtered() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        for i in 0..12 {
+            let tags: &[&str] = if i % 2 == 0 { &["even"] } else { &[] };
+            let id = format!("task{:02}", i);
+            create_task(&db, &id, &id, "task", "todo", None, tags).await;
+        }
+
+        let cmd = ListCommand {
+            levels: vec![],
+            statuses: vec![],
+            priorities: vec![],
+            tags: vec!["even".to_string()],
+            root: false,
+            children: None,
+            all: false,
+            search: None,
+            format: OutputFormat::Table,
+            explain: false,
+            section_types: vec![],
+            no_sections: false,
+            has_refs: false,
+            no_refs: false,
+            summary: false,
+            random: Some(3),
+            seed: Some(7),
+            show_blocked: false,
+            sort: vec![],
+            reverse: false,
+            wrap: false,
+            limit: None,
+            offset: None,
+            overdue: false,
+            include_deleted: false,
+            tag_mode: TagMode::Any,
+            columns: vec![],
+            title_regex: None,
+            case_insensitive: false,
+            watch: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
+        };
+
+        let first = cmd.execute(&db).await.unwrap();
+        let second = cmd.execute(&db).await.unwrap();
+        assert_eq!(first.len(), 3);
+        assert_eq!(first, second, "same seed should pick the same tasks");
+        assert!(
+            first.iter().all(|t| t.tags.contains(&"even".to_string())),
+            "sample should only contain filtered tasks: {:?}",
+            first
+        );
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_list_limit_offset_and_page_footer() {
+        let (db, temp_dir) = setup_test_db().await;
+        for i in 1..=5 {
+            create_task(&db, &format!("t{}", i), "Task", "task", "todo", None, &[]).await;
+        }
+
+        let cmd = ListCommand {
+            levels: vec![],
+            statuses: vec![],
+            priorities: vec![],
+            tags: vec![],
+            root: false,
+            children: None,
+            all: false,
+            search: None,
+            format: OutputFormat::Table,
+            explain: false,
+            section_types: vec![],
+            no_sections: false,
+            has_refs: false,
+            no_refs: false,
+            summary: false,
+            random: None,
+            seed: None,
+            show_blocked: false,
+            sort: vec![],
+            reverse: false,
+            wrap: false,
+            limit: Some(2),
+            offset: Some(2),
+            overdue: false,
+            include_deleted: false,
+            tag_mode: TagMode::Any,
+            columns: vec![],
+            title_regex: None,
+            case_insensitive: false,
+            watch: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
+        };
+        let page = cmd.execute(&db).await.unwrap();
+        assert_eq!(page.len(), 2);
+        assert_eq!(
+            cmd.page_footer(&db, &page).await.unwrap().as_deref(),
+            Some("showing 3-4 of 5")
+        );
+
+        let cmd = ListCommand {
+            offset: Some(9),
+            ..cmd
+        };
+        let page = cmd.execute(&db).await.unwrap();
+        assert!(page.is_empty());
+        assert_eq!(
+            cmd.page_footer(&db, &page).await.unwrap().as_deref(),
+            Some("showing 0 of 5")
+        );
+
+        // No footer when the listing isn't paged
+        let cmd = ListCommand {
+            limit: None,
+            offset: None,
+            ..cmd
+        };
+        let all = cmd.execute(&db).await.unwrap();
+        assert_eq!(all.len(), 5);
+        assert!(cmd.page_footer(&db, &all).await.unwrap().is_none());
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_list_offset_without_limit_is_rejected() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        let cmd = ListCommand {
+            levels: vec![],
+            statuses: vec![],
+            priorities: vec![],
+            tags: vec![],
+            root: false,
+            children: None,
+            all: false,
+            search: None,
+            format: OutputFormat::Table,
+            explain: false,
+            section_types: vec![],
+            no_sections: false,
+            has_refs: false,
+            no_refs: false,
+            summary: false,
+            random: None,
+            seed: None,
+            show_blocked: false,
+            sort: vec![],
+            reverse: false,
+            wrap: false,
+            limit: None,
+            offset: Some(10),
+            overdue: false,
+            include_deleted: false,
+            tag_mode: TagMode::Any,
+            columns: vec![],
+            title_regex: None,
+            case_insensitive: false,
+            watch: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
+        };
+        match cmd.execute(&db).await {
+            Err(DbError::ValidationError { message }) => {
+                assert_eq!(message, "--offset requires --limit")
+            }
+            other => panic!("Expected ValidationError, got {:?}", other),
+        }
+
+        let cmd = ListCommand {
+            limit: Some(0),
+            offset: None,
+            ..cmd
+        };
+        match cmd.execute(&db).await {
+            Err(DbError::ValidationError { message }) => {
+                assert_eq!(message, "--limit must be at least 1")
+            }
+            other => panic!("Expected ValidationError, got {:?}", other),
+        }
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_list_random_zero_is_rejected() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        let cmd = ListCommand {
+            levels: vec![],
+            statuses: vec![],
+            priorities: vec![],
+            tags: vec![],
+            root: false,
+            children: None,
+            all: false,
+            search: None,
+            format: OutputFormat::Table,
+            explain: false,
+            section_types: vec![],
+            no_sections: false,
+            has_refs: false,
+            no_refs: false,
+            summary: false,
+            random: Some(0),
+            seed: None,
+            show_blocked: false,
+            sort: vec![],
+            reverse: false,
+            wrap: false,
+            limit: None,
+            offset: None,
+            overdue: false,
+            include_deleted: false,
+            tag_mode: TagMode::Any,
+            columns: vec![],
+            title_regex: None,
+            case_insensitive: false,
+            watch: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
+        };
+        assert!(matches!(
+            cmd.execute(&db).await,
+            Err(DbError::ValidationError { .. })
+        ));
+
+        cleanup(&temp_dir);
+    }
+
+    #[test]
+    fn test_next_frame_only_redraws_changes() {
+        let mut shown = None;
+        assert_eq!(
+            next_frame(&mut shown, "a".to_string()),
+            Some(format!("{}a\n", CLEAR_SCREEN))
+        );
+        assert_eq!(next_frame(&mut shown, "a".to_string()), None);
+        assert_eq!(
+            next_frame(&mut shown, "b".to_string()),
+            Some(format!("{}b\n", CLEAR_SCREEN))
+        );
+        assert_eq!(shown.as_deref(), Some("b"));
+    }
+
+    #[test]
+    fn test_sample_keeps_order_and_caps_at_len() {
+        let items: Vec<u32> = (0..20).collect();
+
+        let picked = sample(items.clone(), 5, &mut StdRng::seed_from_u64(1));
+        assert_eq!(picked.len(), 5);
+        assert!(picked.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(
+            picked,
+            sample(items.clone(), 5, &mut StdRng::seed_from_u64(1))
+        );
+
+        let all = sample(items.clone(), 50, &mut StdRng::seed_from_u64(1));
+        assert_eq!(all, items);
+    }
+
+    #[tokio::test]
+    async fn test_list_show_blocked_wrap_and_watch_require_table_format() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        let cmd = ListCommand {
+            levels: vec![],
+            statuses: vec![],
+            priorities: vec![],
+            tags: vec![],
+            root: false,
+            children: None,
+            all: false,
+            search: None,
+            format: OutputFormat::Csv,
+            explain: false,
+            section_types: vec![],
+            no_sections: false,
+            has_refs: false,
+            no_refs: false,
+            summary: false,
+            random: None,
+            seed: None,
+            show_blocked: true,
+            sort: vec![],
+            reverse: false,
+            wrap: false,
+            limit: None,
+            offset: None,
+            overdue: false,
+            include_deleted: false,
+            tag_mode: TagMode::Any,
+            columns: vec![],
+            title_regex: None,
+            case_insensitive: false,
+            watch: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
+        };
+        match cmd.execute(&db).await {
+            Err(DbError::ValidationError { message }) => {
+                assert!(message.contains("--show-blocked"), "got: {}", message)
+            }
+            other => panic!("Expected ValidationError, got {:?}", other),
+        }
+
+        let cmd = ListCommand {
+            show_blocked: false,
+            wrap: true,
+            format: OutputFormat::Json,
+            ..cmd
+        };
+        match cmd.execute(&db).await {
+            Err(DbError::ValidationError { message }) => {
+                assert_eq!(message, "--wrap is only supported with the table format")
+            }
+            other => panic!("Expected ValidationError, got {:?}", other),
+        }
+
+        // Streaming formats must not swallow --watch
+        let cmd = ListCommand {
+            wrap: false,
+            watch: Some(2),
+            format: OutputFormat::Ndjson,
+            ..cmd
+        };
+        match cmd.stream(&db, Vec::new()).await {
+            Err(DbError::ValidationError { message }) => {
+                assert_eq!(message, "--watch is only supported with the table format")
+            }
+            other => panic!("Expected ValidationError, got {:?}", other),
+        }
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_list_default_sort_and_sort_override() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        // Created in this order, so newest first is the reverse
+        create_task(&db, "low1", "Low", "task", "todo", Some("low"), &[]).await;
+        create_task(
+            &db,
+            "crit1",
+            "Critical",
+            "task",
+            "todo",
+            Some("critical"),
+            &[],
+        )
+        .await;
+        create_task(&db, "none1", "None", "task", "todo", None, &[]).await;
+        create_task(&db, "high1", "High", "task", "todo", Some("high"), &[]).await;
+        db.client()
+            .query("UPDATE task:low1 SET created_at = d'2024-01-01T00:00:00Z'; UPDATE task:crit1 SET created_at = d'2024-01-02T00:00:00Z'; UPDATE task:none1 SET created_at = d'2024-01-03T00:00:00Z'; UPDATE task:high1 SET created_at = d'2024-01-04T00:00:00Z'")
+            .await
+            .unwrap();
+
+        let mut cmd = ListCommand {
+            levels: vec![],
+            statuses: vec![],
+            priorities: vec![],
+            tags: vec![],
+            root: false,
+            children: None,
+            all: false,
+            search: None,
+            format: OutputFormat::Table,
+            explain: false,
+            section_types: vec![],
+            no_sections: false,
+            has_refs: false,
+            no_refs: false,
+            summary: false,
+            random: None,
+            seed: None,
+            show_blocked: false,
+            sort: vec![],
+            reverse: false,
+            wrap: false,
+            limit: None,
+            offset: None,
+            overdue: false,
+            include_deleted: false,
+            tag_mode: TagMode::Any,
+            columns: vec![],
+            title_regex: None,
+            case_insensitive: false,
+            watch: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
+        };
+
+        // No --sort: the configured default, priority then updated
+        assert_eq!(
+            list_settings().sort,
+            vec![SortKey::Priority, SortKey::Updated]
+        );
+        let ids: Vec<String> = cmd
+            .execute(&db)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|t| t.id)
+            .collect();
+        assert_eq!(ids, vec!["crit1", "high1", "low1", "none1"]);
+
+        cmd.sort = vec![SortKey::Created];
+        let ids: Vec<String> = cmd
+            .execute(&db)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|t| t.id)
+            .collect();
+        assert_eq!(ids, vec!["high1", "none1", "crit1", "low1"]);
+
+        cleanup(&temp_dir);
+    }
+
+    #[test]
+    fn test_parse_sort_key() {
+        assert_eq!(parse_sort_key("Priority").unwrap(), SortKey::Priority);
+        assert_eq!(parse_sort_key(" created").unwrap(), SortKey::Created);
+        assert_eq!(parse_sort_key("title").unwrap(), SortKey::Title);
+        assert_eq!(
+            parse_sort_key("assignee").unwrap_err(),
+            "invalid sort key 'assignee'. Valid values: priority, updated, created, title, status"
+        );
+    }
 }
@@ -3,23 +3,40 @@
 //! This module contains all subcommand implementations for the vtb CLI.
 
 pub mod add;
+pub mod blocked;
 pub mod blockers;
+pub mod board;
+pub mod bottlenecks;
+pub mod clone;
+pub mod commits;
+pub mod count;
 pub mod criterion_ref;
+pub mod critical_path;
 pub mod delete;
 pub mod depend;
+pub mod doctor;
 pub mod export;
+pub mod graph;
 pub mod import;
+pub mod info;
 pub mod init;
 pub mod list;
+pub mod move_task;
+pub mod next;
 pub mod path;
+pub mod plan;
 pub mod ready;
 pub mod r#ref;
 pub mod refs;
+pub mod rename;
+pub mod restore;
 pub mod review;
+pub mod search;
 pub mod section;
 pub mod sections;
 pub mod show;
 pub mod step_done;
+pub mod tags;
 pub mod transition_to;
 pub mod undepend;
 pub mod unref;
@@ -27,31 +44,51 @@ pub mod unsection;
 pub mod update;
 
 pub use add::AddCommand;
+pub use blocked::BlockedCommand;
 pub use blockers::BlockersCommand;
+pub use board::BoardCommand;
+pub use bottlenecks::BottlenecksCommand;
+pub use clone::CloneCommand;
+pub use commits::CommitsCommand;
+pub use count::CountCommand;
 pub use criterion_ref::CriterionRefCommand;
+pub use critical_path::CriticalPathCommand;
 pub use delete::DeleteCommand;
 pub use depend::DependCommand;
+pub use doctor::DoctorCommand;
 pub use export::ExportCommand;
+pub use graph::GraphCommand;
 pub use import::ImportCommand;
+pub use info::InfoCommand;
 pub use init::InitCommand;
 pub use list::ListCommand;
+pub use move_task::MoveCommand;
+pub use next::NextCommand;
 pub use path::PathCommand;
+pub use plan::PlanCommand;
 pub use ready::ReadyCommand;
 pub use r#ref::RefCommand;
 pub use refs::RefsCommand;
+pub use rename::RenameCommand;
+pub use restore::RestoreCommand;
 pub use review::ReviewCommand;
+pub use search::SearchCommand;
 pub use section::SectionCommand;
 pub use sections::SectionsCommand;
 pub use show::ShowCommand;
 pub use step_done::StepDoneCommand;
+pub use tags::TagsCommand;
 pub use transition_to::TransitionToCommand;
 pub use undepend::UndependCommand;
 pub use unref::UnrefCommand;
 pub use unsection::UnsectionCommand;
 pub use update::UpdateCommand;
 
-use crate::output::format_task_table;
+use crate::output::{OutputFormat, Render, error_to_json, to_json};
+use crate::stdin_ids::{BatchResult, IdInput, is_stdin_id, run_input};
 use clap::Subcommand;
+use std::future::Future;
+use std::time::Duration;
 use vertebrae_db::{Database, DbError};
 
 /// Available CLI commands
@@ -59,39 +96,76 @@ use vertebrae_db::{Database, DbError};
 pub enum Command {
     /// Create a new task
     Add(AddCommand),
+    /// List tasks waiting on incomplete blockers, most-blocked first
+    Blocked(BlockedCommand),
     /// Show all tasks blocking a given task (recursive)
     Blockers(BlockersCommand),
+    /// Show tasks as a Kanban board with one column per status
+    Board(BoardCommand),
+    /// Show the open tasks that the most other tasks depend on
+    Bottlenecks(BottlenecksCommand),
+    /// Copy a task (and optionally its subtree) into a new task
+    Clone(CloneCommand),
+    /// Report tasks mentioned in git commits since a ref
+    Commits(CommitsCommand),
+    /// Count tasks grouped by status, level, priority or tag
+    Count(CountCommand),
     /// Add a code reference to a testing criterion
     #[command(name = "criterion-ref")]
     CriterionRef(CriterionRefCommand),
-    /// Delete a task (with optional cascade)
+    /// Show the longest dependency chain below a task
+    #[command(name = "critical-path")]
+    CriticalPath(CriticalPathCommand),
+    /// Move a task to the trash (undo with restore, or delete permanently with --hard)
     Delete(DeleteCommand),
     /// Create a dependency relationship between tasks
     Depend(DependCommand),
-    /// Export all tasks and relationships to JSONL format
+    /// Check the database for inconsistencies such as self-referencing edges
+    Doctor(DoctorCommand),
+    /// Export tasks and relationships as JSONL, or tasks as CSV
     Export(ExportCommand),
-    /// Import tasks and relationships from JSONL format
+    /// Export the dependency graph as Graphviz DOT or Mermaid
+    Graph(GraphCommand),
+    /// Import a JSONL export, or a JSON array of new tasks with --batch
     Import(ImportCommand),
+    /// Show where the database lives, its size and what it contains
+    Info(InfoCommand),
     /// Initialize vertebrae in the current project
     Init(InitCommand),
     /// List tasks with optional filters
     List(ListCommand),
+    /// Move a task under a new parent, or to the root level
+    #[command(visible_alias = "reparent")]
+    Move(MoveCommand),
+    /// Suggest the single unblocked todo task to work on next
+    Next(NextCommand),
     /// Find the dependency path between two tasks
     Path(PathCommand),
+    /// List all tasks in dependency order as a suggested work sequence
+    Plan(PlanCommand),
     /// Show highest-level actionable items (entry points for work/triage)
     Ready(ReadyCommand),
     /// Add a code reference to a task
     Ref(RefCommand),
     /// List all code references for a task
     Refs(RefsCommand),
+    /// Change a task's title
+    #[command(visible_alias = "set-title")]
+    Rename(RenameCommand),
+    /// Restore a deleted task from the trash
+    Restore(RestoreCommand),
     /// Toggle or set the needs_human_review flag on a task
     Review(ReviewCommand),
     /// Add a typed content section to a task
     Section(SectionCommand),
     /// List all sections for a task
     Sections(SectionsCommand),
+    /// Search titles, descriptions and sections, best matches first
+    Search(SearchCommand),
     /// Show full details of a task
     Show(ShowCommand),
+    /// List all tags with the number of tasks using each
+    Tags(TagsCommand),
     /// Remove a dependency relationship between tasks
     Undepend(UndependCommand),
     /// Remove code references from a task
@@ -114,6 +188,49 @@ pub enum CommandResult {
     Message(String),
     /// A formatted table to display
     Table(String),
+    /// Output was already written incrementally (nothing left to print)
+    Streamed,
+    /// One command run per task ID read from stdin
+    Batch(BatchResult),
+}
+
+impl CommandResult {
+    /// The result as printed under the global `--json` flag.
+    ///
+    /// `rendered` says the command was asked for JSON output (see
+    /// [`Command::renders_json`]); JSON it produced is passed through as
+    /// is. Any other text is wrapped as `{"output": "..."}`, and each ID
+    /// of a batch becomes an `{"id", "output"}` or `{"id", "error"}` object.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError::Io` if serialization fails.
+    pub fn to_json(&self, rendered: bool) -> Result<String, DbError> {
+        let output = |text: &str| {
+            rendered
+                .then(|| serde_json::from_str(text).ok())
+                .flatten()
+                .unwrap_or_else(|| serde_json::Value::from(text))
+        };
+        match self {
+            CommandResult::Message(text) | CommandResult::Table(text) => match output(text) {
+                serde_json::Value::String(text) => to_json(&serde_json::json!({ "output": text })),
+                _ => Ok(text.clone()),
+            },
+            CommandResult::Streamed => Ok(String::new()),
+            CommandResult::Batch(batch) => {
+                let outcomes: Vec<serde_json::Value> = batch
+                    .outcomes
+                    .iter()
+                    .map(|(id, outcome)| match outcome {
+                        Ok(text) => serde_json::json!({ "id": id, "output": output(text) }),
+                        Err(e) => serde_json::json!({ "id": id, "error": error_to_json(e) }),
+                    })
+                    .collect();
+                to_json(&outcomes)
+            }
+        }
+    }
 }
 
 impl std::fmt::Display for CommandResult {
@@ -121,11 +238,46 @@ impl std::fmt::Display for CommandResult {
         match self {
             CommandResult::Message(msg) => write!(f, "{}", msg),
             CommandResult::Table(table) => write!(f, "{}", table),
+            CommandResult::Streamed => Ok(()),
+            CommandResult::Batch(batch) => write!(f, "{}", batch),
         }
     }
 }
 
 impl Command {
+    /// Whether the command can format its own output as JSON, through a
+    /// `--format` option.
+    pub fn renders_json(&self) -> bool {
+        matches!(
+            self,
+            Command::Blocked(_)
+                | Command::Blockers(_)
+                | Command::Board(_)
+                | Command::Bottlenecks(_)
+                | Command::Count(_)
+                | Command::Graph(_)
+                | Command::List(_)
+                | Command::Show(_)
+        )
+    }
+
+    /// Switch a command that [renders JSON](Command::renders_json) to its
+    /// JSON format, overriding `--format`. Other commands are unchanged.
+    pub fn use_json_format(&mut self) {
+        let format = match self {
+            Command::Blocked(cmd) => &mut cmd.format,
+            Command::Blockers(cmd) => &mut cmd.format,
+            Command::Board(cmd) => &mut cmd.format,
+            Command::Bottlenecks(cmd) => &mut cmd.format,
+            Command::Count(cmd) => &mut cmd.format,
+            Command::Graph(cmd) => &mut cmd.format,
+            Command::List(cmd) => &mut cmd.format,
+            Command::Show(cmd) => &mut cmd.format,
+            _ => return,
+        };
+        *format = OutputFormat::Json;
+    }
+
     /// Execute the command with the given database connection.
     ///
     /// # Arguments
@@ -136,35 +288,96 @@ impl Command {
     ///
     /// Returns `DbError` if the command execution fails.
     pub async fn execute(&self, db: &Database) -> Result<CommandResult, DbError> {
+        self.execute_with_input(db, IdInput::Stdin).await
+    }
+
+    /// Execute the command, reading task IDs from `input` when a
+    /// single-task command is given `-` as its ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError` if the command execution fails. When running over
+    /// several IDs, only failing to read them is an error; each ID's failure
+    /// is reported in the [`CommandResult::Batch`].
+    pub async fn execute_with_input(
+        &self,
+        db: &Database,
+        input: IdInput<'_>,
+    ) -> Result<CommandResult, DbError> {
         match self {
             Command::Add(cmd) => {
                 let id = cmd.execute(db).await?;
                 Ok(CommandResult::Message(format!("Created task: {}", id)))
             }
+            Command::Blocked(cmd) => {
+                let result = cmd.execute(db).await?;
+                Ok(CommandResult::Message(result.render(cmd.format)?))
+            }
             Command::Blockers(cmd) => {
+                if cmd.explain {
+                    return Ok(CommandResult::Message(cmd.explain(db).await?));
+                }
+                let result = cmd.execute(db).await?;
+                Ok(CommandResult::Message(result.render(cmd.format)?))
+            }
+            Command::Board(cmd) => {
+                let result = cmd.execute(db).await?;
+                Ok(CommandResult::Message(result.render(cmd.format)?))
+            }
+            Command::Bottlenecks(cmd) => {
+                let result = cmd.execute(db).await?;
+                Ok(CommandResult::Message(result.render(cmd.format)?))
+            }
+            Command::Clone(cmd) => {
+                let result = cmd.execute(db).await?;
+                Ok(CommandResult::Message(format!("{}", result)))
+            }
+            Command::Commits(cmd) => {
                 let result = cmd.execute(db).await?;
                 Ok(CommandResult::Message(format!("{}", result)))
             }
+            Command::Count(cmd) => {
+                let result = cmd.execute(db).await?;
+                Ok(CommandResult::Message(result.render(cmd.format)?))
+            }
             Command::CriterionRef(cmd) => {
                 let result = cmd.execute(db).await?;
                 Ok(CommandResult::Message(format!("{}", result)))
             }
+            Command::CriticalPath(cmd) => {
+                let result = cmd.execute(db).await?;
+                Ok(CommandResult::Message(format!("{}", result)))
+            }
             Command::Delete(cmd) => {
-                let message = cmd.execute(db).await?;
-                Ok(CommandResult::Message(message))
+                for_each_id(&cmd.id, input, |id| async move {
+                    DeleteCommand { id, ..cmd.clone() }.execute(db).await
+                })
+                .await
             }
             Command::Depend(cmd) => {
                 let result = cmd.execute(db).await?;
                 Ok(CommandResult::Message(format!("{}", result)))
             }
+            Command::Doctor(cmd) => {
+                let result = cmd.execute(db).await?;
+                Ok(CommandResult::Message(format!("{}", result)))
+            }
             Command::Export(cmd) => {
                 let result = cmd.execute(db).await?;
                 Ok(CommandResult::Message(format!("{}", result)))
             }
+            Command::Graph(cmd) => {
+                let result = cmd.execute(db).await?;
+                Ok(CommandResult::Message(result.render(cmd.format)?))
+            }
             Command::Import(cmd) => {
                 let result = cmd.execute(db).await?;
                 Ok(CommandResult::Message(format!("{}", result)))
             }
+            Command::Info(cmd) => {
+                let result = cmd.execute(db).await?;
+                Ok(CommandResult::Message(format!("{}", result)))
+            }
             Command::Init(cmd) => {
                 // Init doesn't use the database - it creates the db directory
                 let result = cmd.execute().map_err(|e| DbError::InvalidPath {
@@ -174,10 +387,38 @@ impl Command {
                 Ok(CommandResult::Message(format!("{}", result)))
             }
             Command::List(cmd) => {
-                let tasks = cmd.execute(db).await?;
-                Ok(CommandResult::Table(format_task_table(&tasks)))
+                if cmd.explain {
+                    return Ok(CommandResult::Message(cmd.explain(db)?));
+                }
+                // A random sample needs every match before it can pick
+                if cmd.format.is_streaming() && cmd.random.is_none() {
+                    cmd.stream(db, std::io::BufWriter::new(std::io::stdout()))
+                        .await?;
+                    return Ok(CommandResult::Streamed);
+                }
+                if let Some(seconds) = cmd.watch {
+                    cmd.watch(db, Duration::from_secs(seconds), std::io::stdout())
+                        .await?;
+                    return Ok(CommandResult::Streamed);
+                }
+                Ok(CommandResult::Table(cmd.render(db).await?))
+            }
+            Command::Move(cmd) => {
+                let result = cmd.execute(db).await?;
+                Ok(CommandResult::Message(format!("{}", result)))
+            }
+            Command::Next(cmd) => {
+                let result = cmd.execute(db).await?;
+                Ok(CommandResult::Message(format!("{}", result)))
             }
             Command::Path(cmd) => {
+                if cmd.explain {
+                    return Ok(CommandResult::Message(cmd.explain(db).await?));
+                }
+                let result = cmd.execute(db).await?;
+                Ok(CommandResult::Message(format!("{}", result)))
+            }
+            Command::Plan(cmd) => {
                 let result = cmd.execute(db).await?;
                 Ok(CommandResult::Message(format!("{}", result)))
             }
@@ -193,9 +434,19 @@ impl Command {
                 let result = cmd.execute(db).await?;
                 Ok(CommandResult::Message(format!("{}", result)))
             }
-            Command::Review(cmd) => {
+            Command::Rename(cmd) => {
                 let result = cmd.execute(db).await?;
-                Ok(CommandResult::Message(result))
+                Ok(CommandResult::Message(format!("{}", result)))
+            }
+            Command::Restore(cmd) => {
+                let result = cmd.execute(db).await?;
+                Ok(CommandResult::Message(format!("{}", result)))
+            }
+            Command::Review(cmd) => {
+                for_each_id(&cmd.id, input, |id| async move {
+                    ReviewCommand { id, ..cmd.clone() }.execute(db).await
+                })
+                .await
             }
             Command::Section(cmd) => {
                 let result = cmd.execute(db).await?;
@@ -205,9 +456,27 @@ impl Command {
                 let result = cmd.execute(db).await?;
                 Ok(CommandResult::Message(format!("{}", result)))
             }
+            Command::Show(cmd) if cmd.tree => {
+                for_each_id(&cmd.id, input, |id| async move {
+                    let tree = ShowCommand { id, ..cmd.clone() }.execute_tree(db).await?;
+                    tree.render(cmd.format)
+                })
+                .await
+            }
             Command::Show(cmd) => {
-                let detail = cmd.execute(db).await?;
-                Ok(CommandResult::Message(format!("{}", detail)))
+                for_each_id(&cmd.id, input, |id| async move {
+                    let detail = ShowCommand { id, ..cmd.clone() }.execute(db).await?;
+                    detail.render_view(cmd.format, cmd.view())
+                })
+                .await
+            }
+            Command::Search(cmd) => {
+                let result = cmd.execute(db).await?;
+                Ok(CommandResult::Message(format!("{}", result)))
+            }
+            Command::Tags(cmd) => {
+                let result = cmd.execute(db).await?;
+                Ok(CommandResult::Message(format!("{}", result)))
             }
             Command::Undepend(cmd) => {
                 let result = cmd.execute(db).await?;
@@ -226,20 +495,47 @@ impl Command {
                 Ok(CommandResult::Message(format!("{}", result)))
             }
             Command::TransitionTo(cmd) => {
-                let result = cmd.execute(db).await?;
-                Ok(CommandResult::Message(format!("{}", result)))
+                for_each_id(&cmd.id, input, |id| async move {
+                    let result = TransitionToCommand { id, ..cmd.clone() }
+                        .execute(db)
+                        .await?;
+                    Ok(format!("{}", result))
+                })
+                .await
             }
             Command::Update(cmd) => {
-                let id = cmd.execute(db).await?;
-                Ok(CommandResult::Message(format!("Updated task: {}", id)))
+                for_each_id(&cmd.id, input, |id| async move {
+                    let id = UpdateCommand { id, ..cmd.clone() }.execute(db).await?;
+                    Ok(format!("Updated task: {}", id))
+                })
+                .await
             }
         }
     }
 }
 
+/// Run a single-task command for its ID, or once for each ID in `input`
+/// when the ID is `-`.
+async fn for_each_id<F, Fut>(
+    id: &str,
+    input: IdInput<'_>,
+    mut run: F,
+) -> Result<CommandResult, DbError>
+where
+    F: FnMut(String) -> Fut,
+    Fut: Future<Output = Result<String, DbError>>,
+{
+    if is_stdin_id(id) {
+        Ok(CommandResult::Batch(run_input(input, run).await?))
+    } else {
+        Ok(CommandResult::Message(run(id.to_string()).await?))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::output::Column;
     use clap::Parser;
 
     /// Test struct to parse commands
@@ -261,6 +557,518 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_command_clone_parses() {
+        let cli = TestCli::try_parse_from(["test", "clone", "abc123", "--with-children"]).unwrap();
+        match cli.command {
+            Command::Clone(cmd) => {
+                assert_eq!(cmd.id, "abc123");
+                assert!(cmd.with_children);
+            }
+            _ => panic!("Expected Clone command"),
+        }
+        assert!(TestCli::try_parse_from(["test", "clone"]).is_err());
+    }
+
+    #[test]
+    fn test_command_commits_parses() {
+        let cli = TestCli::try_parse_from(["test", "commits", "--since-commit", "v1.0"]);
+        assert!(cli.is_ok());
+        match cli.unwrap().command {
+            Command::Commits(cmd) => {
+                assert_eq!(cmd.since_commit, "v1.0");
+                assert!(!cmd.apply);
+                assert_eq!(cmd.to, transition_to::TargetStatus::InProgress);
+            }
+            _ => panic!("Expected Commits command"),
+        }
+    }
+
+    #[test]
+    fn test_command_commits_requires_since() {
+        let cli = TestCli::try_parse_from(["test", "commits"]);
+        assert!(cli.is_err());
+    }
+
+    #[test]
+    fn test_command_blocked_parses() {
+        let cli = TestCli::try_parse_from(["test", "blocked", "--format", "json"]).unwrap();
+        match cli.command {
+            Command::Blocked(cmd) => assert_eq!(cmd.format, crate::output::OutputFormat::Json),
+            _ => panic!("Expected Blocked command"),
+        }
+    }
+
+    #[test]
+    fn test_command_bottlenecks_parses() {
+        let cli = TestCli::try_parse_from(["test", "bottlenecks"]).unwrap();
+        match cli.command {
+            Command::Bottlenecks(cmd) => assert_eq!(cmd.top, 10),
+            _ => panic!("Expected Bottlenecks command"),
+        }
+        let cli = TestCli::try_parse_from(["test", "bottlenecks", "--top", "3"]).unwrap();
+        match cli.command {
+            Command::Bottlenecks(cmd) => assert_eq!(cmd.top, 3),
+            _ => panic!("Expected Bottlenecks command"),
+        }
+    }
+
+    #[test]
+    fn test_command_blockers_paths_parses() {
+        let cli =
+            TestCli::try_parse_from(["test", "blockers", "abc", "--paths", "--max-paths", "5"]);
+        assert!(cli.is_ok());
+        match cli.unwrap().command {
+            Command::Blockers(cmd) => {
+                assert!(cmd.paths);
+                assert_eq!(cmd.max_paths, 5);
+            }
+            _ => panic!("Expected Blockers command"),
+        }
+    }
+
+    #[test]
+    fn test_command_blockers_tree_chars_parses() {
+        let cli = TestCli::try_parse_from(["test", "blockers", "abc", "--tree-chars", "ascii"]);
+        match cli.unwrap().command {
+            Command::Blockers(cmd) => {
+                assert_eq!(cmd.tree_chars, Some(vertebrae_db::TreeChars::Ascii))
+            }
+            _ => panic!("Expected Blockers command"),
+        }
+        assert!(
+            TestCli::try_parse_from(["test", "blockers", "abc", "--tree-chars", "boxes"]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_command_list_columns() {
+        let cli =
+            TestCli::try_parse_from(["test", "list", "--columns", "id,Title, status"]).unwrap();
+        match cli.command {
+            Command::List(cmd) => {
+                assert_eq!(cmd.columns, vec![Column::Id, Column::Title, Column::Status]);
+                assert_eq!(cmd.table_columns(), cmd.columns);
+            }
+            _ => panic!("Expected List command"),
+        }
+
+        let cli = TestCli::try_parse_from(["test", "list", "--show-blocked"]).unwrap();
+        match cli.command {
+            Command::List(cmd) => assert_eq!(
+                cmd.table_columns(),
+                crate::output::with_blocked_column(&Column::DEFAULT)
+            ),
+            _ => panic!("Expected List command"),
+        }
+
+        let Err(err) = TestCli::try_parse_from(["test", "list", "--columns", "id,assignee"]) else {
+            panic!("Expected an invalid column error");
+        };
+        let err = err.to_string();
+        assert!(err.contains("invalid column 'assignee'"), "got: {}", err);
+        assert!(err.contains("id, level, status, blocked"), "got: {}", err);
+    }
+
+    #[test]
+    fn test_command_list_title_regex() {
+        let cli = TestCli::try_parse_from([
+            "test",
+            "list",
+            "--title-regex",
+            "^fix",
+            "--case-insensitive",
+        ])
+        .unwrap();
+        match cli.command {
+            Command::List(cmd) => {
+                assert_eq!(cmd.title_regex.as_deref(), Some("^fix"));
+                assert!(cmd.case_insensitive);
+            }
+            _ => panic!("Expected List command"),
+        }
+        // --case-insensitive only applies to --title-regex
+        assert!(TestCli::try_parse_from(["test", "list", "--case-insensitive"]).is_err());
+    }
+
+    #[test]
+    fn test_command_list_watch() {
+        let watch = |args: &[&str]| match TestCli::try_parse_from(args).unwrap().command {
+            Command::List(cmd) => cmd.watch,
+            _ => panic!("Expected List command"),
+        };
+        assert_eq!(watch(&["test", "list"]), None);
+        assert_eq!(watch(&["test", "list", "--watch"]), Some(2));
+        assert_eq!(watch(&["test", "list", "--watch", "10"]), Some(10));
+        assert!(TestCli::try_parse_from(["test", "list", "--watch", "0"]).is_err());
+        assert!(TestCli::try_parse_from(["test", "list", "--watch", "--explain"]).is_err());
+    }
+
+    #[test]
+    fn test_use_json_format_overrides_format() {
+        let mut command = TestCli::try_parse_from(["test", "list", "--format", "csv"])
+            .unwrap()
+            .command;
+        assert!(command.renders_json());
+        command.use_json_format();
+        match command {
+            Command::List(cmd) => assert_eq!(cmd.format, OutputFormat::Json),
+            _ => panic!("Expected List command"),
+        }
+
+        let mut command = TestCli::try_parse_from(["test", "add", "Task"])
+            .unwrap()
+            .command;
+        assert!(!command.renders_json());
+        command.use_json_format();
+    }
+
+    #[test]
+    fn test_command_result_to_json() {
+        let message = CommandResult::Message("Created task abc123".to_string());
+        assert_eq!(
+            message.to_json(false).unwrap(),
+            "{\n  \"output\": \"Created task abc123\"\n}"
+        );
+
+        // JSON the command rendered itself passes through
+        let table = CommandResult::Table("[\n  1\n]".to_string());
+        assert_eq!(table.to_json(true).unwrap(), "[\n  1\n]");
+        // Text from a JSON-capable command, e.g. a --wrap table, is still wrapped
+        let table = CommandResult::Table("ID  Title".to_string());
+        assert!(
+            table
+                .to_json(true)
+                .unwrap()
+                .contains("\"output\": \"ID  Title\"")
+        );
+
+        let batch = CommandResult::Batch(BatchResult {
+            outcomes: vec![
+                ("abc123".to_string(), Ok("{\"id\": \"abc123\"}".to_string())),
+                (
+                    "zzz999".to_string(),
+                    Err(DbError::NotFound {
+                        task_id: "zzz999".to_string(),
+                    }),
+                ),
+            ],
+        });
+        let value: serde_json::Value = serde_json::from_str(&batch.to_json(true).unwrap()).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!([
+                { "id": "abc123", "output": { "id": "abc123" } },
+                {
+                    "id": "zzz999",
+                    "error": {
+                        "kind": "not_found",
+                        "code": 200,
+                        "message": "Task 'zzz999' not found; run `vtb list` to see task IDs",
+                    },
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn test_command_list_tag_mode() {
+        let cli = TestCli::try_parse_from(["test", "list", "-t", "a"]).unwrap();
+        match cli.command {
+            Command::List(cmd) => assert_eq!(cmd.tag_mode, vertebrae_db::TagMode::Any),
+            _ => panic!("Expected List command"),
+        }
+
+        let cli = TestCli::try_parse_from(["test", "list", "--tag-mode", "ALL"]).unwrap();
+        match cli.command {
+            Command::List(cmd) => assert_eq!(cmd.tag_mode, vertebrae_db::TagMode::All),
+            _ => panic!("Expected List command"),
+        }
+
+        assert!(TestCli::try_parse_from(["test", "list", "--tag-mode", "some"]).is_err());
+    }
+
+    #[test]
+    fn test_command_list_sort() {
+        let cli = TestCli::try_parse_from(["test", "list", "--sort", "updated,created"]).unwrap();
+        match cli.command {
+            Command::List(cmd) => assert_eq!(
+                cmd.sort,
+                vec![
+                    vertebrae_db::SortKey::Updated,
+                    vertebrae_db::SortKey::Created
+                ]
+            ),
+            _ => panic!("Expected List command"),
+        }
+
+        let result = TestCli::try_parse_from(["test", "list", "--sort", "assignee"]);
+        assert!(result.is_err());
+
+        let cli =
+            TestCli::try_parse_from(["test", "list", "--sort-by", "title,status", "--reverse"])
+                .unwrap();
+        match cli.command {
+            Command::List(cmd) => {
+                assert_eq!(
+                    cmd.sort,
+                    vec![vertebrae_db::SortKey::Title, vertebrae_db::SortKey::Status]
+                );
+                assert!(cmd.reverse);
+            }
+            _ => panic!("Expected List command"),
+        }
+    }
+
+    #[test]
+    fn test_command_list_show_blocked() {
+        let cli = TestCli::try_parse_from(["test", "list", "--show-blocked"]).unwrap();
+        match cli.command {
+            Command::List(cmd) => assert!(cmd.show_blocked),
+            _ => panic!("Expected List command"),
+        }
+    }
+
+    #[test]
+    fn test_command_list_limit_and_offset() {
+        let cli =
+            TestCli::try_parse_from(["test", "list", "--limit", "50", "--offset", "100"]).unwrap();
+        match cli.command {
+            Command::List(cmd) => {
+                assert_eq!(cmd.limit, Some(50));
+                assert_eq!(cmd.offset, Some(100));
+            }
+            _ => panic!("Expected List command"),
+        }
+        assert!(TestCli::try_parse_from(["test", "list", "--limit", "5", "--random"]).is_err());
+    }
+
+    #[test]
+    fn test_command_due_date_and_estimate_flags() {
+        let cli = TestCli::try_parse_from(["test", "list", "--overdue"]).unwrap();
+        match cli.command {
+            Command::List(cmd) => assert!(cmd.overdue),
+            _ => panic!("Expected List command"),
+        }
+
+        let cli = TestCli::try_parse_from([
+            "test",
+            "list",
+            "--created-after",
+            "2024-03-01T00:00:00Z",
+            "--updated-after",
+            "2024-05-01",
+            "--columns",
+            "id,created,updated",
+        ])
+        .unwrap();
+        match cli.command {
+            Command::List(cmd) => {
+                assert_eq!(
+                    cmd.created_after.map(|t| t.to_rfc3339()),
+                    Some("2024-03-01T00:00:00+00:00".to_string())
+                );
+                assert!(cmd.created_before.is_none());
+                assert!(cmd.updated_after.is_some());
+                assert_eq!(
+                    cmd.columns,
+                    vec![Column::Id, Column::Created, Column::Updated]
+                );
+            }
+            _ => panic!("Expected List command"),
+        }
+        assert!(
+            TestCli::try_parse_from(["test", "list", "--created-before", "last week"]).is_err()
+        );
+
+        let cli =
+            TestCli::try_parse_from(["test", "add", "Ship it", "--due", "2024-06-01"]).unwrap();
+        match cli.command {
+            Command::Add(cmd) => assert!(cmd.due.is_some()),
+            _ => panic!("Expected Add command"),
+        }
+        assert!(TestCli::try_parse_from(["test", "add", "Ship it", "--due", "someday"]).is_err());
+
+        let cli =
+            TestCli::try_parse_from(["test", "update", "abc123", "--estimate", "2h30m"]).unwrap();
+        match cli.command {
+            Command::Update(cmd) => assert_eq!(cmd.estimate, Some(150)),
+            _ => panic!("Expected Update command"),
+        }
+        assert!(TestCli::try_parse_from(["test", "add", "Ship it", "--estimate", "2d"]).is_err());
+
+        let cli = TestCli::try_parse_from(["test", "update", "abc123", "--no-due"]).unwrap();
+        match cli.command {
+            Command::Update(cmd) => assert!(cmd.no_due && cmd.due.is_none()),
+            _ => panic!("Expected Update command"),
+        }
+        assert!(
+            TestCli::try_parse_from(["test", "update", "abc123", "--due", "+3d", "--no-due"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_command_list_wrap() {
+        let cli = TestCli::try_parse_from(["test", "list", "--wrap"]).unwrap();
+        match cli.command {
+            Command::List(cmd) => assert!(cmd.wrap),
+            _ => panic!("Expected List command"),
+        }
+    }
+
+    #[test]
+    fn test_command_list_random_and_seed() {
+        let cli = TestCli::try_parse_from(["test", "list", "--random"]).unwrap();
+        match cli.command {
+            Command::List(cmd) => assert_eq!(cmd.random, Some(1)),
+            _ => panic!("Expected List command"),
+        }
+
+        let cli =
+            TestCli::try_parse_from(["test", "list", "--random", "3", "--seed", "42"]).unwrap();
+        match cli.command {
+            Command::List(cmd) => {
+                assert_eq!(cmd.random, Some(3));
+                assert_eq!(cmd.seed, Some(42));
+            }
+            _ => panic!("Expected List command"),
+        }
+
+        let result = TestCli::try_parse_from(["test", "list", "--seed", "42"]);
+        assert!(result.is_err(), "--seed requires --random");
+    }
+
+    #[test]
+    fn test_command_explain_flag_parses() {
+        let cli =
+            TestCli::try_parse_from(["test", "list", "--status", "todo", "--explain"]).unwrap();
+        match cli.command {
+            Command::List(cmd) => assert!(cmd.explain),
+            _ => panic!("Expected List command"),
+        }
+
+        let cli = TestCli::try_parse_from(["test", "blockers", "abc", "--explain"]).unwrap();
+        match cli.command {
+            Command::Blockers(cmd) => assert!(cmd.explain),
+            _ => panic!("Expected Blockers command"),
+        }
+
+        let cli = TestCli::try_parse_from(["test", "path", "a", "b", "--explain"]).unwrap();
+        match cli.command {
+            Command::Path(cmd) => assert!(cmd.explain),
+            _ => panic!("Expected Path command"),
+        }
+    }
+
+    #[test]
+    fn test_command_board_parses() {
+        let cli = TestCli::try_parse_from([
+            "test", "board", "--level", "task", "--tag", "ui", "--width", "100", "--format", "json",
+        ])
+        .unwrap();
+        match cli.command {
+            Command::Board(cmd) => {
+                assert_eq!(cmd.levels, vec![vertebrae_db::Level::Task]);
+                assert_eq!(cmd.tags, vec!["ui"]);
+                assert_eq!(cmd.width, Some(100));
+                assert_eq!(cmd.format, crate::output::OutputFormat::Json);
+            }
+            _ => panic!("Expected Board command"),
+        }
+    }
+
+    #[test]
+    fn test_command_blockers_max_paths_requires_paths() {
+        let cli = TestCli::try_parse_from(["test", "blockers", "abc", "--max-paths", "5"]);
+        assert!(cli.is_err());
+    }
+
+    #[test]
+    fn test_command_export_filters_parse() {
+        let cli = TestCli::try_parse_from([
+            "test",
+            "export",
+            "--descendants",
+            "epic1",
+            "-l",
+            "task",
+            "-s",
+            "done",
+            "-t",
+            "backend",
+            "--search",
+            "auth",
+        ]);
+        assert!(cli.is_ok());
+        match cli.unwrap().command {
+            Command::Export(cmd) => {
+                assert_eq!(cmd.descendants.as_deref(), Some("epic1"));
+                assert_eq!(cmd.levels, vec![vertebrae_db::Level::Task]);
+                assert_eq!(cmd.statuses, vec![vertebrae_db::Status::Done]);
+                assert_eq!(cmd.tags, vec!["backend"]);
+                assert_eq!(cmd.search.as_deref(), Some("auth"));
+                assert!(cmd.output.is_none());
+            }
+            _ => panic!("Expected Export command"),
+        }
+
+        let cli = TestCli::try_parse_from(["test", "export", "--format", "csv"]).unwrap();
+        match cli.command {
+            Command::Export(cmd) => assert_eq!(cmd.format, crate::output::OutputFormat::Csv),
+            _ => panic!("Expected Export command"),
+        }
+    }
+
+    #[test]
+    fn test_command_add_start_and_done_flags() {
+        let cli = TestCli::try_parse_from(["test", "add", "Task", "--done"]).unwrap();
+        match cli.command {
+            Command::Add(cmd) => assert!(cmd.done && !cmd.start),
+            _ => panic!("Expected Add command"),
+        }
+
+        let cli = TestCli::try_parse_from(["test", "add", "Task", "--start"]).unwrap();
+        match cli.command {
+            Command::Add(cmd) => assert!(cmd.start && !cmd.done),
+            _ => panic!("Expected Add command"),
+        }
+
+        let result = TestCli::try_parse_from(["test", "add", "Task", "--start", "--done"]);
+        assert!(result.is_err(), "--start and --done are mutually exclusive");
+    }
+
+    #[test]
+    fn test_command_add_backdated_timestamps() {
+        let cli = TestCli::try_parse_from([
+            "test",
+            "add",
+            "Task",
+            "--done",
+            "--started-at",
+            "2024-03-01T09:00:00Z",
+            "--completed-at",
+            "2024-03-02T09:00:00Z",
+        ])
+        .unwrap();
+        match cli.command {
+            Command::Add(cmd) => {
+                assert_eq!(
+                    cmd.started_at.unwrap().to_rfc3339(),
+                    "2024-03-01T09:00:00+00:00"
+                );
+                assert_eq!(
+                    cmd.completed_at.unwrap().to_rfc3339(),
+                    "2024-03-02T09:00:00+00:00"
+                );
+            }
+            _ => panic!("Expected Add command"),
+        }
+
+        let result = TestCli::try_parse_from(["test", "add", "Task", "--started-at", "soon"]);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_command_add_with_level() {
         let cli = TestCli::try_parse_from(["test", "add", "Epic task", "--level", "epic"]);
@@ -523,6 +1331,50 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_command_list_with_documentation_filters() {
+        let cli = TestCli::try_parse_from([
+            "test",
+            "list",
+            "--has-section",
+            "step",
+            "--has-section",
+            "Goal",
+            "--no-refs",
+        ])
+        .unwrap();
+        match cli.command {
+            Command::List(cmd) => {
+                assert_eq!(
+                    cmd.section_types,
+                    vec![
+                        vertebrae_db::SectionType::Step,
+                        vertebrae_db::SectionType::Goal
+                    ]
+                );
+                assert!(cmd.no_refs);
+                assert!(!cmd.has_refs);
+            }
+            _ => panic!("Expected List command"),
+        }
+
+        assert!(TestCli::try_parse_from(["test", "list", "--has-section", "bogus"]).is_err());
+        assert!(
+            TestCli::try_parse_from(["test", "list", "--no-sections", "--has-section", "step"])
+                .is_err()
+        );
+        assert!(TestCli::try_parse_from(["test", "list", "--has-refs", "--no-refs"]).is_err());
+    }
+
+    #[test]
+    fn test_command_list_summary_parses() {
+        let cli = TestCli::try_parse_from(["test", "list", "--summary"]).unwrap();
+        match cli.command {
+            Command::List(cmd) => assert!(cmd.summary),
+            _ => panic!("Expected List command"),
+        }
+    }
+
     #[test]
     fn test_command_list_invalid_level() {
         let result = TestCli::try_parse_from(["test", "list", "--level", "invalid"]);
@@ -560,11 +1412,128 @@ mod tests {
         match cli.unwrap().command {
             Command::Show(cmd) => {
                 assert_eq!(cmd.id, "abc123");
+                assert_eq!(cmd.format, crate::output::OutputFormat::Table);
             }
             _ => panic!("Expected Show command"),
         }
     }
 
+    #[test]
+    fn test_command_show_partial_views_are_exclusive() {
+        let cli =
+            TestCli::try_parse_from(["test", "show", "abc123", "--relationships-only"]).unwrap();
+        match cli.command {
+            Command::Show(cmd) => assert_eq!(cmd.view(), show::ShowView::Relationships),
+            _ => panic!("Expected Show command"),
+        }
+
+        let cli = TestCli::try_parse_from(["test", "show", "abc123", "--sections-only"]).unwrap();
+        match cli.command {
+            Command::Show(cmd) => assert_eq!(cmd.view(), show::ShowView::Sections),
+            _ => panic!("Expected Show command"),
+        }
+
+        let result = TestCli::try_parse_from([
+            "test",
+            "show",
+            "abc123",
+            "--relationships-only",
+            "--sections-only",
+        ]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_command_show_tree_parses() {
+        let cli =
+            TestCli::try_parse_from(["test", "show", "abc123", "--tree", "--depth", "2"]).unwrap();
+        match cli.command {
+            Command::Show(cmd) => {
+                assert!(cmd.tree);
+                assert_eq!(cmd.depth, Some(2));
+            }
+            _ => panic!("Expected Show command"),
+        }
+
+        // --depth only applies to the tree
+        assert!(TestCli::try_parse_from(["test", "show", "abc123", "--depth", "2"]).is_err());
+        assert!(
+            TestCli::try_parse_from(["test", "show", "abc123", "--tree", "--sections-only"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_command_graph_parses() {
+        let cli = TestCli::try_parse_from(["test", "graph"]).unwrap();
+        match cli.command {
+            Command::Graph(cmd) => {
+                assert_eq!(cmd.root, None);
+                assert!(!cmd.hierarchy);
+                assert_eq!(cmd.format, crate::output::OutputFormat::Dot);
+            }
+            _ => panic!("Expected Graph command"),
+        }
+
+        let cli =
+            TestCli::try_parse_from(["test", "graph", "--root", "abc123", "--hierarchy"]).unwrap();
+        match cli.command {
+            Command::Graph(cmd) => {
+                assert_eq!(cmd.root.as_deref(), Some("abc123"));
+                assert!(cmd.hierarchy);
+            }
+            _ => panic!("Expected Graph command"),
+        }
+
+        let cli = TestCli::try_parse_from(["test", "graph", "--format", "mermaid"]).unwrap();
+        match cli.command {
+            Command::Graph(cmd) => assert_eq!(cmd.format, crate::output::OutputFormat::Mermaid),
+            _ => panic!("Expected Graph command"),
+        }
+    }
+
+    #[test]
+    fn test_command_yaml_format_parses() {
+        let cli = TestCli::try_parse_from(["test", "show", "abc123", "--format", "yaml"]).unwrap();
+        match cli.command {
+            Command::Show(cmd) => assert_eq!(cmd.format, crate::output::OutputFormat::Yaml),
+            _ => panic!("Expected Show command"),
+        }
+
+        let cli = TestCli::try_parse_from(["test", "list", "--format", "yaml"]).unwrap();
+        match cli.command {
+            Command::List(cmd) => assert_eq!(cmd.format, crate::output::OutputFormat::Yaml),
+            _ => panic!("Expected List command"),
+        }
+    }
+
+    #[test]
+    fn test_command_shared_format_values_parse() {
+        use crate::output::OutputFormat;
+
+        let cli = TestCli::try_parse_from(["test", "show", "abc123", "--format", "text"]).unwrap();
+        match cli.command {
+            Command::Show(cmd) => assert_eq!(cmd.format, OutputFormat::Table),
+            _ => panic!("Expected Show command"),
+        }
+
+        let cli =
+            TestCli::try_parse_from(["test", "blockers", "abc123", "--format", "json"]).unwrap();
+        match cli.command {
+            Command::Blockers(cmd) => assert_eq!(cmd.format, OutputFormat::Json),
+            _ => panic!("Expected Blockers command"),
+        }
+
+        // Unsupported combinations parse and are rejected when the command runs
+        let cli = TestCli::try_parse_from(["test", "list", "--format", "markdown"]).unwrap();
+        match cli.command {
+            Command::List(cmd) => assert_eq!(cmd.format, OutputFormat::Markdown),
+            _ => panic!("Expected List command"),
+        }
+
+        assert!(TestCli::try_parse_from(["test", "list", "--format", "xml"]).is_err());
+    }
+
     #[test]
     fn test_command_show_requires_id() {
         let result = TestCli::try_parse_from(["test", "show"]);
@@ -744,6 +1713,171 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_command_doctor_parses() {
+        let cli = TestCli::try_parse_from(["test", "doctor"]).unwrap();
+        assert!(matches!(cli.command, Command::Doctor(_)));
+    }
+
+    #[test]
+    fn test_command_info_parses() {
+        let cli = TestCli::try_parse_from(["test", "info"]).unwrap();
+        assert!(matches!(cli.command, Command::Info(_)));
+    }
+
+    #[test]
+    fn test_command_count_parses() {
+        let cli =
+            TestCli::try_parse_from(["test", "count", "--by", "tag", "--format", "json"]).unwrap();
+        match cli.command {
+            Command::Count(cmd) => {
+                assert_eq!(cmd.by, count::CountField::Tag);
+                assert_eq!(cmd.format, crate::output::OutputFormat::Json);
+            }
+            _ => panic!("Expected Count command"),
+        }
+        assert!(TestCli::try_parse_from(["test", "count"]).is_err());
+        assert!(TestCli::try_parse_from(["test", "count", "--by", "assignee"]).is_err());
+    }
+
+    #[test]
+    fn test_command_search_parses() {
+        let cli = TestCli::try_parse_from(["test", "search", "login bug", "--limit", "5"]).unwrap();
+        match cli.command {
+            Command::Search(cmd) => {
+                assert_eq!(cmd.query, "login bug");
+                assert_eq!(cmd.limit, Some(5));
+            }
+            _ => panic!("Expected Search command"),
+        }
+        assert!(TestCli::try_parse_from(["test", "search"]).is_err());
+    }
+
+    #[test]
+    fn test_command_tags_parses() {
+        let cli = TestCli::try_parse_from(["test", "tags"]).unwrap();
+        assert!(matches!(cli.command, Command::Tags(_)));
+        assert!(TestCli::try_parse_from(["test", "tags", "extra"]).is_err());
+    }
+
+    #[test]
+    fn test_command_import_quiet_parses() {
+        let cli = TestCli::try_parse_from(["test", "import", "-i", "a.jsonl", "--quiet"]).unwrap();
+        match cli.command {
+            Command::Import(cmd) => assert!(cmd.quiet),
+            _ => panic!("Expected Import command"),
+        }
+    }
+
+    #[test]
+    fn test_command_next_parses() {
+        let cli = TestCli::try_parse_from(["test", "next"]).unwrap();
+        assert!(matches!(cli.command, Command::Next(_)));
+    }
+
+    #[test]
+    fn test_command_ready_all_parses() {
+        let cli = TestCli::try_parse_from(["test", "ready", "--all"]).unwrap();
+        match cli.command {
+            Command::Ready(cmd) => assert!(cmd.all),
+            _ => panic!("Expected Ready command"),
+        }
+    }
+
+    #[test]
+    fn test_command_edges_only_parses() {
+        let cli = TestCli::try_parse_from(["test", "export", "--edges-only"]).unwrap();
+        match cli.command {
+            Command::Export(cmd) => assert!(cmd.edges_only),
+            _ => panic!("Expected Export command"),
+        }
+
+        let cli = TestCli::try_parse_from(["test", "import", "--edges-only"]).unwrap();
+        match cli.command {
+            Command::Import(cmd) => assert!(cmd.edges_only && !cmd.skip_existing),
+            _ => panic!("Expected Import command"),
+        }
+        assert!(
+            TestCli::try_parse_from(["test", "import", "--edges-only", "--skip-existing"]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_command_critical_path_parses() {
+        let cli = TestCli::try_parse_from(["test", "critical-path", "abc123"]).unwrap();
+        match cli.command {
+            Command::CriticalPath(cmd) => assert_eq!(cmd.id, "abc123"),
+            _ => panic!("Expected CriticalPath command"),
+        }
+    }
+
+    #[test]
+    fn test_command_move_parses() {
+        for name in ["move", "reparent"] {
+            let cli = TestCli::try_parse_from(["test", name, "abc123", ""]).unwrap();
+            match cli.command {
+                Command::Move(cmd) => {
+                    assert_eq!(cmd.id, "abc123");
+                    assert_eq!(cmd.parent, "");
+                }
+                _ => panic!("Expected Move command"),
+            }
+        }
+        assert!(TestCli::try_parse_from(["test", "move", "abc123"]).is_err());
+    }
+
+    #[test]
+    fn test_command_plan_parses() {
+        let cli = TestCli::try_parse_from(["test", "plan"]).unwrap();
+        assert!(matches!(cli.command, Command::Plan(_)));
+    }
+
+    #[test]
+    fn test_command_single_task_commands_accept_stdin_id() {
+        for args in [
+            vec!["test", "show", "-"],
+            vec!["test", "delete", "-", "--force"],
+            vec!["test", "transition-to", "-", "done"],
+            vec!["test", "update", "-", "--priority", "high"],
+            vec!["test", "review", "-"],
+        ] {
+            let cli = TestCli::try_parse_from(&args).unwrap();
+            let id = match &cli.command {
+                Command::Show(cmd) => &cmd.id,
+                Command::Delete(cmd) => &cmd.id,
+                Command::TransitionTo(cmd) => &cmd.id,
+                Command::Update(cmd) => &cmd.id,
+                Command::Review(cmd) => &cmd.id,
+                _ => panic!("Unexpected command for {:?}", args),
+            };
+            assert!(crate::stdin_ids::is_stdin_id(id));
+        }
+    }
+
+    #[test]
+    fn test_command_rename_parses() {
+        let cli = TestCli::try_parse_from(["test", "rename", "abc123", "New title"]).unwrap();
+        match cli.command {
+            Command::Rename(cmd) => {
+                assert_eq!(cmd.id, "abc123");
+                assert_eq!(cmd.title, Some("New title".to_string()));
+            }
+            _ => panic!("Expected Rename command"),
+        }
+    }
+
+    #[test]
+    fn test_command_set_title_alias_without_title() {
+        let cli = TestCli::try_parse_from(["test", "set-title", "abc123"]).unwrap();
+        match cli.command {
+            Command::Rename(cmd) => {
+                assert_eq!(cmd.id, "abc123");
+                assert_eq!(cmd.title, None);
+            }
+            _ => panic!("Expected Rename command"),
+        }
+    }
+
     #[test]
     fn test_command_delete_parses() {
         let cli = TestCli::try_parse_from(["test", "delete", "abc123"]);
@@ -758,6 +1892,33 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_command_delete_hard_and_restore_parse() {
+        let cli = TestCli::try_parse_from(["test", "delete", "abc123"]).unwrap();
+        match cli.command {
+            Command::Delete(cmd) => assert!(!cmd.hard),
+            _ => panic!("Expected Delete command"),
+        }
+        let cli = TestCli::try_parse_from(["test", "delete", "abc123", "--hard"]).unwrap();
+        match cli.command {
+            Command::Delete(cmd) => assert!(cmd.hard),
+            _ => panic!("Expected Delete command"),
+        }
+
+        let cli = TestCli::try_parse_from(["test", "restore", "abc123"]).unwrap();
+        match cli.command {
+            Command::Restore(cmd) => assert_eq!(cmd.id, "abc123"),
+            _ => panic!("Expected Restore command"),
+        }
+        assert!(TestCli::try_parse_from(["test", "restore"]).is_err());
+
+        let cli = TestCli::try_parse_from(["test", "list", "--include-deleted"]).unwrap();
+        match cli.command {
+            Command::List(cmd) => assert!(cmd.include_deleted),
+            _ => panic!("Expected List command"),
+        }
+    }
+
     #[test]
     fn test_command_delete_requires_id() {
         let result = TestCli::try_parse_from(["test", "delete"]);
@@ -804,6 +1965,33 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_command_delete_yes_aliases_force() {
+        for flag in ["--yes", "--assume-yes", "-y"] {
+            let cli = TestCli::try_parse_from(["test", "delete", "abc123", flag]).unwrap();
+            match cli.command {
+                Command::Delete(cmd) => assert!(cmd.force, "{} should set force", flag),
+                _ => panic!("Expected Delete command"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_command_depend_and_undepend_dry_run() {
+        let cli =
+            TestCli::try_parse_from(["test", "depend", "a1", "--on", "b2", "--dry-run"]).unwrap();
+        match cli.command {
+            Command::Depend(cmd) => assert!(cmd.dry_run),
+            _ => panic!("Expected Depend command"),
+        }
+
+        let cli = TestCli::try_parse_from(["test", "undepend", "a1", "--on", "b2"]).unwrap();
+        match cli.command {
+            Command::Undepend(cmd) => assert!(!cmd.dry_run),
+            _ => panic!("Expected Undepend command"),
+        }
+    }
+
     #[test]
     fn test_command_delete_with_cascade_and_force() {
         let cli = TestCli::try_parse_from(["test", "delete", "abc123", "--cascade", "--force"]);
@@ -902,6 +2090,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_command_transition_to_backdated_timestamps() {
+        let cli = TestCli::try_parse_from([
+            "test",
+            "transition-to",
+            "abc123",
+            "done",
+            "--completed-at",
+            "2024-03-02T09:00:00Z",
+        ])
+        .unwrap();
+        match cli.command {
+            Command::TransitionTo(cmd) => {
+                assert!(cmd.started_at.is_none());
+                assert_eq!(
+                    cmd.completed_at.unwrap().to_rfc3339(),
+                    "2024-03-02T09:00:00+00:00"
+                );
+            }
+            _ => panic!("Expected TransitionTo command"),
+        }
+    }
+
     #[test]
     fn test_command_transition_to_requires_id() {
         let result = TestCli::try_parse_from(["test", "transition-to"]);
@@ -0,0 +1,227 @@
+//! Move command for changing a task's parent
+//!
+//! Implements the `vtb move` command (alias `reparent`). Unlike
+//! `vtb update --parent`, it refuses to put a task under one of its own
+//! descendants, and swaps the parent edge in a single transaction.
+
+use crate::id::resolve_task_id;
+use clap::Args;
+use vertebrae_db::{Database, DbError};
+
+/// Move a task under a new parent, or to the root level
+#[derive(Debug, Args)]
+pub struct MoveCommand {
+    /// Task ID to move (case-insensitive)
+    #[arg(required = true)]
+    pub id: String,
+
+    /// New parent task ID (use empty string "" to move to the root level)
+    #[arg(required = true)]
+    pub parent: String,
+}
+
+/// Result of the move command execution
+#[derive(Debug)]
+pub struct MoveResult {
+    /// The moved task's ID
+    pub id: String,
+    /// Parent before the move
+    pub old_parent: Option<String>,
+    /// Parent after the move, `None` for the root level
+    pub parent: Option<String>,
+}
+
+impl std::fmt::Display for MoveResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.old_parent == self.parent {
+            return match &self.parent {
+                Some(parent) => write!(f, "Task {} is already under {}", self.id, parent),
+                None => write!(f, "Task {} is already at the root level", self.id),
+            };
+        }
+        match &self.parent {
+            Some(parent) => write!(f, "Moved task {} under {}", self.id, parent),
+            None => write!(f, "Moved task {} to the root level", self.id),
+        }
+    }
+}
+
+impl MoveCommand {
+    /// Execute the move command.
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - Reference to the database connection
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError` if:
+    /// - The task or the new parent does not exist
+    /// - The new parent is the task itself or one of its descendants
+    /// - Database operations fail
+    pub async fn execute(&self, db: &Database) -> Result<MoveResult, DbError> {
+        // Resolve case-insensitive and shortened IDs
+        let id = resolve_task_id(db, &self.id).await?;
+        let parent = match self.parent.trim() {
+            "" => None,
+            parent => Some(resolve_task_id(db, parent).await?),
+        };
+
+        if !db.tasks().exists(&id).await? {
+            return Err(DbError::NotFound { task_id: id });
+        }
+        if let Some(parent) = &parent {
+            if !db.tasks().exists(parent).await? {
+                return Err(DbError::NotFound {
+                    task_id: parent.clone(),
+                });
+            }
+            if db.graph().would_create_hierarchy_cycle(&id, parent).await? {
+                return Err(DbError::ValidationError {
+                    message: if *parent == id {
+                        format!("Task '{}' cannot be its own parent", id)
+                    } else {
+                        format!(
+                            "Cannot move task '{}' under '{}': '{}' is one of its descendants",
+                            id, parent, parent
+                        )
+                    },
+                });
+            }
+        }
+
+        let old_parent = db.relationships().get_parent(&id).await?;
+        if old_parent != parent {
+            db.relationships()
+                .set_parent(&id, parent.as_deref())
+                .await?;
+            let query = format!("UPDATE task:{} SET updated_at = time::now()", id);
+            db.query(&query).await?;
+        }
+
+        Ok(MoveResult {
+            id,
+            old_parent,
+            parent,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    async fn setup_test_db() -> (Database, std::path::PathBuf) {
+        let temp_dir = env::temp_dir().join(format!(
+            "vtb-move-test-{}-{:?}-{}",
+            std::process::id(),
+            std::thread::current().id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        let db = Database::connect(&temp_dir).await.unwrap();
+        db.init().await.unwrap();
+        db.client()
+            .query(
+                r#"CREATE task:epic1 SET title = "Epic 1", level = "epic", status = "todo";
+                   CREATE task:epic2 SET title = "Epic 2", level = "epic", status = "todo";
+                   CREATE task:ticket SET title = "Ticket", level = "ticket", status = "todo";
+                   CREATE task:leaf SET title = "Leaf", level = "task", status = "todo";
+                   RELATE task:ticket -> child_of -> task:epic1;
+                   RELATE task:leaf -> child_of -> task:ticket"#,
+            )
+            .await
+            .unwrap();
+        (db, temp_dir)
+    }
+
+    fn cleanup(path: &std::path::Path) {
+        let _ = std::fs::remove_dir_all(path);
+    }
+
+    fn move_cmd(id: &str, parent: &str) -> MoveCommand {
+        MoveCommand {
+            id: id.to_string(),
+            parent: parent.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_move_to_new_parent_and_root() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        let result = move_cmd("TICKET", "epic2").execute(&db).await.unwrap();
+        assert_eq!(result.old_parent.as_deref(), Some("epic1"));
+        assert_eq!(result.to_string(), "Moved task ticket under epic2");
+        assert_eq!(
+            db.relationships().get_parent("ticket").await.unwrap(),
+            Some("epic2".to_string())
+        );
+        assert!(
+            db.relationships()
+                .get_children("epic1")
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        // The subtree moves along
+        assert_eq!(
+            db.relationships().get_parent("leaf").await.unwrap(),
+            Some("ticket".to_string())
+        );
+
+        let result = move_cmd("ticket", "").execute(&db).await.unwrap();
+        assert_eq!(result.to_string(), "Moved task ticket to the root level");
+        assert!(
+            db.relationships()
+                .get_parent("ticket")
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        let result = move_cmd("ticket", "").execute(&db).await.unwrap();
+        assert_eq!(
+            result.to_string(),
+            "Task ticket is already at the root level"
+        );
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_move_rejects_cycles_and_missing_tasks() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        for (id, parent) in [("epic1", "leaf"), ("ticket", "ticket")] {
+            match move_cmd(id, parent).execute(&db).await {
+                Err(DbError::ValidationError { message }) => {
+                    assert!(message.contains(id), "got: {}", message)
+                }
+                other => panic!("Expected ValidationError, got {:?}", other),
+            }
+        }
+        // Nothing changed
+        assert_eq!(
+            db.relationships().get_parent("ticket").await.unwrap(),
+            Some("epic1".to_string())
+        );
+        assert!(
+            db.relationships()
+                .get_parent("epic1")
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        for (id, parent) in [("nope", "epic1"), ("leaf", "nope")] {
+            let err = move_cmd(id, parent).execute(&db).await.unwrap_err();
+            assert!(matches!(err, DbError::NotFound { ref task_id } if task_id == "nope"));
+        }
+
+        cleanup(&temp_dir);
+    }
+}
@@ -0,0 +1,138 @@
+//! Next command for suggesting a single task to work on
+//!
+//! Implements the `vtb next` command. Of the todo tasks whose blockers are
+//! all done (the same set `vtb ready --all` lists), it picks the highest
+//! priority one, preferring the most recently updated and then the shortest
+//! title, and shows it in full like `vtb show`.
+
+use clap::Args;
+use vertebrae_db::{Database, DbError, Status};
+
+use crate::commands::show::{ShowCommand, TaskDetail};
+use crate::output::OutputFormat;
+
+/// Suggest one task to work on next
+#[derive(Debug, Args)]
+pub struct NextCommand {}
+
+/// Result of the next command execution
+#[derive(Debug)]
+pub struct NextResult {
+    /// The suggested task, or `None` if nothing is ready
+    pub task: Option<TaskDetail>,
+}
+
+impl std::fmt::Display for NextResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.task {
+            Some(detail) => write!(f, "{}", detail),
+            None => write!(f, "No actionable tasks."),
+        }
+    }
+}
+
+impl NextCommand {
+    /// Execute the next command.
+    ///
+    /// Having nothing to suggest is not an error; the result is empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - Reference to the database connection
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError` if database operations fail.
+    pub async fn execute(&self, db: &Database) -> Result<NextResult, DbError> {
+        let Some(next) = db.list_tasks().next_unblocked(Status::Todo).await? else {
+            return Ok(NextResult { task: None });
+        };
+
+        let show = ShowCommand {
+            id: next.id,
+            format: OutputFormat::Table,
+            relationships_only: false,
+            sections_only: false,
+            tree: false,
+            depth: None,
+        };
+        Ok(NextResult {
+            task: Some(show.execute(db).await?),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    async fn setup_test_db() -> (Database, std::path::PathBuf) {
+        let temp_dir = env::temp_dir().join(format!(
+            "vtb-next-test-{}-{:?}-{}",
+            std::process::id(),
+            std::thread::current().id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        let db = Database::connect(&temp_dir).await.unwrap();
+        db.init().await.unwrap();
+        (db, temp_dir)
+    }
+
+    fn cleanup(path: &std::path::Path) {
+        let _ = std::fs::remove_dir_all(path);
+    }
+
+    #[tokio::test]
+    async fn test_next_with_nothing_ready() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        let result = NextCommand {}.execute(&db).await.unwrap();
+        assert!(result.task.is_none());
+        assert_eq!(result.to_string(), "No actionable tasks.");
+
+        // Blocked and non-todo tasks are not actionable
+        db.client()
+            .query(
+                r#"CREATE task:wip SET title = "In flight", level = "task", status = "in_progress";
+                   CREATE task:stuck SET title = "Stuck", level = "task", status = "todo";
+                   RELATE task:stuck -> depends_on -> task:wip"#,
+            )
+            .await
+            .unwrap();
+        let result = NextCommand {}.execute(&db).await.unwrap();
+        assert!(result.task.is_none());
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_next_shows_highest_priority_task_in_full() {
+        let (db, temp_dir) = setup_test_db().await;
+        db.client()
+            .query(
+                r#"CREATE task:low SET title = "Tidy up", level = "task", status = "todo",
+                       priority = "low";
+                   CREATE task:high SET title = "Fix login", level = "task", status = "todo",
+                       priority = "high", description = "Sessions expire too early";
+                   CREATE task:epic SET title = "Auth", level = "epic", status = "todo";
+                   RELATE task:high -> child_of -> task:epic"#,
+            )
+            .await
+            .unwrap();
+
+        let result = NextCommand {}.execute(&db).await.unwrap();
+        let detail = result.task.as_ref().expect("a suggestion");
+        assert_eq!(detail.id, "high");
+        assert_eq!(detail.parent.as_ref().map(|p| p.id.as_str()), Some("epic"));
+
+        let output = result.to_string();
+        assert!(output.contains("Fix login"));
+        assert!(output.contains("Sessions expire too early"));
+
+        cleanup(&temp_dir);
+    }
+}
@@ -3,6 +3,7 @@
 //! Implements the `vtb path` command to find the shortest dependency path
 //! between two tasks using BFS traversal of the dependency graph.
 
+use crate::id::resolve_task_id;
 use clap::Args;
 use serde::Deserialize;
 use std::collections::{HashMap, HashSet, VecDeque};
@@ -18,6 +19,10 @@ pub struct PathCommand {
     /// Target task ID (case-insensitive)
     #[arg(required = true)]
     pub to_id: String,
+
+    /// Print the SurrealQL queries instead of running them
+    #[arg(long)]
+    pub explain: bool,
 }
 
 /// A task summary for path display
@@ -64,9 +69,9 @@ impl PathCommand {
     /// - Either task does not exist
     /// - Database operations fail
     pub async fn execute(&self, db: &Database) -> Result<PathResult, DbError> {
-        // Normalize IDs to lowercase for case-insensitive lookup
-        let from_id = self.from_id.to_lowercase();
-        let to_id = self.to_id.to_lowercase();
+        // Resolve case-insensitive and shortened IDs
+        let from_id = resolve_task_id(db, &self.from_id).await?;
+        let to_id = resolve_task_id(db, &self.to_id).await?;
 
         // Validate both tasks exist
         let from_task = self.fetch_task(db, &from_id).await?;
@@ -94,11 +99,32 @@ impl PathCommand {
         })
     }
 
+    /// Return the SurrealQL queries `execute` would run, without running them.
+    ///
+    /// The search issues the dependency query once per task it visits,
+    /// breadth-first from the source, until it reaches the target.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError::NotFound` or `DbError::AmbiguousId` if either ID
+    /// doesn't resolve to exactly one task.
+    pub async fn explain(&self, db: &Database) -> Result<String, DbError> {
+        let from_id = resolve_task_id(db, &self.from_id).await?;
+        let to_id = resolve_task_id(db, &self.to_id).await?;
+        Ok(format!(
+            "{};\n{};\n{};\n-- repeated for each task visited until {} is reached",
+            task_query(&from_id),
+            task_query(&to_id),
+            dependencies_query(&from_id),
+            to_id
+        ))
+    }
+
     /// Fetch a task by ID.
     async fn fetch_task(&self, db: &Database, id: &str) -> Result<TaskRow, DbError> {
-        let query = format!("SELECT id, title FROM task:{}", id);
+        let query = task_query(id);
 
-        let mut result = db.client().query(&query).await?;
+        let mut result = db.query(&query).await?;
         let task: Option<TaskRow> = result.take(0)?;
 
         task.ok_or_else(|| DbError::NotFound {
@@ -131,8 +157,8 @@ impl PathCommand {
 
         while let Some(current) = queue.pop_front() {
             // Get all tasks that the current task depends on
-            let query = format!("SELECT VALUE out FROM task:{}->depends_on", current);
-            let mut result = db.client().query(&query).await?;
+            let query = dependencies_query(&current);
+            let mut result = db.query(&query).await?;
             let deps: Vec<surrealdb::sql::Thing> = result.take(0)?;
 
             for dep in deps {
@@ -250,6 +276,19 @@ impl std::fmt::Display for PathResult {
     }
 }
 
+/// Query for a task's title.
+fn task_query(id: &str) -> String {
+    format!("SELECT id, title FROM task:{}", id)
+}
+
+/// Query for the IDs of the tasks that `id` depends on, skipping trashed ones.
+fn dependencies_query(id: &str) -> String {
+    format!(
+        "SELECT VALUE out FROM task:{}->depends_on WHERE out.deleted_at = NONE",
+        id
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -312,6 +351,7 @@ mod tests {
         let cmd = PathCommand {
             from_id: "taska".to_string(),
             to_id: "taska".to_string(),
+            explain: false,
         };
 
         let result = cmd.execute(&db).await;
@@ -341,6 +381,7 @@ mod tests {
         let cmd = PathCommand {
             from_id: "taska".to_string(),
             to_id: "taskb".to_string(),
+            explain: false,
         };
 
         let result = cmd.execute(&db).await;
@@ -374,6 +415,7 @@ mod tests {
         let cmd = PathCommand {
             from_id: "taska".to_string(),
             to_id: "taskc".to_string(),
+            explain: false,
         };
 
         let result = cmd.execute(&db).await;
@@ -401,6 +443,7 @@ mod tests {
         let cmd = PathCommand {
             from_id: "taska".to_string(),
             to_id: "taskb".to_string(),
+            explain: false,
         };
 
         let result = cmd.execute(&db).await;
@@ -428,6 +471,7 @@ mod tests {
         let cmd = PathCommand {
             from_id: "taskb".to_string(),
             to_id: "taska".to_string(),
+            explain: false,
         };
 
         let result = cmd.execute(&db).await;
@@ -448,6 +492,7 @@ mod tests {
         let cmd = PathCommand {
             from_id: "nonexistent".to_string(),
             to_id: "taskb".to_string(),
+            explain: false,
         };
 
         let result = cmd.execute(&db).await;
@@ -475,6 +520,7 @@ mod tests {
         let cmd = PathCommand {
             from_id: "taska".to_string(),
             to_id: "nonexistent".to_string(),
+            explain: false,
         };
 
         let result = cmd.execute(&db).await;
@@ -504,6 +550,7 @@ mod tests {
         let cmd = PathCommand {
             from_id: "TASKA".to_string(),
             to_id: "TASKB".to_string(),
+            explain: false,
         };
 
         let result = cmd.execute(&db).await;
@@ -534,6 +581,7 @@ mod tests {
         let cmd = PathCommand {
             from_id: "taska".to_string(),
             to_id: "taskd".to_string(),
+            explain: false,
         };
 
         let result = cmd.execute(&db).await;
@@ -572,6 +620,7 @@ mod tests {
         let cmd = PathCommand {
             from_id: "taska".to_string(),
             to_id: "taske".to_string(),
+            explain: false,
         };
 
         let result = cmd.execute(&db).await;
@@ -663,11 +712,45 @@ mod tests {
         assert_eq!(lines[lines.len() - 1], "3 tasks in path");
     }
 
+    #[tokio::test]
+    async fn test_path_explain_lists_queries() {
+        let (db, temp_dir) = setup_test_db().await;
+        create_task(&db, "taska1", "Task A").await;
+        create_task(&db, "taskc1", "Task C").await;
+
+        // Shortened, mixed-case IDs resolve to the stored ones
+        let cmd = PathCommand {
+            from_id: "TaskA".to_string(),
+            to_id: "taskc".to_string(),
+            explain: true,
+        };
+
+        let explain = cmd.explain(&db).await.unwrap();
+        assert!(explain.contains("SELECT id, title FROM task:taska1;"));
+        assert!(explain.contains("SELECT id, title FROM task:taskc1;"));
+        assert!(explain.contains(
+            "SELECT VALUE out FROM task:taska1->depends_on WHERE out.deleted_at = NONE;"
+        ));
+        assert!(explain.ends_with("until taskc1 is reached"));
+
+        let cmd = PathCommand {
+            to_id: "nope".to_string(),
+            ..cmd
+        };
+        assert!(matches!(
+            cmd.explain(&db).await,
+            Err(DbError::NotFound { .. })
+        ));
+
+        cleanup(&temp_dir);
+    }
+
     #[test]
     fn test_path_command_debug() {
         let cmd = PathCommand {
             from_id: "test1".to_string(),
             to_id: "test2".to_string(),
+            explain: false,
         };
         let debug_str = format!("{:?}", cmd);
         assert!(
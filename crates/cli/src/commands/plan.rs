@@ -0,0 +1,199 @@
+//! Plan command for a suggested work sequence
+//!
+//! Implements the `vtb plan` command, which lists every task in an order
+//! that respects `depends_on` edges: each task comes after everything it
+//! depends on.
+
+use clap::Args;
+use serde::Deserialize;
+use std::collections::HashMap;
+use vertebrae_db::{Database, DbError};
+
+use crate::output::level_label;
+
+/// List all tasks in dependency order
+#[derive(Debug, Args)]
+pub struct PlanCommand {}
+
+/// A task in the plan
+#[derive(Debug, Clone)]
+pub struct PlanStep {
+    /// Task ID
+    pub id: String,
+    /// Task title
+    pub title: String,
+    /// Hierarchy level
+    pub level: String,
+    /// Current status
+    pub status: String,
+}
+
+/// Result of the plan command execution
+#[derive(Debug)]
+pub struct PlanResult {
+    /// Tasks with dependencies before dependents
+    pub steps: Vec<PlanStep>,
+}
+
+/// Result from querying a task
+#[derive(Debug, Deserialize)]
+struct TaskRow {
+    id: surrealdb::sql::Thing,
+    title: String,
+    level: String,
+    status: String,
+}
+
+impl PlanCommand {
+    /// Execute the plan command.
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - Reference to the database connection
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError::GraphCycle` if the dependencies contain a cycle, or
+    /// another `DbError` if database operations fail.
+    pub async fn execute(&self, db: &Database) -> Result<PlanResult, DbError> {
+        let order = db.graph().topological_order().await?;
+
+        let mut result = db
+            .query("SELECT id, title, level, status FROM task")
+            .await?;
+        let rows: Vec<TaskRow> = result.take(0)?;
+        let mut tasks: HashMap<String, TaskRow> = rows
+            .into_iter()
+            .map(|row| (row.id.id.to_string(), row))
+            .collect();
+
+        let steps = order
+            .into_iter()
+            .filter_map(|id| {
+                tasks.remove(&id).map(|row| PlanStep {
+                    id,
+                    title: row.title,
+                    level: row.level,
+                    status: row.status,
+                })
+            })
+            .collect();
+
+        Ok(PlanResult { steps })
+    }
+}
+
+impl std::fmt::Display for PlanResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.steps.is_empty() {
+            return write!(f, "No tasks found.");
+        }
+
+        let number_width = self.steps.len().to_string().len();
+        let id_width = self.steps.iter().map(|s| s.id.len()).max().unwrap_or(0);
+        let level_width = self
+            .steps
+            .iter()
+            .map(|s| level_label(&s.level).len())
+            .max()
+            .unwrap_or(0);
+        let status_width = self.steps.iter().map(|s| s.status.len()).max().unwrap_or(0);
+
+        let lines: Vec<String> = self
+            .steps
+            .iter()
+            .enumerate()
+            .map(|(i, step)| {
+                format!(
+                    "{:>nw$}. {:<iw$}  {:<lw$}  {:<sw$}  {}",
+                    i + 1,
+                    step.id,
+                    level_label(&step.level),
+                    step.status,
+                    step.title,
+                    nw = number_width,
+                    iw = id_width,
+                    lw = level_width,
+                    sw = status_width,
+                )
+            })
+            .collect();
+        write!(f, "{}", lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    async fn setup_test_db() -> (Database, std::path::PathBuf) {
+        let temp_dir = env::temp_dir().join(format!(
+            "vtb-plan-test-{}-{:?}-{}",
+            std::process::id(),
+            std::thread::current().id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        let db = Database::connect(&temp_dir).await.unwrap();
+        db.init().await.unwrap();
+        (db, temp_dir)
+    }
+
+    fn cleanup(path: &std::path::Path) {
+        let _ = std::fs::remove_dir_all(path);
+    }
+
+    #[tokio::test]
+    async fn test_plan_lists_dependencies_first_with_levels() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        let result = PlanCommand {}.execute(&db).await.unwrap();
+        assert_eq!(result.to_string(), "No tasks found.");
+
+        db.client()
+            .query(
+                r#"CREATE task:release SET title = "Release", level = "epic", status = "todo";
+                   CREATE task:api SET title = "API", level = "ticket", status = "in_progress";
+                   CREATE task:schema SET title = "Schema", level = "task", status = "done";
+                   RELATE task:release -> depends_on -> task:api;
+                   RELATE task:api -> depends_on -> task:schema"#,
+            )
+            .await
+            .unwrap();
+
+        let result = PlanCommand {}.execute(&db).await.unwrap();
+        let ids: Vec<&str> = result.steps.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(ids, vec!["schema", "api", "release"]);
+
+        let output = result.to_string();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines[0], "1. schema   task    done         Schema");
+        assert_eq!(lines[1], "2. api      ticket  in_progress  API");
+        assert_eq!(lines[2], "3. release  epic    todo         Release");
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_plan_reports_cycle() {
+        let (db, temp_dir) = setup_test_db().await;
+        db.client()
+            .query(
+                r#"CREATE task:a SET title = "A", level = "task", status = "todo";
+                   CREATE task:b SET title = "B", level = "task", status = "todo";
+                   RELATE task:a -> depends_on -> task:b;
+                   RELATE task:b -> depends_on -> task:a"#,
+            )
+            .await
+            .unwrap();
+
+        let err = PlanCommand {}.execute(&db).await.unwrap_err();
+        assert!(matches!(err, DbError::GraphCycle { .. }));
+        assert!(err.to_string().contains("a -> b -> a"), "got: {}", err);
+
+        cleanup(&temp_dir);
+    }
+}
@@ -1,17 +1,26 @@
 //! Ready command for showing highest-level actionable items
 //!
 //! Implements the `vtb ready` command to show entry points for work.
-//! Shows highest-level unblocked items prioritized by hierarchy (epic > ticket > task).
+//! Shows highest-level unblocked items prioritized by hierarchy (epic > ticket > task),
+//! then by priority (critical first, unprioritized last). With `--all` it
+//! instead lists every unblocked todo task as a table.
 
 use clap::Args;
 use vertebrae_db::{Database, DbError, Status, TaskSummary};
 
+use crate::commands::list;
+use crate::output::format_task_table;
+
 #[cfg(test)]
 use vertebrae_db::Level;
 
 /// Show highest-level actionable items
 #[derive(Debug, Args)]
-pub struct ReadyCommand {}
+pub struct ReadyCommand {
+    /// List every unblocked todo task, not just the highest entry points
+    #[arg(long)]
+    pub all: bool,
+}
 
 /// Result of the ready command execution
 #[derive(Debug)]
@@ -20,10 +29,19 @@ pub struct ReadyResult {
     pub todo_ready: Vec<TaskSummary>,
     /// Tasks that are ready to triage (backlog status, unblocked, work not started)
     pub backlog_ready: Vec<TaskSummary>,
+    /// Whether `todo_ready` holds every unblocked todo task (`--all`),
+    /// shown as a table
+    pub all: bool,
 }
 
 impl std::fmt::Display for ReadyResult {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.all {
+            let rows: Vec<list::TaskSummary> =
+                self.todo_ready.iter().cloned().map(Into::into).collect();
+            return write!(f, "{}", format_task_table(&rows));
+        }
+
         let has_todo = !self.todo_ready.is_empty();
         let has_backlog = !self.backlog_ready.is_empty();
 
@@ -34,7 +52,7 @@ impl std::fmt::Display for ReadyResult {
         if has_todo {
             writeln!(f, "Ready to work (todo):")?;
             for task in &self.todo_ready {
-                writeln!(f, "  {}  {}  {}", task.id, task.level, task.title)?;
+                writeln!(f, "  {}  {}  {}", task.id, task.level.label(), task.title)?;
             }
         }
 
@@ -44,7 +62,7 @@ impl std::fmt::Display for ReadyResult {
             }
             writeln!(f, "Ready to triage (backlog):")?;
             for task in &self.backlog_ready {
-                writeln!(f, "  {}  {}  {}", task.id, task.level, task.title)?;
+                writeln!(f, "  {}  {}  {}", task.id, task.level.label(), task.title)?;
             }
         }
 
@@ -63,6 +81,9 @@ impl ReadyCommand {
     /// An item is excluded if any of its children have work started
     /// (status in: in_progress, pending_review, done).
     ///
+    /// With `--all`, returns every todo task without incomplete blockers
+    /// instead, regardless of hierarchy.
+    ///
     /// # Arguments
     ///
     /// * `db` - Reference to the database connection
@@ -71,6 +92,14 @@ impl ReadyCommand {
     ///
     /// Returns `DbError` if database operations fail.
     pub async fn execute(&self, db: &Database) -> Result<ReadyResult, DbError> {
+        if self.all {
+            return Ok(ReadyResult {
+                todo_ready: db.list_tasks().list_unblocked(Status::Todo).await?,
+                backlog_ready: Vec::new(),
+                all: true,
+            });
+        }
+
         // Get ready items for todo status
         let todo_ready = db.list_ready_items(Status::Todo).await?;
 
@@ -80,6 +109,7 @@ impl ReadyCommand {
         Ok(ReadyResult {
             todo_ready,
             backlog_ready,
+            all: false,
         })
     }
 }
@@ -145,7 +175,7 @@ mod tests {
 
     #[test]
     fn test_ready_command_debug() {
-        let cmd = ReadyCommand {};
+        let cmd = ReadyCommand { all: false };
         let debug_str = format!("{:?}", cmd);
         assert!(debug_str.contains("ReadyCommand"));
     }
@@ -155,6 +185,7 @@ mod tests {
         let result = ReadyResult {
             todo_ready: vec![],
             backlog_ready: vec![],
+            all: false,
         };
         assert_eq!(result.to_string(), "No actionable items found.");
     }
@@ -170,8 +201,11 @@ mod tests {
                 priority: None,
                 tags: vec![],
                 needs_human_review: None,
+                created_at: None,
+                updated_at: None,
             }],
             backlog_ready: vec![],
+            all: false,
         };
 
         let output = result.to_string();
@@ -193,7 +227,10 @@ mod tests {
                 priority: None,
                 tags: vec![],
                 needs_human_review: None,
+                created_at: None,
+                updated_at: None,
             }],
+            all: false,
         };
 
         let output = result.to_string();
@@ -214,6 +251,8 @@ mod tests {
                 priority: None,
                 tags: vec![],
                 needs_human_review: None,
+                created_at: None,
+                updated_at: None,
             }],
             backlog_ready: vec![TaskSummary {
                 id: "def456".to_string(),
@@ -223,7 +262,10 @@ mod tests {
                 priority: None,
                 tags: vec![],
                 needs_human_review: None,
+                created_at: None,
+                updated_at: None,
             }],
+            all: false,
         };
 
         let output = result.to_string();
@@ -237,7 +279,7 @@ mod tests {
     async fn test_ready_empty_database() {
         let (db, temp_dir) = setup_test_db().await;
 
-        let cmd = ReadyCommand {};
+        let cmd = ReadyCommand { all: false };
         let result = cmd.execute(&db).await.unwrap();
 
         assert!(result.todo_ready.is_empty());
@@ -252,7 +294,7 @@ mod tests {
 
         create_task(&db, "task1", "Ready Task", "task", "todo").await;
 
-        let cmd = ReadyCommand {};
+        let cmd = ReadyCommand { all: false };
         let result = cmd.execute(&db).await.unwrap();
 
         assert_eq!(result.todo_ready.len(), 1);
@@ -268,7 +310,7 @@ mod tests {
 
         create_task(&db, "task1", "Backlog Task", "task", "backlog").await;
 
-        let cmd = ReadyCommand {};
+        let cmd = ReadyCommand { all: false };
         let result = cmd.execute(&db).await.unwrap();
 
         assert!(result.todo_ready.is_empty());
@@ -284,7 +326,7 @@ mod tests {
 
         create_task(&db, "task1", "In Progress Task", "task", "in_progress").await;
 
-        let cmd = ReadyCommand {};
+        let cmd = ReadyCommand { all: false };
         let result = cmd.execute(&db).await.unwrap();
 
         assert!(result.todo_ready.is_empty());
@@ -299,7 +341,7 @@ mod tests {
 
         create_task(&db, "task1", "Done Task", "task", "done").await;
 
-        let cmd = ReadyCommand {};
+        let cmd = ReadyCommand { all: false };
         let result = cmd.execute(&db).await.unwrap();
 
         assert!(result.todo_ready.is_empty());
@@ -317,7 +359,7 @@ mod tests {
         create_task(&db, "ticket1", "Ticket", "ticket", "todo").await;
         create_child_of(&db, "ticket1", "epic1").await;
 
-        let cmd = ReadyCommand {};
+        let cmd = ReadyCommand { all: false };
         let result = cmd.execute(&db).await.unwrap();
 
         // Should show only epic1, not ticket1
@@ -336,7 +378,7 @@ mod tests {
         create_task(&db, "ticket1", "Ticket", "ticket", "in_progress").await;
         create_child_of(&db, "ticket1", "epic1").await;
 
-        let cmd = ReadyCommand {};
+        let cmd = ReadyCommand { all: false };
         let result = cmd.execute(&db).await.unwrap();
 
         // Epic should be excluded because work has started on a child
@@ -354,7 +396,7 @@ mod tests {
         create_task(&db, "blocked", "Blocked", "task", "todo").await;
         create_depends_on(&db, "blocked", "blocker").await;
 
-        let cmd = ReadyCommand {};
+        let cmd = ReadyCommand { all: false };
         let result = cmd.execute(&db).await.unwrap();
 
         // Should show only blocker (blocked is blocked by an incomplete task)
@@ -373,7 +415,7 @@ mod tests {
         create_task(&db, "blocked", "Blocked", "task", "todo").await;
         create_depends_on(&db, "blocked", "blocker").await;
 
-        let cmd = ReadyCommand {};
+        let cmd = ReadyCommand { all: false };
         let result = cmd.execute(&db).await.unwrap();
 
         // Should show blocked task (blocker is done)
@@ -392,7 +434,7 @@ mod tests {
         create_task(&db, "ticket1", "Ticket", "ticket", "todo").await;
         create_task(&db, "epic1", "Epic", "epic", "todo").await;
 
-        let cmd = ReadyCommand {};
+        let cmd = ReadyCommand { all: false };
         let result = cmd.execute(&db).await.unwrap();
 
         // Should show all three since they're independent
@@ -407,6 +449,62 @@ mod tests {
         cleanup(&temp_dir);
     }
 
+    #[tokio::test]
+    async fn test_ready_orders_by_priority_within_level_with_none_last() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        create_task(&db, "nopri", "No priority", "task", "todo").await;
+        create_task(&db, "low", "Low", "task", "todo").await;
+        create_task(&db, "crit", "Critical", "task", "todo").await;
+        create_task(&db, "epic1", "Epic", "epic", "todo").await;
+        db.client()
+            .query(
+                "UPDATE task:low SET priority = 'low'; UPDATE task:crit SET priority = 'critical'",
+            )
+            .await
+            .unwrap();
+
+        let cmd = ReadyCommand { all: false };
+        let result = cmd.execute(&db).await.unwrap();
+
+        let ids: Vec<&str> = result.todo_ready.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(ids, vec!["epic1", "crit", "low", "nopri"]);
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_ready_all_lists_every_unblocked_todo_task() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        create_task(&db, "epic1", "Epic", "epic", "todo").await;
+        create_task(&db, "task1", "Child task", "task", "todo").await;
+        create_task(&db, "task2", "Blocked task", "task", "todo").await;
+        create_task(&db, "blocker", "Blocker", "task", "in_progress").await;
+        create_child_of(&db, "task1", "epic1").await;
+        create_depends_on(&db, "task2", "blocker").await;
+
+        let result = ReadyCommand { all: true }.execute(&db).await.unwrap();
+
+        let mut ids: Vec<&str> = result.todo_ready.iter().map(|t| t.id.as_str()).collect();
+        ids.sort();
+        assert_eq!(ids, vec!["epic1", "task1"]);
+        assert!(result.backlog_ready.is_empty());
+
+        let output = result.to_string();
+        assert!(output.contains("Child task"));
+        assert!(!output.contains("Ready to work"));
+
+        db.client()
+            .query("UPDATE task:blocker SET status = 'done'")
+            .await
+            .unwrap();
+        let result = ReadyCommand { all: true }.execute(&db).await.unwrap();
+        assert_eq!(result.todo_ready.len(), 3);
+
+        cleanup(&temp_dir);
+    }
+
     #[tokio::test]
     async fn test_ready_deep_hierarchy() {
         let (db, temp_dir) = setup_test_db().await;
@@ -418,7 +516,7 @@ mod tests {
         create_child_of(&db, "ticket1", "epic1").await;
         create_child_of(&db, "task1", "ticket1").await;
 
-        let cmd = ReadyCommand {};
+        let cmd = ReadyCommand { all: false };
         let result = cmd.execute(&db).await.unwrap();
 
         // Should show only epic1 (highest level entry point)
@@ -440,7 +538,7 @@ mod tests {
         create_child_of(&db, "ticket1", "epic1").await;
         create_child_of(&db, "task1", "ticket1").await;
 
-        let cmd = ReadyCommand {};
+        let cmd = ReadyCommand { all: false };
         let result = cmd.execute(&db).await.unwrap();
 
         // Should show nothing (work started deep in hierarchy)
@@ -463,7 +561,7 @@ mod tests {
         create_child_of(&db, "ticket1", "epic1").await;
         create_child_of(&db, "ticket2", "epic1").await;
 
-        let cmd = ReadyCommand {};
+        let cmd = ReadyCommand { all: false };
         let result = cmd.execute(&db).await.unwrap();
 
         // Epic has work started (ticket1 is in_progress), so it shouldn't show
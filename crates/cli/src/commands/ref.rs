@@ -3,10 +3,17 @@
 //! Implements the `vtb ref` command to add code references for context curation.
 //! Supports GitHub-style file:line notation (file:L45-67, file:L45, or just file).
 
+use crate::id::resolve_task_id;
 use clap::Args;
 use serde::Deserialize;
-use std::path::Path;
-use vertebrae_db::{Database, DbError};
+use std::path::{Path, PathBuf};
+use vertebrae_db::{Database, DbError, find_project_root};
+
+/// Directories skipped when looking for a path suggestion
+const SKIPPED_DIRS: &[&str] = &["target", "node_modules"];
+
+/// Upper bound on files scanned when looking for a path suggestion
+const MAX_SCANNED_FILES: usize = 20_000;
 
 /// Add a code reference to a task
 #[derive(Debug, Args)]
@@ -26,6 +33,10 @@ pub struct RefCommand {
     /// Optional description of what this reference points to
     #[arg(long, visible_alias = "desc")]
     pub description: Option<String>,
+
+    /// Fail instead of warning when the file does not exist
+    #[arg(long)]
+    pub strict: bool,
 }
 
 /// Result of parsing a file specification
@@ -109,6 +120,125 @@ struct CodeRefRow {
     description: Option<String>,
 }
 
+/// Outcome of checking a reference path against the project tree
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathCheck {
+    /// The path exists
+    Exists,
+    /// The path does not exist; holds the closest existing file, if any is close enough
+    Missing { suggestion: Option<String> },
+}
+
+impl PathCheck {
+    /// Describe a missing path, including the suggestion if there is one
+    fn message(&self, path: &str) -> Option<String> {
+        match self {
+            PathCheck::Exists => None,
+            PathCheck::Missing { suggestion: None } => {
+                Some(format!("file '{}' does not exist", path))
+            }
+            PathCheck::Missing {
+                suggestion: Some(suggestion),
+            } => Some(format!(
+                "file '{}' does not exist (did you mean '{}'?)",
+                path, suggestion
+            )),
+        }
+    }
+}
+
+/// Check whether `path` exists relative to `root`, suggesting the closest file if not.
+///
+/// Absolute paths are checked as-is. Suggestions come from the files under
+/// `root` (skipping hidden and build directories), compared by edit distance
+/// on the relative path; a candidate must differ in at most a third of the
+/// characters to be suggested.
+pub fn check_ref_path(root: &Path, path: &str) -> PathCheck {
+    let candidate = Path::new(path);
+    let full = if candidate.is_absolute() {
+        candidate.to_path_buf()
+    } else {
+        root.join(candidate)
+    };
+
+    if full.exists() {
+        return PathCheck::Exists;
+    }
+
+    if candidate.is_absolute() {
+        return PathCheck::Missing { suggestion: None };
+    }
+
+    let max_distance = (path.chars().count() / 3).max(1);
+    let suggestion = list_project_files(root)
+        .into_iter()
+        .map(|file| (edit_distance(path, &file), file))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.cmp(&b.1)))
+        .map(|(_, file)| file);
+
+    PathCheck::Missing { suggestion }
+}
+
+/// List files under `root` as `/`-separated relative paths.
+fn list_project_files(root: &Path) -> Vec<String> {
+    let mut files = Vec::new();
+    let mut dirs: Vec<PathBuf> = vec![root.to_path_buf()];
+
+    while let Some(dir) = dirs.pop() {
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+
+        for entry in entries.flatten() {
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            if name.starts_with('.') {
+                continue;
+            }
+
+            let path = entry.path();
+            let Ok(file_type) = entry.file_type() else {
+                continue;
+            };
+
+            if file_type.is_dir() {
+                if !SKIPPED_DIRS.contains(&name.as_ref()) {
+                    dirs.push(path);
+                }
+            } else if let Ok(relative) = path.strip_prefix(root) {
+                let parts: Vec<String> = relative
+                    .components()
+                    .map(|c| c.as_os_str().to_string_lossy().into_owned())
+                    .collect();
+                files.push(parts.join("/"));
+                if files.len() >= MAX_SCANNED_FILES {
+                    return files;
+                }
+            }
+        }
+    }
+
+    files
+}
+
+/// Levenshtein distance between two strings, counted in characters.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
 /// Parse a file specification into its components.
 ///
 /// Supports:
@@ -207,10 +337,11 @@ impl RefCommand {
     /// Returns `DbError` if:
     /// - The task with the given ID does not exist
     /// - The file specification is invalid
+    /// - The file does not exist and `--strict` is set
     /// - Database operations fail
     pub async fn execute(&self, db: &Database) -> Result<RefResult, DbError> {
-        // Normalize ID to lowercase for case-insensitive lookup
-        let id = self.id.to_lowercase();
+        // Resolve case-insensitive and shortened IDs
+        let id = resolve_task_id(db, &self.id).await?;
 
         // Parse the file specification
         let parsed = parse_file_ref(&self.file_spec).map_err(|msg| DbError::InvalidPath {
@@ -221,12 +352,19 @@ impl RefCommand {
         // Fetch task and verify it exists
         self.fetch_task_refs(db, &id).await?;
 
-        // Check if file exists (warning only)
-        let warning = if !Path::new(&parsed.path).exists() {
-            Some(format!("file '{}' does not exist", parsed.path))
-        } else {
-            None
-        };
+        // Check the path relative to the project root (warning unless --strict)
+        let root = find_project_root()
+            .or_else(|| std::env::current_dir().ok())
+            .unwrap_or_default();
+        let warning = check_ref_path(&root, &parsed.path).message(&parsed.path);
+        if self.strict
+            && let Some(reason) = warning
+        {
+            return Err(DbError::InvalidPath {
+                path: PathBuf::from(&parsed.path),
+                reason,
+            });
+        }
 
         // Append the new reference
         self.append_ref(db, &id, &parsed).await?;
@@ -247,7 +385,7 @@ impl RefCommand {
     /// Fetch the task by ID and return its refs (mainly to verify task exists).
     async fn fetch_task_refs(&self, db: &Database, id: &str) -> Result<TaskRefsRow, DbError> {
         let query = format!("SELECT id, refs FROM task:{}", id);
-        let mut result = db.client().query(&query).await?;
+        let mut result = db.query(&query).await?;
         let task: Option<TaskRefsRow> = result.take(0)?;
 
         task.ok_or_else(|| DbError::NotFound {
@@ -291,7 +429,7 @@ impl RefCommand {
             "UPDATE task:{} SET refs = array::append(refs, {})",
             id, ref_obj
         );
-        db.client().query(&query).await?;
+        db.query(&query).await?;
 
         Ok(())
     }
@@ -299,7 +437,7 @@ impl RefCommand {
     /// Update the task's updated_at timestamp.
     async fn update_timestamp(&self, db: &Database, id: &str) -> Result<(), DbError> {
         let query = format!("UPDATE task:{} SET updated_at = time::now()", id);
-        db.client().query(&query).await?;
+        db.query(&query).await?;
         Ok(())
     }
 }
@@ -492,6 +630,7 @@ mod tests {
             file_spec: "nonexistent/path/file.rs".to_string(),
             name: None,
             description: None,
+            strict: false,
         };
 
         let result = cmd.execute(&db).await;
@@ -524,6 +663,7 @@ mod tests {
             file_spec: "src/auth.ex:L45-67".to_string(),
             name: None,
             description: None,
+            strict: false,
         };
 
         let result = cmd.execute(&db).await;
@@ -552,6 +692,7 @@ mod tests {
             file_spec: "src/auth.ex:L45".to_string(),
             name: None,
             description: None,
+            strict: false,
         };
 
         let result = cmd.execute(&db).await;
@@ -580,6 +721,7 @@ mod tests {
             file_spec: "src/auth.ex:L45-67".to_string(),
             name: Some("hash_password".to_string()),
             description: None,
+            strict: false,
         };
 
         let result = cmd.execute(&db).await;
@@ -603,6 +745,7 @@ mod tests {
             file_spec: "src/auth.ex:L45-67".to_string(),
             name: None,
             description: Some("Main authentication function".to_string()),
+            strict: false,
         };
 
         let result = cmd.execute(&db).await;
@@ -629,6 +772,7 @@ mod tests {
             file_spec: "src/auth.ex:L120".to_string(),
             name: Some("authenticate".to_string()),
             description: Some("Entry point".to_string()),
+            strict: false,
         };
 
         let result = cmd.execute(&db).await;
@@ -653,6 +797,7 @@ mod tests {
             file_spec: "src/auth.ex:L45-67".to_string(),
             name: Some("hash_password".to_string()),
             description: None,
+            strict: false,
         };
         cmd1.execute(&db).await.unwrap();
 
@@ -661,6 +806,7 @@ mod tests {
             file_spec: "src/auth.ex:L120".to_string(),
             name: Some("authenticate".to_string()),
             description: Some("Entry point".to_string()),
+            strict: false,
         };
         cmd2.execute(&db).await.unwrap();
 
@@ -669,6 +815,7 @@ mod tests {
             file_spec: "config/auth.exs".to_string(),
             name: Some("config".to_string()),
             description: None,
+            strict: false,
         };
         cmd3.execute(&db).await.unwrap();
 
@@ -710,6 +857,7 @@ mod tests {
             file_spec: "src/main.rs".to_string(),
             name: None,
             description: None,
+            strict: false,
         };
 
         let result = cmd.execute(&db).await;
@@ -739,6 +887,7 @@ mod tests {
             file_spec: "src/auth.ex:L67-45".to_string(),
             name: None,
             description: None,
+            strict: false,
         };
 
         let result = cmd.execute(&db).await;
@@ -768,6 +917,7 @@ mod tests {
             file_spec: "src/auth.ex:Labcd".to_string(),
             name: None,
             description: None,
+            strict: false,
         };
 
         let result = cmd.execute(&db).await;
@@ -803,6 +953,7 @@ mod tests {
             file_spec: "src/main.rs".to_string(),
             name: None,
             description: None,
+            strict: false,
         };
 
         let result = cmd.execute(&db).await;
@@ -831,6 +982,7 @@ mod tests {
             file_spec: "src/main.rs".to_string(),
             name: None,
             description: None,
+            strict: false,
         };
 
         let result = cmd.execute(&db).await;
@@ -854,6 +1006,7 @@ mod tests {
             file_spec: "src/main.rs".to_string(),
             name: Some(r#"name with "quotes""#.to_string()),
             description: Some(r#"desc with \backslash"#.to_string()),
+            strict: false,
         };
 
         let result = cmd.execute(&db).await;
@@ -948,6 +1101,7 @@ mod tests {
             file_spec: "src/main.rs:10-20".to_string(),
             name: Some("test_fn".to_string()),
             description: Some("Test description".to_string()),
+            strict: false,
         };
         let debug_str = format!("{:?}", cmd);
         assert!(
@@ -1002,4 +1156,148 @@ mod tests {
             "Debug output should contain ParsedFileRef and all field values"
         );
     }
+
+    /// Create a temp project tree containing the given files
+    fn setup_project(files: &[&str]) -> std::path::PathBuf {
+        let root = env::temp_dir().join(format!(
+            "vtb-ref-project-{}-{:?}-{}",
+            std::process::id(),
+            std::thread::current().id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        for file in files {
+            let path = root.join(file);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, "").unwrap();
+        }
+        root
+    }
+
+    #[test]
+    fn test_check_ref_path_exists() {
+        let root = setup_project(&["src/auth.rs"]);
+        assert_eq!(check_ref_path(&root, "src/auth.rs"), PathCheck::Exists);
+        cleanup(&root);
+    }
+
+    #[test]
+    fn test_check_ref_path_missing_suggests_closest() {
+        let root = setup_project(&["src/auth.rs", "src/author.rs", "lib/other.rs"]);
+
+        let check = check_ref_path(&root, "src/auht.rs");
+        assert_eq!(
+            check,
+            PathCheck::Missing {
+                suggestion: Some("src/auth.rs".to_string())
+            }
+        );
+        assert_eq!(
+            check.message("src/auht.rs").unwrap(),
+            "file 'src/auht.rs' does not exist (did you mean 'src/auth.rs'?)"
+        );
+
+        cleanup(&root);
+    }
+
+    #[test]
+    fn test_check_ref_path_missing_without_close_match() {
+        let root = setup_project(&["src/auth.rs"]);
+
+        let check = check_ref_path(&root, "docs/completely/different.md");
+        assert_eq!(check, PathCheck::Missing { suggestion: None });
+        assert_eq!(
+            check.message("docs/completely/different.md").unwrap(),
+            "file 'docs/completely/different.md' does not exist"
+        );
+
+        cleanup(&root);
+    }
+
+    #[test]
+    fn test_check_ref_path_skips_hidden_and_build_dirs() {
+        let root = setup_project(&["target/debug/main.rs", ".git/main.rs", "src/lib.rs"]);
+
+        let check = check_ref_path(&root, "debug/main.rs");
+        assert_eq!(check, PathCheck::Missing { suggestion: None });
+
+        cleanup(&root);
+    }
+
+    #[test]
+    fn test_check_ref_path_absolute() {
+        let root = setup_project(&["src/auth.rs"]);
+
+        let existing = root.join("src/auth.rs");
+        assert_eq!(
+            check_ref_path(Path::new("/unrelated"), existing.to_str().unwrap()),
+            PathCheck::Exists
+        );
+        assert_eq!(
+            check_ref_path(&root, "/definitely/not/here.rs"),
+            PathCheck::Missing { suggestion: None }
+        );
+
+        cleanup(&root);
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("abc", "abc"), 0);
+        assert_eq!(edit_distance("abc", ""), 3);
+        assert_eq!(edit_distance("auth", "auht"), 2);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+
+    #[tokio::test]
+    async fn test_ref_strict_rejects_missing_file() {
+        let (db, temp_dir) = setup_test_db().await;
+        create_task(&db, "task1", "Test Task").await;
+
+        let cmd = RefCommand {
+            id: "task1".to_string(),
+            file_spec: "nonexistent/path/file.rs:L10".to_string(),
+            name: None,
+            description: None,
+            strict: true,
+        };
+
+        let result = cmd.execute(&db).await;
+        match result {
+            Err(DbError::InvalidPath { reason, .. }) => {
+                assert!(reason.contains("does not exist"), "reason: {}", reason);
+            }
+            other => panic!("Expected InvalidPath error, got {:?}", other),
+        }
+
+        // Nothing was appended
+        let row = cmd.fetch_task_refs(&db, "task1").await.unwrap();
+        assert!(row.code_refs.is_empty());
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_ref_strict_accepts_existing_file() {
+        let (db, temp_dir) = setup_test_db().await;
+        create_task(&db, "task1", "Test Task").await;
+
+        // Cargo runs tests from the crate directory, which is inside the project root
+        let existing = std::env::current_dir().unwrap().join("Cargo.toml");
+        let cmd = RefCommand {
+            id: "task1".to_string(),
+            file_spec: existing.to_str().unwrap().to_string(),
+            name: None,
+            description: None,
+            strict: true,
+        };
+
+        let result = cmd.execute(&db).await.unwrap();
+        assert!(result.warning.is_none());
+
+        cleanup(&temp_dir);
+    }
 }
@@ -3,6 +3,7 @@
 //! Implements the `vtb refs` command to display all code references for a task,
 //! sorted by file path and then line number.
 
+use crate::id::resolve_task_id;
 use clap::Args;
 use serde::Deserialize;
 use vertebrae_db::{CodeRef, Database, DbError};
@@ -155,8 +156,8 @@ impl RefsCommand {
     /// - The task with the given ID does not exist
     /// - Database operations fail
     pub async fn execute(&self, db: &Database) -> Result<RefsResult, DbError> {
-        // Normalize ID to lowercase for case-insensitive lookup
-        let id = self.id.to_lowercase();
+        // Resolve case-insensitive and shortened IDs
+        let id = resolve_task_id(db, &self.id).await?;
 
         // Fetch task refs
         let task = self.fetch_task_refs(db, &id).await?;
@@ -207,7 +208,7 @@ impl RefsCommand {
     /// Fetch the task by ID and return its refs.
     async fn fetch_task_refs(&self, db: &Database, id: &str) -> Result<TaskRefsRow, DbError> {
         let query = format!("SELECT id, title, refs FROM task:{}", id);
-        let mut result = db.client().query(&query).await?;
+        let mut result = db.query(&query).await?;
         let task: Option<TaskRefsRow> = result.take(0)?;
 
         task.ok_or_else(|| DbError::NotFound {
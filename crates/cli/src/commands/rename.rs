@@ -0,0 +1,293 @@
+//! Rename command for changing a task's title
+//!
+//! Implements the `vtb rename` command (alias `set-title`), a shortcut for
+//! `vtb update --title`. The new title can also be piped in on stdin, which
+//! avoids shell quoting for long titles.
+
+use crate::id::resolve_task_id;
+use std::io::Read;
+
+use clap::Args;
+use serde::Deserialize;
+use vertebrae_db::{Database, DbError, TaskUpdate};
+
+/// Title argument that means "read the title from stdin"
+const STDIN_TITLE: &str = "-";
+
+/// Change the title of a task
+#[derive(Debug, Args)]
+pub struct RenameCommand {
+    /// Task ID to rename (case-insensitive)
+    #[arg(required = true)]
+    pub id: String,
+
+    /// New title (omit or pass "-" to read it from stdin)
+    pub title: Option<String>,
+}
+
+/// Result of the rename command execution
+#[derive(Debug)]
+pub struct RenameResult {
+    /// The renamed task's ID
+    pub id: String,
+    /// Title before the rename
+    pub old_title: String,
+    /// Title after the rename
+    pub title: String,
+}
+
+impl std::fmt::Display for RenameResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.old_title == self.title {
+            write!(f, "Task {} already titled \"{}\"", self.id, self.title)
+        } else {
+            write!(
+                f,
+                "Renamed task {}: \"{}\" -> \"{}\"",
+                self.id, self.old_title, self.title
+            )
+        }
+    }
+}
+
+/// Result from querying a task's title
+#[derive(Debug, Deserialize)]
+struct TitleRow {
+    title: String,
+}
+
+impl RenameCommand {
+    /// Execute the rename command.
+    ///
+    /// Reads the title from stdin when it was omitted or given as `-`.
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - Reference to the database connection
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError` if:
+    /// - The new title is empty
+    /// - The task with the given ID does not exist
+    /// - Reading stdin or database operations fail
+    pub async fn execute(&self, db: &Database) -> Result<RenameResult, DbError> {
+        let title = self.resolve_title(std::io::stdin().lock())?;
+        self.rename(db, &title).await
+    }
+
+    /// Determine the new title, reading `input` if the argument asks for it.
+    ///
+    /// Surrounding whitespace is trimmed and line breaks are collapsed to
+    /// single spaces, since titles are shown on one line.
+    fn resolve_title<R: Read>(&self, mut input: R) -> Result<String, DbError> {
+        let raw = match self.title.as_deref() {
+            Some(title) if title != STDIN_TITLE => title.to_string(),
+            _ => {
+                let mut buf = String::new();
+                input
+                    .read_to_string(&mut buf)
+                    .map_err(|e| DbError::InvalidPath {
+                        path: std::path::PathBuf::from("<stdin>"),
+                        reason: format!("Failed to read title: {}", e),
+                    })?;
+                buf
+            }
+        };
+
+        let title = raw.lines().map(str::trim).filter(|l| !l.is_empty());
+        let title = title.collect::<Vec<_>>().join(" ");
+
+        if title.is_empty() {
+            return Err(DbError::ValidationError {
+                message: "Title cannot be empty".to_string(),
+            });
+        }
+        Ok(title)
+    }
+
+    /// Set the task's title and bump its `updated_at`.
+    async fn rename(&self, db: &Database, title: &str) -> Result<RenameResult, DbError> {
+        // Resolve case-insensitive and shortened IDs
+        let id = resolve_task_id(db, &self.id).await?;
+        let old_title = self.get_current_title(db, &id).await?;
+
+        if old_title != title {
+            let updates = TaskUpdate::new().with_title(title);
+            db.tasks().update(&id, &updates).await?;
+        }
+
+        Ok(RenameResult {
+            id,
+            old_title,
+            title: title.to_string(),
+        })
+    }
+
+    /// Get the current title, or `NotFound` if the task doesn't exist.
+    async fn get_current_title(&self, db: &Database, id: &str) -> Result<String, DbError> {
+        let query = format!("SELECT title FROM task:{}", id);
+        let mut result = db.query(&query).await?;
+        let row: Option<TitleRow> = result.take(0)?;
+
+        row.map(|r| r.title).ok_or_else(|| DbError::NotFound {
+            task_id: self.id.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::io::Cursor;
+
+    /// Helper to create a test database
+    async fn setup_test_db() -> (Database, std::path::PathBuf) {
+        let temp_dir = env::temp_dir().join(format!(
+            "vtb-rename-test-{}-{:?}-{}",
+            std::process::id(),
+            std::thread::current().id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+
+        let db = Database::connect(&temp_dir).await.unwrap();
+        db.init().await.unwrap();
+
+        (db, temp_dir)
+    }
+
+    /// Helper to create a task with a fixed, old updated_at
+    async fn create_task(db: &Database, id: &str, title: &str) {
+        let query = format!(
+            r#"CREATE task:{} SET
+                title = "{}",
+                level = "ticket",
+                status = "todo",
+                priority = "high",
+                tags = ["backend"],
+                updated_at = d"2024-01-01T00:00:00Z""#,
+            id, title
+        );
+        db.client().query(&query).await.unwrap();
+    }
+
+    /// Clean up test database
+    fn cleanup(path: &std::path::Path) {
+        let _ = std::fs::remove_dir_all(path);
+    }
+
+    fn rename_cmd(id: &str, title: Option<&str>) -> RenameCommand {
+        RenameCommand {
+            id: id.to_string(),
+            title: title.map(String::from),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rename_changes_only_title() {
+        let (db, temp_dir) = setup_test_db().await;
+        create_task(&db, "task1", "Old title").await;
+        let before = db.tasks().get("task1").await.unwrap().unwrap();
+
+        let result = rename_cmd("TASK1", Some("New title"))
+            .execute(&db)
+            .await
+            .unwrap();
+        assert_eq!(result.id, "task1");
+        assert_eq!(result.old_title, "Old title");
+        assert_eq!(result.title, "New title");
+
+        let after = db.tasks().get("task1").await.unwrap().unwrap();
+        assert_eq!(after.title, "New title");
+        assert_eq!(after.level, before.level);
+        assert_eq!(after.status, before.status);
+        assert_eq!(after.priority, before.priority);
+        assert_eq!(after.tags, before.tags);
+        assert_eq!(after.created_at, before.created_at);
+        assert!(
+            after.updated_at > before.updated_at,
+            "updated_at not bumped"
+        );
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_rename_nonexistent_task() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        let result = rename_cmd("missing", Some("Title")).execute(&db).await;
+        assert!(
+            matches!(result, Err(DbError::NotFound { ref task_id }) if task_id == "missing"),
+            "Expected NotFound, got {:?}",
+            result
+        );
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_rename_same_title_is_noop() {
+        let (db, temp_dir) = setup_test_db().await;
+        create_task(&db, "task1", "Same").await;
+        let before = db.tasks().get("task1").await.unwrap().unwrap();
+
+        let result = rename_cmd("task1", Some("Same"))
+            .execute(&db)
+            .await
+            .unwrap();
+        assert_eq!(result.to_string(), "Task task1 already titled \"Same\"");
+
+        let after = db.tasks().get("task1").await.unwrap().unwrap();
+        assert_eq!(after.updated_at, before.updated_at);
+
+        cleanup(&temp_dir);
+    }
+
+    #[test]
+    fn test_resolve_title_from_argument_ignores_stdin() {
+        let cmd = rename_cmd("t", Some("  Spaced out  "));
+        let title = cmd.resolve_title(Cursor::new("from stdin")).unwrap();
+        assert_eq!(title, "Spaced out");
+    }
+
+    #[test]
+    fn test_resolve_title_from_stdin() {
+        let input = "A long title that\nwraps across lines\n\n";
+        for arg in [None, Some("-")] {
+            let title = rename_cmd("t", arg)
+                .resolve_title(Cursor::new(input))
+                .unwrap();
+            assert_eq!(title, "A long title that wraps across lines");
+        }
+    }
+
+    #[test]
+    fn test_resolve_title_rejects_empty() {
+        for (arg, input) in [(Some("   "), ""), (None, "\n  \n")] {
+            let result = rename_cmd("t", arg).resolve_title(Cursor::new(input));
+            assert!(
+                matches!(result, Err(DbError::ValidationError { .. })),
+                "Expected ValidationError, got {:?}",
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn test_rename_result_display() {
+        let result = RenameResult {
+            id: "abc123".to_string(),
+            old_title: "Old".to_string(),
+            title: "New".to_string(),
+        };
+        assert_eq!(
+            result.to_string(),
+            "Renamed task abc123: \"Old\" -> \"New\""
+        );
+    }
+}
@@ -0,0 +1,242 @@
+//! Restore command for taking tasks out of the trash
+//!
+//! Implements the `vtb restore` command, which undoes a soft `vtb delete`
+//! by clearing `deleted_at`. Descendants that went to the trash in the same
+//! cascade come back with the task.
+
+use crate::id::resolve_task_id;
+use clap::Args;
+use serde::Deserialize;
+use vertebrae_db::{Database, DbError};
+
+/// Restore a deleted task from the trash
+#[derive(Debug, Args)]
+pub struct RestoreCommand {
+    /// Task ID to restore (case-insensitive)
+    #[arg(required = true)]
+    pub id: String,
+}
+
+/// Result of the restore command execution
+#[derive(Debug)]
+pub struct RestoreResult {
+    /// The restored task's ID
+    pub id: String,
+    /// Descendants restored along with it
+    pub descendants: Vec<String>,
+}
+
+impl std::fmt::Display for RestoreResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.descendants.len() {
+            0 => write!(f, "Restored task {}", self.id),
+            1 => write!(f, "Restored task {} and 1 descendant", self.id),
+            n => write!(f, "Restored task {} and {} descendants", self.id, n),
+        }
+    }
+}
+
+/// A task's trash state
+#[derive(Debug, Deserialize)]
+struct TrashRow {
+    #[serde(default)]
+    deleted_at: Option<surrealdb::sql::Datetime>,
+}
+
+impl RestoreCommand {
+    /// Execute the restore command.
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - Reference to the database connection
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError` if:
+    /// - The task does not exist
+    /// - The task is not in the trash
+    /// - The task's parent is still in the trash
+    /// - Database operations fail
+    pub async fn execute(&self, db: &Database) -> Result<RestoreResult, DbError> {
+        // Resolve case-insensitive and shortened IDs
+        let id = resolve_task_id(db, &self.id).await?;
+
+        let query = format!("SELECT deleted_at FROM task:{}", id);
+        let mut result = db.query(&query).await?;
+        let row: Option<TrashRow> = result.take(0)?;
+        let deleted_at = row
+            .ok_or_else(|| DbError::NotFound {
+                task_id: id.clone(),
+            })?
+            .deleted_at
+            .ok_or_else(|| DbError::ValidationError {
+                message: format!("Task '{}' is not in the trash", id),
+            })?;
+
+        // A restored task must not hang off a parent nobody can see
+        if let Some(parent) = db.relationships().get_parent(&id).await? {
+            let query = format!("SELECT deleted_at FROM task:{}", parent);
+            let mut result = db.query(&query).await?;
+            let row: Option<TrashRow> = result.take(0)?;
+            if row.is_some_and(|r| r.deleted_at.is_some()) {
+                return Err(DbError::ValidationError {
+                    message: format!(
+                        "Parent '{}' of task '{}' is in the trash; restore it first",
+                        parent, id
+                    ),
+                });
+            }
+        }
+
+        let descendants = self.deleted_with(db, &id, &deleted_at).await?;
+
+        let query = format!(
+            "UPDATE task SET deleted_at = NONE, updated_at = time::now() WHERE id IN [{}]",
+            std::iter::once(&id)
+                .chain(&descendants)
+                .map(|id| format!("task:{}", id))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        db.query(&query).await?.check()?;
+
+        Ok(RestoreResult { id, descendants })
+    }
+
+    /// Descendants of `id` that were moved to the trash at `deleted_at`.
+    async fn deleted_with(
+        &self,
+        db: &Database,
+        id: &str,
+        deleted_at: &surrealdb::sql::Datetime,
+    ) -> Result<Vec<String>, DbError> {
+        let mut found = Vec::new();
+        let mut pending = vec![id.to_string()];
+        while let Some(current) = pending.pop() {
+            let query = format!(
+                "SELECT VALUE id FROM task \
+                 WHERE ->child_of->task CONTAINS task:{} AND deleted_at = $deleted_at",
+                current
+            );
+            let mut result = db
+                .query(&query)
+                .bind(("deleted_at", deleted_at.clone()))
+                .await?;
+            let children: Vec<surrealdb::sql::Thing> = result.take(0)?;
+            for child in children {
+                let child = child.id.to_string();
+                if !found.contains(&child) {
+                    found.push(child.clone());
+                    pending.push(child);
+                }
+            }
+        }
+        found.sort();
+        Ok(found)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::DeleteCommand;
+    use crate::confirm::Prompter;
+    use std::env;
+
+    async fn setup_test_db() -> (Database, std::path::PathBuf) {
+        let temp_dir = env::temp_dir().join(format!(
+            "vtb-restore-test-{}-{:?}-{}",
+            std::process::id(),
+            std::thread::current().id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        let db = Database::connect(&temp_dir).await.unwrap();
+        db.init().await.unwrap();
+        db.client()
+            .query(
+                r#"CREATE task:epic SET title = "Epic", level = "epic", status = "todo";
+                   CREATE task:ticket SET title = "Ticket", level = "ticket", status = "todo";
+                   CREATE task:leaf SET title = "Leaf", level = "task", status = "todo";
+                   CREATE task:early SET title = "Early", level = "task", status = "todo";
+                   RELATE task:ticket -> child_of -> task:epic;
+                   RELATE task:leaf -> child_of -> task:ticket;
+                   RELATE task:early -> child_of -> task:ticket"#,
+            )
+            .await
+            .unwrap();
+        (db, temp_dir)
+    }
+
+    fn cleanup(path: &std::path::Path) {
+        let _ = std::fs::remove_dir_all(path);
+    }
+
+    async fn soft_delete(db: &Database, id: &str, cascade: bool) {
+        DeleteCommand {
+            id: id.to_string(),
+            cascade,
+            hard: false,
+            force: true,
+        }
+        .execute_with(db, Prompter::non_interactive())
+        .await
+        .unwrap();
+    }
+
+    fn restore(id: &str) -> RestoreCommand {
+        RestoreCommand { id: id.to_string() }
+    }
+
+    async fn is_deleted(db: &Database, id: &str) -> bool {
+        db.tasks()
+            .get(id)
+            .await
+            .unwrap()
+            .unwrap()
+            .deleted_at
+            .is_some()
+    }
+
+    #[tokio::test]
+    async fn test_restore_brings_back_the_cascade_only() {
+        let (db, temp_dir) = setup_test_db().await;
+        soft_delete(&db, "early", false).await;
+        soft_delete(&db, "ticket", true).await;
+        assert!(is_deleted(&db, "leaf").await);
+
+        let result = restore("TICKET").execute(&db).await.unwrap();
+        assert_eq!(result.descendants, vec!["leaf"]);
+        assert_eq!(result.to_string(), "Restored task ticket and 1 descendant");
+        assert!(!is_deleted(&db, "ticket").await);
+        assert!(!is_deleted(&db, "leaf").await);
+        // Deleted on its own earlier, so it stays in the trash
+        assert!(is_deleted(&db, "early").await);
+        // The hierarchy is intact
+        assert_eq!(
+            db.relationships().get_parent("ticket").await.unwrap(),
+            Some("epic".to_string())
+        );
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_restore_errors() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        let err = restore("epic").execute(&db).await.unwrap_err();
+        assert!(err.to_string().contains("not in the trash"), "got: {}", err);
+        let err = restore("nope").execute(&db).await.unwrap_err();
+        assert!(matches!(err, DbError::NotFound { .. }));
+
+        soft_delete(&db, "leaf", false).await;
+        soft_delete(&db, "ticket", false).await;
+        let err = restore("leaf").execute(&db).await.unwrap_err();
+        assert!(err.to_string().contains("Parent 'ticket'"), "got: {}", err);
+
+        cleanup(&temp_dir);
+    }
+}
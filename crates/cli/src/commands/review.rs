@@ -2,14 +2,15 @@
 //!
 //! Implements the `vtb review` command to toggle the needs_human_review flag on tasks.
 
+use crate::id::resolve_task_id;
 use clap::Args;
 use serde::Deserialize;
 use vertebrae_db::{Database, DbError, TaskUpdate};
 
 /// Toggle the needs_human_review flag on a task
-#[derive(Debug, Args)]
+#[derive(Debug, Clone, Args)]
 pub struct ReviewCommand {
-    /// Task ID to toggle review flag (case-insensitive)
+    /// Task ID to toggle review flag (case-insensitive; `-` reads IDs from stdin)
     #[arg(required = true)]
     pub id: String,
 
@@ -41,8 +42,8 @@ impl ReviewCommand {
     /// - The task with the given ID does not exist
     /// - Database operations fail
     pub async fn execute(&self, db: &Database) -> Result<String, DbError> {
-        // Normalize ID to lowercase for case-insensitive lookup
-        let id = self.id.to_lowercase();
+        // Resolve case-insensitive and shortened IDs
+        let id = resolve_task_id(db, &self.id).await?;
 
         // Fetch current flag value
         let current = self.get_current_flag(db, &id).await?;
@@ -72,7 +73,6 @@ impl ReviewCommand {
         // CREATE task:123 have numeric IDs, causing a mismatch.
         let query = format!("SELECT needs_human_review FROM task:{}", id);
         let mut result = db
-            .client()
             .query(&query)
             .await
             .map_err(|e| DbError::Query(Box::new(e)))?;
@@ -0,0 +1,184 @@
+//! Search command for finding tasks by text
+//!
+//! Implements the `vtb search` command. Unlike `vtb list --search`, it also
+//! looks inside sections and ranks results: title matches first, then
+//! description matches, then section matches.
+
+use clap::Args;
+use vertebrae_db::{Database, DbError, SearchField, SearchHit};
+
+use crate::output::{color_mode, highlight};
+
+/// Search task titles, descriptions and sections
+#[derive(Debug, Args)]
+pub struct SearchCommand {
+    /// Text to look for (case-insensitive)
+    #[arg(required = true)]
+    pub query: String,
+
+    /// Show at most N results
+    #[arg(long, value_name = "N")]
+    pub limit: Option<usize>,
+}
+
+/// Result of the search command execution
+#[derive(Debug)]
+pub struct SearchResult {
+    /// The query searched for
+    pub query: String,
+    /// Matching tasks, best first
+    pub hits: Vec<SearchHit>,
+}
+
+impl SearchResult {
+    /// Render the results, highlighting matches when `highlighted` is set.
+    ///
+    /// Each hit is one line with the ID, the field that matched and the
+    /// title. Description and section matches add an indented line with
+    /// the matching text.
+    pub fn render(&self, highlighted: bool) -> String {
+        if self.hits.is_empty() {
+            return format!("No tasks match '{}'.", self.query);
+        }
+
+        let id_width = self.hits.iter().map(|h| h.task.id.len()).max().unwrap_or(0);
+        let field_width = self
+            .hits
+            .iter()
+            .map(|h| h.field.as_str().len())
+            .max()
+            .unwrap_or(0);
+        let indent = " ".repeat(id_width + field_width + 4);
+
+        let mut lines = Vec::new();
+        for hit in &self.hits {
+            let title = match hit.field {
+                SearchField::Title => highlight(&hit.task.title, hit.matched.clone(), highlighted),
+                _ => hit.task.title.clone(),
+            };
+            lines.push(format!(
+                "{:<iw$}  {:<fw$}  {}",
+                hit.task.id,
+                hit.field.as_str(),
+                title,
+                iw = id_width,
+                fw = field_width,
+            ));
+            if hit.field != SearchField::Title {
+                lines.push(format!(
+                    "{}{}",
+                    indent,
+                    highlight(hit.snippet.trim_end(), hit.matched.clone(), highlighted)
+                ));
+            }
+        }
+        lines.join("\n")
+    }
+}
+
+impl std::fmt::Display for SearchResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.render(color_mode().enabled()))
+    }
+}
+
+impl SearchCommand {
+    /// Execute the search command.
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - Reference to the database connection
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError` if the query is blank or the search fails.
+    pub async fn execute(&self, db: &Database) -> Result<SearchResult, DbError> {
+        let mut hits = db.list_tasks().search_ranked(&self.query).await?;
+        if let Some(limit) = self.limit {
+            hits.truncate(limit);
+        }
+        Ok(SearchResult {
+            query: self.query.trim().to_string(),
+            hits,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    async fn setup_test_db() -> (Database, std::path::PathBuf) {
+        let temp_dir = env::temp_dir().join(format!(
+            "vtb-search-test-{}-{:?}-{}",
+            std::process::id(),
+            std::thread::current().id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        let db = Database::connect(&temp_dir).await.unwrap();
+        db.init().await.unwrap();
+        db.client()
+            .query(
+                r#"CREATE task:a1 SET title = "Cache warmup", level = "task", status = "todo",
+                       description = "Prime the cache\nbefore deploys";
+                   CREATE task:b2 SET title = "Deploy script", level = "task", status = "todo";
+                   CREATE task:c3 SET title = "Docs", level = "task", status = "todo",
+                       sections = [{ type: "step", content: "Document the deploy flow" }]"#,
+            )
+            .await
+            .unwrap();
+        (db, temp_dir)
+    }
+
+    fn cleanup(path: &std::path::Path) {
+        let _ = std::fs::remove_dir_all(path);
+    }
+
+    fn search(query: &str, limit: Option<usize>) -> SearchCommand {
+        SearchCommand {
+            query: query.to_string(),
+            limit,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_search_ranks_and_renders_plain() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        let result = search("DEPLOY", None).execute(&db).await.unwrap();
+        assert_eq!(
+            result.render(false),
+            "b2  title        Deploy script\n\
+             a1  description  Cache warmup\n\
+             \x20                before deploys\n\
+             c3  section      Docs\n\
+             \x20                Document the deploy flow"
+        );
+        assert!(!result.to_string().contains('\x1b'));
+
+        let result = search("deploy", Some(1)).execute(&db).await.unwrap();
+        assert_eq!(result.hits.len(), 1);
+
+        let result = search("missing", None).execute(&db).await.unwrap();
+        assert_eq!(result.to_string(), "No tasks match 'missing'.");
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_search_highlights_match() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        let result = search("script", None).execute(&db).await.unwrap();
+        assert_eq!(
+            result.render(true),
+            "b2  title  Deploy \x1b[1;33mscript\x1b[0m"
+        );
+
+        cleanup(&temp_dir);
+    }
+}
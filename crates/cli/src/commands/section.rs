@@ -5,6 +5,7 @@
 //! step, testing_criterion) and negative space (anti_pattern, failure_test, constraint)
 //! section types.
 
+use crate::id::resolve_task_id;
 use clap::Args;
 use vertebrae_db::{Database, DbError, Section, SectionType, TaskUpdate};
 
@@ -26,7 +27,7 @@ pub struct SectionCommand {
 }
 
 /// Parse a section type string into SectionType enum (case-insensitive)
-fn parse_section_type(s: &str) -> Result<SectionType, String> {
+pub(crate) fn parse_section_type(s: &str) -> Result<SectionType, String> {
     match s.to_lowercase().as_str() {
         "goal" => Ok(SectionType::Goal),
         "context" => Ok(SectionType::Context),
@@ -102,8 +103,8 @@ impl SectionCommand {
     /// - The content is empty
     /// - Database operations fail
     pub async fn execute(&self, db: &Database) -> Result<SectionResult, DbError> {
-        // Normalize ID to lowercase for case-insensitive lookup
-        let id = self.id.to_lowercase();
+        // Resolve case-insensitive and shortened IDs
+        let id = resolve_task_id(db, &self.id).await?;
 
         // Validate content is not empty
         if self.content.trim().is_empty() {
@@ -164,11 +165,7 @@ impl SectionCommand {
 
     /// Fetch the task by ID and return its sections.
     async fn fetch_task_sections(&self, db: &Database, id: &str) -> Result<Vec<Section>, DbError> {
-        let task = db.tasks().get(id).await?;
-
-        task.map(|t| t.sections).ok_or_else(|| DbError::NotFound {
-            task_id: self.id.clone(),
-        })
+        Ok(db.tasks().get_or_err(id).await?.sections)
     }
 
     /// Calculate the ordinal for a multi-instance section type.
@@ -3,6 +3,7 @@
 //! Implements the `vtb sections` command to display all sections for a task,
 //! optionally filtered by type and grouped by positive/negative space.
 
+use crate::id::resolve_task_id;
 use clap::Args;
 use serde::Deserialize;
 use vertebrae_db::{Database, DbError, Section, SectionType};
@@ -259,8 +260,8 @@ impl SectionsCommand {
     /// - The task with the given ID does not exist
     /// - Database operations fail
     pub async fn execute(&self, db: &Database) -> Result<SectionsResult, DbError> {
-        // Normalize ID to lowercase for case-insensitive lookup
-        let id = self.id.to_lowercase();
+        // Resolve case-insensitive and shortened IDs
+        let id = resolve_task_id(db, &self.id).await?;
 
         // Fetch task sections
         let task = self.fetch_task_sections(db, &id).await?;
@@ -357,7 +358,7 @@ impl SectionsCommand {
         id: &str,
     ) -> Result<TaskSectionsRow, DbError> {
         let query = format!("SELECT id, sections FROM task:{}", id);
-        let mut result = db.client().query(&query).await?;
+        let mut result = db.query(&query).await?;
         let task: Option<TaskSectionsRow> = result.take(0)?;
 
         task.ok_or_else(|| DbError::NotFound {
@@ -842,14 +843,8 @@ mod tests {
         );
 
         // Check goal and steps appear
-        assert!(
-            lines.iter().any(|l| *l == "Goal: The goal"),
-            "Should have Goal line"
-        );
-        assert!(
-            lines.iter().any(|l| *l == "Steps:"),
-            "Should have Steps header"
-        );
+        assert!(lines.contains(&"Goal: The goal"), "Should have Goal line");
+        assert!(lines.contains(&"Steps:"), "Should have Steps header");
         assert!(
             lines.iter().any(|l| l.trim() == "[0] Step 1"),
             "Should have Step 1 with ordinal"
@@ -869,7 +864,7 @@ mod tests {
             "Undesired Behavior should come after Desired Behavior"
         );
         assert!(
-            lines.iter().any(|l| *l == "Anti-Patterns:"),
+            lines.contains(&"Anti-Patterns:"),
             "Should have Anti-Patterns header"
         );
         assert!(
@@ -894,19 +889,16 @@ mod tests {
 
         // Check Desired Behavior exists
         assert!(
-            lines.iter().any(|l| *l == "Desired Behavior"),
+            lines.contains(&"Desired Behavior"),
             "Should have Desired Behavior header"
         );
+        assert!(lines.contains(&"Goal: The goal"), "Should have Goal line");
         assert!(
-            lines.iter().any(|l| *l == "Goal: The goal"),
-            "Should have Goal line"
-        );
-        assert!(
-            lines.iter().any(|l| *l == "Context: Some context"),
+            lines.contains(&"Context: Some context"),
             "Should have Context line"
         );
         assert!(
-            !lines.iter().any(|l| *l == "Undesired Behavior"),
+            !lines.contains(&"Undesired Behavior"),
             "Should not have Undesired Behavior header"
         );
     }
@@ -926,19 +918,19 @@ mod tests {
         let lines: Vec<&str> = output.lines().collect();
 
         assert!(
-            !lines.iter().any(|l| *l == "Desired Behavior"),
+            !lines.contains(&"Desired Behavior"),
             "Should not have Desired Behavior header"
         );
         assert!(
-            lines.iter().any(|l| *l == "Undesired Behavior"),
+            lines.contains(&"Undesired Behavior"),
             "Should have Undesired Behavior header"
         );
         assert!(
-            lines.iter().any(|l| *l == "Anti-Patterns:"),
+            lines.contains(&"Anti-Patterns:"),
             "Should have Anti-Patterns header"
         );
         assert!(
-            lines.iter().any(|l| *l == "Constraints:"),
+            lines.contains(&"Constraints:"),
             "Should have Constraints header"
         );
     }
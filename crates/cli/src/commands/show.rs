@@ -4,20 +4,109 @@
 //! including sections, relationships, and code references.
 
 use crate::commands::list::TaskSummary;
+use crate::estimate::format_minutes;
+use crate::id::resolve_task_id;
+use crate::output::{OutputFormat, Render, level_label, progress_bar, to_json, to_yaml};
 use clap::Args;
-use serde::Deserialize;
-use vertebrae_db::{CodeRef, Database, DbError, Section, SectionType};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use vertebrae_db::{
+    CodeRef, Database, DbError, EstimateRollup, IdFormat, IdFormatter, Progress, Section,
+    SectionType, TreeChars, id_format, tree_chars,
+};
+
+/// Width of the progress bar shown for tasks with children
+const PROGRESS_BAR_WIDTH: usize = 20;
 
 /// Show full details of a task
-#[derive(Debug, Args)]
+#[derive(Debug, Clone, Args)]
 pub struct ShowCommand {
-    /// Task ID to show (case-insensitive)
+    /// Task ID to show (case-insensitive; `-` reads IDs from stdin)
     #[arg(required = true)]
     pub id: String,
+
+    /// Output format: table, json or yaml
+    #[arg(long, value_enum, default_value_t = OutputFormat::Table)]
+    pub format: OutputFormat,
+
+    /// Only show the parent, children, blockers and dependents
+    #[arg(long, conflicts_with = "sections_only")]
+    pub relationships_only: bool,
+
+    /// Only show the task's sections (goal, steps, constraints, ...)
+    #[arg(long)]
+    pub sections_only: bool,
+
+    /// Show the task's whole subtree instead of its details
+    #[arg(long, conflicts_with_all = ["relationships_only", "sections_only"])]
+    pub tree: bool,
+
+    /// With --tree, show at most N levels below the task
+    #[arg(long, value_name = "N", requires = "tree")]
+    pub depth: Option<usize>,
+}
+
+/// A task and its children, for `show --tree`
+#[derive(Debug, Clone, Serialize)]
+pub struct SubtreeNode {
+    /// Task ID
+    pub id: String,
+    /// Task title
+    pub title: String,
+    /// Hierarchy level
+    pub level: String,
+    /// Current status
+    pub status: String,
+    /// Children, ordered by ID
+    pub children: Vec<SubtreeNode>,
+    /// Descendants left out because of `--depth`
+    pub hidden: usize,
+}
+
+/// Row for the batched subtree lookup
+#[derive(Debug, Deserialize)]
+struct SubtreeRow {
+    id: surrealdb::sql::Thing,
+    title: String,
+    level: String,
+    status: String,
+    #[serde(default)]
+    parents: Vec<surrealdb::sql::Thing>,
+}
+
+/// Which part of a task `show` prints
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ShowView {
+    /// Everything
+    #[default]
+    Full,
+    /// Only the typed sections
+    Sections,
+    /// Only parent, children, blockers and dependents
+    Relationships,
+}
+
+/// The sections of a task, for `--sections-only` in JSON and YAML
+#[derive(Serialize)]
+struct SectionsView<'a> {
+    id: &'a str,
+    sections: &'a [Section],
+}
+
+/// The relationships of a task, for `--relationships-only` in JSON and YAML
+#[derive(Serialize)]
+struct RelationshipsView<'a> {
+    id: &'a str,
+    parent: &'a Option<TaskSummary>,
+    children: &'a [TaskSummary],
+    blocked_by: &'a [TaskSummary],
+    blocks: &'a [TaskSummary],
+    unblocks: &'a [TaskSummary],
+    blocker_path: &'a Option<Vec<String>>,
 }
 
 /// Detailed view of a task with all relationships
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct TaskDetail {
     /// The task ID
     pub id: String,
@@ -39,6 +128,18 @@ pub struct TaskDetail {
     pub updated_at: Option<String>,
     /// Completed timestamp
     pub completed_at: Option<String>,
+    /// Due date
+    #[serde(default)]
+    pub due_at: Option<String>,
+    /// Estimated effort in minutes
+    #[serde(default)]
+    pub estimate_minutes: Option<u32>,
+    /// Estimates summed over the task and its descendants (parents only)
+    #[serde(default)]
+    pub estimate_rollup: Option<EstimateRollup>,
+    /// Completion of the task and its descendants (parents only)
+    #[serde(default)]
+    pub progress: Option<Progress>,
     /// Whether this task needs human review
     pub needs_human_review: Option<bool>,
     /// Embedded sections
@@ -53,6 +154,12 @@ pub struct TaskDetail {
     pub blocked_by: Vec<TaskSummary>,
     /// Tasks that are blocked by this task
     pub blocks: Vec<TaskSummary>,
+    /// Tasks in `blocks` for which this task is the last incomplete blocker,
+    /// i.e. the ones completing this task would unblock right now
+    pub unblocks: Vec<TaskSummary>,
+    /// Shortest chain of incomplete blockers to the nearest one that isn't
+    /// blocked itself (starts with this task; `None` when not blocked)
+    pub blocker_path: Option<Vec<String>>,
 }
 
 /// Result from querying a task - handles SurrealDB Thing id format
@@ -74,6 +181,10 @@ struct TaskRow {
     #[serde(default)]
     completed_at: Option<surrealdb::sql::Datetime>,
     #[serde(default)]
+    due_at: Option<surrealdb::sql::Datetime>,
+    #[serde(default)]
+    estimate_minutes: Option<u32>,
+    #[serde(default)]
     needs_human_review: Option<bool>,
     #[serde(default)]
     sections: Vec<SectionRow>,
@@ -133,11 +244,24 @@ impl From<RelatedTaskRow> for TaskSummary {
             priority: row.priority,
             tags: row.tags,
             needs_human_review: row.needs_human_review,
+            created_at: None,
+            updated_at: None,
         }
     }
 }
 
 impl ShowCommand {
+    /// The part of the task selected by the `--*-only` flags.
+    pub fn view(&self) -> ShowView {
+        if self.sections_only {
+            ShowView::Sections
+        } else if self.relationships_only {
+            ShowView::Relationships
+        } else {
+            ShowView::Full
+        }
+    }
+
     /// Execute the show command.
     ///
     /// Fetches the task with the given ID along with all its relationships
@@ -153,8 +277,10 @@ impl ShowCommand {
     /// - The task with the given ID does not exist
     /// - Database operations fail
     pub async fn execute(&self, db: &Database) -> Result<TaskDetail, DbError> {
-        // Normalize ID to lowercase for case-insensitive lookup
-        let id = self.id.to_lowercase();
+        TaskDetail::check_format(self.format)?;
+
+        // Resolve case-insensitive and shortened IDs
+        let id = resolve_task_id(db, &self.id).await?;
 
         // Fetch the main task
         let task = self.fetch_task(db, &id).await?;
@@ -164,6 +290,19 @@ impl ShowCommand {
         let children = self.fetch_children(db, &id).await?;
         let blocked_by = self.fetch_blocked_by(db, &id).await?;
         let blocks = self.fetch_blocks(db, &id).await?;
+        let unblocks = self.fetch_unblocks(db, &id, &task.status, &blocks).await?;
+        let blocker_path = db
+            .graph()
+            .find_path_to_nearest_incomplete_blocker(&id)
+            .await?;
+        let (estimate_rollup, progress) = if children.is_empty() {
+            (None, None)
+        } else {
+            (
+                Some(db.graph().estimate_rollup(&id).await?),
+                Some(db.graph().get_progress(&id).await?),
+            )
+        };
 
         // Convert sections - filter out any without required fields
         let sections: Vec<Section> = task
@@ -239,9 +378,13 @@ impl ShowCommand {
             status: task.status,
             priority: task.priority,
             tags: task.tags,
-            created_at: task.created_at.map(|dt| dt.to_string()),
-            updated_at: task.updated_at.map(|dt| dt.to_string()),
-            completed_at: task.completed_at.map(|dt| dt.to_string()),
+            created_at: task.created_at.map(|dt| dt.0.to_rfc3339()),
+            updated_at: task.updated_at.map(|dt| dt.0.to_rfc3339()),
+            completed_at: task.completed_at.map(|dt| dt.0.to_rfc3339()),
+            due_at: task.due_at.map(|dt| dt.0.to_rfc3339()),
+            estimate_minutes: task.estimate_minutes,
+            estimate_rollup,
+            progress,
             needs_human_review: task.needs_human_review,
             sections,
             code_refs,
@@ -249,9 +392,35 @@ impl ShowCommand {
             children,
             blocked_by,
             blocks,
+            unblocks,
+            blocker_path,
         })
     }
 
+    /// Find the dependents that would become unblocked if this task were done.
+    ///
+    /// A finished task no longer blocks anything, and finished dependents
+    /// have nothing left to unblock, so both yield no entries.
+    async fn fetch_unblocks(
+        &self,
+        db: &Database,
+        id: &str,
+        status: &str,
+        blocks: &[TaskSummary],
+    ) -> Result<Vec<TaskSummary>, DbError> {
+        if status == "done" || blocks.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let unblocked = db.graph().get_unblocked_tasks(id).await?;
+        Ok(blocks
+            .iter()
+            .filter(|task| task.status != "done")
+            .filter(|task| unblocked.iter().any(|(uid, _)| *uid == task.id))
+            .cloned()
+            .collect())
+    }
+
     /// Fetch the main task by ID.
     async fn fetch_task(&self, db: &Database, id: &str) -> Result<TaskRow, DbError> {
         // Use raw query instead of .select() to handle both numeric and string IDs.
@@ -259,7 +428,6 @@ impl ShowCommand {
         // CREATE task:123 have numeric IDs, causing a mismatch.
         let query = format!("SELECT * FROM task:{}", id);
         let mut result = db
-            .client()
             .query(&query)
             .await
             .map_err(|e| DbError::Query(Box::new(e)))?;
@@ -276,11 +444,11 @@ impl ShowCommand {
         // SELECT ->child_of->task.* FROM task:<id> gets the parent
         let query = format!(
             "SELECT id, title, level, status, priority, tags, needs_human_review \
-             FROM task WHERE <-child_of<-task CONTAINS task:{}",
+             FROM task WHERE <-child_of<-task CONTAINS task:{} AND deleted_at = NONE",
             id
         );
 
-        let mut result = db.client().query(&query).await?;
+        let mut result = db.query(&query).await?;
         let parents: Vec<RelatedTaskRow> = result.take(0)?;
 
         Ok(parents.into_iter().next().map(TaskSummary::from))
@@ -291,26 +459,74 @@ impl ShowCommand {
         // SELECT <-child_of<-task.* FROM task:<id> gets children
         let query = format!(
             "SELECT id, title, level, status, priority, tags, needs_human_review \
-             FROM task WHERE ->child_of->task CONTAINS task:{}",
+             FROM task WHERE ->child_of->task CONTAINS task:{} AND deleted_at = NONE",
             id
         );
 
-        let mut result = db.client().query(&query).await?;
+        let mut result = db.query(&query).await?;
         let children: Vec<RelatedTaskRow> = result.take(0)?;
 
         Ok(children.into_iter().map(TaskSummary::from).collect())
     }
 
+    /// Fetch the task's subtree for `--tree`.
+    ///
+    /// Descendants come from `GraphQueries::get_all_descendants`; their
+    /// titles, statuses and parents are then looked up in one query.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError::NotFound` if the task does not exist, or another
+    /// `DbError` if the format is unsupported or database operations fail.
+    pub async fn execute_tree(&self, db: &Database) -> Result<SubtreeNode, DbError> {
+        SubtreeNode::check_format(self.format)?;
+
+        // Resolve case-insensitive and shortened IDs
+        let id = resolve_task_id(db, &self.id).await?;
+        if !db.tasks().exists(&id).await? {
+            return Err(DbError::NotFound { task_id: id });
+        }
+
+        let descendants = db.graph().get_all_descendants(&id).await?;
+        let query = format!(
+            "SELECT id, title, level, status, ->child_of->task AS parents \
+             FROM task WHERE id IN [{}]",
+            std::iter::once(&id)
+                .chain(&descendants)
+                .map(|id| format!("task:{}", id))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        let mut result = db.query(&query).await?;
+        let rows: Vec<SubtreeRow> = result.take(0)?;
+
+        let mut root = None;
+        let mut children: HashMap<String, Vec<SubtreeRow>> = HashMap::new();
+        for row in rows {
+            if row.id.id.to_string() == id {
+                root = Some(row);
+            } else if let Some(parent) = row.parents.first() {
+                children.entry(parent.id.to_string()).or_default().push(row);
+            }
+        }
+        for siblings in children.values_mut() {
+            siblings.sort_by_key(|row| row.id.id.to_string());
+        }
+
+        let root = root.ok_or(DbError::NotFound { task_id: id })?;
+        Ok(build_subtree(root, &mut children, 0, self.depth))
+    }
+
     /// Fetch tasks that this task depends on (blocked by).
     async fn fetch_blocked_by(&self, db: &Database, id: &str) -> Result<Vec<TaskSummary>, DbError> {
         // SELECT ->depends_on->task.* FROM task:<id> gets dependencies
         let query = format!(
             "SELECT id, title, level, status, priority, tags, needs_human_review \
-             FROM task WHERE <-depends_on<-task CONTAINS task:{}",
+             FROM task WHERE <-depends_on<-task CONTAINS task:{} AND deleted_at = NONE",
             id
         );
 
-        let mut result = db.client().query(&query).await?;
+        let mut result = db.query(&query).await?;
         let deps: Vec<RelatedTaskRow> = result.take(0)?;
 
         Ok(deps.into_iter().map(TaskSummary::from).collect())
@@ -321,11 +537,11 @@ impl ShowCommand {
         // SELECT <-depends_on<-task.* FROM task:<id> gets tasks that depend on this
         let query = format!(
             "SELECT id, title, level, status, priority, tags, needs_human_review \
-             FROM task WHERE ->depends_on->task CONTAINS task:{}",
+             FROM task WHERE ->depends_on->task CONTAINS task:{} AND deleted_at = NONE",
             id
         );
 
-        let mut result = db.client().query(&query).await?;
+        let mut result = db.query(&query).await?;
         let blocking: Vec<RelatedTaskRow> = result.take(0)?;
 
         Ok(blocking.into_iter().map(TaskSummary::from).collect())
@@ -349,34 +565,270 @@ fn parse_section_type(s: &str) -> SectionType {
     }
 }
 
-/// Format a TaskDetail for display
+impl TaskDetail {
+    /// Build a formatter over every ID this detail view shows.
+    ///
+    /// The task, its relatives and its blocker path are displayed together,
+    /// so short IDs must be unique across all of them.
+    pub fn id_formatter(&self, mode: IdFormat) -> IdFormatter {
+        let relatives = self
+            .parent
+            .iter()
+            .chain(&self.children)
+            .chain(&self.blocked_by)
+            .chain(&self.blocks)
+            .map(|task| task.id.as_str());
+        let path = self.blocker_path.iter().flatten().map(String::as_str);
+
+        IdFormatter::for_ids(
+            mode,
+            std::iter::once(self.id.as_str())
+                .chain(relatives)
+                .chain(path),
+        )
+    }
+
+    /// Display this detail with IDs shown in `mode`.
+    pub fn display_with(&self, mode: IdFormat) -> TaskDetailDisplay<'_> {
+        self.display_view(mode, ShowView::Full)
+    }
+
+    /// Display only the `view` part of this detail, with IDs shown in `mode`.
+    pub fn display_view(&self, mode: IdFormat, view: ShowView) -> TaskDetailDisplay<'_> {
+        TaskDetailDisplay {
+            detail: self,
+            ids: self.id_formatter(mode),
+            view,
+        }
+    }
+
+    /// Render only the `view` part of this detail in `format`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError::ValidationError` for an unsupported format, or
+    /// `DbError::Io` if serialization fails.
+    pub fn render_view(&self, format: OutputFormat, view: ShowView) -> Result<String, DbError> {
+        Self::check_format(format)?;
+        let sections = SectionsView {
+            id: &self.id,
+            sections: &self.sections,
+        };
+        let relationships = RelationshipsView {
+            id: &self.id,
+            parent: &self.parent,
+            children: &self.children,
+            blocked_by: &self.blocked_by,
+            blocks: &self.blocks,
+            unblocks: &self.unblocks,
+            blocker_path: &self.blocker_path,
+        };
+        match (view, format) {
+            (ShowView::Full, _) => self.render_as(format),
+            (ShowView::Sections, OutputFormat::Json) => to_json(&sections),
+            (ShowView::Sections, OutputFormat::Yaml) => to_yaml(&sections),
+            (ShowView::Relationships, OutputFormat::Json) => to_json(&relationships),
+            (ShowView::Relationships, OutputFormat::Yaml) => to_yaml(&relationships),
+            _ => Ok(self
+                .display_view(id_format(), view)
+                .to_string()
+                .trim_end()
+                .to_string()),
+        }
+    }
+}
+
+/// Turn `row` and the rows below it into a node, cutting off at `max_depth`.
+fn build_subtree(
+    row: SubtreeRow,
+    children: &mut HashMap<String, Vec<SubtreeRow>>,
+    depth: usize,
+    max_depth: Option<usize>,
+) -> SubtreeNode {
+    let id = row.id.id.to_string();
+    let below = children.remove(&id).unwrap_or_default();
+    let (children, hidden) = if max_depth.is_some_and(|max| depth >= max) {
+        let hidden = below
+            .into_iter()
+            .map(|child| count_subtree(child, children))
+            .sum();
+        (Vec::new(), hidden)
+    } else {
+        let nodes = below
+            .into_iter()
+            .map(|child| build_subtree(child, children, depth + 1, max_depth))
+            .collect();
+        (nodes, 0)
+    };
+    SubtreeNode {
+        id,
+        title: row.title,
+        level: row.level,
+        status: row.status,
+        children,
+        hidden,
+    }
+}
+
+/// Number of tasks in the subtree rooted at `row`, including it.
+fn count_subtree(row: SubtreeRow, children: &mut HashMap<String, Vec<SubtreeRow>>) -> usize {
+    let below = children.remove(&row.id.id.to_string()).unwrap_or_default();
+    1 + below
+        .into_iter()
+        .map(|child| count_subtree(child, children))
+        .sum::<usize>()
+}
+
+/// Status marker for a tree line, as in the TUI's task tree
+fn status_glyph(status: &str) -> &'static str {
+    match status {
+        "done" => "[x]",
+        "in_progress" => "[>]",
+        "pending_review" => "[?]",
+        "backlog" => "[.]",
+        "rejected" => "[-]",
+        _ => "[ ]",
+    }
+}
+
+impl SubtreeNode {
+    /// Render the tree with the given connector characters.
+    pub fn render_tree(&self, chars: TreeChars) -> String {
+        let mut lines = vec![format!(
+            "{} {}  {}",
+            status_glyph(&self.status),
+            self.id,
+            self.title
+        )];
+        self.push_children(&mut lines, "", chars);
+        lines.join("\n")
+    }
+
+    fn push_children(&self, lines: &mut Vec<String>, prefix: &str, chars: TreeChars) {
+        for (i, child) in self.children.iter().enumerate() {
+            let is_last = i == self.children.len() - 1 && self.hidden == 0;
+            let connector = if is_last {
+                chars.last_branch()
+            } else {
+                chars.branch()
+            };
+            lines.push(format!(
+                "{}{}{} {}  {}",
+                prefix,
+                connector,
+                status_glyph(&child.status),
+                child.id,
+                child.title
+            ));
+            let child_prefix = if is_last {
+                format!("{}    ", prefix)
+            } else {
+                format!("{}{}", prefix, chars.vertical())
+            };
+            child.push_children(lines, &child_prefix, chars);
+        }
+        if self.hidden > 0 {
+            lines.push(format!(
+                "{}{}\u{2026} ({} more)",
+                prefix,
+                chars.last_branch(),
+                self.hidden
+            ));
+        }
+    }
+}
+
+impl Render for SubtreeNode {
+    const COMMAND: &'static str = "show --tree";
+    const FORMATS: &'static [OutputFormat] =
+        &[OutputFormat::Table, OutputFormat::Json, OutputFormat::Yaml];
+
+    fn render_as(&self, format: OutputFormat) -> Result<String, DbError> {
+        match format {
+            OutputFormat::Json => to_json(self),
+            OutputFormat::Yaml => to_yaml(self),
+            _ => Ok(self.render_tree(tree_chars())),
+        }
+    }
+}
+
+/// Format a TaskDetail for display, using the configured ID format
+impl Render for TaskDetail {
+    const COMMAND: &'static str = "show";
+    const FORMATS: &'static [OutputFormat] =
+        &[OutputFormat::Table, OutputFormat::Json, OutputFormat::Yaml];
+
+    fn render_as(&self, format: OutputFormat) -> Result<String, DbError> {
+        match format {
+            OutputFormat::Json => to_json(self),
+            OutputFormat::Yaml => to_yaml(self),
+            _ => Ok(self.to_string()),
+        }
+    }
+}
+
 impl std::fmt::Display for TaskDetail {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.display_with(id_format()).fmt(f)
+    }
+}
+
+/// A [`TaskDetail`] paired with the formatter for its IDs
+pub struct TaskDetailDisplay<'a> {
+    detail: &'a TaskDetail,
+    ids: IdFormatter,
+    view: ShowView,
+}
+
+impl std::fmt::Display for TaskDetailDisplay<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let this = self.detail;
+        match self.view {
+            ShowView::Full => {}
+            ShowView::Sections if this.sections.is_empty() => return writeln!(f, "No sections"),
+            ShowView::Sections => return self.fmt_sections(f),
+            ShowView::Relationships if !self.has_relationships() => {
+                return writeln!(f, "No relationships");
+            }
+            ShowView::Relationships => return self.fmt_relationships(f),
+        }
+        let ids = &self.ids;
+
         // Header with task ID and title
-        writeln!(f, "Task: {} - {}", self.id, self.title)?;
+        writeln!(f, "Task: {} - {}", ids.format(&this.id), this.title)?;
         writeln!(f, "{}", "=".repeat(60))?;
         writeln!(f)?;
 
         // Metadata section
         writeln!(f, "Metadata")?;
         writeln!(f, "{}", "-".repeat(40))?;
-        writeln!(f, "Level:    {}", self.level)?;
-        writeln!(f, "Status:   {}", self.status)?;
+        writeln!(f, "Level:    {}", level_label(&this.level))?;
+        writeln!(f, "Status:   {}", this.status)?;
         writeln!(
             f,
             "Priority: {}",
-            self.priority.as_deref().unwrap_or("(none)")
+            this.priority.as_deref().unwrap_or("(none)")
         )?;
+        if let Some(estimate) = format_estimate(this.estimate_minutes, this.estimate_rollup) {
+            writeln!(f, "Estimate: {}", estimate)?;
+        }
+        if let Some(progress) = &this.progress {
+            writeln!(
+                f,
+                "Progress: {}",
+                progress_bar(progress, PROGRESS_BAR_WIDTH)
+            )?;
+        }
         writeln!(
             f,
             "Tags:     {}\n",
-            if self.tags.is_empty() {
+            if this.tags.is_empty() {
                 "(none)".to_string()
             } else {
-                self.tags.join(", ")
+                this.tags.join(", ")
             }
         )?;
-        let review_status = match self.needs_human_review {
+        let review_status = match this.needs_human_review {
             Some(true) => "True",
             Some(false) => "False",
             None => "False",
@@ -387,29 +839,83 @@ impl std::fmt::Display for TaskDetail {
         writeln!(
             f,
             "Started At:   {}",
-            format_timestamp(self.created_at.as_deref())
+            format_timestamp(this.created_at.as_deref())
         )?;
         writeln!(
             f,
             "Updated At:   {}",
-            format_timestamp(self.updated_at.as_deref())
+            format_timestamp(this.updated_at.as_deref())
         )?;
         writeln!(
             f,
             "Completed At: {}",
-            format_timestamp(self.completed_at.as_deref())
+            format_timestamp(this.completed_at.as_deref())
         )?;
+        if let Some(due_at) = this.due_at.as_deref() {
+            let overdue = if is_overdue(due_at, &this.status, chrono::Utc::now()) {
+                " (overdue)"
+            } else {
+                ""
+            };
+            writeln!(
+                f,
+                "Due At:       {}{}",
+                format_timestamp(Some(due_at)),
+                overdue
+            )?;
+        }
         writeln!(f)?;
 
         // Description section (if present)
-        if let Some(ref description) = self.description {
+        if let Some(ref description) = this.description {
             writeln!(f, "Description")?;
             writeln!(f, "{}", "-".repeat(40))?;
             writeln!(f, "{}", description)?;
             writeln!(f)?;
         }
 
-        // Render each section type with its own heading
+        self.fmt_sections(f)?;
+        if self.has_relationships() {
+            self.fmt_relationships(f)?;
+        }
+
+        // Code references section
+        if !this.code_refs.is_empty() {
+            writeln!(f, "Code References")?;
+            writeln!(f, "{}", "-".repeat(40))?;
+
+            for code_ref in &this.code_refs {
+                let location = format_code_ref_location(code_ref);
+                let name_part = code_ref
+                    .name
+                    .as_ref()
+                    .map(|n| format!(" [{}]", n))
+                    .unwrap_or_default();
+                let desc_part = code_ref
+                    .description
+                    .as_ref()
+                    .map(|d| format!(" ({})", d))
+                    .unwrap_or_default();
+                writeln!(f, "  - {}{}{}", location, name_part, desc_part)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl TaskDetailDisplay<'_> {
+    /// Whether the task has a parent, children, blockers or dependents.
+    fn has_relationships(&self) -> bool {
+        let this = self.detail;
+        this.parent.is_some()
+            || !this.children.is_empty()
+            || !this.blocked_by.is_empty()
+            || !this.blocks.is_empty()
+    }
+
+    /// Write each section type under its own heading.
+    fn fmt_sections(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         // Define section types in display order with their labels
         let section_configs: &[(SectionType, &str)] = &[
             (SectionType::Goal, "Goal"),
@@ -424,72 +930,65 @@ impl std::fmt::Display for TaskDetail {
         ];
 
         for (section_type, label) in section_configs {
-            format_section_with_heading(f, &self.sections, section_type.clone(), label)?;
+            format_section_with_heading(f, &self.detail.sections, section_type.clone(), label)?;
         }
+        Ok(())
+    }
 
-        // Relationships section
-        let has_relationships = self.parent.is_some()
-            || !self.children.is_empty()
-            || !self.blocked_by.is_empty()
-            || !self.blocks.is_empty();
+    /// Write the Relationships block.
+    fn fmt_relationships(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ids = &self.ids;
+        let this = self.detail;
+        writeln!(f, "Relationships")?;
+        writeln!(f, "{}", "-".repeat(40))?;
 
-        if has_relationships {
-            writeln!(f, "Relationships")?;
-            writeln!(f, "{}", "-".repeat(40))?;
+        if let Some(ref parent) = this.parent {
+            writeln!(f, "Parent: {} - {}", ids.format(&parent.id), parent.title)?;
+        }
 
-            if let Some(ref parent) = self.parent {
-                writeln!(f, "Parent: {} - {}", parent.id, parent.title)?;
+        if !this.children.is_empty() {
+            writeln!(f, "Children:")?;
+            for child in &this.children {
+                writeln!(f, "  - {} - {}", ids.format(&child.id), child.title)?;
             }
+        }
 
-            if !self.children.is_empty() {
-                writeln!(f, "Children:")?;
-                for child in &self.children {
-                    writeln!(f, "  - {} - {}", child.id, child.title)?;
-                }
+        if !this.blocked_by.is_empty() {
+            writeln!(f, "Blocked by:")?;
+            for dep in &this.blocked_by {
+                writeln!(f, "  - {} - {}", ids.format(&dep.id), dep.title)?;
             }
+        }
 
-            if !self.blocked_by.is_empty() {
-                writeln!(f, "Blocked by:")?;
-                for dep in &self.blocked_by {
-                    writeln!(f, "  - {} - {}", dep.id, dep.title)?;
-                }
-            }
+        if let Some(ref path) = this.blocker_path {
+            writeln!(f, "Blocker path: {}", format_blocker_path(path, ids))?;
+        }
 
-            if !self.blocks.is_empty() {
-                writeln!(f, "Blocks:")?;
-                for blocking in &self.blocks {
-                    writeln!(f, "  - {} - {}", blocking.id, blocking.title)?;
-                }
+        if !this.blocks.is_empty() {
+            writeln!(f, "Blocks:")?;
+            for blocking in &this.blocks {
+                writeln!(f, "  - {} - {}", ids.format(&blocking.id), blocking.title)?;
             }
-
-            writeln!(f)?;
         }
 
-        // Code references section
-        if !self.code_refs.is_empty() {
-            writeln!(f, "Code References")?;
-            writeln!(f, "{}", "-".repeat(40))?;
-
-            for code_ref in &self.code_refs {
-                let location = format_code_ref_location(code_ref);
-                let name_part = code_ref
-                    .name
-                    .as_ref()
-                    .map(|n| format!(" [{}]", n))
-                    .unwrap_or_default();
-                let desc_part = code_ref
-                    .description
-                    .as_ref()
-                    .map(|d| format!(" ({})", d))
-                    .unwrap_or_default();
-                writeln!(f, "  - {}{}{}", location, name_part, desc_part)?;
+        if !this.unblocks.is_empty() {
+            writeln!(f, "Completing this unblocks:")?;
+            for task in &this.unblocks {
+                writeln!(f, "  - {} - {}", ids.format(&task.id), task.title)?;
             }
         }
 
+        writeln!(f)?;
         Ok(())
     }
 }
 
+/// Format a blocker path as `this -> b1 -> b2 (incomplete)`.
+fn format_blocker_path(path: &[String], ids: &IdFormatter) -> String {
+    let path: Vec<&str> = path.iter().map(|id| ids.format(id)).collect();
+    format!("{} (incomplete)", path.join(" -> "))
+}
+
 /// Format sections of a specific type with their own heading
 fn format_section_with_heading(
     f: &mut std::fmt::Formatter<'_>,
@@ -578,6 +1077,43 @@ fn format_timestamp(ts: Option<&str>) -> String {
     }
 }
 
+/// Describe a task's estimate, rolled up over its descendants for parents.
+///
+/// Returns `None` when neither the task nor any descendant is estimated, so
+/// projects that don't use estimates see nothing.
+fn format_estimate(own: Option<u32>, rollup: Option<EstimateRollup>) -> Option<String> {
+    let Some(rollup) = rollup else {
+        return own.map(|minutes| format_minutes(u64::from(minutes)));
+    };
+    if rollup.total_minutes == 0 && own.is_none() {
+        return None;
+    }
+
+    let mut text = format!(
+        "{} total, {} remaining",
+        format_minutes(rollup.total_minutes),
+        format_minutes(rollup.remaining_minutes)
+    );
+    if rollup.unestimated > 0 {
+        text.push_str(&format!(
+            " ({} task{} unestimated)",
+            rollup.unestimated,
+            if rollup.unestimated == 1 { "" } else { "s" }
+        ));
+    }
+    Some(text)
+}
+
+/// Whether a task due at `due_at` is past due at `now`.
+///
+/// Finished tasks (done or rejected) are never overdue.
+fn is_overdue(due_at: &str, status: &str, now: chrono::DateTime<chrono::Utc>) -> bool {
+    if status == "done" || status == "rejected" {
+        return false;
+    }
+    chrono::DateTime::parse_from_rfc3339(due_at).is_ok_and(|due| due < now)
+}
+
 /// Format a code reference location in file:line format
 fn format_code_ref_location(code_ref: &CodeRef) -> String {
     match (code_ref.line_start, code_ref.line_end) {
@@ -684,6 +1220,11 @@ mod tests {
 
         let cmd = ShowCommand {
             id: "abc123".to_string(),
+            format: OutputFormat::Table,
+            relationships_only: false,
+            sections_only: false,
+            tree: false,
+            depth: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -709,12 +1250,277 @@ mod tests {
         cleanup(&temp_dir);
     }
 
+    #[tokio::test]
+    async fn test_show_due_date() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        create_task(&db, "late1", "Late", "task", "todo", None, &[]).await;
+        create_task(&db, "plain1", "Plain", "task", "todo", None, &[]).await;
+        db.client()
+            .query("UPDATE task:late1 SET due_at = d'2020-01-02T17:00:00Z'")
+            .await
+            .unwrap();
+
+        let show = |id: &str| ShowCommand {
+            id: id.to_string(),
+            format: OutputFormat::Table,
+            relationships_only: false,
+            sections_only: false,
+            tree: false,
+            depth: None,
+        };
+
+        let detail = show("late1").execute(&db).await.unwrap();
+        assert_eq!(detail.due_at.as_deref(), Some("2020-01-02T17:00:00+00:00"));
+        assert!(
+            detail
+                .to_string()
+                .contains("Due At:       2020-01-02 17:00 (overdue)")
+        );
+
+        let detail = show("plain1").execute(&db).await.unwrap();
+        assert!(detail.due_at.is_none());
+        assert!(!detail.to_string().contains("Due At"));
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_show_estimate_rollup_for_parents() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        create_task(&db, "epic1", "Epic", "epic", "in_progress", None, &[]).await;
+        create_task(&db, "done1", "Done", "task", "done", None, &[]).await;
+        create_task(&db, "todo1", "Todo", "task", "todo", None, &[]).await;
+        create_task(&db, "bare1", "Bare", "task", "todo", None, &[]).await;
+        for child in ["done1", "todo1", "bare1"] {
+            create_child_of(&db, child, "epic1").await;
+        }
+        db.client()
+            .query(
+                "UPDATE task:done1 SET estimate_minutes = 120; \
+                 UPDATE task:todo1 SET estimate_minutes = 90",
+            )
+            .await
+            .unwrap();
+
+        let show = |id: &str| ShowCommand {
+            id: id.to_string(),
+            format: OutputFormat::Table,
+            relationships_only: false,
+            sections_only: false,
+            tree: false,
+            depth: None,
+        };
+
+        let detail = show("epic1").execute(&db).await.unwrap();
+        assert_eq!(
+            detail
+                .estimate_rollup
+                .map(|r| (r.total_minutes, r.remaining_minutes)),
+            Some((210, 90))
+        );
+        assert!(
+            detail
+                .to_string()
+                .contains("Estimate: 3h30m total, 1h30m remaining (1 task unestimated)")
+        );
+        // One of the three children is done
+        assert_eq!(detail.progress, Some(Progress::new(1, 3)));
+        assert!(
+            detail
+                .to_string()
+                .contains("Progress: [███████-------------] 33% (1/3)\n")
+        );
+
+        // Leaves show their own estimate, or nothing
+        let detail = show("todo1").execute(&db).await.unwrap();
+        assert!(detail.estimate_rollup.is_none());
+        assert!(detail.progress.is_none());
+        assert!(!detail.to_string().contains("Progress:"));
+        assert!(detail.to_string().contains("Estimate: 1h30m\n"));
+        let detail = show("bare1").execute(&db).await.unwrap();
+        assert!(!detail.to_string().contains("Estimate:"));
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_show_tree_renders_subtree_and_depth_cap() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        create_task(&db, "epic1", "Epic", "epic", "in_progress", None, &[]).await;
+        create_task(&db, "tick1", "Ticket one", "ticket", "done", None, &[]).await;
+        create_task(&db, "tick2", "Ticket two", "ticket", "todo", None, &[]).await;
+        create_task(&db, "leaf1", "Leaf one", "task", "done", None, &[]).await;
+        create_task(&db, "leaf2", "Leaf two", "task", "in_progress", None, &[]).await;
+        create_task(&db, "deep1", "Deep", "task", "backlog", None, &[]).await;
+        create_child_of(&db, "tick1", "epic1").await;
+        create_child_of(&db, "tick2", "epic1").await;
+        create_child_of(&db, "leaf1", "tick1").await;
+        create_child_of(&db, "leaf2", "tick2").await;
+        create_child_of(&db, "deep1", "leaf2").await;
+
+        let tree = |id: &str, depth| ShowCommand {
+            id: id.to_string(),
+            format: OutputFormat::Table,
+            relationships_only: false,
+            sections_only: false,
+            tree: true,
+            depth,
+        };
+
+        let root = tree("EPIC1", None).execute_tree(&db).await.unwrap();
+        assert_eq!(
+            root.render_tree(TreeChars::Unicode),
+            [
+                "[>] epic1  Epic",
+                "├── [x] tick1  Ticket one",
+                "│   └── [x] leaf1  Leaf one",
+                "└── [ ] tick2  Ticket two",
+                "    └── [>] leaf2  Leaf two",
+                "        └── [.] deep1  Deep",
+            ]
+            .join("\n")
+        );
+
+        let root = tree("epic1", Some(1)).execute_tree(&db).await.unwrap();
+        assert_eq!(root.children[1].hidden, 2);
+        assert_eq!(
+            root.render_tree(TreeChars::Ascii),
+            [
+                "[>] epic1  Epic",
+                "+-- [x] tick1  Ticket one",
+                "|   \\-- \u{2026} (1 more)",
+                "\\-- [ ] tick2  Ticket two",
+                "    \\-- \u{2026} (2 more)",
+            ]
+            .join("\n")
+        );
+
+        let root = tree("leaf1", None).execute_tree(&db).await.unwrap();
+        assert_eq!(root.render_tree(TreeChars::Unicode), "[x] leaf1  Leaf one");
+
+        let err = tree("nope", None).execute_tree(&db).await.unwrap_err();
+        assert!(matches!(err, DbError::NotFound { .. }));
+
+        cleanup(&temp_dir);
+    }
+
+    #[test]
+    fn test_format_estimate_hides_unestimated_parents() {
+        let rollup = |total, unestimated| EstimateRollup {
+            total_minutes: total,
+            remaining_minutes: 0,
+            unestimated,
+        };
+        assert_eq!(format_estimate(None, Some(rollup(0, 3))), None);
+        assert_eq!(format_estimate(None, None), None);
+        assert_eq!(
+            format_estimate(None, Some(rollup(60, 2))).as_deref(),
+            Some("1h total, 0m remaining (2 tasks unestimated)")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_show_yaml_round_trips() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        create_task(&db, "epic1", "Epic", "epic", "todo", None, &[]).await;
+        create_task(
+            &db,
+            "task1",
+            "Fix: \\\"quoted\\\"",
+            "task",
+            "todo",
+            Some("high"),
+            &["api"],
+        )
+        .await;
+        create_task(&db, "dep1", "Dependency", "task", "todo", None, &[]).await;
+        create_child_of(&db, "task1", "epic1").await;
+        create_depends_on(&db, "task1", "dep1").await;
+        db.client()
+            .query(
+                r#"UPDATE task:task1 SET
+                    sections = [{ type: "goal", content: "Ship it\nsoon", order: 0 }],
+                    refs = [{ path: "src/main.rs", line_start: 3, line_end: 9 }]"#,
+            )
+            .await
+            .unwrap();
+
+        let cmd = ShowCommand {
+            id: "task1".to_string(),
+            format: OutputFormat::Yaml,
+            relationships_only: false,
+            sections_only: false,
+            tree: false,
+            depth: None,
+        };
+        let detail = cmd.execute(&db).await.unwrap();
+        let yaml = detail.render(cmd.format).unwrap();
+        assert!(yaml.starts_with("id: task1\n"), "got:\n{}", yaml);
+
+        let parsed: TaskDetail = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(parsed.title, "Fix: \"quoted\"");
+        assert_eq!(parsed.sections[0].content, "Ship it\nsoon");
+        assert_eq!(
+            serde_json::to_value(&parsed).unwrap(),
+            serde_json::to_value(&detail).unwrap()
+        );
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_show_json_and_unsupported_formats() {
+        let (db, temp_dir) = setup_test_db().await;
+        create_task(&db, "task1", "Json me", "task", "todo", None, &[]).await;
+
+        let cmd = ShowCommand {
+            id: "task1".to_string(),
+            format: OutputFormat::Json,
+            relationships_only: false,
+            sections_only: false,
+            tree: false,
+            depth: None,
+        };
+        let detail = cmd.execute(&db).await.unwrap();
+        let json = detail.render(cmd.format).unwrap();
+        let parsed: TaskDetail = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.title, "Json me");
+
+        let cmd = ShowCommand {
+            id: "task1".to_string(),
+            format: OutputFormat::Csv,
+            relationships_only: false,
+            sections_only: false,
+            tree: false,
+            depth: None,
+        };
+        let result = cmd.execute(&db).await;
+        match result {
+            Err(DbError::ValidationError { message }) => assert_eq!(
+                message,
+                "--format csv is not supported by `show`; use one of: table, json, yaml"
+            ),
+            other => panic!("Expected ValidationError, got {:?}", other),
+        }
+
+        cleanup(&temp_dir);
+    }
+
     #[tokio::test]
     async fn test_show_nonexistent_task() {
         let (db, temp_dir) = setup_test_db().await;
 
         let cmd = ShowCommand {
             id: "nonexistent".to_string(),
+            format: OutputFormat::Table,
+            relationships_only: false,
+            sections_only: false,
+            tree: false,
+            depth: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -741,6 +1547,11 @@ mod tests {
 
         let cmd = ShowCommand {
             id: "ABC123".to_string(),
+            format: OutputFormat::Table,
+            relationships_only: false,
+            sections_only: false,
+            tree: false,
+            depth: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -768,6 +1579,11 @@ mod tests {
 
         let cmd = ShowCommand {
             id: "child1".to_string(),
+            format: OutputFormat::Table,
+            relationships_only: false,
+            sections_only: false,
+            tree: false,
+            depth: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -816,6 +1632,11 @@ mod tests {
 
         let cmd = ShowCommand {
             id: "parent1".to_string(),
+            format: OutputFormat::Table,
+            relationships_only: false,
+            sections_only: false,
+            tree: false,
+            depth: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -861,6 +1682,11 @@ mod tests {
 
         let cmd = ShowCommand {
             id: "task1".to_string(),
+            format: OutputFormat::Table,
+            relationships_only: false,
+            sections_only: false,
+            tree: false,
+            depth: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -880,6 +1706,120 @@ mod tests {
         cleanup(&temp_dir);
     }
 
+    /// A task with a parent, a blocker, a goal and a step
+    async fn setup_partial_views(db: &Database) {
+        create_task(db, "epic1", "The Epic", "epic", "todo", None, &[]).await;
+        create_task(db, "dep1", "The Blocker", "task", "todo", None, &[]).await;
+        create_task(db, "task1", "Main Task", "task", "todo", None, &["core"]).await;
+        create_child_of(db, "task1", "epic1").await;
+        create_depends_on(db, "task1", "dep1").await;
+        db.tasks()
+            .add_section("task1", SectionType::Goal, "Ship the thing")
+            .await
+            .unwrap();
+        db.tasks()
+            .add_section("task1", SectionType::Step, "Write it")
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_show_partial_views_table() {
+        let (db, temp_dir) = setup_test_db().await;
+        setup_partial_views(&db).await;
+
+        let mut cmd = ShowCommand {
+            id: "task1".to_string(),
+            format: OutputFormat::Table,
+            relationships_only: true,
+            sections_only: false,
+            tree: false,
+            depth: None,
+        };
+        let detail = cmd.execute(&db).await.unwrap();
+
+        let output = detail.render_view(cmd.format, cmd.view()).unwrap();
+        assert!(output.starts_with("Relationships"), "got:\n{}", output);
+        assert!(output.contains("Parent: epic1 - The Epic"));
+        assert!(output.contains("dep1 - The Blocker"));
+        assert!(!output.contains("Metadata"));
+        assert!(!output.contains("Ship the thing"));
+        assert!(!output.contains("Write it"));
+
+        cmd.relationships_only = false;
+        cmd.sections_only = true;
+        let output = detail.render_view(cmd.format, cmd.view()).unwrap();
+        assert!(output.starts_with("Goal"), "got:\n{}", output);
+        assert!(output.contains("Ship the thing"));
+        assert!(output.contains("[ ] Write it"));
+        assert!(!output.contains("Relationships"));
+        assert!(!output.contains("The Epic"));
+        assert!(!output.contains("Metadata"));
+
+        // The full view still has everything
+        let output = detail.render_view(cmd.format, ShowView::Full).unwrap();
+        assert!(output.contains("Metadata"));
+        assert!(output.contains("Ship the thing"));
+        assert!(output.contains("Relationships"));
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_show_partial_views_json_and_empty() {
+        let (db, temp_dir) = setup_test_db().await;
+        setup_partial_views(&db).await;
+
+        let cmd = ShowCommand {
+            id: "task1".to_string(),
+            format: OutputFormat::Json,
+            relationships_only: true,
+            sections_only: false,
+            tree: false,
+            depth: None,
+        };
+        let detail = cmd.execute(&db).await.unwrap();
+
+        let json: serde_json::Value = serde_json::from_str(
+            &detail
+                .render_view(cmd.format, ShowView::Relationships)
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(json["parent"]["id"], "epic1");
+        assert_eq!(json["blocked_by"][0]["id"], "dep1");
+        assert!(json.get("sections").is_none());
+        assert!(json.get("title").is_none());
+
+        let json: serde_json::Value =
+            serde_json::from_str(&detail.render_view(cmd.format, ShowView::Sections).unwrap())
+                .unwrap();
+        assert_eq!(json["sections"].as_array().unwrap().len(), 2);
+        assert!(json.get("parent").is_none());
+
+        // dep1 has no sections of its own
+        let lone = ShowCommand {
+            id: "dep1".to_string(),
+            format: OutputFormat::Table,
+            relationships_only: false,
+            sections_only: true,
+            tree: false,
+            depth: None,
+        };
+        let detail = lone.execute(&db).await.unwrap();
+        assert_eq!(
+            detail.render_view(lone.format, ShowView::Sections).unwrap(),
+            "No sections"
+        );
+        let output = detail
+            .render_view(lone.format, ShowView::Relationships)
+            .unwrap();
+        // ...but it blocks task1
+        assert!(output.contains("Blocks:"));
+
+        cleanup(&temp_dir);
+    }
+
     #[tokio::test]
     async fn test_show_with_blocks() {
         let (db, temp_dir) = setup_test_db().await;
@@ -899,6 +1839,11 @@ mod tests {
 
         let cmd = ShowCommand {
             id: "blocker".to_string(),
+            format: OutputFormat::Table,
+            relationships_only: false,
+            sections_only: false,
+            tree: false,
+            depth: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -929,6 +1874,11 @@ mod tests {
 
         let cmd = ShowCommand {
             id: "withdata".to_string(),
+            format: OutputFormat::Table,
+            relationships_only: false,
+            sections_only: false,
+            tree: false,
+            depth: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -1062,6 +2012,11 @@ mod tests {
 
         let cmd = ShowCommand {
             id: "tagged".to_string(),
+            format: OutputFormat::Table,
+            relationships_only: false,
+            sections_only: false,
+            tree: false,
+            depth: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -1113,6 +2068,20 @@ mod tests {
         assert_eq!(format_timestamp(None), "");
     }
 
+    #[test]
+    fn test_is_overdue() {
+        let now = chrono::DateTime::parse_from_rfc3339("2024-03-02T00:00:00Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+
+        assert!(is_overdue("2024-03-01T23:59:59Z", "todo", now));
+        assert!(is_overdue("2024-03-01T23:59:59+00:00", "in_progress", now));
+        assert!(!is_overdue("2024-03-02T10:00:00Z", "todo", now));
+        assert!(!is_overdue("2024-03-01T23:59:59Z", "done", now));
+        assert!(!is_overdue("2024-03-01T23:59:59Z", "rejected", now));
+        assert!(!is_overdue("not a date", "todo", now));
+    }
+
     #[test]
     fn test_format_code_ref_location() {
         let file_ref = CodeRef::file("src/main.rs");
@@ -1138,6 +2107,10 @@ mod tests {
             created_at: Some("2024-01-15T10:30:00Z".to_string()),
             updated_at: Some("2024-01-15T11:00:00Z".to_string()),
             completed_at: None,
+            due_at: None,
+            estimate_minutes: None,
+            estimate_rollup: None,
+            progress: None,
             needs_human_review: Some(false),
             sections: vec![
                 Section::new(SectionType::Goal, "The goal"),
@@ -1152,10 +2125,14 @@ mod tests {
                 priority: None,
                 tags: vec![],
                 needs_human_review: None,
+                created_at: None,
+                updated_at: None,
             }),
             children: vec![],
             blocked_by: vec![],
             blocks: vec![],
+            unblocks: vec![],
+            blocker_path: None,
         };
 
         let output = format!("{}", detail);
@@ -1189,6 +2166,10 @@ mod tests {
             created_at: None,
             updated_at: None,
             completed_at: None,
+            due_at: None,
+            estimate_minutes: None,
+            estimate_rollup: None,
+            progress: None,
             needs_human_review: Some(false),
             sections: vec![],
             code_refs: vec![],
@@ -1196,6 +2177,8 @@ mod tests {
             children: vec![],
             blocked_by: vec![],
             blocks: vec![],
+            unblocks: vec![],
+            blocker_path: None,
         };
 
         let output = format!("{}", detail);
@@ -1228,6 +2211,10 @@ mod tests {
             created_at: None,
             updated_at: None,
             completed_at: None,
+            due_at: None,
+            estimate_minutes: None,
+            estimate_rollup: None,
+            progress: None,
             needs_human_review: Some(true),
             sections: vec![],
             code_refs: vec![],
@@ -1235,6 +2222,8 @@ mod tests {
             children: vec![],
             blocked_by: vec![],
             blocks: vec![],
+            unblocks: vec![],
+            blocker_path: None,
         };
 
         let output = format!("{}", detail);
@@ -1255,6 +2244,10 @@ mod tests {
             created_at: None,
             updated_at: None,
             completed_at: None,
+            due_at: None,
+            estimate_minutes: None,
+            estimate_rollup: None,
+            progress: None,
             needs_human_review: Some(false),
             sections: vec![
                 Section::with_order(SectionType::Step, "First step", 1),
@@ -1265,6 +2258,8 @@ mod tests {
             children: vec![],
             blocked_by: vec![],
             blocks: vec![],
+            unblocks: vec![],
+            blocker_path: None,
         };
 
         let output = format!("{}", detail);
@@ -1279,6 +2274,11 @@ mod tests {
     fn test_show_command_debug() {
         let cmd = ShowCommand {
             id: "test123".to_string(),
+            format: OutputFormat::Table,
+            relationships_only: false,
+            sections_only: false,
+            tree: false,
+            depth: None,
         };
         let debug_str = format!("{:?}", cmd);
         assert!(
@@ -1300,6 +2300,10 @@ mod tests {
             created_at: None,
             updated_at: None,
             completed_at: None,
+            due_at: None,
+            estimate_minutes: None,
+            estimate_rollup: None,
+            progress: None,
             needs_human_review: Some(false),
             sections: vec![],
             code_refs: vec![],
@@ -1311,10 +2315,14 @@ mod tests {
                 priority: None,
                 tags: vec![],
                 needs_human_review: None,
+                created_at: None,
+                updated_at: None,
             }),
             children: vec![],
             blocked_by: vec![],
             blocks: vec![],
+            unblocks: vec![],
+            blocker_path: None,
         };
         let debug_str = format!("{:?}", detail);
         assert!(
@@ -1329,4 +2337,63 @@ mod tests {
             "Debug output should contain TaskDetail and all field values"
         );
     }
+
+    #[test]
+    fn test_format_blocker_path() {
+        let path = vec!["this".to_string(), "b1".to_string(), "b2".to_string()];
+        let ids = IdFormatter::for_ids(IdFormat::Full, path.iter().map(String::as_str));
+        assert_eq!(
+            format_blocker_path(&path, &ids),
+            "this -> b1 -> b2 (incomplete)"
+        );
+    }
+
+    #[test]
+    fn test_task_detail_display_short_ids_lengthen_when_ambiguous() {
+        let summary = |id: &str, title: &str| TaskSummary {
+            id: id.to_string(),
+            title: title.to_string(),
+            level: "task".to_string(),
+            status: "todo".to_string(),
+            priority: None,
+            tags: vec![],
+            needs_human_review: None,
+            created_at: None,
+            updated_at: None,
+        };
+        let detail = TaskDetail {
+            id: "abc123aaaa".to_string(),
+            title: "Main".to_string(),
+            description: None,
+            level: "task".to_string(),
+            status: "blocked".to_string(),
+            priority: None,
+            tags: vec![],
+            created_at: None,
+            updated_at: None,
+            completed_at: None,
+            due_at: None,
+            estimate_minutes: None,
+            estimate_rollup: None,
+            progress: None,
+            needs_human_review: None,
+            sections: vec![],
+            code_refs: vec![],
+            parent: Some(summary("fed987zzzz", "Parent")),
+            children: vec![],
+            blocked_by: vec![summary("abc123bbbb", "Blocker")],
+            blocks: vec![],
+            unblocks: vec![],
+            blocker_path: Some(vec!["abc123aaaa".to_string(), "abc123bbbb".to_string()]),
+        };
+
+        let output = detail.display_with(IdFormat::Short6).to_string();
+        assert!(output.contains("Task: abc123a - Main"), "got: {}", output);
+        assert!(output.contains("Parent: fed987z - Parent"));
+        assert!(output.contains("  - abc123b - Blocker"));
+        assert!(output.contains("Blocker path: abc123a -> abc123b (incomplete)"));
+
+        let full = detail.display_with(IdFormat::Full).to_string();
+        assert!(full.contains("Task: abc123aaaa - Main"));
+    }
 }
@@ -2,6 +2,7 @@
 //!
 //! Implements the `vtb step-done` command to mark individual steps within a task as done.
 
+use crate::id::resolve_task_id;
 use clap::Args;
 use serde::Deserialize;
 use vertebrae_db::{Database, DbError};
@@ -71,8 +72,8 @@ impl StepDoneCommand {
     /// - The step index is out of bounds
     /// - Database operations fail
     pub async fn execute(&self, db: &Database) -> Result<StepDoneResult, DbError> {
-        // Normalize ID to lowercase for case-insensitive lookup
-        let id = self.id.to_lowercase();
+        // Resolve case-insensitive and shortened IDs
+        let id = resolve_task_id(db, &self.id).await?;
 
         // Validate index is positive
         if self.index == 0 {
@@ -131,14 +132,13 @@ impl StepDoneCommand {
         }
 
         let query = format!("SELECT sections FROM task:{}", id);
-        let mut result = db.client().query(&query).await?;
+        let mut result = db.query(&query).await?;
         let task: Option<TaskRow> = result.take(0)?;
 
         match task {
             Some(t) => Ok(t.sections),
-            None => Err(DbError::InvalidPath {
-                path: std::path::PathBuf::from(&self.id),
-                reason: format!("Task '{}' not found", self.id),
+            None => Err(DbError::NotFound {
+                task_id: self.id.clone(),
             }),
         }
     }
@@ -155,14 +155,14 @@ impl StepDoneCommand {
             "UPDATE task:{} SET sections[{}].done = true, sections[{}].done_at = time::now()",
             id, section_index, section_index
         );
-        db.client().query(&query).await?;
+        db.query(&query).await?;
         Ok(())
     }
 
     /// Update the task's updated_at timestamp.
     async fn update_timestamp(&self, db: &Database, id: &str) -> Result<(), DbError> {
         let query = format!("UPDATE task:{} SET updated_at = time::now()", id);
-        db.client().query(&query).await?;
+        db.query(&query).await?;
         Ok(())
     }
 }
@@ -357,10 +357,10 @@ mod tests {
 
         let result = cmd.execute(&db).await;
         match result {
-            Err(DbError::InvalidPath { reason, .. }) => {
-                assert!(reason.contains("not found"));
+            Err(DbError::NotFound { task_id }) => {
+                assert_eq!(task_id, "nonexistent");
             }
-            _ => panic!("Expected InvalidPath error"),
+            _ => panic!("Expected NotFound error"),
         }
 
         cleanup(&temp_dir);
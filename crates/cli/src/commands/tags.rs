@@ -0,0 +1,108 @@
+//! Tags command for reviewing the tag vocabulary
+//!
+//! Implements the `vtb tags` command, which lists every tag in use with the
+//! number of tasks carrying it. Near-duplicates such as `backend` and
+//! `back-end` end up side by side at the bottom of the list.
+
+use clap::Args;
+use vertebrae_db::{Database, DbError};
+
+/// List all tags with the number of tasks using each
+#[derive(Debug, Args)]
+pub struct TagsCommand {}
+
+/// Result of the tags command execution
+#[derive(Debug)]
+pub struct TagsResult {
+    /// `(tag, task count)` pairs, most used first
+    pub tags: Vec<(String, usize)>,
+}
+
+impl std::fmt::Display for TagsResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.tags.is_empty() {
+            return write!(f, "No tags found.");
+        }
+
+        let width = self
+            .tags
+            .iter()
+            .map(|(tag, _)| tag.chars().count())
+            .chain(["TAG".len()])
+            .max()
+            .unwrap_or(0);
+
+        write!(f, "{:<width$}  COUNT", "TAG", width = width)?;
+        for (tag, count) in &self.tags {
+            write!(f, "\n{:<width$}  {:>5}", tag, count, width = width)?;
+        }
+        Ok(())
+    }
+}
+
+impl TagsCommand {
+    /// Execute the tags command.
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - Reference to the database connection
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError` if the database query fails.
+    pub async fn execute(&self, db: &Database) -> Result<TagsResult, DbError> {
+        let tags = db.list_tasks().tag_counts().await?;
+        Ok(TagsResult { tags })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    async fn setup_test_db() -> (Database, std::path::PathBuf) {
+        let temp_dir = env::temp_dir().join(format!(
+            "vtb-tags-test-{}-{:?}-{}",
+            std::process::id(),
+            std::thread::current().id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        let db = Database::connect(&temp_dir).await.unwrap();
+        db.init().await.unwrap();
+        (db, temp_dir)
+    }
+
+    fn cleanup(path: &std::path::Path) {
+        let _ = std::fs::remove_dir_all(path);
+    }
+
+    #[tokio::test]
+    async fn test_tags_lists_counts() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        let result = TagsCommand {}.execute(&db).await.unwrap();
+        assert_eq!(result.to_string(), "No tags found.");
+
+        db.client()
+            .query(
+                r#"CREATE task:a SET title = "A", level = "task", status = "todo",
+                       tags = ["backend", "infrastructure"];
+                   CREATE task:b SET title = "B", level = "task", status = "todo",
+                       tags = ["backend"]"#,
+            )
+            .await
+            .unwrap();
+
+        let result = TagsCommand {}.execute(&db).await.unwrap();
+        assert_eq!(
+            result.to_string(),
+            "TAG             COUNT\nbackend             2\ninfrastructure      1"
+        );
+
+        cleanup(&temp_dir);
+    }
+}
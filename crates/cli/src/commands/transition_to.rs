@@ -4,11 +4,15 @@
 //! with proper validation. This consolidates the functionality of start, submit,
 //! done, triage, and reject commands into a single unified interface.
 
+use chrono::{DateTime, Utc};
 use clap::{Args, ValueEnum};
 use vertebrae_db::{
-    Database, DbError, Status, TaskUpdate, TriageValidationResult, TriageValidator,
+    Database, DbError, Status, TransitionOptions, TriageValidationResult, format_unblocked,
 };
 
+use crate::id::resolve_task_id;
+use crate::timestamp::{check_order, parse_timestamp};
+
 /// Target status for the transition-to command
 #[derive(Debug, Clone, Copy, ValueEnum, PartialEq, Eq)]
 pub enum TargetStatus {
@@ -51,9 +55,9 @@ impl TargetStatus {
 }
 
 /// Transition a task to a specific status
-#[derive(Debug, Args)]
+#[derive(Debug, Clone, Args)]
 pub struct TransitionToCommand {
-    /// Task ID to transition (case-insensitive)
+    /// Task ID to transition (case-insensitive; `-` reads IDs from stdin)
     #[arg(required = true)]
     pub id: String,
 
@@ -72,6 +76,14 @@ pub struct TransitionToCommand {
     /// Bypass all validation when transitioning to todo (escape hatch)
     #[arg(long)]
     pub skip_validation: bool,
+
+    /// When the work started, instead of now (in_progress and done only)
+    #[arg(long, value_parser = parse_timestamp, value_name = "TIME")]
+    pub started_at: Option<DateTime<Utc>>,
+
+    /// When the work was completed, instead of now (done only)
+    #[arg(long, value_parser = parse_timestamp, value_name = "TIME")]
+    pub completed_at: Option<DateTime<Utc>>,
 }
 
 /// Result of the transition-to command execution
@@ -178,12 +190,7 @@ impl std::fmt::Display for TransitionToResult {
 
         // Show unblocked tasks if any (for done)
         if !self.unblocked_tasks.is_empty() {
-            writeln!(f)?;
-            writeln!(f)?;
-            writeln!(f, "Unblocked tasks:")?;
-            for (id, title) in &self.unblocked_tasks {
-                writeln!(f, "  - {} ({})", id, title)?;
-            }
+            write!(f, "\n{}", format_unblocked(&self.unblocked_tasks))?;
         }
 
         Ok(())
@@ -205,19 +212,17 @@ impl TransitionToCommand {
     /// - The task with the given ID does not exist
     /// - The status transition is invalid
     /// - The task has incomplete children (for done transition)
+    /// - `--started-at`/`--completed-at` don't apply to the target, or would
+    ///   leave the task completed before it started
     /// - Database operations fail
     pub async fn execute(&self, db: &Database) -> Result<TransitionToResult, DbError> {
-        // Normalize ID to lowercase for case-insensitive lookup
-        let id = self.id.to_lowercase();
+        self.check_timestamp_flags()?;
+
+        // Resolve case-insensitive and shortened IDs
+        let id = resolve_task_id(db, &self.id).await?;
 
         // Fetch task and verify it exists
-        let task = db
-            .tasks()
-            .get(&id)
-            .await?
-            .ok_or_else(|| DbError::NotFound {
-                task_id: self.id.clone(),
-            })?;
+        let task = db.tasks().get_or_err(&id).await?;
 
         let target_status = self.target.to_status();
 
@@ -248,205 +253,62 @@ impl TransitionToCommand {
         db.tasks()
             .validate_status_transition(&id, &task.status, &target_status)?;
 
-        // Execute target-specific logic
-        match self.target {
-            TargetStatus::Todo => self.execute_todo_transition(db, &id).await,
-            TargetStatus::InProgress => self.execute_in_progress_transition(db, &id).await,
-            TargetStatus::PendingReview => self.execute_pending_review_transition(db, &id).await,
-            TargetStatus::Done => self.execute_done_transition(db, &id).await,
-            TargetStatus::Rejected => self.execute_rejected_transition(db, &id).await,
-        }
-    }
-
-    /// Execute transition to todo status
-    ///
-    /// Validates the task has required sections before transitioning.
-    /// - Required sections (block if missing): testing_criterion (>=2), step (>=1), constraint (>=2)
-    /// - Encouraged sections (warn but allow): anti_pattern (>=1), failure_test (>=1)
-    /// - Recommended sections (note if missing): goal, context, current_behavior, desired_behavior
-    ///
-    /// Use --force to bypass warnings, --skip-validation to bypass all validation.
-    async fn execute_todo_transition(
-        &self,
-        db: &Database,
-        id: &str,
-    ) -> Result<TransitionToResult, DbError> {
-        // If validation is skipped, proceed directly
-        if self.skip_validation {
-            let updates = TaskUpdate::new().with_status(Status::Todo);
-            db.tasks().update(id, &updates).await?;
-
-            return Ok(TransitionToResult {
-                id: id.to_string(),
-                target: self.target,
-                already_in_target: false,
-                incomplete_deps: vec![],
-                unblocked_tasks: vec![],
-                reason: None,
-                validation: None,
-                validation_skipped: true,
-                warnings_forced: false,
-            });
-        }
-
-        // Fetch the task to validate its sections
-        let task = db.tasks().get(id).await?.ok_or_else(|| DbError::NotFound {
-            task_id: id.to_string(),
-        })?;
-
-        // Run validation
-        let validator = TriageValidator::new();
-        let validation_result = validator.validate(&task);
-
-        // Check for errors (block transition)
-        if validation_result.has_errors() {
-            return Err(DbError::TriageValidationFailed {
-                task_id: id.to_string(),
-                error_count: validation_result.error_count(),
-                warning_count: validation_result.warning_count(),
-                note_count: validation_result.note_count(),
-                details: format!("{}", validation_result),
-            });
-        }
-
-        // Check for warnings (require --force unless in force mode)
-        if validation_result.has_warnings() && !self.force {
-            // Build a helpful error message
-            let mut message = format!(
-                "Task '{}' has validation warnings. Use --force to override:\n\n{}",
-                id, validation_result
-            );
-            message.push_str("\nRun with --force to proceed anyway, or add the missing sections.");
-            return Err(DbError::ValidationError { message });
+        // Backdated timestamps must stay in order with the ones already stored
+        if self.started_at.is_some() || self.completed_at.is_some() {
+            let completed_at = match self.target {
+                TargetStatus::Done => Some(self.completed_at.unwrap_or_else(Utc::now)),
+                _ => task.completed_at,
+            };
+            check_order(self.started_at.or(task.started_at), completed_at)?;
         }
 
-        // All checks passed - perform the transition
-        let updates = TaskUpdate::new().with_status(Status::Todo);
-        db.tasks().update(id, &updates).await?;
-
-        Ok(TransitionToResult {
-            id: id.to_string(),
-            target: self.target,
-            already_in_target: false,
-            incomplete_deps: vec![],
-            unblocked_tasks: vec![],
-            reason: None,
-            validation: Some(validation_result),
-            validation_skipped: false,
-            warnings_forced: self.force,
-        })
-    }
-
-    /// Execute transition to in_progress status
-    async fn execute_in_progress_transition(
-        &self,
-        db: &Database,
-        id: &str,
-    ) -> Result<TransitionToResult, DbError> {
-        // Check for incomplete dependencies (soft enforcement - warn only)
-        let incomplete_deps = db.graph().get_incomplete_dependencies_info(id).await?;
-
-        // Update status to in_progress and set started_at if not already set
-        let updates = TaskUpdate::new()
-            .with_status(Status::InProgress)
-            .set_started_at_if_null();
-        db.tasks().update(id, &updates).await?;
-
-        Ok(TransitionToResult {
-            id: id.to_string(),
-            target: self.target,
-            already_in_target: false,
-            incomplete_deps,
-            unblocked_tasks: vec![],
-            reason: None,
-            validation: None,
-            validation_skipped: false,
-            warnings_forced: false,
-        })
-    }
-
-    /// Execute transition to pending_review status
-    async fn execute_pending_review_transition(
-        &self,
-        db: &Database,
-        id: &str,
-    ) -> Result<TransitionToResult, DbError> {
-        let updates = TaskUpdate::new().with_status(Status::PendingReview);
-        db.tasks().update(id, &updates).await?;
-
-        Ok(TransitionToResult {
-            id: id.to_string(),
-            target: self.target,
-            already_in_target: false,
-            incomplete_deps: vec![],
-            unblocked_tasks: vec![],
-            reason: None,
-            validation: None,
-            validation_skipped: false,
-            warnings_forced: false,
-        })
-    }
-
-    /// Execute transition to done status
-    async fn execute_done_transition(
-        &self,
-        db: &Database,
-        id: &str,
-    ) -> Result<TransitionToResult, DbError> {
-        // Check for incomplete descendants (hard enforcement - error if any exist)
-        let incomplete_descendants = db.graph().get_incomplete_descendants(id).await?;
-
-        if !incomplete_descendants.is_empty() {
-            return Err(DbError::IncompleteChildren {
-                task_id: id.to_string(),
-                children: incomplete_descendants,
-            });
+        let options = TransitionOptions {
+            force: self.force,
+            skip_validation: self.skip_validation,
+            started_at: self.started_at,
+            completed_at: self.completed_at,
+        };
+        if self.target == TargetStatus::Rejected
+            && let Some(reason) = &self.reason
+        {
+            self.add_constraint_section(db, &id, reason).await?;
         }
-
-        // Find tasks that depend on this one and will become unblocked
-        let unblocked_tasks = db.graph().get_unblocked_tasks(id).await?;
-
-        // Mark task as done
-        db.tasks().mark_done(id).await?;
+        let outcome = db.tasks().transition(&id, &target_status, &options).await?;
 
         Ok(TransitionToResult {
-            id: id.to_string(),
+            id,
             target: self.target,
             already_in_target: false,
-            incomplete_deps: vec![],
-            unblocked_tasks,
-            reason: None,
-            validation: None,
-            validation_skipped: false,
-            warnings_forced: false,
+            incomplete_deps: outcome.incomplete_deps,
+            unblocked_tasks: outcome.unblocked,
+            reason: match self.target {
+                TargetStatus::Rejected => self.reason.clone(),
+                _ => None,
+            },
+            validation_skipped: self.target == TargetStatus::Todo && self.skip_validation,
+            warnings_forced: outcome.validation.is_some() && self.force,
+            validation: outcome.validation,
         })
     }
 
-    /// Execute transition to rejected status
-    async fn execute_rejected_transition(
-        &self,
-        db: &Database,
-        id: &str,
-    ) -> Result<TransitionToResult, DbError> {
-        // Add constraint section with reason if provided
-        if let Some(reason) = &self.reason {
-            self.add_constraint_section(db, id, reason).await?;
+    /// Check that `--started-at`/`--completed-at` apply to the target status.
+    fn check_timestamp_flags(&self) -> Result<(), DbError> {
+        let flag = match self.target {
+            TargetStatus::Done => None,
+            TargetStatus::InProgress => self.completed_at.map(|_| "--completed-at"),
+            _ if self.started_at.is_some() => Some("--started-at"),
+            _ => self.completed_at.map(|_| "--completed-at"),
+        };
+        match flag {
+            Some(flag) => Err(DbError::ValidationError {
+                message: format!(
+                    "{} cannot be used when transitioning to {}",
+                    flag,
+                    self.target.as_str()
+                ),
+            }),
+            None => Ok(()),
         }
-
-        // Update status to rejected
-        db.tasks().update_status(id, Status::Rejected).await?;
-
-        Ok(TransitionToResult {
-            id: id.to_string(),
-            target: self.target,
-            already_in_target: false,
-            incomplete_deps: vec![],
-            unblocked_tasks: vec![],
-            reason: self.reason.clone(),
-            validation: None,
-            validation_skipped: false,
-            warnings_forced: false,
-        })
     }
 
     /// Add a constraint section with the rejection reason.
@@ -570,6 +432,8 @@ mod tests {
             reason: None,
             force: false,
             skip_validation: true, // Skip validation in unit tests
+            started_at: None,
+            completed_at: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -598,6 +462,8 @@ mod tests {
             reason: None,
             force: false,
             skip_validation: true, // Skip validation in unit tests
+            started_at: None,
+            completed_at: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -632,6 +498,8 @@ mod tests {
             reason: None,
             force: false,
             skip_validation: true, // Skip validation in unit tests
+            started_at: None,
+            completed_at: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -662,6 +530,8 @@ mod tests {
             reason: None,
             force: false,
             skip_validation: true, // Skip validation in unit tests
+            started_at: None,
+            completed_at: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -689,6 +559,8 @@ mod tests {
             reason: None,
             force: false,
             skip_validation: false,
+            started_at: None,
+            completed_at: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -721,6 +593,8 @@ mod tests {
             reason: None,
             force: false,
             skip_validation: false,
+            started_at: None,
+            completed_at: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -744,6 +618,8 @@ mod tests {
             reason: None,
             force: false,
             skip_validation: false,
+            started_at: None,
+            completed_at: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -780,6 +656,8 @@ mod tests {
             reason: None,
             force: false,
             skip_validation: false,
+            started_at: None,
+            completed_at: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -825,6 +703,8 @@ mod tests {
             reason: None,
             force: false,
             skip_validation: false,
+            started_at: None,
+            completed_at: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -855,6 +735,8 @@ mod tests {
             reason: None,
             force: false,
             skip_validation: false,
+            started_at: None,
+            completed_at: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -881,6 +763,8 @@ mod tests {
             reason: None,
             force: false,
             skip_validation: false,
+            started_at: None,
+            completed_at: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -916,6 +800,8 @@ mod tests {
             reason: None,
             force: false,
             skip_validation: false,
+            started_at: None,
+            completed_at: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -947,6 +833,8 @@ mod tests {
             reason: None,
             force: false,
             skip_validation: false,
+            started_at: None,
+            completed_at: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -980,6 +868,8 @@ mod tests {
             reason: None,
             force: false,
             skip_validation: false,
+            started_at: None,
+            completed_at: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -1014,6 +904,8 @@ mod tests {
             reason: None,
             force: false,
             skip_validation: false,
+            started_at: None,
+            completed_at: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -1030,6 +922,138 @@ mod tests {
         cleanup(&temp_dir);
     }
 
+    #[tokio::test]
+    async fn test_transition_to_done_with_backdated_timestamps() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        create_task(&db, "task1", "Test Task", "task", "pending_review").await;
+
+        let started = parse_timestamp("2024-03-01T09:00:00Z").unwrap();
+        let completed = parse_timestamp("2024-03-04T17:30:00Z").unwrap();
+        let cmd = TransitionToCommand {
+            id: "task1".to_string(),
+            target: TargetStatus::Done,
+            reason: None,
+            force: false,
+            skip_validation: false,
+            started_at: Some(started),
+            completed_at: Some(completed),
+        };
+
+        let result = cmd.execute(&db).await;
+        assert!(result.is_ok(), "Transition failed: {:?}", result.err());
+
+        assert_eq!(get_task_status(&db, "task1").await, "done");
+        assert_eq!(get_started_at(&db, "task1").await, Some(started));
+        assert_eq!(get_completed_at(&db, "task1").await, Some(completed));
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_transition_to_in_progress_with_started_at_overrides() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        create_task(&db, "task1", "Test Task", "task", "todo").await;
+
+        let started = parse_timestamp("2024-03-01T09:00:00Z").unwrap();
+        let cmd = TransitionToCommand {
+            id: "task1".to_string(),
+            target: TargetStatus::InProgress,
+            reason: None,
+            force: false,
+            skip_validation: false,
+            started_at: Some(started),
+            completed_at: None,
+        };
+
+        cmd.execute(&db).await.unwrap();
+        assert_eq!(get_started_at(&db, "task1").await, Some(started));
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_transition_to_done_completed_before_started_fails() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        // Task started on 2025-01-01; completing it a year earlier is refused
+        let query = r#"CREATE task:task1 SET
+            title = "Test Task",
+            level = "task",
+            status = "pending_review",
+            tags = [],
+            sections = [],
+            refs = [],
+            started_at = d'2025-01-01T00:00:00Z'"#;
+        db.client().query(query).await.unwrap();
+
+        let cmd = TransitionToCommand {
+            id: "task1".to_string(),
+            target: TargetStatus::Done,
+            reason: None,
+            force: false,
+            skip_validation: false,
+            started_at: None,
+            completed_at: Some(parse_timestamp("2024-01-01T00:00:00Z").unwrap()),
+        };
+
+        match cmd.execute(&db).await {
+            Err(DbError::ValidationError { message }) => {
+                assert!(message.contains("is before started_at"), "got: {}", message)
+            }
+            other => panic!("Expected ValidationError, got {:?}", other),
+        }
+
+        // Nothing changed
+        assert_eq!(get_task_status(&db, "task1").await, "pending_review");
+        assert!(get_completed_at(&db, "task1").await.is_none());
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_transition_timestamps_rejected_for_other_targets() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        create_task(&db, "task1", "Test Task", "task", "todo").await;
+
+        let at = parse_timestamp("2024-03-01T09:00:00Z").unwrap();
+        let cmd = TransitionToCommand {
+            id: "task1".to_string(),
+            target: TargetStatus::InProgress,
+            reason: None,
+            force: false,
+            skip_validation: false,
+            started_at: None,
+            completed_at: Some(at),
+        };
+        match cmd.execute(&db).await {
+            Err(DbError::ValidationError { message }) => assert_eq!(
+                message,
+                "--completed-at cannot be used when transitioning to in_progress"
+            ),
+            other => panic!("Expected ValidationError, got {:?}", other),
+        }
+
+        let cmd = TransitionToCommand {
+            id: "task1".to_string(),
+            target: TargetStatus::Rejected,
+            reason: None,
+            force: false,
+            skip_validation: false,
+            started_at: Some(at),
+            completed_at: None,
+        };
+        assert!(matches!(
+            cmd.execute(&db).await,
+            Err(DbError::ValidationError { .. })
+        ));
+        assert_eq!(get_task_status(&db, "task1").await, "todo");
+
+        cleanup(&temp_dir);
+    }
+
     // ==========================================================================
     // transition-to rejected tests
     // ==========================================================================
@@ -1046,6 +1070,8 @@ mod tests {
             reason: None,
             force: false,
             skip_validation: false,
+            started_at: None,
+            completed_at: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -1073,6 +1099,8 @@ mod tests {
             reason: Some("Out of scope".to_string()),
             force: false,
             skip_validation: false,
+            started_at: None,
+            completed_at: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -1104,6 +1132,8 @@ mod tests {
             reason: None,
             force: false,
             skip_validation: false,
+            started_at: None,
+            completed_at: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -1135,6 +1165,8 @@ mod tests {
             reason: Some("Additional reason".to_string()),
             force: false,
             skip_validation: false,
+            started_at: None,
+            completed_at: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -1169,6 +1201,8 @@ mod tests {
             reason: None,
             force: false,
             skip_validation: true,
+            started_at: None,
+            completed_at: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -1195,6 +1229,8 @@ mod tests {
             reason: None,
             force: false,
             skip_validation: true,
+            started_at: None,
+            completed_at: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -1288,9 +1324,10 @@ mod tests {
 
         let output = format!("{}", result);
         assert!(output.contains("Completed task: task1"));
-        assert!(output.contains("Unblocked tasks:"));
-        assert!(output.contains("dep1"));
-        assert!(output.contains("Dependent Task"));
+        assert_eq!(
+            output.lines().last(),
+            Some("Unblocked: dep1 (Dependent Task)")
+        );
     }
 
     #[test]
@@ -1359,6 +1396,8 @@ mod tests {
             reason: None,
             force: false,
             skip_validation: false,
+            started_at: None,
+            completed_at: None,
         };
         let debug_str = format!("{:?}", cmd);
         assert!(
@@ -2,6 +2,7 @@
 //!
 //! Implements the `vtb undepend` command to remove dependency relationships between tasks.
 
+use crate::id::resolve_task_id;
 use clap::Args;
 use serde::Deserialize;
 use vertebrae_db::{Database, DbError};
@@ -16,6 +17,10 @@ pub struct UndependCommand {
     /// Task ID of the blocker to remove (case-insensitive)
     #[arg(long = "on", required = true)]
     pub blocker_id: String,
+
+    /// Report which dependency would be removed without removing it
+    #[arg(long)]
+    pub dry_run: bool,
 }
 
 /// Result from querying a task's existence
@@ -41,11 +46,19 @@ pub struct UndependResult {
     pub blocker_id: String,
     /// Whether the dependency existed before removal
     pub existed: bool,
+    /// Whether this was a dry run that made no changes
+    pub dry_run: bool,
 }
 
 impl std::fmt::Display for UndependResult {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if self.existed {
+        if self.existed && self.dry_run {
+            write!(
+                f,
+                "Dry run: would remove dependency: {} -> {}",
+                self.task_id, self.blocker_id
+            )
+        } else if self.existed {
             write!(
                 f,
                 "Removed dependency: {} no longer depends on {}",
@@ -79,22 +92,25 @@ impl UndependCommand {
     ///
     /// Note: Non-existent dependency is handled gracefully with a warning.
     pub async fn execute(&self, db: &Database) -> Result<UndependResult, DbError> {
-        // Normalize IDs to lowercase for case-insensitive lookup
-        let task_id = self.id.to_lowercase();
-        let blocker_id = self.blocker_id.to_lowercase();
+        // Resolve case-insensitive and shortened IDs; an edge to a blocker
+        // that no longer exists can still be removed by its full ID
+        let task_id = resolve_task_id(db, &self.id).await?;
+        let blocker_id = match resolve_task_id(db, &self.blocker_id).await {
+            Err(DbError::NotFound { .. }) => self.blocker_id.to_lowercase(),
+            resolved => resolved?,
+        };
 
         // Validate source task exists
         if !self.task_exists(db, &task_id).await? {
-            return Err(DbError::InvalidPath {
-                path: std::path::PathBuf::from(&self.id),
-                reason: format!("Task '{}' not found", self.id),
+            return Err(DbError::NotFound {
+                task_id: self.id.clone(),
             });
         }
 
         // Check if dependency exists
         let existed = self.dependency_exists(db, &task_id, &blocker_id).await?;
 
-        if existed {
+        if existed && !self.dry_run {
             // Delete the dependency edge
             self.delete_dependency_edge(db, &task_id, &blocker_id)
                 .await?;
@@ -107,13 +123,14 @@ impl UndependCommand {
             task_id,
             blocker_id,
             existed,
+            dry_run: self.dry_run,
         })
     }
 
     /// Check if a task with the given ID exists.
     async fn task_exists(&self, db: &Database, id: &str) -> Result<bool, DbError> {
         let query = format!("SELECT id FROM task:{} LIMIT 1", id);
-        let mut result = db.client().query(&query).await?;
+        let mut result = db.query(&query).await?;
         let tasks: Vec<TaskExistsRow> = result.take(0)?;
         Ok(!tasks.is_empty())
     }
@@ -129,7 +146,7 @@ impl UndependCommand {
             "SELECT id FROM depends_on WHERE in = task:{} AND out = task:{}",
             task_id, blocker_id
         );
-        let mut result = db.client().query(&query).await?;
+        let mut result = db.query(&query).await?;
         let edges: Vec<DependencyEdge> = result.take(0)?;
         Ok(!edges.is_empty())
     }
@@ -145,14 +162,14 @@ impl UndependCommand {
             "DELETE depends_on WHERE in = task:{} AND out = task:{}",
             task_id, blocker_id
         );
-        db.client().query(&query).await?;
+        db.query(&query).await?;
         Ok(())
     }
 
     /// Update the updated_at timestamp for a task.
     async fn update_timestamp(&self, db: &Database, id: &str) -> Result<(), DbError> {
         let query = format!("UPDATE task:{} SET updated_at = time::now()", id);
-        db.client().query(&query).await?;
+        db.query(&query).await?;
         Ok(())
     }
 }
@@ -244,6 +261,7 @@ mod tests {
         let depend_cmd = DependCommand {
             id: "taskb".to_string(),
             blocker_id: "taska".to_string(),
+            dry_run: false,
         };
         depend_cmd.execute(&db).await.unwrap();
 
@@ -254,6 +272,7 @@ mod tests {
         let undepend_cmd = UndependCommand {
             id: "taskb".to_string(),
             blocker_id: "taska".to_string(),
+            dry_run: false,
         };
 
         let result = undepend_cmd.execute(&db).await;
@@ -281,6 +300,7 @@ mod tests {
         let depend_cmd = DependCommand {
             id: "taskb".to_string(),
             blocker_id: "taska".to_string(),
+            dry_run: false,
         };
         depend_cmd.execute(&db).await.unwrap();
 
@@ -288,6 +308,7 @@ mod tests {
         let undepend_cmd = UndependCommand {
             id: "taskb".to_string(),
             blocker_id: "taska".to_string(),
+            dry_run: false,
         };
         undepend_cmd.execute(&db).await.unwrap();
 
@@ -308,6 +329,7 @@ mod tests {
         let undepend_cmd = UndependCommand {
             id: "taskb".to_string(),
             blocker_id: "taska".to_string(),
+            dry_run: false,
         };
 
         let result = undepend_cmd.execute(&db).await;
@@ -340,12 +362,14 @@ mod tests {
         let depend_cmd = DependCommand {
             id: "taskb".to_string(),
             blocker_id: "taska".to_string(),
+            dry_run: false,
         };
         depend_cmd.execute(&db).await.unwrap();
 
         let undepend_cmd = UndependCommand {
             id: "taskb".to_string(),
             blocker_id: "taska".to_string(),
+            dry_run: false,
         };
 
         // Remove dependency first time
@@ -378,23 +402,15 @@ mod tests {
         let undepend_cmd = UndependCommand {
             id: "nonexistent".to_string(),
             blocker_id: "taska".to_string(),
+            dry_run: false,
         };
 
         let result = undepend_cmd.execute(&db).await;
         match result {
-            Err(DbError::InvalidPath { reason, .. }) => {
-                assert!(
-                    reason.contains("not found"),
-                    "Expected 'not found' in error, got: {}",
-                    reason
-                );
-                assert!(
-                    reason.contains("nonexistent"),
-                    "Expected task ID 'nonexistent' in error, got: {}",
-                    reason
-                );
+            Err(DbError::NotFound { task_id }) => {
+                assert_eq!(task_id, "nonexistent");
             }
-            Err(other) => panic!("Expected InvalidPath error, got {:?}", other),
+            Err(other) => panic!("Expected NotFound error, got {:?}", other),
             Ok(_) => panic!("Expected error, got success"),
         }
 
@@ -411,6 +427,7 @@ mod tests {
         let undepend_cmd = UndependCommand {
             id: "taska".to_string(),
             blocker_id: "nonexistent".to_string(),
+            dry_run: false,
         };
 
         let result = undepend_cmd.execute(&db).await;
@@ -435,6 +452,7 @@ mod tests {
         let depend_cmd = DependCommand {
             id: "taskb".to_string(),
             blocker_id: "taska".to_string(),
+            dry_run: false,
         };
         depend_cmd.execute(&db).await.unwrap();
 
@@ -442,6 +460,7 @@ mod tests {
         let undepend_cmd = UndependCommand {
             id: "TASKB".to_string(),
             blocker_id: "TASKA".to_string(),
+            dry_run: false,
         };
 
         let result = undepend_cmd.execute(&db).await;
@@ -466,6 +485,7 @@ mod tests {
         DependCommand {
             id: "taskc".to_string(),
             blocker_id: "taska".to_string(),
+            dry_run: false,
         }
         .execute(&db)
         .await
@@ -474,6 +494,7 @@ mod tests {
         DependCommand {
             id: "taskc".to_string(),
             blocker_id: "taskb".to_string(),
+            dry_run: false,
         }
         .execute(&db)
         .await
@@ -483,6 +504,7 @@ mod tests {
         let undepend_cmd = UndependCommand {
             id: "taskc".to_string(),
             blocker_id: "taska".to_string(),
+            dry_run: false,
         };
         undepend_cmd.execute(&db).await.unwrap();
 
@@ -518,6 +540,7 @@ mod tests {
         let undepend_cmd = UndependCommand {
             id: "taskb".to_string(),
             blocker_id: "taska".to_string(),
+            dry_run: false,
         };
         undepend_cmd.execute(&db).await.unwrap();
 
@@ -541,6 +564,7 @@ mod tests {
             task_id: "taskb".to_string(),
             blocker_id: "taska".to_string(),
             existed: true,
+            dry_run: false,
         };
 
         let output = format!("{}", result);
@@ -556,17 +580,52 @@ mod tests {
             task_id: "taskb".to_string(),
             blocker_id: "taska".to_string(),
             existed: false,
+            dry_run: false,
         };
 
         let output = format!("{}", result);
         assert_eq!(output, "Warning: No dependency from taskb to taska exists");
     }
 
+    #[tokio::test]
+    async fn test_dry_run_reports_edge_without_removing() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        create_task(&db, "taska", "Task A").await;
+        create_task(&db, "taskb", "Task B").await;
+        DependCommand {
+            id: "taskb".to_string(),
+            blocker_id: "taska".to_string(),
+            dry_run: false,
+        }
+        .execute(&db)
+        .await
+        .unwrap();
+
+        let cmd = UndependCommand {
+            id: "taskb".to_string(),
+            blocker_id: "taska".to_string(),
+            dry_run: true,
+        };
+        let result = cmd.execute(&db).await.unwrap();
+
+        assert!(result.existed);
+        assert!(result.dry_run);
+        assert_eq!(
+            result.to_string(),
+            "Dry run: would remove dependency: taskb -> taska"
+        );
+        assert!(dependency_exists(&db, "taskb", "taska").await);
+
+        cleanup(&temp_dir);
+    }
+
     #[test]
     fn test_undepend_command_debug() {
         let cmd = UndependCommand {
             id: "test123".to_string(),
             blocker_id: "blocker456".to_string(),
+            dry_run: false,
         };
         let debug_str = format!("{:?}", cmd);
         assert!(
@@ -3,6 +3,7 @@
 //! Implements the `vtb unref` command to remove code references from tasks.
 //! Supports removing by file path or removing all references.
 
+use crate::id::resolve_task_id;
 use clap::Args;
 use vertebrae_db::{CodeRef, Database, DbError, TaskUpdate};
 
@@ -78,8 +79,8 @@ impl UnrefCommand {
     /// - The task with the given ID does not exist
     /// - Database operations fail
     pub async fn execute(&self, db: &Database) -> Result<UnrefResult, DbError> {
-        // Normalize ID to lowercase for case-insensitive lookup
-        let id = self.id.to_lowercase();
+        // Resolve case-insensitive and shortened IDs
+        let id = resolve_task_id(db, &self.id).await?;
 
         // Fetch task and verify it exists
         let code_refs = self.fetch_task_refs(db, &id).await?;
@@ -133,11 +134,7 @@ impl UnrefCommand {
 
     /// Fetch the task by ID and return its code refs (mainly to verify task exists).
     async fn fetch_task_refs(&self, db: &Database, id: &str) -> Result<Vec<CodeRef>, DbError> {
-        let task = db.tasks().get(id).await?;
-
-        task.map(|t| t.code_refs).ok_or_else(|| DbError::NotFound {
-            task_id: self.id.clone(),
-        })
+        Ok(db.tasks().get_or_err(id).await?.code_refs)
     }
 }
 
@@ -4,6 +4,7 @@
 //! Supports removing single-instance types, specific multi-instance sections by index,
 //! all sections of a type, or all sections from a task.
 
+use crate::id::resolve_task_id;
 use clap::Args;
 use serde::Deserialize;
 use vertebrae_db::{Database, DbError, SectionType};
@@ -150,8 +151,8 @@ impl UnsectionCommand {
     /// - For multi-instance types without --index or --all
     /// - Database operations fail
     pub async fn execute(&self, db: &Database) -> Result<UnsectionResult, DbError> {
-        // Normalize ID to lowercase for case-insensitive lookup
-        let id = self.id.to_lowercase();
+        // Resolve case-insensitive and shortened IDs
+        let id = resolve_task_id(db, &self.id).await?;
 
         // Validate command arguments
         self.validate_arguments()?;
@@ -237,7 +238,7 @@ impl UnsectionCommand {
         id: &str,
     ) -> Result<TaskSectionsRow, DbError> {
         let query = format!("SELECT id, sections FROM task:{}", id);
-        let mut result = db.client().query(&query).await?;
+        let mut result = db.query(&query).await?;
         let task: Option<TaskSectionsRow> = result.take(0)?;
 
         task.ok_or_else(|| DbError::NotFound {
@@ -255,7 +256,7 @@ impl UnsectionCommand {
         let count = existing_sections.len();
 
         let query = format!("UPDATE task:{} SET sections = []", id);
-        db.client().query(&query).await?;
+        db.query(&query).await?;
 
         Ok(count)
     }
@@ -426,14 +427,14 @@ impl UnsectionCommand {
     ) -> Result<(), DbError> {
         let sections_array = format!("[{}]", sections.join(", "));
         let query = format!("UPDATE task:{} SET sections = {}", id, sections_array);
-        db.client().query(&query).await?;
+        db.query(&query).await?;
         Ok(())
     }
 
     /// Update the task's updated_at timestamp.
     async fn update_timestamp(&self, db: &Database, id: &str) -> Result<(), DbError> {
         let query = format!("UPDATE task:{} SET updated_at = time::now()", id);
-        db.client().query(&query).await?;
+        db.query(&query).await?;
         Ok(())
     }
 }
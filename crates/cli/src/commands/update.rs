@@ -1,20 +1,24 @@
 //! Update command for modifying existing tasks
 //!
 //! Implements the `vtb update` command to modify task fields including
-//! title, priority, tags, and parent relationship.
+//! title, priority, due date, estimate, tags, and parent relationship.
 //!
 //! Note: Description support (via --description/-d) is not currently implemented
 //! because it requires storing data in sections, which have limitations with
 //! SurrealDB's SCHEMAFULL mode and array<object> types.
 
+use crate::estimate::parse_estimate;
+use crate::id::resolve_task_id;
+use crate::timestamp::parse_due;
+use chrono::{DateTime, Utc};
 use clap::Args;
 use serde::Deserialize;
-use vertebrae_db::{Database, DbError, Priority};
+use vertebrae_db::{Database, DbError, Priority, tag_settings};
 
 /// Update an existing task
-#[derive(Debug, Args)]
+#[derive(Debug, Clone, Args)]
 pub struct UpdateCommand {
-    /// Task ID to update (case-insensitive)
+    /// Task ID to update (case-insensitive; `-` reads IDs from stdin)
     #[arg(required = true)]
     pub id: String,
 
@@ -37,6 +41,18 @@ pub struct UpdateCommand {
     /// Parent task ID (use empty string "" to remove parent)
     #[arg(long)]
     pub parent: Option<String>,
+
+    /// Due date: RFC 3339, YYYY-MM-DD, today, tomorrow or +Nd/+Nw
+    #[arg(long, value_parser = parse_due, value_name = "DATE", conflicts_with = "no_due")]
+    pub due: Option<DateTime<Utc>>,
+
+    /// Remove the due date
+    #[arg(long)]
+    pub no_due: bool,
+
+    /// Estimated effort, e.g. 2h30m, 3h or 45m
+    #[arg(long, value_parser = parse_estimate, value_name = "DURATION")]
+    pub estimate: Option<u32>,
 }
 
 /// Parse a priority string into a Priority enum
@@ -80,14 +96,13 @@ impl UpdateCommand {
     /// - Attempting to set self as parent
     /// - Database operations fail
     pub async fn execute(&self, db: &Database) -> Result<String, DbError> {
-        // Normalize ID to lowercase for case-insensitive lookup
-        let id = self.id.to_lowercase();
+        // Resolve case-insensitive and shortened IDs
+        let id = resolve_task_id(db, &self.id).await?;
 
         // Verify task exists
         if !self.task_exists(db, &id).await? {
-            return Err(DbError::InvalidPath {
-                path: std::path::PathBuf::from(&self.id),
-                reason: format!("Task '{}' not found", self.id),
+            return Err(DbError::NotFound {
+                task_id: self.id.clone(),
             });
         }
 
@@ -96,11 +111,20 @@ impl UpdateCommand {
             return Ok(id);
         }
 
-        // Validate parent if specified
+        // Validate parent if specified, resolving a shortened ID
+        let mut new_parent = None;
         if let Some(parent_id) = &self.parent
             && !parent_id.is_empty()
         {
-            let parent_id_lower = parent_id.to_lowercase();
+            let parent_id_lower = match resolve_task_id(db, parent_id).await {
+                Err(DbError::NotFound { .. }) => {
+                    return Err(DbError::InvalidPath {
+                        path: std::path::PathBuf::from(parent_id),
+                        reason: format!("Parent task '{}' does not exist", parent_id),
+                    });
+                }
+                resolved => resolved?,
+            };
 
             // Check for self-parent
             if parent_id_lower == id {
@@ -109,14 +133,7 @@ impl UpdateCommand {
                     reason: "Cannot set task as its own parent".to_string(),
                 });
             }
-
-            // Check parent exists
-            if !self.task_exists(db, &parent_id_lower).await? {
-                return Err(DbError::InvalidPath {
-                    path: std::path::PathBuf::from(parent_id),
-                    reason: format!("Parent task '{}' does not exist", parent_id),
-                });
-            }
+            new_parent = Some(parent_id_lower);
         }
 
         // Apply field updates
@@ -126,7 +143,8 @@ impl UpdateCommand {
         self.apply_tag_updates(db, &id).await?;
 
         // Handle parent update
-        self.apply_parent_update(db, &id).await?;
+        self.apply_parent_update(db, &id, new_parent.as_deref())
+            .await?;
 
         // Update timestamp
         self.update_timestamp(db, &id).await?;
@@ -143,7 +161,7 @@ impl UpdateCommand {
         }
 
         let query = format!("SELECT id FROM task:{} LIMIT 1", id);
-        let mut result = db.client().query(&query).await?;
+        let mut result = db.query(&query).await?;
 
         let tasks: Vec<IdOnly> = result.take(0)?;
         Ok(!tasks.is_empty())
@@ -156,9 +174,12 @@ impl UpdateCommand {
             || !self.add_tags.is_empty()
             || !self.remove_tags.is_empty()
             || self.parent.is_some()
+            || self.due.is_some()
+            || self.no_due
+            || self.estimate.is_some()
     }
 
-    /// Apply field updates (title, priority).
+    /// Apply field updates (title, priority, due date, estimate).
     async fn apply_field_updates(&self, db: &Database, id: &str) -> Result<(), DbError> {
         let mut updates = Vec::new();
 
@@ -172,9 +193,19 @@ impl UpdateCommand {
             updates.push(format!("priority = \"{}\"", priority.as_str()));
         }
 
+        if let Some(due) = &self.due {
+            updates.push(format!("due_at = d'{}'", due.to_rfc3339()));
+        } else if self.no_due {
+            updates.push("due_at = NONE".to_string());
+        }
+
+        if let Some(estimate) = self.estimate {
+            updates.push(format!("estimate_minutes = {}", estimate));
+        }
+
         if !updates.is_empty() {
             let query = format!("UPDATE task:{} SET {}", id, updates.join(", "));
-            db.client().query(&query).await?;
+            db.query(&query).await?;
         }
 
         Ok(())
@@ -188,20 +219,22 @@ impl UpdateCommand {
 
         // Fetch current tags
         let query = format!("SELECT id, tags FROM task:{}", id);
-        let mut result = db.client().query(&query).await?;
+        let mut result = db.query(&query).await?;
         let task: Option<TaskRow> = result.take(0)?;
 
-        let mut current_tags: Vec<String> = task.map(|t| t.tags).unwrap_or_default();
+        // Normalize stored tags too, so legacy spellings match and collapse
+        let settings = tag_settings();
+        let mut current_tags = settings.apply_all(&task.map(|t| t.tags).unwrap_or_default());
 
         // Remove tags
-        for tag in &self.remove_tags {
-            current_tags.retain(|t| t != tag);
+        for tag in settings.apply_all(&self.remove_tags) {
+            current_tags.retain(|t| *t != tag);
         }
 
         // Add tags (avoiding duplicates)
-        for tag in &self.add_tags {
-            if !current_tags.contains(tag) {
-                current_tags.push(tag.clone());
+        for tag in settings.apply_all(&self.add_tags) {
+            if !current_tags.contains(&tag) {
+                current_tags.push(tag);
             }
         }
 
@@ -220,27 +253,31 @@ impl UpdateCommand {
         };
 
         let update_query = format!("UPDATE task:{} SET tags = {}", id, tags_str);
-        db.client().query(&update_query).await?;
+        db.query(&update_query).await?;
 
         Ok(())
     }
 
-    /// Apply parent update.
-    async fn apply_parent_update(&self, db: &Database, id: &str) -> Result<(), DbError> {
-        let Some(parent_id) = &self.parent else {
+    /// Apply parent update, moving the task under the resolved `parent`,
+    /// or to the root when `--parent` is empty.
+    async fn apply_parent_update(
+        &self,
+        db: &Database,
+        id: &str,
+        parent: Option<&str>,
+    ) -> Result<(), DbError> {
+        if self.parent.is_none() {
             return Ok(());
-        };
+        }
 
         // First, delete any existing child_of edge from this task
         let delete_query = format!("DELETE child_of WHERE in = task:{}", id);
-        db.client().query(&delete_query).await?;
-
-        // If parent is not empty, create new edge
-        if !parent_id.is_empty() {
-            let parent_id_lower = parent_id.to_lowercase();
-            let create_query =
-                format!("RELATE task:{} -> child_of -> task:{}", id, parent_id_lower);
-            db.client().query(&create_query).await?;
+        db.query(&delete_query).await?;
+
+        // If a parent was given, create new edge
+        if let Some(parent) = parent {
+            let create_query = format!("RELATE task:{} -> child_of -> task:{}", id, parent);
+            db.query(&create_query).await?;
         }
 
         Ok(())
@@ -249,7 +286,7 @@ impl UpdateCommand {
     /// Update the updated_at timestamp.
     async fn update_timestamp(&self, db: &Database, id: &str) -> Result<(), DbError> {
         let query = format!("UPDATE task:{} SET updated_at = time::now()", id);
-        db.client().query(&query).await?;
+        db.query(&query).await?;
         Ok(())
     }
 }
@@ -396,6 +433,9 @@ mod tests {
             add_tags: vec![],
             remove_tags: vec![],
             parent: None,
+            due: None,
+            no_due: false,
+            estimate: None,
         };
         assert!(!cmd.has_updates());
     }
@@ -409,6 +449,9 @@ mod tests {
             add_tags: vec![],
             remove_tags: vec![],
             parent: None,
+            due: None,
+            no_due: false,
+            estimate: None,
         };
         assert!(cmd.has_updates());
     }
@@ -422,6 +465,9 @@ mod tests {
             add_tags: vec![],
             remove_tags: vec![],
             parent: None,
+            due: None,
+            no_due: false,
+            estimate: None,
         };
         assert!(cmd.has_updates());
     }
@@ -435,6 +481,9 @@ mod tests {
             add_tags: vec!["urgent".to_string()],
             remove_tags: vec![],
             parent: None,
+            due: None,
+            no_due: false,
+            estimate: None,
         };
         assert!(cmd.has_updates());
     }
@@ -448,6 +497,9 @@ mod tests {
             add_tags: vec![],
             remove_tags: vec!["old".to_string()],
             parent: None,
+            due: None,
+            no_due: false,
+            estimate: None,
         };
         assert!(cmd.has_updates());
     }
@@ -461,6 +513,9 @@ mod tests {
             add_tags: vec![],
             remove_tags: vec![],
             parent: Some("parent1".to_string()),
+            due: None,
+            no_due: false,
+            estimate: None,
         };
         assert!(cmd.has_updates());
     }
@@ -476,23 +531,17 @@ mod tests {
             add_tags: vec![],
             remove_tags: vec![],
             parent: None,
+            due: None,
+            no_due: false,
+            estimate: None,
         };
 
         let result = cmd.execute(&db).await;
         match result {
-            Err(DbError::InvalidPath { reason, .. }) => {
-                assert!(
-                    reason.contains("not found"),
-                    "Expected 'not found' in error, got: {}",
-                    reason
-                );
-                assert!(
-                    reason.contains("nonexistent"),
-                    "Expected task ID 'nonexistent' in error, got: {}",
-                    reason
-                );
+            Err(DbError::NotFound { task_id }) => {
+                assert_eq!(task_id, "nonexistent");
             }
-            Err(other) => panic!("Expected InvalidPath error, got {:?}", other),
+            Err(other) => panic!("Expected NotFound error, got {:?}", other),
             Ok(_) => panic!("Expected error, got success"),
         }
 
@@ -521,6 +570,9 @@ mod tests {
             add_tags: vec![],
             remove_tags: vec![],
             parent: None,
+            due: None,
+            no_due: false,
+            estimate: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -559,6 +611,9 @@ mod tests {
             add_tags: vec![],
             remove_tags: vec![],
             parent: None,
+            due: None,
+            no_due: false,
+            estimate: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -575,6 +630,36 @@ mod tests {
         cleanup(&temp_dir);
     }
 
+    #[tokio::test]
+    async fn test_update_sets_and_clears_due_date() {
+        let (db, temp_dir) = setup_test_db().await;
+        create_task(&db, "abc123", "Test task", "task", "todo", None, &[]).await;
+
+        let due = crate::timestamp::parse_timestamp("2024-05-01T17:00:00Z").unwrap();
+        let mut cmd = UpdateCommand {
+            id: "abc123".to_string(),
+            title: None,
+            priority: None,
+            add_tags: vec![],
+            remove_tags: vec![],
+            parent: None,
+            due: Some(due),
+            no_due: false,
+            estimate: None,
+        };
+        cmd.execute(&db).await.unwrap();
+        let task = db.tasks().get("abc123").await.unwrap().unwrap();
+        assert_eq!(task.due_at, Some(due));
+
+        cmd.due = None;
+        cmd.no_due = true;
+        cmd.execute(&db).await.unwrap();
+        let task = db.tasks().get("abc123").await.unwrap().unwrap();
+        assert_eq!(task.due_at, None);
+
+        cleanup(&temp_dir);
+    }
+
     #[tokio::test]
     async fn test_update_add_tag() {
         let (db, temp_dir) = setup_test_db().await;
@@ -597,6 +682,9 @@ mod tests {
             add_tags: vec!["urgent".to_string()],
             remove_tags: vec![],
             parent: None,
+            due: None,
+            no_due: false,
+            estimate: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -631,6 +719,9 @@ mod tests {
             add_tags: vec![],
             remove_tags: vec!["toremove".to_string()],
             parent: None,
+            due: None,
+            no_due: false,
+            estimate: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -665,6 +756,9 @@ mod tests {
             add_tags: vec!["existing".to_string()],
             remove_tags: vec![],
             parent: None,
+            due: None,
+            no_due: false,
+            estimate: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -678,6 +772,41 @@ mod tests {
         cleanup(&temp_dir);
     }
 
+    #[tokio::test]
+    async fn test_update_normalizes_added_and_removed_tags() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        create_task(
+            &db,
+            "abc123",
+            "Test task",
+            "task",
+            "todo",
+            None,
+            &["Legacy Tag", "keep"],
+        )
+        .await;
+
+        let cmd = UpdateCommand {
+            id: "abc123".to_string(),
+            title: None,
+            priority: None,
+            add_tags: vec![" Back End ".to_string(), "KEEP".to_string()],
+            remove_tags: vec!["legacy tag".to_string()],
+            parent: None,
+            due: None,
+            no_due: false,
+            estimate: None,
+        };
+
+        cmd.execute(&db).await.unwrap();
+
+        let task = get_task(&db, "abc123").await.unwrap();
+        assert_eq!(task.tags, vec!["keep", "back-end"]);
+
+        cleanup(&temp_dir);
+    }
+
     #[tokio::test]
     async fn test_update_set_parent() {
         let (db, temp_dir) = setup_test_db().await;
@@ -692,6 +821,9 @@ mod tests {
             add_tags: vec![],
             remove_tags: vec![],
             parent: Some("parent1".to_string()),
+            due: None,
+            no_due: false,
+            estimate: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -719,6 +851,9 @@ mod tests {
             add_tags: vec![],
             remove_tags: vec![],
             parent: Some("parent2".to_string()),
+            due: None,
+            no_due: false,
+            estimate: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -749,6 +884,9 @@ mod tests {
             add_tags: vec![],
             remove_tags: vec![],
             parent: Some("".to_string()), // Empty string removes parent
+            due: None,
+            no_due: false,
+            estimate: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -774,6 +912,9 @@ mod tests {
             add_tags: vec![],
             remove_tags: vec![],
             parent: Some("abc123".to_string()),
+            due: None,
+            no_due: false,
+            estimate: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -805,6 +946,9 @@ mod tests {
             add_tags: vec![],
             remove_tags: vec![],
             parent: Some("nonexistent".to_string()),
+            due: None,
+            no_due: false,
+            estimate: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -841,6 +985,9 @@ mod tests {
             add_tags: vec![],
             remove_tags: vec![],
             parent: None,
+            due: None,
+            no_due: false,
+            estimate: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -865,6 +1012,9 @@ mod tests {
             add_tags: vec![],
             remove_tags: vec![],
             parent: None,
+            due: None,
+            no_due: false,
+            estimate: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -889,6 +1039,9 @@ mod tests {
             add_tags: vec![],
             remove_tags: vec![],
             parent: None,
+            due: None,
+            no_due: false,
+            estimate: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -920,6 +1073,9 @@ mod tests {
             add_tags: vec!["new".to_string()],
             remove_tags: vec!["old".to_string()],
             parent: None,
+            due: None,
+            no_due: false,
+            estimate: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -957,6 +1113,9 @@ mod tests {
             add_tags: vec![],
             remove_tags: vec![],
             parent: None,
+            due: None,
+            no_due: false,
+            estimate: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -1002,6 +1161,9 @@ mod tests {
             add_tags: vec!["urgent".to_string()],
             remove_tags: vec!["old".to_string()],
             parent: Some("parent456".to_string()),
+            due: None,
+            no_due: false,
+            estimate: None,
         };
         let debug_str = format!("{:?}", cmd);
         assert!(
@@ -1030,6 +1192,9 @@ mod tests {
             add_tags: vec![],
             remove_tags: vec![],
             parent: Some("PARENT1".to_string()), // Uppercase parent
+            due: None,
+            no_due: false,
+            estimate: None,
         };
 
         let result = cmd.execute(&db).await;
@@ -0,0 +1,123 @@
+//! Confirmation prompts for destructive commands
+//!
+//! Destructive commands ask before acting when stdin is a terminal. When it
+//! isn't (pipes, scripts, CI), nobody can answer, so the prompt fails with an
+//! error pointing at `--yes` instead of silently picking an answer.
+
+use std::io::{self, IsTerminal, Write};
+use vertebrae_db::DbError;
+
+/// Flag that skips confirmation prompts on destructive commands
+pub const ASSUME_YES_FLAG: &str = "--yes";
+
+/// Asks the user to confirm destructive actions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Prompter {
+    interactive: bool,
+}
+
+impl Prompter {
+    /// Prompt only if stdin is attached to a terminal.
+    pub fn from_terminal() -> Self {
+        Self {
+            interactive: io::stdin().is_terminal(),
+        }
+    }
+
+    /// A prompter that never reads input; every prompt is an error.
+    pub fn non_interactive() -> Self {
+        Self { interactive: false }
+    }
+
+    /// Whether prompts can be answered.
+    pub fn is_interactive(&self) -> bool {
+        self.interactive
+    }
+
+    /// Ask a yes/no question. Anything other than `y`/`yes` means no.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError::ValidationError` when not interactive, or if reading
+    /// the answer fails.
+    pub fn confirm(&self, question: &str) -> Result<bool, DbError> {
+        let answer = self.ask(&format!("{} [y/N] ", question))?;
+        Ok(is_yes(&answer))
+    }
+
+    /// Print `prompt` and read one line of input, trimmed and lowercased.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError::ValidationError` when not interactive, or
+    /// `DbError::Io` if stdout/stdin can't be used.
+    pub fn ask(&self, prompt: &str) -> Result<String, DbError> {
+        if !self.interactive {
+            return Err(confirmation_required());
+        }
+
+        print!("{}", prompt);
+        io::stdout().flush().map_err(|source| DbError::Io {
+            stream: "stdout",
+            source,
+        })?;
+
+        let mut input = String::new();
+        io::stdin()
+            .read_line(&mut input)
+            .map_err(|source| DbError::Io {
+                stream: "stdin",
+                source,
+            })?;
+
+        Ok(input.trim().to_lowercase())
+    }
+}
+
+/// Whether an answer counts as confirmation.
+pub fn is_yes(answer: &str) -> bool {
+    matches!(answer.trim().to_lowercase().as_str(), "y" | "yes")
+}
+
+/// Error for a prompt that can't be shown because input isn't a terminal.
+fn confirmation_required() -> DbError {
+    DbError::ValidationError {
+        message: format!(
+            "Confirmation required but stdin is not a terminal; re-run with {} to proceed",
+            ASSUME_YES_FLAG
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_yes() {
+        assert!(is_yes("y"));
+        assert!(is_yes("YES"));
+        assert!(is_yes("  yes\n"));
+        assert!(!is_yes(""));
+        assert!(!is_yes("n"));
+        assert!(!is_yes("yep"));
+    }
+
+    #[test]
+    fn test_non_interactive_confirm_errors() {
+        let prompter = Prompter::non_interactive();
+        assert!(!prompter.is_interactive());
+
+        match prompter.confirm("Delete everything?") {
+            Err(DbError::ValidationError { message }) => {
+                assert!(message.contains("--yes"), "got: {}", message);
+            }
+            other => panic!("Expected ValidationError, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_non_interactive_ask_errors() {
+        assert!(Prompter::non_interactive().ask("Choice? ").is_err());
+    }
+}
@@ -0,0 +1,94 @@
+//! Effort estimates given on the command line
+//!
+//! `add` and `update` take `--estimate` as hours and minutes (`2h30m`,
+//! `3h`, `45m`); tasks store the total in minutes.
+
+/// Parse an estimate such as `2h30m`, `3h`, `45m` or a bare number of minutes.
+pub fn parse_estimate(s: &str) -> Result<u32, String> {
+    let invalid = || {
+        format!(
+            "invalid estimate '{}': expected hours and minutes like 2h30m, 3h or 45m",
+            s.trim()
+        )
+    };
+
+    let compact: String = s
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .to_lowercase();
+    if compact.is_empty() {
+        return Err(invalid());
+    }
+    if let Ok(minutes) = compact.parse::<u32>() {
+        return Ok(minutes);
+    }
+
+    let (hours, rest) = match compact.split_once('h') {
+        Some((hours, rest)) => (Some(hours), rest),
+        None => (None, compact.as_str()),
+    };
+    let minutes = match rest {
+        "" => None,
+        rest => Some(rest.strip_suffix('m').ok_or_else(invalid)?),
+    };
+    if hours.is_none() && minutes.is_none() {
+        return Err(invalid());
+    }
+
+    let number = |part: Option<&str>| match part {
+        None => Ok(0),
+        Some(digits) => digits.parse::<u32>().map_err(|_| invalid()),
+    };
+    number(hours)?
+        .checked_mul(60)
+        .and_then(|hours| hours.checked_add(number(minutes).ok()?))
+        .ok_or_else(invalid)
+}
+
+/// Format minutes as hours and minutes, e.g. `2h30m`, `3h` or `45m`.
+pub fn format_minutes(minutes: u64) -> String {
+    match (minutes / 60, minutes % 60) {
+        (0, m) => format!("{}m", m),
+        (h, 0) => format!("{}h", h),
+        (h, m) => format!("{}h{}m", h, m),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_estimate() {
+        assert_eq!(parse_estimate("2h30m"), Ok(150));
+        assert_eq!(parse_estimate("2H 30M"), Ok(150));
+        assert_eq!(parse_estimate("3h"), Ok(180));
+        assert_eq!(parse_estimate("45m"), Ok(45));
+        assert_eq!(parse_estimate("90"), Ok(90));
+        assert_eq!(parse_estimate("0m"), Ok(0));
+
+        for bad in [
+            "",
+            "h",
+            "m",
+            "2x",
+            "h30m",
+            "2h30",
+            "30m2h",
+            "-5m",
+            "99999999h",
+        ] {
+            let err = parse_estimate(bad).unwrap_err();
+            assert!(err.contains("invalid estimate"), "{}: {}", bad, err);
+        }
+    }
+
+    #[test]
+    fn test_format_minutes() {
+        assert_eq!(format_minutes(0), "0m");
+        assert_eq!(format_minutes(45), "45m");
+        assert_eq!(format_minutes(180), "3h");
+        assert_eq!(format_minutes(150), "2h30m");
+    }
+}
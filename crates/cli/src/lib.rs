@@ -4,7 +4,11 @@
 //! The binary is in `main.rs` and uses this library.
 
 pub mod commands;
+pub mod confirm;
+pub mod estimate;
 mod id;
 pub mod output;
+pub mod stdin_ids;
+pub mod timestamp;
 
 pub use commands::*;
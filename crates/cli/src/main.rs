@@ -3,8 +3,12 @@ use std::path::PathBuf;
 use std::process;
 use tracing_subscriber::EnvFilter;
 
-use vertebrae_cli::commands::Command;
-use vertebrae_db::{Database, DbError};
+use vertebrae_cli::commands::{Command, CommandResult};
+use vertebrae_cli::output::{ColorMode, error_to_json, install_color_mode};
+use vertebrae_db::{
+    Config, Database, DbError, IdFormat, TreeChars, install_graph_settings, install_id_format,
+    install_level_labels, install_list_settings, install_tag_settings, install_tree_chars,
+};
 
 /// Environment variable name for the database path
 const VTB_DB_PATH_ENV: &str = "VTB_DB_PATH";
@@ -19,6 +23,18 @@ struct Args {
     #[arg(long, global = true)]
     db: Option<PathBuf>,
 
+    /// How to display task IDs: full, short-6 or short-8 (overrides [display] id_format)
+    #[arg(long, global = true, value_name = "FORMAT")]
+    id_format: Option<IdFormat>,
+
+    /// When to color table output: auto (only on a terminal), always or never
+    #[arg(long, global = true, value_enum, default_value_t = ColorMode::Auto)]
+    color: ColorMode,
+
+    /// Print results and errors as JSON, e.g. {"error": {"kind": "not_found", "message": "..."}}
+    #[arg(long, global = true)]
+    json: bool,
+
     /// Subcommand to execute
     #[command(subcommand)]
     command: Option<Command>,
@@ -71,16 +87,45 @@ fn init_logging() {
 async fn main() {
     init_logging();
 
-    if let Err(e) = run_app().await {
-        eprintln!("error: {}", e.full_message());
-        process::exit(1);
+    // A broken config is reported before --json has been parsed
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => exit_with_error(&e, false),
+    };
+    if let Err(e) = run_with_args(&args).await {
+        exit_with_error(&e, args.json);
+    }
+}
+
+/// Load the project config and parse the command line against it
+fn parse_args() -> Result<Args, DbError> {
+    // Level names must be installed before parsing so --level accepts them
+    let config = Config::load(&Config::default_path())?;
+    install_level_labels(config.levels);
+    install_tag_settings(config.tags);
+    install_graph_settings(config.graph);
+    install_list_settings(config.list);
+
+    let mut args = Args::parse();
+    install_id_format(args.id_format.unwrap_or(config.display.id_format));
+    install_color_mode(args.color);
+    install_tree_chars(config.display.tree_chars.unwrap_or_else(TreeChars::detect));
+    if args.json
+        && let Some(command) = &mut args.command
+    {
+        command.use_json_format();
     }
+    Ok(args)
 }
 
-/// Main application logic - separated for testability
-async fn run_app() -> Result<(), DbError> {
-    let args = Args::parse();
-    run_with_args(&args).await
+/// Report `e` on stderr, as JSON with `--json`, and exit non-zero
+fn exit_with_error(e: &DbError, json: bool) -> ! {
+    if json {
+        eprintln!("{:#}", serde_json::json!({ "error": error_to_json(e) }));
+    } else {
+        eprintln!("error: {}", e.full_message());
+    }
+    process::exit(1);
 }
 
 /// Run the application with the given arguments
@@ -98,7 +143,18 @@ async fn run_with_args(args: &Args) -> Result<(), DbError> {
     match &args.command {
         Some(cmd) => {
             let result = cmd.execute(&db).await?;
-            println!("{}", result);
+            match &result {
+                // Streamed output has already been written to stdout
+                CommandResult::Streamed => {}
+                _ if args.json => println!("{}", result.to_json(cmd.renders_json())?),
+                _ => println!("{}", result),
+            }
+            // Every ID was reported above; still exit non-zero if any failed
+            if let CommandResult::Batch(batch) = &result
+                && let Some(failure) = batch.failure()
+            {
+                return Err(failure);
+            }
         }
         None => {
             println!("Welcome to Vertebrae!");
@@ -129,6 +185,37 @@ mod tests {
         assert_eq!(args.db, Some(PathBuf::from("/tmp/test-db")));
     }
 
+    #[test]
+    fn test_args_with_id_format() {
+        let args = Args::try_parse_from(["vtb", "list", "--id-format", "short-6"]).unwrap();
+        assert_eq!(args.id_format, Some(IdFormat::Short6));
+
+        let args = Args::try_parse_from(["vtb", "list"]).unwrap();
+        assert_eq!(args.id_format, None);
+
+        assert!(Args::try_parse_from(["vtb", "--id-format", "short", "list"]).is_err());
+    }
+
+    #[test]
+    fn test_args_with_json() {
+        let args = Args::try_parse_from(["vtb", "list", "--json"]).unwrap();
+        assert!(args.json);
+
+        let args = Args::try_parse_from(["vtb", "list"]).unwrap();
+        assert!(!args.json);
+    }
+
+    #[test]
+    fn test_args_with_color() {
+        let args = Args::try_parse_from(["vtb", "list", "--color", "never"]).unwrap();
+        assert_eq!(args.color, ColorMode::Never);
+
+        let args = Args::try_parse_from(["vtb", "list"]).unwrap();
+        assert_eq!(args.color, ColorMode::Auto);
+
+        assert!(Args::try_parse_from(["vtb", "--color", "sometimes", "list"]).is_err());
+    }
+
     #[test]
     fn test_args_with_add_command() {
         let args = Args::try_parse_from(["vtb", "add", "My task"]).unwrap();
@@ -175,7 +262,10 @@ mod tests {
 
         let args = Args {
             db: Some(temp_dir.clone()),
+            id_format: None,
             command: None,
+            color: ColorMode::Auto,
+            json: false,
         };
 
         let result = run_with_args(&args).await;
@@ -190,7 +280,10 @@ mod tests {
         // Test with default path (will use ~/.vtb/data)
         let args = Args {
             db: None,
+            id_format: None,
             command: None,
+            color: ColorMode::Auto,
+            json: false,
         };
 
         // This should succeed as it will use the default path
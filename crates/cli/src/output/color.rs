@@ -0,0 +1,168 @@
+//! ANSI colors for table output
+//!
+//! Task tables color the Status and Priority cells when `--color` allows it.
+//! Escape codes take no room on screen, so padding is computed from the
+//! visible width of a cell rather than its byte length.
+
+use std::io::IsTerminal;
+use std::ops::Range;
+use std::sync::OnceLock;
+
+use clap::ValueEnum;
+use unicode_width::UnicodeWidthStr;
+
+/// When to color output, selected with `--color`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum ColorMode {
+    /// Color when stdout is a terminal and `NO_COLOR` is not set
+    #[default]
+    Auto,
+    /// Always emit color codes
+    Always,
+    /// Never emit color codes
+    Never,
+}
+
+impl ColorMode {
+    /// Whether output should be colored in this mode.
+    pub fn enabled(&self) -> bool {
+        match self {
+            ColorMode::Always => true,
+            ColorMode::Never => false,
+            ColorMode::Auto => {
+                std::env::var_os("NO_COLOR").is_none_or(|v| v.is_empty())
+                    && std::io::stdout().is_terminal()
+            }
+        }
+    }
+}
+
+/// Process-wide color mode, installed once at startup
+static COLOR_MODE: OnceLock<ColorMode> = OnceLock::new();
+
+/// Install the color mode used by table output.
+///
+/// Only the first call takes effect; returns `false` if a mode was already set.
+pub fn install_color_mode(mode: ColorMode) -> bool {
+    COLOR_MODE.set(mode).is_ok()
+}
+
+/// Get the installed color mode, or `ColorMode::Never` if none was installed
+pub fn color_mode() -> ColorMode {
+    COLOR_MODE.get().copied().unwrap_or(ColorMode::Never)
+}
+
+/// SGR parameters for a status cell, if the status gets a color
+fn status_style(status: &str) -> Option<&'static str> {
+    match status {
+        "done" => Some("32"),        // green
+        "in_progress" => Some("33"), // yellow
+        "rejected" => Some("31"),    // red
+        "backlog" => Some("90"),     // gray
+        _ => None,
+    }
+}
+
+/// SGR parameters for a priority cell, if the priority gets a color
+fn priority_style(priority: &str) -> Option<&'static str> {
+    match priority {
+        "critical" => Some("1;31"), // bold red
+        "high" => Some("31"),       // red
+        "low" => Some("90"),        // gray
+        _ => None,
+    }
+}
+
+/// Wrap `text` in the given SGR style, or return it unchanged.
+fn paint(text: &str, style: Option<&str>, enabled: bool) -> String {
+    match style {
+        Some(style) if enabled => format!("\x1b[{}m{}\x1b[0m", style, text),
+        _ => text.to_string(),
+    }
+}
+
+/// A status cell, colored when `enabled`
+pub(crate) fn paint_status(status: &str, enabled: bool) -> String {
+    paint(status, status_style(status), enabled)
+}
+
+/// A priority cell, colored when `enabled`
+pub(crate) fn paint_priority(priority: &str, enabled: bool) -> String {
+    paint(priority, priority_style(priority), enabled)
+}
+
+/// `text` with the bytes in `range` highlighted when `enabled`.
+pub fn highlight(text: &str, range: Range<usize>, enabled: bool) -> String {
+    if !enabled || range.is_empty() {
+        return text.to_string();
+    }
+    format!(
+        "{}{}{}",
+        &text[..range.start],
+        paint(&text[range.clone()], Some("1;33"), true),
+        &text[range.end..]
+    )
+}
+
+/// Number of columns `s` takes on screen, ignoring ANSI escape sequences
+/// and counting wide characters (CJK, most emoji) as two.
+pub fn visible_width(s: &str) -> usize {
+    let mut visible = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            // Skip to the end of the CSI sequence
+            for c in chars.by_ref() {
+                if c.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+        } else {
+            visible.push(c);
+        }
+    }
+    visible.width()
+}
+
+/// Left-align `cell` in `width` visible columns.
+pub(crate) fn pad(cell: &str, width: usize) -> String {
+    let fill = width.saturating_sub(visible_width(cell));
+    format!("{}{}", cell, " ".repeat(fill))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paint_only_when_enabled() {
+        assert_eq!(paint_status("done", false), "done");
+        assert_eq!(paint_status("done", true), "\x1b[32mdone\x1b[0m");
+        assert_eq!(paint_status("todo", true), "todo");
+        assert_eq!(
+            paint_priority("critical", true),
+            "\x1b[1;31mcritical\x1b[0m"
+        );
+        assert_eq!(paint_priority("-", true), "-");
+        assert_eq!(highlight("a match", 2..7, false), "a match");
+        assert_eq!(highlight("a match", 2..7, true), "a \x1b[1;33mmatch\x1b[0m");
+    }
+
+    #[test]
+    fn test_visible_width_and_pad_ignore_escapes() {
+        let painted = paint_status("in_progress", true);
+        assert_eq!(visible_width(&painted), "in_progress".len());
+        assert_eq!(visible_width("plain"), 5);
+        assert_eq!(visible_width("日本"), 4);
+
+        let padded = pad(&painted, 14);
+        assert!(padded.ends_with("\x1b[0m   "));
+        assert_eq!(visible_width(&padded), 14);
+    }
+
+    #[test]
+    fn test_fixed_modes() {
+        assert!(ColorMode::Always.enabled());
+        assert!(!ColorMode::Never.enabled());
+    }
+}
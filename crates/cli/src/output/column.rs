@@ -0,0 +1,118 @@
+//! Columns of the task table
+//!
+//! `vtb list --columns` picks which columns to show and in what order;
+//! without it tables show [`Column::DEFAULT`].
+
+/// A column of the task table
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Column {
+    /// Task ID, in the configured ID format
+    Id,
+    /// Hierarchy level
+    Level,
+    /// Workflow status
+    Status,
+    /// Number of incomplete blockers
+    Blocked,
+    /// Priority, `-` when unset
+    Priority,
+    /// Title, truncated or wrapped when long
+    Title,
+    /// Comma-separated tags, truncated or wrapped when long
+    Tags,
+    /// `[R]` for tasks that need human review
+    Review,
+    /// Time since the task was created, like `3d ago`
+    Created,
+    /// Time since the task was last changed
+    Updated,
+}
+
+impl Column {
+    /// All columns, in the order they're listed in help and errors
+    pub const ALL: [Column; 10] = [
+        Column::Id,
+        Column::Level,
+        Column::Status,
+        Column::Blocked,
+        Column::Priority,
+        Column::Title,
+        Column::Tags,
+        Column::Review,
+        Column::Created,
+        Column::Updated,
+    ];
+
+    /// Columns shown when none are chosen
+    pub const DEFAULT: [Column; 7] = [
+        Column::Id,
+        Column::Level,
+        Column::Status,
+        Column::Priority,
+        Column::Title,
+        Column::Tags,
+        Column::Review,
+    ];
+
+    /// Parse a column name
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|column| column.as_str() == s)
+    }
+
+    /// Returns the name used with `--columns`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Column::Id => "id",
+            Column::Level => "level",
+            Column::Status => "status",
+            Column::Blocked => "blocked",
+            Column::Priority => "priority",
+            Column::Title => "title",
+            Column::Tags => "tags",
+            Column::Review => "review",
+            Column::Created => "created",
+            Column::Updated => "updated",
+        }
+    }
+
+    /// Header shown above the column
+    pub fn header(&self) -> &'static str {
+        match self {
+            Column::Id => "ID",
+            Column::Level => "Level",
+            Column::Status => "Status",
+            Column::Blocked => "Blocked",
+            Column::Priority => "Priority",
+            Column::Title => "Title",
+            Column::Tags => "Tags",
+            Column::Review => "[R]",
+            Column::Created => "Created",
+            Column::Updated => "Updated",
+        }
+    }
+
+    /// Widest a cell may get before it's truncated or wrapped
+    pub(crate) fn max_width(&self) -> Option<usize> {
+        match self {
+            Column::Title => Some(super::MAX_TITLE_WIDTH),
+            Column::Tags => Some(super::MAX_TAGS_WIDTH),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_round_trips_every_column() {
+        for column in Column::ALL {
+            assert_eq!(Column::parse(column.as_str()), Some(column));
+        }
+        assert_eq!(Column::parse("assignee"), None);
+        assert!(!Column::DEFAULT.contains(&Column::Blocked));
+        assert!(!Column::DEFAULT.contains(&Column::Created));
+        assert!(!Column::DEFAULT.contains(&Column::Updated));
+    }
+}
@@ -0,0 +1,221 @@
+//! Graphviz DOT output for `vtb graph`
+//!
+//! Nodes are keyed by full task ID and labeled with the (possibly shortened)
+//! ID and title; fill colors follow the task status. Every string is quoted
+//! and escaped, so titles may contain quotes, backslashes or newlines.
+//! Epic clusters become `subgraph cluster_<id>` blocks, which Graphviz
+//! draws as labeled boxes.
+
+use vertebrae_db::IdFormatter;
+
+use crate::commands::graph::{EdgeKind, GraphNode, GraphResult};
+
+/// Fill color for a node with the given status
+fn status_fill(status: &str) -> &'static str {
+    match status {
+        "done" => "palegreen",
+        "in_progress" => "lightgoldenrod1",
+        "pending_review" => "lightblue",
+        "rejected" => "lightpink",
+        "backlog" => "gray90",
+        _ => "white",
+    }
+}
+
+/// Quote `s` as a DOT string literal.
+fn quote(s: &str) -> String {
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => {}
+            _ => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// DOT statement declaring `node`, indented by `indent`
+fn node_line(node: &GraphNode, ids: &IdFormatter, indent: &str) -> String {
+    format!(
+        "{}{} [label={}, fillcolor={}];",
+        indent,
+        quote(&node.id),
+        quote(&format!("{}: {}", ids.format(&node.id), node.title)),
+        quote(status_fill(&node.status))
+    )
+}
+
+/// Name of the cluster subgraph for `epic`; Graphviz only draws subgraphs
+/// whose names start with `cluster`.
+fn cluster_name(epic: Option<&str>) -> String {
+    match epic {
+        Some(epic) => {
+            let id: String = epic
+                .chars()
+                .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+                .collect();
+            format!("cluster_{}", id)
+        }
+        None => "cluster_no_epic".to_string(),
+    }
+}
+
+/// Render a task graph as a DOT `digraph`, labeling nodes with `ids`.
+///
+/// `depends_on` edges point from a task to its blocker; `child_of` edges
+/// are dashed and point from a child to its parent. Nodes are declared
+/// inside their cluster when the graph has clusters.
+pub fn to_dot(graph: &GraphResult, ids: IdFormatter) -> String {
+    let mut lines = vec![
+        "digraph tasks {".to_string(),
+        "    rankdir=LR;".to_string(),
+        "    node [shape=box, style=\"rounded,filled\"];".to_string(),
+    ];
+    if graph.clusters.is_empty() {
+        for node in &graph.nodes {
+            lines.push(node_line(node, &ids, "    "));
+        }
+    }
+    for cluster in &graph.clusters {
+        lines.push(format!(
+            "    subgraph {} {{",
+            cluster_name(cluster.epic.as_deref())
+        ));
+        lines.push(format!("        label={};", quote(&cluster.label)));
+        for node in graph
+            .nodes
+            .iter()
+            .filter(|node| cluster.members.contains(&node.id))
+        {
+            lines.push(node_line(node, &ids, "        "));
+        }
+        lines.push("    }".to_string());
+    }
+    for edge in &graph.edges {
+        let style = match edge.kind {
+            EdgeKind::DependsOn => "",
+            EdgeKind::ChildOf => " [style=dashed, color=gray50]",
+        };
+        lines.push(format!(
+            "    {} -> {}{};",
+            quote(&edge.from),
+            quote(&edge.to),
+            style
+        ));
+    }
+    lines.push("}".to_string());
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::graph::{GraphCluster, GraphEdge};
+    use vertebrae_db::IdFormat;
+
+    fn node(id: &str, title: &str, status: &str) -> GraphNode {
+        GraphNode {
+            id: id.to_string(),
+            title: title.to_string(),
+            status: status.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_quote_escapes_specials() {
+        assert_eq!(quote("plain"), "\"plain\"");
+        assert_eq!(
+            quote("say \"hi\" \\ bye\nnow"),
+            "\"say \\\"hi\\\" \\\\ bye\\nnow\""
+        );
+    }
+
+    #[test]
+    fn test_to_dot_declares_nodes_inside_clusters() {
+        let graph = GraphResult {
+            nodes: vec![
+                node("aaa111", "Epic", "todo"),
+                node("bbb222", "Lone", "done"),
+            ],
+            edges: vec![],
+            clusters: vec![
+                GraphCluster {
+                    epic: Some("aaa111".to_string()),
+                    label: "aaa111: Epic".to_string(),
+                    members: vec!["aaa111".to_string()],
+                },
+                GraphCluster {
+                    epic: None,
+                    label: "No epic".to_string(),
+                    members: vec!["bbb222".to_string()],
+                },
+            ],
+        };
+        let ids = IdFormatter::for_ids(IdFormat::Full, graph.nodes.iter().map(|n| n.id.as_str()));
+
+        assert_eq!(
+            to_dot(&graph, ids),
+            [
+                "digraph tasks {",
+                "    rankdir=LR;",
+                "    node [shape=box, style=\"rounded,filled\"];",
+                "    subgraph cluster_aaa111 {",
+                "        label=\"aaa111: Epic\";",
+                "        \"aaa111\" [label=\"aaa111: Epic\", fillcolor=\"white\"];",
+                "    }",
+                "    subgraph cluster_no_epic {",
+                "        label=\"No epic\";",
+                "        \"bbb222\" [label=\"bbb222: Lone\", fillcolor=\"palegreen\"];",
+                "    }",
+                "}",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let graph = GraphResult {
+            nodes: vec![
+                node("abcdef12", "The \"API\"", "in_progress"),
+                node("fedcba98", "Schema", "done"),
+                node("zz000000", "Epic", "todo"),
+            ],
+            edges: vec![
+                GraphEdge {
+                    kind: EdgeKind::DependsOn,
+                    from: "abcdef12".to_string(),
+                    to: "fedcba98".to_string(),
+                },
+                GraphEdge {
+                    kind: EdgeKind::ChildOf,
+                    from: "abcdef12".to_string(),
+                    to: "zz000000".to_string(),
+                },
+            ],
+            clusters: Vec::new(),
+        };
+        let ids = IdFormatter::for_ids(IdFormat::Short6, graph.nodes.iter().map(|n| n.id.as_str()));
+
+        assert_eq!(
+            to_dot(&graph, ids),
+            [
+                "digraph tasks {",
+                "    rankdir=LR;",
+                "    node [shape=box, style=\"rounded,filled\"];",
+                "    \"abcdef12\" [label=\"abcdef: The \\\"API\\\"\", fillcolor=\"lightgoldenrod1\"];",
+                "    \"fedcba98\" [label=\"fedcba: Schema\", fillcolor=\"palegreen\"];",
+                "    \"zz000000\" [label=\"zz0000: Epic\", fillcolor=\"white\"];",
+                "    \"abcdef12\" -> \"fedcba98\";",
+                "    \"abcdef12\" -> \"zz000000\" [style=dashed, color=gray50];",
+                "}",
+            ]
+            .join("\n")
+        );
+    }
+}
@@ -0,0 +1,218 @@
+//! Output formats shared by every command that takes `--format`
+//!
+//! Commands accept the same [`OutputFormat`] values and opt into the ones
+//! they can produce by implementing [`Render`] for their output. Asking for
+//! any other format fails with an error naming the formats that would work.
+
+use clap::ValueEnum;
+use serde::Serialize;
+use vertebrae_db::DbError;
+
+/// Output format selected with `--format`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable output: an aligned table, tree or report
+    #[default]
+    #[value(alias = "text")]
+    Table,
+    /// A single JSON document
+    Json,
+    /// One JSON object per line
+    Ndjson,
+    /// Comma-separated values with a header row
+    Csv,
+    /// A YAML document
+    Yaml,
+    /// Markdown
+    Markdown,
+    /// A Graphviz DOT graph
+    Dot,
+    /// A Mermaid flowchart
+    Mermaid,
+}
+
+impl OutputFormat {
+    /// Returns the name used on the command line
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OutputFormat::Table => "table",
+            OutputFormat::Json => "json",
+            OutputFormat::Ndjson => "ndjson",
+            OutputFormat::Csv => "csv",
+            OutputFormat::Yaml => "yaml",
+            OutputFormat::Markdown => "markdown",
+            OutputFormat::Dot => "dot",
+            OutputFormat::Mermaid => "mermaid",
+        }
+    }
+
+    /// Whether this format can be written one record at a time.
+    pub fn is_streaming(&self) -> bool {
+        matches!(
+            self,
+            OutputFormat::Ndjson | OutputFormat::Csv | OutputFormat::Yaml
+        )
+    }
+}
+
+impl std::fmt::Display for OutputFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Command output that can be rendered in a fixed set of formats
+///
+/// Implementors only handle the formats in [`Render::FORMATS`];
+/// [`Render::render`] rejects everything else before dispatching.
+pub trait Render {
+    /// Command name shown when a format is rejected
+    const COMMAND: &'static str;
+
+    /// Formats this output supports
+    const FORMATS: &'static [OutputFormat];
+
+    /// Check that `format` is supported.
+    ///
+    /// Commands call this before touching the database so a bad `--format`
+    /// fails fast.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError::ValidationError` listing the supported formats.
+    fn check_format(format: OutputFormat) -> Result<(), DbError> {
+        if Self::FORMATS.contains(&format) {
+            return Ok(());
+        }
+        Err(DbError::ValidationError {
+            message: format!(
+                "--format {} is not supported by `{}`; use one of: {}",
+                format,
+                Self::COMMAND,
+                Self::FORMATS
+                    .iter()
+                    .map(OutputFormat::as_str)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        })
+    }
+
+    /// Render in `format`, which is always one of [`Render::FORMATS`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError` if serialization fails.
+    fn render_as(&self, format: OutputFormat) -> Result<String, DbError>;
+
+    /// Render in `format` after checking that it is supported.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError::ValidationError` for an unsupported format, or any
+    /// error from [`Render::render_as`].
+    fn render(&self, format: OutputFormat) -> Result<String, DbError> {
+        Self::check_format(format)?;
+        self.render_as(format)
+    }
+}
+
+/// Serialize `value` as pretty-printed JSON.
+///
+/// # Errors
+///
+/// Returns `DbError::Io` if serialization fails.
+pub fn to_json<T: Serialize + ?Sized>(value: &T) -> Result<String, DbError> {
+    serde_json::to_string_pretty(value).map_err(|e| serialize_error("JSON", e))
+}
+
+/// Describe `err` for `--json` output, e.g.
+/// `{"kind": "not_found", "code": 200, "message": "Task 'abc123' not found; ..."}`.
+pub fn error_to_json(err: &DbError) -> serde_json::Value {
+    serde_json::json!({
+        "kind": err.kind(),
+        "code": err.code(),
+        "message": err.full_message(),
+    })
+}
+
+/// Serialize `value` as a YAML document, without the trailing newline.
+///
+/// # Errors
+///
+/// Returns `DbError::Io` if serialization fails.
+pub fn to_yaml<T: Serialize + ?Sized>(value: &T) -> Result<String, DbError> {
+    let yaml = serde_yaml::to_string(value).map_err(|e| serialize_error("YAML", e))?;
+    Ok(yaml.trim_end().to_string())
+}
+
+/// Error for output that could not be serialized.
+fn serialize_error(format: &str, e: impl std::fmt::Display) -> DbError {
+    DbError::Io {
+        stream: "stdout",
+        source: std::io::Error::other(format!("{} serialization error: {}", format, e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Sample {
+        name: &'static str,
+    }
+
+    impl Render for Sample {
+        const COMMAND: &'static str = "sample";
+        const FORMATS: &'static [OutputFormat] = &[OutputFormat::Table, OutputFormat::Json];
+
+        fn render_as(&self, format: OutputFormat) -> Result<String, DbError> {
+            match format {
+                OutputFormat::Json => to_json(self),
+                _ => Ok(format!("name: {}", self.name)),
+            }
+        }
+    }
+
+    #[test]
+    fn test_render_dispatches_supported_formats() {
+        let sample = Sample { name: "a" };
+        assert_eq!(sample.render(OutputFormat::Table).unwrap(), "name: a");
+        assert_eq!(
+            sample.render(OutputFormat::Json).unwrap(),
+            "{\n  \"name\": \"a\"\n}"
+        );
+    }
+
+    #[test]
+    fn test_render_rejects_unsupported_format() {
+        let sample = Sample { name: "a" };
+        match sample.render(OutputFormat::Csv) {
+            Err(DbError::ValidationError { message }) => assert_eq!(
+                message,
+                "--format csv is not supported by `sample`; use one of: table, json"
+            ),
+            other => panic!("Expected ValidationError, got {:?}", other),
+        }
+        assert!(Sample::check_format(OutputFormat::Markdown).is_err());
+        assert!(Sample::check_format(OutputFormat::Json).is_ok());
+    }
+
+    #[test]
+    fn test_output_format_parse() {
+        for format in OutputFormat::value_variants() {
+            assert_eq!(OutputFormat::from_str(format.as_str(), false), Ok(*format));
+        }
+        assert_eq!(
+            OutputFormat::from_str("text", false),
+            Ok(OutputFormat::Table)
+        );
+        assert!(OutputFormat::from_str("xml", false).is_err());
+    }
+
+    #[test]
+    fn test_to_yaml_trims_trailing_newline() {
+        assert_eq!(to_yaml(&Sample { name: "a" }).unwrap(), "name: a");
+    }
+}
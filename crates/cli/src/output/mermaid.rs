@@ -0,0 +1,117 @@
+//! Mermaid flowchart output for `vtb graph --format mermaid`
+//!
+//! Produces a `graph TD` diagram that GitHub renders inside a
+//! ```` ```mermaid ```` block. Task status is shown through `classDef`
+//! styles, one class per status, assigned at the end of the diagram.
+
+use std::collections::BTreeMap;
+
+use crate::commands::graph::{EdgeKind, GraphResult};
+
+/// Style for tasks with the given status, if it gets one
+fn status_style(status: &str) -> Option<&'static str> {
+    match status {
+        "done" => Some("fill:#c6efce,stroke:#4f8a4f"),
+        "in_progress" => Some("fill:#ffeb9c,stroke:#9c7a00"),
+        "pending_review" => Some("fill:#cfe2f3,stroke:#3d6e99"),
+        "rejected" => Some("fill:#f4cccc,stroke:#990000"),
+        "backlog" => Some("fill:#eeeeee,stroke:#999999"),
+        _ => None,
+    }
+}
+
+/// Make `title` safe inside a quoted Mermaid label.
+fn escape(title: &str) -> String {
+    title.replace('"', "#quot;").replace(['\n', '\r'], " ")
+}
+
+/// Render a task graph as a Mermaid `graph TD` diagram.
+///
+/// Each node is written as `id["title"]`. `depends_on` edges point from a
+/// task to its blocker; `child_of` edges are dotted and point from a child
+/// to its parent.
+pub fn to_mermaid(graph: &GraphResult) -> String {
+    let mut lines = vec!["graph TD".to_string()];
+    for node in &graph.nodes {
+        lines.push(format!("    {}[\"{}\"]", node.id, escape(&node.title)));
+    }
+    for edge in &graph.edges {
+        let arrow = match edge.kind {
+            EdgeKind::DependsOn => "-->",
+            EdgeKind::ChildOf => "-.->",
+        };
+        lines.push(format!("    {} {} {}", edge.from, arrow, edge.to));
+    }
+
+    let mut by_status: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    for node in &graph.nodes {
+        by_status
+            .entry(node.status.as_str())
+            .or_default()
+            .push(node.id.as_str());
+    }
+    for (status, ids) in by_status {
+        if let Some(style) = status_style(status) {
+            lines.push(format!("    classDef {} {}", status, style));
+            lines.push(format!("    class {} {}", ids.join(","), status));
+        }
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::graph::{GraphEdge, GraphNode};
+
+    fn node(id: &str, title: &str, status: &str) -> GraphNode {
+        GraphNode {
+            id: id.to_string(),
+            title: title.to_string(),
+            status: status.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_to_mermaid() {
+        let graph = GraphResult {
+            nodes: vec![
+                node("api", "The \"API\"", "in_progress"),
+                node("epic", "Epic", "todo"),
+                node("schema", "Schema", "done"),
+                node("seed", "Seed\ndata", "done"),
+            ],
+            edges: vec![
+                GraphEdge {
+                    kind: EdgeKind::DependsOn,
+                    from: "api".to_string(),
+                    to: "schema".to_string(),
+                },
+                GraphEdge {
+                    kind: EdgeKind::ChildOf,
+                    from: "api".to_string(),
+                    to: "epic".to_string(),
+                },
+            ],
+            clusters: Vec::new(),
+        };
+
+        assert_eq!(
+            to_mermaid(&graph),
+            [
+                "graph TD",
+                "    api[\"The #quot;API#quot;\"]",
+                "    epic[\"Epic\"]",
+                "    schema[\"Schema\"]",
+                "    seed[\"Seed data\"]",
+                "    api --> schema",
+                "    api -.-> epic",
+                "    classDef done fill:#c6efce,stroke:#4f8a4f",
+                "    class schema,seed done",
+                "    classDef in_progress fill:#ffeb9c,stroke:#9c7a00",
+                "    class api in_progress",
+            ]
+            .join("\n")
+        );
+    }
+}
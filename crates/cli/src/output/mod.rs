@@ -2,23 +2,63 @@
 //!
 //! Provides table formatting and display utilities for CLI output.
 
+mod color;
+mod column;
+mod dot;
+mod format;
+mod mermaid;
+
+pub use color::{ColorMode, color_mode, highlight, install_color_mode, visible_width};
+pub use column::Column;
+pub use dot::to_dot;
+pub use format::{OutputFormat, Render, error_to_json, to_json, to_yaml};
+pub use mermaid::to_mermaid;
+
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+use chrono::{DateTime, Utc};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+use vertebrae_db::{IdFormat, IdFormatter, Level, Progress, id_format};
+
 use crate::commands::list::TaskSummary;
 
+/// Header row for CSV task listings
+const CSV_HEADER: &str = "id,level,status,priority,title,tags,needs_human_review";
+
 /// Maximum width for the title column before truncation
 const MAX_TITLE_WIDTH: usize = 30;
 
 /// Maximum width for the tags column before truncation
 const MAX_TAGS_WIDTH: usize = 20;
 
-/// Truncate a string to the specified maximum width, adding ellipsis if needed.
-fn truncate(s: &str, max_width: usize) -> String {
-    if s.len() <= max_width {
-        s.to_string()
-    } else if max_width <= 3 {
-        s.chars().take(max_width).collect()
+/// Truncate a string to the specified maximum display width, adding an
+/// ellipsis if needed.
+///
+/// Cuts between grapheme clusters, so accents and emoji stay whole, and
+/// counts wide (e.g. CJK) characters as two columns.
+pub(crate) fn truncate(s: &str, max_width: usize) -> String {
+    if s.width() <= max_width {
+        return s.to_string();
+    }
+    let (budget, ellipsis) = if max_width <= 3 {
+        (max_width, "")
     } else {
-        format!("{}...", &s[..max_width - 3])
+        (max_width - 3, "...")
+    };
+
+    let mut out = String::new();
+    let mut width = 0;
+    for grapheme in s.graphemes(true) {
+        width += grapheme.width();
+        if width > budget {
+            break;
+        }
+        out.push_str(grapheme);
     }
+    out.push_str(ellipsis);
+    out
 }
 
 /// Format tasks into an aligned table string.
@@ -30,7 +70,9 @@ fn truncate(s: &str, max_width: usize) -> String {
 /// a1b2c3  epic    in_progress  high      Authentication system     backend     [R]
 /// ```
 ///
-/// The [R] column indicates tasks that need human review.
+/// The [R] column indicates tasks that need human review. IDs are shown in
+/// the configured [`IdFormat`] (see `--id-format`), and Status and Priority
+/// are colored as the installed [`ColorMode`] allows (see `--color`).
 ///
 /// # Arguments
 ///
@@ -40,123 +82,229 @@ fn truncate(s: &str, max_width: usize) -> String {
 ///
 /// A formatted string containing the table, or an empty result message if no tasks.
 pub fn format_task_table(tasks: &[TaskSummary]) -> String {
-    if tasks.is_empty() {
-        return "No tasks found.".to_string();
+    format_task_table_with(tasks, id_format())
+}
+
+/// Statuses in workflow order, for the summary footer
+const STATUS_ORDER: [&str; 6] = [
+    "backlog",
+    "todo",
+    "in_progress",
+    "pending_review",
+    "done",
+    "rejected",
+];
+
+/// Summarize tasks as a total plus counts per status.
+///
+/// Statuses appear in workflow order and are omitted when no task has them,
+/// e.g. `Total: 12 | todo 5, in_progress 3, done 4`.
+pub fn format_status_summary(tasks: &[TaskSummary]) -> String {
+    let mut counts: Vec<(&str, usize)> = STATUS_ORDER.iter().map(|s| (*s, 0)).collect();
+    for task in tasks {
+        match counts.iter_mut().find(|(status, _)| *status == task.status) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((task.status.as_str(), 1)),
+        }
     }
 
-    // Column headers
-    let headers = ["ID", "Level", "Status", "Priority", "Title", "Tags", "[R]"];
+    let breakdown: Vec<String> = counts
+        .into_iter()
+        .filter(|(_, count)| *count > 0)
+        .map(|(status, count)| format!("{} {}", status, count))
+        .collect();
 
-    // Calculate column widths based on content
-    let id_width = tasks
-        .iter()
-        .map(|t| t.id.len())
-        .max()
-        .unwrap_or(0)
-        .max(headers[0].len());
+    if breakdown.is_empty() {
+        format!("Total: {}", tasks.len())
+    } else {
+        format!("Total: {} | {}", tasks.len(), breakdown.join(", "))
+    }
+}
 
-    let level_width = tasks
-        .iter()
-        .map(|t| t.level.len())
-        .max()
-        .unwrap_or(0)
-        .max(headers[1].len());
+/// Format tasks into an aligned table, showing IDs in `mode`.
+///
+/// Short modes are lengthened as needed so no two rows share a displayed ID.
+pub fn format_task_table_with(tasks: &[TaskSummary], mode: IdFormat) -> String {
+    render_task_table(tasks, &Column::DEFAULT, mode, None, false, color_mode())
+}
 
-    let status_width = tasks
-        .iter()
-        .map(|t| t.status.len())
-        .max()
-        .unwrap_or(0)
-        .max(headers[2].len());
+/// Format tasks into an aligned table, coloring cells as `color` decides.
+pub fn format_task_table_with_color(tasks: &[TaskSummary], color: ColorMode) -> String {
+    render_task_table(tasks, &Column::DEFAULT, id_format(), None, false, color)
+}
 
-    let priority_width = tasks
-        .iter()
-        .map(|t| t.priority.as_ref().map_or(1, |p| p.len()))
-        .max()
-        .unwrap_or(0)
-        .max(headers[3].len());
+/// Format tasks into a table with a Blocked column after Status.
+///
+/// `blocked` maps task IDs to their number of incomplete blockers; tasks
+/// missing from it, or with zero, get a blank cell.
+pub fn format_task_table_with_blocked(
+    tasks: &[TaskSummary],
+    blocked: &HashMap<String, usize>,
+) -> String {
+    format_task_table_columns(
+        tasks,
+        &with_blocked_column(&Column::DEFAULT),
+        Some(blocked),
+        false,
+    )
+}
 
-    let title_width = tasks
-        .iter()
-        .map(|t| t.title.len().min(MAX_TITLE_WIDTH))
-        .max()
-        .unwrap_or(0)
-        .max(headers[4].len());
+/// Format tasks into a table that wraps long titles and tags instead of
+/// truncating them.
+///
+/// A wrapped row takes as many lines as its tallest cell; the other columns
+/// are filled on the first line only. `blocked` adds the Blocked column as
+/// in [`format_task_table_with_blocked`].
+pub fn format_task_table_wrapped(
+    tasks: &[TaskSummary],
+    blocked: Option<&HashMap<String, usize>>,
+) -> String {
+    let columns = match blocked {
+        Some(_) => with_blocked_column(&Column::DEFAULT),
+        None => Column::DEFAULT.to_vec(),
+    };
+    format_task_table_columns(tasks, &columns, blocked, true)
+}
+
+/// Format tasks into a table with the given columns, in order.
+///
+/// Column widths come from the selected columns only. Blocked cells are
+/// filled from `blocked` and left blank without it; `wrap` works as in
+/// [`format_task_table_wrapped`].
+pub fn format_task_table_columns(
+    tasks: &[TaskSummary],
+    columns: &[Column],
+    blocked: Option<&HashMap<String, usize>>,
+    wrap: bool,
+) -> String {
+    render_task_table(tasks, columns, id_format(), blocked, wrap, color_mode())
+}
 
-    let tags_width = tasks
+/// `columns` with a Blocked column after Status (or at the end without
+/// one), unless it already has it.
+pub fn with_blocked_column(columns: &[Column]) -> Vec<Column> {
+    let mut columns = columns.to_vec();
+    if !columns.contains(&Column::Blocked) {
+        let at = columns
+            .iter()
+            .position(|c| *c == Column::Status)
+            .map_or(columns.len(), |i| i + 1);
+        columns.insert(at, Column::Blocked);
+    }
+    columns
+}
+
+/// Shared table layout for the given columns, with multi-line title and
+/// tags cells when `wrap` is set.
+fn render_task_table(
+    tasks: &[TaskSummary],
+    columns: &[Column],
+    mode: IdFormat,
+    blocked: Option<&HashMap<String, usize>>,
+    wrap: bool,
+    color: ColorMode,
+) -> String {
+    if tasks.is_empty() {
+        return "No tasks found.".to_string();
+    }
+    let color = color.enabled();
+
+    let ids = IdFormatter::for_ids(mode, tasks.iter().map(|t| t.id.as_str()));
+    let now = Utc::now();
+    let age = |time: Option<DateTime<Utc>>| match time {
+        Some(time) => humanize_age(time, now),
+        None => "-".to_string(),
+    };
+
+    // One cell per task and column, each a list of lines
+    let cell = |task: &TaskSummary, column: &Column| {
+        let text = match column {
+            Column::Id => ids.format(&task.id).to_string(),
+            Column::Level => task.level.clone(),
+            Column::Status => task.status.clone(),
+            Column::Blocked => match blocked.and_then(|b| b.get(&task.id)) {
+                Some(&count) if count > 0 => count.to_string(),
+                _ => String::new(),
+            },
+            Column::Priority => task.priority.clone().unwrap_or_else(|| "-".to_string()),
+            Column::Title => task.title.clone(),
+            Column::Tags => format_tags(&task.tags),
+            Column::Review => format_review_status(task.needs_human_review).to_string(),
+            Column::Created => age(task.created_at),
+            Column::Updated => age(task.updated_at),
+        };
+        match column.max_width() {
+            Some(max_width) if wrap => wrap_cell(&text, max_width),
+            Some(max_width) => vec![truncate(&text, max_width)],
+            None => vec![text],
+        }
+    };
+    let rows: Vec<Vec<Vec<String>>> = tasks
         .iter()
-        .map(|t| format_tags(&t.tags).len().min(MAX_TAGS_WIDTH))
-        .max()
-        .unwrap_or(0)
-        .max(headers[5].len());
+        .map(|task| columns.iter().map(|column| cell(task, column)).collect())
+        .collect();
 
-    // Review column is fixed width (3 chars for "[R]")
-    let review_width = headers[6].len();
+    // Calculate column widths based on content
+    let widths: Vec<usize> = columns
+        .iter()
+        .enumerate()
+        .map(|(i, column)| {
+            rows.iter()
+                .flat_map(|row| &row[i])
+                .map(|line| visible_width(line))
+                .max()
+                .unwrap_or(0)
+                .max(column.header().len())
+        })
+        .collect();
+
+    // Colored cells are padded by visible width, not byte length
+    let line = |cells: Vec<String>| -> String {
+        cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &width)| color::pad(cell, width))
+            .collect::<Vec<_>>()
+            .join("  ")
+    };
 
     let mut output = String::new();
 
     // Header row
-    output.push_str(&format!(
-        "{:<id_w$}  {:<level_w$}  {:<status_w$}  {:<priority_w$}  {:<title_w$}  {:<tags_w$}  {:<review_w$}\n",
-        headers[0],
-        headers[1],
-        headers[2],
-        headers[3],
-        headers[4],
-        headers[5],
-        headers[6],
-        id_w = id_width,
-        level_w = level_width,
-        status_w = status_width,
-        priority_w = priority_width,
-        title_w = title_width,
-        tags_w = tags_width,
-        review_w = review_width,
+    output.push_str(&line(
+        columns.iter().map(|c| c.header().to_string()).collect(),
     ));
+    output.push('\n');
 
-    // Separator row using Unicode box-drawing character
-    output.push_str(&format!(
-        "{:->id_w$}  {:->level_w$}  {:->status_w$}  {:->priority_w$}  {:->title_w$}  {:->tags_w$}  {:->review_w$}\n",
-        "",
-        "",
-        "",
-        "",
-        "",
-        "",
-        "",
-        id_w = id_width,
-        level_w = level_width,
-        status_w = status_width,
-        priority_w = priority_width,
-        title_w = title_width,
-        tags_w = tags_width,
-        review_w = review_width,
-    ));
+    // Separator row
+    output.push_str(&line(widths.iter().map(|&w| "-".repeat(w)).collect()));
+    output.push('\n');
 
     // Data rows
-    for task in tasks {
-        let priority_display = task.priority.as_deref().unwrap_or("-");
-        let title_display = truncate(&task.title, MAX_TITLE_WIDTH);
-        let tags_display = truncate(&format_tags(&task.tags), MAX_TAGS_WIDTH);
-        let review_display = format_review_status(task.needs_human_review);
-
-        output.push_str(&format!(
-            "{:<id_w$}  {:<level_w$}  {:<status_w$}  {:<priority_w$}  {:<title_w$}  {:<tags_w$}  {:<review_w$}\n",
-            task.id,
-            task.level,
-            task.status,
-            priority_display,
-            title_display,
-            tags_display,
-            review_display,
-            id_w = id_width,
-            level_w = level_width,
-            status_w = status_width,
-            priority_w = priority_width,
-            title_w = title_width,
-            tags_w = tags_width,
-            review_w = review_width,
-        ));
+    for row in &rows {
+        let first = columns
+            .iter()
+            .zip(row)
+            .map(|(column, cell)| match column {
+                Column::Status => color::paint_status(&cell[0], color),
+                Column::Priority => color::paint_priority(&cell[0], color),
+                _ => cell[0].clone(),
+            })
+            .collect();
+        output.push_str(&line(first));
+        output.push('\n');
+
+        // Continuation lines of wrapped cells, with the other columns blank
+        let height = row.iter().map(Vec::len).max().unwrap_or(1);
+        for index in 1..height {
+            let continuation = line(
+                row.iter()
+                    .map(|cell| cell.get(index).cloned().unwrap_or_default())
+                    .collect(),
+            );
+            output.push_str(continuation.trim_end());
+            output.push('\n');
+        }
     }
 
     // Remove trailing newline
@@ -165,6 +313,188 @@ pub fn format_task_table(tasks: &[TaskSummary]) -> String {
     output
 }
 
+/// Incremental writer for row-oriented task formats (ndjson, csv, yaml).
+///
+/// Writes the CSV header before the first row, so the same writer produces
+/// identical output whether tasks arrive in one batch or page by page.
+/// YAML rows are written as sequence items, so the whole output parses as
+/// one list.
+pub struct TaskRowWriter<W: Write> {
+    out: W,
+    format: OutputFormat,
+    rows: usize,
+}
+
+impl<W: Write> TaskRowWriter<W> {
+    /// Create a row writer for the given format.
+    ///
+    /// Formats that are not row-oriented are written as ndjson.
+    pub fn new(out: W, format: OutputFormat) -> Self {
+        Self {
+            out,
+            format,
+            rows: 0,
+        }
+    }
+
+    /// Write a batch of tasks.
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error if writing to the underlying writer fails.
+    pub fn write_all<I>(&mut self, tasks: I) -> io::Result<()>
+    where
+        I: IntoIterator<Item = TaskSummary>,
+    {
+        for task in tasks {
+            self.write_row(&task)?;
+        }
+        Ok(())
+    }
+
+    /// Write a single task.
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error if writing to the underlying writer fails.
+    pub fn write_row(&mut self, task: &TaskSummary) -> io::Result<()> {
+        match self.format {
+            OutputFormat::Csv => {
+                if self.rows == 0 {
+                    writeln!(self.out, "{}", CSV_HEADER)?;
+                }
+                writeln!(self.out, "{}", format_task_csv_row(task))?;
+            }
+            OutputFormat::Yaml => {
+                let yaml =
+                    serde_yaml::to_string(std::slice::from_ref(task)).map_err(io::Error::other)?;
+                write!(self.out, "{}", yaml)?;
+            }
+            _ => {
+                let json = serde_json::to_string(task).map_err(io::Error::other)?;
+                writeln!(self.out, "{}", json)?;
+            }
+        }
+        self.rows += 1;
+        Ok(())
+    }
+
+    /// Number of rows written so far.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Flush the writer and return the underlying output.
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error if flushing fails.
+    pub fn finish(mut self) -> io::Result<W> {
+        // An empty CSV listing still gets its header, and an empty YAML
+        // listing is an empty sequence rather than a null document
+        if self.rows == 0 {
+            match self.format {
+                OutputFormat::Csv => writeln!(self.out, "{}", CSV_HEADER)?,
+                OutputFormat::Yaml => writeln!(self.out, "[]")?,
+                _ => {}
+            }
+        }
+        self.out.flush()?;
+        Ok(self.out)
+    }
+}
+
+/// Format tasks in the given list format.
+///
+/// This is the buffered path; for row-oriented formats it produces exactly
+/// what `TaskRowWriter` would write for the same tasks.
+pub fn format_task_list(tasks: &[TaskSummary], format: OutputFormat) -> String {
+    if format == OutputFormat::Table {
+        return format_task_table(tasks);
+    }
+
+    let mut writer = TaskRowWriter::new(Vec::new(), format);
+    // Writing to a Vec cannot fail
+    let _ = writer.write_all(tasks.iter().cloned());
+    let bytes = writer.finish().unwrap_or_default();
+
+    let mut output = String::from_utf8_lossy(&bytes).into_owned();
+    // Remove trailing newline
+    output.pop();
+    output
+}
+
+/// Format a task as a CSV row (fields in `CSV_HEADER` order).
+fn format_task_csv_row(task: &TaskSummary) -> String {
+    let fields = [
+        task.id.as_str(),
+        task.level.as_str(),
+        task.status.as_str(),
+        task.priority.as_deref().unwrap_or(""),
+        task.title.as_str(),
+        &task.tags.join(";"),
+        if task.needs_human_review == Some(true) {
+            "true"
+        } else {
+            "false"
+        },
+    ];
+
+    fields
+        .iter()
+        .map(|f| escape_csv_field(f))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Quote a CSV field if it contains a delimiter, quote, or line break.
+pub(crate) fn escape_csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Display label for a stored level name, honoring configured level names.
+///
+/// Unknown names are returned unchanged.
+pub fn level_label(level: &str) -> &str {
+    match Level::parse_name(level) {
+        Some(parsed) => parsed.label(),
+        None => level,
+    }
+}
+
+/// Render progress as a bar `width` cells wide, e.g. `[██████----] 60% (3/5)`.
+///
+/// The bar is only full when everything is done and only empty when nothing
+/// is, matching the percentage; filled and empty cells always add up to
+/// `width`.
+pub fn progress_bar(progress: &Progress, width: usize) -> String {
+    let filled = if progress.total_count == 0 || width == 0 {
+        0
+    } else {
+        let rounded =
+            (progress.done_count * width + progress.total_count / 2) / progress.total_count;
+        if progress.is_complete() {
+            width
+        } else if progress.is_empty() {
+            0
+        } else {
+            rounded.clamp(1, width.saturating_sub(1).max(1))
+        }
+    };
+    format!(
+        "[{}{}] {}% ({}/{})",
+        "█".repeat(filled),
+        "-".repeat(width - filled),
+        progress.percentage,
+        progress.done_count,
+        progress.total_count
+    )
+}
+
 /// Format the review status indicator.
 ///
 /// Returns "[R]" if needs_human_review is true, otherwise returns an empty string.
@@ -175,6 +505,51 @@ fn format_review_status(needs_human_review: Option<bool>) -> &'static str {
     }
 }
 
+/// Split text into lines at most `width` terminal columns wide, breaking at
+/// whitespace where possible and between graphemes inside words longer than
+/// a whole line.
+///
+/// Widths are measured like [`truncate`], so wide characters such as CJK
+/// count as two columns. Always returns at least one (possibly empty) line.
+fn wrap_cell(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    let mut line_width = 0;
+
+    for word in text.split_whitespace() {
+        let word_width = word.width();
+        let gap = usize::from(line_width > 0);
+        if line_width + gap + word_width <= width {
+            if gap > 0 {
+                line.push(' ');
+            }
+            line.push_str(word);
+            line_width += gap + word_width;
+            continue;
+        }
+
+        if line_width > 0 {
+            lines.push(std::mem::take(&mut line));
+            line_width = 0;
+        }
+        for grapheme in word.graphemes(true) {
+            let grapheme_width = grapheme.width();
+            if line_width > 0 && line_width + grapheme_width > width {
+                lines.push(std::mem::take(&mut line));
+                line_width = 0;
+            }
+            line.push_str(grapheme);
+            line_width += grapheme_width;
+        }
+    }
+
+    if line_width > 0 || lines.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
 /// Format tags as a comma-separated string.
 fn format_tags(tags: &[String]) -> String {
     if tags.is_empty() {
@@ -184,10 +559,56 @@ fn format_tags(tags: &[String]) -> String {
     }
 }
 
+/// Describe how long before `now` something happened, like `5m ago` or
+/// `3d ago`.
+///
+/// Anything under a minute old, including times slightly in the future
+/// from clock skew, is `just now`. Months count as 30 days and years as
+/// 365.
+pub fn humanize_age(then: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let age = now - then;
+    let days = age.num_days();
+    if age.num_minutes() < 1 {
+        "just now".to_string()
+    } else if age.num_hours() < 1 {
+        format!("{}m ago", age.num_minutes())
+    } else if days < 1 {
+        format!("{}h ago", age.num_hours())
+    } else if days < 30 {
+        format!("{}d ago", days)
+    } else if days < 365 {
+        format!("{}mo ago", days / 30)
+    } else {
+        format!("{}y ago", days / 365)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    /// Helper to build a CLI task summary
+    fn make_task(
+        id: &str,
+        title: &str,
+        level: &str,
+        status: &str,
+        priority: Option<&str>,
+        tags: Vec<&str>,
+    ) -> TaskSummary {
+        TaskSummary {
+            id: id.to_string(),
+            title: title.to_string(),
+            level: level.to_string(),
+            status: status.to_string(),
+            priority: priority.map(String::from),
+            tags: tags.into_iter().map(String::from).collect(),
+            needs_human_review: None,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
     #[test]
     fn test_format_empty_tasks() {
         let tasks: Vec<TaskSummary> = vec![];
@@ -205,6 +626,8 @@ mod tests {
             priority: Some("high".to_string()),
             tags: vec!["backend".to_string()],
             needs_human_review: None,
+            created_at: None,
+            updated_at: None,
         }];
 
         let result = format_task_table(&tasks);
@@ -245,6 +668,8 @@ mod tests {
                 priority: Some("critical".to_string()),
                 tags: vec!["urgent".to_string(), "backend".to_string()],
                 needs_human_review: Some(true),
+                created_at: None,
+                updated_at: None,
             },
             TaskSummary {
                 id: "d4e5f6".to_string(),
@@ -254,6 +679,8 @@ mod tests {
                 priority: None,
                 tags: vec![],
                 needs_human_review: None,
+                created_at: None,
+                updated_at: None,
             },
         ];
 
@@ -289,6 +716,8 @@ mod tests {
             priority: None,
             tags: vec![],
             needs_human_review: None,
+            created_at: None,
+            updated_at: None,
         }];
 
         let result = format_task_table(&tasks);
@@ -309,6 +738,8 @@ mod tests {
             priority: Some("low".to_string()),
             tags: vec![],
             needs_human_review: None,
+            created_at: None,
+            updated_at: None,
         }];
 
         let result = format_task_table(&tasks);
@@ -339,6 +770,45 @@ mod tests {
         assert_eq!(format_tags(&tags), "backend, api, v2");
     }
 
+    #[test]
+    fn test_progress_bar() {
+        assert_eq!(
+            progress_bar(&Progress::new(3, 5), 10),
+            "[██████----] 60% (3/5)"
+        );
+        assert_eq!(
+            progress_bar(&Progress::new(0, 4), 10),
+            "[----------] 0% (0/4)"
+        );
+        assert_eq!(
+            progress_bar(&Progress::new(4, 4), 10),
+            "[██████████] 100% (4/4)"
+        );
+        // Nearly done or barely started never rounds to a full or empty bar
+        assert_eq!(
+            progress_bar(&Progress::new(199, 200), 10),
+            "[█████████-] 99% (199/200)"
+        );
+        assert_eq!(
+            progress_bar(&Progress::new(1, 200), 10),
+            "[█---------] 1% (1/200)"
+        );
+        assert_eq!(progress_bar(&Progress::new(0, 0), 4), "[----] 0% (0/0)");
+    }
+
+    #[test]
+    fn test_progress_bar_always_fills_width() {
+        for width in 1..=12 {
+            for total in 1..=9 {
+                for done in 0..=total {
+                    let bar = progress_bar(&Progress::new(done, total), width);
+                    let cells = bar.chars().filter(|c| matches!(c, '█' | '-')).count();
+                    assert_eq!(cells, width, "{}/{} at width {}", done, total, width);
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_truncate_short_string() {
         assert_eq!(truncate("hello", 10), "hello");
@@ -366,6 +836,45 @@ mod tests {
         assert_eq!(truncate("", 10), "");
     }
 
+    #[test]
+    fn test_truncate_multibyte_and_wide() {
+        // Cutting inside a multibyte character must not panic
+        assert_eq!(truncate("café au lait", 7), "café...");
+        assert_eq!(truncate("cafe\u{301} au lait", 7), "cafe\u{301}...");
+        // CJK characters take two columns each
+        let cjk = truncate("任务管理系统的标题", 10);
+        assert_eq!(cjk, "任务管...");
+        assert_eq!(cjk.width(), 9);
+        assert_eq!(truncate("🚀🚀🚀🚀 launch", 8), "🚀🚀...");
+    }
+
+    #[test]
+    fn test_format_task_table_aligns_wide_titles() {
+        let tasks = vec![
+            make_task("a", "Crème brûlée", "task", "todo", None, vec!["café"]),
+            make_task("b", "数据库迁移", "task", "todo", None, vec!["后端"]),
+            make_task("c", "🚀 Launch", "task", "done", None, vec![]),
+            make_task(
+                "d",
+                "一二三四五六七八九十一二三四五六七八九十",
+                "task",
+                "todo",
+                None,
+                vec![],
+            ),
+        ];
+        let result = format_task_table(&tasks);
+        let lines: Vec<&str> = result.lines().collect();
+        // Every row, header and separator included, has the same display width
+        let widths: Vec<usize> = lines.iter().map(|l| visible_width(l)).collect();
+        assert!(
+            widths.iter().all(|w| *w == widths[0]),
+            "widths: {:?}",
+            widths
+        );
+        assert!(lines[5].contains("一二三四五六七八九十一二三..."));
+    }
+
     #[test]
     fn test_format_long_title_truncated() {
         let tasks = vec![TaskSummary {
@@ -376,6 +885,8 @@ mod tests {
             priority: None,
             tags: vec![],
             needs_human_review: None,
+            created_at: None,
+            updated_at: None,
         }];
 
         let result = format_task_table(&tasks);
@@ -417,6 +928,8 @@ mod tests {
             priority: None,
             tags: tags_input.clone(),
             needs_human_review: None,
+            created_at: None,
+            updated_at: None,
         }];
 
         let result = format_task_table(&tasks);
@@ -443,6 +956,8 @@ mod tests {
                 priority: Some("high".to_string()),
                 tags: vec!["x".to_string()],
                 needs_human_review: None,
+                created_at: None,
+                updated_at: None,
             },
             TaskSummary {
                 id: "abcdef".to_string(),
@@ -452,6 +967,8 @@ mod tests {
                 priority: Some("critical".to_string()),
                 tags: vec!["backend".to_string(), "api".to_string()],
                 needs_human_review: Some(true),
+                created_at: None,
+                updated_at: None,
             },
         ];
 
@@ -498,6 +1015,8 @@ mod tests {
                 priority: None,
                 tags: vec![],
                 needs_human_review: None,
+                created_at: None,
+                updated_at: None,
             }];
 
             let result = format_task_table(&tasks);
@@ -518,6 +1037,8 @@ mod tests {
                 priority: None,
                 tags: vec![],
                 needs_human_review: None,
+                created_at: None,
+                updated_at: None,
             }];
 
             let result = format_task_table(&tasks);
@@ -538,6 +1059,8 @@ mod tests {
                 priority: Some(priority.to_string()),
                 tags: vec![],
                 needs_human_review: None,
+                created_at: None,
+                updated_at: None,
             }];
 
             let result = format_task_table(&tasks);
@@ -563,6 +1086,8 @@ mod tests {
             priority: None,
             tags: vec![],
             needs_human_review: Some(true),
+            created_at: None,
+            updated_at: None,
         }];
 
         let result = format_task_table(&tasks);
@@ -584,6 +1109,8 @@ mod tests {
             priority: None,
             tags: vec![],
             needs_human_review: Some(false),
+            created_at: None,
+            updated_at: None,
         }];
 
         let result = format_task_table(&tasks);
@@ -595,10 +1122,434 @@ mod tests {
         let data_parts: Vec<&str> = data_row.split_whitespace().collect();
 
         // Data parts should not contain [R] as the last element
-        let has_review_indicator = data_parts.last().map_or(false, |&s| s == "[R]");
+        let has_review_indicator = data_parts.last().is_some_and(|&s| s == "[R]");
         assert!(
             !has_review_indicator,
             "Data row should not have [R] indicator when needs_human_review is false"
         );
     }
+
+    #[test]
+    fn test_format_status_summary_counts_in_workflow_order() {
+        let tasks = vec![
+            make_task("a", "A", "task", "done", None, vec![]),
+            make_task("b", "B", "task", "todo", None, vec![]),
+            make_task("c", "C", "task", "in_progress", None, vec![]),
+            make_task("d", "D", "task", "todo", None, vec![]),
+            make_task("e", "E", "task", "done", None, vec![]),
+        ];
+        assert_eq!(
+            format_status_summary(&tasks),
+            "Total: 5 | todo 2, in_progress 1, done 2"
+        );
+    }
+
+    #[test]
+    fn test_format_status_summary_empty_and_unknown_status() {
+        assert_eq!(format_status_summary(&[]), "Total: 0");
+
+        let tasks = vec![
+            make_task("a", "A", "task", "archived", None, vec![]),
+            make_task("b", "B", "task", "backlog", None, vec![]),
+        ];
+        assert_eq!(
+            format_status_summary(&tasks),
+            "Total: 2 | backlog 1, archived 1"
+        );
+    }
+
+    #[test]
+    fn test_format_task_list_table_matches_table() {
+        let tasks = vec![make_task("abc123", "Test", "task", "todo", None, vec![])];
+        assert_eq!(
+            format_task_list(&tasks, OutputFormat::Table),
+            format_task_table(&tasks)
+        );
+    }
+
+    #[test]
+    fn test_format_task_list_ndjson() {
+        let tasks = vec![
+            make_task("aaa111", "First", "task", "todo", Some("high"), vec![]),
+            make_task("bbb222", "Second", "epic", "done", None, vec!["x"]),
+        ];
+        let output = format_task_list(&tasks, OutputFormat::Ndjson);
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 2);
+
+        let first: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+        assert_eq!(first["id"], "aaa111");
+        assert_eq!(first["priority"], "high");
+        let second: serde_json::Value = serde_json::from_str(lines[1]).unwrap();
+        assert_eq!(second["tags"][0], "x");
+    }
+
+    #[test]
+    fn test_format_task_list_csv_escapes_fields() {
+        let tasks = vec![make_task(
+            "abc123",
+            "Fix \"this\", now",
+            "task",
+            "todo",
+            None,
+            vec!["a", "b"],
+        )];
+        let output = format_task_list(&tasks, OutputFormat::Csv);
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines[0], CSV_HEADER);
+        assert_eq!(
+            lines[1],
+            "abc123,task,todo,,\"Fix \"\"this\"\", now\",a;b,false"
+        );
+    }
+
+    #[test]
+    fn test_format_task_list_csv_empty_has_header() {
+        assert_eq!(format_task_list(&[], OutputFormat::Csv), CSV_HEADER);
+        assert_eq!(format_task_list(&[], OutputFormat::Ndjson), "");
+    }
+
+    #[test]
+    fn test_format_task_list_yaml_round_trips() {
+        let mut review = make_task("bbb222", "- not: a list", "epic", "done", None, vec![]);
+        review.needs_human_review = Some(true);
+        let tasks = vec![
+            make_task(
+                "aaa111",
+                "Fix \"this\": now",
+                "task",
+                "todo",
+                Some("high"),
+                vec!["a", "b"],
+            ),
+            review,
+        ];
+
+        let output = format_task_list(&tasks, OutputFormat::Yaml);
+        assert!(output.starts_with("- id: aaa111\n"), "got:\n{}", output);
+
+        let parsed: Vec<TaskSummary> = serde_yaml::from_str(&output).unwrap();
+        assert_eq!(parsed, tasks);
+    }
+
+    #[test]
+    fn test_format_task_list_yaml_empty_is_empty_sequence() {
+        let output = format_task_list(&[], OutputFormat::Yaml);
+        assert_eq!(output, "[]");
+        let parsed: Vec<TaskSummary> = serde_yaml::from_str(&output).unwrap();
+        assert!(parsed.is_empty());
+    }
+
+    #[test]
+    fn test_row_writer_batches_match_single_batch() {
+        let tasks = vec![
+            make_task("aaa111", "First", "task", "todo", None, vec![]),
+            make_task("bbb222", "Second", "task", "todo", None, vec![]),
+            make_task("ccc333", "Third", "task", "todo", None, vec![]),
+        ];
+
+        for format in [OutputFormat::Csv, OutputFormat::Yaml] {
+            let mut paged = TaskRowWriter::new(Vec::new(), format);
+            paged.write_all(tasks[..1].iter().cloned()).unwrap();
+            paged.write_all(tasks[1..].iter().cloned()).unwrap();
+            assert_eq!(paged.rows(), 3);
+            let paged = String::from_utf8(paged.finish().unwrap()).unwrap();
+
+            assert_eq!(
+                paged.trim_end_matches('\n'),
+                format_task_list(&tasks, format)
+            );
+        }
+    }
+
+    #[test]
+    fn test_output_format_is_streaming() {
+        assert!(!OutputFormat::Table.is_streaming());
+        assert!(OutputFormat::Ndjson.is_streaming());
+        assert!(OutputFormat::Csv.is_streaming());
+        assert!(OutputFormat::Yaml.is_streaming());
+    }
+
+    #[test]
+    fn test_format_task_table_color_never_has_no_escapes() {
+        let tasks = vec![
+            make_task("a", "Done", "task", "done", Some("critical"), vec![]),
+            make_task("b", "Old", "task", "backlog", Some("low"), vec!["x"]),
+        ];
+        let plain = format_task_table_with_color(&tasks, ColorMode::Never);
+        assert!(!plain.contains('\x1b'), "got: {:?}", plain);
+        assert_eq!(plain, format_task_table(&tasks));
+    }
+
+    #[test]
+    fn test_format_task_table_colored_stays_aligned() {
+        let tasks = vec![
+            make_task("a", "Done", "task", "done", Some("critical"), vec![]),
+            make_task("b", "Doing", "task", "in_progress", None, vec![]),
+            make_task("c", "Dropped", "task", "rejected", Some("medium"), vec![]),
+        ];
+        let colored = format_task_table_with_color(&tasks, ColorMode::Always);
+        assert!(colored.contains("\x1b[32mdone\x1b[0m"));
+        assert!(colored.contains("\x1b[1;31mcritical\x1b[0m"));
+
+        let plain = format_task_table_with_color(&tasks, ColorMode::Never);
+        let widths = |table: &str| table.lines().map(visible_width).collect::<Vec<_>>();
+        assert_eq!(widths(&colored), widths(&plain));
+        // Title column starts at the same visible offset on every row
+        for (colored, plain) in colored.lines().zip(plain.lines()).skip(2) {
+            let title_at =
+                |line: &str, title: &str| visible_width(&line[..line.find(title).unwrap()]);
+            for title in ["Done", "Doing", "Dropped"] {
+                if plain.contains(title) {
+                    assert_eq!(title_at(colored, title), title_at(plain, title));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_format_task_table_columns_in_given_order() {
+        let tasks = vec![
+            make_task(
+                "aaa111",
+                "A title well past thirty characters",
+                "epic",
+                "todo",
+                None,
+                vec!["backend-services-team"],
+            ),
+            make_task(
+                "bbb222",
+                "Short",
+                "task",
+                "in_progress",
+                Some("high"),
+                vec![],
+            ),
+        ];
+
+        let result = format_task_table_columns(
+            &tasks,
+            &[Column::Title, Column::Id, Column::Status],
+            None,
+            false,
+        );
+        let lines: Vec<&str> = result.lines().collect();
+        assert_eq!(
+            lines[0],
+            "Title                           ID      Status     "
+        );
+        assert_eq!(
+            lines[1],
+            "------------------------------  ------  -----------"
+        );
+        assert_eq!(
+            lines[2],
+            "A title well past thirty ch...  aaa111  todo       "
+        );
+        assert_eq!(
+            lines[3],
+            "Short                           bbb222  in_progress"
+        );
+        // Unselected columns take no room
+        assert!(!result.contains("backend") && !result.contains("Level"));
+
+        // Narrow columns shrink to their own content
+        let result = format_task_table_columns(&tasks, &[Column::Id], None, false);
+        assert_eq!(result, "ID    \n------\naaa111\nbbb222");
+
+        assert_eq!(
+            format_task_table_columns(&tasks, &Column::DEFAULT, None, false),
+            format_task_table(&tasks)
+        );
+    }
+
+    #[test]
+    fn test_humanize_age() {
+        let now = DateTime::parse_from_rfc3339("2024-06-01T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let ago = |seconds: i64| humanize_age(now - chrono::Duration::seconds(seconds), now);
+
+        assert_eq!(ago(0), "just now");
+        assert_eq!(ago(59), "just now");
+        assert_eq!(ago(-30), "just now");
+        assert_eq!(ago(60), "1m ago");
+        assert_eq!(ago(59 * 60 + 59), "59m ago");
+        assert_eq!(ago(3600), "1h ago");
+        assert_eq!(ago(23 * 3600), "23h ago");
+        assert_eq!(ago(3 * 86400 + 3600), "3d ago");
+        assert_eq!(ago(29 * 86400), "29d ago");
+        assert_eq!(ago(65 * 86400), "2mo ago");
+        assert_eq!(ago(400 * 86400), "1y ago");
+    }
+
+    #[test]
+    fn test_created_and_updated_columns_show_age() {
+        let mut task = make_task("aaa111", "Fresh", "task", "todo", None, vec![]);
+        task.created_at = Some(Utc::now() - chrono::Duration::days(3));
+
+        let result = format_task_table_columns(
+            &[task],
+            &[Column::Id, Column::Created, Column::Updated],
+            None,
+            false,
+        );
+        let lines: Vec<&str> = result.lines().collect();
+        assert_eq!(lines[0], "ID      Created  Updated");
+        assert_eq!(lines[2], "aaa111  3d ago   -      ");
+    }
+
+    #[test]
+    fn test_with_blocked_column_goes_after_status() {
+        assert_eq!(
+            with_blocked_column(&[Column::Id, Column::Status, Column::Title]),
+            vec![Column::Id, Column::Status, Column::Blocked, Column::Title]
+        );
+        assert_eq!(
+            with_blocked_column(&[Column::Id]),
+            vec![Column::Id, Column::Blocked]
+        );
+        assert_eq!(
+            with_blocked_column(&[Column::Blocked, Column::Id]),
+            vec![Column::Blocked, Column::Id]
+        );
+    }
+
+    #[test]
+    fn test_format_task_table_with_blocked_column() {
+        let tasks = vec![
+            make_task("aaa111", "Stuck", "task", "todo", None, vec![]),
+            make_task("bbb222", "Free", "task", "todo", None, vec![]),
+            make_task("ccc333", "Unlisted", "task", "todo", None, vec![]),
+        ];
+        let blocked = HashMap::from([("aaa111".to_string(), 2), ("bbb222".to_string(), 0)]);
+
+        let result = format_task_table_with_blocked(&tasks, &blocked);
+        let lines: Vec<&str> = result.lines().collect();
+        let column = lines[0].find("Blocked").expect("Blocked header");
+        assert!(lines[0][column..].starts_with("Blocked  Priority"));
+
+        let cell = |line: &str| {
+            line[column..column + Column::Blocked.header().len()]
+                .trim()
+                .to_string()
+        };
+        assert_eq!(cell(lines[1]), "-------");
+        assert_eq!(cell(lines[2]), "2");
+        // Zero and missing counts are both blank
+        assert_eq!(cell(lines[3]), "");
+        assert_eq!(cell(lines[4]), "");
+
+        // Without counts the table has no Blocked column
+        assert!(!format_task_table(&tasks).contains("Blocked"));
+    }
+
+    #[test]
+    fn test_format_task_table_wrapped_aligns_continuation_lines() {
+        let tasks = vec![
+            make_task(
+                "aaa111",
+                "Migrate the billing service to the new event pipeline",
+                "task",
+                "todo",
+                Some("high"),
+                vec!["backend", "payments", "infra"],
+            ),
+            make_task("bbb222", "Short", "task", "done", None, vec![]),
+        ];
+
+        let result = format_task_table_wrapped(&tasks, None);
+        let lines: Vec<&str> = result.lines().collect();
+        assert_eq!(lines.len(), 5, "got:\n{}", result);
+
+        let title = lines[0].find("Title").unwrap();
+        let tags = lines[0].find("Tags").unwrap();
+        assert!(lines[2].starts_with("aaa111  task   todo    high"));
+        assert_eq!(
+            lines[2][title..tags].trim_end(),
+            "Migrate the billing service to"
+        );
+        assert!(lines[2][tags..].starts_with("backend, payments,"));
+        // The continuation line only fills the wrapped columns
+        assert!(lines[3][..title].trim().is_empty());
+        assert_eq!(lines[3][title..tags].trim_end(), "the new event pipeline");
+        assert_eq!(&lines[3][tags..], "infra");
+        assert!(lines[4].starts_with("bbb222"));
+
+        // Nothing was cut short
+        assert!(!result.contains("..."));
+        assert!(format_task_table(&tasks).contains("..."));
+    }
+
+    #[test]
+    fn test_wrap_cell() {
+        assert_eq!(wrap_cell("one two three", 7), vec!["one two", "three"]);
+        assert_eq!(wrap_cell("short", 10), vec!["short"]);
+        assert_eq!(wrap_cell("", 10), vec![""]);
+        // Words longer than a line are split
+        assert_eq!(
+            wrap_cell("abcdefghij xy", 4),
+            vec!["abcd", "efgh", "ij", "xy"]
+        );
+    }
+
+    #[test]
+    fn test_wrap_cell_measures_display_width() {
+        // Each CJK character takes two columns
+        let lines = wrap_cell("認証システム 設計", 6);
+        assert_eq!(lines, vec!["認証シ", "ステム", "設計"]);
+        assert!(lines.iter().all(|line| line.width() <= 6));
+
+        // Combining marks stay with their base character
+        assert_eq!(wrap_cell("cafe\u{301}s", 4), vec!["cafe\u{301}", "s"]);
+    }
+
+    #[test]
+    fn test_format_task_table_short_ids() {
+        let tasks = vec![
+            make_task("abc123xyz", "First", "task", "todo", None, vec![]),
+            make_task("def456xyz", "Second", "task", "todo", None, vec![]),
+        ];
+
+        let result = format_task_table_with(&tasks, IdFormat::Short6);
+        let ids: Vec<&str> = result
+            .lines()
+            .skip(2)
+            .map(|line| line.split_whitespace().next().unwrap())
+            .collect();
+        assert_eq!(ids, vec!["abc123", "def456"]);
+        assert!(result.lines().next().unwrap().starts_with("ID      Level"));
+    }
+
+    #[test]
+    fn test_format_task_table_lengthens_ambiguous_short_ids() {
+        let tasks = vec![
+            make_task("abc123aa", "First", "task", "todo", None, vec![]),
+            make_task("abc123bb", "Second", "task", "todo", None, vec![]),
+            make_task("fff999cc", "Third", "task", "todo", None, vec![]),
+        ];
+
+        let result = format_task_table_with(&tasks, IdFormat::Short6);
+        let ids: Vec<&str> = result
+            .lines()
+            .skip(2)
+            .map(|line| line.split_whitespace().next().unwrap())
+            .collect();
+        assert_eq!(ids, vec!["abc123a", "abc123b", "fff999c"]);
+    }
+
+    #[test]
+    fn test_format_task_table_full_ids() {
+        let tasks = vec![make_task(
+            "abc123xyz",
+            "First",
+            "task",
+            "todo",
+            None,
+            vec![],
+        )];
+        let result = format_task_table_with(&tasks, IdFormat::Full);
+        assert!(result.lines().nth(2).unwrap().starts_with("abc123xyz  "));
+    }
 }
@@ -0,0 +1,181 @@
+//! Task IDs read from stdin for single-task commands
+//!
+//! Passing `-` as the task ID of `show`, `transition-to`, `delete`,
+//! `update` or `review` reads IDs from stdin, one per line, and runs the
+//! command once for each. A failing ID is reported alongside the others
+//! instead of stopping the run.
+
+use std::future::Future;
+use std::io::{self, BufRead};
+
+use vertebrae_db::DbError;
+
+/// Task ID argument that means "read IDs from stdin"
+pub const STDIN_ID: &str = "-";
+
+/// Whether a task ID argument asks for IDs from stdin
+pub fn is_stdin_id(id: &str) -> bool {
+    id == STDIN_ID
+}
+
+/// Where `-` reads its task IDs from
+pub enum IdInput<'a> {
+    /// The process's stdin
+    Stdin,
+    /// Any other reader, e.g. a test fixture
+    Reader(&'a mut dyn BufRead),
+}
+
+impl IdInput<'_> {
+    /// Read every ID, releasing the input before any command runs so
+    /// confirmation prompts can still use stdin.
+    fn read(self) -> Result<Vec<String>, DbError> {
+        match self {
+            IdInput::Stdin => read_ids(io::stdin().lock()),
+            IdInput::Reader(reader) => read_ids(reader),
+        }
+    }
+}
+
+/// Read task IDs one per line, trimming whitespace and skipping blank lines.
+///
+/// # Errors
+///
+/// Returns `DbError::Io` if reading fails, or
+/// `DbError::ValidationError` if no IDs were read.
+pub fn read_ids<R: BufRead>(reader: R) -> Result<Vec<String>, DbError> {
+    let mut ids = Vec::new();
+    for line in reader.lines() {
+        let line = line.map_err(|source| DbError::Io {
+            stream: "stdin",
+            source,
+        })?;
+        let id = line.trim();
+        if !id.is_empty() {
+            ids.push(id.to_string());
+        }
+    }
+
+    if ids.is_empty() {
+        return Err(DbError::ValidationError {
+            message: format!("No task IDs on stdin (task ID '{}')", STDIN_ID),
+        });
+    }
+    Ok(ids)
+}
+
+/// Outcome of running a command once per task ID
+#[derive(Debug)]
+pub struct BatchResult {
+    /// Each ID with the command's output or error, in input order
+    pub outcomes: Vec<(String, Result<String, DbError>)>,
+}
+
+impl BatchResult {
+    /// Number of IDs the command failed for
+    pub fn failed(&self) -> usize {
+        self.outcomes.iter().filter(|(_, r)| r.is_err()).count()
+    }
+
+    /// Error to exit with when any ID failed, after the outcomes are shown
+    pub fn failure(&self) -> Option<DbError> {
+        match self.failed() {
+            0 => None,
+            failed => Some(DbError::ValidationError {
+                message: format!("{} of {} tasks failed", failed, self.outcomes.len()),
+            }),
+        }
+    }
+}
+
+impl std::fmt::Display for BatchResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let entries: Vec<String> = self
+            .outcomes
+            .iter()
+            .map(|(id, outcome)| match outcome {
+                Ok(output) => output.clone(),
+                Err(e) => format!("error: {}: {}", id, e.full_message()),
+            })
+            .collect();
+        // Keep multi-line outputs such as `show` apart
+        let separator = if entries.iter().any(|e| e.contains('\n')) {
+            "\n\n"
+        } else {
+            "\n"
+        };
+        write!(f, "{}", entries.join(separator))
+    }
+}
+
+/// Run `run` for each ID in turn, collecting every outcome.
+pub async fn run_each<F, Fut>(ids: Vec<String>, mut run: F) -> BatchResult
+where
+    F: FnMut(String) -> Fut,
+    Fut: Future<Output = Result<String, DbError>>,
+{
+    let mut outcomes = Vec::with_capacity(ids.len());
+    for id in ids {
+        let outcome = run(id.clone()).await;
+        outcomes.push((id, outcome));
+    }
+    BatchResult { outcomes }
+}
+
+/// Run `run` for the IDs read from `input`.
+///
+/// # Errors
+///
+/// Returns `DbError` if the IDs can't be read; failures of `run` are
+/// recorded in the result instead.
+pub async fn run_input<F, Fut>(input: IdInput<'_>, run: F) -> Result<BatchResult, DbError>
+where
+    F: FnMut(String) -> Fut,
+    Fut: Future<Output = Result<String, DbError>>,
+{
+    Ok(run_each(input.read()?, run).await)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_ids_trims_and_skips_blank_lines() {
+        let ids = read_ids("  abc \n\nDEF\n\t\nghi".as_bytes()).unwrap();
+        assert_eq!(ids, vec!["abc", "DEF", "ghi"]);
+
+        match read_ids(" \n".as_bytes()) {
+            Err(DbError::ValidationError { message }) => {
+                assert!(message.contains("No task IDs"), "got: {}", message)
+            }
+            other => panic!("Expected ValidationError, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_run_each_continues_after_failures() {
+        let ids = vec!["a".to_string(), "missing".to_string(), "b".to_string()];
+        let result = run_each(ids, |id| async move {
+            if id == "missing" {
+                Err(DbError::NotFound { task_id: id })
+            } else {
+                Ok(format!("Done: {}", id))
+            }
+        })
+        .await;
+
+        assert_eq!(result.failed(), 1);
+        assert_eq!(
+            result.to_string(),
+            "Done: a\nerror: missing: Task 'missing' not found; run `vtb list` to see task IDs\nDone: b"
+        );
+        assert!(
+            result
+                .failure()
+                .unwrap()
+                .to_string()
+                .contains("1 of 3 tasks failed")
+        );
+    }
+}
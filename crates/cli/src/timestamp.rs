@@ -0,0 +1,211 @@
+//! Timestamps given on the command line
+//!
+//! `--started-at` and `--completed-at` let `add` and `transition-to` record
+//! when work really happened instead of the time the command ran. `--due`
+//! on `add` and `update` takes the same formats plus a few relative ones.
+
+use chrono::{DateTime, Days, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use vertebrae_db::DbError;
+
+/// Parse a timestamp given on the command line.
+///
+/// Accepts RFC 3339 (`2024-03-01T09:00:00Z`), a local date and time
+/// (`2024-03-01 09:00` or `2024-03-01 09:00:00`) or a local date
+/// (`2024-03-01`, meaning midnight).
+pub fn parse_timestamp(s: &str) -> Result<DateTime<Utc>, String> {
+    let s = s.trim();
+    if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
+        return Ok(dt.with_timezone(&Utc));
+    }
+
+    let naive = ["%Y-%m-%d %H:%M:%S", "%Y-%m-%d %H:%M", "%Y-%m-%dT%H:%M:%S"]
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(s, format).ok())
+        .or_else(|| {
+            NaiveDate::parse_from_str(s, "%Y-%m-%d")
+                .ok()
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+        })
+        .ok_or_else(|| {
+            format!(
+                "invalid timestamp '{}': expected RFC 3339, 'YYYY-MM-DD HH:MM' or 'YYYY-MM-DD'",
+                s
+            )
+        })?;
+
+    Local
+        .from_local_datetime(&naive)
+        .earliest()
+        .map(|dt| dt.with_timezone(&Utc))
+        .ok_or_else(|| format!("invalid timestamp '{}': does not exist in local time", s))
+}
+
+/// Parse a due date given on the command line.
+///
+/// Accepts everything [`parse_timestamp`] does, plus `today`, `tomorrow`
+/// and `+Nd`/`+Nw` (days or weeks from today). A due date without a time
+/// means the end of that local day, so a task due today is not overdue
+/// until tomorrow.
+pub fn parse_due(s: &str) -> Result<DateTime<Utc>, String> {
+    parse_due_from(s, Local::now().date_naive())
+}
+
+/// [`parse_due`] with relative dates counted from `today`.
+fn parse_due_from(s: &str, today: NaiveDate) -> Result<DateTime<Utc>, String> {
+    let s = s.trim();
+    let lower = s.to_lowercase();
+    let day = match lower.as_str() {
+        "today" => Some(today),
+        "tomorrow" => today.checked_add_days(Days::new(1)),
+        _ => match lower.strip_prefix('+') {
+            Some(offset) => {
+                let invalid = || {
+                    format!(
+                        "invalid due date '{}': expected a number of days or weeks like +3d or +2w",
+                        s
+                    )
+                };
+                let (count, unit) = offset.split_at(offset.len().saturating_sub(1));
+                let count: u64 = count.parse().map_err(|_| invalid())?;
+                let days = match unit {
+                    "d" => count,
+                    "w" => count.checked_mul(7).ok_or_else(invalid)?,
+                    _ => return Err(invalid()),
+                };
+                Some(
+                    today
+                        .checked_add_days(Days::new(days))
+                        .ok_or_else(invalid)?,
+                )
+            }
+            None => NaiveDate::parse_from_str(s, "%Y-%m-%d").ok(),
+        },
+    };
+
+    let Some(day) = day else {
+        return parse_timestamp(s).map_err(|_| {
+            format!(
+                "invalid due date '{}': expected RFC 3339, 'YYYY-MM-DD', today, tomorrow or +Nd/+Nw",
+                s
+            )
+        });
+    };
+    let end_of_day = day.and_time(NaiveTime::from_hms_opt(23, 59, 59).unwrap_or_default());
+    Local
+        .from_local_datetime(&end_of_day)
+        .latest()
+        .map(|dt| dt.with_timezone(&Utc))
+        .ok_or_else(|| format!("invalid due date '{}': does not exist in local time", s))
+}
+
+/// Check that a task is not completed before it was started.
+///
+/// # Errors
+///
+/// Returns `DbError::ValidationError` if `completed_at` is earlier than
+/// `started_at`.
+pub fn check_order(
+    started_at: Option<DateTime<Utc>>,
+    completed_at: Option<DateTime<Utc>>,
+) -> Result<(), DbError> {
+    match (started_at, completed_at) {
+        (Some(started), Some(completed)) if completed < started => Err(DbError::ValidationError {
+            message: format!(
+                "completed_at ({}) is before started_at ({})",
+                completed.to_rfc3339(),
+                started.to_rfc3339()
+            ),
+        }),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rfc3339() {
+        let dt = parse_timestamp("2024-03-01T09:00:00+02:00").unwrap();
+        assert_eq!(dt.to_rfc3339(), "2024-03-01T07:00:00+00:00");
+    }
+
+    #[test]
+    fn test_parse_local_formats() {
+        let expected = Local
+            .with_ymd_and_hms(2024, 3, 1, 9, 30, 0)
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(parse_timestamp("2024-03-01 09:30").unwrap(), expected);
+        assert_eq!(parse_timestamp("2024-03-01 09:30:00").unwrap(), expected);
+
+        let midnight = Local
+            .with_ymd_and_hms(2024, 3, 1, 0, 0, 0)
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(parse_timestamp(" 2024-03-01 ").unwrap(), midnight);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let err = parse_timestamp("last tuesday").unwrap_err();
+        assert!(
+            err.contains("invalid timestamp 'last tuesday'"),
+            "got: {}",
+            err
+        );
+        assert!(parse_timestamp("2024-13-01").is_err());
+    }
+
+    #[test]
+    fn test_parse_due() {
+        let today = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        let end_of = |y, m, d| {
+            Local
+                .with_ymd_and_hms(y, m, d, 23, 59, 59)
+                .unwrap()
+                .with_timezone(&Utc)
+        };
+
+        assert_eq!(parse_due_from("today", today).unwrap(), end_of(2024, 3, 1));
+        assert_eq!(
+            parse_due_from("Tomorrow", today).unwrap(),
+            end_of(2024, 3, 2)
+        );
+        assert_eq!(parse_due_from("+3d", today).unwrap(), end_of(2024, 3, 4));
+        assert_eq!(parse_due_from("+2w", today).unwrap(), end_of(2024, 3, 15));
+        assert_eq!(
+            parse_due_from("2024-04-10", today).unwrap(),
+            end_of(2024, 4, 10)
+        );
+        assert_eq!(
+            parse_due_from("2024-04-10T12:00:00Z", today)
+                .unwrap()
+                .to_rfc3339(),
+            "2024-04-10T12:00:00+00:00"
+        );
+
+        for bad in ["+3", "+d", "+3m", "next week"] {
+            let err = parse_due_from(bad, today).unwrap_err();
+            assert!(err.contains("invalid due date"), "got: {}", err);
+        }
+    }
+
+    #[test]
+    fn test_check_order() {
+        let early = parse_timestamp("2024-03-01T00:00:00Z").unwrap();
+        let late = parse_timestamp("2024-03-02T00:00:00Z").unwrap();
+
+        assert!(check_order(Some(early), Some(late)).is_ok());
+        assert!(check_order(Some(early), Some(early)).is_ok());
+        assert!(check_order(None, Some(early)).is_ok());
+        assert!(check_order(Some(late), None).is_ok());
+
+        match check_order(Some(late), Some(early)) {
+            Err(DbError::ValidationError { message }) => {
+                assert!(message.contains("is before started_at"), "got: {}", message)
+            }
+            other => panic!("Expected ValidationError, got {:?}", other),
+        }
+    }
+}
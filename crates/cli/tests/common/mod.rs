@@ -6,9 +6,10 @@
 use std::path::PathBuf;
 use vertebrae_cli::commands::{
     AddCommand, DeleteCommand, DependCommand, ExportCommand, ListCommand, RefCommand,
-    SectionCommand, TransitionToCommand, transition_to::TargetStatus,
+    SectionCommand, ShowCommand, TransitionToCommand, transition_to::TargetStatus,
 };
-use vertebrae_db::{Database, DbError, Level, SectionType};
+use vertebrae_cli::output::OutputFormat;
+use vertebrae_db::{Database, DbError, Level, SectionType, TagMode};
 
 /// Test context containing an isolated database and temp directory
 pub struct TestContext {
@@ -86,6 +87,12 @@ pub fn add_cmd(title: &str) -> AddCommand {
         parent: None,
         depends_on: vec![],
         needs_review: false,
+        start: false,
+        done: false,
+        started_at: None,
+        completed_at: None,
+        due: None,
+        estimate: None,
     }
 }
 
@@ -101,6 +108,12 @@ pub fn add_cmd_with_level(title: &str, level: Level) -> AddCommand {
         parent: None,
         depends_on: vec![],
         needs_review: false,
+        start: false,
+        done: false,
+        started_at: None,
+        completed_at: None,
+        due: None,
+        estimate: None,
     }
 }
 
@@ -115,6 +128,12 @@ pub fn add_cmd_with_parent(title: &str, parent: &str) -> AddCommand {
         parent: Some(parent.to_string()),
         depends_on: vec![],
         needs_review: false,
+        start: false,
+        done: false,
+        started_at: None,
+        completed_at: None,
+        due: None,
+        estimate: None,
     }
 }
 
@@ -134,6 +153,12 @@ pub fn add_cmd_full(
         parent: parent.map(String::from),
         depends_on: vec![],
         needs_review: false,
+        start: false,
+        done: false,
+        started_at: None,
+        completed_at: None,
+        due: None,
+        estimate: None,
     }
 }
 
@@ -152,6 +177,8 @@ pub fn triage_cmd(id: &str) -> TransitionToCommand {
         reason: None,
         force: false,
         skip_validation: true, // Skip validation by default for existing tests
+        started_at: None,
+        completed_at: None,
     }
 }
 
@@ -164,6 +191,8 @@ pub fn triage_cmd_with_validation(id: &str) -> TransitionToCommand {
         reason: None,
         force: false,
         skip_validation: false,
+        started_at: None,
+        completed_at: None,
     }
 }
 
@@ -176,6 +205,8 @@ pub fn triage_cmd_force(id: &str) -> TransitionToCommand {
         reason: None,
         force: true,
         skip_validation: false,
+        started_at: None,
+        completed_at: None,
     }
 }
 
@@ -187,6 +218,8 @@ pub fn start_cmd(id: &str) -> TransitionToCommand {
         reason: None,
         force: false,
         skip_validation: false,
+        started_at: None,
+        completed_at: None,
     }
 }
 
@@ -198,6 +231,8 @@ pub fn submit_cmd(id: &str) -> TransitionToCommand {
         reason: None,
         force: false,
         skip_validation: false,
+        started_at: None,
+        completed_at: None,
     }
 }
 
@@ -209,6 +244,8 @@ pub fn done_cmd(id: &str) -> TransitionToCommand {
         reason: None,
         force: false,
         skip_validation: false,
+        started_at: None,
+        completed_at: None,
     }
 }
 
@@ -220,6 +257,8 @@ pub fn reject_cmd(id: &str) -> TransitionToCommand {
         reason: None,
         force: false,
         skip_validation: false,
+        started_at: None,
+        completed_at: None,
     }
 }
 
@@ -232,6 +271,8 @@ pub fn reject_cmd_with_reason(id: &str, reason: &str) -> TransitionToCommand {
         reason: Some(reason.to_string()),
         force: false,
         skip_validation: false,
+        started_at: None,
+        completed_at: None,
     }
 }
 
@@ -240,6 +281,7 @@ pub fn depend_cmd(id: &str, blocker_id: &str) -> DependCommand {
     DependCommand {
         id: id.to_string(),
         blocker_id: blocker_id.to_string(),
+        dry_run: false,
     }
 }
 
@@ -259,6 +301,7 @@ pub fn ref_cmd(id: &str, file_spec: &str) -> RefCommand {
         file_spec: file_spec.to_string(),
         name: None,
         description: None,
+        strict: false,
     }
 }
 
@@ -274,15 +317,29 @@ pub fn ref_cmd_full(
         file_spec: file_spec.to_string(),
         name: name.map(String::from),
         description: description.map(String::from),
+        strict: false,
     }
 }
 
-/// Create a delete command.
+/// Create a delete command that removes tasks permanently.
 pub fn delete_cmd(id: &str, cascade: bool) -> DeleteCommand {
     DeleteCommand {
         id: id.to_string(),
         cascade,
         force: true,
+        hard: true,
+    }
+}
+
+/// Create a show command.
+pub fn show_cmd(id: &str) -> ShowCommand {
+    ShowCommand {
+        id: id.to_string(),
+        format: OutputFormat::Table,
+        relationships_only: false,
+        sections_only: false,
+        tree: false,
+        depth: None,
     }
 }
 
@@ -297,6 +354,31 @@ pub fn list_cmd() -> ListCommand {
         children: None,
         all: false,
         search: None,
+        format: OutputFormat::Table,
+        explain: false,
+        section_types: vec![],
+        no_sections: false,
+        has_refs: false,
+        no_refs: false,
+        summary: false,
+        random: None,
+        seed: None,
+        show_blocked: false,
+        sort: vec![],
+        reverse: false,
+        wrap: false,
+        limit: None,
+        offset: None,
+        overdue: false,
+        include_deleted: false,
+        tag_mode: TagMode::Any,
+        columns: vec![],
+        title_regex: None,
+        case_insensitive: false,
+        watch: None,
+        created_after: None,
+        created_before: None,
+        updated_after: None,
     }
 }
 
@@ -311,12 +393,47 @@ pub fn list_cmd_with_search(search: &str) -> ListCommand {
         children: None,
         all: false,
         search: Some(search.to_string()),
+        format: OutputFormat::Table,
+        explain: false,
+        section_types: vec![],
+        no_sections: false,
+        has_refs: false,
+        no_refs: false,
+        summary: false,
+        random: None,
+        seed: None,
+        show_blocked: false,
+        sort: vec![],
+        reverse: false,
+        wrap: false,
+        limit: None,
+        offset: None,
+        overdue: false,
+        include_deleted: false,
+        tag_mode: TagMode::Any,
+        columns: vec![],
+        title_regex: None,
+        case_insensitive: false,
+        watch: None,
+        created_after: None,
+        created_before: None,
+        updated_after: None,
     }
 }
 
 /// Create an export command.
 pub fn export_cmd(output: Option<PathBuf>) -> ExportCommand {
-    ExportCommand { output }
+    ExportCommand {
+        output,
+        levels: vec![],
+        statuses: vec![],
+        tags: vec![],
+        search: None,
+        descendants: None,
+        compact: false,
+        format: OutputFormat::Json,
+        edges_only: false,
+    }
 }
 
 // =============================================================================
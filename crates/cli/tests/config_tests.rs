@@ -0,0 +1,58 @@
+//! Integration tests for project config settings
+//!
+//! Settings are installed once per process, so these tests live in their
+//! own binary instead of `integration_tests.rs`, where they would change
+//! the defaults every other test relies on.
+
+use vertebrae_cli::commands::ShowCommand;
+use vertebrae_cli::output::OutputFormat;
+use vertebrae_db::{Config, Database, Progress, install_graph_settings};
+
+#[tokio::test]
+async fn test_completion_statuses_from_config_count_toward_progress() {
+    let config =
+        Config::parse("[graph]\ncompletion_statuses = [\"done\", \"pending_review\"]\n").unwrap();
+    assert!(install_graph_settings(config.graph));
+
+    let temp_dir = std::env::temp_dir().join(format!(
+        "vtb-config-test-{}-{:?}",
+        std::process::id(),
+        std::thread::current().id()
+    ));
+    let db = Database::connect(&temp_dir).await.unwrap();
+    db.init().await.unwrap();
+    db.client()
+        .query(
+            r#"CREATE task:epic SET title = "Epic", level = "epic", status = "in_progress";
+               CREATE task:shipped SET title = "Shipped", level = "task", status = "done";
+               CREATE task:review SET title = "Review", level = "task",
+                   status = "pending_review";
+               CREATE task:open SET title = "Open", level = "task", status = "todo";
+               RELATE task:shipped -> child_of -> task:epic;
+               RELATE task:review -> child_of -> task:epic;
+               RELATE task:open -> child_of -> task:epic"#,
+        )
+        .await
+        .unwrap();
+
+    // Shipped and review count as complete; open does not
+    assert_eq!(
+        db.graph().get_progress("epic").await.unwrap(),
+        Progress::new(2, 3)
+    );
+
+    let detail = ShowCommand {
+        id: "epic".to_string(),
+        format: OutputFormat::Table,
+        relationships_only: false,
+        sections_only: false,
+        tree: false,
+        depth: None,
+    }
+    .execute(&db)
+    .await
+    .unwrap();
+    assert_eq!(detail.progress, Some(Progress::new(2, 3)));
+
+    let _ = std::fs::remove_dir_all(&temp_dir);
+}
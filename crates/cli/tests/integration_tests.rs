@@ -316,6 +316,65 @@ mod lifecycle {
         assert!(task.started_at.is_some());
         assert!(task.completed_at.is_some());
     }
+
+    #[tokio::test]
+    async fn test_done_with_ids_from_stdin_reports_each_id() {
+        use clap::Parser;
+        use vertebrae_cli::commands::{Command, CommandResult};
+        use vertebrae_cli::stdin_ids::IdInput;
+
+        #[derive(Parser)]
+        struct Cli {
+            #[command(subcommand)]
+            command: Command,
+        }
+
+        let ctx = TestContext::new().await;
+        create_task(&ctx.db, "first", "First", "task", "pending_review").await;
+        create_task(&ctx.db, "second", "Second", "task", "pending_review").await;
+
+        let cli = Cli::try_parse_from(["vtb", "transition-to", "-", "done"]).unwrap();
+        let mut stdin = "first\nmissing\n\nSECOND\n".as_bytes();
+        let result = cli
+            .command
+            .execute_with_input(&ctx.db, IdInput::Reader(&mut stdin))
+            .await
+            .unwrap();
+
+        let CommandResult::Batch(batch) = &result else {
+            panic!("Expected a batch result");
+        };
+        assert_eq!(batch.outcomes.len(), 3);
+        assert_eq!(batch.failed(), 1);
+        assert!(matches!(batch.outcomes[1].1, Err(DbError::NotFound { .. })));
+        assert!(
+            batch
+                .failure()
+                .unwrap()
+                .to_string()
+                .contains("1 of 3 tasks failed")
+        );
+
+        // The failure in the middle didn't stop the last ID
+        assert_eq!(
+            get_task_status(&ctx.db, "first").await,
+            Some("done".to_string())
+        );
+        assert_eq!(
+            get_task_status(&ctx.db, "second").await,
+            Some("done".to_string())
+        );
+
+        let output = result.to_string();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 3, "got: {}", output);
+        assert!(lines[0].contains("first"), "got: {}", output);
+        assert_eq!(
+            lines[1],
+            "error: missing: Task 'missing' not found; run `vtb list` to see task IDs"
+        );
+        assert!(lines[2].contains("second"), "got: {}", output);
+    }
 }
 
 // =============================================================================
@@ -509,6 +568,54 @@ mod relationships {
         assert!(result.is_err());
     }
 
+    #[tokio::test]
+    async fn test_self_parent_fails() {
+        let ctx = TestContext::new().await;
+        create_task(&ctx.db, "task1", "Task 1", "task", "todo").await;
+
+        let cmd = vertebrae_cli::commands::UpdateCommand {
+            id: "task1".to_string(),
+            title: None,
+            priority: None,
+            add_tags: vec![],
+            remove_tags: vec![],
+            parent: Some("TASK1".to_string()),
+            due: None,
+            no_due: false,
+            estimate: None,
+        };
+        assert!(cmd.execute(&ctx.db).await.is_err());
+        assert!(!child_of_exists(&ctx.db, "task1", "task1").await);
+    }
+
+    #[tokio::test]
+    async fn test_doctor_finds_and_clears_manual_self_edge() {
+        let ctx = TestContext::new().await;
+        create_task(&ctx.db, "task1", "Task 1", "task", "todo").await;
+
+        let doctor = vertebrae_cli::commands::DoctorCommand {};
+        assert!(doctor.execute(&ctx.db).await.unwrap().is_healthy());
+
+        // Raw edit bypassing the command guards
+        create_depends_on(&ctx.db, "task1", "task1").await;
+
+        let report = doctor.execute(&ctx.db).await.unwrap();
+        assert_eq!(report.self_edges.len(), 1);
+        assert_eq!(report.self_edges[0].task_id, "task1");
+        assert!(report.to_string().contains("vtb undepend task1 task1"));
+
+        // The suggested fix works
+        vertebrae_cli::commands::UndependCommand {
+            id: "task1".to_string(),
+            blocker_id: "task1".to_string(),
+            dry_run: false,
+        }
+        .execute(&ctx.db)
+        .await
+        .unwrap();
+        assert!(doctor.execute(&ctx.db).await.unwrap().is_healthy());
+    }
+
     #[tokio::test]
     async fn test_direct_cycle_detected() {
         let ctx = TestContext::new().await;
@@ -565,6 +672,99 @@ mod relationships {
         assert!(dependency_exists(&ctx.db, "d", "b").await);
         assert!(dependency_exists(&ctx.db, "d", "c").await);
     }
+
+    #[tokio::test]
+    async fn test_show_blocker_path_to_first_unblocked_blocker() {
+        let ctx = TestContext::new().await;
+
+        // a -> b (todo) -> c (todo) -> d (done)
+        create_task(&ctx.db, "a", "Task A", "task", "todo").await;
+        create_task(&ctx.db, "b", "Task B", "task", "todo").await;
+        create_task(&ctx.db, "c", "Task C", "task", "todo").await;
+        create_task(&ctx.db, "d", "Task D", "task", "done").await;
+        create_depends_on(&ctx.db, "a", "b").await;
+        create_depends_on(&ctx.db, "b", "c").await;
+        create_depends_on(&ctx.db, "c", "d").await;
+
+        let detail = show_cmd("a").execute(&ctx.db).await.unwrap();
+        assert_eq!(
+            detail.blocker_path,
+            Some(vec!["a".to_string(), "b".to_string(), "c".to_string()])
+        );
+
+        let output = format!("{}", detail);
+        assert!(output.contains("Blocker path: a -> b -> c (incomplete)"));
+    }
+
+    #[tokio::test]
+    async fn test_show_no_blocker_path_when_not_blocked() {
+        let ctx = TestContext::new().await;
+
+        create_task(&ctx.db, "a", "Task A", "task", "todo").await;
+        create_task(&ctx.db, "b", "Task B", "task", "done").await;
+        create_depends_on(&ctx.db, "a", "b").await;
+
+        let detail = show_cmd("a").execute(&ctx.db).await.unwrap();
+        assert!(detail.blocker_path.is_none());
+        assert!(!format!("{}", detail).contains("Blocker path"));
+    }
+
+    #[tokio::test]
+    async fn test_show_unblocks_only_dependents_with_no_other_blockers() {
+        let ctx = TestContext::new().await;
+
+        // "solo" waits only on "blocker"; "shared" also waits on "other"
+        create_task(&ctx.db, "blocker", "Blocker", "task", "in_progress").await;
+        create_task(&ctx.db, "other", "Other", "task", "todo").await;
+        create_task(&ctx.db, "solo", "Solo dependent", "task", "todo").await;
+        create_task(&ctx.db, "shared", "Shared dependent", "task", "todo").await;
+        create_depends_on(&ctx.db, "solo", "blocker").await;
+        create_depends_on(&ctx.db, "shared", "blocker").await;
+        create_depends_on(&ctx.db, "shared", "other").await;
+
+        let detail = show_cmd("blocker").execute(&ctx.db).await.unwrap();
+        assert_eq!(detail.blocks.len(), 2);
+        let unblocks: Vec<&str> = detail.unblocks.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(unblocks, vec!["solo"]);
+
+        let output = format!("{}", detail);
+        let section: Vec<&str> = output
+            .lines()
+            .skip_while(|line| *line != "Completing this unblocks:")
+            .skip(1)
+            .take_while(|line| line.starts_with("  - "))
+            .collect();
+        assert_eq!(section, vec!["  - solo - Solo dependent"]);
+    }
+
+    #[tokio::test]
+    async fn test_show_unblocks_counts_done_blockers_as_cleared() {
+        let ctx = TestContext::new().await;
+
+        create_task(&ctx.db, "blocker", "Blocker", "task", "todo").await;
+        create_task(&ctx.db, "finished", "Finished", "task", "done").await;
+        create_task(&ctx.db, "dependent", "Dependent", "task", "todo").await;
+        create_depends_on(&ctx.db, "dependent", "blocker").await;
+        create_depends_on(&ctx.db, "dependent", "finished").await;
+
+        let detail = show_cmd("blocker").execute(&ctx.db).await.unwrap();
+        let unblocks: Vec<&str> = detail.unblocks.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(unblocks, vec!["dependent"]);
+    }
+
+    #[tokio::test]
+    async fn test_show_done_task_unblocks_nothing() {
+        let ctx = TestContext::new().await;
+
+        create_task(&ctx.db, "blocker", "Blocker", "task", "done").await;
+        create_task(&ctx.db, "dependent", "Dependent", "task", "todo").await;
+        create_depends_on(&ctx.db, "dependent", "blocker").await;
+
+        let detail = show_cmd("blocker").execute(&ctx.db).await.unwrap();
+        assert_eq!(detail.blocks.len(), 1);
+        assert!(detail.unblocks.is_empty());
+        assert!(!format!("{}", detail).contains("Completing this unblocks"));
+    }
 }
 
 // =============================================================================
@@ -954,6 +1154,12 @@ mod search {
 
 mod error_cases {
     use super::*;
+    use vertebrae_cli::commands::{
+        BlockersCommand, CriterionRefCommand, RefsCommand, ReviewCommand, SectionsCommand,
+        StepDoneCommand, UndependCommand, UnrefCommand, UnsectionCommand, UpdateCommand,
+    };
+    use vertebrae_cli::confirm::Prompter;
+    use vertebrae_cli::output::OutputFormat;
 
     #[tokio::test]
     async fn test_triage_nonexistent_task() {
@@ -963,6 +1169,37 @@ mod error_cases {
         assert!(matches!(result, Err(DbError::NotFound { task_id }) if task_id == "nonexistent"));
     }
 
+    #[tokio::test]
+    async fn test_commands_resolve_shortened_ids() {
+        let ctx = TestContext::new().await;
+
+        create_task(&ctx.db, "abc123", "First", "task", "todo").await;
+        create_task(&ctx.db, "abd456", "Second", "task", "todo").await;
+
+        let detail = show_cmd("ABC").execute(&ctx.db).await.unwrap();
+        assert_eq!(detail.id, "abc123");
+
+        depend_cmd("abd", "abc").execute(&ctx.db).await.unwrap();
+        assert!(
+            ctx.db
+                .graph()
+                .get_blockers("abd456", Some(1))
+                .await
+                .unwrap()
+                .iter()
+                .any(|blocker| blocker.id == "abc123")
+        );
+
+        match start_cmd("ab").execute(&ctx.db).await {
+            Err(DbError::AmbiguousId { prefix, candidates }) => {
+                assert_eq!(prefix, "ab");
+                assert_eq!(candidates, vec!["abc123", "abd456"]);
+            }
+            other => panic!("Expected AmbiguousId, got {:?}", other),
+        }
+        assert_not_found(show_cmd("nonexistent").execute(&ctx.db).await);
+    }
+
     #[tokio::test]
     async fn test_start_nonexistent_task() {
         let ctx = TestContext::new().await;
@@ -979,6 +1216,206 @@ mod error_cases {
         assert!(matches!(result, Err(DbError::NotFound { .. })));
     }
 
+    /// Assert that a command failed with `NotFound` for the "nonexistent" ID.
+    fn assert_not_found<T: std::fmt::Debug>(result: Result<T, DbError>) {
+        match result {
+            Err(DbError::NotFound { task_id }) => assert_eq!(task_id, "nonexistent"),
+            other => panic!("Expected NotFound for 'nonexistent', got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_transitions_nonexistent_task() {
+        let ctx = TestContext::new().await;
+
+        assert_not_found(submit_cmd("nonexistent").execute(&ctx.db).await);
+        assert_not_found(reject_cmd("nonexistent").execute(&ctx.db).await);
+    }
+
+    #[tokio::test]
+    async fn test_show_and_delete_nonexistent_task() {
+        let ctx = TestContext::new().await;
+
+        assert_not_found(show_cmd("nonexistent").execute(&ctx.db).await);
+        assert_not_found(delete_cmd("nonexistent", false).execute(&ctx.db).await);
+    }
+
+    #[tokio::test]
+    async fn test_update_nonexistent_task() {
+        let ctx = TestContext::new().await;
+
+        let cmd = UpdateCommand {
+            id: "nonexistent".to_string(),
+            title: Some("New title".to_string()),
+            priority: None,
+            add_tags: vec![],
+            remove_tags: vec![],
+            parent: None,
+            due: None,
+            no_due: false,
+            estimate: None,
+        };
+        assert_not_found(cmd.execute(&ctx.db).await);
+    }
+
+    #[tokio::test]
+    async fn test_dependency_commands_nonexistent_task() {
+        let ctx = TestContext::new().await;
+        create_task(&ctx.db, "task1", "Task", "task", "todo").await;
+
+        assert_not_found(depend_cmd("nonexistent", "task1").execute(&ctx.db).await);
+        assert_not_found(depend_cmd("task1", "nonexistent").execute(&ctx.db).await);
+
+        let undepend = UndependCommand {
+            id: "nonexistent".to_string(),
+            blocker_id: "task1".to_string(),
+            dry_run: false,
+        };
+        assert_not_found(undepend.execute(&ctx.db).await);
+
+        let blockers = BlockersCommand {
+            id: "nonexistent".to_string(),
+            depth: None,
+            paths: false,
+            max_paths: 100,
+            explain: false,
+            format: OutputFormat::Table,
+            tree_chars: None,
+        };
+        assert_not_found(blockers.execute(&ctx.db).await);
+    }
+
+    #[tokio::test]
+    async fn test_blockers_on_corrupt_cycle_errors_instead_of_hanging() {
+        let ctx = TestContext::new().await;
+
+        // Raw edges bypass `vtb depend`'s cycle check
+        for id in ["task1", "loopa", "loopb"] {
+            create_task(&ctx.db, id, id, "task", "todo").await;
+        }
+        create_depends_on(&ctx.db, "task1", "loopa").await;
+        create_depends_on(&ctx.db, "loopa", "loopb").await;
+        create_depends_on(&ctx.db, "loopb", "loopa").await;
+
+        let blockers = BlockersCommand {
+            id: "task1".to_string(),
+            depth: None,
+            paths: false,
+            max_paths: 100,
+            explain: false,
+            format: OutputFormat::Table,
+            tree_chars: None,
+        };
+        let result = tokio::time::timeout(
+            std::time::Duration::from_secs(10),
+            blockers.execute(&ctx.db),
+        )
+        .await
+        .expect("blockers did not terminate");
+
+        match result {
+            Err(err @ DbError::GraphCycle { .. }) => {
+                assert_eq!(
+                    err.to_string(),
+                    "Found a depends_on cycle: loopa -> loopb -> loopa"
+                );
+            }
+            other => panic!("Expected GraphCycle, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_section_commands_nonexistent_task() {
+        let ctx = TestContext::new().await;
+
+        assert_not_found(
+            section_cmd("nonexistent", SectionType::Step, "Step")
+                .execute(&ctx.db)
+                .await,
+        );
+
+        let sections = SectionsCommand {
+            id: "nonexistent".to_string(),
+            section_type: None,
+        };
+        assert_not_found(sections.execute(&ctx.db).await);
+
+        let unsection = UnsectionCommand {
+            id: "nonexistent".to_string(),
+            section_type: None,
+            index: None,
+            all: true,
+        };
+        assert_not_found(unsection.execute(&ctx.db).await);
+
+        let step_done = StepDoneCommand {
+            id: "nonexistent".to_string(),
+            index: 1,
+        };
+        assert_not_found(step_done.execute(&ctx.db).await);
+    }
+
+    #[tokio::test]
+    async fn test_ref_commands_nonexistent_task() {
+        let ctx = TestContext::new().await;
+
+        assert_not_found(ref_cmd("nonexistent", "src/main.rs").execute(&ctx.db).await);
+
+        let refs = RefsCommand {
+            id: "nonexistent".to_string(),
+        };
+        assert_not_found(refs.execute(&ctx.db).await);
+
+        let unref = UnrefCommand {
+            id: "nonexistent".to_string(),
+            file: None,
+            all: true,
+        };
+        assert_not_found(unref.execute(&ctx.db).await);
+
+        let criterion_ref = CriterionRefCommand {
+            id: "nonexistent".to_string(),
+            index: 1,
+            file_spec: "tests/test.rs".to_string(),
+            name: None,
+            description: None,
+        };
+        assert_not_found(criterion_ref.execute(&ctx.db).await);
+    }
+
+    #[tokio::test]
+    async fn test_non_interactive_delete_requires_yes() {
+        let ctx = TestContext::new().await;
+        create_task(&ctx.db, "parent", "Parent", "ticket", "todo").await;
+        create_task(&ctx.db, "child", "Child", "task", "todo").await;
+        create_child_of(&ctx.db, "child", "parent").await;
+
+        let mut cmd = delete_cmd("parent", true);
+        cmd.force = false;
+        let result = cmd.execute_with(&ctx.db, Prompter::non_interactive()).await;
+        assert!(matches!(result, Err(DbError::ValidationError { .. })));
+        assert!(task_exists(&ctx.db, "parent").await);
+        assert!(task_exists(&ctx.db, "child").await);
+
+        cmd.force = true;
+        cmd.execute_with(&ctx.db, Prompter::non_interactive())
+            .await
+            .unwrap();
+        assert!(!task_exists(&ctx.db, "parent").await);
+        assert!(!task_exists(&ctx.db, "child").await);
+    }
+
+    #[tokio::test]
+    async fn test_review_nonexistent_task() {
+        let ctx = TestContext::new().await;
+
+        let cmd = ReviewCommand {
+            id: "nonexistent".to_string(),
+            set: Some(true),
+        };
+        assert_not_found(cmd.execute(&ctx.db).await);
+    }
+
     #[tokio::test]
     async fn test_invalid_status_transition_todo_to_done() {
         let ctx = TestContext::new().await;
@@ -1024,6 +1461,11 @@ mod error_cases {
 
 mod data_operations {
     use super::*;
+    use vertebrae_cli::commands::{
+        BlockersCommand, DeleteCommand, ImportCommand, PathCommand, RestoreCommand,
+    };
+    use vertebrae_cli::confirm::Prompter;
+    use vertebrae_cli::output::OutputFormat;
 
     #[tokio::test]
     async fn test_delete_single_task() {
@@ -1077,6 +1519,76 @@ mod data_operations {
         assert!(task_exists(&ctx.db, "child1").await);
     }
 
+    #[tokio::test]
+    async fn test_soft_delete_hides_task_until_restored() {
+        let ctx = TestContext::new().await;
+        create_task(&ctx.db, "main", "Main", "task", "todo").await;
+        create_task(&ctx.db, "blocker", "Blocker", "task", "todo").await;
+        create_depends_on(&ctx.db, "main", "blocker").await;
+
+        let soft = DeleteCommand {
+            hard: false,
+            ..delete_cmd("blocker", false)
+        };
+        soft.execute(&ctx.db).await.unwrap();
+
+        let listed = list_cmd().execute(&ctx.db).await.unwrap();
+        let ids: Vec<&str> = listed.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(ids, vec!["main"]);
+        let blockers = ctx.db.graph().get_blockers("main", None).await.unwrap();
+        assert!(blockers.is_empty());
+
+        let restore = RestoreCommand {
+            id: "blocker".to_string(),
+        };
+        restore.execute(&ctx.db).await.unwrap();
+        assert_eq!(list_cmd().execute(&ctx.db).await.unwrap().len(), 2);
+        let blockers = ctx.db.graph().get_blockers("main", None).await.unwrap();
+        assert_eq!(blockers.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_trashed_blocker_leaves_blockers_path_and_show() {
+        let ctx = TestContext::new().await;
+        create_task(&ctx.db, "taskb", "Dependent", "task", "todo").await;
+        create_task(&ctx.db, "taska", "Blocker", "task", "todo").await;
+        create_task(&ctx.db, "taskc", "Upstream", "task", "todo").await;
+        depend_cmd("taskb", "taska").execute(&ctx.db).await.unwrap();
+        depend_cmd("taska", "taskc").execute(&ctx.db).await.unwrap();
+
+        // `vtb delete taska --force` moves it to the trash
+        let soft = DeleteCommand {
+            hard: false,
+            ..delete_cmd("taska", false)
+        };
+        soft.execute(&ctx.db).await.unwrap();
+
+        let blockers = BlockersCommand {
+            id: "taskb".to_string(),
+            depth: None,
+            paths: false,
+            max_paths: 100,
+            explain: false,
+            format: OutputFormat::Table,
+            tree_chars: None,
+        };
+        let result = blockers.execute(&ctx.db).await.unwrap();
+        assert!(result.blockers.is_empty());
+        assert_eq!(result.total_count, 0);
+
+        let path = PathCommand {
+            from_id: "taskb".to_string(),
+            to_id: "taskc".to_string(),
+            explain: false,
+        };
+        assert!(path.execute(&ctx.db).await.unwrap().path.is_none());
+
+        let detail = show_cmd("taskb").execute(&ctx.db).await.unwrap();
+        assert!(detail.blocked_by.is_empty());
+        let detail = show_cmd("taskc").execute(&ctx.db).await.unwrap();
+        assert!(detail.blocks.is_empty());
+    }
+
     #[tokio::test]
     async fn test_export_empty_database() {
         let ctx = TestContext::new().await;
@@ -1104,6 +1616,68 @@ mod data_operations {
         assert_eq!(result.child_of_relations, 1);
         assert_eq!(result.depends_on_relations, 1);
     }
+
+    #[tokio::test]
+    async fn test_edges_only_round_trip_keeps_task_fields() {
+        let source = TestContext::new().await;
+        create_task(&source.db, "epic", "Epic", "epic", "todo").await;
+        create_task(&source.db, "ticket", "Ticket", "ticket", "todo").await;
+        create_task(&source.db, "blocker", "Blocker", "task", "done").await;
+        create_child_of(&source.db, "ticket", "epic").await;
+        create_depends_on(&source.db, "ticket", "blocker").await;
+
+        let path = source.temp_dir.join("edges.jsonl");
+        let mut export = export_cmd(Some(path.clone()));
+        export.edges_only = true;
+        let exported = export.execute(&source.db).await.unwrap();
+        assert_eq!(exported.tasks, 0);
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(contents.lines().count(), 2);
+        assert!(!contents.contains(r#""type":"task""#));
+
+        // Same IDs, different fields, no edges yet
+        let target = TestContext::new().await;
+        create_task(&target.db, "epic", "Other epic", "epic", "in_progress").await;
+        create_task(&target.db, "ticket", "Other ticket", "ticket", "todo").await;
+        create_task(&target.db, "blocker", "Other blocker", "task", "todo").await;
+
+        let import = ImportCommand {
+            input: Some(path),
+            skip_existing: false,
+            force: false,
+            quiet: true,
+            edges_only: true,
+            batch: false,
+        };
+        let result = import
+            .execute_with(&target.db, Prompter::non_interactive())
+            .await
+            .unwrap();
+        assert_eq!(result.tasks_imported, 0);
+        assert_eq!(result.child_of_relations, 1);
+        assert_eq!(result.depends_on_relations, 1);
+        assert!(child_of_exists(&target.db, "ticket", "epic").await);
+        assert!(dependency_exists(&target.db, "ticket", "blocker").await);
+
+        let task = target.db.tasks().get("epic").await.unwrap().unwrap();
+        assert_eq!(task.title, "Other epic");
+        assert_eq!(
+            get_task_status(&target.db, "epic").await.as_deref(),
+            Some("in_progress")
+        );
+        assert_eq!(
+            get_task_status(&target.db, "blocker").await.as_deref(),
+            Some("todo")
+        );
+
+        // Applying the same edges again changes nothing
+        let again = import
+            .execute_with(&target.db, Prompter::non_interactive())
+            .await
+            .unwrap();
+        assert_eq!(again.child_of_relations + again.depends_on_relations, 0);
+        assert_eq!(again.edges_skipped, 2);
+    }
 }
 
 // =============================================================================
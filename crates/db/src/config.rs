@@ -0,0 +1,559 @@
+//! Project configuration for Vertebrae
+//!
+//! Loads optional settings from `.vtb/config.toml` at the project root.
+//! A missing file yields the defaults, so configuration is always opt-in.
+//!
+//! ```toml
+//! [levels]
+//! epic = "initiative"
+//! ticket = "story"
+//!
+//! [tags]
+//! normalize = false
+//!
+//! [display]
+//! id_format = "short-6"
+//! tree_chars = "ascii"
+//!
+//! [graph]
+//! max_nodes = 50000
+//! completion_statuses = ["done", "pending_review"]
+//!
+//! [list]
+//! sort = ["priority", "updated"]
+//! ```
+
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use serde::Deserialize;
+
+use crate::error::{DbError, DbResult};
+use crate::find_project_root;
+use crate::id_format::IdFormat;
+use crate::models::{Level, Status};
+use crate::repository::{DEFAULT_COMPLETION_STATUSES, SortKey};
+use crate::tree_chars::TreeChars;
+
+/// Default config file path relative to project root or current working directory
+pub const DEFAULT_CONFIG_PATH: &str = ".vtb/config.toml";
+
+/// Project configuration
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Display names for task levels
+    pub levels: LevelLabels,
+    /// How tags are cleaned up on input
+    pub tags: TagSettings,
+    /// How values are presented in output
+    pub display: DisplaySettings,
+    /// Safety limits for graph traversals and what counts as progress
+    pub graph: GraphSettings,
+    /// Defaults for `vtb list`
+    pub list: ListSettings,
+}
+
+impl Config {
+    /// Load configuration from a TOML file.
+    ///
+    /// Returns the default configuration if the file does not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError::InvalidConfig` if the file cannot be read or parsed,
+    /// or if the level labels are ambiguous.
+    pub fn load(path: &Path) -> DbResult<Self> {
+        let contents = match std::fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => {
+                return Err(DbError::InvalidConfig {
+                    path: path.to_path_buf(),
+                    reason: e.to_string(),
+                });
+            }
+        };
+
+        Self::parse(&contents).map_err(|reason| DbError::InvalidConfig {
+            path: path.to_path_buf(),
+            reason,
+        })
+    }
+
+    /// Parse configuration from TOML text.
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        let config: Config = toml::from_str(contents).map_err(|e| e.message().to_string())?;
+        config.levels.validate()?;
+        Ok(config)
+    }
+
+    /// Get the default config file path.
+    ///
+    /// Uses the git project root when available, otherwise the current
+    /// working directory, matching `Database::default_path()`.
+    pub fn default_path() -> PathBuf {
+        match find_project_root() {
+            Some(root) => root.join(DEFAULT_CONFIG_PATH),
+            None => PathBuf::from(DEFAULT_CONFIG_PATH),
+        }
+    }
+}
+
+/// Display names for the task levels
+///
+/// Labels only change how levels are shown and parsed on input; the
+/// canonical names are always stored in the database and remain accepted.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LevelLabels {
+    /// Label for `Level::Epic`
+    pub epic: String,
+    /// Label for `Level::Ticket`
+    pub ticket: String,
+    /// Label for `Level::Task`
+    pub task: String,
+}
+
+impl Default for LevelLabels {
+    fn default() -> Self {
+        Self {
+            epic: Level::Epic.as_str().to_string(),
+            ticket: Level::Ticket.as_str().to_string(),
+            task: Level::Task.as_str().to_string(),
+        }
+    }
+}
+
+impl LevelLabels {
+    /// Returns the display label for a level
+    pub fn label(&self, level: &Level) -> &str {
+        match level {
+            Level::Epic => &self.epic,
+            Level::Ticket => &self.ticket,
+            Level::Task => &self.task,
+        }
+    }
+
+    /// Parse a level from its canonical name or configured label (case-insensitive)
+    pub fn parse(&self, s: &str) -> Option<Level> {
+        let s = s.trim().to_lowercase();
+        [Level::Epic, Level::Ticket, Level::Task]
+            .into_iter()
+            .find(|level| level.as_str() == s || self.label(level).to_lowercase() == s)
+    }
+
+    /// Comma-separated list of accepted names, for error messages
+    pub fn valid_names(&self) -> String {
+        let mut names: Vec<String> = Vec::new();
+        for level in [Level::Epic, Level::Ticket, Level::Task] {
+            let label = self.label(&level).to_lowercase();
+            if label != level.as_str() {
+                names.push(label);
+            }
+            names.push(level.as_str().to_string());
+        }
+        names.join(", ")
+    }
+
+    /// Ensure labels are non-empty and don't collide with another level's name
+    fn validate(&self) -> Result<(), String> {
+        let levels = [Level::Epic, Level::Ticket, Level::Task];
+        for level in &levels {
+            let label = self.label(level).trim().to_lowercase();
+            if label.is_empty() {
+                return Err(format!("label for level '{}' is empty", level.as_str()));
+            }
+            for other in levels.iter().filter(|other| *other != level) {
+                if label == other.as_str() || label == self.label(other).trim().to_lowercase() {
+                    return Err(format!(
+                        "label '{}' for level '{}' conflicts with level '{}'",
+                        self.label(level),
+                        level.as_str(),
+                        other.as_str()
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Tag handling settings
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TagSettings {
+    /// Normalize tags on input with [`normalize_tag`]; on by default
+    pub normalize: bool,
+}
+
+impl Default for TagSettings {
+    fn default() -> Self {
+        Self { normalize: true }
+    }
+}
+
+impl TagSettings {
+    /// Apply the configured normalization to a single tag
+    pub fn apply(&self, tag: &str) -> String {
+        if self.normalize {
+            normalize_tag(tag)
+        } else {
+            tag.to_string()
+        }
+    }
+
+    /// Apply normalization to a list, dropping empty tags and duplicates
+    /// (the first occurrence wins)
+    pub fn apply_all(&self, tags: &[String]) -> Vec<String> {
+        let mut result: Vec<String> = Vec::with_capacity(tags.len());
+        for tag in tags {
+            let tag = self.apply(tag);
+            if !tag.is_empty() && !result.contains(&tag) {
+                result.push(tag);
+            }
+        }
+        result
+    }
+}
+
+/// Normalize a tag: trim, lowercase, and join internal whitespace runs with `-`.
+///
+/// `" Back End "` becomes `"back-end"`.
+pub fn normalize_tag(tag: &str) -> String {
+    tag.split_whitespace()
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Output presentation settings
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DisplaySettings {
+    /// How task IDs are shown; `--id-format` overrides it
+    pub id_format: IdFormat,
+    /// Connectors for tree output; detected from the locale when unset
+    pub tree_chars: Option<TreeChars>,
+}
+
+/// Default number of tasks a single graph traversal may visit
+pub const DEFAULT_MAX_TRAVERSAL_NODES: usize = 100_000;
+
+/// Graph traversal settings
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct GraphSettings {
+    /// Most tasks one traversal may visit before failing with
+    /// `DbError::TraversalLimit`
+    pub max_nodes: usize,
+    /// Statuses that count as complete in progress bars and estimate
+    /// rollups; empty means the default, `done` only
+    pub completion_statuses: Vec<Status>,
+}
+
+impl Default for GraphSettings {
+    fn default() -> Self {
+        Self {
+            max_nodes: DEFAULT_MAX_TRAVERSAL_NODES,
+            completion_statuses: DEFAULT_COMPLETION_STATUSES.to_vec(),
+        }
+    }
+}
+
+/// Listing settings
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ListSettings {
+    /// Order of `vtb list` when `--sort` is not given
+    pub sort: Vec<SortKey>,
+}
+
+impl Default for ListSettings {
+    fn default() -> Self {
+        Self {
+            sort: vec![SortKey::Priority, SortKey::Updated],
+        }
+    }
+}
+
+/// Process-wide level labels, installed once at startup
+static LEVEL_LABELS: OnceLock<LevelLabels> = OnceLock::new();
+
+/// Install the level labels used by `Level::label()` and `Level::parse_name()`.
+///
+/// Only the first call takes effect; returns `false` if labels were already set.
+pub fn install_level_labels(labels: LevelLabels) -> bool {
+    LEVEL_LABELS.set(labels).is_ok()
+}
+
+/// Get the installed level labels, or the canonical names if none were installed
+pub fn level_labels() -> &'static LevelLabels {
+    static DEFAULT: OnceLock<LevelLabels> = OnceLock::new();
+    LEVEL_LABELS
+        .get()
+        .unwrap_or_else(|| DEFAULT.get_or_init(LevelLabels::default))
+}
+
+/// Process-wide tag settings, installed once at startup
+static TAG_SETTINGS: OnceLock<TagSettings> = OnceLock::new();
+
+/// Install the tag settings used when commands store or filter by tags.
+///
+/// Only the first call takes effect; returns `false` if settings were already set.
+pub fn install_tag_settings(settings: TagSettings) -> bool {
+    TAG_SETTINGS.set(settings).is_ok()
+}
+
+/// Get the installed tag settings, or the defaults if none were installed
+pub fn tag_settings() -> &'static TagSettings {
+    static DEFAULT: OnceLock<TagSettings> = OnceLock::new();
+    TAG_SETTINGS
+        .get()
+        .unwrap_or_else(|| DEFAULT.get_or_init(TagSettings::default))
+}
+
+/// Process-wide ID display format, installed once at startup
+static ID_FORMAT: OnceLock<IdFormat> = OnceLock::new();
+
+/// Install the ID format used when commands display task IDs.
+///
+/// Only the first call takes effect; returns `false` if a format was already set.
+pub fn install_id_format(format: IdFormat) -> bool {
+    ID_FORMAT.set(format).is_ok()
+}
+
+/// Get the installed ID format, or `IdFormat::Full` if none was installed
+pub fn id_format() -> IdFormat {
+    ID_FORMAT.get().copied().unwrap_or_default()
+}
+
+/// Process-wide tree connector set, installed once at startup
+static TREE_CHARS: OnceLock<TreeChars> = OnceLock::new();
+
+/// Install the connector set used when commands draw trees.
+///
+/// Only the first call takes effect; returns `false` if a set was already chosen.
+pub fn install_tree_chars(chars: TreeChars) -> bool {
+    TREE_CHARS.set(chars).is_ok()
+}
+
+/// Get the installed connector set, or `TreeChars::Unicode` if none was installed
+pub fn tree_chars() -> TreeChars {
+    TREE_CHARS.get().copied().unwrap_or_default()
+}
+
+/// Process-wide graph settings, installed once at startup
+static GRAPH_SETTINGS: OnceLock<GraphSettings> = OnceLock::new();
+
+/// Install the graph settings used as defaults by `GraphQueries`.
+///
+/// Only the first call takes effect; returns `false` if settings were already set.
+pub fn install_graph_settings(settings: GraphSettings) -> bool {
+    GRAPH_SETTINGS.set(settings).is_ok()
+}
+
+/// Get the installed graph settings, or the defaults if none were installed
+pub fn graph_settings() -> &'static GraphSettings {
+    static DEFAULT: OnceLock<GraphSettings> = OnceLock::new();
+    GRAPH_SETTINGS
+        .get()
+        .unwrap_or_else(|| DEFAULT.get_or_init(GraphSettings::default))
+}
+
+/// Process-wide listing settings, installed once at startup
+static LIST_SETTINGS: OnceLock<ListSettings> = OnceLock::new();
+
+/// Install the listing settings used as defaults by `vtb list`.
+///
+/// Only the first call takes effect; returns `false` if settings were already set.
+pub fn install_list_settings(settings: ListSettings) -> bool {
+    LIST_SETTINGS.set(settings).is_ok()
+}
+
+/// Get the installed listing settings, or the defaults if none were installed
+pub fn list_settings() -> &'static ListSettings {
+    static DEFAULT: OnceLock<ListSettings> = OnceLock::new();
+    LIST_SETTINGS
+        .get()
+        .unwrap_or_else(|| DEFAULT.get_or_init(ListSettings::default))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn custom_labels() -> LevelLabels {
+        LevelLabels {
+            epic: "Initiative".to_string(),
+            ticket: "story".to_string(),
+            task: "task".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_default_labels_are_canonical() {
+        let labels = LevelLabels::default();
+        assert_eq!(labels.label(&Level::Epic), "epic");
+        assert_eq!(labels.label(&Level::Ticket), "ticket");
+        assert_eq!(labels.label(&Level::Task), "task");
+    }
+
+    #[test]
+    fn test_parse_accepts_canonical_and_custom_names() {
+        let labels = custom_labels();
+        assert_eq!(labels.parse("epic"), Some(Level::Epic));
+        assert_eq!(labels.parse("initiative"), Some(Level::Epic));
+        assert_eq!(labels.parse("INITIATIVE"), Some(Level::Epic));
+        assert_eq!(labels.parse("Story"), Some(Level::Ticket));
+        assert_eq!(labels.parse("ticket"), Some(Level::Ticket));
+        assert_eq!(labels.parse("task"), Some(Level::Task));
+        assert_eq!(labels.parse("feature"), None);
+    }
+
+    #[test]
+    fn test_valid_names_lists_labels_and_canonical() {
+        assert_eq!(
+            custom_labels().valid_names(),
+            "initiative, epic, story, ticket, task"
+        );
+        assert_eq!(LevelLabels::default().valid_names(), "epic, ticket, task");
+    }
+
+    #[test]
+    fn test_config_parse_levels() {
+        let config = Config::parse("[levels]\nepic = \"initiative\"\n").unwrap();
+        assert_eq!(config.levels.epic, "initiative");
+        assert_eq!(config.levels.ticket, "ticket");
+        assert_eq!(config.levels.task, "task");
+    }
+
+    #[test]
+    fn test_config_parse_empty_is_default() {
+        assert_eq!(Config::parse("").unwrap(), Config::default());
+    }
+
+    #[test]
+    fn test_config_parse_rejects_unknown_keys() {
+        assert!(Config::parse("[levels]\nsubtask = \"x\"\n").is_err());
+    }
+
+    #[test]
+    fn test_config_parse_rejects_conflicting_labels() {
+        let err = Config::parse("[levels]\nepic = \"task\"\n").unwrap_err();
+        assert!(err.contains("conflicts"), "got: {}", err);
+
+        let err = Config::parse("[levels]\nepic = \"story\"\nticket = \"Story\"\n").unwrap_err();
+        assert!(err.contains("conflicts"), "got: {}", err);
+    }
+
+    #[test]
+    fn test_config_parse_rejects_empty_label() {
+        let err = Config::parse("[levels]\nticket = \"  \"\n").unwrap_err();
+        assert!(err.contains("empty"), "got: {}", err);
+    }
+
+    #[test]
+    fn test_config_load_missing_file_is_default() {
+        let path = std::env::temp_dir().join("vtb-config-test-missing/config.toml");
+        assert_eq!(Config::load(&path).unwrap(), Config::default());
+    }
+
+    #[test]
+    fn test_config_load_invalid_file() {
+        let dir = std::env::temp_dir().join(format!(
+            "vtb-config-test-invalid-{}-{:?}",
+            std::process::id(),
+            std::thread::current().id()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("config.toml");
+        std::fs::write(&path, "[levels\n").unwrap();
+
+        let result = Config::load(&path);
+        assert!(matches!(result, Err(DbError::InvalidConfig { .. })));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_normalize_tag() {
+        assert_eq!(normalize_tag(" Back End "), "back-end");
+        assert_eq!(normalize_tag("Backend"), "backend");
+        assert_eq!(normalize_tag("multi\t  space\nrun"), "multi-space-run");
+        assert_eq!(normalize_tag("   "), "");
+    }
+
+    #[test]
+    fn test_tag_settings_apply_all_dedups_and_drops_empty() {
+        let tags: Vec<String> = ["Backend", " backend ", "", "Back End"]
+            .iter()
+            .map(|t| t.to_string())
+            .collect();
+        assert_eq!(
+            TagSettings::default().apply_all(&tags),
+            vec!["backend", "back-end"]
+        );
+    }
+
+    #[test]
+    fn test_tag_settings_disabled_keeps_tags() {
+        let settings = TagSettings { normalize: false };
+        assert_eq!(settings.apply(" Back End "), " Back End ");
+        let tags = vec!["Backend".to_string(), "backend".to_string()];
+        assert_eq!(settings.apply_all(&tags), vec!["Backend", "backend"]);
+    }
+
+    #[test]
+    fn test_config_parse_display() {
+        assert_eq!(Config::default().display.id_format, IdFormat::Full);
+        let config = Config::parse("[display]\nid_format = \"short-8\"\n").unwrap();
+        assert_eq!(config.display.id_format, IdFormat::Short8);
+        assert!(Config::parse("[display]\nid_format = \"tiny\"\n").is_err());
+
+        assert_eq!(Config::default().display.tree_chars, None);
+        let config = Config::parse("[display]\ntree_chars = \"ascii\"\n").unwrap();
+        assert_eq!(config.display.tree_chars, Some(TreeChars::Ascii));
+        assert!(Config::parse("[display]\ntree_chars = \"boxes\"\n").is_err());
+    }
+
+    #[test]
+    fn test_config_parse_tags() {
+        assert!(Config::default().tags.normalize);
+        let config = Config::parse("[tags]\nnormalize = false\n").unwrap();
+        assert!(!config.tags.normalize);
+        assert!(Config::parse("[tags]\ncase = \"upper\"\n").is_err());
+    }
+
+    #[test]
+    fn test_config_parse_graph() {
+        assert_eq!(
+            Config::default().graph.max_nodes,
+            DEFAULT_MAX_TRAVERSAL_NODES
+        );
+        let config = Config::parse("[graph]\nmax_nodes = 500\n").unwrap();
+        assert_eq!(config.graph.max_nodes, 500);
+        assert_eq!(config.graph.completion_statuses, vec![Status::Done]);
+        assert!(Config::parse("[graph]\nmax_depth = 5\n").is_err());
+
+        let config =
+            Config::parse("[graph]\ncompletion_statuses = [\"done\", \"pending_review\"]\n")
+                .unwrap();
+        assert_eq!(
+            config.graph.completion_statuses,
+            vec![Status::Done, Status::PendingReview]
+        );
+        assert!(Config::parse("[graph]\ncompletion_statuses = [\"shipped\"]\n").is_err());
+    }
+
+    #[test]
+    fn test_config_parse_list_sort() {
+        assert_eq!(
+            Config::default().list.sort,
+            vec![SortKey::Priority, SortKey::Updated]
+        );
+        let config = Config::parse("[list]\nsort = [\"created\"]\n").unwrap();
+        assert_eq!(config.list.sort, vec![SortKey::Created]);
+        assert!(Config::parse("[list]\nsort = [\"assignee\"]\n").is_err());
+    }
+}
@@ -37,10 +37,32 @@ pub enum DbError {
     #[error("Invalid database path: {path} - {reason}")]
     InvalidPath { path: PathBuf, reason: String },
 
+    /// Error reading or parsing the project config file
+    #[error("Invalid config file {path}: {reason}")]
+    InvalidConfig { path: PathBuf, reason: String },
+
+    /// Error reading or writing a standard stream, e.g. a closed stdout pipe
+    #[error("I/O error on {stream}: {source}")]
+    Io {
+        stream: &'static str,
+        #[source]
+        source: std::io::Error,
+    },
+
     /// Error when a requested task was not found
-    #[error("Task '{task_id}' not found")]
+    #[error("Task '{task_id}' not found; run `vtb list` to see task IDs")]
     NotFound { task_id: String },
 
+    /// Error when an abbreviated task ID matches more than one task
+    #[error(
+        "Task ID '{prefix}' is ambiguous; it matches {}",
+        .candidates.join(", ")
+    )]
+    AmbiguousId {
+        prefix: String,
+        candidates: Vec<String>,
+    },
+
     /// Error creating database directory
     #[error("Failed to create database directory at {path}: {source}")]
     CreateDirectory {
@@ -69,6 +91,16 @@ pub enum DbError {
     #[error("{message}")]
     ValidationError { message: String },
 
+    /// Error when a graph traversal visits more tasks than the configured budget
+    #[error(
+        "Graph traversal from task '{task_id}' exceeded the limit of {limit} tasks; raise [graph] max_nodes if this is expected"
+    )]
+    TraversalLimit { task_id: String, limit: usize },
+
+    /// Error when stored edges form a cycle that should be impossible
+    #[error("Found a {relation} cycle: {cycle}")]
+    GraphCycle { relation: String, cycle: String },
+
     /// Error when task fails triage validation (missing required sections)
     #[error("Task '{task_id}' cannot be triaged: missing required sections")]
     TriageValidationFailed {
@@ -100,6 +132,56 @@ impl DbError {
             other => other.to_string(),
         }
     }
+
+    /// Stable, machine-readable name of the error's variant, e.g.
+    /// `not_found` or `validation`.
+    ///
+    /// Unlike the message, this doesn't change with wording tweaks, so
+    /// scripts can branch on it.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            DbError::Connection { .. } => "connection",
+            DbError::Schema(_) => "schema",
+            DbError::Query(_) => "query",
+            DbError::InvalidPath { .. } => "invalid_path",
+            DbError::InvalidConfig { .. } => "invalid_config",
+            DbError::Io { .. } => "io",
+            DbError::NotFound { .. } => "not_found",
+            DbError::AmbiguousId { .. } => "ambiguous_id",
+            DbError::CreateDirectory { .. } => "create_directory",
+            DbError::IncompleteChildren { .. } => "incomplete_children",
+            DbError::InvalidStatusTransition { .. } => "invalid_status_transition",
+            DbError::ValidationError { .. } => "validation",
+            DbError::TraversalLimit { .. } => "traversal_limit",
+            DbError::GraphCycle { .. } => "graph_cycle",
+            DbError::TriageValidationFailed { .. } => "triage_validation_failed",
+        }
+    }
+
+    /// Stable numeric code of the error's variant.
+    ///
+    /// Codes are grouped by category: 1xx storage and configuration, 2xx
+    /// task lookup, 3xx rejected input or workflow rules, 4xx graph
+    /// problems. A code is never reused for another variant.
+    pub fn code(&self) -> u16 {
+        match self {
+            DbError::Connection { .. } => 100,
+            DbError::Schema(_) => 101,
+            DbError::Query(_) => 102,
+            DbError::InvalidPath { .. } => 103,
+            DbError::InvalidConfig { .. } => 104,
+            DbError::CreateDirectory { .. } => 105,
+            DbError::Io { .. } => 106,
+            DbError::NotFound { .. } => 200,
+            DbError::AmbiguousId { .. } => 201,
+            DbError::ValidationError { .. } => 300,
+            DbError::InvalidStatusTransition { .. } => 301,
+            DbError::IncompleteChildren { .. } => 302,
+            DbError::TriageValidationFailed { .. } => 303,
+            DbError::TraversalLimit { .. } => 400,
+            DbError::GraphCycle { .. } => 401,
+        }
+    }
 }
 
 /// Result type alias for database operations
@@ -121,6 +203,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_io_error_display() {
+        let err = DbError::Io {
+            stream: "stdout",
+            source: std::io::Error::other("Broken pipe"),
+        };
+        assert_eq!(err.to_string(), "I/O error on stdout: Broken pipe");
+        assert_eq!(err.kind(), "io");
+        assert_eq!(err.code(), 106);
+    }
+
+    #[test]
+    fn test_invalid_config_error_display() {
+        let err = DbError::InvalidConfig {
+            path: PathBuf::from(".vtb/config.toml"),
+            reason: "expected `]`".to_string(),
+        };
+        assert_eq!(
+            err.to_string(),
+            "Invalid config file .vtb/config.toml: expected `]`"
+        );
+    }
+
     #[test]
     fn test_create_directory_error_display() {
         let io_err = std::io::Error::new(std::io::ErrorKind::PermissionDenied, "access denied");
@@ -154,7 +259,7 @@ mod tests {
     fn test_db_result_type_alias() {
         // Test that DbResult works correctly
         let ok_result: DbResult<i32> = Ok(42);
-        assert_eq!(ok_result.unwrap(), 42);
+        assert!(matches!(ok_result, Ok(42)));
 
         let err_result: DbResult<i32> = Err(DbError::InvalidPath {
             path: PathBuf::from("/test"),
@@ -280,7 +385,10 @@ mod tests {
         let err = DbError::NotFound {
             task_id: "abc123".to_string(),
         };
-        assert_eq!(err.to_string(), "Task 'abc123' not found");
+        assert_eq!(
+            err.to_string(),
+            "Task 'abc123' not found; run `vtb list` to see task IDs"
+        );
     }
 
     #[test]
@@ -295,6 +403,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_ambiguous_id_error_display() {
+        let err = DbError::AmbiguousId {
+            prefix: "ab".to_string(),
+            candidates: vec!["abc123".to_string(), "abd456".to_string()],
+        };
+        assert_eq!(
+            err.to_string(),
+            "Task ID 'ab' is ambiguous; it matches abc123, abd456"
+        );
+    }
+
     #[test]
     fn test_validation_error_display() {
         let err = DbError::ValidationError {
@@ -303,6 +423,129 @@ mod tests {
         assert_eq!(err.to_string(), "Search query cannot be empty");
     }
 
+    #[test]
+    fn test_traversal_limit_error_display() {
+        let err = DbError::TraversalLimit {
+            task_id: "epic01".to_string(),
+            limit: 50,
+        };
+        let message = err.to_string();
+        assert!(message.contains("'epic01'"), "got: {}", message);
+        assert!(message.contains("limit of 50 tasks"), "got: {}", message);
+    }
+
+    #[test]
+    fn test_graph_cycle_error_display() {
+        let err = DbError::GraphCycle {
+            relation: "child_of".to_string(),
+            cycle: "a -> b -> a".to_string(),
+        };
+        assert_eq!(err.to_string(), "Found a child_of cycle: a -> b -> a");
+    }
+
+    #[test]
+    fn test_error_kind() {
+        let not_found = DbError::NotFound {
+            task_id: "abc123".to_string(),
+        };
+        assert_eq!(not_found.kind(), "not_found");
+        let validation = DbError::ValidationError {
+            message: "bad".to_string(),
+        };
+        assert_eq!(validation.kind(), "validation");
+        let cycle = DbError::GraphCycle {
+            relation: "depends_on".to_string(),
+            cycle: "a -> a".to_string(),
+        };
+        assert_eq!(cycle.kind(), "graph_cycle");
+    }
+
+    /// One error of every variant
+    fn every_variant() -> Vec<DbError> {
+        let surreal = || Box::new(surrealdb::Error::Db(surrealdb::error::Db::QueryEmpty));
+        vec![
+            DbError::Connection {
+                path: PathBuf::from("/db"),
+                source: surreal(),
+            },
+            DbError::Schema(surreal()),
+            DbError::Query(surreal()),
+            DbError::InvalidPath {
+                path: PathBuf::from("/db"),
+                reason: "missing".to_string(),
+            },
+            DbError::InvalidConfig {
+                path: PathBuf::from(".vtb/config.toml"),
+                reason: "bad".to_string(),
+            },
+            DbError::Io {
+                stream: "stdout",
+                source: std::io::Error::from(std::io::ErrorKind::BrokenPipe),
+            },
+            DbError::NotFound {
+                task_id: "abc123".to_string(),
+            },
+            DbError::AmbiguousId {
+                prefix: "ab".to_string(),
+                candidates: vec!["abc123".to_string(), "abd456".to_string()],
+            },
+            DbError::CreateDirectory {
+                path: PathBuf::from("/db"),
+                source: std::io::Error::other("denied"),
+            },
+            DbError::IncompleteChildren {
+                task_id: "abc123".to_string(),
+                children: Vec::new(),
+            },
+            DbError::InvalidStatusTransition {
+                task_id: "abc123".to_string(),
+                from_status: "done".to_string(),
+                to_status: "todo".to_string(),
+                message: "final state".to_string(),
+            },
+            DbError::ValidationError {
+                message: "bad".to_string(),
+            },
+            DbError::TraversalLimit {
+                task_id: "abc123".to_string(),
+                limit: 10,
+            },
+            DbError::GraphCycle {
+                relation: "depends_on".to_string(),
+                cycle: "a -> a".to_string(),
+            },
+            DbError::TriageValidationFailed {
+                task_id: "abc123".to_string(),
+                error_count: 1,
+                warning_count: 0,
+                note_count: 0,
+                details: String::new(),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_every_variant_has_a_unique_kind_and_code() {
+        let errors = every_variant();
+        let kinds: std::collections::HashSet<_> = errors.iter().map(DbError::kind).collect();
+        let codes: std::collections::HashSet<_> = errors.iter().map(DbError::code).collect();
+        assert_eq!(kinds.len(), errors.len());
+        assert_eq!(codes.len(), errors.len());
+    }
+
+    #[test]
+    fn test_error_code_categories() {
+        let not_found = DbError::NotFound {
+            task_id: "abc123".to_string(),
+        };
+        assert_eq!(not_found.code(), 200);
+        let cycle = DbError::GraphCycle {
+            relation: "child_of".to_string(),
+            cycle: "a -> b -> a".to_string(),
+        };
+        assert_eq!(cycle.code(), 401);
+    }
+
     #[test]
     fn test_validation_error_debug() {
         let err = DbError::ValidationError {
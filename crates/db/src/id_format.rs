@@ -0,0 +1,199 @@
+//! Display formatting for task IDs
+//!
+//! IDs are always stored and accepted in full; these helpers only shorten
+//! them for display. Short forms grow past their nominal length whenever
+//! that length would make two IDs in the same listing look identical.
+
+use std::collections::HashSet;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+/// How task IDs are shown to the user
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+pub enum IdFormat {
+    /// The complete ID
+    #[default]
+    #[serde(rename = "full")]
+    Full,
+    /// The first 6 characters
+    #[serde(rename = "short-6")]
+    Short6,
+    /// The first 8 characters
+    #[serde(rename = "short-8")]
+    Short8,
+}
+
+impl IdFormat {
+    /// All formats, in the order they're listed in help and errors
+    pub const ALL: [IdFormat; 3] = [IdFormat::Full, IdFormat::Short6, IdFormat::Short8];
+
+    /// Returns the name used on the command line and in config
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IdFormat::Full => "full",
+            IdFormat::Short6 => "short-6",
+            IdFormat::Short8 => "short-8",
+        }
+    }
+
+    /// Nominal display length, or `None` to show the whole ID
+    pub fn min_len(&self) -> Option<usize> {
+        match self {
+            IdFormat::Full => None,
+            IdFormat::Short6 => Some(6),
+            IdFormat::Short8 => Some(8),
+        }
+    }
+}
+
+impl fmt::Display for IdFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for IdFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim().to_lowercase();
+        IdFormat::ALL
+            .into_iter()
+            .find(|format| format.as_str() == s)
+            .ok_or_else(|| {
+                format!(
+                    "invalid id format '{}'. Valid values: {}",
+                    s,
+                    IdFormat::ALL.map(|f| f.as_str()).join(", ")
+                )
+            })
+    }
+}
+
+/// Format a single ID at the nominal length for `mode`.
+///
+/// This does not check for ambiguity; use [`IdFormatter`] when showing
+/// several IDs together.
+pub fn format_id(id: &str, mode: IdFormat) -> &str {
+    match mode.min_len() {
+        Some(len) => prefix(id, len),
+        None => id,
+    }
+}
+
+/// Formats a set of IDs so their short forms stay distinct
+///
+/// The default formatter shows full IDs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct IdFormatter {
+    /// Display length, or `None` to show whole IDs
+    len: Option<usize>,
+}
+
+impl IdFormatter {
+    /// Build a formatter for the IDs that will be shown together.
+    ///
+    /// Starts at the nominal length for `mode` and lengthens it until no two
+    /// distinct IDs in `ids` share the same displayed prefix.
+    pub fn for_ids<'a>(mode: IdFormat, ids: impl IntoIterator<Item = &'a str>) -> Self {
+        let Some(min_len) = mode.min_len() else {
+            return Self { len: None };
+        };
+
+        let ids: HashSet<&str> = ids.into_iter().collect();
+        let max_len = ids.iter().map(|id| id.chars().count()).max().unwrap_or(0);
+
+        let mut len = min_len;
+        while len < max_len {
+            let mut seen = HashSet::with_capacity(ids.len());
+            if ids.iter().all(|id| seen.insert(prefix(id, len))) {
+                break;
+            }
+            len += 1;
+        }
+
+        Self { len: Some(len) }
+    }
+
+    /// Format one of the IDs this formatter was built for
+    pub fn format<'b>(&self, id: &'b str) -> &'b str {
+        match self.len {
+            Some(len) => prefix(id, len),
+            None => id,
+        }
+    }
+}
+
+/// First `len` characters of `id` (the whole ID if it is shorter)
+fn prefix(id: &str, len: usize) -> &str {
+    match id.char_indices().nth(len) {
+        Some((end, _)) => &id[..end],
+        None => id,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_id_modes() {
+        assert_eq!(format_id("abcdef123456", IdFormat::Full), "abcdef123456");
+        assert_eq!(format_id("abcdef123456", IdFormat::Short6), "abcdef");
+        assert_eq!(format_id("abcdef123456", IdFormat::Short8), "abcdef12");
+        assert_eq!(format_id("abc", IdFormat::Short8), "abc");
+    }
+
+    #[test]
+    fn test_id_format_parse() {
+        assert_eq!("full".parse::<IdFormat>(), Ok(IdFormat::Full));
+        assert_eq!("Short-6".parse::<IdFormat>(), Ok(IdFormat::Short6));
+        assert_eq!("short-8".parse::<IdFormat>(), Ok(IdFormat::Short8));
+        let err = "short".parse::<IdFormat>().unwrap_err();
+        assert!(err.contains("full, short-6, short-8"), "got: {}", err);
+    }
+
+    #[test]
+    fn test_formatter_keeps_nominal_length_when_unambiguous() {
+        let ids = ["abcdef111111", "bcdefa222222"];
+        let formatter = IdFormatter::for_ids(IdFormat::Short6, ids);
+        assert_eq!(formatter.format(ids[0]), "abcdef");
+        assert_eq!(formatter.format(ids[1]), "bcdefa");
+    }
+
+    #[test]
+    fn test_formatter_lengthens_shared_prefix() {
+        let ids = ["abcdef12aaaa", "abcdef12bbbb", "abcdef34cccc"];
+
+        let short6 = IdFormatter::for_ids(IdFormat::Short6, ids);
+        assert_eq!(short6.format(ids[0]), "abcdef12a");
+        assert_eq!(short6.format(ids[1]), "abcdef12b");
+        assert_eq!(short6.format(ids[2]), "abcdef34c");
+
+        let short8 = IdFormatter::for_ids(IdFormat::Short8, ids);
+        assert_eq!(short8.format(ids[0]), "abcdef12a");
+    }
+
+    #[test]
+    fn test_formatter_handles_id_that_prefixes_another() {
+        let ids = ["abcdef", "abcdefgh"];
+        let formatter = IdFormatter::for_ids(IdFormat::Short6, ids);
+        assert_eq!(formatter.format(ids[0]), "abcdef");
+        assert_eq!(formatter.format(ids[1]), "abcdefg");
+    }
+
+    #[test]
+    fn test_formatter_ignores_duplicate_ids() {
+        let ids = ["abcdef99", "abcdef99"];
+        let formatter = IdFormatter::for_ids(IdFormat::Short6, ids);
+        assert_eq!(formatter.format(ids[0]), "abcdef");
+    }
+
+    #[test]
+    fn test_formatter_full_mode() {
+        let formatter = IdFormatter::for_ids(IdFormat::Full, ["abcdef12", "abcdef34"]);
+        assert_eq!(formatter.format("abcdef12"), "abcdef12");
+    }
+}
@@ -3,19 +3,40 @@
 //! Provides SurrealDB connection management with embedded SurrealKV backend,
 //! schema initialization, and data models for task management.
 
+pub mod config;
 pub mod error;
+pub mod id;
+pub mod id_format;
 pub mod models;
 pub mod repository;
+pub mod retry;
 pub mod schema;
+pub mod tree_chars;
 
+pub use config::{
+    Config, DisplaySettings, GraphSettings, LevelLabels, ListSettings, TagSettings, graph_settings,
+    id_format, install_graph_settings, install_id_format, install_level_labels,
+    install_list_settings, install_tag_settings, install_tree_chars, level_labels, list_settings,
+    normalize_tag, tag_settings, tree_chars,
+};
 pub use error::{DbError, DbResult, IncompleteChildInfo};
+pub use id::{ID_LENGTH, IdGenerator, generate_id};
+pub use id_format::{IdFormat, IdFormatter, format_id};
 #[allow(unused_imports)]
 pub use models::{CodeRef, Level, Priority, Section, SectionType, Status, Task};
 pub use repository::{
-    BlockerNode, GraphQueries, Progress, RelationshipRepository, SectionRule, TaskFilter,
-    TaskLister, TaskRepository, TaskSummary, TaskUpdate, TriageValidationConfig,
-    TriageValidationResult, TriageValidator, ValidationIssue, ValidationSeverity,
+    BlockerNode, BulkTask, DEFAULT_COMPLETION_STATUSES, DEFAULT_PAGE_SIZE, DuplicateTitle,
+    EstimateRollup, GraphQueries, PendingEdges, Progress, RelationshipRepository, SearchField,
+    SearchHit, SectionRule, SelfEdge, SortKey, TagMode, TaskFilter, TaskLister, TaskRepository,
+    TaskStream, TaskSummary, TaskUpdate, TransitionOptions, TransitionOutcome,
+    TriageValidationConfig, TriageValidationResult, TriageValidator, ValidationIssue,
+    ValidationSeverity, format_unblocked,
+};
+pub use retry::{
+    DB_RETRIES_ENV, DEFAULT_DB_RETRIES, RetryClient, RetryPolicy, RetryQuery, Transient,
 };
+pub use schema::SCHEMA_VERSION;
+pub use tree_chars::TreeChars;
 
 use std::path::{Path, PathBuf};
 use std::process::Command;
@@ -30,8 +51,9 @@ pub struct Database {
     /// The underlying SurrealDB client
     client: Surreal<Db>,
     /// Path where the database is stored
-    #[allow(dead_code)] // Used by path() method for tests and future features
     path: PathBuf,
+    /// How transient failures of `init` and `query` are retried
+    retry: RetryPolicy,
 }
 
 impl Database {
@@ -48,62 +70,83 @@ impl Database {
     /// Returns `DbError::InvalidPath` if the path is invalid.
     /// Returns `DbError::CreateDirectory` if directory creation fails.
     /// Returns `DbError::Connection` if database connection fails.
+    /// Returns `DbError::ValidationError` if `VTB_DB_RETRIES` is not a number.
     pub async fn connect(path: &Path) -> DbResult<Self> {
+        Self::connect_with_retry(path, RetryPolicy::from_env()?).await
+    }
+
+    /// Connect like [`Database::connect`], retrying transient failures
+    /// according to `retry` instead of `VTB_DB_RETRIES`.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Database::connect`], once the retries are used up.
+    pub async fn connect_with_retry(path: &Path, retry: RetryPolicy) -> DbResult<Self> {
         // Validate and create the database directory
         let path = Self::prepare_path(path)?;
 
         // Connect to the database using SurrealKV backend
-        let client =
-            Surreal::new::<SurrealKv>(path.clone())
-                .await
-                .map_err(|e| DbError::Connection {
-                    path: path.clone(),
-                    source: Box::new(e),
-                })?;
-
-        Ok(Self { client, path })
+        let client = retry
+            .run(|| async {
+                Surreal::new::<SurrealKv>(path.clone())
+                    .await
+                    .map_err(|e| DbError::Connection {
+                        path: path.clone(),
+                        source: Box::new(e),
+                    })
+            })
+            .await?;
+
+        Ok(Self {
+            client,
+            path,
+            retry,
+        })
     }
 
     /// Initialize the database schema.
     ///
     /// Sets up the namespace and database for Vertebrae operations,
-    /// then initializes the task table and graph relations.
+    /// then initializes the task table and graph relations. Transaction
+    /// conflicts are retried like a failed connection.
     ///
     /// # Errors
     ///
     /// Returns `DbError::Schema` if schema initialization fails.
     pub async fn init(&self) -> DbResult<()> {
-        // Use namespace and database for Vertebrae
-        self.client
-            .use_ns("vertebrae")
-            .use_db("main")
+        self.retry
+            .run(|| async {
+                // Use namespace and database for Vertebrae
+                self.client
+                    .use_ns("vertebrae")
+                    .use_db("main")
+                    .await
+                    .map_err(|e| DbError::Schema(Box::new(e)))?;
+
+                // Initialize the schema (task table, relations)
+                schema::init_schema(&self.client).await
+            })
             .await
-            .map_err(|e| DbError::Schema(Box::new(e)))?;
-
-        // Initialize the schema (task table, relations)
-        schema::init_schema(&self.client).await?;
-
-        Ok(())
     }
 
     /// Get a task repository for CRUD operations on tasks.
     pub fn tasks(&self) -> TaskRepository<'_> {
-        TaskRepository::new(&self.client)
+        TaskRepository::new(self.retry_client())
     }
 
     /// Get a graph queries instance for hierarchy and dependency operations.
     pub fn graph(&self) -> GraphQueries<'_> {
-        GraphQueries::new(&self.client)
+        GraphQueries::new(self.retry_client())
     }
 
     /// Get a relationship repository for managing task relationships.
     pub fn relationships(&self) -> RelationshipRepository<'_> {
-        RelationshipRepository::new(&self.client)
+        RelationshipRepository::new(self.retry_client())
     }
 
     /// Get a task lister for filtering and listing tasks.
     pub fn list_tasks(&self) -> TaskLister<'_> {
-        TaskLister::new(&self.client)
+        TaskLister::new(self.retry_client())
     }
 
     /// List ready items for work or triage at a given status.
@@ -137,25 +180,26 @@ impl Database {
         &self.client
     }
 
-    /// Execute a raw database query (for specialized use cases only).
+    /// Start a raw database query (for specialized use cases only).
     ///
     /// This method should only be used for queries that don't fit the repository pattern.
     /// Most operations should use repository accessors instead.
-    /// The TUI may use this for specialized data loading that requires raw queries.
+    /// Like repository queries, it is retried on transient failures; await
+    /// it to get the raw response.
     ///
     /// # Arguments
     ///
     /// * `query` - The SurrealDB query string
-    ///
-    /// # Returns
-    ///
-    /// The raw query result from SurrealDB
-    pub async fn query(&self, query: &str) -> surrealdb::Result<surrealdb::Response> {
-        self.client.query(query).await
+    pub fn query(&self, query: impl Into<String>) -> RetryQuery<'_> {
+        self.retry_client().query(query)
+    }
+
+    /// The client repositories use, retrying with this database's policy.
+    fn retry_client(&self) -> RetryClient<'_> {
+        RetryClient::new(&self.client, self.retry)
     }
 
     /// Get the path where the database is stored.
-    #[allow(dead_code)] // Used in tests and future features
     pub fn path(&self) -> &Path {
         &self.path
     }
@@ -31,6 +31,16 @@ impl Level {
             Level::Task => "task",
         }
     }
+
+    /// Returns the display label, honoring configured level names
+    pub fn label(&self) -> &'static str {
+        crate::config::level_labels().label(self)
+    }
+
+    /// Parse a level from its canonical name or configured label (case-insensitive)
+    pub fn parse_name(s: &str) -> Option<Self> {
+        crate::config::level_labels().parse(s)
+    }
 }
 
 impl std::fmt::Display for Level {
@@ -159,7 +169,11 @@ impl std::fmt::Display for Status {
 /// Task priority level
 ///
 /// Optional priority for tasks, from low to critical.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// Variants are ordered from lowest to highest. A task without a priority
+/// ranks below `Low`; use `Priority::rank` or `Priority::cmp_optional` when
+/// comparing optional priorities so every caller agrees on that.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum Priority {
     Low,
@@ -178,6 +192,22 @@ impl Priority {
             Priority::Critical => "critical",
         }
     }
+
+    /// Numeric rank of an optional priority: 0 for none, then 1 (low) to 4 (critical)
+    pub fn rank(priority: Option<&Priority>) -> u8 {
+        match priority {
+            None => 0,
+            Some(Priority::Low) => 1,
+            Some(Priority::Medium) => 2,
+            Some(Priority::High) => 3,
+            Some(Priority::Critical) => 4,
+        }
+    }
+
+    /// Compare optional priorities, with none strictly below low
+    pub fn cmp_optional(a: Option<&Priority>, b: Option<&Priority>) -> std::cmp::Ordering {
+        Self::rank(a).cmp(&Self::rank(b))
+    }
 }
 
 impl std::fmt::Display for Priority {
@@ -445,6 +475,18 @@ pub struct Task {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub completed_at: Option<DateTime<Utc>>,
 
+    /// When this task is due
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub due_at: Option<DateTime<Utc>>,
+
+    /// Estimated effort in minutes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub estimate_minutes: Option<u32>,
+
+    /// When this task was moved to the trash; `None` for live tasks
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<DateTime<Utc>>,
+
     /// Embedded sections
     #[serde(default)]
     pub sections: Vec<Section>,
@@ -473,6 +515,9 @@ impl Task {
             updated_at: None,
             started_at: None,
             completed_at: None,
+            due_at: None,
+            estimate_minutes: None,
+            deleted_at: None,
             sections: Vec::new(),
             code_refs: Vec::new(),
             needs_human_review: None,
@@ -497,6 +542,18 @@ impl Task {
         self
     }
 
+    /// Set when this task is due
+    pub fn with_due_at(mut self, due_at: DateTime<Utc>) -> Self {
+        self.due_at = Some(due_at);
+        self
+    }
+
+    /// Set the estimated effort of this task, in minutes
+    pub fn with_estimate_minutes(mut self, minutes: u32) -> Self {
+        self.estimate_minutes = Some(minutes);
+        self
+    }
+
     /// Add a tag to this task
     pub fn with_tag(mut self, tag: impl Into<String>) -> Self {
         self.tags.push(tag.into());
@@ -536,6 +593,8 @@ impl PartialEq for Task {
             && self.status == other.status
             && self.priority == other.priority
             && self.tags == other.tags
+            && self.due_at == other.due_at
+            && self.estimate_minutes == other.estimate_minutes
             && self.sections == other.sections
             && self.code_refs == other.code_refs
             && self.needs_human_review == other.needs_human_review
@@ -889,6 +948,65 @@ mod tests {
         assert_eq!(priority, cloned);
     }
 
+    #[test]
+    fn test_priority_ord() {
+        assert!(Priority::Low < Priority::Medium);
+        assert!(Priority::Medium < Priority::High);
+        assert!(Priority::High < Priority::Critical);
+    }
+
+    #[test]
+    fn test_priority_rank() {
+        assert_eq!(Priority::rank(None), 0);
+        assert_eq!(Priority::rank(Some(&Priority::Low)), 1);
+        assert_eq!(Priority::rank(Some(&Priority::Critical)), 4);
+    }
+
+    #[test]
+    fn test_priority_cmp_optional_none_below_low() {
+        use std::cmp::Ordering;
+
+        assert_eq!(
+            Priority::cmp_optional(None, Some(&Priority::Low)),
+            Ordering::Less
+        );
+        assert_eq!(
+            Priority::cmp_optional(Some(&Priority::Low), None),
+            Ordering::Greater
+        );
+        assert_eq!(Priority::cmp_optional(None, None), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_priority_cmp_optional_total_order() {
+        let mut priorities = vec![
+            Some(Priority::High),
+            None,
+            Some(Priority::Critical),
+            Some(Priority::Low),
+            None,
+            Some(Priority::Medium),
+        ];
+        priorities.sort_by(|a, b| Priority::cmp_optional(a.as_ref(), b.as_ref()));
+        assert_eq!(
+            priorities,
+            vec![
+                None,
+                None,
+                Some(Priority::Low),
+                Some(Priority::Medium),
+                Some(Priority::High),
+                Some(Priority::Critical),
+            ]
+        );
+
+        // Agrees with the derived Ord on Option<Priority>
+        let mut derived = priorities.clone();
+        derived.reverse();
+        derived.sort();
+        assert_eq!(derived, priorities);
+    }
+
     // SectionType enum tests
     #[test]
     fn test_section_type_as_str() {
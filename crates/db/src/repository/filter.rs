@@ -3,11 +3,13 @@
 //! Provides a builder-pattern TaskFilter and TaskLister for querying
 //! tasks with complex filter combinations.
 
-use crate::error::DbResult;
-use crate::models::{Level, Priority, Status};
+use crate::error::{DbError, DbResult};
+use crate::models::{Level, Priority, SectionType, Status};
+use crate::retry::RetryClient;
+use chrono::{DateTime, Utc};
+use regex::Regex;
 use serde::Deserialize;
-use surrealdb::Surreal;
-use surrealdb::engine::local::Db;
+use std::ops::Range;
 
 /// A summary of a task for display in listings
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -26,6 +28,10 @@ pub struct TaskSummary {
     pub tags: Vec<String>,
     /// Whether this task needs human review
     pub needs_human_review: Option<bool>,
+    /// When the task was created
+    pub created_at: Option<DateTime<Utc>>,
+    /// When the task was last changed
+    pub updated_at: Option<DateTime<Utc>>,
 }
 
 /// Internal row type for deserializing from SurrealDB
@@ -40,9 +46,10 @@ struct TaskRow {
     tags: Vec<String>,
     #[serde(default)]
     needs_human_review: Option<bool>,
-    /// Created timestamp - used by SQL ORDER BY for sorting, must be selected to match query
-    #[allow(dead_code)]
-    created_at: surrealdb::sql::Datetime,
+    #[serde(default)]
+    created_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    updated_at: Option<DateTime<Utc>>,
 }
 
 impl TaskRow {
@@ -56,6 +63,8 @@ impl TaskRow {
             priority: self.priority.as_deref().map(parse_priority),
             tags: self.tags,
             needs_human_review: self.needs_human_review,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
         }
     }
 }
@@ -85,14 +94,206 @@ fn parse_priority(s: &str) -> Priority {
     }
 }
 
+/// Keys task listings can be sorted by
+///
+/// Each key sorts in its most useful direction: highest priority first,
+/// most recently updated or created first, titles A to Z and statuses in
+/// workflow order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SortKey {
+    /// Critical first, tasks without a priority last
+    Priority,
+    /// Most recently updated first
+    Updated,
+    /// Newest first
+    Created,
+    /// Alphabetical, ignoring case
+    Title,
+    /// Workflow order, backlog first
+    Status,
+}
+
+impl SortKey {
+    /// All keys, in the order they're listed in help and errors
+    pub const ALL: [SortKey; 5] = [
+        SortKey::Priority,
+        SortKey::Updated,
+        SortKey::Created,
+        SortKey::Title,
+        SortKey::Status,
+    ];
+
+    /// Parse a sort key name
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|key| key.as_str() == s)
+    }
+
+    /// Returns the key's name
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SortKey::Priority => "priority",
+            SortKey::Updated => "updated",
+            SortKey::Created => "created",
+            SortKey::Title => "title",
+            SortKey::Status => "status",
+        }
+    }
+
+    /// Field to select in addition to [`LIST_FIELDS`] so ORDER BY can use it
+    fn select_field(&self) -> Option<&'static str> {
+        match self {
+            SortKey::Priority => Some(PRIORITY_RANK_FIELD),
+            SortKey::Updated | SortKey::Created => None,
+            SortKey::Title => Some("string::lowercase(title) AS title_key"),
+            SortKey::Status => Some(STATUS_RANK_FIELD),
+        }
+    }
+
+    /// ORDER BY term for this key, flipped when `reverse` is set
+    fn order_term(&self, reverse: bool) -> String {
+        let (column, descending) = match self {
+            SortKey::Priority => ("priority_rank", true),
+            SortKey::Updated => ("updated_at", true),
+            SortKey::Created => ("created_at", true),
+            SortKey::Title => ("title_key", false),
+            SortKey::Status => ("status_rank", false),
+        };
+        order_term(column, descending != reverse)
+    }
+}
+
+/// How several tag filters combine
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TagMode {
+    /// A task needs at least one of the tags
+    #[default]
+    Any,
+    /// A task needs every one of the tags
+    All,
+}
+
+impl TagMode {
+    /// All modes, in the order they're listed in help and errors
+    pub const ALL: [TagMode; 2] = [TagMode::Any, TagMode::All];
+
+    /// Parse a tag mode name
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|mode| mode.as_str() == s)
+    }
+
+    /// Returns the mode's name
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TagMode::Any => "any",
+            TagMode::All => "all",
+        }
+    }
+
+    /// Whether a task carrying `tags` satisfies the `wanted` tag filters
+    fn matches(&self, wanted: &[String], tags: &[String]) -> bool {
+        match self {
+            TagMode::Any => wanted.iter().any(|t| tags.contains(t)),
+            TagMode::All => wanted.iter().all(|t| tags.contains(t)),
+        }
+    }
+
+    /// SurrealQL operator joining one condition per wanted tag
+    fn joiner(&self) -> &'static str {
+        match self {
+            TagMode::Any => " OR ",
+            TagMode::All => " AND ",
+        }
+    }
+}
+
+/// Format an ORDER BY term
+fn order_term(column: &str, descending: bool) -> String {
+    format!("{} {}", column, if descending { "DESC" } else { "ASC" })
+}
+
+/// Part of a task a search matched, best match first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SearchField {
+    /// The title
+    Title,
+    /// The description
+    Description,
+    /// The content of a section
+    Section,
+}
+
+impl SearchField {
+    /// Returns the field's name
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SearchField::Title => "title",
+            SearchField::Description => "description",
+            SearchField::Section => "section",
+        }
+    }
+}
+
+/// A task found by [`TaskLister::search_ranked`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchHit {
+    /// The matching task
+    pub task: TaskSummary,
+    /// Best field the query was found in
+    pub field: SearchField,
+    /// The line of that field containing the match
+    pub snippet: String,
+    /// Byte range of the match within `snippet`
+    pub matched: Range<usize>,
+}
+
+/// Byte range of the first case-insensitive occurrence of `needle` in
+/// `haystack`.
+fn find_ignore_case(haystack: &str, needle: &str) -> Option<Range<usize>> {
+    let needle = needle.to_lowercase();
+    if needle.is_empty() {
+        return None;
+    }
+
+    // Lowercasing can change byte lengths, so remember where each byte of
+    // the lowered text came from
+    let mut lowered = String::with_capacity(haystack.len());
+    let mut origin = Vec::with_capacity(haystack.len() + 1);
+    for (i, c) in haystack.char_indices() {
+        for lower in c.to_lowercase() {
+            lowered.push(lower);
+            origin.resize(lowered.len(), i);
+        }
+    }
+    origin.push(haystack.len());
+
+    let start = lowered.find(&needle)?;
+    Some(origin[start]..origin[start + needle.len()])
+}
+
+/// The line of `text` around `matched`, with the match's range in it.
+fn matched_line(text: &str, matched: Range<usize>) -> (String, Range<usize>) {
+    let start = text[..matched.start].rfind('\n').map_or(0, |i| i + 1);
+    let end = text[matched.end..]
+        .find('\n')
+        .map_or(text.len(), |i| matched.end + i);
+    (
+        text[start..end].to_string(),
+        matched.start - start..matched.end - start,
+    )
+}
+
 /// Filter criteria for listing tasks
 ///
-/// Supports filtering by level, status, priority, tags, and structural
-/// relationships (root-only or children of a specific parent).
+/// Supports filtering by level, status, priority, tags, documentation
+/// (sections and code refs), and structural relationships (root-only or
+/// children of a specific parent).
 ///
 /// All filter criteria use OR semantics within the same type
 /// (e.g., multiple statuses means "match any of these statuses")
-/// and AND semantics across different types.
+/// and AND semantics across different types. Tags are the exception when
+/// `tag_mode` is [`TagMode::All`].
 #[derive(Debug, Clone, Default)]
 pub struct TaskFilter {
     /// Filter by levels (OR semantics)
@@ -101,8 +302,10 @@ pub struct TaskFilter {
     pub statuses: Vec<Status>,
     /// Filter by priorities (OR semantics)
     pub priorities: Vec<Priority>,
-    /// Filter by tags (OR semantics - task must have at least one matching tag)
+    /// Filter by tags; `tag_mode` decides whether any or all must match
     pub tags: Vec<String>,
+    /// How multiple tags combine (any by default)
+    pub tag_mode: TagMode,
     /// Show only root items (no parent)
     pub root_only: bool,
     /// Show only children of a specific task
@@ -111,6 +314,32 @@ pub struct TaskFilter {
     pub include_done: bool,
     /// Search text in title and description (case-insensitive)
     pub search: Option<String>,
+    /// Keep only tasks whose title matches (applied in memory, after the query)
+    pub title_regex: Option<Regex>,
+    /// Require at least one section of any of these types (OR semantics)
+    pub section_types: Vec<SectionType>,
+    /// Show only tasks with no sections at all
+    pub no_sections: bool,
+    /// Require code refs to be present (`Some(true)`) or absent (`Some(false)`)
+    pub has_refs: Option<bool>,
+    /// Show only unfinished tasks whose due date has passed
+    pub overdue: bool,
+    /// Show only tasks created at or after this time
+    pub created_after: Option<DateTime<Utc>>,
+    /// Show only tasks created before this time
+    pub created_before: Option<DateTime<Utc>>,
+    /// Show only tasks last changed at or after this time
+    pub updated_after: Option<DateTime<Utc>>,
+    /// Include tasks in the trash (excluded by default)
+    pub include_deleted: bool,
+    /// Sort keys in order of precedence; empty means newest first
+    pub sort: Vec<SortKey>,
+    /// Reverse the whole listing order, tie-breakers included
+    pub reverse: bool,
+    /// Return at most this many tasks
+    pub limit: Option<usize>,
+    /// Skip this many matching tasks before returning any
+    pub offset: usize,
 }
 
 impl TaskFilter {
@@ -167,6 +396,12 @@ impl TaskFilter {
         self
     }
 
+    /// Set how multiple tags combine
+    pub fn with_tag_mode(mut self, mode: TagMode) -> Self {
+        self.tag_mode = mode;
+        self
+    }
+
     /// Filter to only root items (no parent)
     pub fn root_only(mut self) -> Self {
         self.root_only = true;
@@ -191,6 +426,139 @@ impl TaskFilter {
         self
     }
 
+    /// Keep only tasks whose title matches `regex`
+    pub fn with_title_regex(mut self, regex: Regex) -> Self {
+        self.title_regex = Some(regex);
+        self
+    }
+
+    /// Add a section type the task must have
+    pub fn with_section_type(mut self, section_type: SectionType) -> Self {
+        self.section_types.push(section_type);
+        self
+    }
+
+    /// Filter to tasks with no sections
+    pub fn without_sections(mut self) -> Self {
+        self.no_sections = true;
+        self
+    }
+
+    /// Filter on whether the task has code refs
+    pub fn with_has_refs(mut self, has_refs: bool) -> Self {
+        self.has_refs = Some(has_refs);
+        self
+    }
+
+    /// Filter to unfinished tasks past their due date
+    pub fn overdue_only(mut self) -> Self {
+        self.overdue = true;
+        self
+    }
+
+    /// Filter to tasks created at or after `time`
+    pub fn created_after(mut self, time: DateTime<Utc>) -> Self {
+        self.created_after = Some(time);
+        self
+    }
+
+    /// Filter to tasks created before `time`
+    pub fn created_before(mut self, time: DateTime<Utc>) -> Self {
+        self.created_before = Some(time);
+        self
+    }
+
+    /// Filter to tasks last changed at or after `time`
+    pub fn updated_after(mut self, time: DateTime<Utc>) -> Self {
+        self.updated_after = Some(time);
+        self
+    }
+
+    /// Include soft-deleted tasks in the results
+    pub fn include_deleted(mut self) -> Self {
+        self.include_deleted = true;
+        self
+    }
+
+    /// Sort by these keys, in order of precedence
+    pub fn with_sort(mut self, keys: impl IntoIterator<Item = SortKey>) -> Self {
+        self.sort = keys.into_iter().collect();
+        self
+    }
+
+    /// Reverse the listing order
+    pub fn with_reverse(mut self, reverse: bool) -> Self {
+        self.reverse = reverse;
+        self
+    }
+
+    /// Return at most `limit` tasks
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Skip the first `offset` matching tasks
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// `LIMIT`/`START` clause for the page this filter selects, if any
+    fn pagination_clause(&self) -> Option<String> {
+        match (self.limit, self.offset) {
+            (Some(limit), offset) => Some(format!("LIMIT {} START {}", limit, offset)),
+            (None, 0) => None,
+            (None, offset) => Some(format!("START {}", offset)),
+        }
+    }
+
+    /// Whether some filters run in memory after the query, so the page
+    /// must be cut in memory too
+    fn filters_in_memory(&self) -> bool {
+        self.children_of.is_some() || self.title_regex.is_some()
+    }
+
+    /// Apply `offset` and `limit` to tasks that were filtered in memory
+    fn paginate(&self, tasks: Vec<TaskSummary>) -> Vec<TaskSummary> {
+        tasks
+            .into_iter()
+            .skip(self.offset)
+            .take(self.limit.unwrap_or(usize::MAX))
+            .collect()
+    }
+
+    /// Fields selected for the listing, including any the sort keys need
+    fn select_fields(&self) -> String {
+        let mut fields = LIST_FIELDS.to_string();
+        for field in self.sort.iter().filter_map(SortKey::select_field) {
+            if !fields.contains(field) {
+                fields.push_str(", ");
+                fields.push_str(field);
+            }
+        }
+        fields
+    }
+
+    /// ORDER BY clause for the listing.
+    ///
+    /// Newest first and then `id` break ties, so the order is the same on
+    /// every run and paging stays deterministic.
+    fn order_by(&self) -> String {
+        let mut terms: Vec<String> = Vec::new();
+        for key in &self.sort {
+            let term = key.order_term(self.reverse);
+            if !terms.contains(&term) {
+                terms.push(term);
+            }
+        }
+        if !self.sort.contains(&SortKey::Created) {
+            terms.push(SortKey::Created.order_term(self.reverse));
+        }
+        terms.push(order_term("id", self.reverse));
+        terms.join(", ")
+    }
+
     /// Check if this filter has any structural constraints (root or children_of)
     #[allow(dead_code)] // Useful for future optimizations and tests
     fn has_structural_filter(&self) -> bool {
@@ -198,18 +566,40 @@ impl TaskFilter {
     }
 }
 
+/// Fields selected for task listings
+const LIST_FIELDS: &str =
+    "id, title, level, status, priority, tags, needs_human_review, created_at, updated_at";
+
+/// Numeric priority for sorting, since the stored names don't sort by rank
+const PRIORITY_RANK_FIELD: &str = r#"(IF priority = "critical" THEN 4 ELSE IF priority = "high" THEN 3 ELSE IF priority = "medium" THEN 2 ELSE IF priority = "low" THEN 1 ELSE 0 END) AS priority_rank"#;
+
+/// Position of the status in the workflow, for sorting
+const STATUS_RANK_FIELD: &str = r#"(IF status = "backlog" THEN 0 ELSE IF status = "todo" THEN 1 ELSE IF status = "in_progress" THEN 2 ELSE IF status = "pending_review" THEN 3 ELSE IF status = "done" THEN 4 ELSE 5 END) AS status_rank"#;
+
+/// Matches tasks whose blockers are all done, including tasks with none
+const UNBLOCKED_CONDITION: &str =
+    r#"array::len(->depends_on->task[WHERE status != "done" AND deleted_at = NONE]) = 0"#;
+
+/// Condition matching tasks that are not in the trash
+const NOT_DELETED: &str = "deleted_at = NONE";
+
+/// Default number of rows fetched per page when streaming listings
+pub const DEFAULT_PAGE_SIZE: usize = 500;
+
 /// Repository for listing tasks with filters
 ///
 /// Provides methods to query tasks from the database with various
 /// filter criteria applied.
 pub struct TaskLister<'a> {
-    client: &'a Surreal<Db>,
+    client: RetryClient<'a>,
 }
 
 impl<'a> TaskLister<'a> {
     /// Create a new TaskLister with the given database client
-    pub fn new(client: &'a Surreal<Db>) -> Self {
-        Self { client }
+    pub fn new(client: impl Into<RetryClient<'a>>) -> Self {
+        Self {
+            client: client.into(),
+        }
     }
 
     /// List tasks matching the given filter
@@ -226,46 +616,180 @@ impl<'a> TaskLister<'a> {
     ///
     /// Returns `DbError::Query` if the database query fails.
     pub async fn list(&self, filter: &TaskFilter) -> DbResult<Vec<TaskSummary>> {
+        let query = self.build_paged_query(filter);
+
+        let mut result = self.client.query(&query).await?;
+        let rows: Vec<TaskRow> = result.take(0)?;
+
+        let tasks: Vec<TaskSummary> = rows.into_iter().map(|r| r.into_summary()).collect();
+
+        // Children queries only push the search down to SQL; the rest is
+        // applied in memory (preserves sort order from SQL), and so is
+        // the page
+        let tasks = self.apply_memory_filters(tasks, filter);
+        if filter.filters_in_memory() {
+            return Ok(filter.paginate(tasks));
+        }
+
+        Ok(tasks)
+    }
+
+    /// Count every task matching the filter, ignoring `limit` and `offset`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError::Query` if the database query fails.
+    pub async fn count(&self, filter: &TaskFilter) -> DbResult<usize> {
+        let unpaged = TaskFilter {
+            limit: None,
+            offset: 0,
+            ..filter.clone()
+        };
+        // Some filters only run in memory, so count the listing
+        if filter.filters_in_memory() {
+            return Ok(self.list(&unpaged).await?.len());
+        }
+
+        #[derive(Debug, Deserialize)]
+        struct CountRow {
+            count: usize,
+        }
+
+        let query = format!(
+            "SELECT count() AS count FROM ({}) GROUP ALL",
+            self.build_list_query(&unpaged)
+        );
+        let mut result = self.client.query(&query).await?;
+        let rows: Vec<CountRow> = result.take(0)?;
+
+        Ok(rows.first().map_or(0, |row| row.count))
+    }
+
+    /// Every distinct tag with the number of live tasks carrying it.
+    ///
+    /// Sorted by descending count, then alphabetically. Tasks in the trash
+    /// are not counted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError::Query` if the database query fails.
+    pub async fn tag_counts(&self) -> DbResult<Vec<(String, usize)>> {
+        #[derive(Debug, Deserialize)]
+        struct TagRow {
+            tag: String,
+            count: usize,
+        }
+
+        // Unnest in a subquery so the grouping sees one row per tag
+        let query = format!(
+            "SELECT tag, count() AS count \
+             FROM (SELECT tags AS tag FROM task WHERE {} SPLIT tag) \
+             WHERE tag != NONE GROUP BY tag",
+            NOT_DELETED
+        );
+        let mut result = self.client.query(&query).await?;
+        let rows: Vec<TagRow> = result.take(0)?;
+
+        let mut counts: Vec<(String, usize)> =
+            rows.into_iter().map(|row| (row.tag, row.count)).collect();
+        counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        Ok(counts)
+    }
+
+    /// Stream tasks matching the given filter page by page.
+    ///
+    /// Produces the same rows in the same order as [`TaskLister::list`], but
+    /// fetches them in pages of `page_size` rows so callers can process
+    /// large result sets without holding them all in memory.
+    ///
+    /// # Arguments
+    ///
+    /// * `filter` - The filter criteria to apply
+    /// * `page_size` - Maximum number of rows fetched per query (minimum 1)
+    ///
+    /// # Returns
+    ///
+    /// A `TaskStream` cursor; call `next_page` until it returns `None`.
+    pub fn stream(&self, filter: &TaskFilter, page_size: usize) -> TaskStream<'a> {
+        // Rows filtered in memory can only have their offset skipped after
+        // filtering
+        let (offset, skip) = if filter.filters_in_memory() {
+            (0, filter.offset)
+        } else {
+            (filter.offset, 0)
+        };
+        TaskStream {
+            client: self.client,
+            query: self.build_list_query(filter),
+            filter: filter.clone(),
+            page_size: page_size.max(1),
+            offset,
+            skip,
+            remaining: filter.limit,
+            exhausted: false,
+        }
+    }
+
+    /// Return the SurrealQL that `list` would run for `filter`, without running it.
+    ///
+    /// `stream` runs the same statement with `LIMIT`/`START` set per page.
+    /// Filters that `list` applies in memory (for `children_of`, and the
+    /// title regex) are not part
+    /// of the statement, and neither is their `limit`/`offset`.
+    pub fn explain(&self, filter: &TaskFilter) -> String {
+        self.build_paged_query(filter)
+    }
+
+    /// The listing statement with the filter's `LIMIT`/`START` appended,
+    /// except for listings filtered in memory, which are paged there.
+    fn build_paged_query(&self, filter: &TaskFilter) -> String {
+        let query = self.build_list_query(filter);
+        match filter.pagination_clause() {
+            Some(clause) if !filter.filters_in_memory() => format!("{} {}", query, clause),
+            _ => query,
+        }
+    }
+
+    /// Build the SELECT statement used by `list` and `stream`.
+    ///
+    /// Dispatches on the structural filters: children (graph traversal),
+    /// root-only, or the standard filtered query.
+    fn build_list_query(&self, filter: &TaskFilter) -> String {
         // Handle children_of filter separately (uses graph traversal)
         if let Some(parent_id) = &filter.children_of {
-            return self.query_children(parent_id, filter).await;
+            return self.build_children_query(parent_id, filter);
         }
 
         // Handle root filter separately
         if filter.root_only {
-            return self.query_root_tasks(filter).await;
+            return self.build_root_query(filter);
         }
 
-        // Build and execute the standard query
-        self.query_tasks(filter).await
+        self.build_tasks_query(filter)
     }
 
-    /// Query tasks with standard filters
-    async fn query_tasks(&self, filter: &TaskFilter) -> DbResult<Vec<TaskSummary>> {
+    /// Build the query for tasks with standard filters
+    fn build_tasks_query(&self, filter: &TaskFilter) -> String {
         let conditions = self.build_filter_conditions(filter);
 
-        let query = if conditions.is_empty() {
-            "SELECT id, title, level, status, priority, tags, needs_human_review, created_at FROM task ORDER BY created_at DESC"
-                .to_string()
+        if conditions.is_empty() {
+            format!(
+                "SELECT {} FROM task ORDER BY {}",
+                filter.select_fields(),
+                filter.order_by()
+            )
         } else {
             format!(
-                "SELECT id, title, level, status, priority, tags, needs_human_review, created_at FROM task WHERE {} ORDER BY created_at DESC",
-                conditions.join(" AND ")
+                "SELECT {} FROM task WHERE {} ORDER BY {}",
+                filter.select_fields(),
+                conditions.join(" AND "),
+                filter.order_by()
             )
-        };
-
-        let mut result = self.client.query(&query).await?;
-        let rows: Vec<TaskRow> = result.take(0)?;
-
-        Ok(rows.into_iter().map(|r| r.into_summary()).collect())
+        }
     }
 
-    /// Query children of a specific task using graph traversal
-    async fn query_children(
-        &self,
-        parent_id: &str,
-        filter: &TaskFilter,
-    ) -> DbResult<Vec<TaskSummary>> {
+    /// Build the query for children of a specific task using graph traversal
+    fn build_children_query(&self, parent_id: &str, filter: &TaskFilter) -> String {
         // Build query with graph traversal condition plus search filter at SQL level
         let mut conditions = vec![format!("->child_of->task CONTAINS task:{}", parent_id)];
 
@@ -274,36 +798,30 @@ impl<'a> TaskLister<'a> {
             conditions.push(Self::build_search_condition(search));
         }
 
-        let query = format!(
-            "SELECT id, title, level, status, priority, tags, needs_human_review, created_at FROM task WHERE {} ORDER BY created_at DESC",
-            conditions.join(" AND ")
-        );
-
-        let mut result = self.client.query(&query).await?;
-        let rows: Vec<TaskRow> = result.take(0)?;
-
-        let tasks: Vec<TaskSummary> = rows.into_iter().map(|r| r.into_summary()).collect();
+        // Sections, refs and due dates aren't in TaskSummary either
+        conditions.extend(Self::build_detail_conditions(filter));
 
-        // Apply post-filters for other criteria (preserves sort order from SQL)
-        Ok(self.apply_post_filters(tasks, filter))
+        format!(
+            "SELECT {} FROM task WHERE {} ORDER BY {}",
+            filter.select_fields(),
+            conditions.join(" AND "),
+            filter.order_by()
+        )
     }
 
-    /// Query root tasks (tasks with no parent)
-    async fn query_root_tasks(&self, filter: &TaskFilter) -> DbResult<Vec<TaskSummary>> {
+    /// Build the query for root tasks (tasks with no parent)
+    fn build_root_query(&self, filter: &TaskFilter) -> String {
         let mut conditions = vec!["array::len(->child_of->task) = 0".to_string()];
 
         // Add other filter conditions
         conditions.extend(self.build_filter_conditions(filter));
 
-        let query = format!(
-            "SELECT id, title, level, status, priority, tags, needs_human_review, created_at FROM task WHERE {} ORDER BY created_at DESC",
-            conditions.join(" AND ")
-        );
-
-        let mut result = self.client.query(&query).await?;
-        let rows: Vec<TaskRow> = result.take(0)?;
-
-        Ok(rows.into_iter().map(|r| r.into_summary()).collect())
+        format!(
+            "SELECT {} FROM task WHERE {} ORDER BY {}",
+            filter.select_fields(),
+            conditions.join(" AND "),
+            filter.order_by()
+        )
     }
 
     /// Build filter condition strings for the WHERE clause
@@ -345,14 +863,17 @@ impl<'a> TaskLister<'a> {
             conditions.push(format!("({})", priority_conditions.join(" OR ")));
         }
 
-        // Tag filter (OR within type - task must have at least one matching tag)
+        // Tag filter (any or all of the tags, per tag_mode)
         if !filter.tags.is_empty() {
             let tag_conditions: Vec<String> = filter
                 .tags
                 .iter()
                 .map(|t| format!("\"{}\" IN tags", t.replace('\"', "\\\"")))
                 .collect();
-            conditions.push(format!("({})", tag_conditions.join(" OR ")));
+            conditions.push(format!(
+                "({})",
+                tag_conditions.join(filter.tag_mode.joiner())
+            ));
         }
 
         // Search filter (case-insensitive, searches title and description)
@@ -360,6 +881,70 @@ impl<'a> TaskLister<'a> {
             conditions.push(Self::build_search_condition(search));
         }
 
+        conditions.extend(Self::build_detail_conditions(filter));
+
+        conditions
+    }
+
+    /// Build conditions on fields not carried by `TaskSummary`: the embedded
+    /// `sections` and `refs` arrays, the due date and the trash.
+    ///
+    /// Missing arrays (records written before the fields had defaults) are
+    /// treated as empty.
+    fn build_detail_conditions(filter: &TaskFilter) -> Vec<String> {
+        let mut conditions = Vec::new();
+
+        if filter.no_sections {
+            conditions.push("array::len(sections ?? []) = 0".to_string());
+        }
+
+        // Section type filter (OR within type)
+        if !filter.section_types.is_empty() {
+            let section_conditions: Vec<String> = filter
+                .section_types
+                .iter()
+                .map(|t| format!("\"{}\" IN (sections ?? []).type", t.as_str()))
+                .collect();
+            conditions.push(format!("({})", section_conditions.join(" OR ")));
+        }
+
+        match filter.has_refs {
+            Some(true) => conditions.push("array::len(refs ?? []) > 0".to_string()),
+            Some(false) => conditions.push("array::len(refs ?? []) = 0".to_string()),
+            None => {}
+        }
+
+        if !filter.include_deleted {
+            conditions.push(NOT_DELETED.to_string());
+        }
+
+        // Tasks without a due date compare as NONE and never match
+        if filter.overdue {
+            conditions.push(
+                "(due_at != NONE AND due_at < time::now() \
+                 AND status != \"done\" AND status != \"rejected\")"
+                    .to_string(),
+            );
+        }
+
+        // Tasks without the timestamp compare as NONE and never match
+        let bounds = [
+            ("created_at", ">=", filter.created_after),
+            ("created_at", "<", filter.created_before),
+            ("updated_at", ">=", filter.updated_after),
+        ];
+        for (field, op, time) in bounds {
+            if let Some(time) = time {
+                conditions.push(format!(
+                    "({} != NONE AND {} {} d'{}')",
+                    field,
+                    field,
+                    op,
+                    time.to_rfc3339()
+                ));
+            }
+        }
+
         conditions
     }
 
@@ -435,9 +1020,11 @@ impl<'a> TaskLister<'a> {
                 tags,
                 needs_human_review,
                 created_at,
+                updated_at,
                 (->child_of->task)[0].id AS parent_id,
                 (->child_of->task)[0].status AS parent_status
             FROM task
+            WHERE deleted_at = NONE
             ORDER BY created_at DESC
         "#;
 
@@ -452,8 +1039,10 @@ impl<'a> TaskLister<'a> {
             tags: Vec<String>,
             #[serde(default)]
             needs_human_review: Option<bool>,
-            #[allow(dead_code)]
-            created_at: surrealdb::sql::Datetime,
+            #[serde(default)]
+            created_at: Option<DateTime<Utc>>,
+            #[serde(default)]
+            updated_at: Option<DateTime<Utc>>,
             parent_id: Option<surrealdb::sql::Thing>,
             parent_status: Option<String>,
         }
@@ -462,7 +1051,8 @@ impl<'a> TaskLister<'a> {
         let all_tasks: Vec<TaskWithParent> = result.take(0)?;
 
         // Query 2: Get all child_of relationships to build parent->children map
-        let children_query = "SELECT in.id AS child_id, out.id AS parent_id FROM child_of";
+        let children_query = "SELECT in.id AS child_id, out.id AS parent_id FROM child_of \
+                              WHERE in.deleted_at = NONE";
 
         #[derive(Debug, Deserialize)]
         struct ChildOfRow {
@@ -477,7 +1067,7 @@ impl<'a> TaskLister<'a> {
         let blockers_query = r#"
             SELECT in.id AS dependent_id, out.id AS blocker_id
             FROM depends_on
-            WHERE out.status != "done"
+            WHERE out.status != "done" AND out.deleted_at = NONE
         "#;
 
         #[derive(Debug, Deserialize)]
@@ -594,10 +1184,13 @@ impl<'a> TaskLister<'a> {
                 priority: task.priority.map(|p| parse_priority(&p)),
                 tags: task.tags,
                 needs_human_review: task.needs_human_review,
+                created_at: task.created_at,
+                updated_at: task.updated_at,
             })
             .collect();
 
-        // Sort by level priority: epic > ticket > task
+        // Sort by level priority: epic > ticket > task, then highest priority
+        // first within a level (tasks without a priority come last)
         ready_tasks.sort_by(|a, b| {
             let level_priority = |level: &Level| -> u8 {
                 match level {
@@ -606,12 +1199,205 @@ impl<'a> TaskLister<'a> {
                     Level::Task => 2,
                 }
             };
-            level_priority(&a.level).cmp(&level_priority(&b.level))
+            level_priority(&a.level)
+                .cmp(&level_priority(&b.level))
+                .then_with(|| Priority::cmp_optional(b.priority.as_ref(), a.priority.as_ref()))
         });
 
         Ok(ready_tasks)
     }
 
+    /// List every task at `status` that has no incomplete blockers.
+    ///
+    /// Unlike [`TaskLister::list_ready`] this ignores the hierarchy: each
+    /// unblocked task is listed, not just the highest entry point. A task
+    /// with no dependencies at all is always unblocked. Highest priority
+    /// comes first, then newest.
+    ///
+    /// # Arguments
+    ///
+    /// * `status` - The status to filter by (typically Todo)
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError::Query` if the database query fails.
+    pub async fn list_unblocked(&self, status: Status) -> DbResult<Vec<TaskSummary>> {
+        let query = format!(
+            r#"SELECT {}, {} FROM task
+               WHERE status = "{}" AND {} AND {}
+               ORDER BY priority_rank DESC, created_at DESC, id ASC"#,
+            LIST_FIELDS,
+            PRIORITY_RANK_FIELD,
+            status.as_str(),
+            UNBLOCKED_CONDITION,
+            NOT_DELETED
+        );
+
+        let mut result = self.client.query(&query).await?;
+        let rows: Vec<TaskRow> = result.take(0)?;
+
+        Ok(rows.into_iter().map(|r| r.into_summary()).collect())
+    }
+
+    /// Pick the single unblocked task at `status` to work on next.
+    ///
+    /// Candidates are the tasks [`TaskLister::list_unblocked`] returns. The
+    /// highest priority wins; ties go to the most recently updated task and
+    /// then to the shortest title.
+    ///
+    /// # Arguments
+    ///
+    /// * `status` - The status to filter by (typically Todo)
+    ///
+    /// # Returns
+    ///
+    /// The chosen task, or `None` if no task at `status` is unblocked.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError::Query` if the database query fails.
+    pub async fn next_unblocked(&self, status: Status) -> DbResult<Option<TaskSummary>> {
+        let query = format!(
+            r#"SELECT {}, {}, string::len(title) AS title_len FROM task
+               WHERE status = "{}" AND {} AND {}
+               ORDER BY priority_rank DESC, updated_at DESC, title_len ASC, id ASC
+               LIMIT 1"#,
+            LIST_FIELDS,
+            PRIORITY_RANK_FIELD,
+            status.as_str(),
+            UNBLOCKED_CONDITION,
+            NOT_DELETED
+        );
+
+        let mut result = self.client.query(&query).await?;
+        let rows: Vec<TaskRow> = result.take(0)?;
+
+        Ok(rows.into_iter().next().map(|r| r.into_summary()))
+    }
+
+    /// Search titles, descriptions and section content, best matches first.
+    ///
+    /// Matching is case-insensitive. A title match ranks above a description
+    /// match, which ranks above a section match; within a field, earlier
+    /// matches rank higher. Tasks in the trash are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError::ValidationError` if the query is blank, or
+    /// `DbError::Query` if the database query fails.
+    pub async fn search_ranked(&self, query: &str) -> DbResult<Vec<SearchHit>> {
+        #[derive(Debug, Deserialize)]
+        struct SectionText {
+            content: String,
+        }
+
+        // TaskRow plus the searched text; serde's flatten can't read Things
+        #[derive(Debug, Deserialize)]
+        struct SearchRow {
+            id: surrealdb::sql::Thing,
+            title: String,
+            level: String,
+            status: String,
+            priority: Option<String>,
+            #[serde(default)]
+            tags: Vec<String>,
+            #[serde(default)]
+            needs_human_review: Option<bool>,
+            #[serde(default)]
+            created_at: Option<DateTime<Utc>>,
+            #[serde(default)]
+            updated_at: Option<DateTime<Utc>>,
+            description: Option<String>,
+            #[serde(default)]
+            sections: Vec<SectionText>,
+        }
+
+        let query = query.trim();
+        if query.is_empty() {
+            return Err(DbError::ValidationError {
+                message: "Search query cannot be empty".to_string(),
+            });
+        }
+
+        // Narrow down in SQL, then rank (and re-check) in Rust
+        let sql = format!(
+            r#"SELECT {}, description, sections FROM task
+               WHERE {} AND (
+                   string::lowercase(title) CONTAINS $query
+                   OR string::lowercase(description ?? "") CONTAINS $query
+                   OR sections[WHERE string::lowercase(content) CONTAINS $query] != []
+               )"#,
+            LIST_FIELDS, NOT_DELETED
+        );
+        let mut result = self
+            .client
+            .query(&sql)
+            .bind(("query", query.to_lowercase()))
+            .await?;
+        let rows: Vec<SearchRow> = result.take(0)?;
+
+        let mut hits: Vec<SearchHit> = rows
+            .into_iter()
+            .filter_map(|row| {
+                let description = row.description.unwrap_or_default();
+                let fields = [(SearchField::Title, row.title.as_str())]
+                    .into_iter()
+                    .chain([(SearchField::Description, description.as_str())])
+                    .chain(
+                        row.sections
+                            .iter()
+                            .map(|s| (SearchField::Section, s.content.as_str())),
+                    );
+                let (field, snippet, matched) = fields
+                    .filter_map(|(field, text)| {
+                        let (snippet, matched) = matched_line(text, find_ignore_case(text, query)?);
+                        Some((field, snippet, matched))
+                    })
+                    .min_by_key(|(field, _, matched)| (*field, matched.start))?;
+                let task = TaskRow {
+                    id: row.id,
+                    title: row.title,
+                    level: row.level,
+                    status: row.status,
+                    priority: row.priority,
+                    tags: row.tags,
+                    needs_human_review: row.needs_human_review,
+                    created_at: row.created_at,
+                    updated_at: row.updated_at,
+                };
+                Some(SearchHit {
+                    task: task.into_summary(),
+                    field,
+                    snippet,
+                    matched,
+                })
+            })
+            .collect();
+
+        hits.sort_by(|a, b| {
+            (a.field, a.matched.start, &a.task.id).cmp(&(b.field, b.matched.start, &b.task.id))
+        });
+        Ok(hits)
+    }
+
+    /// Apply the filters SQL couldn't: everything for children queries, and
+    /// the title regex for any query.
+    fn apply_memory_filters(
+        &self,
+        tasks: Vec<TaskSummary>,
+        filter: &TaskFilter,
+    ) -> Vec<TaskSummary> {
+        let mut tasks = if filter.children_of.is_some() {
+            self.apply_post_filters(tasks, filter)
+        } else {
+            tasks
+        };
+        if let Some(regex) = &filter.title_regex {
+            tasks.retain(|task| regex.is_match(&task.title));
+        }
+        tasks
+    }
+
     /// Apply post-query filters (used for children query where we can't use all SQL filters)
     fn apply_post_filters(&self, tasks: Vec<TaskSummary>, filter: &TaskFilter) -> Vec<TaskSummary> {
         tasks
@@ -646,7 +1432,7 @@ impl<'a> TaskLister<'a> {
                 }
 
                 // Filter by tags if specified
-                if !filter.tags.is_empty() && !filter.tags.iter().any(|t| task.tags.contains(t)) {
+                if !filter.tags.is_empty() && !filter.tag_mode.matches(&filter.tags, &task.tags) {
                     return false;
                 }
 
@@ -656,6 +1442,71 @@ impl<'a> TaskLister<'a> {
     }
 }
 
+/// Page-by-page cursor over a task listing.
+///
+/// Created by [`TaskLister::stream`]. Each call to `next_page` runs one
+/// `LIMIT`/`START` query, so at most one page of rows is held at a time.
+pub struct TaskStream<'a> {
+    client: RetryClient<'a>,
+    query: String,
+    filter: TaskFilter,
+    page_size: usize,
+    offset: usize,
+    /// Filtered rows still to drop before the first returned task
+    skip: usize,
+    /// Tasks still to return, when the filter has a limit
+    remaining: Option<usize>,
+    exhausted: bool,
+}
+
+impl TaskStream<'_> {
+    /// Fetch the next page of tasks.
+    ///
+    /// A page may hold fewer than `page_size` tasks (or none) when in-memory
+    /// filters drop rows from a children listing; only `None` marks the end.
+    ///
+    /// # Returns
+    ///
+    /// `Some(tasks)` for the next page, or `None` once all rows were read.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError::Query` if the database query fails.
+    pub async fn next_page(&mut self) -> DbResult<Option<Vec<TaskSummary>>> {
+        if self.exhausted || self.remaining == Some(0) {
+            return Ok(None);
+        }
+
+        let query = format!(
+            "{} LIMIT {} START {}",
+            self.query, self.page_size, self.offset
+        );
+        let mut result = self.client.query(&query).await?;
+        let rows: Vec<TaskRow> = result.take(0)?;
+
+        if rows.len() < self.page_size {
+            self.exhausted = true;
+        }
+        if rows.is_empty() {
+            return Ok(None);
+        }
+        self.offset += rows.len();
+
+        let tasks: Vec<TaskSummary> = rows.into_iter().map(|r| r.into_summary()).collect();
+        let mut tasks = TaskLister::new(self.client).apply_memory_filters(tasks, &self.filter);
+
+        let skipped = self.skip.min(tasks.len());
+        tasks.drain(..skipped);
+        self.skip -= skipped;
+        if let Some(remaining) = self.remaining.as_mut() {
+            tasks.truncate(*remaining);
+            *remaining -= tasks.len();
+        }
+
+        Ok(Some(tasks))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -890,6 +1741,8 @@ mod tests {
             priority: Some(Priority::High),
             tags: vec!["backend".to_string()],
             needs_human_review: Some(true),
+            created_at: None,
+            updated_at: None,
         };
 
         let cloned = summary.clone();
@@ -906,6 +1759,8 @@ mod tests {
             priority: Some(Priority::High),
             tags: vec!["backend".to_string()],
             needs_human_review: None,
+            created_at: None,
+            updated_at: None,
         };
 
         let debug_str = format!("{:?}", summary);
@@ -924,6 +1779,8 @@ mod tests {
             priority: None,
             tags: vec![],
             needs_human_review: None,
+            created_at: None,
+            updated_at: None,
         };
 
         let summary2 = TaskSummary {
@@ -934,6 +1791,8 @@ mod tests {
             priority: None,
             tags: vec![],
             needs_human_review: None,
+            created_at: None,
+            updated_at: None,
         };
 
         assert_eq!(summary1, summary2);
@@ -1344,6 +2203,146 @@ mod tests {
         cleanup(&temp_dir);
     }
 
+    #[tokio::test]
+    async fn test_list_tag_mode_any_vs_all() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        create_task(&db, "parent", "Parent", "epic", "todo", None, &[]).await;
+        create_task(&db, "both", "Both", "task", "todo", None, &["a", "b"]).await;
+        create_task(&db, "only_a", "Only A", "task", "todo", None, &["a"]).await;
+        create_child_of(&db, "both", "parent").await;
+        create_child_of(&db, "only_a", "parent").await;
+
+        let lister = TaskLister::new(db.client());
+        let any = TaskFilter::new().with_tags(["a", "b"]);
+        assert_eq!(any.tag_mode, TagMode::Any);
+        let all = any.clone().with_tag_mode(TagMode::All);
+
+        // In SQL, and in memory for children listings
+        for (any, all) in [
+            (any.clone(), all.clone()),
+            (any.children_of("parent"), all.children_of("parent")),
+        ] {
+            assert_eq!(
+                ids(&lister.list(&any).await.unwrap()),
+                vec!["both", "only_a"]
+            );
+            assert_eq!(ids(&lister.list(&all).await.unwrap()), vec!["both"]);
+        }
+
+        cleanup(&temp_dir);
+    }
+
+    #[test]
+    fn test_tag_mode_parse() {
+        assert_eq!(TagMode::parse("any"), Some(TagMode::Any));
+        assert_eq!(TagMode::parse("all"), Some(TagMode::All));
+        assert_eq!(TagMode::parse("both"), None);
+        assert_eq!(TagMode::default(), TagMode::Any);
+    }
+
+    #[tokio::test]
+    async fn test_list_title_regex_filters_in_memory() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        create_task(&db, "t1", "Fix login", "task", "todo", None, &[]).await;
+        create_task(&db, "t2", "Fix logout", "task", "todo", None, &[]).await;
+        create_task(&db, "t3", "Refix parser", "task", "todo", None, &[]).await;
+        create_task(&db, "t4", "Docs", "task", "todo", None, &[]).await;
+
+        let lister = TaskLister::new(db.client());
+        let anchored = TaskFilter::new().with_title_regex(Regex::new("^Fix").unwrap());
+        assert_eq!(
+            ids(&lister.list(&anchored).await.unwrap()),
+            vec!["t1", "t2"]
+        );
+        assert_eq!(lister.count(&anchored).await.unwrap(), 2);
+
+        // The page is cut after the regex, not before
+        let paged = anchored
+            .clone()
+            .with_sort([SortKey::Title])
+            .with_limit(1)
+            .with_offset(1);
+        assert_eq!(ids(&lister.list(&paged).await.unwrap()), vec!["t2"]);
+        assert!(!lister.explain(&paged).contains("LIMIT"));
+
+        let none = TaskFilter::new().with_title_regex(Regex::new(r"^\d+$").unwrap());
+        assert!(lister.list(&none).await.unwrap().is_empty());
+        assert_eq!(lister.count(&none).await.unwrap(), 0);
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_search_ranked_orders_title_description_section() {
+        let (db, temp_dir) = setup_test_db().await;
+        db.client()
+            .query(
+                r#"CREATE task:in_section SET title = "Deploy", level = "task", status = "todo",
+                       sections = [{ type: "step", content: "Rotate the LOGIN keys" }];
+                   CREATE task:in_desc SET title = "Auth", level = "task", status = "todo",
+                       description = "First line\nHandles Login retries";
+                   CREATE task:late_title SET title = "Fix the login page", level = "task",
+                       status = "todo";
+                   CREATE task:early_title SET title = "Login form", level = "task",
+                       status = "done";
+                   CREATE task:gone SET title = "Login gone", level = "task", status = "todo",
+                       deleted_at = time::now();
+                   CREATE task:other SET title = "Unrelated", level = "task", status = "todo""#,
+            )
+            .await
+            .unwrap();
+
+        let lister = TaskLister::new(db.client());
+        let hits = lister.search_ranked("  login ").await.unwrap();
+        let ids: Vec<&str> = hits.iter().map(|h| h.task.id.as_str()).collect();
+        assert_eq!(
+            ids,
+            vec!["early_title", "late_title", "in_desc", "in_section"]
+        );
+
+        let fields: Vec<SearchField> = hits.iter().map(|h| h.field).collect();
+        assert_eq!(
+            fields,
+            vec![
+                SearchField::Title,
+                SearchField::Title,
+                SearchField::Description,
+                SearchField::Section
+            ]
+        );
+        // Snippets are the matching line, with the match located in it
+        assert_eq!(hits[2].snippet, "Handles Login retries");
+        assert_eq!(&hits[2].snippet[hits[2].matched.clone()], "Login");
+        assert_eq!(&hits[3].snippet[hits[3].matched.clone()], "LOGIN");
+
+        assert!(
+            lister
+                .search_ranked("nothing here")
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        assert!(matches!(
+            lister.search_ranked("   ").await,
+            Err(DbError::ValidationError { .. })
+        ));
+
+        cleanup(&temp_dir);
+    }
+
+    #[test]
+    fn test_find_ignore_case_maps_back_to_original_bytes() {
+        assert_eq!(find_ignore_case("Hello World", "WORLD"), Some(6..11));
+        // 'İ' lowercases to two chars, shifting the lowered text
+        let text = "İstanbul café";
+        let range = find_ignore_case(text, "CAFÉ").unwrap();
+        assert_eq!(&text[range], "café");
+        assert_eq!(find_ignore_case("abc", ""), None);
+        assert_eq!(find_ignore_case("abc", "d"), None);
+    }
+
     #[tokio::test]
     async fn test_list_children_with_tag_filter() {
         let (db, temp_dir) = setup_test_db().await;
@@ -1438,6 +2437,22 @@ mod tests {
         assert!(condition.contains("string::lowercase(description ?? \"\")"));
     }
 
+    #[tokio::test]
+    async fn test_explain_returns_list_query() {
+        let (db, temp_dir) = setup_test_db().await;
+        let lister = TaskLister::new(db.client());
+
+        let query = lister.explain(&TaskFilter::new().with_status(Status::Todo));
+        assert!(query.starts_with("SELECT "));
+        assert!(query.contains("status = \"todo\""));
+        assert!(query.contains("ORDER BY created_at DESC, id ASC"));
+
+        let query = lister.explain(&TaskFilter::new().root_only());
+        assert!(query.contains("child_of"));
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
     #[test]
     fn test_task_filter_with_search() {
         let filter = TaskFilter::new().with_search("test query");
@@ -1674,6 +2689,146 @@ mod tests {
         db.client().query(&query).await.unwrap();
     }
 
+    #[tokio::test]
+    async fn test_list_sorted_by_priority_then_updated() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        let tasks = [
+            ("low_recent", "low", "2024-03-05T00:00:00Z"),
+            ("high_old", "high", "2024-03-01T00:00:00Z"),
+            ("none_recent", "NONE", "2024-03-09T00:00:00Z"),
+            ("high_recent", "high", "2024-03-04T00:00:00Z"),
+            ("critical", "critical", "2024-02-01T00:00:00Z"),
+        ];
+        for (id, priority, updated) in tasks {
+            let priority = if priority == "NONE" {
+                priority.to_string()
+            } else {
+                format!("\"{}\"", priority)
+            };
+            let query = format!(
+                r#"CREATE task:{} SET title = "{}", level = "task", status = "todo",
+                   priority = {}, updated_at = d'{}'"#,
+                id, id, priority, updated
+            );
+            db.client().query(&query).await.unwrap();
+        }
+
+        let lister = TaskLister::new(db.client());
+        let filter = TaskFilter::new().with_sort([SortKey::Priority, SortKey::Updated]);
+        let ids: Vec<String> = lister
+            .list(&filter)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|t| t.id)
+            .collect();
+        assert_eq!(
+            ids,
+            vec![
+                "critical",
+                "high_recent",
+                "high_old",
+                "low_recent",
+                "none_recent"
+            ]
+        );
+
+        // Updated alone puts the most recently touched task first
+        let filter = TaskFilter::new().with_sort([SortKey::Updated]);
+        let first = lister.list(&filter).await.unwrap();
+        assert_eq!(first[0].id, "none_recent");
+
+        cleanup(&temp_dir);
+    }
+
+    #[test]
+    fn test_sort_order_by_clause() {
+        assert_eq!(TaskFilter::new().order_by(), "created_at DESC, id ASC");
+        assert_eq!(
+            TaskFilter::new()
+                .with_sort([SortKey::Priority, SortKey::Updated])
+                .order_by(),
+            "priority_rank DESC, updated_at DESC, created_at DESC, id ASC"
+        );
+        assert_eq!(
+            TaskFilter::new().with_sort([SortKey::Created]).order_by(),
+            "created_at DESC, id ASC"
+        );
+        assert!(
+            TaskFilter::new()
+                .with_sort([SortKey::Priority])
+                .select_fields()
+                .ends_with("AS priority_rank")
+        );
+        assert_eq!(SortKey::parse("updated"), Some(SortKey::Updated));
+        assert_eq!(SortKey::parse("title"), Some(SortKey::Title));
+        assert_eq!(SortKey::parse("assignee"), None);
+    }
+
+    #[test]
+    fn test_sort_reverse_flips_every_term() {
+        assert_eq!(
+            TaskFilter::new().with_reverse(true).order_by(),
+            "created_at ASC, id DESC"
+        );
+        assert_eq!(
+            TaskFilter::new()
+                .with_sort([SortKey::Title, SortKey::Priority])
+                .with_reverse(true)
+                .order_by(),
+            "title_key DESC, priority_rank ASC, created_at ASC, id DESC"
+        );
+        assert_eq!(
+            TaskFilter::new().with_sort([SortKey::Status]).order_by(),
+            "status_rank ASC, created_at DESC, id ASC"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_sorts_by_title_and_status() {
+        let (db, temp_dir) = setup_test_db().await;
+        for (id, title, status, created) in [
+            ("t1", "banana", "done", "2024-01-01T00:00:00Z"),
+            ("t2", "Apple", "todo", "2024-01-02T00:00:00Z"),
+            ("t3", "cherry", "todo", "2024-01-03T00:00:00Z"),
+            ("t4", "apple pie", "backlog", "2024-01-04T00:00:00Z"),
+        ] {
+            let query = format!(
+                r#"CREATE task:{} SET title = "{}", level = "task", status = "{}",
+                   created_at = d'{}'"#,
+                id, title, status, created
+            );
+            db.client().query(&query).await.unwrap();
+        }
+
+        let lister = TaskLister::new(db.client());
+        let ids = |tasks: Vec<TaskSummary>| tasks.into_iter().map(|t| t.id).collect::<Vec<_>>();
+
+        let filter = TaskFilter::new().include_done().with_sort([SortKey::Title]);
+        assert_eq!(
+            ids(lister.list(&filter).await.unwrap()),
+            vec!["t2", "t4", "t1", "t3"]
+        );
+
+        // Ties within a status fall back to newest first
+        let filter = TaskFilter::new()
+            .include_done()
+            .with_sort([SortKey::Status]);
+        assert_eq!(
+            ids(lister.list(&filter).await.unwrap()),
+            vec!["t4", "t3", "t2", "t1"]
+        );
+
+        let filter = filter.with_reverse(true);
+        assert_eq!(
+            ids(lister.list(&filter).await.unwrap()),
+            vec!["t1", "t2", "t3", "t4"]
+        );
+
+        cleanup(&temp_dir);
+    }
+
     #[tokio::test]
     async fn test_list_standard_query_returns_newest_first() {
         let (db, temp_dir) = setup_test_db().await;
@@ -2009,4 +3164,544 @@ mod tests {
 
         cleanup(&temp_dir);
     }
+
+    /// Drain a stream into a single vector
+    async fn collect_stream(mut stream: TaskStream<'_>) -> Vec<TaskSummary> {
+        let mut all = Vec::new();
+        while let Some(page) = stream.next_page().await.unwrap() {
+            all.extend(page);
+        }
+        all
+    }
+
+    #[tokio::test]
+    async fn test_stream_matches_list_across_pages() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        for i in 0..7 {
+            create_task_with_timestamp(
+                &db,
+                &format!("task_{}", i),
+                &format!("Task {}", i),
+                "task",
+                "todo",
+                &format!("2024-01-0{}T00:00:00Z", i + 1),
+            )
+            .await;
+        }
+        // Ties on created_at must not duplicate or skip rows across pages
+        create_task_with_timestamp(
+            &db,
+            "tie_a",
+            "Tie A",
+            "task",
+            "todo",
+            "2024-01-03T00:00:00Z",
+        )
+        .await;
+        create_task_with_timestamp(
+            &db,
+            "tie_b",
+            "Tie B",
+            "task",
+            "todo",
+            "2024-01-03T00:00:00Z",
+        )
+        .await;
+
+        let lister = TaskLister::new(db.client());
+        let filter = TaskFilter::new();
+
+        let listed = lister.list(&filter).await.unwrap();
+        let streamed = collect_stream(lister.stream(&filter, 2)).await;
+
+        assert_eq!(listed.len(), 9);
+        assert_eq!(streamed, listed);
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_stream_children_applies_post_filters() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        create_task(&db, "parent", "Parent", "epic", "todo", None, &[]).await;
+        create_task(&db, "t1", "Ticket 1", "ticket", "todo", None, &[]).await;
+        create_task(&db, "k1", "Task 1", "task", "todo", None, &[]).await;
+        create_task(&db, "t2", "Ticket 2", "ticket", "todo", None, &[]).await;
+        create_child_of(&db, "t1", "parent").await;
+        create_child_of(&db, "k1", "parent").await;
+        create_child_of(&db, "t2", "parent").await;
+
+        let lister = TaskLister::new(db.client());
+        let filter = TaskFilter::new()
+            .children_of("parent")
+            .with_level(Level::Ticket);
+
+        let listed = lister.list(&filter).await.unwrap();
+        let streamed = collect_stream(lister.stream(&filter, 1)).await;
+
+        assert_eq!(listed.len(), 2);
+        assert_eq!(streamed, listed);
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_limit_and_offset_page_list_stream_and_count() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        for i in 0..7 {
+            create_task_with_timestamp(
+                &db,
+                &format!("task_{}", i),
+                &format!("Task {}", i),
+                "task",
+                "todo",
+                &format!("2024-01-0{}T00:00:00Z", i + 1),
+            )
+            .await;
+        }
+
+        let lister = TaskLister::new(db.client());
+        let all = lister.list(&TaskFilter::new()).await.unwrap();
+        let filter = TaskFilter::new().with_limit(3).with_offset(2);
+
+        let page = lister.list(&filter).await.unwrap();
+        assert_eq!(page, all[2..5].to_vec());
+        assert!(lister.explain(&filter).ends_with("LIMIT 3 START 2"));
+        assert_eq!(collect_stream(lister.stream(&filter, 2)).await, page);
+        assert_eq!(lister.count(&filter).await.unwrap(), 7);
+
+        // Past the end: an empty page, but the total is unchanged
+        let beyond = TaskFilter::new().with_limit(3).with_offset(10);
+        assert!(lister.list(&beyond).await.unwrap().is_empty());
+        assert_eq!(lister.count(&beyond).await.unwrap(), 7);
+        assert_eq!(
+            lister
+                .count(&TaskFilter::new().with_status(Status::Done))
+                .await
+                .unwrap(),
+            0
+        );
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_limit_and_offset_page_children_after_post_filters() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        create_task(&db, "parent", "Parent", "epic", "todo", None, &[]).await;
+        for id in ["t1", "k1", "t2", "k2", "t3"] {
+            let level = if id.starts_with('t') {
+                "ticket"
+            } else {
+                "task"
+            };
+            create_task(&db, id, id, level, "todo", None, &[]).await;
+            create_child_of(&db, id, "parent").await;
+        }
+
+        let lister = TaskLister::new(db.client());
+        let tickets = TaskFilter::new()
+            .children_of("parent")
+            .with_level(Level::Ticket);
+        let all = lister.list(&tickets).await.unwrap();
+        assert_eq!(all.len(), 3);
+
+        let filter = tickets.clone().with_limit(1).with_offset(1);
+        let page = lister.list(&filter).await.unwrap();
+        assert_eq!(page, all[1..2].to_vec());
+        assert_eq!(collect_stream(lister.stream(&filter, 1)).await, page);
+        assert_eq!(lister.count(&filter).await.unwrap(), 3);
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_stream_empty_result() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        let lister = TaskLister::new(db.client());
+        let mut stream = lister.stream(&TaskFilter::new(), DEFAULT_PAGE_SIZE);
+        assert!(stream.next_page().await.unwrap().is_none());
+        assert!(stream.next_page().await.unwrap().is_none());
+
+        cleanup(&temp_dir);
+    }
+
+    /// Create a task with the given sections (as `(type, content)`) and refs
+    async fn create_documented_task(
+        db: &Database,
+        id: &str,
+        sections: &[(&str, &str)],
+        ref_paths: &[&str],
+    ) {
+        let sections = sections
+            .iter()
+            .map(|(t, c)| format!("{{ type: \"{}\", content: \"{}\" }}", t, c))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let refs = ref_paths
+            .iter()
+            .map(|p| format!("{{ path: \"{}\" }}", p))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let query = format!(
+            r#"CREATE task:{} SET
+                title = "{}",
+                level = "task",
+                status = "todo",
+                sections = [{}],
+                refs = [{}]"#,
+            id, id, sections, refs
+        );
+        db.client().query(&query).await.unwrap();
+    }
+
+    /// Sorted IDs of the listed tasks
+    fn ids(tasks: &[TaskSummary]) -> Vec<&str> {
+        let mut ids: Vec<&str> = tasks.iter().map(|t| t.id.as_str()).collect();
+        ids.sort();
+        ids
+    }
+
+    #[tokio::test]
+    async fn test_list_no_sections() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        create_documented_task(&db, "bare", &[], &[]).await;
+        create_documented_task(&db, "goal", &[("goal", "Ship it")], &[]).await;
+        create_documented_task(&db, "refonly", &[], &["src/lib.rs"]).await;
+
+        let lister = TaskLister::new(db.client());
+        let result = lister
+            .list(&TaskFilter::new().without_sections())
+            .await
+            .unwrap();
+        assert_eq!(ids(&result), vec!["bare", "refonly"]);
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_list_has_section_type() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        create_documented_task(&db, "bare", &[], &[]).await;
+        create_documented_task(&db, "goal", &[("goal", "Ship it")], &[]).await;
+        create_documented_task(
+            &db,
+            "steps",
+            &[("goal", "Ship it"), ("step", "Write code")],
+            &[],
+        )
+        .await;
+        create_documented_task(&db, "constraint", &[("constraint", "No deps")], &[]).await;
+
+        let lister = TaskLister::new(db.client());
+
+        let steps = lister
+            .list(&TaskFilter::new().with_section_type(SectionType::Step))
+            .await
+            .unwrap();
+        assert_eq!(ids(&steps), vec!["steps"]);
+
+        let either = lister
+            .list(
+                &TaskFilter::new()
+                    .with_section_type(SectionType::Step)
+                    .with_section_type(SectionType::Constraint),
+            )
+            .await
+            .unwrap();
+        assert_eq!(ids(&either), vec!["constraint", "steps"]);
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_list_has_refs_and_no_refs() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        create_documented_task(&db, "bare", &[], &[]).await;
+        create_documented_task(&db, "linked", &[], &["src/main.rs"]).await;
+
+        let lister = TaskLister::new(db.client());
+        let with_refs = lister
+            .list(&TaskFilter::new().with_has_refs(true))
+            .await
+            .unwrap();
+        assert_eq!(ids(&with_refs), vec!["linked"]);
+
+        let without_refs = lister
+            .list(&TaskFilter::new().with_has_refs(false))
+            .await
+            .unwrap();
+        assert_eq!(ids(&without_refs), vec!["bare"]);
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_list_overdue_skips_undated_and_finished_tasks() {
+        let (db, temp_dir) = setup_test_db().await;
+        db.client()
+            .query(
+                r#"CREATE task:late SET title = "Late", level = "task", status = "todo",
+                       due_at = time::now() - 1d;
+                   CREATE task:soon SET title = "Soon", level = "task", status = "todo",
+                       due_at = time::now() + 1d;
+                   CREATE task:undated SET title = "Undated", level = "task", status = "todo";
+                   CREATE task:shipped SET title = "Shipped", level = "task", status = "done",
+                       due_at = time::now() - 1d;
+                   CREATE task:dropped SET title = "Dropped", level = "task",
+                       status = "rejected", due_at = time::now() - 1d;
+                   CREATE task:epic SET title = "Epic", level = "epic", status = "todo";
+                   CREATE task:child SET title = "Child", level = "task", status = "in_progress",
+                       due_at = time::now() - 1h;
+                   RELATE task:child -> child_of -> task:epic"#,
+            )
+            .await
+            .unwrap();
+
+        let lister = TaskLister::new(db.client());
+        let overdue = lister
+            .list(&TaskFilter::new().include_done().overdue_only())
+            .await
+            .unwrap();
+        assert_eq!(ids(&overdue), vec!["child", "late"]);
+
+        let children = lister
+            .list(&TaskFilter::new().children_of("epic").overdue_only())
+            .await
+            .unwrap();
+        assert_eq!(ids(&children), vec!["child"]);
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_list_filters_by_created_and_updated_time() {
+        let (db, temp_dir) = setup_test_db().await;
+        db.client()
+            .query(
+                r#"CREATE task:old SET title = "Old", level = "task", status = "todo",
+                       created_at = d'2024-01-01T00:00:00Z', updated_at = d'2024-05-01T00:00:00Z';
+                   CREATE task:mid SET title = "Mid", level = "task", status = "todo",
+                       created_at = d'2024-03-01T00:00:00Z', updated_at = d'2024-03-01T00:00:00Z';
+                   CREATE task:new SET title = "New", level = "task", status = "todo",
+                       created_at = d'2024-06-01T00:00:00Z', updated_at = d'2024-06-01T00:00:00Z'"#,
+            )
+            .await
+            .unwrap();
+        let at = |s: &str| DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc);
+
+        let lister = TaskLister::new(db.client());
+        let tasks = lister
+            .list(&TaskFilter::new().created_after(at("2024-03-01T00:00:00Z")))
+            .await
+            .unwrap();
+        assert_eq!(ids(&tasks), vec!["mid", "new"]);
+        // Newest first, carrying both timestamps
+        assert_eq!(tasks[0].created_at, Some(at("2024-06-01T00:00:00Z")));
+        assert_eq!(tasks[1].updated_at, Some(at("2024-03-01T00:00:00Z")));
+
+        let tasks = lister
+            .list(&TaskFilter::new().created_before(at("2024-03-01T00:00:00Z")))
+            .await
+            .unwrap();
+        assert_eq!(ids(&tasks), vec!["old"]);
+
+        let tasks = lister
+            .list(
+                &TaskFilter::new()
+                    .created_before(at("2024-04-01T00:00:00Z"))
+                    .updated_after(at("2024-04-01T00:00:00Z")),
+            )
+            .await
+            .unwrap();
+        assert_eq!(ids(&tasks), vec!["old"]);
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_list_hides_deleted_tasks_unless_included() {
+        let (db, temp_dir) = setup_test_db().await;
+        db.client()
+            .query(
+                r#"CREATE task:live SET title = "Live", level = "task", status = "todo";
+                   CREATE task:gone SET title = "Gone", level = "task", status = "todo",
+                       deleted_at = time::now();
+                   CREATE task:epic SET title = "Epic", level = "epic", status = "todo";
+                   RELATE task:live -> child_of -> task:epic;
+                   RELATE task:gone -> child_of -> task:epic;
+                   RELATE task:epic -> depends_on -> task:gone"#,
+            )
+            .await
+            .unwrap();
+
+        let lister = TaskLister::new(db.client());
+        let all = lister.list(&TaskFilter::new()).await.unwrap();
+        assert_eq!(ids(&all), vec!["epic", "live"]);
+        let children = lister
+            .list(&TaskFilter::new().children_of("epic"))
+            .await
+            .unwrap();
+        assert_eq!(ids(&children), vec!["live"]);
+        assert_eq!(lister.count(&TaskFilter::new()).await.unwrap(), 2);
+
+        let with_deleted = lister
+            .list(&TaskFilter::new().include_deleted())
+            .await
+            .unwrap();
+        assert_eq!(ids(&with_deleted), vec!["epic", "gone", "live"]);
+
+        // A blocker in the trash doesn't block
+        let unblocked = lister.list_unblocked(Status::Todo).await.unwrap();
+        assert_eq!(ids(&unblocked), vec!["epic", "live"]);
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_tag_counts() {
+        let (db, temp_dir) = setup_test_db().await;
+        let lister = TaskLister::new(db.client());
+        assert!(lister.tag_counts().await.unwrap().is_empty());
+
+        db.client()
+            .query(
+                r#"CREATE task:a SET title = "A", level = "task", status = "todo",
+                       tags = ["backend", "api"];
+                   CREATE task:b SET title = "B", level = "task", status = "done",
+                       tags = ["backend"];
+                   CREATE task:c SET title = "C", level = "task", status = "todo",
+                       tags = ["back-end"];
+                   CREATE task:d SET title = "D", level = "task", status = "todo";
+                   CREATE task:e SET title = "E", level = "task", status = "todo",
+                       tags = ["backend", "zombie"], deleted_at = time::now()"#,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            lister.tag_counts().await.unwrap(),
+            vec![
+                ("backend".to_string(), 2),
+                ("api".to_string(), 1),
+                ("back-end".to_string(), 1),
+            ]
+        );
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_list_children_with_section_filter() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        create_documented_task(&db, "parent", &[], &[]).await;
+        create_documented_task(&db, "c1", &[("step", "Do it")], &[]).await;
+        create_documented_task(&db, "c2", &[], &[]).await;
+        create_child_of(&db, "c1", "parent").await;
+        create_child_of(&db, "c2", "parent").await;
+
+        let lister = TaskLister::new(db.client());
+        let result = lister
+            .list(
+                &TaskFilter::new()
+                    .children_of("parent")
+                    .with_section_type(SectionType::Step),
+            )
+            .await
+            .unwrap();
+        assert_eq!(ids(&result), vec!["c1"]);
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_list_unblocked_ignores_hierarchy_and_done_blockers() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        create_task(&db, "epic", "Epic", "epic", "todo", None, &[]).await;
+        create_task(&db, "child", "Child", "task", "todo", Some("high"), &[]).await;
+        create_task(&db, "blocked", "Blocked", "task", "todo", None, &[]).await;
+        create_task(&db, "cleared", "Cleared", "task", "todo", None, &[]).await;
+        create_task(&db, "open", "Open", "task", "todo", None, &[]).await;
+        create_task(&db, "finished", "Finished", "task", "done", None, &[]).await;
+        create_task(&db, "later", "Later", "task", "backlog", None, &[]).await;
+        create_child_of(&db, "child", "epic").await;
+        db.client()
+            .query(
+                "RELATE task:blocked -> depends_on -> task:open;
+                 RELATE task:cleared -> depends_on -> task:finished",
+            )
+            .await
+            .unwrap();
+
+        let lister = TaskLister::new(db.client());
+        let result = lister.list_unblocked(Status::Todo).await.unwrap();
+        let mut rest = ids(&result);
+        assert_eq!(rest.remove(0), "child", "highest priority comes first");
+        rest.sort();
+        assert_eq!(rest, vec!["cleared", "epic", "open"]);
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_next_unblocked_tie_breaks() {
+        let (db, temp_dir) = setup_test_db().await;
+        let lister = TaskLister::new(db.client());
+        assert!(lister.next_unblocked(Status::Todo).await.unwrap().is_none());
+
+        create_task(&db, "old", "Old", "task", "todo", Some("high"), &[]).await;
+        create_task(&db, "recent", "Recent", "task", "todo", Some("high"), &[]).await;
+        create_task(&db, "tiny", "Tiny", "task", "todo", Some("high"), &[]).await;
+        create_task(&db, "low", "Low", "task", "todo", Some("low"), &[]).await;
+        db.client()
+            .query(
+                "UPDATE task:old SET updated_at = d'2024-01-01T00:00:00Z';
+                 UPDATE task:recent SET updated_at = d'2024-03-01T00:00:00Z';
+                 UPDATE task:tiny SET updated_at = d'2024-03-01T00:00:00Z';
+                 UPDATE task:low SET updated_at = d'2024-06-01T00:00:00Z'",
+            )
+            .await
+            .unwrap();
+
+        // Same priority and update time: the shorter title wins
+        let next = lister.next_unblocked(Status::Todo).await.unwrap().unwrap();
+        assert_eq!(next.id, "tiny");
+
+        // A blocked task is never picked, however urgent
+        create_task(
+            &db,
+            "urgent",
+            "Urgent",
+            "task",
+            "todo",
+            Some("critical"),
+            &[],
+        )
+        .await;
+        db.client()
+            .query("RELATE task:urgent -> depends_on -> task:old")
+            .await
+            .unwrap();
+        let next = lister.next_unblocked(Status::Todo).await.unwrap().unwrap();
+        assert_eq!(next.id, "tiny");
+
+        db.client()
+            .query("UPDATE task:tiny SET updated_at = d'2024-02-01T00:00:00Z'")
+            .await
+            .unwrap();
+        let next = lister.next_unblocked(Status::Todo).await.unwrap().unwrap();
+        assert_eq!(next.id, "recent");
+
+        cleanup(&temp_dir);
+    }
 }
@@ -4,25 +4,36 @@
 //! such as dependency chain traversal, path finding, cycle detection,
 //! and descendant collection.
 
-use crate::error::DbResult;
-use serde::Deserialize;
-use std::collections::{HashMap, HashSet, VecDeque};
-use surrealdb::Surreal;
-use surrealdb::engine::local::Db;
+use crate::config::graph_settings;
+use crate::error::{DbError, DbResult};
+use crate::models::Status;
+use crate::retry::RetryClient;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
 
 /// Repository for graph-based query operations
 ///
 /// Encapsulates complex graph traversal queries that operate on
 /// the task dependency graph (depends_on edges) and hierarchy
 /// (child_of edges).
+///
+/// Traversals skip tasks in the trash (`deleted_at` set). Cycle checks
+/// still follow their edges, so restoring a task can never close a cycle.
 pub struct GraphQueries<'a> {
-    client: &'a Surreal<Db>,
+    client: RetryClient<'a>,
+    /// Statuses counted as complete when computing progress
+    completion_statuses: Vec<Status>,
+    /// Most tasks a single traversal may visit
+    traversal_limit: usize,
 }
 
+/// Statuses counted as complete by default when computing progress.
+pub const DEFAULT_COMPLETION_STATUSES: &[Status] = &[Status::Done];
+
 /// Progress information for a task and its descendants.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Progress {
-    /// Number of descendants (including self) that are done.
+    /// Number of descendants (including self) in a completion status.
     pub done_count: usize,
     /// Total number of descendants (including self).
     pub total_count: usize,
@@ -32,11 +43,17 @@ pub struct Progress {
 
 impl Progress {
     /// Create a new Progress with computed percentage.
+    ///
+    /// Rounds to the nearest percent, except that 100% is reserved for all
+    /// done and 0% for none done: 199/200 shows 99%, 1/200 shows 1%.
     pub fn new(done_count: usize, total_count: usize) -> Self {
-        let percentage = if total_count == 0 {
+        let percentage = if total_count == 0 || done_count == 0 {
             0
+        } else if done_count >= total_count {
+            100
         } else {
-            ((done_count as f64 / total_count as f64) * 100.0).round() as u8
+            let rounded = ((done_count as f64 / total_count as f64) * 100.0).round() as u8;
+            rounded.clamp(1, 99)
         };
         Self {
             done_count,
@@ -45,6 +62,14 @@ impl Progress {
         }
     }
 
+    /// Compute progress from a set of statuses.
+    ///
+    /// A status counts toward `done_count` if it appears in `completion`.
+    pub fn from_statuses(statuses: &[Status], completion: &[Status]) -> Self {
+        let done = statuses.iter().filter(|s| completion.contains(s)).count();
+        Self::new(done, statuses.len())
+    }
+
     /// Check if this represents complete progress (100%).
     pub fn is_complete(&self) -> bool {
         self.percentage == 100
@@ -56,6 +81,89 @@ impl Progress {
     }
 }
 
+/// Estimated effort summed over a task and its descendants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct EstimateRollup {
+    /// Minutes estimated for the task and all its descendants
+    pub total_minutes: u64,
+    /// Minutes estimated for those not yet in a completion status
+    pub remaining_minutes: u64,
+    /// Descendants without an estimate, counted as zero in both sums
+    pub unestimated: usize,
+}
+
+/// Edges about to be created, followed by the `*_with` cycle checks as if
+/// they were already stored.
+///
+/// A batch of new edges can then be checked one at a time, each against
+/// the stored graph plus the edges accepted before it.
+#[derive(Debug, Clone, Default)]
+pub struct PendingEdges {
+    /// Blockers of each task
+    depends_on: HashMap<String, Vec<String>>,
+    /// Parent of each task
+    child_of: HashMap<String, String>,
+}
+
+impl PendingEdges {
+    /// Create an empty set of pending edges
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a pending `task -> depends_on -> blocker` edge
+    pub fn add_depends_on(&mut self, task_id: &str, blocker_id: &str) {
+        self.depends_on
+            .entry(task_id.to_string())
+            .or_default()
+            .push(blocker_id.to_string());
+    }
+
+    /// Add a pending `child -> child_of -> parent` edge
+    pub fn add_child_of(&mut self, child_id: &str, parent_id: &str) {
+        self.child_of
+            .insert(child_id.to_string(), parent_id.to_string());
+    }
+}
+
+/// Counts the tasks visited by one traversal and stops it at the limit
+struct TraversalBudget<'t> {
+    /// Task the traversal started from, for the error message
+    root: &'t str,
+    limit: usize,
+    visited: usize,
+}
+
+impl<'t> TraversalBudget<'t> {
+    fn new(root: &'t str, limit: usize) -> Self {
+        Self {
+            root,
+            limit,
+            visited: 0,
+        }
+    }
+
+    /// Record one visited task, failing once the limit is exceeded.
+    fn visit(&mut self) -> DbResult<()> {
+        self.visited += 1;
+        if self.visited > self.limit {
+            return Err(DbError::TraversalLimit {
+                task_id: self.root.to_string(),
+                limit: self.limit,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Error for a cycle met while walking `relation` edges along `path`.
+fn cycle_error(relation: &str, path: &[String], repeated: &str) -> DbError {
+    DbError::GraphCycle {
+        relation: relation.to_string(),
+        cycle: GraphQueries::format_cycle_path(&GraphQueries::close_cycle(path, repeated)),
+    }
+}
+
 /// A node in the blocker tree with full task information
 #[derive(Debug, Clone)]
 pub struct BlockerNode {
@@ -88,8 +196,37 @@ struct TaskIdRow {
 
 impl<'a> GraphQueries<'a> {
     /// Create a new GraphQueries with the given database client
-    pub fn new(client: &'a Surreal<Db>) -> Self {
-        Self { client }
+    pub fn new(client: impl Into<RetryClient<'a>>) -> Self {
+        let settings = graph_settings();
+        Self {
+            client: client.into(),
+            completion_statuses: DEFAULT_COMPLETION_STATUSES.to_vec(),
+            traversal_limit: settings.max_nodes,
+        }
+        .with_completion_statuses(settings.completion_statuses.iter().cloned())
+    }
+
+    /// Set the most tasks a recursive traversal may visit.
+    ///
+    /// Defaults to `[graph] max_nodes` from the project config. Blocker
+    /// trees, cycle detection and descendant collection fail with
+    /// `DbError::TraversalLimit` instead of running past it.
+    pub fn with_traversal_limit(mut self, limit: usize) -> Self {
+        self.traversal_limit = limit;
+        self
+    }
+
+    /// Set which statuses count as complete for progress calculations.
+    ///
+    /// Defaults to `[graph] completion_statuses` from the project config,
+    /// which is `done` only unless set. Some teams also count
+    /// `pending_review`. An empty set falls back to `done` only.
+    pub fn with_completion_statuses(mut self, statuses: impl IntoIterator<Item = Status>) -> Self {
+        let statuses: Vec<Status> = statuses.into_iter().collect();
+        if !statuses.is_empty() {
+            self.completion_statuses = statuses;
+        }
+        self
     }
 
     // ========================================
@@ -109,23 +246,36 @@ impl<'a> GraphQueries<'a> {
     /// # Returns
     ///
     /// A vector of root blocker nodes, each potentially containing children.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError::TraversalLimit` if the tree has more nodes than the
+    /// traversal limit. Shared blockers count once per place they appear.
+    /// Returns `DbError::GraphCycle` if a blocker depends on itself, which
+    /// `vtb depend` prevents but raw edits can introduce.
     pub async fn get_blockers(
         &self,
         task_id: &str,
         max_depth: Option<usize>,
     ) -> DbResult<Vec<BlockerNode>> {
-        self.build_blocker_tree(task_id, 0, max_depth).await
+        let mut budget = TraversalBudget::new(task_id, self.traversal_limit);
+        let mut trail = vec![task_id.to_string()];
+        self.build_blocker_tree(task_id, 0, max_depth, &mut budget, &mut trail)
+            .await
     }
 
     /// Build the blocker tree recursively.
     ///
     /// Internal method that performs the actual tree construction with
-    /// depth tracking.
+    /// depth tracking. `trail` holds the chain from the root task down to
+    /// `task_id`; meeting one of those tasks again means a cycle.
     async fn build_blocker_tree(
         &self,
         task_id: &str,
         current_depth: usize,
         max_depth: Option<usize>,
+        budget: &mut TraversalBudget<'_>,
+        trail: &mut Vec<String>,
     ) -> DbResult<Vec<BlockerNode>> {
         // Check depth limit
         if let Some(max) = max_depth
@@ -141,11 +291,22 @@ impl<'a> GraphQueries<'a> {
         let mut nodes = Vec::new();
         for blocker in direct_blockers {
             let blocker_id = blocker.id.id.to_string();
+            if trail.contains(&blocker_id) {
+                return Err(cycle_error("depends_on", trail, &blocker_id));
+            }
+            budget.visit()?;
 
             // Recursively get children (blockers of this blocker)
-            let children =
-                Box::pin(self.build_blocker_tree(&blocker_id, current_depth + 1, max_depth))
-                    .await?;
+            trail.push(blocker_id.clone());
+            let children = Box::pin(self.build_blocker_tree(
+                &blocker_id,
+                current_depth + 1,
+                max_depth,
+                budget,
+                trail,
+            ))
+            .await?;
+            trail.pop();
 
             nodes.push(BlockerNode {
                 id: blocker_id,
@@ -163,7 +324,8 @@ impl<'a> GraphQueries<'a> {
     async fn fetch_direct_blockers(&self, task_id: &str) -> DbResult<Vec<TaskInfoRow>> {
         // Get tasks that this task depends on via the depends_on edge
         let query = format!(
-            "SELECT id, title, level, status FROM task WHERE <-depends_on<-task CONTAINS task:{}",
+            "SELECT id, title, level, status FROM task \
+             WHERE <-depends_on<-task CONTAINS task:{} AND deleted_at = NONE",
             task_id
         );
 
@@ -207,7 +369,10 @@ impl<'a> GraphQueries<'a> {
 
         while let Some(current) = queue.pop_front() {
             // Get all tasks that the current task depends on
-            let query = format!("SELECT VALUE out FROM task:{}->depends_on", current);
+            let query = format!(
+                "SELECT VALUE out FROM task:{}->depends_on WHERE out.deleted_at = NONE",
+                current
+            );
             let mut result = self.client.query(&query).await?;
             let deps: Vec<surrealdb::sql::Thing> = result.take(0)?;
 
@@ -233,6 +398,83 @@ impl<'a> GraphQueries<'a> {
         Ok(None)
     }
 
+    /// Find the shortest chain of incomplete blockers from a task to the
+    /// nearest one that can be worked on.
+    ///
+    /// Runs a BFS over `depends_on` edges starting at `task_id`, following
+    /// only blockers outside the completion statuses, so completed blockers
+    /// end a chain just as they do for `get_incomplete_blockers`. The search
+    /// stops at the first incomplete blocker with no incomplete blockers of
+    /// its own. The edges are loaded in one query and walked in memory.
+    ///
+    /// # Arguments
+    ///
+    /// * `task_id` - The task to start from
+    ///
+    /// # Returns
+    ///
+    /// `Some(path)` from `task_id` to that blocker (inclusive), or `None` if
+    /// nothing blocks the task.
+    pub async fn find_path_to_nearest_incomplete_blocker(
+        &self,
+        task_id: &str,
+    ) -> DbResult<Option<Vec<String>>> {
+        #[derive(Debug, Deserialize)]
+        struct EdgeRow {
+            task: surrealdb::sql::Thing,
+            blocker: surrealdb::sql::Thing,
+            status: String,
+        }
+
+        let query = "SELECT in AS task, out AS blocker, out.status AS status FROM depends_on \
+                     WHERE out.deleted_at = NONE";
+        let mut result = self.client.query(query).await?;
+        let rows: Vec<EdgeRow> = result.take(0)?;
+
+        let mut incomplete: HashMap<String, Vec<String>> = HashMap::new();
+        for row in rows {
+            let complete = Status::parse(&row.status)
+                .is_some_and(|status| self.completion_statuses.contains(&status));
+            if !complete {
+                incomplete
+                    .entry(row.task.id.to_string())
+                    .or_default()
+                    .push(row.blocker.id.to_string());
+            }
+        }
+        // Deterministic choice when several blockers are equally near
+        for blockers in incomplete.values_mut() {
+            blockers.sort();
+            blockers.dedup();
+        }
+
+        let mut visited = HashSet::new();
+        let mut queue = VecDeque::new();
+        let mut parent_map: HashMap<String, String> = HashMap::new();
+        // Path to the nearest blocker, used if every one is itself blocked
+        let mut nearest = None;
+
+        queue.push_back(task_id.to_string());
+        visited.insert(task_id.to_string());
+
+        while let Some(current) = queue.pop_front() {
+            for dep_id in incomplete.get(&current).into_iter().flatten() {
+                if visited.insert(dep_id.clone()) {
+                    parent_map.insert(dep_id.clone(), current.clone());
+
+                    if !incomplete.contains_key(dep_id) {
+                        return Ok(Some(self.reconstruct_path(task_id, dep_id, &parent_map)));
+                    }
+                    nearest.get_or_insert_with(|| dep_id.clone());
+
+                    queue.push_back(dep_id.clone());
+                }
+            }
+        }
+
+        Ok(nearest.map(|dep_id| self.reconstruct_path(task_id, &dep_id, &parent_map)))
+    }
+
     /// Reconstruct the path from source to target using the parent map.
     fn reconstruct_path(
         &self,
@@ -326,6 +568,28 @@ impl<'a> GraphQueries<'a> {
         Ok(false)
     }
 
+    /// Check if making `parent_id` the parent of `task_id` would form a
+    /// hierarchy cycle.
+    ///
+    /// That happens when the new parent is the task itself or one of its
+    /// descendants, i.e. when `task_id` is among the new parent's ancestors.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError::GraphCycle` if the new parent's ancestors already
+    /// contain a cycle.
+    pub async fn would_create_hierarchy_cycle(
+        &self,
+        task_id: &str,
+        parent_id: &str,
+    ) -> DbResult<bool> {
+        if task_id == parent_id {
+            return Ok(true);
+        }
+        let ancestors = self.get_ancestor_chain(parent_id).await?;
+        Ok(ancestors.iter().any(|id| id == task_id))
+    }
+
     /// Get the cycle path that would be created by adding a dependency.
     ///
     /// Uses BFS with parent tracking to reconstruct the path that would
@@ -353,6 +617,17 @@ impl<'a> GraphQueries<'a> {
         &self,
         task_id: &str,
         depends_on_id: &str,
+    ) -> DbResult<Option<Vec<String>>> {
+        self.get_cycle_path_with(task_id, depends_on_id, &PendingEdges::new())
+            .await
+    }
+
+    /// [`GraphQueries::get_cycle_path`], also following `pending` edges.
+    pub async fn get_cycle_path_with(
+        &self,
+        task_id: &str,
+        depends_on_id: &str,
+        pending: &PendingEdges,
     ) -> DbResult<Option<Vec<String>>> {
         // Use BFS with parent tracking to find the path from depends_on_id to task_id
         let mut visited = HashSet::new();
@@ -366,10 +641,13 @@ impl<'a> GraphQueries<'a> {
             let query = format!("SELECT VALUE out FROM task:{}->depends_on", current);
             let mut result = self.client.query(&query).await?;
             let deps: Vec<surrealdb::sql::Thing> = result.take(0)?;
+            let pending_deps = pending.depends_on.get(&current).into_iter().flatten();
 
-            for dep in deps {
-                let dep_id = dep.id.to_string();
-
+            for dep_id in deps
+                .into_iter()
+                .map(|dep| dep.id.to_string())
+                .chain(pending_deps.cloned())
+            {
                 if dep_id == task_id {
                     // Found the path - reconstruct it
                     let mut path = vec![task_id.to_string()];
@@ -424,6 +702,17 @@ impl<'a> GraphQueries<'a> {
         path.join(" -> ")
     }
 
+    /// Close a cycle found while walking `path`.
+    ///
+    /// `repeated` is a task already on `path`; the result runs from its first
+    /// occurrence to the end of `path` and back to it.
+    pub fn close_cycle(path: &[String], repeated: &str) -> Vec<String> {
+        let start = path.iter().position(|id| id == repeated).unwrap_or(0);
+        let mut cycle = path[start..].to_vec();
+        cycle.push(repeated.to_string());
+        cycle
+    }
+
     /// Detect if a task is part of any existing cycle.
     ///
     /// Checks whether the given task participates in a cycle in the current
@@ -457,8 +746,9 @@ impl<'a> GraphQueries<'a> {
         let mut visited = HashSet::new();
         let mut path = Vec::new();
         let mut path_set = HashSet::new();
+        let mut budget = TraversalBudget::new(task_id, self.traversal_limit);
 
-        self.detect_cycle_dfs(task_id, &mut visited, &mut path, &mut path_set)
+        self.detect_cycle_dfs(task_id, &mut visited, &mut path, &mut path_set, &mut budget)
             .await
     }
 
@@ -472,14 +762,11 @@ impl<'a> GraphQueries<'a> {
         visited: &mut HashSet<String>,
         path: &mut Vec<String>,
         path_set: &mut HashSet<String>,
+        budget: &mut TraversalBudget<'_>,
     ) -> DbResult<Option<Vec<String>>> {
         // If we've seen this node in the current path, we found a cycle
         if path_set.contains(current) {
-            // Extract the cycle from the path
-            let cycle_start = path.iter().position(|x| x == current).unwrap();
-            let mut cycle: Vec<String> = path[cycle_start..].to_vec();
-            cycle.push(current.to_string()); // Close the cycle
-            return Ok(Some(cycle));
+            return Ok(Some(Self::close_cycle(path, current)));
         }
 
         // If already fully explored, no cycle through this node
@@ -487,6 +774,8 @@ impl<'a> GraphQueries<'a> {
             return Ok(None);
         }
 
+        budget.visit()?;
+
         // Mark as in current path and visited
         path.push(current.to_string());
         path_set.insert(current.to_string());
@@ -501,7 +790,7 @@ impl<'a> GraphQueries<'a> {
         for dep in deps {
             let dep_id = dep.id.to_string();
             if let Some(cycle) =
-                Box::pin(self.detect_cycle_dfs(&dep_id, visited, path, path_set)).await?
+                Box::pin(self.detect_cycle_dfs(&dep_id, visited, path, path_set, budget)).await?
             {
                 return Ok(Some(cycle));
             }
@@ -514,6 +803,235 @@ impl<'a> GraphQueries<'a> {
         Ok(None)
     }
 
+    // ========================================
+    // Dependency Chain Queries
+    // ========================================
+
+    /// Find the longest chain of dependencies starting from a task.
+    ///
+    /// Follows `depends_on` edges depth-first, remembering the longest chain
+    /// below each task so shared blockers are only explored once. Length is
+    /// the number of edges; ties go to the blocker with the smallest ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `task_id` - The task to start from
+    ///
+    /// # Returns
+    ///
+    /// The task IDs along the longest chain, starting with `task_id`. A task
+    /// with no dependencies gives just `[task_id]`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError::GraphCycle` if the chain runs into a cycle, or
+    /// `DbError::TraversalLimit` if it visits more tasks than the limit.
+    pub async fn critical_path(&self, task_id: &str) -> DbResult<Vec<String>> {
+        let mut longest = HashMap::new();
+        let mut trail = Vec::new();
+        let mut budget = TraversalBudget::new(task_id, self.traversal_limit);
+
+        self.critical_path_dfs(task_id, &mut longest, &mut trail, &mut budget)
+            .await
+    }
+
+    /// DFS helper for the critical path, memoizing the chain below each task.
+    async fn critical_path_dfs(
+        &self,
+        current: &str,
+        longest: &mut HashMap<String, Vec<String>>,
+        trail: &mut Vec<String>,
+        budget: &mut TraversalBudget<'_>,
+    ) -> DbResult<Vec<String>> {
+        if trail.iter().any(|id| id == current) {
+            return Err(cycle_error("depends_on", trail, current));
+        }
+        if let Some(chain) = longest.get(current) {
+            return Ok(chain.clone());
+        }
+
+        budget.visit()?;
+        trail.push(current.to_string());
+
+        let query = format!(
+            "SELECT VALUE out FROM task:{}->depends_on WHERE out.deleted_at = NONE",
+            current
+        );
+        let mut result = self.client.query(&query).await?;
+        let deps: Vec<surrealdb::sql::Thing> = result.take(0)?;
+        let mut dep_ids: Vec<String> = deps.into_iter().map(|d| d.id.to_string()).collect();
+        dep_ids.sort();
+        dep_ids.dedup();
+
+        let mut below: Vec<String> = Vec::new();
+        for dep_id in dep_ids {
+            let chain = Box::pin(self.critical_path_dfs(&dep_id, longest, trail, budget)).await?;
+            if chain.len() > below.len() {
+                below = chain;
+            }
+        }
+
+        trail.pop();
+
+        let mut chain = Vec::with_capacity(below.len() + 1);
+        chain.push(current.to_string());
+        chain.extend(below);
+        longest.insert(current.to_string(), chain.clone());
+        Ok(chain)
+    }
+
+    /// Order every task so that each comes after the tasks it depends on.
+    ///
+    /// Fetches all tasks and `depends_on` edges in one query and runs Kahn's
+    /// algorithm over them. Among tasks that are ready at the same time, the
+    /// smallest ID goes first, so the order is stable between runs. Edges to
+    /// tasks that no longer exist or are in the trash are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError::GraphCycle` naming the tasks of a cycle if the
+    /// dependencies cannot be ordered.
+    pub async fn topological_order(&self) -> DbResult<Vec<String>> {
+        #[derive(Debug, Deserialize)]
+        struct EdgeRow {
+            #[serde(rename = "in")]
+            task: surrealdb::sql::Thing,
+            #[serde(rename = "out")]
+            blocker: surrealdb::sql::Thing,
+        }
+
+        let mut result = self
+            .client
+            .query(
+                "SELECT VALUE id FROM task WHERE deleted_at = NONE; \
+                 SELECT in, out FROM depends_on",
+            )
+            .await?;
+        let tasks: Vec<surrealdb::sql::Thing> = result.take(0)?;
+        let edges: Vec<EdgeRow> = result.take(1)?;
+
+        // Blockers not yet ordered, per task, and the dependents of each task
+        let mut waiting: HashMap<String, usize> = tasks
+            .into_iter()
+            .map(|task| (task.id.to_string(), 0))
+            .collect();
+        let mut dependents: HashMap<String, Vec<String>> = HashMap::new();
+        let mut seen = HashSet::new();
+        for edge in edges {
+            let (task, blocker) = (edge.task.id.to_string(), edge.blocker.id.to_string());
+            if !waiting.contains_key(&blocker) || !seen.insert((task.clone(), blocker.clone())) {
+                continue;
+            }
+            if let Some(count) = waiting.get_mut(&task) {
+                *count += 1;
+                dependents.entry(blocker).or_default().push(task);
+            }
+        }
+
+        let mut ready: BTreeSet<String> = waiting
+            .iter()
+            .filter(|(_, count)| **count == 0)
+            .map(|(id, _)| id.clone())
+            .collect();
+        let mut order = Vec::with_capacity(waiting.len());
+
+        while let Some(id) = ready.pop_first() {
+            for dependent in dependents.remove(&id).unwrap_or_default() {
+                if let Some(count) = waiting.get_mut(&dependent) {
+                    *count -= 1;
+                    if *count == 0 {
+                        ready.insert(dependent);
+                    }
+                }
+            }
+            order.push(id);
+        }
+
+        if order.len() < waiting.len() {
+            // Whatever is left depends, directly or not, on a cycle
+            let mut stuck: Vec<&String> = waiting
+                .iter()
+                .filter(|(_, count)| **count > 0)
+                .map(|(id, _)| id)
+                .collect();
+            stuck.sort();
+            let cycle = match self.detect_cycle(stuck[0]).await? {
+                Some(cycle) => Self::format_cycle_path(&cycle),
+                None => stuck
+                    .iter()
+                    .map(|id| id.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            };
+            return Err(DbError::GraphCycle {
+                relation: "depends_on".to_string(),
+                cycle,
+            });
+        }
+
+        Ok(order)
+    }
+
+    /// Count, for every task, how many other tasks transitively depend on it.
+    ///
+    /// The inverse of the blocker tree: walks `depends_on` edges from
+    /// blockers to dependents. Tasks are visited in reverse dependency order
+    /// so each task's dependents are computed once and reused by the tasks
+    /// it depends on. Tasks in the trash are left out.
+    ///
+    /// # Returns
+    ///
+    /// Every task with its number of transitive dependents, highest first
+    /// and then by ID. Tasks nothing depends on have an impact of 0.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError::GraphCycle` if the dependencies contain a cycle.
+    pub async fn blocker_impact(&self) -> DbResult<Vec<(String, usize)>> {
+        #[derive(Debug, Deserialize)]
+        struct EdgeRow {
+            #[serde(rename = "in")]
+            task: surrealdb::sql::Thing,
+            #[serde(rename = "out")]
+            blocker: surrealdb::sql::Thing,
+        }
+
+        let order = self.topological_order().await?;
+
+        let mut result = self.client.query("SELECT in, out FROM depends_on").await?;
+        let edges: Vec<EdgeRow> = result.take(0)?;
+
+        let live: HashSet<&str> = order.iter().map(String::as_str).collect();
+        let mut dependents: HashMap<String, Vec<String>> = HashMap::new();
+        for edge in edges {
+            let (task, blocker) = (edge.task.id.to_string(), edge.blocker.id.to_string());
+            if live.contains(task.as_str()) && live.contains(blocker.as_str()) {
+                dependents.entry(blocker).or_default().push(task);
+            }
+        }
+
+        // Dependents come after their blockers, so walking the order backwards
+        // finishes every dependent before the tasks it depends on
+        let mut reach: HashMap<&str, HashSet<&str>> = HashMap::with_capacity(order.len());
+        for id in order.iter().rev() {
+            let mut all = HashSet::new();
+            for dependent in dependents.get(id).into_iter().flatten() {
+                all.insert(dependent.as_str());
+                if let Some(further) = reach.get(dependent.as_str()) {
+                    all.extend(further.iter().copied());
+                }
+            }
+            reach.insert(id.as_str(), all);
+        }
+
+        let mut impact: Vec<(String, usize)> = reach
+            .into_iter()
+            .map(|(id, all)| (id.to_string(), all.len()))
+            .collect();
+        impact.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        Ok(impact)
+    }
+
     // ========================================
     // Hierarchy Traversal Queries
     // ========================================
@@ -530,14 +1048,21 @@ impl<'a> GraphQueries<'a> {
     /// # Returns
     ///
     /// A vector of all descendant task IDs.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError::TraversalLimit` if there are more descendants than
+    /// the traversal limit.
     pub async fn get_all_descendants(&self, task_id: &str) -> DbResult<Vec<String>> {
         let mut all_descendants = Vec::new();
         let mut to_process = vec![task_id.to_string()];
+        let mut budget = TraversalBudget::new(task_id, self.traversal_limit);
 
         while let Some(current_id) = to_process.pop() {
             let children = self.fetch_children_ids(&current_id).await?;
             for child_id in children {
                 if !all_descendants.contains(&child_id) {
+                    budget.visit()?;
                     all_descendants.push(child_id.clone());
                     to_process.push(child_id);
                 }
@@ -551,7 +1076,7 @@ impl<'a> GraphQueries<'a> {
     async fn fetch_children_ids(&self, parent_id: &str) -> DbResult<Vec<String>> {
         // Children are tasks that have a child_of edge pointing to this task
         let query = format!(
-            "SELECT id FROM task WHERE ->child_of->task CONTAINS task:{}",
+            "SELECT id FROM task WHERE ->child_of->task CONTAINS task:{} AND deleted_at = NONE",
             parent_id
         );
 
@@ -573,19 +1098,46 @@ impl<'a> GraphQueries<'a> {
     /// # Returns
     ///
     /// A vector of ancestor task IDs, ordered from immediate parent to root.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError::GraphCycle` if the `child_of` edges loop back on
+    /// themselves instead of reaching a root.
     pub async fn get_ancestor_chain(&self, task_id: &str) -> DbResult<Vec<String>> {
+        self.get_ancestor_chain_with(task_id, &PendingEdges::new())
+            .await
+    }
+
+    /// [`GraphQueries::get_ancestor_chain`], where a pending parent takes
+    /// the place of a stored one.
+    pub async fn get_ancestor_chain_with(
+        &self,
+        task_id: &str,
+        pending: &PendingEdges,
+    ) -> DbResult<Vec<String>> {
         let mut ancestors = Vec::new();
         let mut current_id = task_id.to_string();
+        let mut seen = HashSet::from([current_id.clone()]);
 
         // Follow child_of edges upward
         loop {
-            let query = format!("SELECT VALUE out FROM task:{}->child_of", current_id);
-            let mut result = self.client.query(&query).await?;
-            let parents: Vec<surrealdb::sql::Thing> = result.take(0)?;
-
-            match parents.first() {
-                Some(parent) => {
-                    let parent_id = parent.id.to_string();
+            let parent = match pending.child_of.get(&current_id) {
+                Some(parent) => Some(parent.clone()),
+                None => {
+                    let query = format!("SELECT VALUE out FROM task:{}->child_of", current_id);
+                    let mut result = self.client.query(&query).await?;
+                    let parents: Vec<surrealdb::sql::Thing> = result.take(0)?;
+                    parents.first().map(|parent| parent.id.to_string())
+                }
+            };
+
+            match parent {
+                Some(parent_id) => {
+                    if !seen.insert(parent_id.clone()) {
+                        let mut chain = vec![task_id.to_string()];
+                        chain.extend(ancestors);
+                        return Err(cycle_error("child_of", &chain, &parent_id));
+                    }
                     ancestors.push(parent_id.clone());
                     current_id = parent_id;
                 }
@@ -615,7 +1167,7 @@ impl<'a> GraphQueries<'a> {
         let query = format!(
             r#"SELECT id FROM task
                WHERE ->child_of->task CONTAINS task:{}
-               AND status != "done""#,
+               AND status != "done" AND deleted_at = NONE"#,
             task_id
         );
 
@@ -705,7 +1257,7 @@ impl<'a> GraphQueries<'a> {
         let query = format!(
             r#"SELECT id FROM task
                WHERE <-depends_on<-task CONTAINS task:{}
-               AND status != "done""#,
+               AND status != "done" AND deleted_at = NONE"#,
             task_id
         );
 
@@ -715,6 +1267,127 @@ impl<'a> GraphQueries<'a> {
         Ok(blockers.into_iter().map(|r| r.id.id.to_string()).collect())
     }
 
+    /// Get the incomplete blockers of every task that has any.
+    ///
+    /// The batch form of [`GraphQueries::get_incomplete_blockers`]: one
+    /// query over the `depends_on` edges instead of one per task. Tasks with
+    /// no incomplete blockers are absent from the map.
+    ///
+    /// # Returns
+    ///
+    /// A map from task ID to the sorted IDs of its blockers that are not done.
+    pub async fn get_incomplete_blockers_batch(&self) -> DbResult<HashMap<String, Vec<String>>> {
+        let query = r#"SELECT in AS task, out AS blocker FROM depends_on
+               WHERE out.status != "done" AND out.deleted_at = NONE"#;
+
+        #[derive(Debug, Deserialize)]
+        struct EdgeRow {
+            task: surrealdb::sql::Thing,
+            blocker: surrealdb::sql::Thing,
+        }
+
+        let mut result = self.client.query(query).await?;
+        let rows: Vec<EdgeRow> = result.take(0)?;
+
+        let mut blockers: HashMap<String, Vec<String>> = HashMap::new();
+        for row in rows {
+            blockers
+                .entry(row.task.id.to_string())
+                .or_default()
+                .push(row.blocker.id.to_string());
+        }
+        for ids in blockers.values_mut() {
+            ids.sort();
+            ids.dedup();
+        }
+        Ok(blockers)
+    }
+
+    /// Count the incomplete blockers of every task that has any.
+    ///
+    /// Runs one aggregation over the `depends_on` edges instead of a query
+    /// per task, so listings can show the count for every row. Tasks with
+    /// no incomplete blockers are absent from the map.
+    ///
+    /// # Returns
+    ///
+    /// A map from task ID to the number of its blockers that are not done.
+    pub async fn count_incomplete_blockers(&self) -> DbResult<HashMap<String, usize>> {
+        let query = r#"SELECT in AS task, count() AS blockers FROM depends_on
+               WHERE out.status != "done" AND out.deleted_at = NONE
+               GROUP BY task"#;
+
+        #[derive(Debug, Deserialize)]
+        struct BlockerCountRow {
+            task: surrealdb::sql::Thing,
+            blockers: usize,
+        }
+
+        let mut result = self.client.query(query).await?;
+        let rows: Vec<BlockerCountRow> = result.take(0)?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| (row.task.id.to_string(), row.blockers))
+            .collect())
+    }
+
+    /// Sum the estimates of a task and all its descendants.
+    ///
+    /// `total_minutes` covers every task; `remaining_minutes` only those not
+    /// in a completion status. Descendants without an estimate count as zero
+    /// and are reported in `unestimated`.
+    ///
+    /// # Arguments
+    ///
+    /// * `task_id` - The task at the top of the rollup
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError::TraversalLimit` if there are more descendants than
+    /// the traversal limit.
+    pub async fn estimate_rollup(&self, task_id: &str) -> DbResult<EstimateRollup> {
+        let descendants = self.get_all_descendants(task_id).await?;
+
+        let ids_str = std::iter::once(task_id)
+            .chain(descendants.iter().map(String::as_str))
+            .map(|id| format!("task:{}", id))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let query = format!(
+            "SELECT id, status, estimate_minutes FROM task WHERE id IN [{}]",
+            ids_str
+        );
+
+        #[derive(Debug, Deserialize)]
+        struct EstimateRow {
+            id: surrealdb::sql::Thing,
+            status: String,
+            estimate_minutes: Option<u32>,
+        }
+
+        let mut result = self.client.query(&query).await?;
+        let rows: Vec<EstimateRow> = result.take(0)?;
+
+        let mut rollup = EstimateRollup::default();
+        for row in rows {
+            let Some(minutes) = row.estimate_minutes.map(u64::from) else {
+                if row.id.id.to_string() != task_id {
+                    rollup.unestimated += 1;
+                }
+                continue;
+            };
+            rollup.total_minutes += minutes;
+            let complete = Status::parse(&row.status)
+                .is_some_and(|status| self.completion_statuses.contains(&status));
+            if !complete {
+                rollup.remaining_minutes += minutes;
+            }
+        }
+
+        Ok(rollup)
+    }
+
     /// Get all incomplete dependencies for a task with their details.
     ///
     /// Returns tasks that this task depends on (blockers) which are not yet done,
@@ -734,7 +1407,7 @@ impl<'a> GraphQueries<'a> {
         let query = format!(
             r#"SELECT id, title, level, status FROM task
                WHERE <-depends_on<-task CONTAINS task:{}
-               AND status != "done""#,
+               AND status != "done" AND deleted_at = NONE"#,
             task_id
         );
 
@@ -771,7 +1444,8 @@ impl<'a> GraphQueries<'a> {
     pub async fn get_unblocked_tasks(&self, task_id: &str) -> DbResult<Vec<(String, String)>> {
         // Find all tasks that depend on this task
         let dependents_query = format!(
-            "SELECT id, title FROM task WHERE ->depends_on->task CONTAINS task:{}",
+            "SELECT id, title FROM task \
+             WHERE ->depends_on->task CONTAINS task:{} AND deleted_at = NONE",
             task_id
         );
 
@@ -796,7 +1470,7 @@ impl<'a> GraphQueries<'a> {
                 "SELECT count() as cnt FROM task \
                  WHERE <-depends_on<-task CONTAINS task:{} \
                  AND id != task:{} \
-                 AND status != 'done' \
+                 AND status != 'done' AND deleted_at = NONE \
                  GROUP ALL",
                 dep_id, task_id
             );
@@ -825,7 +1499,9 @@ impl<'a> GraphQueries<'a> {
     /// Get progress information for a task based on its descendants.
     ///
     /// For a leaf task (no children), returns progress based on the task's own status.
-    /// For a parent task, recursively counts all descendants and how many are done.
+    /// For a parent task, recursively counts all descendants and how many are in
+    /// one of the completion statuses (`done` unless configured otherwise via
+    /// `with_completion_statuses`).
     ///
     /// # Arguments
     ///
@@ -860,7 +1536,10 @@ impl<'a> GraphQueries<'a> {
 
             let rows: Vec<StatusRow> = result.take(0)?;
 
-            let is_done = rows.first().map(|r| r.status == "done").unwrap_or(false);
+            let is_done = rows
+                .first()
+                .and_then(|r| Status::parse(&r.status))
+                .is_some_and(|status| self.completion_statuses.contains(&status));
 
             if is_done {
                 return Ok(Progress::new(1, 1));
@@ -877,13 +1556,20 @@ impl<'a> GraphQueries<'a> {
             .collect();
         let ids_str = ids_quoted.join(", ");
 
+        let completion_str = self
+            .completion_statuses
+            .iter()
+            .map(|s| format!("\"{}\"", s.as_str()))
+            .collect::<Vec<_>>()
+            .join(", ");
+
         let query = format!(
             r#"SELECT count() as total,
-                      count(status = "done") as done
+                      count(status IN [{}]) as done
                FROM task
                WHERE id IN [{}]
                GROUP ALL"#,
-            ids_str
+            completion_str, ids_str
         );
 
         let mut result = self.client.query(&query).await?;
@@ -905,11 +1591,30 @@ impl<'a> GraphQueries<'a> {
     }
 }
 
+/// Describe the tasks unblocked by completing another, as returned by
+/// [`GraphQueries::get_unblocked_tasks`].
+///
+/// Every surface that completes tasks uses this so the wording matches:
+/// `Unblocked: a (First), b (Second)`. Returns an empty string when nothing
+/// was unblocked, meaning there is no message to show.
+pub fn format_unblocked(tasks: &[(String, String)]) -> String {
+    if tasks.is_empty() {
+        return String::new();
+    }
+    let items: Vec<String> = tasks
+        .iter()
+        .map(|(id, title)| format!("{} ({})", id, title))
+        .collect();
+    format!("Unblocked: {}", items.join(", "))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::Database;
     use std::env;
+    use surrealdb::Surreal;
+    use surrealdb::engine::local::Db;
 
     /// Helper to create a test database
     async fn setup_test_db() -> (Database, std::path::PathBuf) {
@@ -1069,6 +1774,30 @@ mod tests {
         cleanup(&temp_dir);
     }
 
+    #[tokio::test]
+    async fn test_get_blockers_cycle_errors() {
+        let (db, temp_dir) = setup_test_db().await;
+        let graph = GraphQueries::new(db.client());
+
+        // task1 -> a -> b -> a, inserted directly to bypass cycle checks
+        for id in ["task1", "a", "b"] {
+            create_task(&db, id, id, "task", "todo").await;
+        }
+        create_depends_on(&db, "task1", "a").await;
+        create_depends_on(&db, "a", "b").await;
+        create_depends_on(&db, "b", "a").await;
+
+        let (relation, cycle) = expect_cycle(graph.get_blockers("task1", None)).await;
+        assert_eq!(relation, "depends_on");
+        assert_eq!(cycle, "a -> b -> a");
+
+        // A depth limit that stops before the loop closes still succeeds
+        let blockers = graph.get_blockers("task1", Some(2)).await.unwrap();
+        assert_eq!(blockers[0].children[0].id, "b");
+
+        cleanup(&temp_dir);
+    }
+
     #[tokio::test]
     async fn test_get_blockers_multiple_direct() {
         let (db, temp_dir) = setup_test_db().await;
@@ -1299,6 +2028,16 @@ mod tests {
         assert_eq!(formatted, "a -> b -> c -> a");
     }
 
+    #[test]
+    fn test_close_cycle() {
+        let path: Vec<String> = ["x", "a", "b"].map(String::from).to_vec();
+        assert_eq!(GraphQueries::close_cycle(&path, "a"), vec!["a", "b", "a"]);
+        assert_eq!(
+            GraphQueries::close_cycle(&path, "x"),
+            vec!["x", "a", "b", "x"]
+        );
+    }
+
     #[tokio::test]
     async fn test_detect_cycle_no_cycle() {
         let (db, temp_dir) = setup_test_db().await;
@@ -1333,6 +2072,288 @@ mod tests {
     // get_all_descendants tests
     // ========================================
 
+    #[tokio::test]
+    async fn test_traversals_skip_deleted_tasks() {
+        let (db, temp_dir) = setup_test_db().await;
+        create_task(&db, "main", "Main", "ticket", "todo").await;
+        create_task(&db, "live", "Live", "task", "todo").await;
+        create_task(&db, "gone", "Gone", "task", "todo").await;
+        create_depends_on(&db, "main", "live").await;
+        create_depends_on(&db, "main", "gone").await;
+        create_child_of(&db, "live", "main").await;
+        create_child_of(&db, "gone", "main").await;
+        db.client()
+            .query("UPDATE task:gone SET deleted_at = time::now()")
+            .await
+            .unwrap();
+
+        let graph = GraphQueries::new(db.client());
+        let blockers = graph.get_blockers("main", None).await.unwrap();
+        let ids: Vec<&str> = blockers.iter().map(|b| b.id.as_str()).collect();
+        assert_eq!(ids, vec!["live"]);
+        assert_eq!(
+            graph.get_incomplete_blockers("main").await.unwrap(),
+            vec!["live"]
+        );
+        assert_eq!(
+            graph.get_all_descendants("main").await.unwrap(),
+            vec!["live"]
+        );
+        assert_eq!(
+            graph.count_incomplete_blockers().await.unwrap().get("main"),
+            Some(&1)
+        );
+        assert_eq!(
+            graph.critical_path("main").await.unwrap(),
+            vec!["main", "live"]
+        );
+        assert!(graph.find_path("main", "gone").await.unwrap().is_none());
+        assert!(
+            !graph
+                .topological_order()
+                .await
+                .unwrap()
+                .contains(&"gone".to_string())
+        );
+
+        // Cycle checks still see the tombstone's edges
+        assert!(graph.would_create_cycle("gone", "main").await.unwrap());
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_critical_path_no_dependencies() {
+        let (db, temp_dir) = setup_test_db().await;
+        create_task(&db, "solo", "Solo", "task", "todo").await;
+
+        let graph = GraphQueries::new(db.client());
+        assert_eq!(graph.critical_path("solo").await.unwrap(), vec!["solo"]);
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_critical_path_takes_longest_branch() {
+        let (db, temp_dir) = setup_test_db().await;
+        // a -> b -> e, and a -> c -> d -> e: the second branch is longer
+        for id in ["a", "b", "c", "d", "e"] {
+            create_task(&db, id, id, "task", "todo").await;
+        }
+        create_depends_on(&db, "a", "b").await;
+        create_depends_on(&db, "b", "e").await;
+        create_depends_on(&db, "a", "c").await;
+        create_depends_on(&db, "c", "d").await;
+        create_depends_on(&db, "d", "e").await;
+
+        let graph = GraphQueries::new(db.client());
+        assert_eq!(
+            graph.critical_path("a").await.unwrap(),
+            vec!["a", "c", "d", "e"]
+        );
+        // Equal branches go to the smallest blocker ID
+        assert_eq!(graph.critical_path("b").await.unwrap(), vec!["b", "e"]);
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_critical_path_cycle_errors() {
+        let (db, temp_dir) = setup_test_db().await;
+        create_task(&db, "a", "A", "task", "todo").await;
+        create_task(&db, "b", "B", "task", "todo").await;
+        create_task(&db, "c", "C", "task", "todo").await;
+        create_depends_on(&db, "a", "b").await;
+        create_depends_on(&db, "b", "c").await;
+        create_depends_on(&db, "c", "b").await;
+
+        let graph = GraphQueries::new(db.client());
+        match graph.critical_path("a").await {
+            Err(DbError::GraphCycle { relation, cycle }) => {
+                assert_eq!(relation, "depends_on");
+                assert_eq!(cycle, "b -> c -> b");
+            }
+            other => panic!("Expected GraphCycle, got {:?}", other),
+        }
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_topological_order_puts_dependencies_first() {
+        let (db, temp_dir) = setup_test_db().await;
+        // deploy -> build -> {fetch, lint}; docs is independent
+        for id in ["deploy", "build", "fetch", "lint", "docs"] {
+            create_task(&db, id, id, "task", "todo").await;
+        }
+        create_depends_on(&db, "deploy", "build").await;
+        create_depends_on(&db, "build", "lint").await;
+        create_depends_on(&db, "build", "fetch").await;
+
+        let graph = GraphQueries::new(db.client());
+        let order = graph.topological_order().await.unwrap();
+        assert_eq!(order, vec!["docs", "fetch", "lint", "build", "deploy"]);
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_topological_order_empty() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        let graph = GraphQueries::new(db.client());
+        assert!(graph.topological_order().await.unwrap().is_empty());
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_topological_order_cycle_names_its_tasks() {
+        let (db, temp_dir) = setup_test_db().await;
+        for id in ["a", "b", "c", "d"] {
+            create_task(&db, id, id, "task", "todo").await;
+        }
+        // a waits on the b <-> c cycle; d is fine
+        create_depends_on(&db, "a", "b").await;
+        create_depends_on(&db, "b", "c").await;
+        create_depends_on(&db, "c", "b").await;
+
+        let graph = GraphQueries::new(db.client());
+        match graph.topological_order().await {
+            Err(DbError::GraphCycle { relation, cycle }) => {
+                assert_eq!(relation, "depends_on");
+                assert_eq!(cycle, "b -> c -> b");
+            }
+            other => panic!("Expected GraphCycle, got {:?}", other),
+        }
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_blocker_impact_counts_transitive_dependents_once() {
+        let (db, temp_dir) = setup_test_db().await;
+        // Diamond: deploy and docs both reach lint through build and test
+        for id in ["deploy", "build", "test", "lint", "docs", "gone"] {
+            create_task(&db, id, id, "task", "todo").await;
+        }
+        create_depends_on(&db, "deploy", "build").await;
+        create_depends_on(&db, "deploy", "test").await;
+        create_depends_on(&db, "build", "lint").await;
+        create_depends_on(&db, "test", "lint").await;
+        create_depends_on(&db, "docs", "test").await;
+        create_depends_on(&db, "gone", "lint").await;
+        db.client()
+            .query("UPDATE task:gone SET deleted_at = time::now()")
+            .await
+            .unwrap();
+
+        let graph = GraphQueries::new(db.client());
+        let impact = graph.blocker_impact().await.unwrap();
+        assert_eq!(
+            impact,
+            vec![
+                ("lint".to_string(), 4),
+                ("test".to_string(), 2),
+                ("build".to_string(), 1),
+                ("deploy".to_string(), 0),
+                ("docs".to_string(), 0),
+            ]
+        );
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_estimate_rollup_sums_remaining_and_counts_unestimated() {
+        let (db, temp_dir) = setup_test_db().await;
+        db.client()
+            .query(
+                r#"CREATE task:epic SET title = "Epic", level = "epic", status = "in_progress";
+                   CREATE task:t1 SET title = "One", level = "ticket", status = "done",
+                       estimate_minutes = 120;
+                   CREATE task:t2 SET title = "Two", level = "ticket", status = "todo",
+                       estimate_minutes = 90;
+                   CREATE task:t3 SET title = "Three", level = "task", status = "todo",
+                       estimate_minutes = 30;
+                   CREATE task:t4 SET title = "Four", level = "task", status = "todo";
+                   RELATE task:t1 -> child_of -> task:epic;
+                   RELATE task:t2 -> child_of -> task:epic;
+                   RELATE task:t3 -> child_of -> task:t2;
+                   RELATE task:t4 -> child_of -> task:t2"#,
+            )
+            .await
+            .unwrap();
+
+        let graph = GraphQueries::new(db.client());
+        assert_eq!(
+            graph.estimate_rollup("epic").await.unwrap(),
+            EstimateRollup {
+                total_minutes: 240,
+                remaining_minutes: 120,
+                unestimated: 1,
+            }
+        );
+
+        // The task's own estimate is included; it is not counted as unestimated
+        assert_eq!(
+            graph.estimate_rollup("t3").await.unwrap(),
+            EstimateRollup {
+                total_minutes: 30,
+                remaining_minutes: 30,
+                unestimated: 0,
+            }
+        );
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_would_create_hierarchy_cycle() {
+        let (db, temp_dir) = setup_test_db().await;
+        // epic <- ticket <- task, plus an unrelated other
+        create_task(&db, "epic", "Epic", "epic", "todo").await;
+        create_task(&db, "ticket", "Ticket", "ticket", "todo").await;
+        create_task(&db, "task", "Task", "task", "todo").await;
+        create_task(&db, "other", "Other", "epic", "todo").await;
+        create_child_of(&db, "ticket", "epic").await;
+        create_child_of(&db, "task", "ticket").await;
+
+        let graph = GraphQueries::new(db.client());
+        assert!(
+            graph
+                .would_create_hierarchy_cycle("epic", "epic")
+                .await
+                .unwrap()
+        );
+        assert!(
+            graph
+                .would_create_hierarchy_cycle("epic", "task")
+                .await
+                .unwrap()
+        );
+        assert!(
+            graph
+                .would_create_hierarchy_cycle("ticket", "task")
+                .await
+                .unwrap()
+        );
+        assert!(
+            !graph
+                .would_create_hierarchy_cycle("task", "epic")
+                .await
+                .unwrap()
+        );
+        assert!(
+            !graph
+                .would_create_hierarchy_cycle("ticket", "other")
+                .await
+                .unwrap()
+        );
+
+        cleanup(&temp_dir);
+    }
+
     #[tokio::test]
     async fn test_get_all_descendants_none() {
         let (db, temp_dir) = setup_test_db().await;
@@ -1437,6 +2458,43 @@ mod tests {
         cleanup(&temp_dir);
     }
 
+    /// Unwrap a `GraphCycle` error, failing if the call took too long.
+    async fn expect_cycle<T: std::fmt::Debug>(
+        call: impl std::future::Future<Output = DbResult<T>>,
+    ) -> (String, String) {
+        let result = tokio::time::timeout(std::time::Duration::from_secs(10), call)
+            .await
+            .expect("traversal did not terminate");
+        match result {
+            Err(DbError::GraphCycle { relation, cycle }) => (relation, cycle),
+            other => panic!("Expected GraphCycle, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_ancestor_chain_cycle_errors() {
+        let (db, temp_dir) = setup_test_db().await;
+        let graph = GraphQueries::new(db.client());
+
+        // leaf -> a -> b -> c -> a (corrupt hierarchy)
+        for id in ["leaf", "a", "b", "c"] {
+            create_task(&db, id, id, "task", "todo").await;
+        }
+        create_child_of(&db, "leaf", "a").await;
+        create_child_of(&db, "a", "b").await;
+        create_child_of(&db, "b", "c").await;
+        create_child_of(&db, "c", "a").await;
+
+        let (relation, cycle) = expect_cycle(graph.get_ancestor_chain("leaf")).await;
+        assert_eq!(relation, "child_of");
+        assert_eq!(cycle, "a -> b -> c -> a");
+
+        let (_, cycle) = expect_cycle(graph.get_ancestor_chain("b")).await;
+        assert_eq!(cycle, "b -> c -> a -> b");
+
+        cleanup(&temp_dir);
+    }
+
     // ========================================
     // has_incomplete_children tests
     // ========================================
@@ -1544,6 +2602,55 @@ mod tests {
         cleanup(&temp_dir);
     }
 
+    #[tokio::test]
+    async fn test_count_incomplete_blockers_matches_per_task_query() {
+        let (db, temp_dir) = setup_test_db().await;
+        let graph = GraphQueries::new(db.client());
+
+        create_task(&db, "done1", "Done", "task", "done").await;
+        create_task(&db, "open1", "Open 1", "task", "todo").await;
+        create_task(&db, "open2", "Open 2", "task", "in_progress").await;
+        create_task(&db, "a", "A", "task", "todo").await;
+        create_task(&db, "b", "B", "task", "todo").await;
+        create_task(&db, "c", "C", "task", "todo").await;
+
+        // a: two open blockers and a done one; b: only done; c: one open
+        create_depends_on(&db, "a", "open1").await;
+        create_depends_on(&db, "a", "open2").await;
+        create_depends_on(&db, "a", "done1").await;
+        create_depends_on(&db, "b", "done1").await;
+        create_depends_on(&db, "c", "open2").await;
+        create_depends_on(&db, "open2", "open1").await;
+
+        let counts = graph.count_incomplete_blockers().await.unwrap();
+        for id in ["done1", "open1", "open2", "a", "b", "c"] {
+            let expected = graph.get_incomplete_blockers(id).await.unwrap().len();
+            assert_eq!(
+                counts.get(id).copied().unwrap_or(0),
+                expected,
+                "blocker count for {}",
+                id
+            );
+        }
+        assert_eq!(counts.get("a"), Some(&2));
+        assert!(!counts.contains_key("b"));
+
+        let batch = graph.get_incomplete_blockers_batch().await.unwrap();
+        for id in ["done1", "open1", "open2", "a", "b", "c"] {
+            let mut expected = graph.get_incomplete_blockers(id).await.unwrap();
+            expected.sort();
+            assert_eq!(
+                batch.get(id).cloned().unwrap_or_default(),
+                expected,
+                "blockers of {}",
+                id
+            );
+        }
+        assert_eq!(batch["a"], vec!["open1", "open2"]);
+
+        cleanup(&temp_dir);
+    }
+
     // ========================================
     // get_incomplete_descendants tests
     // ========================================
@@ -1800,6 +2907,26 @@ mod tests {
         assert_eq!(progress.percentage, 33);
     }
 
+    #[test]
+    fn test_progress_rounding_never_reaches_boundaries_early() {
+        // 99.5% would round to 100% but not everything is done
+        let progress = Progress::new(199, 200);
+        assert_eq!(progress.percentage, 99);
+        assert!(!progress.is_complete());
+
+        // 0.5% would round to 0% but something is done
+        let progress = Progress::new(1, 200);
+        assert_eq!(progress.percentage, 1);
+        assert!(!progress.is_empty());
+
+        let progress = Progress::new(1, 1000);
+        assert_eq!(progress.percentage, 1);
+        let progress = Progress::new(999, 1000);
+        assert_eq!(progress.percentage, 99);
+        let progress = Progress::new(200, 200);
+        assert_eq!(progress.percentage, 100);
+    }
+
     #[test]
     fn test_progress_clone_and_eq() {
         let p1 = Progress::new(3, 5);
@@ -1955,4 +3082,311 @@ mod tests {
 
         cleanup(&temp_dir);
     }
+
+    #[test]
+    fn test_progress_from_statuses() {
+        let statuses = [Status::Done, Status::PendingReview, Status::Todo];
+
+        let progress = Progress::from_statuses(&statuses, DEFAULT_COMPLETION_STATUSES);
+        assert_eq!(progress.done_count, 1);
+        assert_eq!(progress.percentage, 33);
+
+        let progress = Progress::from_statuses(&statuses, &[Status::Done, Status::PendingReview]);
+        assert_eq!(progress.done_count, 2);
+        assert_eq!(progress.percentage, 67);
+    }
+
+    #[tokio::test]
+    async fn test_get_progress_pending_review_counts_when_configured() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        // Epic with 4 tickets: 1 done, 2 pending review, 1 todo
+        create_task(&db, "epic1", "Epic 1", "epic", "in_progress").await;
+        create_task(&db, "ticket1", "Ticket 1", "ticket", "done").await;
+        create_task(&db, "ticket2", "Ticket 2", "ticket", "pending_review").await;
+        create_task(&db, "ticket3", "Ticket 3", "ticket", "pending_review").await;
+        create_task(&db, "ticket4", "Ticket 4", "ticket", "todo").await;
+
+        for ticket in ["ticket1", "ticket2", "ticket3", "ticket4"] {
+            create_child_of(&db, ticket, "epic1").await;
+        }
+
+        // Default: only done counts
+        let default_progress = GraphQueries::new(db.client())
+            .get_progress("epic1")
+            .await
+            .unwrap();
+        assert_eq!(default_progress.done_count, 1);
+        assert_eq!(default_progress.percentage, 25);
+
+        // Widened: pending_review counts too
+        let widened = GraphQueries::new(db.client())
+            .with_completion_statuses([Status::Done, Status::PendingReview])
+            .get_progress("epic1")
+            .await
+            .unwrap();
+        assert_eq!(widened.done_count, 3);
+        assert_eq!(widened.total_count, 4);
+        assert_eq!(widened.percentage, 75);
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_get_progress_leaf_pending_review_when_configured() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        create_task(&db, "task1", "Task 1", "task", "pending_review").await;
+
+        let graph = GraphQueries::new(db.client());
+        assert_eq!(graph.get_progress("task1").await.unwrap().percentage, 0);
+
+        let graph = graph.with_completion_statuses([Status::Done, Status::PendingReview]);
+        assert!(graph.get_progress("task1").await.unwrap().is_complete());
+
+        cleanup(&temp_dir);
+    }
+
+    #[test]
+    fn test_with_completion_statuses_empty_keeps_default() {
+        let client = Surreal::<Db>::init();
+        let graph = GraphQueries::new(&client).with_completion_statuses([]);
+        assert_eq!(graph.completion_statuses, DEFAULT_COMPLETION_STATUSES);
+    }
+
+    // ========================================
+    // Nearest incomplete blocker path tests
+    // ========================================
+
+    #[tokio::test]
+    async fn test_nearest_incomplete_blocker_none_when_unblocked() {
+        let (db, temp_dir) = setup_test_db().await;
+        let graph = GraphQueries::new(db.client());
+
+        create_task(&db, "task1", "Task", "task", "todo").await;
+        create_task(&db, "done1", "Done", "task", "done").await;
+        create_depends_on(&db, "task1", "done1").await;
+
+        let path = graph
+            .find_path_to_nearest_incomplete_blocker("task1")
+            .await
+            .unwrap();
+        assert!(path.is_none());
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_nearest_incomplete_blocker_direct() {
+        let (db, temp_dir) = setup_test_db().await;
+        let graph = GraphQueries::new(db.client());
+
+        create_task(&db, "task1", "Task", "task", "todo").await;
+        create_task(&db, "b1", "Blocker", "task", "in_progress").await;
+        create_depends_on(&db, "task1", "b1").await;
+
+        let path = graph
+            .find_path_to_nearest_incomplete_blocker("task1")
+            .await
+            .unwrap();
+        assert_eq!(path, Some(vec!["task1".to_string(), "b1".to_string()]));
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_nearest_incomplete_blocker_stops_at_done_blockers() {
+        let (db, temp_dir) = setup_test_db().await;
+        let graph = GraphQueries::new(db.client());
+
+        // task1 -> b1 (done) -> b2 (todo): every direct blocker is done
+        create_task(&db, "task1", "Task", "task", "todo").await;
+        create_task(&db, "b1", "Done Blocker", "task", "done").await;
+        create_task(&db, "b2", "Open Blocker", "task", "todo").await;
+        create_depends_on(&db, "task1", "b1").await;
+        create_depends_on(&db, "b1", "b2").await;
+
+        let path = graph
+            .find_path_to_nearest_incomplete_blocker("task1")
+            .await
+            .unwrap();
+        assert!(path.is_none());
+        assert!(
+            graph
+                .get_incomplete_blockers("task1")
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_nearest_incomplete_blocker_follows_open_chain() {
+        let (db, temp_dir) = setup_test_db().await;
+        let graph = GraphQueries::new(db.client());
+
+        // task1 -> b1 (todo) -> b2 (todo) -> b3 (done)
+        create_task(&db, "task1", "Task", "task", "todo").await;
+        create_task(&db, "b1", "Blocked Blocker", "task", "todo").await;
+        create_task(&db, "b2", "Open Blocker", "task", "todo").await;
+        create_task(&db, "b3", "Done Blocker", "task", "done").await;
+        create_depends_on(&db, "task1", "b1").await;
+        create_depends_on(&db, "b1", "b2").await;
+        create_depends_on(&db, "b2", "b3").await;
+
+        let path = graph
+            .find_path_to_nearest_incomplete_blocker("task1")
+            .await
+            .unwrap();
+        assert_eq!(
+            path,
+            Some(vec![
+                "task1".to_string(),
+                "b1".to_string(),
+                "b2".to_string()
+            ])
+        );
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_nearest_incomplete_blocker_uses_completion_statuses() {
+        let (db, temp_dir) = setup_test_db().await;
+        let graph = GraphQueries::new(db.client())
+            .with_completion_statuses([Status::Done, Status::PendingReview]);
+
+        create_task(&db, "task1", "Task", "task", "todo").await;
+        create_task(&db, "b1", "Reviewed Blocker", "task", "pending_review").await;
+        create_depends_on(&db, "task1", "b1").await;
+
+        let path = graph
+            .find_path_to_nearest_incomplete_blocker("task1")
+            .await
+            .unwrap();
+        assert!(path.is_none());
+
+        cleanup(&temp_dir);
+    }
+
+    // ========================================
+    // Traversal Limit Tests
+    // ========================================
+
+    fn assert_traversal_limit<T: std::fmt::Debug>(result: DbResult<T>, root: &str, max: usize) {
+        match result {
+            Err(DbError::TraversalLimit { task_id, limit }) => {
+                assert_eq!(task_id, root);
+                assert_eq!(limit, max);
+            }
+            other => panic!("Expected TraversalLimit, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_traversal_limit_get_all_descendants() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        create_task(&db, "root", "Root", "epic", "todo").await;
+        for i in 0..5 {
+            let id = format!("child{}", i);
+            create_task(&db, &id, "Child", "ticket", "todo").await;
+            create_child_of(&db, &id, "root").await;
+        }
+
+        let limited = GraphQueries::new(db.client()).with_traversal_limit(3);
+        assert_traversal_limit(limited.get_all_descendants("root").await, "root", 3);
+
+        // The limit is inclusive: exactly as many tasks as allowed is fine
+        let exact = GraphQueries::new(db.client()).with_traversal_limit(5);
+        assert_eq!(exact.get_all_descendants("root").await.unwrap().len(), 5);
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_traversal_limit_get_blockers() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        // Chain: a -> b -> c -> d -> e
+        let ids = ["a", "b", "c", "d", "e"];
+        for id in ids {
+            create_task(&db, id, id, "task", "todo").await;
+        }
+        for pair in ids.windows(2) {
+            create_depends_on(&db, pair[0], pair[1]).await;
+        }
+
+        let limited = GraphQueries::new(db.client()).with_traversal_limit(2);
+        assert_traversal_limit(limited.get_blockers("a", None).await, "a", 2);
+
+        // A depth limit that keeps the tree within budget still succeeds
+        let blockers = limited.get_blockers("a", Some(2)).await.unwrap();
+        assert_eq!(blockers[0].id, "b");
+        assert_eq!(blockers[0].children[0].id, "c");
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_traversal_limit_detect_cycle() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        let ids = ["a", "b", "c", "d", "e"];
+        for id in ids {
+            create_task(&db, id, id, "task", "todo").await;
+        }
+        for pair in ids.windows(2) {
+            create_depends_on(&db, pair[0], pair[1]).await;
+        }
+
+        let limited = GraphQueries::new(db.client()).with_traversal_limit(3);
+        assert_traversal_limit(limited.detect_cycle("a").await, "a", 3);
+
+        let default = GraphQueries::new(db.client());
+        assert_eq!(default.detect_cycle("a").await.unwrap(), None);
+
+        cleanup(&temp_dir);
+    }
+
+    #[test]
+    fn test_traversal_limit_defaults_to_config() {
+        let client = Surreal::<Db>::init();
+        let graph = GraphQueries::new(&client);
+        assert_eq!(graph.traversal_limit, graph_settings().max_nodes);
+    }
+
+    fn unblocked(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(id, title)| (id.to_string(), title.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_format_unblocked_empty_has_no_message() {
+        assert_eq!(format_unblocked(&[]), "");
+    }
+
+    #[test]
+    fn test_format_unblocked_single() {
+        assert_eq!(
+            format_unblocked(&unblocked(&[("abc123", "Write docs")])),
+            "Unblocked: abc123 (Write docs)"
+        );
+    }
+
+    #[test]
+    fn test_format_unblocked_several() {
+        assert_eq!(
+            format_unblocked(&unblocked(&[
+                ("a", "First"),
+                ("b", "Second"),
+                ("c", "Third")
+            ])),
+            "Unblocked: a (First), b (Second), c (Third)"
+        );
+    }
 }
@@ -9,10 +9,18 @@ mod relationship;
 mod task;
 mod validation;
 
-pub use filter::{TaskFilter, TaskLister, TaskSummary};
-pub use graph::{BlockerNode, GraphQueries, Progress};
-pub use relationship::RelationshipRepository;
-pub use task::{TaskRepository, TaskUpdate};
+pub use filter::{
+    DEFAULT_PAGE_SIZE, SearchField, SearchHit, SortKey, TagMode, TaskFilter, TaskLister,
+    TaskStream, TaskSummary,
+};
+pub use graph::{
+    BlockerNode, DEFAULT_COMPLETION_STATUSES, EstimateRollup, GraphQueries, PendingEdges, Progress,
+    format_unblocked,
+};
+pub use relationship::{RelationshipRepository, SelfEdge};
+pub use task::{
+    BulkTask, DuplicateTitle, TaskRepository, TaskUpdate, TransitionOptions, TransitionOutcome,
+};
 pub use validation::{
     SectionRule, TriageValidationConfig, TriageValidationResult, TriageValidator, ValidationIssue,
     ValidationSeverity,
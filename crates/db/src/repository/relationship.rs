@@ -3,10 +3,9 @@
 //! Provides a repository pattern implementation for graph edge operations,
 //! encapsulating SurrealDB RELATE queries for child_of and depends_on edges.
 
-use crate::error::DbResult;
+use crate::error::{DbError, DbResult};
+use crate::retry::RetryClient;
 use serde::Deserialize;
-use surrealdb::Surreal;
-use surrealdb::engine::local::Db;
 
 /// Repository for task relationship (edge) operations
 ///
@@ -14,7 +13,7 @@ use surrealdb::engine::local::Db;
 /// - `child_of`: Parent-child hierarchy (child -> parent)
 /// - `depends_on`: Task dependencies (dependent -> dependency/blocker)
 pub struct RelationshipRepository<'a> {
-    client: &'a Surreal<Db>,
+    client: RetryClient<'a>,
 }
 
 /// Minimal row for checking edge existence
@@ -24,6 +23,15 @@ struct EdgeRow {
     id: surrealdb::sql::Thing,
 }
 
+/// An edge from a task to itself, which the commands refuse to create
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelfEdge {
+    /// Edge table (`child_of` or `depends_on`)
+    pub relation: &'static str,
+    /// The task at both ends of the edge
+    pub task_id: String,
+}
+
 /// Row for fetching task IDs from edge queries
 #[derive(Debug, Deserialize)]
 struct TaskIdRow {
@@ -32,8 +40,10 @@ struct TaskIdRow {
 
 impl<'a> RelationshipRepository<'a> {
     /// Create a new RelationshipRepository with the given database client
-    pub fn new(client: &'a Surreal<Db>) -> Self {
-        Self { client }
+    pub fn new(client: impl Into<RetryClient<'a>>) -> Self {
+        Self {
+            client: client.into(),
+        }
     }
 
     // ========================================
@@ -51,8 +61,14 @@ impl<'a> RelationshipRepository<'a> {
     ///
     /// # Errors
     ///
-    /// Returns `DbError::Query` if the database operation fails.
+    /// Returns `DbError::ValidationError` if both IDs are the same task, or
+    /// `DbError::Query` if the database operation fails.
     pub async fn create_child_of(&self, child_id: &str, parent_id: &str) -> DbResult<()> {
+        if child_id == parent_id {
+            return Err(DbError::ValidationError {
+                message: format!("Task '{}' cannot be its own parent", child_id),
+            });
+        }
         let query = format!("RELATE task:{} -> child_of -> task:{}", child_id, parent_id);
         self.client.query(&query).await?;
         Ok(())
@@ -131,6 +147,44 @@ impl<'a> RelationshipRepository<'a> {
         Ok(())
     }
 
+    /// Replace a task's parent in one transaction.
+    ///
+    /// The existing child_of edge is removed and, if `parent_id` is given, a
+    /// new one created, so the task is never left half-moved. `None` detaches
+    /// the task to the root level. Hierarchy cycles are not checked here; see
+    /// `GraphQueries::would_create_hierarchy_cycle`.
+    ///
+    /// # Arguments
+    ///
+    /// * `child_id` - The ID of the task to move
+    /// * `parent_id` - The ID of the new parent, or `None` for root level
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError::ValidationError` if the task would be its own
+    /// parent, or `DbError::Query` if the database operation fails.
+    pub async fn set_parent(&self, child_id: &str, parent_id: Option<&str>) -> DbResult<()> {
+        if parent_id == Some(child_id) {
+            return Err(DbError::ValidationError {
+                message: format!("Task '{}' cannot be its own parent", child_id),
+            });
+        }
+        let relate = parent_id
+            .map(|parent_id| {
+                format!(
+                    "RELATE task:{} -> child_of -> task:{};",
+                    child_id, parent_id
+                )
+            })
+            .unwrap_or_default();
+        let query = format!(
+            "BEGIN TRANSACTION; DELETE child_of WHERE in = task:{}; {} COMMIT TRANSACTION;",
+            child_id, relate
+        );
+        self.client.query(&query).await?.check()?;
+        Ok(())
+    }
+
     // ========================================
     // depends_on relationship methods
     // ========================================
@@ -146,8 +200,14 @@ impl<'a> RelationshipRepository<'a> {
     ///
     /// # Errors
     ///
-    /// Returns `DbError::Query` if the database operation fails.
+    /// Returns `DbError::ValidationError` if both IDs are the same task, or
+    /// `DbError::Query` if the database operation fails.
     pub async fn create_depends_on(&self, task_id: &str, depends_on_id: &str) -> DbResult<()> {
+        if task_id == depends_on_id {
+            return Err(DbError::ValidationError {
+                message: format!("Task '{}' cannot depend on itself", task_id),
+            });
+        }
         let query = format!(
             "RELATE task:{} -> depends_on -> task:{}",
             task_id, depends_on_id
@@ -320,6 +380,33 @@ impl<'a> RelationshipRepository<'a> {
             .collect())
     }
 
+    /// Find edges whose two ends are the same task.
+    ///
+    /// These can only come from raw database edits or older versions, since
+    /// `create_child_of` and `create_depends_on` reject them.
+    ///
+    /// # Returns
+    ///
+    /// The self-edges in `child_of` then `depends_on`, each sorted by task ID.
+    pub async fn find_self_edges(&self) -> DbResult<Vec<SelfEdge>> {
+        let mut edges = Vec::new();
+        for relation in ["child_of", "depends_on"] {
+            let query = format!("SELECT VALUE in FROM {} WHERE in = out", relation);
+            let mut result = self.client.query(&query).await?;
+            let tasks: Vec<surrealdb::sql::Thing> = result.take(0)?;
+
+            let mut task_ids: Vec<String> = tasks.into_iter().map(|t| t.id.to_raw()).collect();
+            task_ids.sort();
+            task_ids.dedup();
+            edges.extend(
+                task_ids
+                    .into_iter()
+                    .map(|task_id| SelfEdge { relation, task_id }),
+            );
+        }
+        Ok(edges)
+    }
+
     // ========================================
     // Cleanup methods
     // ========================================
@@ -524,6 +611,32 @@ mod tests {
         cleanup(&temp_dir);
     }
 
+    #[tokio::test]
+    async fn test_set_parent_moves_and_detaches() {
+        let (db, temp_dir) = setup_test_db().await;
+        let repo = RelationshipRepository::new(db.client());
+
+        create_task(&db, "old", "Old Parent").await;
+        create_task(&db, "new", "New Parent").await;
+        create_task(&db, "child1", "Child").await;
+        repo.create_child_of("child1", "old").await.unwrap();
+
+        repo.set_parent("child1", Some("new")).await.unwrap();
+        assert_eq!(
+            repo.get_parent("child1").await.unwrap(),
+            Some("new".to_string())
+        );
+        assert!(repo.get_children("old").await.unwrap().is_empty());
+
+        repo.set_parent("child1", None).await.unwrap();
+        assert!(repo.get_parent("child1").await.unwrap().is_none());
+
+        let err = repo.set_parent("child1", Some("child1")).await.unwrap_err();
+        assert!(matches!(err, DbError::ValidationError { .. }));
+
+        cleanup(&temp_dir);
+    }
+
     // ========================================
     // depends_on tests
     // ========================================
@@ -915,4 +1028,70 @@ mod tests {
 
         cleanup(&temp_dir);
     }
+
+    // ========================================
+    // Self-edge tests
+    // ========================================
+
+    #[tokio::test]
+    async fn test_self_edges_rejected() {
+        let (db, temp_dir) = setup_test_db().await;
+        let repo = RelationshipRepository::new(db.client());
+
+        create_task(&db, "task1", "Task 1").await;
+
+        let result = repo.create_child_of("task1", "task1").await;
+        assert!(
+            matches!(result, Err(DbError::ValidationError { ref message }) if message.contains("own parent")),
+            "got {:?}",
+            result
+        );
+        let result = repo.create_depends_on("task1", "task1").await;
+        assert!(
+            matches!(result, Err(DbError::ValidationError { ref message }) if message.contains("itself")),
+            "got {:?}",
+            result
+        );
+
+        assert_eq!(repo.get_parent("task1").await.unwrap(), None);
+        assert!(repo.get_dependencies("task1").await.unwrap().is_empty());
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_find_self_edges() {
+        let (db, temp_dir) = setup_test_db().await;
+        let repo = RelationshipRepository::new(db.client());
+
+        for id in ["a", "b", "c"] {
+            create_task(&db, id, id).await;
+        }
+        repo.create_child_of("b", "a").await.unwrap();
+        repo.create_depends_on("c", "a").await.unwrap();
+        assert!(repo.find_self_edges().await.unwrap().is_empty());
+
+        // Raw edits bypass the repository guards
+        db.client()
+            .query("RELATE task:c -> child_of -> task:c; RELATE task:b -> depends_on -> task:b")
+            .await
+            .unwrap();
+
+        let edges = repo.find_self_edges().await.unwrap();
+        assert_eq!(
+            edges,
+            vec![
+                SelfEdge {
+                    relation: "child_of",
+                    task_id: "c".to_string()
+                },
+                SelfEdge {
+                    relation: "depends_on",
+                    task_id: "b".to_string()
+                },
+            ]
+        );
+
+        cleanup(&temp_dir);
+    }
 }
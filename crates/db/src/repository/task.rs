@@ -5,18 +5,70 @@
 
 use crate::error::{DbError, DbResult};
 use crate::models::{CodeRef, Priority, Section, Status, Task};
+use crate::repository::{
+    GraphQueries, RelationshipRepository, TriageValidationResult, TriageValidator,
+};
+use crate::retry::{RetryClient, RetryQuery};
+use chrono::{DateTime, Utc};
 use serde::Deserialize;
 use serde_json;
-use surrealdb::Surreal;
-use surrealdb::engine::local::Db;
 use tracing::{debug, trace};
 
+/// Candidate IDs `clone_task` tries per copy before giving up
+const MAX_ID_ATTEMPTS: u32 = 100;
+
 /// Repository for task CRUD operations
 ///
 /// Encapsulates database queries for tasks, providing a clean API
 /// that hides the underlying SurrealDB implementation details.
 pub struct TaskRepository<'a> {
-    client: &'a Surreal<Db>,
+    client: RetryClient<'a>,
+}
+
+/// Tasks whose titles only differ in case or whitespace
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateTitle {
+    /// Title of the first task in the group, as stored
+    pub title: String,
+    /// IDs of every task in the group, sorted
+    pub task_ids: Vec<String>,
+}
+
+/// A task for [`TaskRepository::bulk_create`], with the edges from it
+#[derive(Debug, Clone)]
+pub struct BulkTask {
+    /// ID to create the task with
+    pub id: String,
+    /// The task data
+    pub task: Task,
+    /// Parent task, created or existing
+    pub parent: Option<String>,
+    /// Tasks this one depends on, created or existing
+    pub depends_on: Vec<String>,
+}
+
+/// Options for [`TaskRepository::transition`]
+#[derive(Debug, Clone, Default)]
+pub struct TransitionOptions {
+    /// Accept triage warnings (but not errors) when moving to todo
+    pub force: bool,
+    /// Skip triage validation entirely when moving to todo
+    pub skip_validation: bool,
+    /// When the work started, instead of now (in_progress and done only)
+    pub started_at: Option<DateTime<Utc>>,
+    /// When the work was completed, instead of now (done only)
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+/// What [`TaskRepository::transition`] found while moving a task
+#[derive(Debug, Clone, Default)]
+pub struct TransitionOutcome {
+    /// Triage validation result, for a validated move to todo
+    pub validation: Option<TriageValidationResult>,
+    /// Dependencies still open when the task was started, as (id, title, status)
+    pub incomplete_deps: Vec<(String, String, String)>,
+    /// Tasks a move to done unblocked, as (id, title)
+    pub unblocked: Vec<(String, String)>,
 }
 
 /// Update structure for partial task updates
@@ -44,6 +96,10 @@ pub struct TaskUpdate {
     pub set_started_at: bool,
     /// Whether to conditionally set started_at only if currently NULL (null-coalescing)
     pub set_started_at_if_null: bool,
+    /// Explicit started_at, overriding both `set_started_at` flags (if Some)
+    pub started_at: Option<DateTime<Utc>>,
+    /// Explicit completed_at (if Some)
+    pub completed_at: Option<DateTime<Utc>>,
     /// New status (if Some)
     pub status: Option<Status>,
 }
@@ -127,6 +183,18 @@ impl TaskUpdate {
         self
     }
 
+    /// Set started_at to an explicit time, e.g. when backfilling history
+    pub fn with_started_at(mut self, at: DateTime<Utc>) -> Self {
+        self.started_at = Some(at);
+        self
+    }
+
+    /// Set completed_at to an explicit time
+    pub fn with_completed_at(mut self, at: DateTime<Utc>) -> Self {
+        self.completed_at = Some(at);
+        self
+    }
+
     /// Set the task status
     pub fn with_status(mut self, status: Status) -> Self {
         self.status = Some(status);
@@ -146,6 +214,8 @@ impl TaskUpdate {
             || self.clear_sections
             || self.set_started_at
             || self.set_started_at_if_null
+            || self.started_at.is_some()
+            || self.completed_at.is_some()
             || self.status.is_some()
     }
 }
@@ -153,7 +223,6 @@ impl TaskUpdate {
 /// Minimal row for checking task existence
 #[derive(Debug, Deserialize)]
 struct IdOnly {
-    #[allow(dead_code)]
     id: surrealdb::sql::Thing,
 }
 
@@ -168,8 +237,10 @@ struct TaskTagsRow {
 
 impl<'a> TaskRepository<'a> {
     /// Create a new TaskRepository with the given database client
-    pub fn new(client: &'a Surreal<Db>) -> Self {
-        Self { client }
+    pub fn new(client: impl Into<RetryClient<'a>>) -> Self {
+        Self {
+            client: client.into(),
+        }
     }
 
     /// Check if a task with the given ID exists.
@@ -246,6 +317,192 @@ impl<'a> TaskRepository<'a> {
         Ok(())
     }
 
+    /// Copy a task into a new task with a fresh ID.
+    ///
+    /// The copy keeps the title (suffixed with " (copy)"), level, priority,
+    /// tags, description, sections and refs, and starts in the backlog with
+    /// its steps undone. Dependency edges are never copied; with
+    /// `with_children` the subtree is copied as well, each child under its
+    /// parent's copy and keeping its own title.
+    ///
+    /// # Arguments
+    ///
+    /// * `source_id` - The task to copy
+    /// * `with_children` - Whether to copy the task's descendants too
+    /// * `next_id` - Yields a candidate ID for a copy given its title;
+    ///   candidates that are already taken are skipped
+    ///
+    /// # Returns
+    ///
+    /// `(source_id, copy_id)` pairs with the requested task first and every
+    /// parent before its children.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError::NotFound` if the source task does not exist, or
+    /// `DbError::ValidationError` if no free ID could be found.
+    pub async fn clone_task<F>(
+        &self,
+        source_id: &str,
+        with_children: bool,
+        mut next_id: F,
+    ) -> DbResult<Vec<(String, String)>>
+    where
+        F: FnMut(&str) -> String,
+    {
+        debug!(
+            "Cloning task: {} (with children: {})",
+            source_id, with_children
+        );
+        let relationships = RelationshipRepository::new(self.client);
+        let mut copies = Vec::new();
+        // (task to copy, parent of its copy)
+        let mut pending = vec![(source_id.to_string(), None::<String>)];
+
+        while let Some((id, parent)) = pending.pop() {
+            let task = self.get_or_err(&id).await?;
+            let title = if parent.is_none() {
+                format!("{} (copy)", task.title)
+            } else {
+                task.title.clone()
+            };
+
+            let copy_id = self.free_id(&title, &mut next_id).await?;
+            self.create_copy(&copy_id, &title, &task).await?;
+            if let Some(parent) = &parent {
+                relationships.create_child_of(&copy_id, parent).await?;
+            }
+
+            if with_children {
+                let mut children = relationships.get_children(&id).await?;
+                // Sorted descending so the stack pops them in ID order
+                children.sort_unstable_by(|a, b| b.cmp(a));
+                pending.extend(
+                    children
+                        .into_iter()
+                        .map(|child| (child, Some(copy_id.clone()))),
+                );
+            }
+            copies.push((id, copy_id));
+        }
+
+        Ok(copies)
+    }
+
+    /// First candidate from `next_id` that no task uses yet.
+    async fn free_id<F>(&self, title: &str, next_id: &mut F) -> DbResult<String>
+    where
+        F: FnMut(&str) -> String,
+    {
+        for _ in 0..MAX_ID_ATTEMPTS {
+            let id = next_id(title);
+            if !self.exists(&id).await? {
+                return Ok(id);
+            }
+        }
+        Err(DbError::ValidationError {
+            message: format!(
+                "Could not find a free task ID for '{}' after {} attempts",
+                title, MAX_ID_ATTEMPTS
+            ),
+        })
+    }
+
+    /// Create the copy of `task` for `clone_task`.
+    async fn create_copy(&self, id: &str, title: &str, task: &Task) -> DbResult<()> {
+        let mut copy = Task::new(title, task.level.clone()).with_status(Status::Backlog);
+        copy.description = task.description.clone();
+        copy.priority = task.priority.clone();
+        copy.tags = task.tags.clone();
+        copy.code_refs = task.code_refs.clone();
+        copy.sections = task
+            .sections
+            .iter()
+            .cloned()
+            .map(|mut section| {
+                section.done = None;
+                section.done_at = None;
+                section
+            })
+            .collect();
+
+        let query = format!("CREATE task:{} SET {}", id, set_clause(id, &copy, 0)?);
+        bind_text_fields(self.client.query(&query), &copy, 0)
+            .await?
+            .check()?;
+        Ok(())
+    }
+
+    /// Create many tasks and their edges in a single transaction.
+    ///
+    /// Every task is created before any edge, so a `parent` or
+    /// `depends_on` may name a task that comes later in `tasks`. If any
+    /// statement fails (e.g. an ID that is already taken) nothing is
+    /// created.
+    ///
+    /// # Arguments
+    ///
+    /// * `tasks` - The tasks to create, with the edges from each
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError::ValidationError` if an ID appears twice or a task
+    /// refers to itself, and `DbError::Query` if the transaction fails.
+    pub async fn bulk_create(&self, tasks: &[BulkTask]) -> DbResult<()> {
+        debug!("Bulk creating {} tasks", tasks.len());
+        let mut seen = std::collections::HashSet::new();
+        for entry in tasks {
+            if !seen.insert(entry.id.as_str()) {
+                return Err(DbError::ValidationError {
+                    message: format!("Task ID '{}' appears more than once", entry.id),
+                });
+            }
+            if entry.parent.as_deref() == Some(entry.id.as_str())
+                || entry.depends_on.contains(&entry.id)
+            {
+                return Err(DbError::ValidationError {
+                    message: format!("Task '{}' cannot refer to itself", entry.id),
+                });
+            }
+        }
+        if tasks.is_empty() {
+            return Ok(());
+        }
+
+        let mut statements = vec!["BEGIN TRANSACTION".to_string()];
+        for (n, entry) in tasks.iter().enumerate() {
+            statements.push(format!(
+                "CREATE task:{} SET {}",
+                entry.id,
+                set_clause(&entry.id, &entry.task, n)?
+            ));
+        }
+        for entry in tasks {
+            if let Some(parent) = &entry.parent {
+                statements.push(format!(
+                    "RELATE task:{} -> child_of -> task:{}",
+                    entry.id, parent
+                ));
+            }
+            for blocker in &entry.depends_on {
+                statements.push(format!(
+                    "RELATE task:{} -> depends_on -> task:{}",
+                    entry.id, blocker
+                ));
+            }
+        }
+        statements.push("COMMIT TRANSACTION;".to_string());
+        let query = statements.join(";\n");
+        trace!("Query: {}", query);
+
+        let mut request = self.client.query(&query);
+        for (n, entry) in tasks.iter().enumerate() {
+            request = bind_text_fields(request, &entry.task, n);
+        }
+        request.await?.check()?;
+        Ok(())
+    }
+
     /// Get a task by ID.
     ///
     /// # Arguments
@@ -274,6 +531,64 @@ impl<'a> TaskRepository<'a> {
         Ok(task)
     }
 
+    /// Get a task by ID, for callers that need it to exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The task ID to fetch
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError::NotFound` if there is no task with that ID.
+    pub async fn get_or_err(&self, id: &str) -> DbResult<Task> {
+        self.get(id).await?.ok_or_else(|| DbError::NotFound {
+            task_id: id.to_string(),
+        })
+    }
+
+    /// Resolve a task ID that may be shortened to a unique prefix.
+    ///
+    /// An exact ID wins even when it also starts longer IDs.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The full task ID or its first few characters
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError::NotFound` if no task ID starts with `prefix`, or
+    /// `DbError::AmbiguousId` listing the matches if several do.
+    pub async fn resolve_id(&self, prefix: &str) -> DbResult<String> {
+        if prefix.is_empty() {
+            return Err(DbError::NotFound {
+                task_id: prefix.to_string(),
+            });
+        }
+        if self.exists(prefix).await? {
+            return Ok(prefix.to_string());
+        }
+
+        let mut result = self.client.query("SELECT id FROM task").await?;
+        let rows: Vec<IdOnly> = result.take(0)?;
+        let mut candidates: Vec<String> = rows
+            .into_iter()
+            .map(|row| row.id.id.to_string())
+            .filter(|id| id.starts_with(prefix))
+            .collect();
+        candidates.sort();
+
+        match candidates.len() {
+            0 => Err(DbError::NotFound {
+                task_id: prefix.to_string(),
+            }),
+            1 => Ok(candidates.remove(0)),
+            _ => Err(DbError::AmbiguousId {
+                prefix: prefix.to_string(),
+                candidates,
+            }),
+        }
+    }
+
     /// Update the status of a task with workflow validation.
     ///
     /// Validates the transition before updating. Use `update_status_unchecked` for
@@ -344,6 +659,93 @@ impl<'a> TaskRepository<'a> {
             })
     }
 
+    /// Move a task to `target`, enforcing the workflow rules.
+    ///
+    /// Shared by `vtb transition-to` and the TUI so both apply the same rules:
+    /// - the workflow must allow the transition
+    /// - a task entering todo must pass triage validation with no errors, and
+    ///   with no warnings unless `force` is set (`skip_validation` skips it)
+    /// - a task entering in_progress keeps an existing `started_at`
+    /// - a task can only be done once all its descendants are
+    ///
+    /// Starting a task with open dependencies is allowed; they are returned
+    /// in the outcome as a warning.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError::NotFound` if the task doesn't exist,
+    /// `DbError::InvalidStatusTransition`, `DbError::TriageValidationFailed`,
+    /// `DbError::ValidationError` or `DbError::IncompleteChildren` when the
+    /// move is refused, and `DbError::Query` if the database operation fails.
+    pub async fn transition(
+        &self,
+        id: &str,
+        target: &Status,
+        options: &TransitionOptions,
+    ) -> DbResult<TransitionOutcome> {
+        let task = self.get_or_err(id).await?;
+        self.validate_status_transition(id, &task.status, target)?;
+
+        let graph = GraphQueries::new(self.client);
+        let mut outcome = TransitionOutcome::default();
+        match target {
+            Status::Todo => {
+                if !options.skip_validation {
+                    let validation = TriageValidator::new().validate(&task);
+                    if validation.has_errors() {
+                        return Err(DbError::TriageValidationFailed {
+                            task_id: id.to_string(),
+                            error_count: validation.error_count(),
+                            warning_count: validation.warning_count(),
+                            note_count: validation.note_count(),
+                            details: format!("{}", validation),
+                        });
+                    }
+                    if validation.has_warnings() && !options.force {
+                        let message = format!(
+                            "Task '{}' has validation warnings. Use --force to override:\n\n{}\n\
+                             Run with --force to proceed anyway, or add the missing sections.",
+                            id, validation
+                        );
+                        return Err(DbError::ValidationError { message });
+                    }
+                    outcome.validation = Some(validation);
+                }
+                self.update(id, &TaskUpdate::new().with_status(Status::Todo))
+                    .await?;
+            }
+            Status::InProgress => {
+                outcome.incomplete_deps = graph.get_incomplete_dependencies_info(id).await?;
+                let updates = TaskUpdate::new().with_status(Status::InProgress);
+                let updates = match options.started_at {
+                    Some(started_at) => updates.with_started_at(started_at),
+                    None => updates.set_started_at_if_null(),
+                };
+                self.update(id, &updates).await?;
+            }
+            Status::Done => {
+                let children = graph.get_incomplete_descendants(id).await?;
+                if !children.is_empty() {
+                    return Err(DbError::IncompleteChildren {
+                        task_id: id.to_string(),
+                        children,
+                    });
+                }
+                outcome.unblocked = graph.get_unblocked_tasks(id).await?;
+                if let Some(started_at) = options.started_at {
+                    self.update(id, &TaskUpdate::new().with_started_at(started_at))
+                        .await?;
+                }
+                match options.completed_at {
+                    Some(completed_at) => self.mark_done_at(id, completed_at).await?,
+                    None => self.mark_done(id).await?,
+                }
+            }
+            _ => self.update_status(id, target.clone()).await?,
+        }
+        Ok(outcome)
+    }
+
     /// Mark a task as done with completed_at timestamp and workflow validation.
     ///
     /// Updates the status to 'done' and sets both updated_at and completed_at timestamps.
@@ -368,6 +770,30 @@ impl<'a> TaskRepository<'a> {
         self.mark_done_unchecked(id).await
     }
 
+    /// Mark a task as done, recording `completed_at` as the given time.
+    ///
+    /// Like [`TaskRepository::mark_done`], but for backfilling work that was
+    /// finished earlier.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError::InvalidStatusTransition` if the transition is not allowed.
+    /// Returns `DbError::Query` if the database operation fails.
+    pub async fn mark_done_at(&self, id: &str, completed_at: DateTime<Utc>) -> DbResult<()> {
+        let task = self.get(id).await?;
+        if let Some(task) = &task {
+            self.validate_status_transition(id, &task.status, &Status::Done)?;
+        }
+
+        let query = format!(
+            "UPDATE task:{} SET status = 'done', updated_at = time::now(), completed_at = d'{}'",
+            id,
+            completed_at.to_rfc3339()
+        );
+        self.client.query(&query).await?;
+        Ok(())
+    }
+
     /// Mark a task as done without workflow validation.
     ///
     /// This should only be used for internal operations where validation
@@ -493,12 +919,16 @@ impl<'a> TaskRepository<'a> {
             field_updates.push(format!("needs_human_review = {}", needs_review));
         }
 
-        if updates.set_started_at {
+        if let Some(at) = updates.started_at {
+            field_updates.push(format!("started_at = d'{}'", at.to_rfc3339()));
+        } else if updates.set_started_at {
             field_updates.push("started_at = time::now()".to_string());
+        } else if updates.set_started_at_if_null {
+            field_updates.push("started_at = started_at ?? time::now()".to_string());
         }
 
-        if updates.set_started_at_if_null {
-            field_updates.push("started_at = started_at ?? time::now()".to_string());
+        if let Some(at) = updates.completed_at {
+            field_updates.push(format!("completed_at = d'{}'", at.to_rfc3339()));
         }
 
         if let Some(status) = &updates.status {
@@ -650,6 +1080,136 @@ impl<'a> TaskRepository<'a> {
             .map(|t| (t.id.id.to_raw(), t.task))
             .collect())
     }
+
+    /// Find groups of tasks that share a title, ignoring case and whitespace.
+    ///
+    /// Titles are compared after trimming, collapsing runs of whitespace and
+    /// lowercasing, so "Fix  login" and "fix login " fall in one group.
+    ///
+    /// # Returns
+    ///
+    /// Every group with more than one task, ordered by normalized title.
+    pub async fn find_duplicate_titles(&self) -> DbResult<Vec<DuplicateTitle>> {
+        #[derive(Debug, Deserialize)]
+        struct TitleRow {
+            id: surrealdb::sql::Thing,
+            title: String,
+        }
+
+        let mut result = self
+            .client
+            .query("SELECT id, title FROM task ORDER BY id")
+            .await?;
+        let rows: Vec<TitleRow> = result.take(0)?;
+
+        let mut groups: std::collections::BTreeMap<String, DuplicateTitle> =
+            std::collections::BTreeMap::new();
+        for row in rows {
+            groups
+                .entry(normalize_title(&row.title))
+                .or_insert_with(|| DuplicateTitle {
+                    title: row.title,
+                    task_ids: Vec::new(),
+                })
+                .task_ids
+                .push(row.id.id.to_raw());
+        }
+
+        Ok(groups
+            .into_values()
+            .filter(|group| group.task_ids.len() > 1)
+            .collect())
+    }
+
+    /// Export one page of tasks, ordered by ID.
+    ///
+    /// Lets exports of large projects hold a single page in memory; keep
+    /// increasing `start` by the page length until a short page comes back.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - Number of tasks to skip
+    /// * `limit` - Most tasks to return
+    ///
+    /// # Returns
+    ///
+    /// A vector of (task_id, Task) tuples.
+    pub async fn export_page(&self, start: usize, limit: usize) -> DbResult<Vec<(String, Task)>> {
+        #[derive(Debug, Deserialize)]
+        struct TaskWithId {
+            id: surrealdb::sql::Thing,
+            #[serde(flatten)]
+            task: Task,
+        }
+
+        let query = format!(
+            "SELECT * FROM task ORDER BY id LIMIT {} START {}",
+            limit, start
+        );
+        let mut result = self.client.query(&query).await?;
+        let tasks: Vec<TaskWithId> = result.take(0)?;
+
+        Ok(tasks
+            .into_iter()
+            .map(|t| (t.id.id.to_raw(), t.task))
+            .collect())
+    }
+}
+
+/// SET clause writing every stored field of `task`.
+///
+/// The title, description and tags come from the `$title{n}`,
+/// `$description{n}` and `$tags{n}` parameters, see [`bind_text_fields`].
+fn set_clause(id: &str, task: &Task, n: usize) -> DbResult<String> {
+    let to_json = |what: &str, value: serde_json::Result<String>| {
+        value.map_err(|e| DbError::InvalidPath {
+            path: std::path::PathBuf::from(id),
+            reason: format!("Failed to serialize {}: {}", what, e),
+        })
+    };
+    let datetime = |value: Option<DateTime<Utc>>| match value {
+        Some(dt) => format!("d'{}'", dt.to_rfc3339()),
+        None => "NONE".to_string(),
+    };
+    let or_none = |value: Option<String>| value.unwrap_or_else(|| "NONE".to_string());
+
+    Ok(format!(
+        "title = $title{n}, description = $description{n}, level = \"{}\", \
+         status = \"{}\", priority = {}, tags = $tags{n}, sections = {}, refs = {}, \
+         needs_human_review = {}, started_at = {}, completed_at = {}, due_at = {}, \
+         estimate_minutes = {}",
+        task.level.as_str(),
+        task.status.as_str(),
+        or_none(
+            task.priority
+                .as_ref()
+                .map(|p| format!("\"{}\"", p.as_str()))
+        ),
+        to_json("sections", serde_json::to_string(&task.sections))?,
+        to_json("refs", serde_json::to_string(&task.code_refs))?,
+        or_none(task.needs_human_review.map(|b| b.to_string())),
+        datetime(task.started_at),
+        datetime(task.completed_at),
+        datetime(task.due_at),
+        or_none(task.estimate_minutes.map(|m| m.to_string())),
+    ))
+}
+
+/// Bind the parameters a [`set_clause`] for the same `n` reads.
+fn bind_text_fields<'r>(request: RetryQuery<'r>, task: &Task, n: usize) -> RetryQuery<'r> {
+    request
+        .bind((format!("title{}", n), task.title.clone()))
+        .bind((format!("description{}", n), task.description.clone()))
+        .bind((format!("tags{}", n), task.tags.clone()))
+}
+
+/// Key for comparing titles: trimmed, single-spaced and lowercase.
+fn normalize_title(title: &str) -> String {
+    title
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
 }
 
 #[cfg(test)]
@@ -682,6 +1242,255 @@ mod tests {
         let _ = std::fs::remove_dir_all(path);
     }
 
+    #[tokio::test]
+    async fn test_clone_task_copies_content_but_not_edges() {
+        let (db, temp_dir) = setup_test_db().await;
+        let repo = TaskRepository::new(db.client());
+        db.client()
+            .query(
+                r#"CREATE task:orig SET title = "Original", description = "Details",
+                       level = "ticket", status = "in_progress", priority = "high",
+                       tags = ["backend", "api"], estimate_minutes = 90,
+                       sections = [
+                           { type: "goal", content: "Ship it" },
+                           { type: "step", content: "Write code", done: true, done_at: time::now() }
+                       ],
+                       refs = [{ path: "src/lib.rs", line_start: 3 }];
+                   CREATE task:dep SET title = "Dep", level = "task", status = "todo";
+                   CREATE task:epic SET title = "Epic", level = "epic", status = "todo";
+                   CREATE task:kid SET title = "Kid", level = "task", status = "todo";
+                   RELATE task:orig -> depends_on -> task:dep;
+                   RELATE task:orig -> child_of -> task:epic;
+                   RELATE task:kid -> child_of -> task:orig"#,
+            )
+            .await
+            .unwrap();
+        let original = repo.get("orig").await.unwrap().unwrap();
+
+        let copies = repo
+            .clone_task("orig", false, |_| "copy01".to_string())
+            .await
+            .unwrap();
+        assert_eq!(copies, vec![("orig".to_string(), "copy01".to_string())]);
+
+        let copy = repo.get("copy01").await.unwrap().unwrap();
+        assert_eq!(copy.title, "Original (copy)");
+        assert_eq!(copy.description.as_deref(), Some("Details"));
+        assert_eq!(copy.level, Level::Ticket);
+        assert_eq!(copy.status, Status::Backlog);
+        assert_eq!(copy.priority, Some(Priority::High));
+        assert_eq!(copy.tags, vec!["backend", "api"]);
+        assert_eq!(copy.code_refs, original.code_refs);
+        assert_eq!(copy.sections.len(), 2);
+        assert_eq!(copy.sections[1].content, "Write code");
+        assert_eq!(copy.sections[1].done, None);
+        assert_eq!(copy.sections[1].done_at, None);
+
+        let relationships = RelationshipRepository::new(db.client());
+        assert!(
+            relationships
+                .get_dependencies("copy01")
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        assert!(relationships.get_parent("copy01").await.unwrap().is_none());
+        assert!(
+            relationships
+                .get_children("copy01")
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        // The original is untouched
+        assert_eq!(repo.get("orig").await.unwrap().unwrap(), original);
+        assert_eq!(
+            relationships.get_children("orig").await.unwrap(),
+            vec!["kid"]
+        );
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_clone_task_with_children_and_taken_ids() {
+        let (db, temp_dir) = setup_test_db().await;
+        let repo = TaskRepository::new(db.client());
+        db.client()
+            .query(
+                r#"CREATE task:root SET title = "Root", level = "epic", status = "todo";
+                   CREATE task:kid1 SET title = "Kid 1", level = "ticket", status = "done";
+                   CREATE task:kid2 SET title = "Kid 2", level = "ticket", status = "todo";
+                   CREATE task:leaf SET title = "Leaf", level = "task", status = "todo";
+                   RELATE task:kid1 -> child_of -> task:root;
+                   RELATE task:kid2 -> child_of -> task:root;
+                   RELATE task:leaf -> child_of -> task:kid1"#,
+            )
+            .await
+            .unwrap();
+
+        // "kid2" is taken, so the generator is asked again
+        let mut candidates = ["new0", "kid2", "new1", "new2", "new3"].into_iter();
+        let copies = repo
+            .clone_task("root", true, |_| candidates.next().unwrap().to_string())
+            .await
+            .unwrap();
+        let pairs: Vec<(&str, &str)> = copies
+            .iter()
+            .map(|(from, to)| (from.as_str(), to.as_str()))
+            .collect();
+        assert_eq!(
+            pairs,
+            vec![
+                ("root", "new0"),
+                ("kid1", "new1"),
+                ("leaf", "new2"),
+                ("kid2", "new3")
+            ]
+        );
+
+        let relationships = RelationshipRepository::new(db.client());
+        assert_eq!(
+            relationships.get_parent("new2").await.unwrap(),
+            Some("new1".to_string())
+        );
+        assert_eq!(
+            relationships.get_parent("new3").await.unwrap(),
+            Some("new0".to_string())
+        );
+        assert_eq!(repo.get("new1").await.unwrap().unwrap().title, "Kid 1");
+        assert_eq!(relationships.get_children("root").await.unwrap().len(), 2);
+
+        let err = repo
+            .clone_task("root", false, |_| "root".to_string())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, DbError::ValidationError { .. }));
+        let err = repo
+            .clone_task("missing", false, |_| "x".to_string())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, DbError::NotFound { .. }));
+
+        cleanup(&temp_dir);
+    }
+
+    fn bulk(id: &str, parent: Option<&str>, depends_on: &[&str]) -> BulkTask {
+        BulkTask {
+            id: id.to_string(),
+            task: Task::new(format!("Task {}", id), Level::Task).with_tag("imported"),
+            parent: parent.map(str::to_string),
+            depends_on: depends_on.iter().map(|d| d.to_string()).collect(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_bulk_create_resolves_forward_references() {
+        let (db, temp_dir) = setup_test_db().await;
+        let repo = TaskRepository::new(db.client());
+        db.client()
+            .query(r#"CREATE task:old SET title = "Old", level = "epic", status = "todo""#)
+            .await
+            .unwrap();
+
+        let mut first = bulk("first", Some("later"), &["old", "later"]);
+        first.task.description = Some("Has \"quotes\"".to_string());
+        first.task.estimate_minutes = Some(30);
+        repo.bulk_create(&[first, bulk("later", Some("old"), &[])])
+            .await
+            .unwrap();
+
+        let task = repo.get("first").await.unwrap().unwrap();
+        assert_eq!(task.title, "Task first");
+        assert_eq!(task.description.as_deref(), Some("Has \"quotes\""));
+        assert_eq!(task.tags, vec!["imported"]);
+        assert_eq!(task.estimate_minutes, Some(30));
+
+        let relationships = RelationshipRepository::new(db.client());
+        assert_eq!(
+            relationships.get_parent("first").await.unwrap(),
+            Some("later".to_string())
+        );
+        assert_eq!(
+            relationships.get_parent("later").await.unwrap(),
+            Some("old".to_string())
+        );
+        let mut deps = relationships.get_dependencies("first").await.unwrap();
+        deps.sort();
+        assert_eq!(deps, vec!["later", "old"]);
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_bulk_create_failure_creates_nothing() {
+        let (db, temp_dir) = setup_test_db().await;
+        let repo = TaskRepository::new(db.client());
+        db.client()
+            .query(r#"CREATE task:taken SET title = "Taken", level = "task", status = "todo""#)
+            .await
+            .unwrap();
+
+        // The second task's ID is taken, so the first must not survive
+        let err = repo
+            .bulk_create(&[bulk("fresh", None, &[]), bulk("taken", Some("fresh"), &[])])
+            .await;
+        assert!(err.is_err());
+        assert!(!repo.exists("fresh").await.unwrap());
+        assert_eq!(repo.get("taken").await.unwrap().unwrap().title, "Taken");
+
+        for tasks in [
+            vec![bulk("dup", None, &[]), bulk("dup", None, &[])],
+            vec![bulk("me", None, &["me"])],
+        ] {
+            let err = repo.bulk_create(&tasks).await.unwrap_err();
+            assert!(matches!(err, DbError::ValidationError { .. }));
+        }
+        assert!(!repo.exists("dup").await.unwrap());
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_explicit_timestamps_are_stored() {
+        let (db, temp_dir) = setup_test_db().await;
+        let repo = TaskRepository::new(db.client());
+        db.client()
+            .query(r#"CREATE task:bf SET title = "Backfill", level = "task", status = "pending_review", started_at = time::now()"#)
+            .await
+            .unwrap();
+
+        let started = "2024-03-01T09:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let completed = "2024-03-04T17:30:00Z".parse::<DateTime<Utc>>().unwrap();
+
+        // An explicit time wins over the "only if unset" flag
+        let updates = TaskUpdate::new()
+            .set_started_at_if_null()
+            .with_started_at(started);
+        assert!(updates.has_updates());
+        repo.update("bf", &updates).await.unwrap();
+
+        repo.mark_done_at("bf", completed).await.unwrap();
+
+        let task = repo.get("bf").await.unwrap().unwrap();
+        assert_eq!(task.status, Status::Done);
+        assert_eq!(task.started_at, Some(started));
+        assert_eq!(task.completed_at, Some(completed));
+
+        // The workflow is still enforced
+        db.client()
+            .query(r#"CREATE task:td SET title = "Todo", level = "task", status = "todo""#)
+            .await
+            .unwrap();
+        assert!(matches!(
+            repo.mark_done_at("td", completed).await,
+            Err(DbError::InvalidStatusTransition { .. })
+        ));
+
+        cleanup(&temp_dir);
+    }
+
     #[tokio::test]
     async fn test_exists_returns_false_for_nonexistent() {
         let (db, temp_dir) = setup_test_db().await;
@@ -779,6 +1588,58 @@ mod tests {
         cleanup(&temp_dir);
     }
 
+    #[tokio::test]
+    async fn test_resolve_id() {
+        let (db, temp_dir) = setup_test_db().await;
+        let repo = TaskRepository::new(db.client());
+
+        for id in ["abc123", "abd456", "ab"] {
+            repo.create(id, &Task::new(id, Level::Task)).await.unwrap();
+        }
+
+        assert_eq!(repo.resolve_id("abc123").await.unwrap(), "abc123");
+        assert_eq!(repo.resolve_id("abc").await.unwrap(), "abc123");
+        // An exact ID beats the longer IDs it is a prefix of
+        assert_eq!(repo.resolve_id("ab").await.unwrap(), "ab");
+
+        let err = repo.resolve_id("a").await.unwrap_err();
+        match err {
+            DbError::AmbiguousId { prefix, candidates } => {
+                assert_eq!(prefix, "a");
+                assert_eq!(candidates, vec!["ab", "abc123", "abd456"]);
+            }
+            other => panic!("Expected AmbiguousId, got {:?}", other),
+        }
+
+        assert!(matches!(
+            repo.resolve_id("zz").await,
+            Err(DbError::NotFound { task_id }) if task_id == "zz"
+        ));
+        assert!(matches!(
+            repo.resolve_id("").await,
+            Err(DbError::NotFound { .. })
+        ));
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_get_or_err() {
+        let (db, temp_dir) = setup_test_db().await;
+        let repo = TaskRepository::new(db.client());
+
+        repo.create("get2", &Task::new("Present", Level::Task))
+            .await
+            .unwrap();
+        assert_eq!(repo.get_or_err("get2").await.unwrap().title, "Present");
+
+        let err = repo.get_or_err("typo").await.unwrap_err();
+        assert!(matches!(err, DbError::NotFound { ref task_id } if task_id == "typo"));
+        assert!(err.to_string().contains("vtb list"), "got: {}", err);
+
+        cleanup(&temp_dir);
+    }
+
     #[tokio::test]
     async fn test_update_status() {
         let (db, temp_dir) = setup_test_db().await;
@@ -799,6 +1660,97 @@ mod tests {
         cleanup(&temp_dir);
     }
 
+    #[tokio::test]
+    async fn test_transition_enforces_workflow_rules() {
+        let (db, temp_dir) = setup_test_db().await;
+        let repo = TaskRepository::new(db.client());
+        db.client()
+            .query(
+                r#"CREATE task:parent SET title = "Parent", level = "ticket", status = "backlog";
+                   CREATE task:kid SET title = "Kid", level = "task", status = "todo";
+                   CREATE task:dep SET title = "Dep", level = "task", status = "todo";
+                   CREATE task:waiter SET title = "Waiter", level = "task", status = "todo";
+                   RELATE task:kid -> child_of -> task:parent;
+                   RELATE task:parent -> depends_on -> task:dep;
+                   RELATE task:waiter -> depends_on -> task:parent"#,
+            )
+            .await
+            .unwrap();
+        let options = TransitionOptions::default();
+
+        // Triage errors block the move to todo unless validation is skipped
+        let err = repo
+            .transition("parent", &Status::Todo, &options)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, DbError::TriageValidationFailed { .. }));
+        let skip = TransitionOptions {
+            skip_validation: true,
+            ..Default::default()
+        };
+        let outcome = repo
+            .transition("parent", &Status::Todo, &skip)
+            .await
+            .unwrap();
+        assert!(outcome.validation.is_none());
+
+        // Skipping a step of the workflow is refused
+        let err = repo
+            .transition("parent", &Status::Done, &options)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, DbError::InvalidStatusTransition { .. }));
+
+        // Open dependencies only warn when starting
+        let outcome = repo
+            .transition("parent", &Status::InProgress, &options)
+            .await
+            .unwrap();
+        assert_eq!(outcome.incomplete_deps.len(), 1);
+        assert_eq!(outcome.incomplete_deps[0].0, "dep");
+        let started_at = repo.get("parent").await.unwrap().unwrap().started_at;
+        assert!(started_at.is_some());
+
+        repo.transition("parent", &Status::PendingReview, &options)
+            .await
+            .unwrap();
+        repo.transition("parent", &Status::InProgress, &options)
+            .await
+            .unwrap();
+        // Going back to in_progress keeps the original start
+        assert_eq!(
+            repo.get("parent").await.unwrap().unwrap().started_at,
+            started_at
+        );
+        repo.transition("parent", &Status::PendingReview, &options)
+            .await
+            .unwrap();
+
+        // Done waits for every descendant
+        let err = repo
+            .transition("parent", &Status::Done, &options)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, DbError::IncompleteChildren { .. }));
+
+        repo.update_status_unchecked("kid", Status::Done)
+            .await
+            .unwrap();
+        let outcome = repo
+            .transition("parent", &Status::Done, &options)
+            .await
+            .unwrap();
+        assert_eq!(
+            outcome.unblocked,
+            vec![("waiter".to_string(), "Waiter".to_string())]
+        );
+        let parent = repo.get("parent").await.unwrap().unwrap();
+        assert_eq!(parent.status, Status::Done);
+        assert!(parent.completed_at.is_some());
+
+        cleanup(&temp_dir);
+    }
+
     #[tokio::test]
     async fn test_update_timestamp() {
         let (db, temp_dir) = setup_test_db().await;
@@ -1061,4 +2013,56 @@ mod tests {
 
         cleanup(&temp_dir);
     }
+
+    #[tokio::test]
+    async fn test_export_page_walks_tasks_in_id_order() {
+        let (db, temp_dir) = setup_test_db().await;
+        let repo = TaskRepository::new(db.client());
+        for id in ["ccc", "aaa", "bbb"] {
+            repo.create(id, &Task::new(id, Level::Task)).await.unwrap();
+        }
+
+        let ids =
+            |page: Vec<(String, Task)>| page.into_iter().map(|(id, _)| id).collect::<Vec<_>>();
+        assert_eq!(
+            ids(repo.export_page(0, 2).await.unwrap()),
+            vec!["aaa", "bbb"]
+        );
+        assert_eq!(ids(repo.export_page(2, 2).await.unwrap()), vec!["ccc"]);
+        assert!(repo.export_page(3, 2).await.unwrap().is_empty());
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_find_duplicate_titles_ignores_case_and_whitespace() {
+        let (db, temp_dir) = setup_test_db().await;
+        let repo = TaskRepository::new(db.client());
+        for (id, title) in [
+            ("dup2", "fix  Login bug "),
+            ("dup1", "Fix login bug"),
+            ("uniq", "Fix logout bug"),
+        ] {
+            repo.create(id, &Task::new(title, Level::Task))
+                .await
+                .unwrap();
+        }
+
+        let groups = repo.find_duplicate_titles().await.unwrap();
+        assert_eq!(
+            groups,
+            vec![DuplicateTitle {
+                title: "Fix login bug".to_string(),
+                task_ids: vec!["dup1".to_string(), "dup2".to_string()],
+            }]
+        );
+
+        cleanup(&temp_dir);
+    }
+
+    #[test]
+    fn test_normalize_title() {
+        assert_eq!(normalize_title("  Fix\tthe   BUG "), "fix the bug");
+        assert_eq!(normalize_title(""), "");
+    }
 }
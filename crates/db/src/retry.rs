@@ -0,0 +1,405 @@
+//! Retrying transient database failures
+//!
+//! Opening the datastore can fail while another `vtb` process is still
+//! releasing it, and SurrealKV aborts transactions that hit a read/write
+//! conflict with an error that explicitly says it can be retried. Those
+//! failures are retried a few times with exponential backoff. Everything
+//! else (missing tasks, validation errors, bad queries) fails immediately.
+//!
+//! Repositories send their queries through [`RetryClient`], so a conflict
+//! reported by any statement of a query re-runs the whole query.
+
+use std::future::{Future, IntoFuture};
+use std::pin::Pin;
+use std::time::Duration;
+
+use serde::Serialize;
+use surrealdb::engine::local::Db;
+use surrealdb::method::Query;
+use surrealdb::{Response, Surreal};
+
+use crate::error::{DbError, DbResult};
+
+/// Environment variable overriding the number of retries
+pub const DB_RETRIES_ENV: &str = "VTB_DB_RETRIES";
+
+/// Retries made when `VTB_DB_RETRIES` is not set
+pub const DEFAULT_DB_RETRIES: u32 = 2;
+
+/// Delay before the first retry; each later retry waits twice as long
+const BASE_DELAY: Duration = Duration::from_millis(50);
+
+/// Errors that may succeed if the operation is simply tried again
+pub trait Transient {
+    /// Whether retrying the failed operation could succeed.
+    fn is_transient(&self) -> bool;
+}
+
+impl Transient for surrealdb::Error {
+    fn is_transient(&self) -> bool {
+        matches!(
+            self,
+            surrealdb::Error::Db(surrealdb::error::Db::TxRetryable)
+        )
+    }
+}
+
+impl Transient for DbError {
+    fn is_transient(&self) -> bool {
+        match self {
+            DbError::Connection { .. } => true,
+            DbError::Query(err) | DbError::Schema(err) => err.is_transient(),
+            _ => false,
+        }
+    }
+}
+
+/// How many times to retry a transient failure, and how long to wait
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    retries: u32,
+    base_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new(DEFAULT_DB_RETRIES, BASE_DELAY)
+    }
+}
+
+impl RetryPolicy {
+    /// Create a policy making up to `retries` extra attempts, waiting
+    /// `base_delay` before the first and doubling the wait each time.
+    pub fn new(retries: u32, base_delay: Duration) -> Self {
+        Self {
+            retries,
+            base_delay,
+        }
+    }
+
+    /// A policy that never retries.
+    pub fn none() -> Self {
+        Self::new(0, Duration::ZERO)
+    }
+
+    /// Read the retry count from `VTB_DB_RETRIES`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError::ValidationError` if the variable is set to something
+    /// other than a non-negative integer.
+    pub fn from_env() -> DbResult<Self> {
+        Self::from_env_value(std::env::var(DB_RETRIES_ENV).ok().as_deref())
+    }
+
+    /// Build a policy from the value of `VTB_DB_RETRIES`, if set.
+    ///
+    /// Unset or empty values use [`DEFAULT_DB_RETRIES`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError::ValidationError` for values that are not a
+    /// non-negative integer.
+    pub fn from_env_value(value: Option<&str>) -> DbResult<Self> {
+        let retries = match value.map(str::trim) {
+            None | Some("") => DEFAULT_DB_RETRIES,
+            Some(value) => value.parse().map_err(|_| DbError::ValidationError {
+                message: format!(
+                    "{} must be a non-negative integer, got '{}'",
+                    DB_RETRIES_ENV, value
+                ),
+            })?,
+        };
+        Ok(Self::new(retries, BASE_DELAY))
+    }
+
+    /// Number of retries after the first attempt.
+    pub fn retries(&self) -> u32 {
+        self.retries
+    }
+
+    /// Run `op`, retrying it while it fails with a transient error.
+    ///
+    /// Returns the first success, the first permanent error, or the last
+    /// transient error once the retries are used up.
+    pub async fn run<T, E, F, Fut>(&self, mut op: F) -> Result<T, E>
+    where
+        E: Transient + std::fmt::Display,
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        let mut delay = self.base_delay;
+        let mut attempt = 0;
+        loop {
+            match op().await {
+                Err(err) if err.is_transient() && attempt < self.retries => {
+                    attempt += 1;
+                    tracing::debug!(
+                        "transient database error, retry {}/{} in {:?}: {}",
+                        attempt,
+                        self.retries,
+                        delay,
+                        err
+                    );
+                    tokio::time::sleep(delay).await;
+                    delay = delay.saturating_mul(2);
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+/// A database client whose queries retry transient failures
+#[derive(Debug, Clone, Copy)]
+pub struct RetryClient<'a> {
+    client: &'a Surreal<Db>,
+    policy: RetryPolicy,
+}
+
+impl<'a> RetryClient<'a> {
+    /// Wrap `client`, retrying its queries according to `policy`.
+    pub fn new(client: &'a Surreal<Db>, policy: RetryPolicy) -> Self {
+        Self { client, policy }
+    }
+
+    /// Start a query; await it to run it.
+    pub fn query(&self, query: impl Into<String>) -> RetryQuery<'a> {
+        RetryQuery {
+            client: self.client,
+            policy: self.policy,
+            query: query.into(),
+            bindings: Vec::new(),
+        }
+    }
+}
+
+/// Wraps a plain client with the default policy
+impl<'a> From<&'a Surreal<Db>> for RetryClient<'a> {
+    fn from(client: &'a Surreal<Db>) -> Self {
+        Self::new(client, RetryPolicy::default())
+    }
+}
+
+/// Re-applies one binding to each attempt of a query
+type Binding<'a> = Box<dyn Fn(Query<'a, Db>) -> Query<'a, Db> + Send + Sync + 'a>;
+
+/// A query built by [`RetryClient::query`].
+///
+/// Awaiting it runs the query and fails with the first statement error, so
+/// callers see a statement's error on `.await` rather than on `take`. The
+/// query is run again while that error is transient.
+pub struct RetryQuery<'a> {
+    client: &'a Surreal<Db>,
+    policy: RetryPolicy,
+    query: String,
+    bindings: Vec<Binding<'a>>,
+}
+
+impl<'a> RetryQuery<'a> {
+    /// Bind a parameter, as `surrealdb`'s `Query::bind` does.
+    pub fn bind(mut self, binding: impl Serialize + Clone + Send + Sync + 'static) -> Self {
+        self.bindings
+            .push(Box::new(move |query| query.bind(binding.clone())));
+        self
+    }
+
+    /// Run the query once.
+    async fn attempt(&self) -> surrealdb::Result<Response> {
+        let mut query = self.client.query(self.query.as_str());
+        for binding in &self.bindings {
+            query = binding(query);
+        }
+        let mut response = query.await?;
+        let errors = response.take_errors();
+        match errors.into_iter().min_by_key(|(index, _)| *index) {
+            Some((_, error)) => Err(error),
+            None => Ok(response),
+        }
+    }
+}
+
+impl<'a> IntoFuture for RetryQuery<'a> {
+    type Output = surrealdb::Result<Response>;
+    type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(async move { self.policy.run(|| self.attempt()).await })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::{Cell, RefCell};
+    use std::path::PathBuf;
+
+    fn retryable() -> DbError {
+        DbError::Query(Box::new(surrealdb::Error::Db(
+            surrealdb::error::Db::TxRetryable,
+        )))
+    }
+
+    fn not_found() -> DbError {
+        DbError::NotFound {
+            task_id: "abc".to_string(),
+        }
+    }
+
+    /// A client seam that fails with each queued error in turn, then succeeds
+    struct Flaky {
+        failures: RefCell<Vec<DbError>>,
+        calls: Cell<usize>,
+    }
+
+    impl Flaky {
+        fn new(mut failures: Vec<DbError>) -> Self {
+            failures.reverse();
+            Self {
+                failures: RefCell::new(failures),
+                calls: Cell::new(0),
+            }
+        }
+
+        async fn call(&self) -> DbResult<&'static str> {
+            self.calls.set(self.calls.get() + 1);
+            match self.failures.borrow_mut().pop() {
+                Some(err) => Err(err),
+                None => Ok("done"),
+            }
+        }
+    }
+
+    fn quick(retries: u32) -> RetryPolicy {
+        RetryPolicy::new(retries, Duration::from_millis(1))
+    }
+
+    #[test]
+    fn test_transient_classification() {
+        assert!(retryable().is_transient());
+        assert!(
+            DbError::Connection {
+                path: PathBuf::from("/db"),
+                source: Box::new(surrealdb::Error::Db(surrealdb::error::Db::Ds(
+                    "locked".to_string()
+                ))),
+            }
+            .is_transient()
+        );
+
+        assert!(!not_found().is_transient());
+        assert!(
+            !DbError::ValidationError {
+                message: "bad".to_string()
+            }
+            .is_transient()
+        );
+        assert!(
+            !DbError::Query(Box::new(surrealdb::Error::Db(
+                surrealdb::error::Db::QueryEmpty
+            )))
+            .is_transient()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_transient_failure_is_retried_until_success() {
+        let flaky = Flaky::new(vec![retryable(), retryable()]);
+        let result = quick(2).run(|| flaky.call()).await;
+
+        assert_eq!(result.unwrap(), "done");
+        assert_eq!(flaky.calls.get(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_gives_up_after_retries() {
+        let flaky = Flaky::new(vec![retryable(), retryable(), retryable()]);
+        let result = quick(2).run(|| flaky.call()).await;
+
+        assert!(result.unwrap_err().is_transient());
+        assert_eq!(flaky.calls.get(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_permanent_errors_are_not_retried() {
+        let flaky = Flaky::new(vec![not_found()]);
+        let result = quick(5).run(|| flaky.call()).await;
+
+        assert!(matches!(result, Err(DbError::NotFound { .. })));
+        assert_eq!(flaky.calls.get(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_no_retry_policy() {
+        let flaky = Flaky::new(vec![retryable()]);
+        let result = RetryPolicy::none().run(|| flaky.call()).await;
+
+        assert!(result.is_err());
+        assert_eq!(flaky.calls.get(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_retry_query_fails_with_first_statement_error() {
+        let path = std::env::temp_dir().join(format!(
+            "vtb-retry-test-{}-{:?}",
+            std::process::id(),
+            std::thread::current().id()
+        ));
+        let db = crate::Database::connect(&path).await.unwrap();
+        db.init().await.unwrap();
+        let client = RetryClient::new(db.client(), quick(3));
+
+        let mut response = client
+            .query("RETURN $a; RETURN $b")
+            .bind(("a", 1))
+            .bind(("b", "two".to_string()))
+            .await
+            .unwrap();
+        let a: Option<i64> = response.take(0).unwrap();
+        let b: Option<String> = response.take(1).unwrap();
+        assert_eq!((a, b), (Some(1), Some("two".to_string())));
+
+        // A permanent statement error surfaces on await and isn't retried
+        let err = client
+            .query(
+                r#"CREATE task:once SET title = "Once", level = "task", status = "todo";
+                   THROW "boom""#,
+            )
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("boom"), "got: {}", err);
+        assert!(!err.is_transient());
+
+        let err = client
+            .query(r#"CREATE task:once SET title = "Again", level = "task", status = "todo""#)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("already exists"), "got: {}", err);
+
+        let _ = std::fs::remove_dir_all(&path);
+    }
+
+    #[test]
+    fn test_from_env_value() {
+        assert_eq!(
+            RetryPolicy::from_env_value(None).unwrap().retries(),
+            DEFAULT_DB_RETRIES
+        );
+        assert_eq!(
+            RetryPolicy::from_env_value(Some("")).unwrap().retries(),
+            DEFAULT_DB_RETRIES
+        );
+        assert_eq!(RetryPolicy::from_env_value(Some("0")).unwrap().retries(), 0);
+        assert_eq!(
+            RetryPolicy::from_env_value(Some(" 5 ")).unwrap().retries(),
+            5
+        );
+
+        match RetryPolicy::from_env_value(Some("-1")) {
+            Err(DbError::ValidationError { message }) => {
+                assert!(message.contains(DB_RETRIES_ENV), "got: {}", message);
+            }
+            other => panic!("Expected ValidationError, got {:?}", other),
+        }
+    }
+}
@@ -7,6 +7,12 @@ use crate::error::DbError;
 use surrealdb::Surreal;
 use surrealdb::engine::local::Db;
 
+/// Version of the schema defined here.
+///
+/// Bump this whenever a change to the definitions below needs existing
+/// databases to be migrated, so `vtb info` can tell builds apart.
+pub const SCHEMA_VERSION: u32 = 1;
+
 /// SQL statements for schema initialization
 mod sql {
     /// Define the task table with all fields
@@ -36,6 +42,13 @@ mod sql {
 
         DEFINE FIELD completed_at ON task TYPE option<datetime>;
 
+        DEFINE FIELD due_at ON task TYPE option<datetime>;
+
+        DEFINE FIELD estimate_minutes ON task TYPE option<int>
+            ASSERT $value = NONE OR $value >= 0;
+
+        DEFINE FIELD deleted_at ON task TYPE option<datetime>;
+
         DEFINE FIELD sections ON task FLEXIBLE TYPE array<object> DEFAULT [];
 
         DEFINE FIELD refs ON task FLEXIBLE TYPE array<object> DEFAULT [];
@@ -586,6 +599,14 @@ mod tests {
             sql::DEFINE_TASK_TABLE.contains("completed_at"),
             "Schema should define completed_at field"
         );
+        assert!(
+            sql::DEFINE_TASK_TABLE.contains("due_at"),
+            "Schema should define due_at field"
+        );
+        assert!(
+            sql::DEFINE_TASK_TABLE.contains("deleted_at"),
+            "Schema should define deleted_at field"
+        );
         assert!(
             sql::DEFINE_TASK_TABLE.contains("option<datetime>"),
             "Timestamp fields should use option<datetime> type"
@@ -820,7 +841,11 @@ mod tests {
             .await
             .unwrap();
         let row: Option<DatetimeRow> = result.take(0).unwrap();
-        assert!(row.is_some(), "started_at should be set after update");
+        let row = row.expect("started_at should be set after update");
+        assert!(
+            row.started_at.0.timestamp() > 0,
+            "started_at should be a valid datetime after update"
+        );
 
         cleanup(&temp_dir);
     }
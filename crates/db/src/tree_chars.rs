@@ -0,0 +1,168 @@
+//! Connector characters for tree output
+//!
+//! Trees are drawn with box-drawing characters by default. Terminals and
+//! fonts that cannot show them get a plain ASCII set instead, chosen
+//! explicitly or picked from the locale.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+/// Which characters connect the branches of a tree
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TreeChars {
+    /// Box-drawing characters: `├──`, `└──`, `│`
+    #[default]
+    Unicode,
+    /// Plain ASCII: `+--`, `\--`, `|`
+    Ascii,
+}
+
+impl TreeChars {
+    /// All sets, in the order they're listed in help and errors
+    pub const ALL: [TreeChars; 2] = [TreeChars::Unicode, TreeChars::Ascii];
+
+    /// Returns the name used on the command line and in config
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TreeChars::Unicode => "unicode",
+            TreeChars::Ascii => "ascii",
+        }
+    }
+
+    /// Connector for a child that has siblings after it
+    pub fn branch(&self) -> &'static str {
+        match self {
+            TreeChars::Unicode => "\u{251C}\u{2500}\u{2500} ", // ├──
+            TreeChars::Ascii => "+-- ",
+        }
+    }
+
+    /// Connector for the last child of a node
+    pub fn last_branch(&self) -> &'static str {
+        match self {
+            TreeChars::Unicode => "\u{2514}\u{2500}\u{2500} ", // └──
+            TreeChars::Ascii => "\\-- ",
+        }
+    }
+
+    /// Indent continuing a branch past a child that has siblings after it
+    pub fn vertical(&self) -> &'static str {
+        match self {
+            TreeChars::Unicode => "\u{2502}   ", // │
+            TreeChars::Ascii => "|   ",
+        }
+    }
+
+    /// Pick a set for the current terminal from the locale environment.
+    pub fn detect() -> Self {
+        let var = |name| std::env::var(name).ok();
+        Self::from_locale(var("LC_ALL"), var("LC_CTYPE"), var("LANG"))
+    }
+
+    /// Pick a set from the values of `LC_ALL`, `LC_CTYPE` and `LANG`.
+    ///
+    /// The first non-empty variable decides, as it does for the C library.
+    /// A UTF-8 locale, or no locale at all, gets [`TreeChars::Unicode`];
+    /// anything else (`C`, `POSIX`, Latin-1 locales) gets [`TreeChars::Ascii`].
+    pub fn from_locale(
+        lc_all: Option<String>,
+        lc_ctype: Option<String>,
+        lang: Option<String>,
+    ) -> Self {
+        let locale = [lc_all, lc_ctype, lang]
+            .into_iter()
+            .flatten()
+            .find(|value| !value.is_empty());
+        match locale {
+            None => TreeChars::Unicode,
+            Some(locale) => {
+                let locale = locale.to_lowercase();
+                if locale.contains("utf-8") || locale.contains("utf8") {
+                    TreeChars::Unicode
+                } else {
+                    TreeChars::Ascii
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for TreeChars {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for TreeChars {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim().to_lowercase();
+        TreeChars::ALL
+            .into_iter()
+            .find(|chars| chars.as_str() == s)
+            .ok_or_else(|| {
+                format!(
+                    "invalid tree chars '{}'. Valid values: {}",
+                    s,
+                    TreeChars::ALL.map(|c| c.as_str()).join(", ")
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn some(s: &str) -> Option<String> {
+        Some(s.to_string())
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("unicode".parse::<TreeChars>(), Ok(TreeChars::Unicode));
+        assert_eq!(" ASCII ".parse::<TreeChars>(), Ok(TreeChars::Ascii));
+        let err = "boxes".parse::<TreeChars>().unwrap_err();
+        assert_eq!(
+            err,
+            "invalid tree chars 'boxes'. Valid values: unicode, ascii"
+        );
+    }
+
+    #[test]
+    fn test_connectors_share_a_width() {
+        for chars in TreeChars::ALL {
+            assert_eq!(chars.branch().chars().count(), 4);
+            assert_eq!(chars.last_branch().chars().count(), 4);
+            assert_eq!(chars.vertical().chars().count(), 4);
+        }
+        assert!(TreeChars::Ascii.branch().is_ascii());
+        assert!(TreeChars::Ascii.last_branch().is_ascii());
+        assert!(TreeChars::Ascii.vertical().is_ascii());
+    }
+
+    #[test]
+    fn test_from_locale() {
+        assert_eq!(TreeChars::from_locale(None, None, None), TreeChars::Unicode);
+        assert_eq!(
+            TreeChars::from_locale(None, None, some("en_US.UTF-8")),
+            TreeChars::Unicode
+        );
+        assert_eq!(
+            TreeChars::from_locale(None, None, some("de_DE.utf8")),
+            TreeChars::Unicode
+        );
+        assert_eq!(
+            TreeChars::from_locale(some("C"), None, some("en_US.UTF-8")),
+            TreeChars::Ascii
+        );
+        assert_eq!(
+            TreeChars::from_locale(some(""), some("POSIX"), some("en_US.UTF-8")),
+            TreeChars::Ascii
+        );
+    }
+}
@@ -0,0 +1,473 @@
+//! Task creation form for the TUI.
+//!
+//! Opened with `a`, the form asks for a title and level and, when a task is
+//! selected in the navigation panel, offers it as the parent. Submitting
+//! creates the task the way `vtb add` does: a task-level backlog item by
+//! default, with an ID from the same generator, created together with its
+//! parent edge.
+//!
+//! Opened with `e`, the same form edits the selected task's title instead,
+//! like `vtb update --title`.
+
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+use vertebrae_db::{
+    BulkTask, Database, DbError, DbResult, IdGenerator, Level, Status, Task, TaskUpdate,
+};
+
+use crate::palette::centered_rect;
+
+/// Levels offered by the form, in the order Left/Right cycles through them.
+const LEVELS: [Level; 3] = [Level::Epic, Level::Ticket, Level::Task];
+
+/// A field of the add-task form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FormField {
+    /// The task title, typed in.
+    #[default]
+    Title,
+    /// The hierarchy level, cycled with Left/Right.
+    Level,
+    /// Whether to put the task under the selected one.
+    Parent,
+}
+
+impl FormField {
+    /// The field Tab moves to.
+    fn next(self) -> Self {
+        match self {
+            Self::Title => Self::Level,
+            Self::Level => Self::Parent,
+            Self::Parent => Self::Title,
+        }
+    }
+}
+
+/// State of the add-task form.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AddTaskForm {
+    /// Title typed so far.
+    title: String,
+    /// Selected level.
+    level: Level,
+    /// Task that was selected when the form opened, if any.
+    selected: Option<String>,
+    /// Whether the new task goes under `selected`.
+    use_parent: bool,
+    /// Field receiving key presses.
+    field: FormField,
+    /// Why the last submit was refused.
+    error: Option<String>,
+    /// Task whose title is being edited, when the form edits rather than adds.
+    editing: Option<String>,
+}
+
+impl AddTaskForm {
+    /// Open the form, offering the `selected` task as the parent.
+    ///
+    /// The level starts at task, the `vtb add` default.
+    pub fn new(selected: Option<String>) -> Self {
+        Self {
+            title: String::new(),
+            level: Level::Task,
+            use_parent: selected.is_some(),
+            selected,
+            field: FormField::default(),
+            error: None,
+            editing: None,
+        }
+    }
+
+    /// Open the form to edit the title of task `id`, starting from `title`.
+    pub fn edit(id: impl Into<String>, title: impl Into<String>, level: Level) -> Self {
+        Self {
+            title: title.into(),
+            level,
+            selected: None,
+            use_parent: false,
+            field: FormField::default(),
+            error: None,
+            editing: Some(id.into()),
+        }
+    }
+
+    /// The task being edited, or `None` when the form adds a task.
+    pub fn editing(&self) -> Option<&str> {
+        self.editing.as_deref()
+    }
+
+    /// Get the title typed so far.
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Get the selected level.
+    pub fn level(&self) -> &Level {
+        &self.level
+    }
+
+    /// The parent the task will be created under, if any.
+    pub fn parent(&self) -> Option<&str> {
+        self.selected.as_deref().filter(|_| self.use_parent)
+    }
+
+    /// Get the field receiving key presses.
+    pub fn field(&self) -> FormField {
+        self.field
+    }
+
+    /// Get the reason the last submit was refused, if any.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Record why the form could not be submitted.
+    pub fn set_error(&mut self, error: impl Into<String>) {
+        self.error = Some(error.into());
+    }
+
+    /// Move to the next field.
+    ///
+    /// Editing only changes the title, so focus stays there.
+    pub fn next_field(&mut self) {
+        if self.editing.is_none() {
+            self.field = self.field.next();
+        }
+    }
+
+    /// Type a character into the title.
+    ///
+    /// Ignored unless the title field is focused.
+    pub fn push_char(&mut self, c: char) {
+        if self.field == FormField::Title {
+            self.title.push(c);
+            self.error = None;
+        }
+    }
+
+    /// Delete the last character of the title.
+    pub fn pop_char(&mut self) {
+        if self.field == FormField::Title {
+            self.title.pop();
+        }
+    }
+
+    /// Change the focused choice: the level, or whether to use the parent.
+    pub fn cycle(&mut self, forward: bool) {
+        match self.field {
+            FormField::Title => {}
+            FormField::Level => {
+                let index = LEVELS.iter().position(|l| *l == self.level).unwrap_or(0);
+                let next = if forward {
+                    (index + 1) % LEVELS.len()
+                } else {
+                    (index + LEVELS.len() - 1) % LEVELS.len()
+                };
+                self.level = LEVELS[next].clone();
+            }
+            FormField::Parent => {
+                if self.selected.is_some() {
+                    self.use_parent = !self.use_parent;
+                }
+            }
+        }
+    }
+
+    /// Check the form can be submitted, recording why not otherwise.
+    pub fn validate(&mut self) -> bool {
+        if self.title.trim().is_empty() {
+            self.error = Some("Title is required".to_string());
+            self.field = FormField::Title;
+            return false;
+        }
+        true
+    }
+}
+
+/// Create the task described by `form`, returning its new ID.
+///
+/// # Errors
+///
+/// Returns `DbError::ValidationError` if the title is blank or no free ID
+/// could be found, `DbError::NotFound` if the parent no longer exists, or
+/// another `DbError` if a database operation fails.
+pub async fn create_task(db: &Database, form: &AddTaskForm) -> DbResult<String> {
+    let title = form.title.trim();
+    if title.is_empty() {
+        return Err(DbError::ValidationError {
+            message: "Title is required".to_string(),
+        });
+    }
+    if let Some(parent) = form.parent()
+        && !db.tasks().exists(parent).await?
+    {
+        return Err(DbError::NotFound {
+            task_id: parent.to_string(),
+        });
+    }
+
+    let id = unused_id(db, title).await?;
+    // Same defaults as `vtb add`
+    let task = Task::new(title, form.level.clone())
+        .with_status(Status::Backlog)
+        .with_needs_human_review(false);
+    // One transaction, so a failed edge can't leave an orphaned root task
+    let entry = BulkTask {
+        id: id.clone(),
+        task,
+        parent: form.parent().map(str::to_string),
+        depends_on: Vec::new(),
+    };
+    db.tasks().bulk_create(&[entry]).await?;
+    Ok(id)
+}
+
+/// Save the title typed into an edit form, returning the edited task's ID.
+///
+/// # Errors
+///
+/// Returns `DbError::ValidationError` if the title is blank or the form
+/// isn't editing a task, `DbError::NotFound` if the task no longer exists,
+/// or another `DbError` if a database operation fails.
+pub async fn update_task(db: &Database, form: &AddTaskForm) -> DbResult<String> {
+    let Some(id) = form.editing() else {
+        return Err(DbError::ValidationError {
+            message: "No task is being edited".to_string(),
+        });
+    };
+    let title = form.title.trim();
+    if title.is_empty() {
+        return Err(DbError::ValidationError {
+            message: "Title is required".to_string(),
+        });
+    }
+    if !db.tasks().exists(id).await? {
+        return Err(DbError::NotFound {
+            task_id: id.to_string(),
+        });
+    }
+
+    let updates = TaskUpdate::new().with_title(title);
+    db.tasks().update(id, &updates).await?;
+    Ok(id.to_string())
+}
+
+/// A task ID that no task uses yet, picked the way `vtb add` picks one.
+async fn unused_id(db: &Database, title: &str) -> DbResult<String> {
+    let mut generator = IdGenerator::new(title);
+    while let Some(id) = generator.next_id() {
+        if !db.tasks().exists(&id).await? {
+            return Ok(id);
+        }
+    }
+    Err(DbError::ValidationError {
+        message: "failed to generate a unique task ID".to_string(),
+    })
+}
+
+/// Render the add-task form as a popup centered over `area`.
+pub fn render_add_task(frame: &mut Frame, area: Rect, form: &AddTaskForm) {
+    let popup = centered_rect(area, 60, 8);
+    frame.render_widget(Clear, popup);
+
+    let title = match &form.editing {
+        Some(id) => format!(" Edit Task {} ", id),
+        None => " Add Task ".to_string(),
+    };
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow));
+
+    let label = |field: FormField, text: &'static str| {
+        let style = if form.field == field {
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::Gray)
+        };
+        Span::styled(text, style)
+    };
+    let parent = match (&form.selected, form.use_parent) {
+        (Some(id), true) => id.clone(),
+        _ => "(none)".to_string(),
+    };
+
+    let mut lines = vec![Line::from(vec![
+        label(FormField::Title, "Title:  "),
+        Span::raw(form.title.as_str()),
+        Span::styled("_", Style::default().fg(Color::DarkGray)),
+    ])];
+    if form.editing.is_none() {
+        lines.push(Line::from(vec![
+            label(FormField::Level, "Level:  "),
+            Span::raw(format!("< {} >", form.level.label())),
+        ]));
+        lines.push(Line::from(vec![
+            label(FormField::Parent, "Parent: "),
+            Span::raw(parent),
+        ]));
+    }
+    lines.push(Line::from(""));
+    let hint = if form.editing.is_some() {
+        "[Enter] Save  [Esc] Cancel"
+    } else {
+        "[Tab] Next field  [</>] Change  [Enter] Create  [Esc] Cancel"
+    };
+    lines.push(match &form.error {
+        Some(error) => Line::from(Span::styled(
+            error.as_str(),
+            Style::default().fg(Color::Red),
+        )),
+        None => Line::from(Span::styled(hint, Style::default().fg(Color::DarkGray))),
+    });
+
+    frame.render_widget(Paragraph::new(lines).block(block), popup);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn setup_test_db() -> (Database, std::path::PathBuf) {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "vtb-tui-add-test-{}-{:?}-{}",
+            std::process::id(),
+            std::thread::current().id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        let db = Database::connect(&temp_dir).await.unwrap();
+        db.init().await.unwrap();
+        (db, temp_dir)
+    }
+
+    #[test]
+    fn test_form_fields_and_cycling() {
+        let mut form = AddTaskForm::new(Some("epic1".to_string()));
+        assert_eq!(form.level(), &Level::Task);
+        assert_eq!(form.parent(), Some("epic1"));
+
+        for c in "Hi".chars() {
+            form.push_char(c);
+        }
+        form.next_field();
+        // Only the title takes text
+        form.push_char('x');
+        assert_eq!(form.title(), "Hi");
+
+        form.cycle(true);
+        assert_eq!(form.level(), &Level::Epic);
+        form.cycle(true);
+        assert_eq!(form.level(), &Level::Ticket);
+        form.cycle(false);
+        assert_eq!(form.level(), &Level::Epic);
+
+        form.next_field();
+        form.cycle(true);
+        assert_eq!(form.parent(), None);
+
+        // Without a selection there is no parent to offer
+        let mut form = AddTaskForm::new(None);
+        assert_eq!(form.level(), &Level::Task);
+        form.next_field();
+        form.next_field();
+        form.cycle(true);
+        assert_eq!(form.parent(), None);
+    }
+
+    #[test]
+    fn test_blank_title_is_refused() {
+        let mut form = AddTaskForm::new(None);
+        form.push_char(' ');
+        form.next_field();
+        assert!(!form.validate());
+        assert_eq!(form.error(), Some("Title is required"));
+        assert_eq!(form.field(), FormField::Title);
+
+        form.push_char('a');
+        assert_eq!(form.error(), None);
+        assert!(form.validate());
+    }
+
+    #[tokio::test]
+    async fn test_create_task_under_parent() {
+        let (db, temp_dir) = setup_test_db().await;
+        db.client()
+            .query(r#"CREATE task:epic1 SET title = "Epic", level = "epic", status = "todo""#)
+            .await
+            .unwrap();
+
+        let mut form = AddTaskForm::new(Some("epic1".to_string()));
+        for c in " New ticket ".chars() {
+            form.push_char(c);
+        }
+        form.next_field();
+        form.cycle(false);
+        let id = create_task(&db, &form).await.unwrap();
+        assert_eq!(id.len(), vertebrae_db::ID_LENGTH);
+
+        let task = db.tasks().get(&id).await.unwrap().unwrap();
+        assert_eq!(task.title, "New ticket");
+        assert_eq!(task.level, Level::Ticket);
+        assert_eq!(task.status, Status::Backlog);
+        assert_eq!(task.needs_human_review, Some(false));
+        assert_eq!(
+            db.relationships().get_parent(&id).await.unwrap(),
+            Some("epic1".to_string())
+        );
+
+        let form = AddTaskForm::new(Some("gone".to_string()));
+        let mut form = form;
+        form.push_char('x');
+        let err = create_task(&db, &form).await.unwrap_err();
+        assert!(matches!(err, DbError::NotFound { .. }));
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_update_task_renames_edited_task() {
+        let (db, temp_dir) = setup_test_db().await;
+        db.client()
+            .query(r#"CREATE task:task1 SET title = "Old", level = "task", status = "todo""#)
+            .await
+            .unwrap();
+
+        let mut form = AddTaskForm::edit("task1", "Old", Level::Task);
+        assert_eq!(form.editing(), Some("task1"));
+        // Only the title can be edited
+        form.next_field();
+        assert_eq!(form.field(), FormField::Title);
+        for _ in 0..3 {
+            form.pop_char();
+        }
+        for c in "New ".chars() {
+            form.push_char(c);
+        }
+        assert_eq!(update_task(&db, &form).await.unwrap(), "task1");
+
+        let task = db.tasks().get("task1").await.unwrap().unwrap();
+        assert_eq!(task.title, "New");
+        assert_eq!(task.status, Status::Todo);
+
+        let mut form = AddTaskForm::edit("gone", "Gone", Level::Task);
+        let err = update_task(&db, &form).await.unwrap_err();
+        assert!(matches!(err, DbError::NotFound { .. }));
+        form.pop_char();
+        form.pop_char();
+        form.pop_char();
+        form.pop_char();
+        assert!(!form.validate());
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+}
@@ -1,28 +1,51 @@
 //! Main application state and event loop.
 
+use std::collections::HashSet;
 use std::io::{self, Stdout};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 
 use crossterm::{
+    event::{
+        DisableMouseCapture, EnableMouseCapture, KeyCode, KeyEvent, MouseButton, MouseEvent,
+        MouseEventKind,
+    },
     execute,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
 use ratatui::Terminal;
 use ratatui::prelude::*;
 
-use vertebrae_db::Database;
+use vertebrae_db::{Database, Status, format_unblocked};
 
-use crate::data::{load_full_tree, load_task_details, load_timeline_tasks};
-use crate::details::TaskDetails;
+use crate::add_task::{AddTaskForm, create_task, update_task};
+use crate::board::{BoardState, MoveDirection, adjacent_status, apply_transition};
+use crate::data::{
+    export_task, load_blocker_summary, load_board_cards, load_node_children, load_task_details,
+    load_timeline_tasks, load_tree,
+};
+use crate::details::{TaskDetails, max_details_scroll};
 use crate::error::TuiResult;
 use crate::event::{
-    is_down, is_enter, is_h, is_l, is_left, is_quit, is_right, is_tab, is_up, poll_key,
+    AppMode, Input, ZoomChange, drain_refresh_events, is_add_task, is_arrows_toggle, is_backspace,
+    is_bottom, is_collapse_all, is_down, is_edit_task, is_enter, is_escape, is_expand_all,
+    is_export, is_filter, is_h, is_half_page_down, is_half_page_up, is_help, is_l, is_left,
+    is_legend_toggle, is_move_left, is_move_right, is_next_blocker, is_palette, is_quit, is_reload,
+    is_right, is_status_filter, is_tab, is_tag_filter, is_today, is_top, is_up, poll_input,
+    spawn_refresh_ticker, status_key, timeline_window_key, timeline_zoom_key,
+};
+use crate::navigation::{FlatNode, TreeNode, TreeState, flatten_tree, hit_test_nav};
+use crate::palette::{PaletteAction, PaletteState};
+use crate::session::SessionState;
+use crate::theme::Theme;
+use crate::timeline::{
+    StatusFilter, TimelineTask, TimelineViewState, TimelineWindow, ZoomLevel, filter_to_window,
 };
-use crate::navigation::{FlatNode, TreeNode, TreeState, flatten_tree};
-use crate::timeline::TimelineTask;
 use crate::ui;
 
+/// Directory next to the database that `x` writes exported tasks to.
+const EXPORT_DIR: &str = "exports";
+
 /// The active tab in the right panel.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum ActiveTab {
@@ -30,6 +53,7 @@ pub enum ActiveTab {
     Details,
     Tree,
     Timeline,
+    Board,
 }
 
 impl ActiveTab {
@@ -38,16 +62,18 @@ impl ActiveTab {
         match self {
             Self::Details => Self::Tree,
             Self::Tree => Self::Timeline,
-            Self::Timeline => Self::Details,
+            Self::Timeline => Self::Board,
+            Self::Board => Self::Details,
         }
     }
 
-    /// Get the index of the current tab (0, 1, or 2).
+    /// Get the index of the current tab (0 to 3).
     pub fn index(self) -> usize {
         match self {
             Self::Details => 0,
             Self::Tree => 1,
             Self::Timeline => 2,
+            Self::Board => 3,
         }
     }
 }
@@ -80,6 +106,11 @@ pub struct App {
     db: Database,
     /// Index of the currently selected task in the navigation list.
     selected_index: usize,
+    /// Navigation panel area in the last draw, for half-page jumps and
+    /// mouse clicks.
+    nav_area: Rect,
+    /// Area of the active view in the last draw, for clamping its scroll.
+    content_area: Rect,
     /// The active tab in the right panel.
     active_tab: ActiveTab,
     /// The currently focused panel (navigation or content).
@@ -90,33 +121,95 @@ pub struct App {
     tree_state: TreeState,
     /// Root nodes of the task tree.
     tree_roots: Vec<TreeNode>,
+    /// Task count above which the tree is loaded lazily.
+    tree_load_limit: usize,
+    /// Whether the tree is loaded lazily, one level per expansion.
+    lazy_tree: bool,
+    /// Node expanded in lazy mode whose children still need loading.
+    pending_children: Option<String>,
+    /// Whether expand-all was requested in lazy mode and the rest of the
+    /// tree still needs loading.
+    pending_expand_all: bool,
     /// Flattened visible nodes (cached).
     visible_nodes: Vec<FlatNode>,
     /// Cached details for the currently selected task.
     selected_task_details: Option<TaskDetails>,
     /// Flag indicating that task details need to be reloaded.
     details_dirty: bool,
-    /// Cached timeline tasks for the timeline view.
+    /// Every started task, before the timeline window is applied.
+    all_timeline_tasks: Vec<TimelineTask>,
+    /// Timeline tasks inside the current window, as shown in the view.
     timeline_tasks: Vec<TimelineTask>,
+    /// Date window the timeline is limited to.
+    timeline_window: TimelineWindow,
+    /// Zoom level forced from the keyboard (`None` fits the date span).
+    timeline_zoom: Option<ZoomLevel>,
+    /// Which timeline tasks are shown by status.
+    timeline_status_filter: StatusFilter,
+    /// Tag timeline tasks must have to be shown.
+    timeline_tag_filter: Option<String>,
     /// Scroll offset for content panel (used in Details, Tree, and Timeline views).
     content_scroll_offset: usize,
     /// Horizontal scroll offset for timeline view (percentage of total timeline width).
     timeline_horizontal_offset: u16,
     /// Selected task index in the timeline view.
     selected_timeline_index: usize,
+    /// Whether the timeline shows its dependency-group color key.
+    show_timeline_legend: bool,
+    /// Whether the timeline connects dependent tasks on adjacent rows.
+    show_dependency_arrows: bool,
+    /// Cards and selection for the board view.
+    board: BoardState,
+    /// Status change requested on the board or from the navigation panel,
+    /// as (task ID, target status).
+    pending_move: Option<(String, Status)>,
+    /// Message shown in the legend bar until the next key press.
+    notification: Option<String>,
+    /// The command palette, when open.
+    palette: Option<PaletteState>,
+    /// What key presses are routed to.
+    mode: AppMode,
+    /// The add-task form, while `mode` is `AppMode::AddTask`.
+    add_task_form: Option<AddTaskForm>,
+    /// Submitted add-task or edit form, waiting to be saved.
+    pending_add: Option<AddTaskForm>,
+    /// Task to write to `export_dir`.
+    pending_export: Option<String>,
+    /// Where exported tasks are written, next to the database.
+    export_dir: PathBuf,
+    /// Index of the highlighted blocker in the details panel.
+    selected_blocker: usize,
+    /// Task to reveal and select in the navigation panel.
+    pending_jump: Option<String>,
+    /// Flag indicating that tasks should be reloaded from the database.
+    reload_requested: bool,
+    /// How often to reload data from the database (`None` disables polling).
+    refresh_interval: Option<Duration>,
+    /// Whether the terminal reports mouse events; off keeps the terminal's
+    /// own text selection working.
+    mouse_capture: bool,
+    /// Color overrides for every view.
+    theme: Theme,
+    /// Where expansion and selection are saved on quit.
+    session_path: PathBuf,
 }
 
 impl App {
     /// Create a new App instance connected to the database.
     ///
+    /// Expanded tasks and the selection saved by the previous session are
+    /// restored, skipping tasks that no longer exist.
+    ///
     /// # Arguments
     ///
     /// * `db_path` - Optional path to the database. If `None`, uses the default path.
+    /// * `tree_load_limit` - Largest task count loaded into the tree up front;
+    ///   bigger trees load children as nodes are expanded.
     ///
     /// # Errors
     ///
     /// Returns `TuiError::Database` if the database connection fails.
-    pub async fn new(db_path: Option<&Path>) -> TuiResult<Self> {
+    pub async fn new(db_path: Option<&Path>, tree_load_limit: usize) -> TuiResult<Self> {
         let path = match db_path {
             Some(p) => p.to_path_buf(),
             None => Database::default_path()?,
@@ -126,7 +219,8 @@ impl App {
         db.init().await?;
 
         // Load task tree from database
-        let tree_roots = load_full_tree(&db).await?;
+        let tree = load_tree(&db, tree_load_limit).await?;
+        let tree_roots = tree.roots;
         let tree_state = TreeState::new();
         let visible_nodes = flatten_tree(&tree_roots, &tree_state);
 
@@ -139,35 +233,305 @@ impl App {
 
         // Load timeline tasks (tasks with started_at timestamp)
         let timeline_tasks = load_timeline_tasks(&db).await?;
+        let board = BoardState::new(load_board_cards(&db).await?);
 
-        Ok(Self {
+        let mut app = Self {
             db,
             selected_index: 0,
+            nav_area: Rect::default(),
+            content_area: Rect::default(),
             active_tab: ActiveTab::default(),
             focused_panel: FocusedPanel::default(),
             running: true,
             tree_state,
             tree_roots,
+            tree_load_limit,
+            lazy_tree: tree.lazy,
+            pending_children: None,
+            pending_expand_all: false,
             visible_nodes,
             selected_task_details,
             details_dirty: false,
-            timeline_tasks,
+            timeline_tasks: timeline_tasks.clone(),
+            all_timeline_tasks: timeline_tasks,
+            timeline_window: TimelineWindow::default(),
+            timeline_zoom: None,
+            timeline_status_filter: StatusFilter::default(),
+            timeline_tag_filter: None,
             content_scroll_offset: 0,
             timeline_horizontal_offset: 0,
             selected_timeline_index: 0,
-        })
+            show_timeline_legend: false,
+            show_dependency_arrows: false,
+            board,
+            pending_move: None,
+            notification: None,
+            palette: None,
+            mode: AppMode::default(),
+            add_task_form: None,
+            pending_add: None,
+            pending_export: None,
+            export_dir: path
+                .parent()
+                .unwrap_or_else(|| Path::new("."))
+                .join(EXPORT_DIR),
+            selected_blocker: 0,
+            pending_jump: None,
+            reload_requested: false,
+            refresh_interval: None,
+            mouse_capture: true,
+            theme: Theme::default(),
+            session_path: SessionState::path_for(&path),
+        };
+        let session = SessionState::load(&app.session_path);
+        app.restore_session(session).await?;
+        Ok(app)
+    }
+
+    /// Re-expand and reselect the tasks recorded in a saved session.
+    async fn restore_session(&mut self, session: SessionState) -> TuiResult<()> {
+        for id in session.expanded {
+            self.tree_state.expand(id);
+        }
+        if self.lazy_tree {
+            self.load_expanded_children(false).await?;
+        }
+        self.tree_state.prune(&self.tree_roots);
+        self.refresh_visible_nodes();
+
+        if let Some(index) = session
+            .selected
+            .and_then(|id| self.visible_nodes.iter().position(|node| node.id == id))
+            && index != self.selected_index
+        {
+            self.selected_index = index;
+            self.details_dirty = true;
+        }
+        self.reload_details_if_dirty().await
+    }
+
+    /// Save the expanded tasks and the selection for the next session.
+    pub fn save_session(&self) -> TuiResult<()> {
+        let session = SessionState {
+            expanded: self.tree_state.expanded_in(&self.tree_roots),
+            selected: self.selected_node().map(|node| node.id.clone()),
+        };
+        session.save(&self.session_path)?;
+        Ok(())
     }
 
     /// Reload tasks from the database.
     ///
     /// This reloads the entire task tree from the database and refreshes
-    /// the visible nodes.
+    /// the visible nodes. In lazy mode the children of every expanded node
+    /// are loaded again, so the same nodes stay open.
     pub async fn reload_tasks(&mut self) -> TuiResult<()> {
-        self.tree_roots = load_full_tree(&self.db).await?;
+        let tree = load_tree(&self.db, self.tree_load_limit).await?;
+        self.tree_roots = tree.roots;
+        self.lazy_tree = tree.lazy;
+
+        if self.lazy_tree {
+            self.load_expanded_children(false).await?;
+        }
+
         self.refresh_visible_nodes();
         Ok(())
     }
 
+    /// Load the children of every expanded node in lazy mode.
+    ///
+    /// With `expand_loaded`, loaded nodes are expanded too, so the whole
+    /// tree ends up loaded and open.
+    async fn load_expanded_children(&mut self, expand_loaded: bool) -> TuiResult<()> {
+        let mut loaded = HashSet::new();
+        loop {
+            if expand_loaded {
+                self.tree_state.expand_all(&self.tree_roots);
+            }
+            let pending: Vec<String> = flatten_tree(&self.tree_roots, &self.tree_state)
+                .into_iter()
+                .filter(|node| node.is_expanded && node.has_children)
+                .map(|node| node.id)
+                .filter(|id| !loaded.contains(id))
+                .collect();
+            if pending.is_empty() {
+                return Ok(());
+            }
+            for id in pending {
+                self.load_children_of(&id).await?;
+                loaded.insert(id);
+            }
+        }
+    }
+
+    /// Load the children of a node expanded in lazy mode, if any.
+    ///
+    /// This should be called in the event loop after handling key events.
+    pub async fn load_pending_children(&mut self) -> TuiResult<()> {
+        if std::mem::take(&mut self.pending_expand_all) {
+            self.pending_children = None;
+            self.load_expanded_children(true).await?;
+            self.refresh_visible_nodes();
+        } else if let Some(id) = self.pending_children.take() {
+            self.load_children_of(&id).await?;
+            self.refresh_visible_nodes();
+        }
+        Ok(())
+    }
+
+    /// Replace a node's placeholder children with freshly loaded ones.
+    async fn load_children_of(&mut self, id: &str) -> TuiResult<()> {
+        let children = load_node_children(&self.db, id).await?;
+        if let Some(node) = find_node_mut(&mut self.tree_roots, id) {
+            node.children = children;
+        }
+        Ok(())
+    }
+
+    /// Whether the tree was too large to load up front.
+    pub fn is_lazy_tree(&self) -> bool {
+        self.lazy_tree
+    }
+
+    /// Reload tasks, timeline, and details if a reload was requested.
+    ///
+    /// This should be called in the event loop after handling key events.
+    pub async fn reload_if_requested(&mut self) -> TuiResult<()> {
+        if self.reload_requested {
+            self.reload_requested = false;
+            self.refresh().await?;
+        }
+        Ok(())
+    }
+
+    /// Reload all view data from the database.
+    ///
+    /// The selected task in the navigation panel and timeline are kept by ID,
+    /// so new or removed tasks don't move the selection to a different task.
+    /// Expansion state is keyed by ID and survives the reload unchanged.
+    pub async fn refresh(&mut self) -> TuiResult<()> {
+        let selected_id = self.selected_node().map(|node| node.id.clone());
+        let timeline_id = self.selected_timeline_task().map(|task| task.id.clone());
+
+        self.reload_tasks().await?;
+        if let Some(index) =
+            selected_id.and_then(|id| self.visible_nodes.iter().position(|node| node.id == id))
+        {
+            self.selected_index = index;
+        }
+
+        self.all_timeline_tasks = load_timeline_tasks(&self.db).await?;
+        self.apply_timeline_window();
+        match timeline_id.and_then(|id| self.timeline_tasks.iter().position(|task| task.id == id)) {
+            Some(index) => self.selected_timeline_index = index,
+            None => {
+                self.selected_timeline_index = self
+                    .selected_timeline_index
+                    .min(self.timeline_tasks.len().saturating_sub(1));
+            }
+        }
+
+        self.board.set_cards(load_board_cards(&self.db).await?);
+
+        self.details_dirty = true;
+        Ok(())
+    }
+
+    /// Apply the status change requested on the board or for the selected
+    /// task, if any.
+    ///
+    /// A refused transition is reported in the notification area rather
+    /// than as an error, and the task keeps its status. A successful one
+    /// reloads the views, so the tree shows the new status right away.
+    ///
+    /// This should be called in the event loop after handling key events.
+    pub async fn apply_pending_move(&mut self) -> TuiResult<()> {
+        let Some((id, target)) = self.pending_move.take() else {
+            return Ok(());
+        };
+
+        match apply_transition(&self.db, &id, &target).await {
+            Ok(unblocked) => {
+                self.refresh().await?;
+                self.board.select_card(&id);
+                let mut message = format!("Moved {} to {}", id, target.as_str());
+                if !unblocked.is_empty() {
+                    message.push_str(". ");
+                    message.push_str(&format_unblocked(&unblocked));
+                }
+                self.notification = Some(message);
+            }
+            Err(err) => self.notification = Some(err.to_string()),
+        }
+        Ok(())
+    }
+
+    /// Create the task submitted in the add-task form, or save the edited
+    /// title, if any.
+    ///
+    /// On success the views reload and the new task is selected, with its
+    /// parent expanded. On failure the form reopens with the error, so the
+    /// typed title isn't lost.
+    ///
+    /// This should be called in the event loop after handling key events.
+    pub async fn apply_pending_add(&mut self) -> TuiResult<()> {
+        let Some(mut form) = self.pending_add.take() else {
+            return Ok(());
+        };
+
+        let saved = match form.editing() {
+            Some(_) => update_task(&self.db, &form).await,
+            None => create_task(&self.db, &form).await,
+        };
+        match saved {
+            Ok(id) => {
+                if let Some(parent) = form.parent() {
+                    self.tree_state.expand(parent);
+                }
+                self.refresh().await?;
+                if let Some(index) = self.visible_nodes.iter().position(|node| node.id == id) {
+                    self.selected_index = index;
+                }
+                self.details_dirty = true;
+                self.notification = Some(match form.editing() {
+                    Some(_) => format!("Updated task {}", id),
+                    None => format!("Created task {}", id),
+                });
+            }
+            Err(err) => {
+                form.set_error(err.to_string());
+                self.add_task_form = Some(form);
+                self.mode = AppMode::AddTask;
+            }
+        }
+        Ok(())
+    }
+
+    /// Set how often the event loop reloads data from the database.
+    ///
+    /// A value of 0 disables periodic refresh.
+    pub fn with_refresh_interval(mut self, seconds: u64) -> Self {
+        self.refresh_interval = (seconds > 0).then(|| Duration::from_secs(seconds));
+        self
+    }
+
+    /// Set whether clicks and the scroll wheel are captured.
+    pub fn with_mouse_capture(mut self, enabled: bool) -> Self {
+        self.mouse_capture = enabled;
+        self
+    }
+
+    /// Set the color theme used by every view.
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Get the color theme.
+    pub fn theme(&self) -> &Theme {
+        &self.theme
+    }
+
     /// Get a reference to the database.
     pub fn db(&self) -> &Database {
         &self.db
@@ -198,6 +562,89 @@ impl App {
         self.selected_task_details.as_ref()
     }
 
+    /// Index of the highlighted blocker, while the focused details panel
+    /// lists blockers.
+    pub fn selected_blocker(&self) -> Option<usize> {
+        let blockers = self.loaded_blockers()?;
+        (self.focused_panel.is_content() && self.active_tab == ActiveTab::Details)
+            .then(|| self.selected_blocker.min(blockers.len().saturating_sub(1)))
+            .filter(|_| !blockers.is_empty())
+    }
+
+    /// Blockers of the selected task, once the summary is loaded.
+    fn loaded_blockers(&self) -> Option<&[(String, String, Status)]> {
+        self.selected_task_details
+            .as_ref()?
+            .blockers
+            .as_ref()
+            .map(|summary| summary.blocked_by.as_slice())
+    }
+
+    /// Highlight the next blocker in the details panel, wrapping around.
+    pub fn select_next_blocker(&mut self) {
+        if let Some(count) = self.loaded_blockers().map(<[_]>::len)
+            && count > 0
+        {
+            self.selected_blocker = (self.selected_blocker + 1) % count;
+        }
+    }
+
+    /// Queue a jump to the highlighted blocker in the navigation panel.
+    pub fn jump_to_selected_blocker(&mut self) {
+        if let Some(index) = self.selected_blocker()
+            && let Some(blockers) = self.loaded_blockers()
+        {
+            self.pending_jump = Some(blockers[index].0.clone());
+        }
+    }
+
+    /// Load the blocker summary for the selected task if the details panel
+    /// is focused and it isn't loaded yet.
+    ///
+    /// This should be called in the event loop after handling key events.
+    pub async fn load_blockers_if_focused(&mut self) -> TuiResult<()> {
+        if !self.focused_panel.is_content() || self.active_tab != ActiveTab::Details {
+            return Ok(());
+        }
+        if let Some(details) = self.selected_task_details.as_mut()
+            && details.blockers.is_none()
+        {
+            details.blockers = Some(load_blocker_summary(&self.db, &details.id).await?);
+        }
+        Ok(())
+    }
+
+    /// Reveal and select the task queued by `jump_to_selected_blocker`.
+    ///
+    /// Its ancestors are expanded and a title filter hiding it is cleared.
+    /// Focus moves to the navigation panel.
+    ///
+    /// This should be called in the event loop after handling key events.
+    pub async fn apply_pending_jump(&mut self) -> TuiResult<()> {
+        let Some(id) = self.pending_jump.take() else {
+            return Ok(());
+        };
+        for ancestor in self.db.graph().get_ancestor_chain(&id).await? {
+            self.tree_state.expand(ancestor);
+        }
+        if self.lazy_tree {
+            self.load_expanded_children(false).await?;
+        }
+        self.refresh_visible_nodes();
+        if self.filter().is_some() && !self.visible_nodes.iter().any(|node| node.id == id) {
+            self.clear_filter();
+        }
+
+        match self.visible_nodes.iter().position(|node| node.id == id) {
+            Some(index) => {
+                self.select_index(index);
+                self.focus_navigation();
+            }
+            None => self.notification = Some(format!("Task {} is not in the tree", id)),
+        }
+        Ok(())
+    }
+
     /// Get a mutable reference to the tree state.
     pub fn tree_state_mut(&mut self) -> &mut TreeState {
         &mut self.tree_state
@@ -218,6 +665,11 @@ impl App {
         self.focused_panel
     }
 
+    /// Get the command palette state, if the palette is open.
+    pub fn palette(&self) -> Option<&PaletteState> {
+        self.palette.as_ref()
+    }
+
     /// Get the content scroll offset.
     pub fn content_scroll_offset(&self) -> usize {
         self.content_scroll_offset
@@ -233,6 +685,26 @@ impl App {
         self.selected_timeline_index
     }
 
+    /// Whether the timeline color key is shown.
+    pub fn show_timeline_legend(&self) -> bool {
+        self.show_timeline_legend
+    }
+
+    /// Show or hide the timeline color key.
+    pub fn toggle_timeline_legend(&mut self) {
+        self.show_timeline_legend = !self.show_timeline_legend;
+    }
+
+    /// Whether the timeline draws dependency arrows.
+    pub fn show_dependency_arrows(&self) -> bool {
+        self.show_dependency_arrows
+    }
+
+    /// Show or hide the timeline dependency arrows.
+    pub fn toggle_dependency_arrows(&mut self) {
+        self.show_dependency_arrows = !self.show_dependency_arrows;
+    }
+
     /// Set the tree roots and refresh the visible nodes.
     pub fn set_tree_roots(&mut self, roots: Vec<TreeNode>) {
         self.tree_roots = roots;
@@ -260,6 +732,158 @@ impl App {
         self.content_scroll_offset = 0;
     }
 
+    /// Switch directly to the given tab.
+    pub fn set_active_tab(&mut self, tab: ActiveTab) {
+        if self.active_tab != tab {
+            self.active_tab = tab;
+            self.content_scroll_offset = 0;
+        }
+    }
+
+    /// Open the command palette with an empty query.
+    pub fn open_palette(&mut self) {
+        self.palette = Some(PaletteState::new());
+    }
+
+    /// Close the command palette without running an action.
+    pub fn close_palette(&mut self) {
+        self.palette = None;
+    }
+
+    /// Get the mode key presses are routed to.
+    pub fn mode(&self) -> AppMode {
+        self.mode
+    }
+
+    /// Get the add-task form, if it is open.
+    pub fn add_task_form(&self) -> Option<&AddTaskForm> {
+        self.add_task_form.as_ref()
+    }
+
+    /// Open the add-task form, offering the selected task as the parent.
+    pub fn open_add_task(&mut self) {
+        let selected = self.selected_node().map(|node| node.id.clone());
+        self.add_task_form = Some(AddTaskForm::new(selected));
+        self.mode = AppMode::AddTask;
+    }
+
+    /// Open the edit form for the task selected in the navigation panel.
+    pub fn open_edit_task(&mut self) {
+        if let Some(node) = self.selected_node() {
+            self.add_task_form = Some(AddTaskForm::edit(
+                node.id.clone(),
+                node.title.clone(),
+                node.level.clone(),
+            ));
+            self.mode = AppMode::AddTask;
+        }
+    }
+
+    /// Request exporting the task selected in the navigation panel.
+    ///
+    /// The file is written by [`App::apply_pending_export`].
+    pub fn export_selected(&mut self) {
+        if let Some(node) = self.selected_node() {
+            self.pending_export = Some(node.id.clone());
+        }
+    }
+
+    /// Write the task requested by [`App::export_selected`], if any, as JSON
+    /// under the export directory.
+    ///
+    /// The outcome is reported in the notification area rather than as an
+    /// error, so a failed write doesn't end the session.
+    ///
+    /// This should be called in the event loop after handling key events.
+    pub async fn apply_pending_export(&mut self) -> TuiResult<()> {
+        let Some(id) = self.pending_export.take() else {
+            return Ok(());
+        };
+
+        self.notification = Some(match export_task(&self.db, &id, &self.export_dir).await {
+            Ok(path) => format!("Exported {} to {}", id, path.display()),
+            Err(err) => format!("Export failed: {}", err),
+        });
+        Ok(())
+    }
+
+    /// Handle a key while the keybinding help is shown.
+    ///
+    /// `?`, Esc and `q` close the help; every other key is ignored.
+    fn handle_help_key(&mut self, key: &crossterm::event::KeyEvent) {
+        if is_help(key) || is_escape(key) || is_quit(key) {
+            self.mode = AppMode::Normal;
+        }
+    }
+
+    /// Start typing a filter for the navigation panel.
+    ///
+    /// Any filter already set is kept and extended.
+    pub fn start_filter(&mut self) {
+        self.mode = AppMode::Filter;
+        self.focus_navigation();
+    }
+
+    /// Get the navigation panel's title filter, if one is set.
+    pub fn filter(&self) -> Option<&str> {
+        self.tree_state.filter()
+    }
+
+    /// Remove the navigation panel's title filter.
+    pub fn clear_filter(&mut self) {
+        self.tree_state.clear_filter();
+        self.apply_filter();
+    }
+
+    /// Recompute the visible nodes after the filter changed.
+    ///
+    /// The selected task stays selected while it's visible; otherwise the
+    /// first match is selected.
+    fn apply_filter(&mut self) {
+        let selected_id = self.selected_node().map(|node| node.id.clone());
+        self.visible_nodes = flatten_tree(&self.tree_roots, &self.tree_state);
+        self.selected_index = selected_id
+            .and_then(|id| self.visible_nodes.iter().position(|node| node.id == id))
+            .or_else(|| {
+                self.visible_nodes
+                    .iter()
+                    .position(|node| node.matched.is_some())
+            })
+            .unwrap_or(0);
+        self.details_dirty = true;
+    }
+
+    /// Close the add-task form without creating anything.
+    pub fn close_add_task(&mut self) {
+        self.add_task_form = None;
+        self.mode = AppMode::Normal;
+    }
+
+    /// Run a command palette action on the current selection.
+    pub fn apply_palette_action(&mut self, action: PaletteAction) {
+        match action {
+            PaletteAction::ShowDetails => self.set_active_tab(ActiveTab::Details),
+            PaletteAction::ShowTree => self.set_active_tab(ActiveTab::Tree),
+            PaletteAction::ShowTimeline => self.set_active_tab(ActiveTab::Timeline),
+            PaletteAction::ShowBoard => self.set_active_tab(ActiveTab::Board),
+            PaletteAction::FocusNavigation => self.focus_navigation(),
+            PaletteAction::FocusContent => self.focus_content(),
+            PaletteAction::ToggleExpand => self.toggle_selected(),
+            PaletteAction::ExpandAll => self.expand_all(),
+            PaletteAction::CollapseAll => self.collapse_all(),
+            PaletteAction::StartTask => self.transition_selected(Status::InProgress),
+            PaletteAction::SubmitForReview => self.transition_selected(Status::PendingReview),
+            PaletteAction::CompleteTask => self.transition_selected(Status::Done),
+            PaletteAction::AddTask => self.open_add_task(),
+            PaletteAction::EditTask => self.open_edit_task(),
+            PaletteAction::ExportTask => self.export_selected(),
+            PaletteAction::Filter => self.start_filter(),
+            PaletteAction::ToggleTimelineLegend => self.toggle_timeline_legend(),
+            PaletteAction::Reload => self.reload_requested = true,
+            PaletteAction::Quit => self.quit(),
+        }
+    }
+
     /// Focus the navigation panel.
     pub fn focus_navigation(&mut self) {
         self.focused_panel = FocusedPanel::Navigation;
@@ -271,13 +895,69 @@ impl App {
     }
 
     /// Scroll content down (increase offset).
+    ///
+    /// Stops once the last line of the details is on screen.
     pub fn scroll_content_down(&mut self) {
-        self.content_scroll_offset = self.content_scroll_offset.saturating_add(1);
+        self.set_content_scroll(self.content_scroll_offset.saturating_add(1));
     }
 
     /// Scroll content up (decrease offset).
     pub fn scroll_content_up(&mut self) {
-        self.content_scroll_offset = self.content_scroll_offset.saturating_sub(1);
+        self.set_content_scroll(self.content_scroll_offset.saturating_sub(1));
+    }
+
+    /// Scroll content down by half the view's height.
+    pub fn scroll_content_half_page_down(&mut self) {
+        let half = self.content_half_page();
+        self.set_content_scroll(self.content_scroll_offset.saturating_add(half));
+    }
+
+    /// Scroll content up by half the view's height.
+    pub fn scroll_content_half_page_up(&mut self) {
+        let half = self.content_half_page();
+        self.set_content_scroll(self.content_scroll_offset.saturating_sub(half));
+    }
+
+    /// Scroll content back to the top.
+    pub fn scroll_content_to_top(&mut self) {
+        self.content_scroll_offset = 0;
+    }
+
+    /// Scroll content to the bottom, when the view's height is known.
+    pub fn scroll_content_to_bottom(&mut self) {
+        if let Some(max) = self.max_content_scroll() {
+            self.content_scroll_offset = max;
+        }
+    }
+
+    /// Record where the active view was drawn, border included.
+    pub fn set_content_area(&mut self, area: Rect) {
+        self.content_area = area;
+    }
+
+    /// Rows moved by a half-page content scroll; at least one.
+    fn content_half_page(&self) -> usize {
+        (self.content_area.height.saturating_sub(2) as usize / 2).max(1)
+    }
+
+    /// Largest content scroll offset, for views that know their height.
+    fn max_content_scroll(&self) -> Option<usize> {
+        match self.active_tab {
+            ActiveTab::Details => Some(
+                self.selected_task_details
+                    .as_ref()
+                    .map_or(0, |details| max_details_scroll(details, self.content_area)),
+            ),
+            _ => None,
+        }
+    }
+
+    /// Set the content scroll offset, clamped to the view's height if known.
+    fn set_content_scroll(&mut self, offset: usize) {
+        self.content_scroll_offset = match self.max_content_scroll() {
+            Some(max) => offset.min(max),
+            None => offset,
+        };
     }
 
     /// Scroll timeline left (decrease horizontal offset).
@@ -310,6 +990,160 @@ impl App {
         }
     }
 
+    /// Get the board view state.
+    pub fn board(&self) -> &BoardState {
+        &self.board
+    }
+
+    /// Get the message for the notification area, if any.
+    pub fn notification(&self) -> Option<&str> {
+        self.notification.as_deref()
+    }
+
+    /// Request moving the selected board card to the adjacent status.
+    ///
+    /// The move is applied by [`App::apply_pending_move`].
+    pub fn move_selected_card(&mut self, direction: MoveDirection) {
+        let Some(card) = self.board.selected_card() else {
+            return;
+        };
+        match adjacent_status(&card.status, direction) {
+            Some(target) => self.pending_move = Some((card.id.clone(), target)),
+            None => {
+                self.notification = Some(format!(
+                    "{} is already in the {} column",
+                    card.id,
+                    if direction == MoveDirection::Left {
+                        "first"
+                    } else {
+                        "last"
+                    }
+                ));
+            }
+        }
+    }
+
+    /// Request moving the task selected in the navigation panel to `target`.
+    ///
+    /// The transition is validated and applied by [`App::apply_pending_move`].
+    pub fn transition_selected(&mut self, target: Status) {
+        if let Some(node) = self.selected_node() {
+            self.pending_move = Some((node.id.clone(), target));
+        }
+    }
+
+    /// Get the date window the timeline is limited to.
+    pub fn timeline_window(&self) -> TimelineWindow {
+        self.timeline_window
+    }
+
+    /// Limit the timeline to a window ending today.
+    ///
+    /// The selection and horizontal scroll reset, since the visible tasks
+    /// and the date scale both change.
+    pub fn set_timeline_window(&mut self, window: TimelineWindow) {
+        self.timeline_window = window;
+        self.apply_timeline_window();
+        self.selected_timeline_index = 0;
+        self.timeline_horizontal_offset = 0;
+    }
+
+    /// Get the zoom level forced on the timeline, if any.
+    pub fn timeline_zoom(&self) -> Option<ZoomLevel> {
+        self.timeline_zoom
+    }
+
+    /// Change the timeline zoom relative to what is currently shown.
+    pub fn zoom_timeline(&mut self, change: ZoomChange) {
+        let mut state = self.timeline_view_state(false, 0);
+        match change {
+            ZoomChange::In => state.zoom_in(&self.timeline_tasks),
+            ZoomChange::Out => state.zoom_out(&self.timeline_tasks),
+            ZoomChange::Auto => state.auto_zoom(),
+        }
+        self.timeline_zoom = state.forced_zoom;
+    }
+
+    /// Scroll the timeline so today is in the middle.
+    ///
+    /// When today is outside the timeline this is reported in the
+    /// notification area instead.
+    pub fn center_timeline_on_today(&mut self) {
+        let mut state = self.timeline_view_state(false, 0);
+        if state.center_on(&self.timeline_tasks, chrono::Local::now().date_naive()) {
+            self.timeline_horizontal_offset = state.horizontal_offset;
+        } else {
+            self.notification = Some("Today is outside the timeline".to_string());
+        }
+    }
+
+    /// Recompute the visible timeline tasks for the current window.
+    ///
+    /// The status and tag filters apply on top of the window, so the date
+    /// range fits only the tasks still shown.
+    fn apply_timeline_window(&mut self) {
+        let state = self.timeline_view_state(false, 0);
+        let windowed = filter_to_window(
+            &self.all_timeline_tasks,
+            state.window_start,
+            state.window_end,
+        );
+        self.timeline_tasks = state.filter_tasks(&windowed);
+    }
+
+    /// Get the timeline status filter.
+    pub fn timeline_status_filter(&self) -> StatusFilter {
+        self.timeline_status_filter
+    }
+
+    /// Get the tag the timeline is limited to, if any.
+    pub fn timeline_tag_filter(&self) -> Option<&str> {
+        self.timeline_tag_filter.as_deref()
+    }
+
+    /// Move to the next timeline status filter.
+    pub fn cycle_timeline_status_filter(&mut self) {
+        self.timeline_status_filter = self.timeline_status_filter.next();
+        self.apply_timeline_window();
+        self.selected_timeline_index = 0;
+    }
+
+    /// Limit the timeline to the next tag in alphabetical order, or to no
+    /// tag after the last one.
+    pub fn cycle_timeline_tag_filter(&mut self) {
+        let mut tags: Vec<&String> = self
+            .all_timeline_tasks
+            .iter()
+            .flat_map(|task| &task.tags)
+            .collect();
+        tags.sort();
+        tags.dedup();
+        let next = match &self.timeline_tag_filter {
+            None => tags.first(),
+            Some(current) => tags.iter().find(|tag| tag.as_str() > current.as_str()),
+        };
+        self.timeline_tag_filter = next.map(|tag| tag.to_string());
+        self.apply_timeline_window();
+        self.selected_timeline_index = 0;
+    }
+
+    /// Build the timeline view state for rendering.
+    pub fn timeline_view_state(&self, is_focused: bool, scroll_offset: usize) -> TimelineViewState {
+        let mut state = TimelineViewState {
+            is_focused,
+            scroll_offset,
+            horizontal_offset: self.timeline_horizontal_offset,
+            selected_index: self.selected_timeline_index,
+            forced_zoom: self.timeline_zoom,
+            status_filter: self.timeline_status_filter,
+            tag_filter: self.timeline_tag_filter.clone(),
+            show_dependency_arrows: self.show_dependency_arrows,
+            ..TimelineViewState::default()
+        };
+        state.set_window(self.timeline_window, chrono::Local::now().date_naive());
+        state
+    }
+
     /// Get the currently selected timeline task, if any.
     pub fn selected_timeline_task(&self) -> Option<&TimelineTask> {
         self.timeline_tasks.get(self.selected_timeline_index)
@@ -336,13 +1170,57 @@ impl App {
         }
     }
 
+    /// Select the first item in the navigation list.
+    pub fn select_first(&mut self) {
+        self.select_index(0);
+    }
+
+    /// Select the last item in the navigation list.
+    pub fn select_last(&mut self) {
+        self.select_index(self.visible_nodes.len().saturating_sub(1));
+    }
+
+    /// Move the selection down by half the navigation viewport.
+    pub fn select_half_page_down(&mut self) {
+        let last = self.visible_nodes.len().saturating_sub(1);
+        self.select_index((self.selected_index + self.half_page()).min(last));
+    }
+
+    /// Move the selection up by half the navigation viewport.
+    pub fn select_half_page_up(&mut self) {
+        self.select_index(self.selected_index.saturating_sub(self.half_page()));
+    }
+
+    /// Record where the navigation panel was drawn, border included.
+    pub fn set_nav_area(&mut self, area: Rect) {
+        self.nav_area = area;
+    }
+
+    /// Rows moved by a half-page jump; at least one so the keys always act.
+    fn half_page(&self) -> usize {
+        (self.nav_area.height.saturating_sub(2) as usize / 2).max(1)
+    }
+
+    /// Select the given navigation index, marking the details stale if it
+    /// changed.
+    fn select_index(&mut self, index: usize) {
+        if index != self.selected_index {
+            self.selected_index = index;
+            self.details_dirty = true;
+        }
+    }
+
     /// Toggle expand/collapse on the currently selected node.
     ///
-    /// Only affects nodes that have children.
+    /// Only affects nodes that have children. In lazy mode, expanding a node
+    /// also queues its children to be loaded.
     pub fn toggle_selected(&mut self) {
         if let Some(node) = self.visible_nodes.get(self.selected_index)
             && node.has_children
         {
+            if self.lazy_tree && !node.is_expanded {
+                self.pending_children = Some(node.id.clone());
+            }
             self.tree_state.toggle(&node.id);
             self.refresh_visible_nodes();
             // Selection may have changed after refresh, so reload details
@@ -350,6 +1228,35 @@ impl App {
         }
     }
 
+    /// Expand every node in the tree.
+    ///
+    /// In lazy mode the rest of the tree is loaded by
+    /// [`App::load_pending_children`].
+    pub fn expand_all(&mut self) {
+        self.tree_state.expand_all(&self.tree_roots);
+        self.pending_expand_all = self.lazy_tree;
+        self.refresh_visible_nodes();
+        self.details_dirty = true;
+    }
+
+    /// Collapse every node, leaving only the roots visible.
+    ///
+    /// The selection moves to the root of the selected node, and is clamped
+    /// to the shorter list.
+    pub fn collapse_all(&mut self) {
+        let root_index = self
+            .visible_nodes
+            .iter()
+            .take(self.selected_index + 1)
+            .filter(|node| node.depth == 0)
+            .count()
+            .saturating_sub(1);
+        self.tree_state.collapse_all();
+        self.refresh_visible_nodes();
+        self.selected_index = root_index.min(self.visible_nodes.len().saturating_sub(1));
+        self.details_dirty = true;
+    }
+
     /// Get the currently selected node, if any.
     pub fn selected_node(&self) -> Option<&FlatNode> {
         self.visible_nodes.get(self.selected_index)
@@ -366,6 +1273,9 @@ impl App {
             } else {
                 self.selected_task_details = None;
             }
+            // The new details may be shorter than the old ones
+            self.set_content_scroll(self.content_scroll_offset);
+            self.selected_blocker = 0;
         }
         Ok(())
     }
@@ -376,7 +1286,7 @@ impl App {
     /// the terminal is restored on exit (even on panic).
     pub async fn run(&mut self) -> TuiResult<()> {
         // Initialize terminal
-        let mut terminal = init_terminal()?;
+        let mut terminal = init_terminal(self.mouse_capture)?;
 
         // Use scopeguard to ensure terminal cleanup on panic
         // The guard will run restore_terminal() even if we panic
@@ -389,7 +1299,8 @@ impl App {
         // Explicitly drop guard before returning (runs cleanup)
         drop(_guard);
 
-        result
+        result?;
+        self.save_session()
     }
 
     /// The main event loop.
@@ -397,22 +1308,107 @@ impl App {
         &mut self,
         terminal: &mut Terminal<CrosstermBackend<Stdout>>,
     ) -> TuiResult<()> {
+        let mut refresh_events = self.refresh_interval.map(spawn_refresh_ticker);
+
         while self.running {
             // Draw the UI
-            terminal.draw(|frame| ui::draw(frame, self))?;
+            let frame = terminal.draw(|frame| ui::draw(frame, self))?;
+            self.set_nav_area(ui::nav_panel_area(frame.area));
+            self.set_content_area(ui::tab_content_area(frame.area));
+
+            // Reload data if the refresh interval elapsed since the last check
+            if let Some(events) = refresh_events.as_mut()
+                && drain_refresh_events(events)
+            {
+                self.refresh().await?;
+                self.reload_details_if_dirty().await?;
+                self.load_blockers_if_focused().await?;
+            }
 
             // Poll for keyboard events
-            if let Some(key) = poll_key(Duration::from_millis(100))? {
-                self.handle_key(&key);
+            if let Some(input) = poll_input(Duration::from_millis(100))? {
+                match input {
+                    Input::Key(key) => self.handle_key(&key),
+                    Input::Mouse(mouse) => self.handle_mouse(&mouse),
+                }
+                self.load_pending_children().await?;
+                self.apply_pending_move().await?;
+                self.apply_pending_add().await?;
+                self.apply_pending_export().await?;
+                self.apply_pending_jump().await?;
+                self.reload_if_requested().await?;
                 // Reload task details if selection changed
                 self.reload_details_if_dirty().await?;
+                self.load_blockers_if_focused().await?;
             }
         }
         Ok(())
     }
 
+    /// Handle a mouse event.
+    ///
+    /// Clicking a navigation row selects it, and clicking its expand
+    /// indicator also toggles it. The scroll wheel acts as up/down: over
+    /// the navigation panel it moves the selection, elsewhere it goes to
+    /// the focused panel. Mouse input is ignored while an overlay is open.
+    pub fn handle_mouse(&mut self, mouse: &MouseEvent) {
+        if self.palette.is_some() || self.mode != AppMode::Normal {
+            return;
+        }
+        let over_nav = self
+            .nav_area
+            .contains(Position::new(mouse.column, mouse.row));
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) if over_nav => {
+                self.notification = None;
+                self.focus_navigation();
+                if let Some(hit) = hit_test_nav(
+                    self.nav_area,
+                    &self.visible_nodes,
+                    self.selected_index,
+                    mouse.column,
+                    mouse.row,
+                ) {
+                    self.select_index(hit.index);
+                    if hit.on_indicator {
+                        self.toggle_selected();
+                    }
+                }
+            }
+            MouseEventKind::ScrollDown if over_nav => self.select_next(),
+            MouseEventKind::ScrollUp if over_nav => self.select_previous(),
+            MouseEventKind::ScrollDown => self.handle_key(&KeyEvent::from(KeyCode::Down)),
+            MouseEventKind::ScrollUp => self.handle_key(&KeyEvent::from(KeyCode::Up)),
+            _ => {}
+        }
+    }
+
     /// Handle a keyboard event.
-    fn handle_key(&mut self, key: &crossterm::event::KeyEvent) {
+    pub fn handle_key(&mut self, key: &crossterm::event::KeyEvent) {
+        self.notification = None;
+
+        // An open palette or form captures all input
+        if self.palette.is_some() {
+            self.handle_palette_key(key);
+            return;
+        }
+        match self.mode {
+            AppMode::AddTask => return self.handle_add_task_key(key),
+            AppMode::Filter => return self.handle_filter_key(key),
+            AppMode::Help => return self.handle_help_key(key),
+            AppMode::Normal => {}
+        }
+
+        if is_help(key) {
+            self.mode = AppMode::Help;
+            return;
+        }
+
+        if is_palette(key) {
+            self.open_palette();
+            return;
+        }
+
         // Global keys work regardless of focus
         if is_quit(key) {
             self.quit();
@@ -424,6 +1420,11 @@ impl App {
             return;
         }
 
+        if is_reload(key) {
+            self.reload_requested = true;
+            return;
+        }
+
         // Context-sensitive keys based on focused panel and active tab
         match self.focused_panel {
             FocusedPanel::Navigation => {
@@ -434,8 +1435,32 @@ impl App {
                     self.select_next();
                 } else if is_up(key) {
                     self.select_previous();
+                } else if is_top(key) {
+                    self.select_first();
+                } else if is_bottom(key) {
+                    self.select_last();
+                } else if is_half_page_down(key) {
+                    self.select_half_page_down();
+                } else if is_half_page_up(key) {
+                    self.select_half_page_up();
                 } else if is_enter(key) {
                     self.toggle_selected();
+                } else if let Some(target) = status_key(key) {
+                    self.transition_selected(target);
+                } else if is_add_task(key) {
+                    self.open_add_task();
+                } else if is_edit_task(key) {
+                    self.open_edit_task();
+                } else if is_export(key) {
+                    self.export_selected();
+                } else if is_expand_all(key) {
+                    self.expand_all();
+                } else if is_collapse_all(key) {
+                    self.collapse_all();
+                } else if is_filter(key) {
+                    self.start_filter();
+                } else if is_escape(key) && self.filter().is_some() {
+                    self.clear_filter();
                 }
             }
             FocusedPanel::Content => {
@@ -445,7 +1470,25 @@ impl App {
                         // - j/k (down/up) for vertical task selection
                         // - h/l (left/right) for horizontal timeline scrolling
                         // - When at left edge (offset 0), h switches back to navigation
-                        if is_down(key) {
+                        // - c toggles the dependency-group color key
+                        // - 0-3 pick the date window (all, week, month, quarter)
+                        // - +/- force a finer/coarser zoom, a returns to auto
+                        // - t scrolls so today is centered
+                        // - f cycles the status filter, # the tag filter
+                        // - e toggles dependency arrows
+                        if let Some(window) = timeline_window_key(key) {
+                            self.set_timeline_window(window);
+                        } else if is_arrows_toggle(key) {
+                            self.toggle_dependency_arrows();
+                        } else if is_status_filter(key) {
+                            self.cycle_timeline_status_filter();
+                        } else if is_tag_filter(key) {
+                            self.cycle_timeline_tag_filter();
+                        } else if is_today(key) {
+                            self.center_timeline_on_today();
+                        } else if let Some(change) = timeline_zoom_key(key) {
+                            self.zoom_timeline(change);
+                        } else if is_down(key) {
                             self.select_next_timeline_task();
                         } else if is_up(key) {
                             self.select_previous_timeline_task();
@@ -458,6 +1501,30 @@ impl App {
                             }
                         } else if is_l(key) || is_right(key) {
                             self.scroll_timeline_right();
+                        } else if is_legend_toggle(key) {
+                            self.toggle_timeline_legend();
+                        }
+                    }
+                    ActiveTab::Board => {
+                        // Board navigation:
+                        // - h/l (left/right) move between columns, and h in the
+                        //   first column switches back to navigation
+                        // - j/k (down/up) move within a column
+                        // - < and > move the selected card to the adjacent status
+                        if is_h(key) || is_left(key) {
+                            if !self.board.select_left() {
+                                self.focus_navigation();
+                            }
+                        } else if is_l(key) || is_right(key) {
+                            self.board.select_right();
+                        } else if is_down(key) {
+                            self.board.select_down();
+                        } else if is_up(key) {
+                            self.board.select_up();
+                        } else if is_move_left(key) {
+                            self.move_selected_card(MoveDirection::Left);
+                        } else if is_move_right(key) {
+                            self.move_selected_card(MoveDirection::Right);
                         }
                     }
                     ActiveTab::Details | ActiveTab::Tree => {
@@ -468,19 +1535,136 @@ impl App {
                             self.scroll_content_down();
                         } else if is_up(key) {
                             self.scroll_content_up();
+                        } else if is_half_page_down(key) {
+                            self.scroll_content_half_page_down();
+                        } else if is_half_page_up(key) {
+                            self.scroll_content_half_page_up();
+                        } else if is_top(key) {
+                            self.scroll_content_to_top();
+                        } else if is_bottom(key) {
+                            self.scroll_content_to_bottom();
+                        } else if self.active_tab == ActiveTab::Details && is_next_blocker(key) {
+                            self.select_next_blocker();
+                        } else if self.active_tab == ActiveTab::Details && is_enter(key) {
+                            self.jump_to_selected_blocker();
                         }
                     }
                 }
             }
         }
     }
+
+    /// Handle a keyboard event while the command palette is open.
+    ///
+    /// Letters go to the query, so only arrow keys move the highlight.
+    fn handle_palette_key(&mut self, key: &crossterm::event::KeyEvent) {
+        use crossterm::event::{KeyCode, KeyModifiers};
+
+        let Some(palette) = self.palette.as_mut() else {
+            return;
+        };
+
+        if is_escape(key) {
+            self.close_palette();
+        } else if is_enter(key) {
+            let action = palette.selected_action();
+            self.close_palette();
+            if let Some(action) = action {
+                self.apply_palette_action(action);
+            }
+        } else if key.code == KeyCode::Down {
+            palette.select_next();
+        } else if key.code == KeyCode::Up {
+            palette.select_previous();
+        } else if is_backspace(key) {
+            palette.pop_char();
+        } else if let KeyCode::Char(c) = key.code
+            && !key.modifiers.contains(KeyModifiers::CONTROL)
+        {
+            palette.push_char(c);
+        }
+    }
+
+    /// Handle a keyboard event while typing a navigation filter.
+    ///
+    /// The visible nodes are recomputed on every keystroke. Enter keeps the
+    /// filter and returns to navigation; Esc removes it.
+    fn handle_filter_key(&mut self, key: &crossterm::event::KeyEvent) {
+        use crossterm::event::{KeyCode, KeyModifiers};
+
+        if is_escape(key) {
+            self.mode = AppMode::Normal;
+            self.clear_filter();
+        } else if is_enter(key) {
+            self.mode = AppMode::Normal;
+        } else if is_backspace(key) {
+            self.tree_state.pop_filter_char();
+            self.apply_filter();
+        } else if let KeyCode::Char(c) = key.code
+            && !key.modifiers.contains(KeyModifiers::CONTROL)
+        {
+            self.tree_state.push_filter_char(c);
+            self.apply_filter();
+        }
+    }
+
+    /// Handle a keyboard event while the add-task form is open.
+    ///
+    /// Letters go to the title, so j/k, q and the other navigation keys
+    /// type rather than act. Tab moves between fields and Left/Right change
+    /// the level or parent.
+    fn handle_add_task_key(&mut self, key: &crossterm::event::KeyEvent) {
+        use crossterm::event::{KeyCode, KeyModifiers};
+
+        let Some(form) = self.add_task_form.as_mut() else {
+            self.mode = AppMode::Normal;
+            return;
+        };
+
+        if is_escape(key) {
+            self.close_add_task();
+        } else if is_enter(key) {
+            if form.validate() {
+                self.pending_add = self.add_task_form.take();
+                self.mode = AppMode::Normal;
+            }
+        } else if is_tab(key) {
+            form.next_field();
+        } else if is_left(key) {
+            form.cycle(false);
+        } else if is_right(key) {
+            form.cycle(true);
+        } else if is_backspace(key) {
+            form.pop_char();
+        } else if let KeyCode::Char(c) = key.code
+            && !key.modifiers.contains(KeyModifiers::CONTROL)
+        {
+            form.push_char(c);
+        }
+    }
+}
+
+/// Find the node with `id` anywhere in the tree.
+fn find_node_mut<'a>(nodes: &'a mut [TreeNode], id: &str) -> Option<&'a mut TreeNode> {
+    for node in nodes {
+        if node.id == id {
+            return Some(node);
+        }
+        if let Some(found) = find_node_mut(&mut node.children, id) {
+            return Some(found);
+        }
+    }
+    None
 }
 
 /// Initialize the terminal for TUI rendering.
-fn init_terminal() -> TuiResult<Terminal<CrosstermBackend<Stdout>>> {
+fn init_terminal(mouse_capture: bool) -> TuiResult<Terminal<CrosstermBackend<Stdout>>> {
     enable_raw_mode()?;
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen)?;
+    if mouse_capture {
+        execute!(stdout, EnableMouseCapture)?;
+    }
     let backend = CrosstermBackend::new(stdout);
     let terminal = Terminal::new(backend)?;
     Ok(terminal)
@@ -489,7 +1673,7 @@ fn init_terminal() -> TuiResult<Terminal<CrosstermBackend<Stdout>>> {
 /// Restore the terminal to its original state.
 fn restore_terminal() -> TuiResult<()> {
     disable_raw_mode()?;
-    execute!(io::stdout(), LeaveAlternateScreen)?;
+    execute!(io::stdout(), DisableMouseCapture, LeaveAlternateScreen)?;
     Ok(())
 }
 
@@ -502,7 +1686,8 @@ mod tests {
     fn test_active_tab_next() {
         assert_eq!(ActiveTab::Details.next(), ActiveTab::Tree);
         assert_eq!(ActiveTab::Tree.next(), ActiveTab::Timeline);
-        assert_eq!(ActiveTab::Timeline.next(), ActiveTab::Details);
+        assert_eq!(ActiveTab::Timeline.next(), ActiveTab::Board);
+        assert_eq!(ActiveTab::Board.next(), ActiveTab::Details);
     }
 
     #[test]
@@ -510,6 +1695,7 @@ mod tests {
         assert_eq!(ActiveTab::Details.index(), 0);
         assert_eq!(ActiveTab::Tree.index(), 1);
         assert_eq!(ActiveTab::Timeline.index(), 2);
+        assert_eq!(ActiveTab::Board.index(), 3);
     }
 
     #[test]
@@ -567,11 +1753,11 @@ mod tests {
         }
 
         fn toggle_selected(&mut self) {
-            if let Some(node) = self.visible_nodes.get(self.selected_index) {
-                if node.has_children {
-                    self.tree_state.toggle(&node.id);
-                    self.refresh_visible_nodes();
-                }
+            if let Some(node) = self.visible_nodes.get(self.selected_index)
+                && node.has_children
+            {
+                self.tree_state.toggle(&node.id);
+                self.refresh_visible_nodes();
             }
         }
     }
@@ -887,4 +2073,897 @@ mod tests {
         app.select_previous_timeline_task();
         assert_eq!(app.selected_timeline_index, 0);
     }
+
+    // ========================================
+    // Command palette tests
+    // ========================================
+
+    fn key(code: crossterm::event::KeyCode) -> crossterm::event::KeyEvent {
+        crossterm::event::KeyEvent::new(code, crossterm::event::KeyModifiers::NONE)
+    }
+
+    /// Create an App backed by an empty temporary database.
+    async fn app_with_temp_db() -> (App, std::path::PathBuf) {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "vtb-tui-app-test-{}-{:?}-{}",
+            std::process::id(),
+            std::thread::current().id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        let app = App::new(Some(&temp_dir), crate::data::DEFAULT_TREE_LOAD_LIMIT)
+            .await
+            .unwrap();
+        (app, temp_dir)
+    }
+
+    #[tokio::test]
+    async fn test_session_restores_expansion_and_selection() {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "vtb-tui-session-app-test-{}-{:?}",
+            std::process::id(),
+            std::thread::current().id()
+        ));
+        let db_path = temp_dir.join("data");
+        let limit = crate::data::DEFAULT_TREE_LOAD_LIMIT;
+
+        let mut app = App::new(Some(&db_path), limit).await.unwrap();
+        app.db()
+            .query(
+                r#"CREATE task:epic1 SET title = "Epic", level = "epic", status = "todo";
+                   CREATE task:task1 SET title = "Child", level = "task", status = "todo";
+                   RELATE task:task1 -> child_of -> task:epic1"#,
+            )
+            .await
+            .unwrap();
+        app.refresh().await.unwrap();
+        app.toggle_selected();
+        app.select_next();
+        app.save_session().unwrap();
+        drop(app);
+
+        // Tasks deleted since the last session are dropped silently
+        let session_path = temp_dir.join(crate::session::SESSION_FILE);
+        let mut session = SessionState::load(&session_path);
+        assert_eq!(session.expanded, vec!["epic1"]);
+        session.expanded.push("gone".to_string());
+        session.save(&session_path).unwrap();
+
+        let app = App::new(Some(&db_path), limit).await.unwrap();
+        assert_eq!(app.visible_nodes().len(), 2);
+        assert_eq!(app.selected_node().unwrap().id, "task1");
+        assert_eq!(app.tree_state.expanded_in(&app.tree_roots), vec!["epic1"]);
+        drop(app);
+
+        // A corrupt file starts fresh
+        std::fs::write(&session_path, "not json").unwrap();
+        let app = App::new(Some(&db_path), limit).await.unwrap();
+        assert_eq!(app.visible_nodes().len(), 1);
+        assert_eq!(app.selected_index(), 0);
+        drop(app);
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_lazy_tree_loads_children_on_expand() {
+        use crossterm::event::KeyCode;
+
+        let (mut app, temp_dir) = app_with_temp_db().await;
+        app.tree_load_limit = 2;
+        app.db()
+            .query(
+                r#"CREATE task:epic1 SET title = "Epic", level = "epic", status = "todo";
+                   CREATE task:ticket1 SET title = "Ticket", level = "ticket", status = "todo";
+                   CREATE task:task1 SET title = "Task", level = "task", status = "todo";
+                   RELATE task:ticket1 -> child_of -> task:epic1;
+                   RELATE task:task1 -> child_of -> task:ticket1"#,
+            )
+            .await
+            .unwrap();
+        app.reload_tasks().await.unwrap();
+        assert!(app.is_lazy_tree());
+
+        let ids = |app: &App| -> Vec<String> {
+            app.visible_nodes().iter().map(|n| n.id.clone()).collect()
+        };
+        assert_eq!(ids(&app), vec!["epic1"]);
+
+        app.handle_key(&key(KeyCode::Enter));
+        app.load_pending_children().await.unwrap();
+        assert_eq!(ids(&app), vec!["epic1", "ticket1"]);
+        assert!(app.visible_nodes()[1].has_children);
+
+        app.select_next();
+        app.handle_key(&key(KeyCode::Enter));
+        app.load_pending_children().await.unwrap();
+        assert_eq!(ids(&app), vec!["epic1", "ticket1", "task1"]);
+
+        // Collapse-all moves the selection to its root
+        app.handle_key(&key(KeyCode::Char('C')));
+        assert_eq!(ids(&app), vec!["epic1"]);
+        assert_eq!(app.selected_index(), 0);
+
+        // Expand-all loads everything still unloaded
+        app.reload_tasks().await.unwrap();
+        app.handle_key(&key(KeyCode::Char('E')));
+        app.load_pending_children().await.unwrap();
+        assert_eq!(ids(&app), vec!["epic1", "ticket1", "task1"]);
+
+        // A reload keeps the expanded nodes open
+        app.reload_tasks().await.unwrap();
+        assert_eq!(ids(&app), vec!["epic1", "ticket1", "task1"]);
+
+        // Within the limit the whole tree is loaded again
+        app.tree_load_limit = 3;
+        app.reload_tasks().await.unwrap();
+        assert!(!app.is_lazy_tree());
+        assert_eq!(ids(&app), vec!["epic1", "ticket1", "task1"]);
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_board_keys_move_card_with_validated_transition() {
+        use crossterm::event::KeyCode;
+
+        let (mut app, temp_dir) = app_with_temp_db().await;
+        app.db()
+            .query(r#"CREATE task:t1 SET title = "Todo", level = "task", status = "todo""#)
+            .await
+            .unwrap();
+        app.refresh().await.unwrap();
+        app.set_active_tab(ActiveTab::Board);
+        app.focus_content();
+
+        // Select the todo column and move the card right
+        app.handle_key(&key(KeyCode::Char('l')));
+        assert_eq!(app.board().selected_card().unwrap().id, "t1");
+        app.handle_key(&key(KeyCode::Char('>')));
+        app.apply_pending_move().await.unwrap();
+        assert_eq!(app.notification(), Some("Moved t1 to in_progress"));
+        assert_eq!(app.board().selected_column(), 2);
+        assert_eq!(
+            app.board().selected_card().unwrap().status,
+            Status::InProgress
+        );
+
+        // in_progress -> todo is refused and reported
+        app.handle_key(&key(KeyCode::Char('<')));
+        app.apply_pending_move().await.unwrap();
+        let message = app.notification().unwrap();
+        assert!(
+            message.contains("Invalid status transition"),
+            "got: {}",
+            message
+        );
+        assert_eq!(
+            app.board().selected_card().unwrap().status,
+            Status::InProgress
+        );
+
+        // The notification clears on the next key press
+        app.handle_key(&key(KeyCode::Char('j')));
+        assert!(app.notification().is_none());
+
+        // h from the first column returns to navigation
+        for _ in 0..2 {
+            app.handle_key(&key(KeyCode::Char('h')));
+        }
+        assert!(app.focused_panel().is_content());
+        app.handle_key(&key(KeyCode::Char('h')));
+        assert!(app.focused_panel().is_navigation());
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_add_task_form_creates_under_selection() {
+        use crossterm::event::KeyCode;
+
+        let (mut app, temp_dir) = app_with_temp_db().await;
+        app.db()
+            .query(r#"CREATE task:epic1 SET title = "Epic", level = "epic", status = "todo""#)
+            .await
+            .unwrap();
+        app.refresh().await.unwrap();
+
+        app.handle_key(&key(KeyCode::Char('a')));
+        assert_eq!(app.mode(), AppMode::AddTask);
+        // Navigation keys type into the title instead
+        for c in "jkq".chars() {
+            app.handle_key(&key(KeyCode::Char(c)));
+        }
+        assert!(app.is_running());
+        assert_eq!(app.selected_index(), 0);
+        assert_eq!(app.add_task_form().unwrap().title(), "jkq");
+
+        app.handle_key(&key(KeyCode::Enter));
+        app.apply_pending_add().await.unwrap();
+        assert_eq!(app.mode(), AppMode::Normal);
+        let node = app.selected_node().unwrap();
+        assert_eq!(node.title, "jkq");
+        assert_eq!(node.level, vertebrae_db::Level::Task);
+        assert_eq!(node.depth, 1);
+        assert_eq!(
+            app.notification(),
+            Some(format!("Created task {}", node.id).as_str())
+        );
+
+        // Esc discards the form and changes nothing
+        app.handle_key(&key(KeyCode::Char('a')));
+        app.handle_key(&key(KeyCode::Char('x')));
+        app.handle_key(&key(KeyCode::Esc));
+        app.apply_pending_add().await.unwrap();
+        assert_eq!(app.mode(), AppMode::Normal);
+        assert!(app.add_task_form().is_none());
+        assert_eq!(app.visible_nodes().len(), 2);
+
+        // An empty title keeps the form open
+        app.handle_key(&key(KeyCode::Char('a')));
+        app.handle_key(&key(KeyCode::Enter));
+        assert_eq!(app.mode(), AppMode::AddTask);
+        assert_eq!(
+            app.add_task_form().unwrap().error(),
+            Some("Title is required")
+        );
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_mouse_selects_and_toggles_navigation_rows() {
+        use crossterm::event::{KeyCode, KeyModifiers};
+
+        let (mut app, temp_dir) = app_with_temp_db().await;
+        app.db()
+            .query(
+                r#"CREATE task:epic1 SET title = "Epic", level = "epic", status = "todo";
+                   CREATE task:task1 SET title = "Child", level = "task", status = "todo";
+                   RELATE task:task1 -> child_of -> task:epic1"#,
+            )
+            .await
+            .unwrap();
+        app.refresh().await.unwrap();
+        app.set_nav_area(Rect::new(0, 0, 20, 10));
+        let mouse = |kind, column, row| MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        };
+        let click = |column, row| mouse(MouseEventKind::Down(MouseButton::Left), column, row);
+
+        // Clicking the title only selects; the indicator expands
+        app.handle_mouse(&click(8, 1));
+        assert_eq!(app.visible_nodes().len(), 1);
+        app.handle_mouse(&click(1, 1));
+        assert_eq!(app.visible_nodes().len(), 2);
+
+        app.handle_mouse(&click(8, 2));
+        assert_eq!(app.selected_node().unwrap().id, "task1");
+        // Clicks on the border or past the last row change nothing
+        app.handle_mouse(&click(0, 1));
+        app.handle_mouse(&click(8, 5));
+        assert_eq!(app.selected_index(), 1);
+
+        app.handle_mouse(&mouse(MouseEventKind::ScrollUp, 5, 5));
+        assert_eq!(app.selected_index(), 0);
+        app.handle_mouse(&mouse(MouseEventKind::ScrollDown, 5, 5));
+        assert_eq!(app.selected_index(), 1);
+
+        // Overlays swallow the mouse
+        app.handle_key(&key(KeyCode::Char('/')));
+        app.handle_mouse(&click(8, 1));
+        assert_eq!(app.selected_index(), 1);
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_details_scroll_clamps_to_content() {
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+        let (mut app, temp_dir) = app_with_temp_db().await;
+        let steps: Vec<String> = (0..40)
+            .map(|i| format!(r#"{{ type: "step", content: "Step {i}", order: {i} }}"#))
+            .collect();
+        app.db()
+            .query(format!(
+                r#"CREATE task:long1 SET title = "Long", level = "task", status = "todo",
+                   sections = [{}]"#,
+                steps.join(", ")
+            ))
+            .await
+            .unwrap();
+        app.refresh().await.unwrap();
+        app.reload_details_if_dirty().await.unwrap();
+        app.set_content_area(Rect::new(0, 0, 60, 22));
+        let max = max_details_scroll(app.selected_task_details().unwrap(), app.content_area);
+        assert!(max > 20);
+
+        app.handle_key(&key(KeyCode::Char('l')));
+        assert_eq!(app.active_tab(), ActiveTab::Details);
+        app.handle_key(&key(KeyCode::Char('j')));
+        assert_eq!(app.content_scroll_offset(), 1);
+        app.handle_key(&KeyEvent::new(KeyCode::Char('d'), KeyModifiers::CONTROL));
+        assert_eq!(app.content_scroll_offset(), 11);
+
+        // Scrolling stops at the last line
+        app.handle_key(&KeyEvent::new(KeyCode::Char('G'), KeyModifiers::SHIFT));
+        assert_eq!(app.content_scroll_offset(), max);
+        app.handle_key(&key(KeyCode::Char('j')));
+        assert_eq!(app.content_scroll_offset(), max);
+        app.handle_key(&key(KeyCode::Char('k')));
+        assert_eq!(app.content_scroll_offset(), max - 1);
+
+        app.handle_key(&key(KeyCode::Char('g')));
+        assert_eq!(app.content_scroll_offset(), 0);
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_details_blocker_jumps_to_task_in_navigation() {
+        use crossterm::event::KeyCode;
+
+        let (mut app, temp_dir) = app_with_temp_db().await;
+        app.db()
+            .query(
+                r#"CREATE task:epic1 SET title = "Epic", level = "epic", status = "todo";
+                   CREATE task:blocker1 SET title = "Blocker", level = "task", status = "in_progress";
+                   CREATE task:task1 SET title = "Blocked", level = "task", status = "todo";
+                   RELATE task:blocker1 -> child_of -> task:epic1;
+                   RELATE task:task1 -> depends_on -> task:blocker1"#,
+            )
+            .await
+            .unwrap();
+        app.refresh().await.unwrap();
+        let index = app
+            .visible_nodes()
+            .iter()
+            .position(|node| node.id == "task1")
+            .unwrap();
+        app.select_index(index);
+        app.reload_details_if_dirty().await.unwrap();
+
+        // Blockers load only once the details panel is focused
+        app.load_blockers_if_focused().await.unwrap();
+        assert!(app.selected_task_details().unwrap().blockers.is_none());
+        app.handle_key(&key(KeyCode::Char('l')));
+        app.load_blockers_if_focused().await.unwrap();
+        let summary = app
+            .selected_task_details()
+            .unwrap()
+            .blockers
+            .clone()
+            .unwrap();
+        assert_eq!(summary.blocked_by[0].2, Status::InProgress);
+        assert_eq!(app.selected_blocker(), Some(0));
+
+        // One blocker: `b` wraps onto it, Enter jumps into the collapsed epic
+        app.handle_key(&key(KeyCode::Char('b')));
+        app.handle_key(&key(KeyCode::Enter));
+        app.apply_pending_jump().await.unwrap();
+        assert_eq!(app.selected_node().unwrap().id, "blocker1");
+        assert!(app.focused_panel().is_navigation());
+        assert_eq!(app.selected_blocker(), None);
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_vim_jumps_clamp_to_navigation_bounds() {
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+        let (mut app, temp_dir) = app_with_temp_db().await;
+        let statements: Vec<String> = (0..10)
+            .map(|i| {
+                format!(
+                    r#"CREATE task:t{i} SET title = "Task {i}", level = "task", status = "todo""#
+                )
+            })
+            .collect();
+        app.db().query(statements.join(";")).await.unwrap();
+        app.refresh().await.unwrap();
+        assert_eq!(app.visible_nodes().len(), 10);
+        let ctrl = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL);
+
+        // Before the first draw a half page is a single row
+        app.handle_key(&ctrl('d'));
+        assert_eq!(app.selected_index(), 1);
+
+        app.set_nav_area(Rect::new(0, 0, 20, 8));
+        app.handle_key(&ctrl('d'));
+        assert_eq!(app.selected_index(), 4);
+        app.handle_key(&ctrl('d'));
+        app.handle_key(&ctrl('d'));
+        assert_eq!(app.selected_index(), 9);
+        app.handle_key(&ctrl('u'));
+        assert_eq!(app.selected_index(), 6);
+
+        app.handle_key(&key(KeyCode::Char('g')));
+        assert_eq!(app.selected_index(), 0);
+        app.handle_key(&ctrl('u'));
+        assert_eq!(app.selected_index(), 0);
+        app.handle_key(&KeyEvent::new(KeyCode::Char('G'), KeyModifiers::SHIFT));
+        assert_eq!(app.selected_index(), 9);
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_filter_mode_narrows_navigation() {
+        use crossterm::event::KeyCode;
+
+        let (mut app, temp_dir) = app_with_temp_db().await;
+        app.db()
+            .query(
+                r#"CREATE task:epic1 SET title = "Epic", level = "epic", status = "todo";
+                   CREATE task:task1 SET title = "Fix login", level = "task", status = "todo";
+                   CREATE task:task2 SET title = "Billing", level = "task", status = "todo";
+                   RELATE task:task1 -> child_of -> task:epic1;
+                   RELATE task:task2 -> child_of -> task:epic1"#,
+            )
+            .await
+            .unwrap();
+        app.refresh().await.unwrap();
+        assert_eq!(app.visible_nodes().len(), 1);
+
+        app.handle_key(&key(KeyCode::Char('/')));
+        assert_eq!(app.mode(), AppMode::Filter);
+        for c in "lo".chars() {
+            app.handle_key(&key(KeyCode::Char(c)));
+        }
+        assert_eq!(app.filter(), Some("lo"));
+        let ids: Vec<&str> = app.visible_nodes().iter().map(|n| n.id.as_str()).collect();
+        assert_eq!(ids, vec!["epic1", "task1"]);
+        assert_eq!(app.selected_node().unwrap().id, "epic1");
+
+        // Enter keeps the filter; navigation works on the filtered list
+        app.handle_key(&key(KeyCode::Enter));
+        assert_eq!(app.mode(), AppMode::Normal);
+        app.handle_key(&key(KeyCode::Char('j')));
+        assert_eq!(app.selected_node().unwrap().id, "task1");
+
+        // The filter survives a reload
+        app.refresh().await.unwrap();
+        assert_eq!(app.visible_nodes().len(), 2);
+
+        // Esc while typing clears it
+        app.handle_key(&key(KeyCode::Char('/')));
+        app.handle_key(&key(KeyCode::Char('x')));
+        assert!(app.visible_nodes().is_empty());
+        app.handle_key(&key(KeyCode::Esc));
+        assert_eq!(app.filter(), None);
+        assert_eq!(app.mode(), AppMode::Normal);
+        assert_eq!(app.visible_nodes().len(), 1);
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_help_swallows_keys_until_dismissed() {
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+        let (mut app, temp_dir) = app_with_temp_db().await;
+        let help = KeyEvent::new(KeyCode::Char('?'), KeyModifiers::SHIFT);
+        let tab = app.active_tab();
+
+        app.handle_key(&help);
+        assert_eq!(app.mode(), AppMode::Help);
+
+        // Keys that would normally act are ignored, and quit only closes it
+        app.handle_key(&key(KeyCode::Tab));
+        assert_eq!(app.active_tab(), tab);
+        app.handle_key(&key(KeyCode::Char('q')));
+        assert_eq!(app.mode(), AppMode::Normal);
+        assert!(app.is_running());
+
+        app.handle_key(&help);
+        app.handle_key(&key(KeyCode::Esc));
+        assert_eq!(app.mode(), AppMode::Normal);
+        app.handle_key(&help);
+        app.handle_key(&help);
+        assert_eq!(app.mode(), AppMode::Normal);
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_nav_keys_transition_selected_task() {
+        use crossterm::event::KeyCode;
+
+        let (mut app, temp_dir) = app_with_temp_db().await;
+        app.db()
+            .query(
+                r#"CREATE task:epic1 SET title = "Epic", level = "epic", status = "todo";
+                   CREATE task:task1 SET title = "Task", level = "task", status = "todo";
+                   RELATE task:task1 -> child_of -> task:epic1"#,
+            )
+            .await
+            .unwrap();
+        app.refresh().await.unwrap();
+        assert_eq!(app.selected_node().unwrap().id, "epic1");
+
+        app.handle_key(&key(KeyCode::Char('s')));
+        app.apply_pending_move().await.unwrap();
+        assert_eq!(app.notification(), Some("Moved epic1 to in_progress"));
+        assert_eq!(app.selected_node().unwrap().status, Status::InProgress);
+
+        // Same workflow as the CLI: review comes before done
+        app.handle_key(&key(KeyCode::Char('d')));
+        app.apply_pending_move().await.unwrap();
+        let message = app.notification().unwrap();
+        assert!(
+            message.contains("Invalid status transition"),
+            "got: {}",
+            message
+        );
+
+        app.handle_key(&key(KeyCode::Char('r')));
+        app.apply_pending_move().await.unwrap();
+        assert_eq!(app.selected_node().unwrap().status, Status::PendingReview);
+
+        // ...and nothing is done while a child is still open
+        app.handle_key(&key(KeyCode::Char('d')));
+        app.apply_pending_move().await.unwrap();
+        let message = app.notification().unwrap();
+        assert!(message.contains("incomplete children"), "got: {}", message);
+        assert_eq!(app.selected_node().unwrap().status, Status::PendingReview);
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_palette_dispatch_mapping() {
+        let (mut app, temp_dir) = app_with_temp_db().await;
+
+        app.apply_palette_action(PaletteAction::ShowBoard);
+        assert_eq!(app.active_tab(), ActiveTab::Board);
+        app.apply_palette_action(PaletteAction::ShowTimeline);
+        assert_eq!(app.active_tab(), ActiveTab::Timeline);
+        app.apply_palette_action(PaletteAction::ShowTree);
+        assert_eq!(app.active_tab(), ActiveTab::Tree);
+        app.apply_palette_action(PaletteAction::ShowDetails);
+        assert_eq!(app.active_tab(), ActiveTab::Details);
+
+        app.apply_palette_action(PaletteAction::FocusContent);
+        assert!(app.focused_panel().is_content());
+        app.apply_palette_action(PaletteAction::FocusNavigation);
+        assert!(app.focused_panel().is_navigation());
+
+        app.apply_palette_action(PaletteAction::ToggleTimelineLegend);
+        assert!(app.show_timeline_legend());
+        app.apply_palette_action(PaletteAction::ToggleTimelineLegend);
+        assert!(!app.show_timeline_legend());
+
+        app.apply_palette_action(PaletteAction::Filter);
+        assert_eq!(app.mode(), AppMode::Filter);
+        app.mode = AppMode::Normal;
+
+        app.apply_palette_action(PaletteAction::Reload);
+        assert!(app.reload_requested);
+        app.reload_if_requested().await.unwrap();
+        assert!(!app.reload_requested);
+        app.handle_key(&key(crossterm::event::KeyCode::Char('R')));
+        assert!(app.reload_requested);
+        app.reload_if_requested().await.unwrap();
+
+        app.apply_palette_action(PaletteAction::Quit);
+        assert!(!app.is_running());
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_palette_dispatches_task_actions_on_selection() {
+        let (mut app, temp_dir) = app_with_temp_db().await;
+        app.db()
+            .query(
+                r#"CREATE task:epic1 SET title = "Epic", level = "epic", status = "todo";
+                   CREATE task:task1 SET title = "Task", level = "task", status = "todo";
+                   RELATE task:task1 -> child_of -> task:epic1"#,
+            )
+            .await
+            .unwrap();
+        app.refresh().await.unwrap();
+        assert_eq!(app.selected_node().unwrap().id, "epic1");
+
+        app.apply_palette_action(PaletteAction::ExpandAll);
+        assert_eq!(app.visible_nodes().len(), 2);
+        app.apply_palette_action(PaletteAction::CollapseAll);
+        assert_eq!(app.visible_nodes().len(), 1);
+
+        // Transitions go through the same checks as s/r/d
+        app.apply_palette_action(PaletteAction::StartTask);
+        app.apply_pending_move().await.unwrap();
+        assert_eq!(app.selected_node().unwrap().status, Status::InProgress);
+        app.apply_palette_action(PaletteAction::SubmitForReview);
+        app.apply_pending_move().await.unwrap();
+        assert_eq!(app.selected_node().unwrap().status, Status::PendingReview);
+        app.apply_palette_action(PaletteAction::CompleteTask);
+        app.apply_pending_move().await.unwrap();
+        assert!(app.notification().unwrap().contains("incomplete children"));
+
+        app.apply_palette_action(PaletteAction::AddTask);
+        assert_eq!(app.mode(), AppMode::AddTask);
+        assert_eq!(app.add_task_form().unwrap().parent(), Some("epic1"));
+        app.close_add_task();
+
+        app.apply_palette_action(PaletteAction::EditTask);
+        assert_eq!(app.add_task_form().unwrap().editing(), Some("epic1"));
+        for c in " 2".chars() {
+            app.handle_key(&key(KeyCode::Char(c)));
+        }
+        app.handle_key(&key(KeyCode::Enter));
+        app.apply_pending_add().await.unwrap();
+        assert_eq!(app.notification(), Some("Updated task epic1"));
+        assert_eq!(app.selected_node().unwrap().title, "Epic 2");
+
+        app.export_dir = temp_dir.join("exports");
+        app.apply_palette_action(PaletteAction::ExportTask);
+        app.apply_pending_export().await.unwrap();
+        let path = temp_dir.join("exports").join("epic1.json");
+        assert_eq!(
+            app.notification(),
+            Some(format!("Exported epic1 to {}", path.display()).as_str())
+        );
+        assert!(std::fs::read_to_string(&path).unwrap().contains("Epic 2"));
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_palette_keys_filter_and_run_action() {
+        use crossterm::event::KeyCode;
+
+        let (mut app, temp_dir) = app_with_temp_db().await;
+
+        app.handle_key(&key(KeyCode::Char(':')));
+        assert!(app.palette().is_some());
+
+        // Letters type into the query instead of triggering keybindings
+        for c in "timeline".chars() {
+            app.handle_key(&key(KeyCode::Char(c)));
+        }
+        assert!(app.is_running());
+        assert_eq!(app.palette().unwrap().query(), "timeline");
+
+        app.handle_key(&key(KeyCode::Enter));
+        assert!(app.palette().is_none());
+        assert_eq!(app.active_tab(), ActiveTab::Timeline);
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_timeline_window_keys_filter_tasks() {
+        use crossterm::event::KeyCode;
+
+        let (mut app, temp_dir) = app_with_temp_db().await;
+        app.db()
+            .query(
+                r#"CREATE task:recent SET title = "Recent", level = "task", status = "in_progress",
+                       started_at = time::now() - 2d;
+                   CREATE task:old SET title = "Old", level = "task", status = "done",
+                       started_at = time::now() - 200d, completed_at = time::now() - 190d"#,
+            )
+            .await
+            .unwrap();
+        app.refresh().await.unwrap();
+        app.set_active_tab(ActiveTab::Timeline);
+        app.focus_content();
+        assert_eq!(app.timeline_tasks().len(), 2);
+
+        app.handle_key(&key(KeyCode::Char('1')));
+        assert_eq!(app.timeline_window(), TimelineWindow::LastWeek);
+        let ids: Vec<&str> = app.timeline_tasks().iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(ids, vec!["recent"]);
+        let state = app.timeline_view_state(true, 0);
+        assert_eq!(state.window_end, Some(chrono::Local::now().date_naive()));
+
+        // The window survives a reload
+        app.refresh().await.unwrap();
+        assert_eq!(app.timeline_tasks().len(), 1);
+
+        app.handle_key(&key(KeyCode::Char('0')));
+        assert_eq!(app.timeline_tasks().len(), 2);
+        assert_eq!(app.timeline_view_state(true, 0).window_start, None);
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_timeline_zoom_and_today_keys() {
+        use crossterm::event::KeyCode;
+
+        let (mut app, temp_dir) = app_with_temp_db().await;
+        app.db()
+            .query(
+                r#"CREATE task:t1 SET title = "Long", level = "task", status = "done",
+                       started_at = time::now() - 200d, completed_at = time::now() - 100d"#,
+            )
+            .await
+            .unwrap();
+        app.refresh().await.unwrap();
+        app.set_active_tab(ActiveTab::Timeline);
+        app.focus_content();
+        let zoom = |app: &App| {
+            app.timeline_view_state(true, 0)
+                .zoom_level(app.timeline_tasks())
+        };
+        assert_eq!(zoom(&app), ZoomLevel::Months);
+
+        app.handle_key(&key(KeyCode::Char('+')));
+        assert_eq!(app.timeline_zoom(), Some(ZoomLevel::Weeks));
+        app.handle_key(&key(KeyCode::Char('+')));
+        assert_eq!(zoom(&app), ZoomLevel::Days);
+        app.handle_key(&key(KeyCode::Char('-')));
+        assert_eq!(zoom(&app), ZoomLevel::Weeks);
+
+        app.handle_key(&key(KeyCode::Char('a')));
+        assert_eq!(app.timeline_zoom(), None);
+        assert_eq!(zoom(&app), ZoomLevel::Months);
+
+        // The task ended 100 days ago, so today is past the timeline
+        app.handle_key(&key(KeyCode::Char('t')));
+        assert_eq!(app.notification(), Some("Today is outside the timeline"));
+        assert_eq!(app.timeline_horizontal_offset(), 0);
+
+        // A window ending today always contains it
+        app.set_timeline_window(TimelineWindow::LastQuarter);
+        app.handle_key(&key(KeyCode::Char('t')));
+        assert_eq!(app.notification(), None);
+        assert_eq!(app.timeline_horizontal_offset(), 50);
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_timeline_filter_keys() {
+        use crossterm::event::KeyCode;
+
+        let (mut app, temp_dir) = app_with_temp_db().await;
+        app.db()
+            .query(
+                r#"CREATE task:old SET title = "Old", level = "task", status = "done",
+                       started_at = time::now() - 200d, completed_at = time::now() - 190d;
+                   CREATE task:api SET title = "API", level = "task", status = "in_progress",
+                       tags = ["backend"], started_at = time::now() - 2d;
+                   CREATE task:ui SET title = "UI", level = "task", status = "in_progress",
+                       tags = ["frontend"], started_at = time::now() - 1d"#,
+            )
+            .await
+            .unwrap();
+        app.refresh().await.unwrap();
+        app.set_active_tab(ActiveTab::Timeline);
+        app.focus_content();
+        let ids = |app: &App| -> Vec<String> {
+            app.timeline_tasks().iter().map(|t| t.id.clone()).collect()
+        };
+        assert_eq!(ids(&app).len(), 3);
+
+        app.handle_key(&key(KeyCode::Char('f')));
+        assert_eq!(app.timeline_status_filter(), StatusFilter::HideDone);
+        assert_eq!(ids(&app), vec!["api", "ui"]);
+        app.handle_key(&key(KeyCode::Char('f')));
+        assert_eq!(ids(&app), vec!["old"]);
+        app.handle_key(&key(KeyCode::Char('f')));
+        assert_eq!(app.timeline_status_filter(), StatusFilter::All);
+
+        app.handle_key(&key(KeyCode::Char('#')));
+        assert_eq!(app.timeline_tag_filter(), Some("backend"));
+        assert_eq!(ids(&app), vec!["api"]);
+        app.handle_key(&key(KeyCode::Char('#')));
+        assert_eq!(ids(&app), vec!["ui"]);
+        // The filter survives a reload
+        app.refresh().await.unwrap();
+        assert_eq!(ids(&app), vec!["ui"]);
+        app.handle_key(&key(KeyCode::Char('#')));
+        assert_eq!(app.timeline_tag_filter(), None);
+        assert_eq!(ids(&app).len(), 3);
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_legend_key_only_toggles_in_timeline() {
+        use crossterm::event::KeyCode;
+
+        let (mut app, temp_dir) = app_with_temp_db().await;
+        app.focus_content();
+
+        app.handle_key(&key(KeyCode::Char('c')));
+        assert!(!app.show_timeline_legend());
+
+        app.set_active_tab(ActiveTab::Timeline);
+        app.handle_key(&key(KeyCode::Char('c')));
+        assert!(app.show_timeline_legend());
+        app.handle_key(&key(KeyCode::Char('c')));
+        assert!(!app.show_timeline_legend());
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_palette_escape_closes_without_action() {
+        use crossterm::event::KeyCode;
+
+        let (mut app, temp_dir) = app_with_temp_db().await;
+
+        app.handle_key(&key(KeyCode::Char(':')));
+        app.handle_key(&key(KeyCode::Char('q')));
+        app.handle_key(&key(KeyCode::Esc));
+
+        assert!(app.palette().is_none());
+        assert!(app.is_running());
+        assert_eq!(app.active_tab(), ActiveTab::Details);
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    // ========================================
+    // Refresh tests
+    // ========================================
+
+    async fn create_task(db: &Database, id: &str, title: &str) {
+        let task = vertebrae_db::Task::new(title, Level::Epic);
+        db.tasks().create(id, &task).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_refresh_preserves_selected_id() {
+        let (mut app, temp_dir) = app_with_temp_db().await;
+        create_task(app.db(), "first", "First").await;
+        create_task(app.db(), "second", "Second").await;
+        app.refresh().await.unwrap();
+
+        let index = app
+            .visible_nodes()
+            .iter()
+            .position(|node| node.id == "first")
+            .unwrap();
+        app.selected_index = index;
+
+        // A newer task sorts first and shifts the old selection's index
+        create_task(app.db(), "third", "Third").await;
+        app.refresh().await.unwrap();
+
+        assert_eq!(app.visible_nodes().len(), 3);
+        assert_eq!(app.selected_node().unwrap().id, "first");
+        assert!(app.details_dirty);
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_refresh_clamps_when_selected_task_removed() {
+        let (mut app, temp_dir) = app_with_temp_db().await;
+        create_task(app.db(), "only", "Only").await;
+        app.refresh().await.unwrap();
+        assert_eq!(app.selected_node().unwrap().id, "only");
+
+        app.db().query("DELETE task:only").await.unwrap();
+        app.refresh().await.unwrap();
+
+        assert!(app.visible_nodes().is_empty());
+        assert!(app.selected_node().is_none());
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_with_refresh_interval_zero_disables_polling() {
+        let (app, temp_dir) = app_with_temp_db().await;
+
+        let app = app.with_refresh_interval(0);
+        assert_eq!(app.refresh_interval, None);
+        let app = app.with_refresh_interval(5);
+        assert_eq!(app.refresh_interval, Some(Duration::from_secs(5)));
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
 }
@@ -0,0 +1,487 @@
+//! Kanban board view for the TUI.
+//!
+//! Lays tasks out in one column per workflow status. The selected card can
+//! be moved to the neighbouring column, which runs the same validated status
+//! transition as `vtb transition-to`.
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+};
+use vertebrae_db::{Database, DbResult, Status, TransitionOptions};
+
+use crate::navigation::status_color;
+use crate::theme::Theme;
+
+/// Statuses shown as board columns, left to right.
+pub const BOARD_STATUSES: [Status; 5] = [
+    Status::Backlog,
+    Status::Todo,
+    Status::InProgress,
+    Status::PendingReview,
+    Status::Done,
+];
+
+/// A task shown as a card on the board.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoardCard {
+    /// Task ID.
+    pub id: String,
+    /// Task title.
+    pub title: String,
+    /// Current status, which decides the card's column.
+    pub status: Status,
+}
+
+/// Direction to move a card across the board.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MoveDirection {
+    /// Towards backlog.
+    Left,
+    /// Towards done.
+    Right,
+}
+
+/// Cards grouped into columns, plus the selected card's position.
+#[derive(Debug, Clone, Default)]
+pub struct BoardState {
+    /// One list of cards per entry in [`BOARD_STATUSES`].
+    columns: Vec<Vec<BoardCard>>,
+    /// Index of the selected column.
+    column: usize,
+    /// Index of the selected card within the selected column.
+    row: usize,
+}
+
+impl BoardState {
+    /// Create a board from cards, selecting the first column.
+    ///
+    /// Cards whose status has no column (rejected) are left off.
+    pub fn new(cards: Vec<BoardCard>) -> Self {
+        let mut state = Self::default();
+        state.set_cards(cards);
+        state
+    }
+
+    /// Replace the cards, keeping the selected card selected if it is still
+    /// on the board. Otherwise the selection stays in the same column.
+    pub fn set_cards(&mut self, cards: Vec<BoardCard>) {
+        let selected_id = self.selected_card().map(|card| card.id.clone());
+
+        let mut columns = vec![Vec::new(); BOARD_STATUSES.len()];
+        for card in cards {
+            if let Some(index) = column_index(&card.status) {
+                columns[index].push(card);
+            }
+        }
+        self.columns = columns;
+
+        if !selected_id.is_some_and(|id| self.select_card(&id)) {
+            self.clamp_row();
+        }
+    }
+
+    /// The board's columns, in [`BOARD_STATUSES`] order.
+    pub fn columns(&self) -> &[Vec<BoardCard>] {
+        &self.columns
+    }
+
+    /// Index of the selected column.
+    pub fn selected_column(&self) -> usize {
+        self.column
+    }
+
+    /// Index of the selected card within its column.
+    pub fn selected_row(&self) -> usize {
+        self.row
+    }
+
+    /// The selected card, if its column has any cards.
+    pub fn selected_card(&self) -> Option<&BoardCard> {
+        self.columns.get(self.column)?.get(self.row)
+    }
+
+    /// Select the card with `id`, returning whether it is on the board.
+    pub fn select_card(&mut self, id: &str) -> bool {
+        for (column, cards) in self.columns.iter().enumerate() {
+            if let Some(row) = cards.iter().position(|card| card.id == id) {
+                self.column = column;
+                self.row = row;
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Select the column to the left, returning `false` at the first column.
+    pub fn select_left(&mut self) -> bool {
+        if self.column == 0 {
+            return false;
+        }
+        self.column -= 1;
+        self.clamp_row();
+        true
+    }
+
+    /// Select the column to the right, returning `false` at the last column.
+    pub fn select_right(&mut self) -> bool {
+        if self.column + 1 >= self.columns.len() {
+            return false;
+        }
+        self.column += 1;
+        self.clamp_row();
+        true
+    }
+
+    /// Select the card below, clamping at the bottom of the column.
+    pub fn select_down(&mut self) {
+        if self.row + 1 < self.column_len() {
+            self.row += 1;
+        }
+    }
+
+    /// Select the card above, clamping at the top of the column.
+    pub fn select_up(&mut self) {
+        self.row = self.row.saturating_sub(1);
+    }
+
+    /// Number of cards in the selected column.
+    fn column_len(&self) -> usize {
+        self.columns.get(self.column).map_or(0, Vec::len)
+    }
+
+    /// Keep the row inside the selected column after it changed.
+    fn clamp_row(&mut self) {
+        self.column = self.column.min(self.columns.len().saturating_sub(1));
+        self.row = self.row.min(self.column_len().saturating_sub(1));
+    }
+}
+
+/// Index of the board column for `status`, if it has one.
+pub fn column_index(status: &Status) -> Option<usize> {
+    BOARD_STATUSES.iter().position(|s| s == status)
+}
+
+/// The status of the column next to `status` in `direction`.
+///
+/// This is only the board layout; whether the task may actually make that
+/// transition is checked when the move is applied.
+pub fn adjacent_status(status: &Status, direction: MoveDirection) -> Option<Status> {
+    let index = column_index(status)?;
+    let target = match direction {
+        MoveDirection::Left => index.checked_sub(1)?,
+        MoveDirection::Right => index + 1,
+    };
+    BOARD_STATUSES.get(target).cloned()
+}
+
+/// Move a task to `target`, enforcing the same rules as `vtb transition-to`.
+///
+/// The rules live in [`vertebrae_db::TaskRepository::transition`]; the
+/// board never forces past triage warnings.
+///
+/// Returns the `(id, title)` of each task the move unblocked, which is only
+/// ever non-empty for a move to done.
+///
+/// # Errors
+///
+/// Returns `DbError::InvalidStatusTransition`, `TriageValidationFailed`,
+/// `ValidationError` or `IncompleteChildren` when the move is refused, and
+/// `DbError::Query` if the database operation fails.
+pub async fn apply_transition(
+    db: &Database,
+    id: &str,
+    target: &Status,
+) -> DbResult<Vec<(String, String)>> {
+    let outcome = db
+        .tasks()
+        .transition(id, target, &TransitionOptions::default())
+        .await?;
+    Ok(outcome.unblocked)
+}
+
+/// Render the board view with one column per status.
+///
+/// # Arguments
+///
+/// * `frame` - The frame to render to
+/// * `area` - The area to render within
+/// * `state` - Board cards and selection
+/// * `is_focused` - Whether this panel currently has focus
+/// * `theme` - Color overrides
+pub fn render_board_view(
+    frame: &mut Frame,
+    area: Rect,
+    state: &BoardState,
+    is_focused: bool,
+    theme: &Theme,
+) {
+    let border_color = theme.border(is_focused);
+
+    let block = Block::default()
+        .title(" Board ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(border_color));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    if state.columns().iter().all(Vec::is_empty) {
+        let paragraph =
+            Paragraph::new("No tasks found").style(Style::default().fg(Color::DarkGray));
+        frame.render_widget(paragraph, inner);
+        return;
+    }
+
+    let constraints = vec![Constraint::Ratio(1, BOARD_STATUSES.len() as u32); BOARD_STATUSES.len()];
+    let areas = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(constraints)
+        .split(inner);
+
+    for (index, (status, cards)) in BOARD_STATUSES.iter().zip(state.columns()).enumerate() {
+        let selected = index == state.selected_column();
+        let color = theme.status(status, status_color(status));
+
+        let title = Line::from(vec![Span::styled(
+            format!(" {} ({}) ", status.as_str(), cards.len()),
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        )]);
+        let column_border = if selected && is_focused {
+            theme.border(true)
+        } else {
+            Color::DarkGray
+        };
+        let column_block = Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(column_border));
+
+        let items: Vec<ListItem> = cards
+            .iter()
+            .map(|card| {
+                ListItem::new(Line::from(vec![
+                    Span::styled(format!("{} ", card.id), Style::default().fg(color)),
+                    Span::raw(card.title.clone()),
+                ]))
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(column_block)
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+
+        let mut list_state = ListState::default();
+        if selected && !cards.is_empty() {
+            list_state.select(Some(state.selected_row()));
+        }
+        frame.render_stateful_widget(list, areas[index], &mut list_state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use vertebrae_db::DbError;
+
+    /// Helper to create a test database
+    async fn setup_test_db() -> (Database, std::path::PathBuf) {
+        let temp_dir = env::temp_dir().join(format!(
+            "vtb-board-test-{}-{:?}-{}",
+            std::process::id(),
+            std::thread::current().id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+
+        let db = Database::connect(&temp_dir).await.unwrap();
+        db.init().await.unwrap();
+
+        (db, temp_dir)
+    }
+
+    /// Clean up test database
+    fn cleanup(path: &std::path::Path) {
+        let _ = std::fs::remove_dir_all(path);
+    }
+
+    fn card(id: &str, status: Status) -> BoardCard {
+        BoardCard {
+            id: id.to_string(),
+            title: format!("Task {}", id),
+            status,
+        }
+    }
+
+    fn sample_board() -> BoardState {
+        BoardState::new(vec![
+            card("b1", Status::Backlog),
+            card("t1", Status::Todo),
+            card("t2", Status::Todo),
+            card("t3", Status::Todo),
+            card("d1", Status::Done),
+            card("x1", Status::Rejected),
+        ])
+    }
+
+    fn ids(cards: &[BoardCard]) -> Vec<&str> {
+        cards.iter().map(|c| c.id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_cards_land_in_their_status_column() {
+        let mut board = sample_board();
+        let columns = board.columns();
+        assert_eq!(columns.len(), BOARD_STATUSES.len());
+        assert_eq!(ids(&columns[0]), vec!["b1"]);
+        assert_eq!(ids(&columns[1]), vec!["t1", "t2", "t3"]);
+        assert!(columns[2].is_empty());
+        assert!(columns[3].is_empty());
+        assert_eq!(ids(&columns[4]), vec!["d1"]);
+        assert!(!board.select_card("x1"));
+    }
+
+    #[test]
+    fn test_navigation_within_and_across_columns() {
+        let mut board = sample_board();
+        assert_eq!(board.selected_card().unwrap().id, "b1");
+
+        // Left edge reports that it did not move
+        assert!(!board.select_left());
+
+        assert!(board.select_right());
+        assert_eq!(board.selected_card().unwrap().id, "t1");
+        board.select_down();
+        board.select_down();
+        board.select_down();
+        assert_eq!(board.selected_card().unwrap().id, "t3");
+        board.select_up();
+        assert_eq!(board.selected_card().unwrap().id, "t2");
+
+        // An empty column has no selected card
+        assert!(board.select_right());
+        assert_eq!(board.selected_column(), 2);
+        assert_eq!(board.selected_row(), 0);
+        assert!(board.selected_card().is_none());
+        board.select_down();
+        assert_eq!(board.selected_row(), 0);
+
+        assert!(board.select_right());
+        assert!(board.select_right());
+        assert_eq!(board.selected_card().unwrap().id, "d1");
+        assert!(!board.select_right());
+    }
+
+    #[test]
+    fn test_row_clamps_when_moving_to_shorter_column() {
+        let mut board = sample_board();
+        board.select_right();
+        board.select_down();
+        board.select_down();
+        assert_eq!(board.selected_row(), 2);
+
+        board.select_left();
+        assert_eq!(board.selected_card().unwrap().id, "b1");
+    }
+
+    #[test]
+    fn test_set_cards_keeps_selected_card() {
+        let mut board = sample_board();
+        board.select_card("t2");
+
+        // t2 moved to in_progress: the selection follows it
+        board.set_cards(vec![
+            card("t1", Status::Todo),
+            card("t2", Status::InProgress),
+        ]);
+        assert_eq!(board.selected_column(), 2);
+        assert_eq!(board.selected_card().unwrap().id, "t2");
+
+        // t2 is gone: the selection stays in its column
+        board.set_cards(vec![card("t1", Status::Todo)]);
+        assert_eq!(board.selected_column(), 2);
+        assert!(board.selected_card().is_none());
+    }
+
+    #[test]
+    fn test_adjacent_status_mapping() {
+        use MoveDirection::{Left, Right};
+
+        assert_eq!(adjacent_status(&Status::Backlog, Left), None);
+        assert_eq!(adjacent_status(&Status::Backlog, Right), Some(Status::Todo));
+        assert_eq!(adjacent_status(&Status::Todo, Left), Some(Status::Backlog));
+        assert_eq!(
+            adjacent_status(&Status::Todo, Right),
+            Some(Status::InProgress)
+        );
+        assert_eq!(
+            adjacent_status(&Status::InProgress, Right),
+            Some(Status::PendingReview)
+        );
+        assert_eq!(
+            adjacent_status(&Status::PendingReview, Left),
+            Some(Status::InProgress)
+        );
+        assert_eq!(
+            adjacent_status(&Status::PendingReview, Right),
+            Some(Status::Done)
+        );
+        assert_eq!(adjacent_status(&Status::Done, Right), None);
+        assert_eq!(adjacent_status(&Status::Rejected, Left), None);
+        assert_eq!(adjacent_status(&Status::Rejected, Right), None);
+    }
+
+    #[test]
+    fn test_adjacent_moves_match_workflow() {
+        // Every forward move on the board is an allowed transition
+        for status in &BOARD_STATUSES[..BOARD_STATUSES.len() - 1] {
+            let target = adjacent_status(status, MoveDirection::Right).unwrap();
+            assert!(status.can_transition_to(&target), "{:?}", status);
+        }
+
+        // Moving back is only allowed out of review
+        assert!(!Status::Todo.can_transition_to(&Status::Backlog));
+        assert!(Status::PendingReview.can_transition_to(&Status::InProgress));
+    }
+
+    #[tokio::test]
+    async fn test_apply_transition_validates_moves() {
+        let (db, temp_dir) = setup_test_db().await;
+        db.client()
+            .query(
+                r#"CREATE task:t1 SET title = "Todo", level = "task", status = "todo";
+                   CREATE task:r1 SET title = "Review", level = "task", status = "pending_review";
+                   CREATE task:w1 SET title = "Waiting", level = "task", status = "todo";
+                   RELATE task:w1->depends_on->task:r1"#,
+            )
+            .await
+            .unwrap();
+
+        // todo -> backlog is not part of the workflow
+        let err = apply_transition(&db, "t1", &Status::Backlog)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, DbError::InvalidStatusTransition { .. }));
+
+        let unblocked = apply_transition(&db, "t1", &Status::InProgress)
+            .await
+            .unwrap();
+        assert!(unblocked.is_empty());
+        let task = db.tasks().get("t1").await.unwrap().unwrap();
+        assert_eq!(task.status, Status::InProgress);
+        assert!(task.started_at.is_some());
+
+        let unblocked = apply_transition(&db, "r1", &Status::Done).await.unwrap();
+        assert_eq!(unblocked, vec![("w1".to_string(), "Waiting".to_string())]);
+        let task = db.tasks().get("r1").await.unwrap().unwrap();
+        assert_eq!(task.status, Status::Done);
+        assert!(task.completed_at.is_some());
+
+        cleanup(&temp_dir);
+    }
+}
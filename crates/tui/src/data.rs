@@ -3,14 +3,20 @@
 //! Provides functions to load tasks from the database and convert them
 //! to tree structures for the navigation panel.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
 
-use vertebrae_db::{Database, Level, TaskFilter, TaskSummary};
+use serde::Serialize;
+use vertebrae_db::{Database, Level, Status, Task, TaskFilter, TaskSummary};
 
-use crate::details::{TaskDetails, TaskRelationships};
+use crate::board::BoardCard;
+use crate::details::{BlockerSummary, TaskDetails, TaskRelationships};
 use crate::error::TuiResult;
 use crate::navigation::TreeNode;
-use crate::timeline::{DependencyEdge, TimelineTask, compute_dependency_groups};
+use crate::timeline::{
+    DependencyEdge, RollupParent, TimelineTask, build_rollup_tasks, compute_dependency_groups,
+    merge_rollup_tasks,
+};
 
 // Re-export needed for TimelineTaskRow deserialization
 use surrealdb;
@@ -137,29 +143,7 @@ async fn build_tree_node_with_progress(
 /// a node is expanded.
 pub async fn load_root_epics_lazy(db: &Database) -> TuiResult<Vec<TreeNode>> {
     let roots = load_root_tasks(db).await?;
-    let graph = db.graph();
-
-    let mut nodes = Vec::with_capacity(roots.len());
-
-    for root in &roots {
-        let mut node = task_to_node(root);
-
-        // Check if this task has children
-        let children = load_children(db, &root.id).await?;
-        if !children.is_empty() {
-            // Add placeholder children so has_children() returns true
-            // These will be replaced when the node is expanded
-            node = node.with_children(children.iter().map(task_to_node).collect::<Vec<_>>());
-
-            // Load progress for nodes with children
-            let progress = graph.get_progress(&root.id).await?;
-            node = node.with_progress(progress);
-        }
-
-        nodes.push(node);
-    }
-
-    Ok(nodes)
+    lazy_nodes(db, &roots).await
 }
 
 /// Refresh children of a specific node in the tree.
@@ -168,20 +152,26 @@ pub async fn load_root_epics_lazy(db: &Database) -> TuiResult<Vec<TreeNode>> {
 /// its children from the database.
 pub async fn load_node_children(db: &Database, node_id: &str) -> TuiResult<Vec<TreeNode>> {
     let children = load_children(db, node_id).await?;
-    let graph = db.graph();
+    lazy_nodes(db, &children).await
+}
 
-    let mut nodes = Vec::with_capacity(children.len());
+/// Build nodes for `tasks` whose own children are placeholders.
+///
+/// Placeholder children make `has_children()` true so the node can be
+/// expanded; they are replaced by [`load_node_children`] when it is.
+async fn lazy_nodes(db: &Database, tasks: &[TaskSummary]) -> TuiResult<Vec<TreeNode>> {
+    let graph = db.graph();
+    let mut nodes = Vec::with_capacity(tasks.len());
 
-    for child in &children {
-        let mut node = task_to_node(child);
+    for task in tasks {
+        let mut node = task_to_node(task);
 
-        // Check if this child has its own children
-        let grandchildren = load_children(db, &child.id).await?;
-        if !grandchildren.is_empty() {
-            node = node.with_children(grandchildren.iter().map(task_to_node).collect::<Vec<_>>());
+        let children = load_children(db, &task.id).await?;
+        if !children.is_empty() {
+            node = node.with_children(children.iter().map(task_to_node).collect::<Vec<_>>());
 
             // Load progress for nodes with children
-            let progress = graph.get_progress(&child.id).await?;
+            let progress = graph.get_progress(&task.id).await?;
             node = node.with_progress(progress);
         }
 
@@ -191,6 +181,54 @@ pub async fn load_node_children(db: &Database, node_id: &str) -> TuiResult<Vec<T
     Ok(nodes)
 }
 
+/// Number of tasks above which the navigation tree is loaded lazily
+pub const DEFAULT_TREE_LOAD_LIMIT: usize = 5_000;
+
+/// The navigation tree and how it was loaded
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoadedTree {
+    /// Root nodes of the tree
+    pub roots: Vec<TreeNode>,
+    /// Whether the tree was too large to load eagerly, so nodes below the
+    /// roots are loaded when expanded
+    pub lazy: bool,
+}
+
+/// Load the navigation tree, eagerly if it has at most `limit` tasks.
+///
+/// Larger trees load only their root tasks (of any level); use
+/// [`load_node_children`] to fill in a node when it is expanded.
+pub async fn load_tree(db: &Database, limit: usize) -> TuiResult<LoadedTree> {
+    if count_tasks(db).await? <= limit {
+        return Ok(LoadedTree {
+            roots: load_full_tree(db).await?,
+            lazy: false,
+        });
+    }
+
+    let filter = TaskFilter::new().root_only().include_done();
+    let roots = db.list_tasks().list(&filter).await?;
+    Ok(LoadedTree {
+        roots: lazy_nodes(db, &roots).await?,
+        lazy: true,
+    })
+}
+
+/// Result row for the task count query.
+#[derive(Debug, serde::Deserialize)]
+struct CountRow {
+    count: usize,
+}
+
+/// Count every task that is not in the trash.
+async fn count_tasks(db: &Database) -> TuiResult<usize> {
+    let mut result = db
+        .query("SELECT count() AS count FROM task WHERE deleted_at = NONE GROUP ALL")
+        .await?;
+    let rows: Vec<CountRow> = result.take(0)?;
+    Ok(rows.first().map(|r| r.count).unwrap_or(0))
+}
+
 /// Load full task details including relationships for the details view.
 ///
 /// This function fetches the complete task data along with parent and
@@ -268,15 +306,51 @@ pub async fn load_task_details(db: &Database, task_id: &str) -> TuiResult<Option
         id: task_id.to_string(),
         relationships,
         progress,
+        blockers: None,
     }))
 }
 
+/// Load the blocker statuses and transitive dependent count for a task.
+///
+/// Blockers come from the blocker tree at depth 1; dependents are counted
+/// by walking `depends_on` edges backwards until no new task turns up.
+pub async fn load_blocker_summary(db: &Database, task_id: &str) -> TuiResult<BlockerSummary> {
+    let blocked_by = db
+        .graph()
+        .get_blockers(task_id, Some(1))
+        .await?
+        .into_iter()
+        .map(|node| {
+            let status = Status::parse(&node.status).unwrap_or(Status::Todo);
+            (node.id, node.title, status)
+        })
+        .collect();
+
+    let rel_repo = db.relationships();
+    let mut seen = HashSet::from([task_id.to_string()]);
+    let mut frontier = vec![task_id.to_string()];
+    while let Some(id) = frontier.pop() {
+        for dependent in rel_repo.get_dependents(&id).await? {
+            if seen.insert(dependent.clone()) {
+                frontier.push(dependent);
+            }
+        }
+    }
+
+    Ok(BlockerSummary {
+        blocked_by,
+        dependents: seen.len() - 1,
+    })
+}
+
 /// Row type for timeline task query
 #[derive(Debug, serde::Deserialize)]
 struct TimelineTaskRow {
     id: surrealdb::sql::Thing,
     title: String,
     status: vertebrae_db::Status,
+    #[serde(default)]
+    tags: Option<Vec<String>>,
     started_at: chrono::DateTime<chrono::Utc>,
     completed_at: Option<chrono::DateTime<chrono::Utc>>,
 }
@@ -288,11 +362,20 @@ struct DependencyEdgeRow {
     out_id: surrealdb::sql::Thing,
 }
 
+/// Row type for epic query used to build rollup bars
+#[derive(Debug, serde::Deserialize)]
+struct EpicRow {
+    id: surrealdb::sql::Thing,
+    title: String,
+    status: vertebrae_db::Status,
+}
+
 /// Load all tasks that have been started for the timeline view.
 ///
 /// Only returns tasks where `started_at` is set. Tasks are sorted by
 /// their start time (oldest first). Tasks in the same dependency chain
-/// are assigned the same color group.
+/// are assigned the same color group. Epics with started descendants
+/// get an additional rollup bar spanning those descendants.
 ///
 /// # Arguments
 ///
@@ -307,8 +390,8 @@ pub async fn load_timeline_tasks(db: &Database) -> TuiResult<Vec<TimelineTask>>
     // Query tasks with started_at directly from the database
     // The TaskRepository.get() doesn't include started_at/completed_at
     // Note: In SurrealDB, we need to check both that the field exists AND is not None
-    let query = "SELECT id, title, status, started_at, completed_at \
-                 FROM task WHERE started_at != NONE";
+    let query = "SELECT id, title, status, tags, started_at, completed_at \
+                 FROM task WHERE started_at != NONE AND deleted_at = NONE";
 
     let mut result = db.query(query).await?;
     let rows: Vec<TimelineTaskRow> = result.take(0)?;
@@ -359,13 +442,124 @@ pub async fn load_timeline_tasks(db: &Database) -> TuiResult<Vec<TimelineTask>>
             completed_at: row.completed_at,
             has_dependencies,
             dependency_group,
+            is_rollup: false,
+            depends_on: dependencies,
+            tags: row.tags.unwrap_or_default(),
         });
     }
 
     // Sort by start time (oldest first)
     timeline_tasks.sort_by_key(|t| t.started_at);
 
-    Ok(timeline_tasks)
+    let parents = load_rollup_parents(db).await?;
+    let rollups = build_rollup_tasks(&timeline_tasks, &parents);
+
+    Ok(merge_rollup_tasks(timeline_tasks, rollups))
+}
+
+/// Load every task as a card for the board view.
+///
+/// Tasks come in listing order, so each column keeps that order. Rejected
+/// tasks are included here and dropped by the board, which has no column
+/// for them.
+pub async fn load_board_cards(db: &Database) -> TuiResult<Vec<BoardCard>> {
+    let tasks = db
+        .list_tasks()
+        .list(&TaskFilter::new().include_done())
+        .await?;
+    Ok(tasks
+        .into_iter()
+        .map(|task| BoardCard {
+            id: task.id,
+            title: task.title,
+            status: task.status,
+        })
+        .collect())
+}
+
+/// Load epics along with the IDs of all their descendants.
+///
+/// Uses a single query for the `child_of` edges and walks them in memory.
+async fn load_rollup_parents(db: &Database) -> TuiResult<Vec<RollupParent>> {
+    let mut result = db
+        .query("SELECT id, title, status FROM task WHERE level = 'epic' AND deleted_at = NONE")
+        .await?;
+    let epics: Vec<EpicRow> = result.take(0)?;
+
+    if epics.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    // child_of edges go: child -> child_of -> parent
+    let mut edge_result = db
+        .query("SELECT in.id AS in_id, out.id AS out_id FROM child_of")
+        .await?;
+    let edge_rows: Vec<DependencyEdgeRow> = edge_result.take(0)?;
+
+    let mut children_of: HashMap<String, Vec<String>> = HashMap::new();
+    for edge in edge_rows {
+        children_of
+            .entry(edge.out_id.id.to_string())
+            .or_default()
+            .push(edge.in_id.id.to_string());
+    }
+
+    let parents = epics
+        .into_iter()
+        .map(|epic| {
+            let id = epic.id.id.to_string();
+
+            let mut child_ids = Vec::new();
+            let mut seen = std::collections::HashSet::new();
+            let mut stack = vec![id.clone()];
+            while let Some(current) = stack.pop() {
+                for child in children_of.get(&current).into_iter().flatten() {
+                    if seen.insert(child.clone()) {
+                        child_ids.push(child.clone());
+                        stack.push(child.clone());
+                    }
+                }
+            }
+
+            RollupParent {
+                id,
+                title: epic.title,
+                status: epic.status,
+                child_ids,
+            }
+        })
+        .collect();
+
+    Ok(parents)
+}
+
+/// A task as written by [`export_task`], with its ID alongside the fields.
+#[derive(Serialize)]
+struct ExportedTask<'a> {
+    id: &'a str,
+    #[serde(flatten)]
+    task: &'a Task,
+}
+
+/// Write task `id` as pretty-printed JSON to `<dir>/<id>.json`.
+///
+/// The directory is created if needed. Returns the path written.
+///
+/// # Errors
+///
+/// Returns `TuiError::Database` if the task doesn't exist or can't be read,
+/// or `TuiError::Terminal` if the file can't be written.
+pub async fn export_task(db: &Database, id: &str, dir: &Path) -> TuiResult<PathBuf> {
+    let mut task = db.tasks().get_or_err(id).await?;
+    // Written as the plain ID below rather than a record reference
+    task.id = None;
+    let json = serde_json::to_string_pretty(&ExportedTask { id, task: &task })
+        .map_err(std::io::Error::other)?;
+
+    std::fs::create_dir_all(dir)?;
+    let path = dir.join(format!("{}.json", id));
+    std::fs::write(&path, json + "\n")?;
+    Ok(path)
 }
 
 #[cfg(test)]
@@ -493,6 +687,8 @@ mod tests {
             priority: None,
             tags: vec![],
             needs_human_review: None,
+            created_at: None,
+            updated_at: None,
         };
 
         let node = task_to_node(&summary);
@@ -519,6 +715,73 @@ mod tests {
         cleanup(&temp_dir);
     }
 
+    #[tokio::test]
+    async fn test_load_tree_within_limit_is_eager() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        create_task_with_parent(&db, "epic1", "Epic 1", Level::Epic, None).await;
+        create_task_with_parent(&db, "ticket1", "Ticket 1", Level::Ticket, Some("epic1")).await;
+        create_task_with_parent(&db, "task1", "Task 1", Level::Task, Some("ticket1")).await;
+
+        let tree = load_tree(&db, 3).await.unwrap();
+        assert!(!tree.lazy);
+        assert_eq!(tree.roots, load_full_tree(&db).await.unwrap());
+        assert_eq!(tree.roots[0].children[0].children[0].id, "task1");
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_load_tree_limit_ignores_trashed_tasks() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        create_task_with_parent(&db, "epic1", "Epic 1", Level::Epic, None).await;
+        create_task_with_parent(&db, "ticket1", "Ticket 1", Level::Ticket, Some("epic1")).await;
+        create_task_with_parent(&db, "task1", "Task 1", Level::Task, Some("ticket1")).await;
+        create_task_with_parent(&db, "gone", "Trashed", Level::Ticket, None).await;
+        db.query("UPDATE task:gone SET deleted_at = time::now()")
+            .await
+            .unwrap();
+
+        let tree = load_tree(&db, 3).await.unwrap();
+        assert!(
+            !tree.lazy,
+            "Trashed tasks should not count toward the limit"
+        );
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_load_tree_past_limit_switches_to_lazy() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        create_task_with_parent(&db, "epic1", "Epic 1", Level::Epic, None).await;
+        create_task_with_parent(&db, "ticket1", "Ticket 1", Level::Ticket, Some("epic1")).await;
+        create_task_with_parent(&db, "task1", "Task 1", Level::Task, Some("ticket1")).await;
+        create_task_with_parent(&db, "loose", "Loose ticket", Level::Ticket, None).await;
+
+        let tree = load_tree(&db, 3).await.unwrap();
+        assert!(tree.lazy);
+
+        // Every parentless task is a root, not just epics
+        let mut root_ids: Vec<&str> = tree.roots.iter().map(|n| n.id.as_str()).collect();
+        root_ids.sort();
+        assert_eq!(root_ids, vec!["epic1", "loose"]);
+
+        // Only one level below the roots is loaded, as placeholders
+        let epic = tree.roots.iter().find(|n| n.id == "epic1").unwrap();
+        assert_eq!(epic.children.len(), 1);
+        assert!(!epic.children[0].has_children());
+
+        // Expanding a placeholder loads its children
+        let children = load_node_children(&db, "epic1").await.unwrap();
+        assert_eq!(children[0].id, "ticket1");
+        assert_eq!(children[0].children[0].id, "task1");
+
+        cleanup(&temp_dir);
+    }
+
     #[tokio::test]
     async fn test_tree_node_with_3_epics_shows_exactly_3() {
         let (db, temp_dir) = setup_test_db().await;
@@ -657,6 +920,66 @@ mod tests {
         cleanup(&temp_dir);
     }
 
+    #[tokio::test]
+    async fn test_load_blocker_summary_counts_transitive_dependents() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        for id in ["blocker", "task1", "dep1", "dep2", "dep3"] {
+            create_task_with_parent(&db, id, id, Level::Task, None).await;
+        }
+        db.tasks()
+            .update_status_unchecked("blocker", Status::InProgress)
+            .await
+            .unwrap();
+        // dep3 reaches task1 along two paths but counts once
+        let rel_repo = db.relationships();
+        for (task, blocker) in [
+            ("task1", "blocker"),
+            ("dep1", "task1"),
+            ("dep2", "dep1"),
+            ("dep3", "dep2"),
+            ("dep3", "task1"),
+        ] {
+            rel_repo.create_depends_on(task, blocker).await.unwrap();
+        }
+
+        let summary = load_blocker_summary(&db, "task1").await.unwrap();
+        assert_eq!(
+            summary.blocked_by,
+            vec![(
+                "blocker".to_string(),
+                "blocker".to_string(),
+                Status::InProgress
+            )]
+        );
+        assert_eq!(summary.dependents, 3);
+
+        let summary = load_blocker_summary(&db, "dep3").await.unwrap();
+        assert_eq!(summary.blocked_by.len(), 2);
+        assert_eq!(summary.dependents, 0);
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_export_task_writes_json_file() {
+        let (db, temp_dir) = setup_test_db().await;
+        create_task_with_parent(&db, "task1", "Exported", Level::Task, None).await;
+
+        let dir = temp_dir.join("exports");
+        let path = export_task(&db, "task1", &dir).await.unwrap();
+        assert_eq!(path, dir.join("task1.json"));
+
+        let json: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(json["id"], "task1");
+        assert_eq!(json["title"], "Exported");
+
+        assert!(export_task(&db, "gone", &dir).await.is_err());
+
+        cleanup(&temp_dir);
+    }
+
     #[tokio::test]
     async fn test_load_timeline_tasks_empty_db() {
         let (db, temp_dir) = setup_test_db().await;
@@ -773,7 +1096,8 @@ mod tests {
 
         // Set timestamps using raw SQL
         let query1 = "UPDATE task:blocker SET started_at = time::now() - 2h, status = 'done'";
-        let query2 = "UPDATE task:dependent SET started_at = time::now(), status = 'in_progress'";
+        let query2 = "UPDATE task:dependent SET started_at = time::now(), status = 'in_progress', \
+                      tags = ['api']";
         db.query(query1).await.unwrap();
         db.query(query2).await.unwrap();
 
@@ -792,6 +1116,8 @@ mod tests {
             dependent_task.has_dependencies,
             "Dependent task should have has_dependencies=true"
         );
+        assert_eq!(dependent_task.depends_on, vec!["blocker"]);
+        assert_eq!(dependent_task.tags, vec!["api"]);
 
         // Blocker task should not have dependencies
         let blocker_task = timeline_tasks.iter().find(|t| t.id == "blocker").unwrap();
@@ -909,4 +1235,54 @@ mod tests {
 
         cleanup(&temp_dir);
     }
+
+    #[tokio::test]
+    async fn test_load_timeline_tasks_skips_trashed_tasks() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        create_task_with_parent(&db, "epic1", "Trashed Epic", Level::Epic, None).await;
+        create_task_with_parent(&db, "task1", "Live Task", Level::Task, Some("epic1")).await;
+        create_task_with_parent(&db, "task2", "Trashed Task", Level::Task, None).await;
+        set_started_at(&db, "task1").await;
+        set_started_at(&db, "task2").await;
+        db.query("UPDATE task:epic1, task:task2 SET deleted_at = time::now()")
+            .await
+            .unwrap();
+
+        // Neither the trashed task nor a rollup for the trashed epic shows up
+        let timeline_tasks = load_timeline_tasks(&db).await.unwrap();
+        let ids: Vec<&str> = timeline_tasks.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(ids, vec!["task1"]);
+
+        cleanup(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_load_timeline_tasks_adds_epic_rollup() {
+        let (db, temp_dir) = setup_test_db().await;
+
+        create_task_with_parent(&db, "epic1", "Rollup Epic", Level::Epic, None).await;
+        create_task_with_parent(&db, "ticket1", "Ticket", Level::Ticket, Some("epic1")).await;
+        create_task_with_parent(&db, "task1", "Nested Task", Level::Task, Some("ticket1")).await;
+        create_task_with_parent(&db, "epic2", "Unstarted Epic", Level::Epic, None).await;
+
+        db.query("UPDATE task:task1 SET started_at = time::now() - 3h, completed_at = time::now() - 1h, status = 'done'")
+            .await
+            .unwrap();
+
+        let timeline_tasks = load_timeline_tasks(&db).await.unwrap();
+        assert_eq!(timeline_tasks.len(), 2);
+
+        let rollup = &timeline_tasks[0];
+        assert!(rollup.is_rollup);
+        assert_eq!(rollup.id, "epic1");
+        assert_eq!(rollup.started_at, timeline_tasks[1].started_at);
+        assert_eq!(rollup.completed_at, timeline_tasks[1].completed_at);
+        assert!(
+            !timeline_tasks.iter().any(|t| t.id == "epic2"),
+            "Epic without started children should have no rollup"
+        );
+
+        cleanup(&temp_dir);
+    }
 }
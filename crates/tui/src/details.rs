@@ -11,7 +11,13 @@ use ratatui::{
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph, Wrap},
 };
-use vertebrae_db::{CodeRef, Level, Priority, Progress, Section, SectionType, Status, Task};
+use vertebrae_db::{
+    CodeRef, IdFormat, IdFormatter, Level, Priority, Progress, Section, SectionType, Status, Task,
+    id_format,
+};
+
+use crate::navigation::{status_color, status_indicator};
+use crate::theme::Theme;
 
 /// Additional relationship data for a task
 #[derive(Debug, Clone, Default)]
@@ -35,6 +41,21 @@ pub struct TaskDetails {
     pub relationships: TaskRelationships,
     /// Progress information for tasks with children (optional)
     pub progress: Option<Progress>,
+    /// Blocker statuses and dependent count, loaded once the details
+    /// panel is focused
+    pub blockers: Option<BlockerSummary>,
+}
+
+/// Blocker statuses and the reach of a task's dependents.
+///
+/// This takes a graph walk to build, so it is only loaded for the task
+/// shown in a focused details panel.
+#[derive(Debug, Clone, Default)]
+pub struct BlockerSummary {
+    /// Direct blockers: (id, title, status)
+    pub blocked_by: Vec<(String, String, Status)>,
+    /// Number of tasks depending on this one, directly or transitively
+    pub dependents: usize,
 }
 
 /// Render the details view for a task.
@@ -48,18 +69,18 @@ pub struct TaskDetails {
 /// * `task` - The task details to display, or None
 /// * `is_focused` - Whether this panel currently has focus
 /// * `scroll_offset` - Vertical scroll offset for the content
+/// * `selected_blocker` - Index of the highlighted blocker, if any
+/// * `theme` - Color overrides
 pub fn render_details_view(
     frame: &mut Frame,
     area: Rect,
     task: Option<&TaskDetails>,
     is_focused: bool,
     scroll_offset: usize,
+    selected_blocker: Option<usize>,
+    theme: &Theme,
 ) {
-    let border_color = if is_focused {
-        Color::Yellow
-    } else {
-        Color::Cyan
-    };
+    let border_color = theme.border(is_focused);
 
     let block = Block::default()
         .title(" Details ")
@@ -68,11 +89,22 @@ pub fn render_details_view(
 
     match task {
         Some(details) => {
-            let lines = build_details_lines(details);
+            // Clamp the offset and mark content hidden above or below
+            let max_scroll = max_details_scroll(details, area);
+            let offset = scroll_offset.min(max_scroll);
+            let mut block = block;
+            if offset > 0 {
+                block = block.title_top(Line::from(" \u{25B2} ").right_aligned());
+            }
+            if offset < max_scroll {
+                block = block.title_bottom(Line::from(" \u{25BC} ").right_aligned());
+            }
+
+            let lines = build_details_lines(details, selected_blocker, theme);
             let paragraph = Paragraph::new(lines)
                 .block(block)
                 .wrap(Wrap { trim: false })
-                .scroll((scroll_offset as u16, 0));
+                .scroll((offset as u16, 0));
             frame.render_widget(paragraph, area);
         }
         None => {
@@ -84,13 +116,28 @@ pub fn render_details_view(
     }
 }
 
+/// Largest scroll offset that still fills the panel, for details drawn in
+/// `area` (border included).
+pub fn max_details_scroll(details: &TaskDetails, area: Rect) -> usize {
+    let inner = Block::default().borders(Borders::ALL).inner(area);
+    let height = Paragraph::new(build_details_lines(details, None, &Theme::default()))
+        .wrap(Wrap { trim: false })
+        .line_count(inner.width);
+    height.saturating_sub(inner.height as usize)
+}
+
 /// Build all the lines for the details view.
-fn build_details_lines(details: &TaskDetails) -> Vec<Line<'static>> {
+fn build_details_lines(
+    details: &TaskDetails,
+    selected_blocker: Option<usize>,
+    theme: &Theme,
+) -> Vec<Line<'static>> {
     let mut lines = Vec::new();
     let task = &details.task;
+    let ids = details_id_formatter(details, id_format());
 
     // Header section
-    lines.extend(build_header_section(details));
+    lines.extend(build_header_section(details, &ids, theme));
     lines.push(Line::from(""));
 
     // Progress section (if task has children)
@@ -100,7 +147,7 @@ fn build_details_lines(details: &TaskDetails) -> Vec<Line<'static>> {
     }
 
     // Metadata section
-    lines.extend(build_metadata_section(task));
+    lines.extend(build_metadata_section(task, theme));
     lines.push(Line::from(""));
 
     // Tags section (if any)
@@ -122,7 +169,13 @@ fn build_details_lines(details: &TaskDetails) -> Vec<Line<'static>> {
     }
 
     // Relationships section
-    lines.extend(build_relationships_section(&details.relationships));
+    lines.extend(build_relationships_section(
+        &details.relationships,
+        details.blockers.as_ref(),
+        selected_blocker,
+        &ids,
+        theme,
+    ));
     lines.push(Line::from(""));
 
     // Timestamps section
@@ -131,15 +184,31 @@ fn build_details_lines(details: &TaskDetails) -> Vec<Line<'static>> {
     lines
 }
 
+/// Build a formatter that keeps short IDs unique across the whole view.
+fn details_id_formatter(details: &TaskDetails, mode: IdFormat) -> IdFormatter {
+    let rel = &details.relationships;
+    let related = rel
+        .parent
+        .iter()
+        .chain(&rel.blocked_by)
+        .chain(&rel.blocks)
+        .map(|(id, _)| id.as_str());
+    IdFormatter::for_ids(mode, std::iter::once(details.id.as_str()).chain(related))
+}
+
 /// Build the header section with ID, title, and badges.
-fn build_header_section(details: &TaskDetails) -> Vec<Line<'static>> {
+fn build_header_section(
+    details: &TaskDetails,
+    ids: &IdFormatter,
+    theme: &Theme,
+) -> Vec<Line<'static>> {
     let task = &details.task;
 
     // ID line
     let id_line = Line::from(vec![
         Span::styled("ID: ", Style::default().fg(Color::DarkGray)),
         Span::styled(
-            details.id.clone(),
+            ids.format(&details.id).to_string(),
             Style::default()
                 .fg(Color::Yellow)
                 .add_modifier(Modifier::BOLD),
@@ -167,17 +236,10 @@ fn build_header_section(details: &TaskDetails) -> Vec<Line<'static>> {
             .add_modifier(Modifier::BOLD),
     };
 
-    let status_style = match task.status {
-        Status::Todo => Style::default().fg(Color::White),
-        Status::InProgress => Style::default().fg(Color::Yellow),
-        Status::PendingReview => Style::default().fg(Color::Cyan),
-        Status::Done => Style::default().fg(Color::Green),
-        Status::Backlog => Style::default().fg(Color::DarkGray),
-        Status::Rejected => Style::default().fg(Color::Red),
-    };
+    let status_style = Style::default().fg(theme.status(&task.status, status_color(&task.status)));
 
     let badges_line = Line::from(vec![
-        Span::styled(format!("[{}]", task.level), level_style),
+        Span::styled(format!("[{}]", task.level.label()), level_style),
         Span::raw(" "),
         Span::styled(format!("[{}]", task.status), status_style),
     ]);
@@ -231,14 +293,14 @@ fn build_progress_section(progress: &Progress) -> Vec<Line<'static>> {
 }
 
 /// Build the metadata section (priority).
-fn build_metadata_section(task: &Task) -> Vec<Line<'static>> {
+fn build_metadata_section(task: &Task, theme: &Theme) -> Vec<Line<'static>> {
     let mut lines = Vec::new();
 
     lines.push(section_header("Metadata"));
 
     // Priority
     let priority_value = match &task.priority {
-        Some(p) => format_priority(p),
+        Some(p) => format_priority(p, theme),
         None => Span::styled("-", Style::default().fg(Color::DarkGray)),
     };
 
@@ -251,14 +313,17 @@ fn build_metadata_section(task: &Task) -> Vec<Line<'static>> {
 }
 
 /// Format priority with appropriate color.
-fn format_priority(priority: &Priority) -> Span<'static> {
+fn format_priority(priority: &Priority, theme: &Theme) -> Span<'static> {
     let (text, color) = match priority {
         Priority::Low => ("low", Color::Gray),
         Priority::Medium => ("medium", Color::White),
         Priority::High => ("high", Color::Yellow),
         Priority::Critical => ("critical", Color::Red),
     };
-    Span::styled(text.to_string(), Style::default().fg(color))
+    Span::styled(
+        text.to_string(),
+        Style::default().fg(theme.priority(priority, color)),
+    )
 }
 
 /// Build the tags section.
@@ -444,7 +509,16 @@ fn build_code_refs_section(code_refs: &[CodeRef]) -> Vec<Line<'static>> {
 }
 
 /// Build the relationships section.
-fn build_relationships_section(relationships: &TaskRelationships) -> Vec<Line<'static>> {
+///
+/// Once the blocker summary is loaded, blockers show their status and the
+/// dependents line gives the transitive count.
+fn build_relationships_section(
+    relationships: &TaskRelationships,
+    blockers: Option<&BlockerSummary>,
+    selected_blocker: Option<usize>,
+    ids: &IdFormatter,
+    theme: &Theme,
+) -> Vec<Line<'static>> {
     let mut lines = Vec::new();
     lines.push(section_header("Relationships"));
 
@@ -453,7 +527,10 @@ fn build_relationships_section(relationships: &TaskRelationships) -> Vec<Line<'s
         Some((id, title)) => {
             lines.push(Line::from(vec![
                 Span::styled("  Parent: ", Style::default().fg(Color::DarkGray)),
-                Span::styled(id.clone(), Style::default().fg(Color::Yellow)),
+                Span::styled(
+                    ids.format(id).to_string(),
+                    Style::default().fg(Color::Yellow),
+                ),
                 Span::raw(" - "),
                 Span::styled(title.clone(), Style::default().fg(Color::White)),
             ]));
@@ -471,7 +548,27 @@ fn build_relationships_section(relationships: &TaskRelationships) -> Vec<Line<'s
         "  Blocked by:",
         Style::default().fg(Color::DarkGray),
     )]));
-    if relationships.blocked_by.is_empty() {
+    if let Some(summary) = blockers.filter(|summary| !summary.blocked_by.is_empty()) {
+        for (i, (id, title, status)) in summary.blocked_by.iter().enumerate() {
+            let mut line = Line::from(vec![
+                Span::raw("    "),
+                Span::styled(
+                    format!("{} ", status_indicator(status)),
+                    Style::default().fg(theme.status(status, status_color(status))),
+                ),
+                Span::styled(
+                    ids.format(id).to_string(),
+                    Style::default().fg(Color::Yellow),
+                ),
+                Span::raw(" - "),
+                Span::styled(title.clone(), Style::default().fg(Color::White)),
+            ]);
+            if selected_blocker == Some(i) {
+                line = line.style(Style::default().bg(Color::DarkGray));
+            }
+            lines.push(line);
+        }
+    } else if relationships.blocked_by.is_empty() {
         lines.push(Line::from(vec![
             Span::raw("    "),
             Span::styled("-", Style::default().fg(Color::DarkGray)),
@@ -480,7 +577,10 @@ fn build_relationships_section(relationships: &TaskRelationships) -> Vec<Line<'s
         for (id, title) in &relationships.blocked_by {
             lines.push(Line::from(vec![
                 Span::raw("    "),
-                Span::styled(id.clone(), Style::default().fg(Color::Yellow)),
+                Span::styled(
+                    ids.format(id).to_string(),
+                    Style::default().fg(Color::Yellow),
+                ),
                 Span::raw(" - "),
                 Span::styled(title.clone(), Style::default().fg(Color::White)),
             ]));
@@ -501,12 +601,23 @@ fn build_relationships_section(relationships: &TaskRelationships) -> Vec<Line<'s
         for (id, title) in &relationships.blocks {
             lines.push(Line::from(vec![
                 Span::raw("    "),
-                Span::styled(id.clone(), Style::default().fg(Color::Yellow)),
+                Span::styled(
+                    ids.format(id).to_string(),
+                    Style::default().fg(Color::Yellow),
+                ),
                 Span::raw(" - "),
                 Span::styled(title.clone(), Style::default().fg(Color::White)),
             ]));
         }
     }
+    if let Some(summary) = blockers
+        && summary.dependents > relationships.blocks.len()
+    {
+        lines.push(Line::from(Span::styled(
+            format!("    {} tasks blocked in total", summary.dependents),
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
 
     lines
 }
@@ -575,13 +686,13 @@ mod tests {
 
     #[test]
     fn test_format_priority_low() {
-        let span = format_priority(&Priority::Low);
+        let span = format_priority(&Priority::Low, &Theme::default());
         assert_eq!(span.content, "low");
     }
 
     #[test]
     fn test_format_priority_critical() {
-        let span = format_priority(&Priority::Critical);
+        let span = format_priority(&Priority::Critical, &Theme::default());
         assert_eq!(span.content, "critical");
     }
 
@@ -600,23 +711,89 @@ mod tests {
             id: "abc123".to_string(),
             relationships: TaskRelationships::default(),
             progress: None,
+            blockers: None,
         };
 
-        let lines = build_header_section(&details);
+        let ids = details_id_formatter(&details, IdFormat::Full);
+        let lines = build_header_section(&details, &ids, &Theme::default());
         assert_eq!(lines.len(), 3); // ID, title, badges
     }
 
+    #[test]
+    fn test_details_short_ids_stay_distinct() {
+        let details = TaskDetails {
+            task: Task::new("Test Task", Level::Task),
+            id: "abc123aaaa".to_string(),
+            relationships: TaskRelationships {
+                parent: Some(("fed987zzzz".to_string(), "Parent".to_string())),
+                blocked_by: vec![("abc123bbbb".to_string(), "Blocker".to_string())],
+                blocks: vec![],
+            },
+            progress: None,
+            blockers: None,
+        };
+
+        let ids = details_id_formatter(&details, IdFormat::Short6);
+        let header = build_header_section(&details, &ids, &Theme::default());
+        assert_eq!(header[0].spans[1].content, "abc123a");
+
+        let lines = build_relationships_section(
+            &details.relationships,
+            None,
+            None,
+            &ids,
+            &Theme::default(),
+        );
+        let text: Vec<String> = lines
+            .iter()
+            .map(|line| line.spans.iter().map(|s| s.content.as_ref()).collect())
+            .collect();
+        assert!(text.contains(&"  Parent: fed987z - Parent".to_string()));
+        assert!(text.contains(&"    abc123b - Blocker".to_string()));
+    }
+
+    #[test]
+    fn test_max_details_scroll_grows_with_content() {
+        let details = |task: Task| TaskDetails {
+            task,
+            id: "abc123".to_string(),
+            relationships: TaskRelationships::default(),
+            progress: None,
+            blockers: None,
+        };
+        let area = Rect::new(0, 0, 40, 12);
+
+        let short = details(Task::new("Short", Level::Task));
+        let long = details((0..30).fold(Task::new("Long", Level::Task), |task, i| {
+            task.with_section(Section::new(SectionType::Step, format!("Step {i}")))
+        }));
+        let short_max = max_details_scroll(&short, area);
+        let long_max = max_details_scroll(&long, area);
+        assert!(long_max >= short_max + 30);
+
+        // A taller panel needs less scrolling; a narrower one wraps more
+        assert!(max_details_scroll(&long, Rect::new(0, 0, 40, 30)) < long_max);
+        let wide = details(
+            Task::new("Wide", Level::Task)
+                .with_section(Section::new(SectionType::Step, "word ".repeat(40))),
+        );
+        assert!(
+            max_details_scroll(&wide, Rect::new(0, 0, 20, 12))
+                > max_details_scroll(&wide, Rect::new(0, 0, 80, 12))
+        );
+    }
+
     #[test]
     fn test_build_metadata_section() {
         let task = Task::new("Test", Level::Task).with_priority(Priority::High);
-        let lines = build_metadata_section(&task);
+        let lines = build_metadata_section(&task, &Theme::default());
         assert!(!lines.is_empty());
     }
 
     #[test]
     fn test_build_metadata_section_no_priority() {
         let task = Task::new("Test", Level::Task);
-        let lines = build_metadata_section(&task);
+        let lines = build_metadata_section(&task, &Theme::default());
         assert!(!lines.is_empty());
     }
 
@@ -641,10 +818,45 @@ mod tests {
     #[test]
     fn test_build_relationships_section_empty() {
         let relationships = TaskRelationships::default();
-        let lines = build_relationships_section(&relationships);
+        let lines = build_relationships_section(
+            &relationships,
+            None,
+            None,
+            &IdFormatter::default(),
+            &Theme::default(),
+        );
         assert!(!lines.is_empty());
     }
 
+    #[test]
+    fn test_relationships_show_blocker_status_and_dependents() {
+        let relationships = TaskRelationships {
+            parent: None,
+            blocked_by: vec![("b1".to_string(), "Blocker".to_string())],
+            blocks: vec![("d1".to_string(), "Dependent".to_string())],
+        };
+        let summary = BlockerSummary {
+            blocked_by: vec![("b1".to_string(), "Blocker".to_string(), Status::InProgress)],
+            dependents: 4,
+        };
+        let lines = build_relationships_section(
+            &relationships,
+            Some(&summary),
+            Some(0),
+            &IdFormatter::default(),
+            &Theme::default(),
+        );
+        let text: Vec<String> = lines
+            .iter()
+            .map(|line| line.spans.iter().map(|s| s.content.as_ref()).collect())
+            .collect();
+        assert!(text.contains(&"    [>] b1 - Blocker".to_string()));
+        assert!(text.contains(&"    4 tasks blocked in total".to_string()));
+
+        let blocker = lines.iter().position(|line| line.spans.len() == 5).unwrap();
+        assert_eq!(lines[blocker].style.bg, Some(Color::DarkGray));
+    }
+
     #[test]
     fn test_build_relationships_section_with_data() {
         let relationships = TaskRelationships {
@@ -655,7 +867,13 @@ mod tests {
                 ("dep2".to_string(), "Dependent 2".to_string()),
             ],
         };
-        let lines = build_relationships_section(&relationships);
+        let lines = build_relationships_section(
+            &relationships,
+            None,
+            None,
+            &IdFormatter::default(),
+            &Theme::default(),
+        );
         assert!(!lines.is_empty());
     }
 
@@ -698,9 +916,10 @@ mod tests {
             id: "test123".to_string(),
             relationships: TaskRelationships::default(),
             progress: None,
+            blockers: None,
         };
 
-        let lines = build_details_lines(&details);
+        let lines = build_details_lines(&details, None, &Theme::default());
         assert!(!lines.is_empty());
     }
 
@@ -742,9 +961,10 @@ mod tests {
             id: "epic123".to_string(),
             relationships: TaskRelationships::default(),
             progress: Some(Progress::new(2, 4)),
+            blockers: None,
         };
 
-        let lines = build_details_lines(&details);
+        let lines = build_details_lines(&details, None, &Theme::default());
 
         // Should include progress section
         let all_text: String = lines
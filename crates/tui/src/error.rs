@@ -1,6 +1,7 @@
 //! Error types for the TUI module.
 
 use std::io;
+use std::path::PathBuf;
 use thiserror::Error;
 
 /// Result type for TUI operations.
@@ -20,6 +21,10 @@ pub enum TuiError {
     /// SurrealDB error (for direct queries).
     #[error("SurrealDB error: {0}")]
     Surreal(Box<surrealdb::Error>),
+
+    /// The theme file couldn't be read or parsed.
+    #[error("Invalid theme file {path}: {reason}")]
+    InvalidTheme { path: PathBuf, reason: String },
 }
 
 impl From<surrealdb::Error> for TuiError {
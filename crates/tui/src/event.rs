@@ -1,11 +1,48 @@
 //! Event handling for the TUI.
 //!
-//! Provides keyboard event polling and handling.
+//! Provides keyboard and mouse event polling and handling.
 
-use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers, MouseEvent};
 use std::time::Duration;
+use tokio::sync::mpsc;
+use vertebrae_db::Status;
 
 use crate::TuiResult;
+use crate::timeline::TimelineWindow;
+
+/// A change to the timeline zoom requested from the keyboard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZoomChange {
+    /// Show a finer scale.
+    In,
+    /// Show a coarser scale.
+    Out,
+    /// Pick the scale from the date span again.
+    Auto,
+}
+
+/// What key presses are routed to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AppMode {
+    /// Keys navigate and act on the views.
+    #[default]
+    Normal,
+    /// Keys go to the add-task form; navigation keys are suppressed.
+    AddTask,
+    /// Keys edit the navigation panel's title filter.
+    Filter,
+    /// The keybinding help is shown; keys only dismiss it.
+    Help,
+}
+
+/// A keyboard or mouse event read from the terminal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Input {
+    /// A key was pressed.
+    Key(KeyEvent),
+    /// The mouse was clicked, moved or scrolled.
+    Mouse(MouseEvent),
+}
 
 /// Poll for keyboard events with a timeout.
 ///
@@ -20,6 +57,54 @@ pub fn poll_key(timeout: Duration) -> TuiResult<Option<KeyEvent>> {
     Ok(None)
 }
 
+/// Poll for keyboard or mouse events with a timeout.
+///
+/// Returns `None` if nothing arrived within the timeout or the event was
+/// neither a key nor a mouse event (e.g. a resize).
+pub fn poll_input(timeout: Duration) -> TuiResult<Option<Input>> {
+    if !event::poll(timeout)? {
+        return Ok(None);
+    }
+    Ok(match event::read()? {
+        Event::Key(key) => Some(Input::Key(key)),
+        Event::Mouse(mouse) => Some(Input::Mouse(mouse)),
+        _ => None,
+    })
+}
+
+/// Spawn a background ticker that sends a refresh event every `period`.
+///
+/// The first event arrives after one full period. The ticker stops once the
+/// receiver is dropped.
+pub fn spawn_refresh_ticker(period: Duration) -> mpsc::Receiver<()> {
+    let (tx, rx) = mpsc::channel(1);
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(period);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        // The first tick completes immediately
+        interval.tick().await;
+        loop {
+            interval.tick().await;
+            // A full channel means a refresh is already pending
+            if let Err(mpsc::error::TrySendError::Closed(_)) = tx.try_send(()) {
+                break;
+            }
+        }
+    });
+    rx
+}
+
+/// Drain pending refresh events without blocking.
+///
+/// Returns `true` if at least one event was pending.
+pub fn drain_refresh_events(rx: &mut mpsc::Receiver<()>) -> bool {
+    let mut pending = false;
+    while rx.try_recv().is_ok() {
+        pending = true;
+    }
+    pending
+}
+
 /// Check if the key event represents a quit command.
 ///
 /// Returns `true` for 'q' key or Ctrl+C.
@@ -112,6 +197,66 @@ pub fn is_right(key: &KeyEvent) -> bool {
     )
 }
 
+/// Check if the key event highlights the next blocker in the details (`b`).
+pub fn is_next_blocker(key: &KeyEvent) -> bool {
+    matches!(
+        key,
+        KeyEvent {
+            code: KeyCode::Char('b'),
+            modifiers: KeyModifiers::NONE,
+            ..
+        }
+    )
+}
+
+/// Check if the key event jumps to the first item (`g`).
+pub fn is_top(key: &KeyEvent) -> bool {
+    matches!(
+        key,
+        KeyEvent {
+            code: KeyCode::Char('g'),
+            modifiers: KeyModifiers::NONE,
+            ..
+        }
+    )
+}
+
+/// Check if the key event jumps to the last item (`G`).
+pub fn is_bottom(key: &KeyEvent) -> bool {
+    matches!(
+        key,
+        KeyEvent {
+            code: KeyCode::Char('G'),
+            modifiers: KeyModifiers::NONE | KeyModifiers::SHIFT,
+            ..
+        }
+    )
+}
+
+/// Check if the key event moves half a page down (Ctrl-d).
+pub fn is_half_page_down(key: &KeyEvent) -> bool {
+    matches!(
+        key,
+        KeyEvent {
+            code: KeyCode::Char('d'),
+            modifiers: KeyModifiers::CONTROL,
+            ..
+        }
+    )
+}
+
+/// Check if the key event moves half a page up (Ctrl-u).
+pub fn is_half_page_up(key: &KeyEvent) -> bool {
+    matches!(
+        key,
+        KeyEvent {
+            code: KeyCode::Char('u'),
+            modifiers: KeyModifiers::CONTROL,
+            ..
+        }
+    )
+}
+
 /// Check if the key event is the h key (vim-style left).
 pub fn is_h(key: &KeyEvent) -> bool {
     matches!(
@@ -136,6 +281,275 @@ pub fn is_l(key: &KeyEvent) -> bool {
     )
 }
 
+/// Check if the key event toggles the timeline color key (c).
+pub fn is_legend_toggle(key: &KeyEvent) -> bool {
+    matches!(
+        key,
+        KeyEvent {
+            code: KeyCode::Char('c'),
+            modifiers: KeyModifiers::NONE,
+            ..
+        }
+    )
+}
+
+/// Check if the key event moves a board card to the previous status (`<`).
+///
+/// Shift is ignored since `<` is typed with it on most layouts.
+pub fn is_move_left(key: &KeyEvent) -> bool {
+    matches!(
+        key,
+        KeyEvent {
+            code: KeyCode::Char('<'),
+            ..
+        }
+    )
+}
+
+/// Check if the key event moves a board card to the next status (`>`).
+///
+/// Shift is ignored since `>` is typed with it on most layouts.
+pub fn is_move_right(key: &KeyEvent) -> bool {
+    matches!(
+        key,
+        KeyEvent {
+            code: KeyCode::Char('>'),
+            ..
+        }
+    )
+}
+
+/// The timeline date window selected by a digit key, if any.
+///
+/// `1`, `2` and `3` pick the last week, month and quarter; `0` shows
+/// everything again.
+pub fn timeline_window_key(key: &KeyEvent) -> Option<TimelineWindow> {
+    if key.modifiers != KeyModifiers::NONE {
+        return None;
+    }
+    match key.code {
+        KeyCode::Char('0') => Some(TimelineWindow::All),
+        KeyCode::Char('1') => Some(TimelineWindow::LastWeek),
+        KeyCode::Char('2') => Some(TimelineWindow::LastMonth),
+        KeyCode::Char('3') => Some(TimelineWindow::LastQuarter),
+        _ => None,
+    }
+}
+
+/// The zoom change a key asks the timeline for, if any.
+///
+/// `+` (or `=`, on the same key) zooms in, `-` zooms out and `a` returns
+/// to picking the zoom from the date span.
+pub fn timeline_zoom_key(key: &KeyEvent) -> Option<ZoomChange> {
+    if key.modifiers.contains(KeyModifiers::CONTROL) {
+        return None;
+    }
+    match key.code {
+        KeyCode::Char('+') | KeyCode::Char('=') => Some(ZoomChange::In),
+        KeyCode::Char('-') => Some(ZoomChange::Out),
+        KeyCode::Char('a') => Some(ZoomChange::Auto),
+        _ => None,
+    }
+}
+
+/// Check if the key event toggles timeline dependency arrows (`e`).
+pub fn is_arrows_toggle(key: &KeyEvent) -> bool {
+    matches!(
+        key,
+        KeyEvent {
+            code: KeyCode::Char('e'),
+            modifiers: KeyModifiers::NONE,
+            ..
+        }
+    )
+}
+
+/// Check if the key event cycles the timeline status filter (`f`).
+pub fn is_status_filter(key: &KeyEvent) -> bool {
+    matches!(
+        key,
+        KeyEvent {
+            code: KeyCode::Char('f'),
+            modifiers: KeyModifiers::NONE,
+            ..
+        }
+    )
+}
+
+/// Check if the key event cycles the timeline tag filter (`#`).
+///
+/// Shift is ignored since `#` needs it on most layouts.
+pub fn is_tag_filter(key: &KeyEvent) -> bool {
+    matches!(
+        key,
+        KeyEvent {
+            code: KeyCode::Char('#'),
+            modifiers: KeyModifiers::NONE | KeyModifiers::SHIFT,
+            ..
+        }
+    )
+}
+
+/// Check if the key event scrolls the timeline to today (`t`).
+pub fn is_today(key: &KeyEvent) -> bool {
+    matches!(
+        key,
+        KeyEvent {
+            code: KeyCode::Char('t'),
+            modifiers: KeyModifiers::NONE,
+            ..
+        }
+    )
+}
+
+/// The status a key moves the selected task to, if any.
+///
+/// `s` starts the task, `r` submits it for review and `d` marks it done.
+pub fn status_key(key: &KeyEvent) -> Option<Status> {
+    if key.modifiers != KeyModifiers::NONE {
+        return None;
+    }
+    match key.code {
+        KeyCode::Char('s') => Some(Status::InProgress),
+        KeyCode::Char('r') => Some(Status::PendingReview),
+        KeyCode::Char('d') => Some(Status::Done),
+        _ => None,
+    }
+}
+
+/// Check if the key event opens the command palette (`:`).
+///
+/// Shift is ignored since some terminals report it for `:`.
+pub fn is_palette(key: &KeyEvent) -> bool {
+    matches!(
+        key,
+        KeyEvent {
+            code: KeyCode::Char(':'),
+            ..
+        }
+    )
+}
+
+/// Check if the key event starts filtering the navigation panel (`/`).
+pub fn is_filter(key: &KeyEvent) -> bool {
+    matches!(
+        key,
+        KeyEvent {
+            code: KeyCode::Char('/'),
+            modifiers: KeyModifiers::NONE,
+            ..
+        }
+    )
+}
+
+/// Check if the key event expands every node in the tree (`E`).
+pub fn is_expand_all(key: &KeyEvent) -> bool {
+    matches!(
+        key,
+        KeyEvent {
+            code: KeyCode::Char('E'),
+            modifiers: KeyModifiers::NONE | KeyModifiers::SHIFT,
+            ..
+        }
+    )
+}
+
+/// Check if the key event collapses every node in the tree (`C`).
+pub fn is_collapse_all(key: &KeyEvent) -> bool {
+    matches!(
+        key,
+        KeyEvent {
+            code: KeyCode::Char('C'),
+            modifiers: KeyModifiers::NONE | KeyModifiers::SHIFT,
+            ..
+        }
+    )
+}
+
+/// Check if the key event toggles the keybinding help (`?`).
+///
+/// Shift is ignored since `?` needs it on most layouts.
+pub fn is_help(key: &KeyEvent) -> bool {
+    matches!(
+        key,
+        KeyEvent {
+            code: KeyCode::Char('?'),
+            modifiers: KeyModifiers::NONE | KeyModifiers::SHIFT,
+            ..
+        }
+    )
+}
+
+/// Check if the key event opens the add-task form (`a`).
+pub fn is_add_task(key: &KeyEvent) -> bool {
+    matches!(
+        key,
+        KeyEvent {
+            code: KeyCode::Char('a'),
+            modifiers: KeyModifiers::NONE,
+            ..
+        }
+    )
+}
+
+/// Check if the key event opens the edit form for the selected task (`e`).
+pub fn is_edit_task(key: &KeyEvent) -> bool {
+    matches!(
+        key,
+        KeyEvent {
+            code: KeyCode::Char('e'),
+            modifiers: KeyModifiers::NONE,
+            ..
+        }
+    )
+}
+
+/// Check if the key event exports the selected task (`x`).
+pub fn is_export(key: &KeyEvent) -> bool {
+    matches!(
+        key,
+        KeyEvent {
+            code: KeyCode::Char('x'),
+            modifiers: KeyModifiers::NONE,
+            ..
+        }
+    )
+}
+
+/// Check if the key event reloads tasks from the database (`R`).
+pub fn is_reload(key: &KeyEvent) -> bool {
+    matches!(
+        key,
+        KeyEvent {
+            code: KeyCode::Char('R'),
+            modifiers: KeyModifiers::NONE | KeyModifiers::SHIFT,
+            ..
+        }
+    )
+}
+
+/// Check if the key event is the Escape key.
+pub fn is_escape(key: &KeyEvent) -> bool {
+    matches!(
+        key,
+        KeyEvent {
+            code: KeyCode::Esc,
+            ..
+        }
+    )
+}
+
+/// Check if the key event is the Backspace key.
+pub fn is_backspace(key: &KeyEvent) -> bool {
+    matches!(
+        key,
+        KeyEvent {
+            code: KeyCode::Backspace,
+            ..
+        }
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -216,6 +630,50 @@ mod tests {
         assert!(is_right(&key));
     }
 
+    #[test]
+    fn test_is_next_blocker() {
+        assert!(is_next_blocker(&make_key(
+            KeyCode::Char('b'),
+            KeyModifiers::NONE
+        )));
+        assert!(!is_next_blocker(&make_key(
+            KeyCode::Char('B'),
+            KeyModifiers::SHIFT
+        )));
+    }
+
+    #[test]
+    fn test_top_and_bottom_keys() {
+        assert!(is_top(&make_key(KeyCode::Char('g'), KeyModifiers::NONE)));
+        assert!(is_bottom(&make_key(
+            KeyCode::Char('G'),
+            KeyModifiers::SHIFT
+        )));
+        assert!(is_bottom(&make_key(KeyCode::Char('G'), KeyModifiers::NONE)));
+        assert!(!is_top(&make_key(KeyCode::Char('G'), KeyModifiers::SHIFT)));
+    }
+
+    #[test]
+    fn test_half_page_keys() {
+        assert!(is_half_page_down(&make_key(
+            KeyCode::Char('d'),
+            KeyModifiers::CONTROL
+        )));
+        assert!(is_half_page_up(&make_key(
+            KeyCode::Char('u'),
+            KeyModifiers::CONTROL
+        )));
+        // Plain `d` stays the done shortcut
+        assert!(!is_half_page_down(&make_key(
+            KeyCode::Char('d'),
+            KeyModifiers::NONE
+        )));
+        assert_eq!(
+            status_key(&make_key(KeyCode::Char('d'), KeyModifiers::CONTROL)),
+            None
+        );
+    }
+
     #[test]
     fn test_is_h() {
         let key = make_key(KeyCode::Char('h'), KeyModifiers::NONE);
@@ -228,6 +686,191 @@ mod tests {
         assert!(is_l(&key));
     }
 
+    #[test]
+    fn test_is_arrows_toggle() {
+        assert!(is_arrows_toggle(&make_key(
+            KeyCode::Char('e'),
+            KeyModifiers::NONE
+        )));
+        assert!(!is_arrows_toggle(&make_key(
+            KeyCode::Char('E'),
+            KeyModifiers::SHIFT
+        )));
+    }
+
+    #[test]
+    fn test_timeline_filter_keys() {
+        assert!(is_status_filter(&make_key(
+            KeyCode::Char('f'),
+            KeyModifiers::NONE
+        )));
+        assert!(is_tag_filter(&make_key(
+            KeyCode::Char('#'),
+            KeyModifiers::SHIFT
+        )));
+        assert!(!is_tag_filter(&make_key(
+            KeyCode::Char('3'),
+            KeyModifiers::NONE
+        )));
+    }
+
+    #[test]
+    fn test_is_today() {
+        assert!(is_today(&make_key(KeyCode::Char('t'), KeyModifiers::NONE)));
+        assert!(!is_today(&make_key(
+            KeyCode::Char('T'),
+            KeyModifiers::SHIFT
+        )));
+    }
+
+    #[test]
+    fn test_timeline_zoom_key() {
+        assert_eq!(
+            timeline_zoom_key(&make_key(KeyCode::Char('+'), KeyModifiers::SHIFT)),
+            Some(ZoomChange::In)
+        );
+        assert_eq!(
+            timeline_zoom_key(&make_key(KeyCode::Char('-'), KeyModifiers::NONE)),
+            Some(ZoomChange::Out)
+        );
+        assert_eq!(
+            timeline_zoom_key(&make_key(KeyCode::Char('a'), KeyModifiers::NONE)),
+            Some(ZoomChange::Auto)
+        );
+        assert_eq!(
+            timeline_zoom_key(&make_key(KeyCode::Char('x'), KeyModifiers::NONE)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_is_expand_and_collapse_all() {
+        assert!(is_expand_all(&make_key(
+            KeyCode::Char('E'),
+            KeyModifiers::SHIFT
+        )));
+        assert!(is_collapse_all(&make_key(
+            KeyCode::Char('C'),
+            KeyModifiers::NONE
+        )));
+        // Lowercase c toggles the timeline legend instead
+        assert!(!is_collapse_all(&make_key(
+            KeyCode::Char('c'),
+            KeyModifiers::NONE
+        )));
+    }
+
+    #[test]
+    fn test_is_filter() {
+        assert!(is_filter(&make_key(KeyCode::Char('/'), KeyModifiers::NONE)));
+        assert!(!is_filter(&make_key(
+            KeyCode::Char('?'),
+            KeyModifiers::SHIFT
+        )));
+    }
+
+    #[test]
+    fn test_is_help() {
+        assert!(is_help(&make_key(KeyCode::Char('?'), KeyModifiers::SHIFT)));
+        assert!(!is_help(&make_key(KeyCode::Char('/'), KeyModifiers::NONE)));
+    }
+
+    #[test]
+    fn test_is_add_task() {
+        assert!(is_add_task(&make_key(
+            KeyCode::Char('a'),
+            KeyModifiers::NONE
+        )));
+        assert!(!is_add_task(&make_key(
+            KeyCode::Char('a'),
+            KeyModifiers::CONTROL
+        )));
+    }
+
+    #[test]
+    fn test_is_edit_task_and_export() {
+        assert!(is_edit_task(&make_key(
+            KeyCode::Char('e'),
+            KeyModifiers::NONE
+        )));
+        assert!(!is_edit_task(&make_key(
+            KeyCode::Char('e'),
+            KeyModifiers::CONTROL
+        )));
+        assert!(is_export(&make_key(KeyCode::Char('x'), KeyModifiers::NONE)));
+        assert!(!is_export(&make_key(
+            KeyCode::Char('x'),
+            KeyModifiers::CONTROL
+        )));
+        assert!(is_reload(&make_key(
+            KeyCode::Char('R'),
+            KeyModifiers::SHIFT
+        )));
+        assert!(!is_reload(&make_key(
+            KeyCode::Char('r'),
+            KeyModifiers::NONE
+        )));
+    }
+
+    #[test]
+    fn test_is_legend_toggle() {
+        assert!(is_legend_toggle(&make_key(
+            KeyCode::Char('c'),
+            KeyModifiers::NONE
+        )));
+        assert!(!is_legend_toggle(&make_key(
+            KeyCode::Char('c'),
+            KeyModifiers::CONTROL
+        )));
+    }
+
+    #[test]
+    fn test_is_move_keys() {
+        assert!(is_move_left(&make_key(
+            KeyCode::Char('<'),
+            KeyModifiers::SHIFT
+        )));
+        assert!(is_move_right(&make_key(
+            KeyCode::Char('>'),
+            KeyModifiers::NONE
+        )));
+        assert!(!is_move_left(&make_key(
+            KeyCode::Char('>'),
+            KeyModifiers::SHIFT
+        )));
+        assert!(!is_move_right(&make_key(
+            KeyCode::Char('l'),
+            KeyModifiers::NONE
+        )));
+    }
+
+    #[test]
+    fn test_timeline_window_key() {
+        let window = |c| timeline_window_key(&make_key(KeyCode::Char(c), KeyModifiers::NONE));
+        assert_eq!(window('0'), Some(TimelineWindow::All));
+        assert_eq!(window('1'), Some(TimelineWindow::LastWeek));
+        assert_eq!(window('2'), Some(TimelineWindow::LastMonth));
+        assert_eq!(window('3'), Some(TimelineWindow::LastQuarter));
+        assert_eq!(window('4'), None);
+        assert_eq!(
+            timeline_window_key(&make_key(KeyCode::Char('1'), KeyModifiers::CONTROL)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_status_key() {
+        let status = |c| status_key(&make_key(KeyCode::Char(c), KeyModifiers::NONE));
+        assert_eq!(status('s'), Some(Status::InProgress));
+        assert_eq!(status('r'), Some(Status::PendingReview));
+        assert_eq!(status('d'), Some(Status::Done));
+        assert_eq!(status('x'), None);
+        assert_eq!(
+            status_key(&make_key(KeyCode::Char('d'), KeyModifiers::CONTROL)),
+            None
+        );
+    }
+
     #[test]
     fn test_is_h_with_modifier_is_false() {
         let key = make_key(KeyCode::Char('h'), KeyModifiers::CONTROL);
@@ -239,4 +882,55 @@ mod tests {
         let key = make_key(KeyCode::Char('l'), KeyModifiers::CONTROL);
         assert!(!is_l(&key));
     }
+
+    #[test]
+    fn test_is_palette_colon() {
+        assert!(is_palette(&make_key(
+            KeyCode::Char(':'),
+            KeyModifiers::NONE
+        )));
+        assert!(is_palette(&make_key(
+            KeyCode::Char(':'),
+            KeyModifiers::SHIFT
+        )));
+        assert!(!is_palette(&make_key(
+            KeyCode::Char(';'),
+            KeyModifiers::NONE
+        )));
+    }
+
+    #[test]
+    fn test_is_escape() {
+        assert!(is_escape(&make_key(KeyCode::Esc, KeyModifiers::NONE)));
+        assert!(!is_escape(&make_key(
+            KeyCode::Char('q'),
+            KeyModifiers::NONE
+        )));
+    }
+
+    #[test]
+    fn test_is_backspace() {
+        assert!(is_backspace(&make_key(
+            KeyCode::Backspace,
+            KeyModifiers::NONE
+        )));
+    }
+
+    #[tokio::test]
+    async fn test_refresh_ticker_sends_events() {
+        let mut rx = spawn_refresh_ticker(Duration::from_millis(20));
+        assert!(!drain_refresh_events(&mut rx));
+
+        let event = tokio::time::timeout(Duration::from_secs(2), rx.recv()).await;
+        assert_eq!(event.unwrap(), Some(()));
+    }
+
+    #[test]
+    fn test_drain_refresh_events_collapses_pending() {
+        let (tx, mut rx) = mpsc::channel(4);
+        tx.try_send(()).unwrap();
+        tx.try_send(()).unwrap();
+        assert!(drain_refresh_events(&mut rx));
+        assert!(!drain_refresh_events(&mut rx));
+    }
 }
@@ -0,0 +1,122 @@
+//! Fuzzy matching for interactive filtering.
+//!
+//! Provides a small subsequence matcher: every query character must appear in
+//! the candidate in order, and matches are ranked so that consecutive runs and
+//! word-start hits score higher.
+
+/// Bonus for a match directly following the previous match.
+const CONSECUTIVE_BONUS: i64 = 8;
+
+/// Bonus for a match at the start of a word.
+const WORD_START_BONUS: i64 = 6;
+
+/// Penalty per skipped candidate character before the first match.
+const LEADING_GAP_PENALTY: i64 = 1;
+
+/// Score how well `query` fuzzy-matches `candidate` (case-insensitive).
+///
+/// Returns `None` if the query characters don't all appear in order.
+/// An empty query matches everything with a score of 0.
+pub fn fuzzy_score(query: &str, candidate: &str) -> Option<i64> {
+    let query: Vec<char> = query.to_lowercase().chars().collect();
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let candidate: Vec<char> = candidate.to_lowercase().chars().collect();
+    let mut score = 0;
+    let mut next = 0;
+    let mut last_match: Option<usize> = None;
+
+    for (i, &c) in candidate.iter().enumerate() {
+        if next == query.len() {
+            break;
+        }
+        if c != query[next] {
+            continue;
+        }
+
+        score += 1;
+        if last_match.is_some_and(|last| last + 1 == i) {
+            score += CONSECUTIVE_BONUS;
+        }
+        if i == 0 || !candidate[i - 1].is_alphanumeric() {
+            score += WORD_START_BONUS;
+        }
+        if last_match.is_none() {
+            score -= i as i64 * LEADING_GAP_PENALTY;
+        }
+
+        last_match = Some(i);
+        next += 1;
+    }
+
+    (next == query.len()).then_some(score)
+}
+
+/// Filter `items` by fuzzy-matching `query` against the text from `key`.
+///
+/// Results are ordered best match first; ties keep their original order.
+pub fn fuzzy_filter<'a, T, F>(query: &str, items: &'a [T], key: F) -> Vec<&'a T>
+where
+    F: Fn(&T) -> &str,
+{
+    let mut scored: Vec<(i64, &T)> = items
+        .iter()
+        .filter_map(|item| fuzzy_score(query, key(item)).map(|score| (score, item)))
+        .collect();
+    scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+    scored.into_iter().map(|(_, item)| item).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuzzy_score_empty_query_matches() {
+        assert_eq!(fuzzy_score("", "anything"), Some(0));
+    }
+
+    #[test]
+    fn test_fuzzy_score_subsequence() {
+        assert!(fuzzy_score("tml", "Timeline").is_some());
+        assert!(fuzzy_score("TIME", "timeline").is_some());
+    }
+
+    #[test]
+    fn test_fuzzy_score_out_of_order_fails() {
+        assert_eq!(fuzzy_score("lt", "tl"), None);
+        assert_eq!(fuzzy_score("xyz", "Timeline"), None);
+    }
+
+    #[test]
+    fn test_fuzzy_score_prefers_consecutive() {
+        let tight = fuzzy_score("tree", "Show tree view").unwrap();
+        let loose = fuzzy_score("tree", "Toggle expand/collapse").unwrap_or(i64::MIN);
+        assert!(tight > loose);
+    }
+
+    #[test]
+    fn test_fuzzy_score_prefers_word_start() {
+        let start = fuzzy_score("d", "Show details").unwrap();
+        let middle = fuzzy_score("d", "Reload").unwrap();
+        assert!(start > middle);
+    }
+
+    #[test]
+    fn test_fuzzy_filter_orders_by_score() {
+        let items = ["Reload tasks", "Show details view", "Show tree view"];
+        let results = fuzzy_filter("tree", &items, |s| s);
+        assert_eq!(results, vec![&"Show tree view"]);
+
+        let results = fuzzy_filter("show", &items, |s| s);
+        assert_eq!(results, vec![&"Show details view", &"Show tree view"]);
+    }
+
+    #[test]
+    fn test_fuzzy_filter_empty_query_keeps_order() {
+        let items = ["b", "a", "c"];
+        assert_eq!(fuzzy_filter("", &items, |s| s), vec![&"b", &"a", &"c"]);
+    }
+}
@@ -3,26 +3,44 @@
 //! Provides a terminal user interface for viewing and navigating
 //! Vertebrae tasks using ratatui and crossterm.
 
+pub mod add_task;
 pub mod app;
+pub mod board;
 pub mod data;
 pub mod details;
 pub mod error;
 pub mod event;
+pub mod fuzzy;
 pub mod navigation;
+pub mod palette;
+pub mod session;
+pub mod theme;
 pub mod timeline;
 pub mod tree_view;
 pub mod ui;
 
+pub use add_task::{AddTaskForm, FormField, create_task, render_add_task};
 pub use app::App;
+pub use board::{
+    BOARD_STATUSES, BoardCard, BoardState, MoveDirection, adjacent_status, apply_transition,
+    render_board_view,
+};
 pub use data::{
-    load_full_tree, load_node_children, load_root_epics_lazy, load_task_details,
-    load_timeline_tasks,
+    DEFAULT_TREE_LOAD_LIMIT, LoadedTree, load_blocker_summary, load_board_cards, load_full_tree,
+    load_node_children, load_root_epics_lazy, load_task_details, load_timeline_tasks, load_tree,
+};
+pub use details::{
+    BlockerSummary, TaskDetails, TaskRelationships, max_details_scroll, render_details_view,
 };
-pub use details::{TaskDetails, TaskRelationships, render_details_view};
 pub use error::{TuiError, TuiResult};
-pub use navigation::{FlatNode, TreeNode, TreeState, flatten_tree, render_nav_panel};
+pub use navigation::{
+    FlatNode, NavHit, TreeNode, TreeState, flatten_tree, hit_test_nav, render_nav_panel,
+};
+pub use palette::{PaletteAction, PaletteState, render_palette};
+pub use session::{SESSION_FILE, SessionState};
+pub use theme::{THEME_FILE, Theme};
 pub use timeline::{
-    DependencyEdge, TimelineTask, TimelineViewState, compute_dependency_groups,
-    render_timeline_view,
+    DependencyEdge, StatusFilter, TimelineTask, TimelineViewState, TimelineWindow, ZoomLevel,
+    compute_dependency_groups, filter_to_window, render_timeline_view,
 };
 pub use tree_view::render_tree_view;
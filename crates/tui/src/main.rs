@@ -1,9 +1,46 @@
 //! Entry point for the Vertebrae TUI application.
 
-use vertebrae_tui::{App, TuiResult};
+use clap::Parser;
+use vertebrae_db::{Config, install_graph_settings, install_id_format, install_level_labels};
+use vertebrae_tui::{App, DEFAULT_TREE_LOAD_LIMIT, Theme, TuiResult};
+
+/// Vertebrae TUI - browse tasks in the terminal
+#[derive(Parser)]
+#[command(name = "vtb-tui")]
+#[command(version = "0.1.0")]
+struct Args {
+    /// Reload data from the database every N seconds (0 disables polling)
+    #[arg(
+        long,
+        visible_alias = "watch",
+        value_name = "SECONDS",
+        default_value_t = 0
+    )]
+    refresh: u64,
+
+    /// Load the whole task tree up front only if it has at most N tasks;
+    /// larger trees load each node's children when it is expanded
+    #[arg(long, value_name = "N", default_value_t = DEFAULT_TREE_LOAD_LIMIT)]
+    tree_limit: usize,
+
+    /// Don't capture the mouse, so the terminal's text selection keeps working
+    #[arg(long)]
+    no_mouse: bool,
+}
 
 #[tokio::main]
 async fn main() -> TuiResult<()> {
-    let mut app = App::new(None).await?;
+    let args = Args::parse();
+
+    let config = Config::load(&Config::default_path())?;
+    install_level_labels(config.levels);
+    install_id_format(config.display.id_format);
+    install_graph_settings(config.graph);
+
+    let mut app = App::new(None, args.tree_limit)
+        .await?
+        .with_refresh_interval(args.refresh)
+        .with_mouse_capture(!args.no_mouse)
+        .with_theme(Theme::load(&Theme::default_path())?);
     app.run().await
 }
@@ -1,9 +1,11 @@
 //! Navigation panel with task hierarchy tree widget.
 //!
 //! Provides a tree widget for displaying and navigating task hierarchies
-//! (epic -> ticket -> task) with expand/collapse functionality.
+//! (epic -> ticket -> task) with expand/collapse functionality, and an
+//! optional title filter that narrows the tree to matching nodes.
 
 use std::collections::HashSet;
+use std::ops::Range;
 
 use ratatui::{
     Frame,
@@ -93,6 +95,8 @@ pub struct FlatNode {
     pub is_expanded: bool,
     /// Progress information for nodes with children.
     pub progress: Option<Progress>,
+    /// Byte range of the title matching the filter, if it matches.
+    pub matched: Option<Range<usize>>,
 }
 
 /// State for managing expanded nodes in the tree.
@@ -100,6 +104,8 @@ pub struct FlatNode {
 pub struct TreeState {
     /// Set of expanded node IDs.
     expanded: HashSet<String>,
+    /// Title filter; an empty query filters nothing.
+    filter: String,
 }
 
 impl TreeState {
@@ -144,6 +150,52 @@ impl TreeState {
         }
     }
 
+    /// IDs of the expanded nodes that are in the given tree, sorted.
+    pub fn expanded_in(&self, roots: &[TreeNode]) -> Vec<String> {
+        fn collect(nodes: &[TreeNode], state: &TreeState, ids: &mut Vec<String>) {
+            for node in nodes {
+                if state.is_expanded(&node.id) {
+                    ids.push(node.id.clone());
+                }
+                collect(&node.children, state, ids);
+            }
+        }
+        let mut ids = Vec::new();
+        collect(roots, self, &mut ids);
+        ids.sort();
+        ids
+    }
+
+    /// Forget expanded IDs that are not in the given tree.
+    pub fn prune(&mut self, roots: &[TreeNode]) {
+        self.expanded = self.expanded_in(roots).into_iter().collect();
+    }
+
+    /// Get the title filter, if one is set.
+    pub fn filter(&self) -> Option<&str> {
+        Some(self.filter.as_str()).filter(|query| !query.is_empty())
+    }
+
+    /// Set the title filter.
+    pub fn set_filter(&mut self, query: impl Into<String>) {
+        self.filter = query.into();
+    }
+
+    /// Append a character to the title filter.
+    pub fn push_filter_char(&mut self, c: char) {
+        self.filter.push(c);
+    }
+
+    /// Remove the last character of the title filter.
+    pub fn pop_filter_char(&mut self) {
+        self.filter.pop();
+    }
+
+    /// Remove the title filter.
+    pub fn clear_filter(&mut self) {
+        self.filter.clear();
+    }
+
     /// Recursively expand a node and all its children.
     fn expand_node_recursive(&mut self, node: &TreeNode) {
         if node.has_children() {
@@ -167,15 +219,76 @@ mod prefix {
 
 /// Flatten a tree into a list of visible nodes based on expansion state.
 ///
-/// Only includes children of nodes that are expanded.
+/// Only includes children of nodes that are expanded. With a filter set,
+/// expansion is ignored: the list holds the nodes whose title contains the
+/// query (case-insensitive) and their ancestors, so matches keep their
+/// place in the hierarchy.
 pub fn flatten_tree(roots: &[TreeNode], state: &TreeState) -> Vec<FlatNode> {
     let mut result = Vec::new();
     for root in roots {
-        flatten_node(&mut result, root, 0, state);
+        match state.filter() {
+            Some(query) => {
+                flatten_filtered(&mut result, root, 0, query);
+            }
+            None => flatten_node(&mut result, root, 0, state),
+        }
     }
     result
 }
 
+/// Recursively flatten a node if it or any descendant matches `query`.
+///
+/// Returns whether anything was added.
+fn flatten_filtered(
+    result: &mut Vec<FlatNode>,
+    node: &TreeNode,
+    depth: usize,
+    query: &str,
+) -> bool {
+    let mut children = Vec::new();
+    for child in &node.children {
+        flatten_filtered(&mut children, child, depth + 1, query);
+    }
+    let matched = match_range(&node.title, query);
+    if matched.is_none() && children.is_empty() {
+        return false;
+    }
+
+    result.push(FlatNode {
+        id: node.id.clone(),
+        title: node.title.clone(),
+        level: node.level.clone(),
+        status: node.status.clone(),
+        depth,
+        has_children: node.has_children(),
+        is_expanded: !children.is_empty(),
+        progress: node.progress.clone(),
+        matched,
+    });
+    result.append(&mut children);
+    true
+}
+
+/// Byte range of the first case-insensitive occurrence of `query` in `title`.
+fn match_range(title: &str, query: &str) -> Option<Range<usize>> {
+    if query.is_empty() {
+        return None;
+    }
+    title.char_indices().find_map(|(start, _)| {
+        let mut rest = title[start..].char_indices();
+        for q in query.chars() {
+            let (_, c) = rest.next()?;
+            if !c.to_lowercase().eq(q.to_lowercase()) {
+                return None;
+            }
+        }
+        let end = rest
+            .next()
+            .map_or(title.len(), |(offset, _)| start + offset);
+        Some(start..end)
+    })
+}
+
 /// Recursively flatten a single node and its visible children.
 fn flatten_node(result: &mut Vec<FlatNode>, node: &TreeNode, depth: usize, state: &TreeState) {
     let is_expanded = state.is_expanded(&node.id);
@@ -189,6 +302,7 @@ fn flatten_node(result: &mut Vec<FlatNode>, node: &TreeNode, depth: usize, state
         has_children: node.has_children(),
         is_expanded,
         progress: node.progress.clone(),
+        matched: None,
     });
 
     // Only include children if the node is expanded
@@ -199,6 +313,46 @@ fn flatten_node(result: &mut Vec<FlatNode>, node: &TreeNode, depth: usize, state
     }
 }
 
+/// Where a click landed in the navigation panel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NavHit {
+    /// Index of the clicked node in the flattened list.
+    pub index: usize,
+    /// Whether the click was on the node's expand indicator.
+    pub on_indicator: bool,
+}
+
+/// First row shown when `rows` rows are visible, scrolled just far enough
+/// to keep the selection on screen.
+fn nav_scroll(selected_index: usize, rows: usize) -> usize {
+    (selected_index + 1).saturating_sub(rows)
+}
+
+/// Map a terminal cell to the node drawn there by `render_nav_panel`.
+///
+/// `area` is the whole panel including its border. Returns `None` for
+/// clicks on the border or below the last node.
+pub fn hit_test_nav(
+    area: Rect,
+    nodes: &[FlatNode],
+    selected_index: usize,
+    column: u16,
+    row: u16,
+) -> Option<NavHit> {
+    let inner = Block::default().borders(Borders::ALL).inner(area);
+    if !(inner.x..inner.right()).contains(&column) || !(inner.y..inner.bottom()).contains(&row) {
+        return None;
+    }
+    let index = nav_scroll(selected_index, inner.height as usize) + (row - inner.y) as usize;
+    let node = nodes.get(index)?;
+    // The indicator follows two columns of indentation per level
+    let indicator = inner.x as usize + node.depth * 2;
+    Some(NavHit {
+        index,
+        on_indicator: column as usize == indicator,
+    })
+}
+
 /// Render the navigation panel with task tree.
 ///
 /// # Arguments
@@ -208,23 +362,23 @@ fn flatten_node(result: &mut Vec<FlatNode>, node: &TreeNode, depth: usize, state
 /// * `nodes` - The flattened list of visible nodes
 /// * `selected_index` - Index of the currently selected node
 /// * `empty_message` - Message to show when there are no nodes
-/// * `is_focused` - Whether this panel currently has focus
+/// * `notice` - Short note shown in the panel title, if any
+/// * `border_color` - Border color, which tells whether the panel has focus
 pub fn render_nav_panel(
     frame: &mut Frame,
     area: Rect,
     nodes: &[FlatNode],
     selected_index: usize,
     empty_message: Option<&str>,
-    is_focused: bool,
+    notice: Option<&str>,
+    border_color: Color,
 ) {
-    let border_color = if is_focused {
-        Color::Yellow
-    } else {
-        Color::Cyan
+    let title = match notice {
+        Some(notice) => format!(" Navigation ({}) ", notice),
+        None => " Navigation ".to_string(),
     };
-
     let block = Block::default()
-        .title(" Navigation ")
+        .title(title)
         .borders(Borders::ALL)
         .border_style(Style::default().fg(border_color));
 
@@ -244,10 +398,24 @@ pub fn render_nav_panel(
         .map(|(i, node)| render_node_line(node, i == selected_index))
         .collect();
 
-    let nav_list = Paragraph::new(items).block(block);
+    let rows = area.height.saturating_sub(2) as usize;
+    let scroll = nav_scroll(selected_index, rows) as u16;
+    let nav_list = Paragraph::new(items).block(block).scroll((scroll, 0));
     frame.render_widget(nav_list, area);
 }
 
+/// Checkbox-style glyph for a task status, as shown in the tree.
+pub(crate) fn status_indicator(status: &Status) -> &'static str {
+    match status {
+        Status::Done => "[x]",
+        Status::InProgress => "[>]",
+        Status::PendingReview => "[?]",
+        Status::Backlog => "[.]",
+        Status::Rejected => "[-]",
+        Status::Todo => "[ ]",
+    }
+}
+
 /// Render a single node as a styled line.
 fn render_node_line(node: &FlatNode, is_selected: bool) -> Line<'static> {
     let mut spans = Vec::new();
@@ -266,18 +434,7 @@ fn render_node_line(node: &FlatNode, is_selected: bool) -> Line<'static> {
         prefix::LEAF
     };
 
-    // Status indicator
-    let status_indicator = match node.status {
-        Status::Done => "[x]",
-        Status::InProgress => "[>]",
-        Status::PendingReview => "[?]",
-        Status::Backlog => "[.]",
-        Status::Rejected => "[-]",
-        Status::Todo => "[ ]",
-    };
-
-    // Build the main text
-    let text = format!("{}{} {} {}", indent, prefix, status_indicator, node.title);
+    let status_indicator = status_indicator(&node.status);
 
     // Apply styling
     let style = if is_selected {
@@ -294,7 +451,23 @@ fn render_node_line(node: &FlatNode, is_selected: bool) -> Line<'static> {
         }
     };
 
-    spans.push(Span::styled(text, style));
+    spans.push(Span::styled(
+        format!("{}{} {} ", indent, prefix, status_indicator),
+        style,
+    ));
+    // Highlight the part of the title matching the filter
+    match &node.matched {
+        Some(range) => {
+            let highlight = style.fg(Color::Black).bg(Color::Yellow);
+            spans.push(Span::styled(node.title[..range.start].to_string(), style));
+            spans.push(Span::styled(
+                node.title[range.clone()].to_string(),
+                highlight,
+            ));
+            spans.push(Span::styled(node.title[range.end..].to_string(), style));
+        }
+        None => spans.push(Span::styled(node.title.clone(), style)),
+    }
 
     // Add progress indicator for nodes with children
     if let Some(progress) = &node.progress {
@@ -317,8 +490,7 @@ fn render_node_line(node: &FlatNode, is_selected: bool) -> Line<'static> {
 }
 
 /// Get the status color for a task.
-#[allow(dead_code)] // May be used for status-based coloring in future
-fn status_color(status: &Status) -> Color {
+pub(crate) fn status_color(status: &Status) -> Color {
     match status {
         Status::Done => Color::Green,
         Status::InProgress => Color::Yellow,
@@ -337,6 +509,42 @@ mod tests {
     // TreeNode tests
     // ========================================
 
+    #[test]
+    fn test_hit_test_nav_accounts_for_border_scroll_and_depth() {
+        let parent = TreeNode::new("p", "Parent", Level::Ticket).with_child(TreeNode::new(
+            "c",
+            "Child",
+            Level::Task,
+        ));
+        let mut state = TreeState::new();
+        state.expand("p");
+        let nodes = flatten_tree(&[parent], &state);
+        let area = Rect::new(10, 5, 30, 10);
+
+        // Border cells and rows past the last node miss
+        assert_eq!(hit_test_nav(area, &nodes, 0, 10, 6), None);
+        assert_eq!(hit_test_nav(area, &nodes, 0, 15, 5), None);
+        assert_eq!(hit_test_nav(area, &nodes, 0, 15, 8), None);
+
+        let hit = |column, row| hit_test_nav(area, &nodes, 0, column, row).unwrap();
+        assert_eq!(
+            hit(11, 6),
+            NavHit {
+                index: 0,
+                on_indicator: true
+            }
+        );
+        assert!(!hit(20, 6).on_indicator);
+        // The child's indicator is indented one level
+        assert_eq!(hit(13, 7).index, 1);
+        assert!(hit(13, 7).on_indicator);
+        assert!(!hit(11, 7).on_indicator);
+
+        // With one visible row, selecting the child scrolls it to the top
+        let short = Rect::new(10, 5, 30, 3);
+        assert_eq!(hit_test_nav(short, &nodes, 1, 15, 6).unwrap().index, 1);
+    }
+
     #[test]
     fn test_tree_node_new() {
         let node = TreeNode::new("abc123", "Test Task", Level::Task);
@@ -384,6 +592,27 @@ mod tests {
     // TreeState tests
     // ========================================
 
+    #[test]
+    fn test_tree_state_prune_drops_missing_ids() {
+        let roots = vec![
+            TreeNode::new("b", "B", Level::Epic).with_child(TreeNode::new(
+                "b1",
+                "B1",
+                Level::Ticket,
+            )),
+            TreeNode::new("a", "A", Level::Epic),
+        ];
+        let mut state = TreeState::new();
+        for id in ["gone", "b1", "a"] {
+            state.expand(id);
+        }
+        assert_eq!(state.expanded_in(&roots), vec!["a", "b1"]);
+
+        state.prune(&roots);
+        assert!(!state.is_expanded("gone"));
+        assert!(state.is_expanded("b1"));
+    }
+
     #[test]
     fn test_tree_state_new() {
         let state = TreeState::new();
@@ -449,6 +678,39 @@ mod tests {
         assert!(!state.is_expanded("task1"));
     }
 
+    #[test]
+    fn test_tree_state_expand_all_then_collapse_all() {
+        let mut state = TreeState::new();
+        let roots = vec![
+            TreeNode::new("epic1", "Epic 1", Level::Epic).with_child(
+                TreeNode::new("ticket1", "Ticket", Level::Ticket).with_child(TreeNode::new(
+                    "task1",
+                    "Task",
+                    Level::Task,
+                )),
+            ),
+            TreeNode::new("epic2", "Epic 2", Level::Epic).with_child(TreeNode::new(
+                "task2",
+                "Task",
+                Level::Task,
+            )),
+        ];
+
+        state.expand_all(&roots);
+        let ids: Vec<String> = flatten_tree(&roots, &state)
+            .into_iter()
+            .map(|n| n.id)
+            .collect();
+        assert_eq!(ids, vec!["epic1", "ticket1", "task1", "epic2", "task2"]);
+
+        state.collapse_all();
+        let ids: Vec<String> = flatten_tree(&roots, &state)
+            .into_iter()
+            .map(|n| n.id)
+            .collect();
+        assert_eq!(ids, vec!["epic1", "epic2"]);
+    }
+
     // ========================================
     // flatten_tree tests
     // ========================================
@@ -577,6 +839,48 @@ mod tests {
         assert_eq!(result[3].id, "c3");
     }
 
+    #[test]
+    fn test_flatten_tree_filter_keeps_ancestors_of_matches() {
+        let roots = vec![
+            TreeNode::new("epic", "Release", Level::Epic).with_children(vec![
+                TreeNode::new("t1", "Login page", Level::Ticket).with_child(TreeNode::new(
+                    "leaf",
+                    "Write LOGIN tests",
+                    Level::Task,
+                )),
+                TreeNode::new("t2", "Billing", Level::Ticket),
+            ]),
+            TreeNode::new("other", "Docs", Level::Epic),
+        ];
+
+        // Collapsed nodes don't hide matches
+        let mut state = TreeState::new();
+        state.set_filter("login");
+        let result = flatten_tree(&roots, &state);
+        let ids: Vec<&str> = result.iter().map(|n| n.id.as_str()).collect();
+        assert_eq!(ids, vec!["epic", "t1", "leaf"]);
+        assert_eq!(result[0].matched, None);
+        assert!(result[0].is_expanded);
+        assert_eq!(result[1].matched, Some(0..5));
+        assert_eq!(result[2].matched, Some(6..11));
+        assert_eq!(result[2].depth, 2);
+
+        state.set_filter("nothing");
+        assert!(flatten_tree(&roots, &state).is_empty());
+
+        state.clear_filter();
+        assert_eq!(state.filter(), None);
+        assert_eq!(flatten_tree(&roots, &state).len(), 2);
+    }
+
+    #[test]
+    fn test_match_range_is_case_insensitive_on_char_boundaries() {
+        assert_eq!(match_range("Café Menu", "CAFÉ"), Some(0..5));
+        assert_eq!(match_range("Café Menu", "menu"), Some(6..10));
+        assert_eq!(match_range("Menu", "menus"), None);
+        assert_eq!(match_range("Menu", ""), None);
+    }
+
     // ========================================
     // FlatNode tests
     // ========================================
@@ -592,6 +896,7 @@ mod tests {
             has_children: true,
             is_expanded: false,
             progress: None,
+            matched: None,
         };
 
         // When collapsed with children, should use collapsed prefix
@@ -610,6 +915,7 @@ mod tests {
             has_children: true,
             is_expanded: true,
             progress: None,
+            matched: None,
         };
 
         assert!(node.has_children);
@@ -627,6 +933,7 @@ mod tests {
             has_children: false,
             is_expanded: false,
             progress: None,
+            matched: None,
         };
 
         assert!(!node.has_children);
@@ -643,6 +950,7 @@ mod tests {
             has_children: false,
             is_expanded: false,
             progress: None,
+            matched: None,
         };
 
         // Depth of 2 should result in 4 spaces of indentation
@@ -661,6 +969,7 @@ mod tests {
             has_children: true,
             is_expanded: false,
             progress: Some(Progress::new(2, 3)),
+            matched: None,
         };
 
         assert!(node.progress.is_some());
@@ -0,0 +1,373 @@
+//! Command palette for the TUI.
+//!
+//! Opened with `:`, the palette lists every available action with its
+//! keybinding and narrows the list with fuzzy filtering as the user types.
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+};
+
+use crate::fuzzy::fuzzy_filter;
+
+/// An action that can be run from the command palette.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaletteAction {
+    /// Switch the content panel to the Details view.
+    ShowDetails,
+    /// Switch the content panel to the Tree view.
+    ShowTree,
+    /// Switch the content panel to the Timeline view.
+    ShowTimeline,
+    /// Switch the content panel to the Board view.
+    ShowBoard,
+    /// Move focus to the navigation panel.
+    FocusNavigation,
+    /// Move focus to the content panel.
+    FocusContent,
+    /// Expand or collapse the selected task.
+    ToggleExpand,
+    /// Expand every task in the navigation tree.
+    ExpandAll,
+    /// Collapse every task in the navigation tree.
+    CollapseAll,
+    /// Move the selected task to in progress.
+    StartTask,
+    /// Move the selected task to pending review.
+    SubmitForReview,
+    /// Move the selected task to done.
+    CompleteTask,
+    /// Open the add-task form under the selected task.
+    AddTask,
+    /// Open the edit form for the selected task's title.
+    EditTask,
+    /// Write the selected task to a JSON file.
+    ExportTask,
+    /// Start filtering the navigation panel by title.
+    Filter,
+    /// Show or hide the timeline's dependency-group color key.
+    ToggleTimelineLegend,
+    /// Reload tasks from the database.
+    Reload,
+    /// Quit the application.
+    Quit,
+}
+
+impl PaletteAction {
+    /// All actions, in the order the palette lists them when unfiltered.
+    pub const ALL: [PaletteAction; 19] = [
+        PaletteAction::ShowDetails,
+        PaletteAction::ShowTree,
+        PaletteAction::ShowTimeline,
+        PaletteAction::ShowBoard,
+        PaletteAction::FocusNavigation,
+        PaletteAction::FocusContent,
+        PaletteAction::ToggleExpand,
+        PaletteAction::ExpandAll,
+        PaletteAction::CollapseAll,
+        PaletteAction::StartTask,
+        PaletteAction::SubmitForReview,
+        PaletteAction::CompleteTask,
+        PaletteAction::AddTask,
+        PaletteAction::EditTask,
+        PaletteAction::ExportTask,
+        PaletteAction::Filter,
+        PaletteAction::ToggleTimelineLegend,
+        PaletteAction::Reload,
+        PaletteAction::Quit,
+    ];
+
+    /// Human-readable name matched against the palette query.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::ShowDetails => "View: details",
+            Self::ShowTree => "View: tree",
+            Self::ShowTimeline => "View: timeline",
+            Self::ShowBoard => "View: board",
+            Self::FocusNavigation => "Focus: navigation panel",
+            Self::FocusContent => "Focus: content panel",
+            Self::ToggleExpand => "Toggle expand/collapse selected task",
+            Self::ExpandAll => "Expand all tasks",
+            Self::CollapseAll => "Collapse all tasks",
+            Self::StartTask => "Transition: start selected task",
+            Self::SubmitForReview => "Transition: submit selected task for review",
+            Self::CompleteTask => "Transition: mark selected task done",
+            Self::AddTask => "Add task",
+            Self::EditTask => "Edit selected task title",
+            Self::ExportTask => "Export selected task to JSON",
+            Self::Filter => "Filter tasks by title",
+            Self::ToggleTimelineLegend => "Toggle dependency color key",
+            Self::Reload => "Reload tasks",
+            Self::Quit => "Quit",
+        }
+    }
+
+    /// The direct keybinding for this action, shown alongside the label.
+    pub fn key_hint(self) -> &'static str {
+        match self {
+            Self::ShowDetails | Self::ShowTree | Self::ShowTimeline | Self::ShowBoard => "Tab",
+            Self::FocusNavigation => "h",
+            Self::FocusContent => "l",
+            Self::ToggleExpand => "Enter",
+            Self::ExpandAll => "E",
+            Self::CollapseAll => "C",
+            Self::StartTask => "s",
+            Self::SubmitForReview => "r",
+            Self::CompleteTask => "d",
+            Self::AddTask => "a",
+            Self::EditTask => "e",
+            Self::ExportTask => "x",
+            Self::Filter => "/",
+            Self::ToggleTimelineLegend => "c",
+            Self::Reload => "R",
+            Self::Quit => "q",
+        }
+    }
+}
+
+/// State of an open command palette.
+#[derive(Debug, Clone, Default)]
+pub struct PaletteState {
+    /// Text typed into the palette.
+    query: String,
+    /// Index of the highlighted entry within the filtered matches.
+    selected: usize,
+}
+
+impl PaletteState {
+    /// Create an empty palette.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the current query.
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// Get the index of the highlighted match.
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Actions matching the current query, best match first.
+    pub fn matches(&self) -> Vec<PaletteAction> {
+        fuzzy_filter(&self.query, &PaletteAction::ALL, |action| action.label())
+            .into_iter()
+            .copied()
+            .collect()
+    }
+
+    /// The highlighted action, if any action matches.
+    pub fn selected_action(&self) -> Option<PaletteAction> {
+        self.matches().get(self.selected).copied()
+    }
+
+    /// Append a character to the query and reset the highlight.
+    pub fn push_char(&mut self, c: char) {
+        self.query.push(c);
+        self.selected = 0;
+    }
+
+    /// Remove the last query character and reset the highlight.
+    pub fn pop_char(&mut self) {
+        self.query.pop();
+        self.selected = 0;
+    }
+
+    /// Highlight the next match (clamps at the last one).
+    pub fn select_next(&mut self) {
+        let count = self.matches().len();
+        if count > 0 && self.selected < count - 1 {
+            self.selected += 1;
+        }
+    }
+
+    /// Highlight the previous match (clamps at the first one).
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+}
+
+/// Render the command palette as a popup centered over `area`.
+pub fn render_palette(frame: &mut Frame, area: Rect, state: &PaletteState) {
+    let popup = centered_rect(area, 60, PaletteAction::ALL.len() as u16 + 5);
+    frame.render_widget(Clear, popup);
+
+    let block = Block::default()
+        .title(" Command Palette ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow));
+    let inner = block.inner(popup);
+    frame.render_widget(block, popup);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(2), Constraint::Min(0)])
+        .split(inner);
+
+    let input = Paragraph::new(Line::from(vec![
+        Span::styled(":", Style::default().fg(Color::Yellow)),
+        Span::raw(state.query()),
+    ]));
+    frame.render_widget(input, chunks[0]);
+
+    let matches = state.matches();
+    if matches.is_empty() {
+        let empty = Paragraph::new("No matching actions").style(Style::default().fg(Color::Gray));
+        frame.render_widget(empty, chunks[1]);
+        return;
+    }
+
+    let items: Vec<ListItem> = matches
+        .iter()
+        .map(|action| {
+            ListItem::new(Line::from(vec![
+                Span::raw(action.label()),
+                Span::styled(
+                    format!("  [{}]", action.key_hint()),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).highlight_style(
+        Style::default()
+            .fg(Color::Yellow)
+            .bg(Color::DarkGray)
+            .add_modifier(Modifier::BOLD),
+    );
+    let mut list_state = ListState::default().with_selected(Some(state.selected()));
+    frame.render_stateful_widget(list, chunks[1], &mut list_state);
+}
+
+/// Compute a rectangle of `percent_x` width and `height` rows centered in `area`.
+pub(crate) fn centered_rect(area: Rect, percent_x: u16, height: u16) -> Rect {
+    let width = area.width * percent_x / 100;
+    let height = height.min(area.height);
+    Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_query_lists_all_actions() {
+        let state = PaletteState::new();
+        assert_eq!(state.matches(), PaletteAction::ALL.to_vec());
+        assert_eq!(state.selected_action(), Some(PaletteAction::ShowDetails));
+    }
+
+    #[test]
+    fn test_query_filters_actions() {
+        let mut state = PaletteState::new();
+        for c in "timeline".chars() {
+            state.push_char(c);
+        }
+        assert_eq!(state.matches(), vec![PaletteAction::ShowTimeline]);
+    }
+
+    #[test]
+    fn test_query_finds_task_actions() {
+        let mut state = PaletteState::new();
+        for c in "transition".chars() {
+            state.push_char(c);
+        }
+        assert_eq!(
+            state.matches(),
+            vec![
+                PaletteAction::StartTask,
+                PaletteAction::SubmitForReview,
+                PaletteAction::CompleteTask
+            ]
+        );
+
+        let mut state = PaletteState::new();
+        for c in "export".chars() {
+            state.push_char(c);
+        }
+        assert_eq!(state.selected_action(), Some(PaletteAction::ExportTask));
+    }
+
+    #[test]
+    fn test_fuzzy_query_ranks_best_match_first() {
+        let mut state = PaletteState::new();
+        for c in "view".chars() {
+            state.push_char(c);
+        }
+        let matches = state.matches();
+        assert_eq!(
+            &matches[..3],
+            &[
+                PaletteAction::ShowDetails,
+                PaletteAction::ShowTree,
+                PaletteAction::ShowTimeline
+            ]
+        );
+    }
+
+    #[test]
+    fn test_no_matches() {
+        let mut state = PaletteState::new();
+        state.push_char('z');
+        state.push_char('z');
+        assert!(state.matches().is_empty());
+        assert_eq!(state.selected_action(), None);
+    }
+
+    #[test]
+    fn test_selection_moves_and_clamps() {
+        let mut state = PaletteState::new();
+        state.select_previous();
+        assert_eq!(state.selected(), 0);
+
+        state.select_next();
+        assert_eq!(state.selected_action(), Some(PaletteAction::ShowTree));
+
+        for _ in 0..20 {
+            state.select_next();
+        }
+        assert_eq!(state.selected(), PaletteAction::ALL.len() - 1);
+        assert_eq!(state.selected_action(), Some(PaletteAction::Quit));
+    }
+
+    #[test]
+    fn test_typing_resets_selection() {
+        let mut state = PaletteState::new();
+        state.select_next();
+        state.push_char('q');
+        assert_eq!(state.selected(), 0);
+        assert_eq!(state.selected_action(), Some(PaletteAction::Quit));
+
+        state.pop_char();
+        assert_eq!(state.query(), "");
+        assert_eq!(state.selected(), 0);
+    }
+
+    #[test]
+    fn test_every_action_has_label_and_key_hint() {
+        for action in PaletteAction::ALL {
+            assert!(!action.label().is_empty());
+            assert!(!action.key_hint().is_empty(), "{:?}", action);
+        }
+    }
+
+    #[test]
+    fn test_centered_rect_fits_area() {
+        let area = Rect::new(0, 0, 100, 10);
+        let rect = centered_rect(area, 60, 20);
+        assert_eq!(rect.width, 60);
+        assert_eq!(rect.height, 10);
+        assert_eq!(rect.x, 20);
+    }
+}
@@ -0,0 +1,97 @@
+//! Saved TUI session state.
+//!
+//! The expanded tasks and the selected task are written to
+//! `.vtb/tui_state.json` on quit and restored on the next launch.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+/// File name of the session state, stored next to the database directory.
+pub const SESSION_FILE: &str = "tui_state.json";
+
+/// Navigation state kept between TUI sessions.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionState {
+    /// IDs of the expanded tasks.
+    #[serde(default)]
+    pub expanded: Vec<String>,
+    /// ID of the selected task, if any.
+    #[serde(default)]
+    pub selected: Option<String>,
+}
+
+impl SessionState {
+    /// Path of the session file for the database at `db_path`.
+    ///
+    /// For the default `.vtb/data` database this is `.vtb/tui_state.json`.
+    pub fn path_for(db_path: &Path) -> PathBuf {
+        db_path
+            .parent()
+            .unwrap_or_else(|| Path::new("."))
+            .join(SESSION_FILE)
+    }
+
+    /// Read the session from `path`.
+    ///
+    /// A missing or unreadable file gives an empty session, so the TUI
+    /// starts fresh.
+    pub fn load(path: &Path) -> Self {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    /// Write the session to `path`.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let contents = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+        fs::write(path, contents)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_file(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "vtb-tui-session-test-{}-{}.json",
+            std::process::id(),
+            name
+        ))
+    }
+
+    #[test]
+    fn test_path_for_sits_next_to_database() {
+        assert_eq!(
+            SessionState::path_for(Path::new("/repo/.vtb/data")),
+            PathBuf::from("/repo/.vtb/tui_state.json")
+        );
+    }
+
+    #[test]
+    fn test_save_and_load_round_trip() {
+        let path = temp_file("round-trip");
+        let session = SessionState {
+            expanded: vec!["epic1".to_string(), "ticket1".to_string()],
+            selected: Some("task1".to_string()),
+        };
+        session.save(&path).unwrap();
+        assert_eq!(SessionState::load(&path), session);
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_missing_or_corrupt_file_starts_fresh() {
+        let path = temp_file("corrupt");
+        let _ = fs::remove_file(&path);
+        assert_eq!(SessionState::load(&path), SessionState::default());
+
+        fs::write(&path, "{ not json").unwrap();
+        assert_eq!(SessionState::load(&path), SessionState::default());
+        let _ = fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,267 @@
+//! Color theme for the TUI.
+//!
+//! `.vtb/theme.toml` overrides the built-in colors, for example to keep
+//! text readable on a light terminal:
+//!
+//! ```toml
+//! [status]
+//! todo = "black"
+//! done = "#008800"
+//!
+//! [priority]
+//! critical = "magenta"
+//!
+//! [border]
+//! focused = "blue"
+//! unfocused = "darkgray"
+//!
+//! [dependencies]
+//! palette = ["blue", "magenta", "red"]
+//! ```
+//!
+//! Colors are ratatui color names (`red`, `lightblue`, `darkgray`, ...),
+//! `#rrggbb` hex values or 0-255 palette indexes. Anything left out keeps
+//! its default.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use ratatui::style::Color;
+use serde::Deserialize;
+use vertebrae_db::{Config, Priority, Status};
+
+use crate::error::{TuiError, TuiResult};
+
+/// File name of the theme, stored next to the config file.
+pub const THEME_FILE: &str = "theme.toml";
+
+/// Color overrides for the TUI.
+///
+/// The default theme overrides nothing, so every view keeps its built-in
+/// colors.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Theme {
+    /// Status colors, keyed by the status's database name.
+    statuses: HashMap<String, Color>,
+    /// Priority colors, keyed by the priority's database name.
+    priorities: HashMap<String, Color>,
+    /// Border color of the focused panel.
+    focused_border: Option<Color>,
+    /// Border color of unfocused panels.
+    unfocused_border: Option<Color>,
+    /// Colors cycled through for timeline dependency groups.
+    dependency_palette: Vec<Color>,
+}
+
+/// Layout of `theme.toml`, before the colors are parsed.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct ThemeFile {
+    status: HashMap<String, String>,
+    priority: HashMap<String, String>,
+    border: BorderFile,
+    dependencies: DependencyFile,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct BorderFile {
+    focused: Option<String>,
+    unfocused: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct DependencyFile {
+    palette: Vec<String>,
+}
+
+const PRIORITIES: [Priority; 4] = [
+    Priority::Low,
+    Priority::Medium,
+    Priority::High,
+    Priority::Critical,
+];
+
+impl Theme {
+    /// Load the theme from `path`.
+    ///
+    /// A missing file gives the default theme.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TuiError::InvalidTheme` if the file can't be read, isn't
+    /// valid TOML, or names an unknown status, priority or color.
+    pub fn load(path: &Path) -> TuiResult<Self> {
+        let invalid = |reason: String| TuiError::InvalidTheme {
+            path: path.to_path_buf(),
+            reason,
+        };
+        match std::fs::read_to_string(path) {
+            Ok(contents) => Self::parse(&contents).map_err(invalid),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(invalid(e.to_string())),
+        }
+    }
+
+    /// Parse a theme from TOML text.
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        let file: ThemeFile = toml::from_str(contents).map_err(|e| e.message().to_string())?;
+
+        let mut statuses = HashMap::new();
+        for (name, color) in &file.status {
+            let status = Status::parse(name).ok_or_else(|| format!("unknown status '{}'", name))?;
+            statuses.insert(status.as_str().to_string(), parse_color(color)?);
+        }
+
+        let mut priorities = HashMap::new();
+        for (name, color) in &file.priority {
+            let priority = PRIORITIES
+                .iter()
+                .find(|priority| priority.as_str() == name)
+                .ok_or_else(|| format!("unknown priority '{}'", name))?;
+            priorities.insert(priority.as_str().to_string(), parse_color(color)?);
+        }
+
+        Ok(Self {
+            statuses,
+            priorities,
+            focused_border: file
+                .border
+                .focused
+                .as_deref()
+                .map(parse_color)
+                .transpose()?,
+            unfocused_border: file
+                .border
+                .unfocused
+                .as_deref()
+                .map(parse_color)
+                .transpose()?,
+            dependency_palette: file
+                .dependencies
+                .palette
+                .iter()
+                .map(|color| parse_color(color))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    /// Get the default theme file path, next to `.vtb/config.toml`.
+    pub fn default_path() -> PathBuf {
+        Config::default_path().with_file_name(THEME_FILE)
+    }
+
+    /// Color for a status, or `default` when the theme doesn't set one.
+    pub fn status(&self, status: &Status, default: Color) -> Color {
+        self.statuses
+            .get(status.as_str())
+            .copied()
+            .unwrap_or(default)
+    }
+
+    /// Color for a priority, or `default` when the theme doesn't set one.
+    pub fn priority(&self, priority: &Priority, default: Color) -> Color {
+        self.priorities
+            .get(priority.as_str())
+            .copied()
+            .unwrap_or(default)
+    }
+
+    /// Border color for a panel; yellow when focused and cyan otherwise
+    /// unless the theme says differently.
+    pub fn border(&self, is_focused: bool) -> Color {
+        if is_focused {
+            self.focused_border.unwrap_or(Color::Yellow)
+        } else {
+            self.unfocused_border.unwrap_or(Color::Cyan)
+        }
+    }
+
+    /// The theme's dependency group colors, if it sets any.
+    pub fn dependency_palette(&self) -> Option<&[Color]> {
+        Some(self.dependency_palette.as_slice()).filter(|palette| !palette.is_empty())
+    }
+}
+
+/// Parse a color name, `#rrggbb` value or palette index.
+fn parse_color(value: &str) -> Result<Color, String> {
+    Color::from_str(value).map_err(|_| format!("unknown color '{}'", value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_theme_keeps_built_in_colors() {
+        let theme = Theme::default();
+        assert_eq!(theme.status(&Status::Todo, Color::White), Color::White);
+        assert_eq!(
+            theme.priority(&Priority::High, Color::Yellow),
+            Color::Yellow
+        );
+        assert_eq!(theme.border(true), Color::Yellow);
+        assert_eq!(theme.border(false), Color::Cyan);
+        assert_eq!(theme.dependency_palette(), None);
+    }
+
+    #[test]
+    fn test_parse_overrides_only_listed_entries() {
+        let theme = Theme::parse(
+            r##"
+            [status]
+            todo = "black"
+            in_progress = "#ff8800"
+
+            [priority]
+            critical = "magenta"
+
+            [border]
+            focused = "blue"
+
+            [dependencies]
+            palette = ["red", "12"]
+            "##,
+        )
+        .unwrap();
+
+        assert_eq!(theme.status(&Status::Todo, Color::White), Color::Black);
+        assert_eq!(
+            theme.status(&Status::InProgress, Color::Yellow),
+            Color::Rgb(0xff, 0x88, 0x00)
+        );
+        assert_eq!(theme.status(&Status::Done, Color::Green), Color::Green);
+        assert_eq!(
+            theme.priority(&Priority::Critical, Color::Red),
+            Color::Magenta
+        );
+        assert_eq!(theme.border(true), Color::Blue);
+        assert_eq!(theme.border(false), Color::Cyan);
+        assert_eq!(
+            theme.dependency_palette(),
+            Some(&[Color::Red, Color::Indexed(12)][..])
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_names() {
+        let err = Theme::parse("[status]\nblocked = \"red\"").unwrap_err();
+        assert!(err.contains("unknown status 'blocked'"));
+        let err = Theme::parse("[priority]\nurgent = \"red\"").unwrap_err();
+        assert!(err.contains("unknown priority 'urgent'"));
+        let err = Theme::parse("[status]\ndone = \"greenish\"").unwrap_err();
+        assert!(err.contains("unknown color 'greenish'"));
+        assert!(Theme::parse("[colors]\ndone = \"green\"").is_err());
+    }
+
+    #[test]
+    fn test_load_missing_file_gives_default() {
+        let path = std::env::temp_dir().join(format!(
+            "vtb-tui-theme-test-{}-missing.toml",
+            std::process::id()
+        ));
+        assert_eq!(Theme::load(&path).unwrap(), Theme::default());
+    }
+}
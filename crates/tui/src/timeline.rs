@@ -6,6 +6,7 @@
 //!
 //! Tasks are color-coded by dependency group - tasks in the same dependency
 //! chain share the same color, making it easy to visualize related work.
+//! An optional color key maps each visible group color to one of its tasks.
 
 use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, Utc};
 use ratatui::{
@@ -13,11 +14,13 @@ use ratatui::{
     layout::Rect,
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph},
+    widgets::{Block, Borders, Clear, Paragraph},
 };
 use std::collections::{HashMap, HashSet};
 use vertebrae_db::Status;
 
+use crate::theme::Theme;
+
 /// Zoom level for the timeline display.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ZoomLevel {
@@ -40,6 +43,104 @@ impl ZoomLevel {
             ZoomLevel::Months
         }
     }
+
+    /// The next finer zoom level, stopping at days.
+    pub fn finer(self) -> Self {
+        match self {
+            ZoomLevel::Months => ZoomLevel::Weeks,
+            ZoomLevel::Weeks | ZoomLevel::Days => ZoomLevel::Days,
+        }
+    }
+
+    /// The next coarser zoom level, stopping at months.
+    pub fn coarser(self) -> Self {
+        match self {
+            ZoomLevel::Days => ZoomLevel::Weeks,
+            ZoomLevel::Weeks | ZoomLevel::Months => ZoomLevel::Months,
+        }
+    }
+
+    /// Lowercase name shown in the timeline title.
+    pub fn label(self) -> &'static str {
+        match self {
+            ZoomLevel::Days => "days",
+            ZoomLevel::Weeks => "weeks",
+            ZoomLevel::Months => "months",
+        }
+    }
+}
+
+/// Preset date windows the timeline can be limited to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimelineWindow {
+    /// Span every started task.
+    #[default]
+    All,
+    /// The last 7 days.
+    LastWeek,
+    /// The last 30 days.
+    LastMonth,
+    /// The last 90 days.
+    LastQuarter,
+}
+
+impl TimelineWindow {
+    /// Number of days covered, or `None` for the full range.
+    pub fn days(self) -> Option<i64> {
+        match self {
+            Self::All => None,
+            Self::LastWeek => Some(7),
+            Self::LastMonth => Some(30),
+            Self::LastQuarter => Some(90),
+        }
+    }
+
+    /// First and last day of the window when it ends on `today`.
+    pub fn range(self, today: NaiveDate) -> Option<(NaiveDate, NaiveDate)> {
+        self.days()
+            .map(|days| (today - Duration::days(days), today))
+    }
+}
+
+/// Which tasks the timeline shows by status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StatusFilter {
+    /// Show every started task.
+    #[default]
+    All,
+    /// Hide finished tasks.
+    HideDone,
+    /// Show only finished tasks.
+    DoneOnly,
+}
+
+impl StatusFilter {
+    /// The filter the cycle key moves to.
+    pub fn next(self) -> Self {
+        match self {
+            Self::All => Self::HideDone,
+            Self::HideDone => Self::DoneOnly,
+            Self::DoneOnly => Self::All,
+        }
+    }
+
+    /// Whether a task with `status` is shown.
+    pub fn matches(self, status: &Status) -> bool {
+        match self {
+            Self::All => true,
+            Self::HideDone => *status != Status::Done,
+            Self::DoneOnly => *status == Status::Done,
+        }
+    }
+
+    /// Short name shown in the timeline title, `None` when nothing is hidden.
+    pub fn label(self) -> Option<&'static str> {
+        match self {
+            Self::All => None,
+            Self::HideDone => Some("hide done"),
+            Self::DoneOnly => Some("done only"),
+        }
+    }
 }
 
 /// A task with timeline information for rendering in the timeline view.
@@ -61,6 +162,12 @@ pub struct TimelineTask {
     /// Tasks with the same group_id are in the same dependency chain.
     /// None means the task has no dependencies (isolated task).
     pub dependency_group: Option<usize>,
+    /// Whether this is a synthetic rollup bar spanning a parent's children.
+    pub is_rollup: bool,
+    /// IDs of the tasks this one depends on.
+    pub depends_on: Vec<String>,
+    /// Tags on the task.
+    pub tags: Vec<String>,
 }
 
 impl TimelineTask {
@@ -75,12 +182,105 @@ impl TimelineTask {
     }
 }
 
+/// A parent task (typically an epic) whose children are rolled up into a
+/// single aggregate bar on the timeline.
+#[derive(Debug, Clone)]
+pub struct RollupParent {
+    /// Parent task ID.
+    pub id: String,
+    /// Parent task title.
+    pub title: String,
+    /// Parent task status.
+    pub status: Status,
+    /// IDs of the parent's descendants that may appear on the timeline.
+    pub child_ids: Vec<String>,
+}
+
+/// Compute the span covered by a set of child timeline tasks.
+///
+/// The span starts at the earliest child `started_at` and ends at the latest
+/// child `completed_at`. If any child is still in progress the end is `None`,
+/// meaning the span extends to the present.
+///
+/// # Returns
+///
+/// `None` if there are no children, otherwise `(start, end)`.
+pub fn compute_rollup_span(
+    children: &[&TimelineTask],
+) -> Option<(DateTime<Utc>, Option<DateTime<Utc>>)> {
+    let start = children.iter().map(|t| t.started_at).min()?;
+
+    let end = if children.iter().any(|t| t.completed_at.is_none()) {
+        None
+    } else {
+        children.iter().filter_map(|t| t.completed_at).max()
+    };
+
+    Some((start, end))
+}
+
+/// Build synthetic rollup bars for parents that have started children.
+///
+/// Parents whose children do not appear in `tasks` are skipped.
+///
+/// # Arguments
+///
+/// * `tasks` - The started tasks shown on the timeline
+/// * `parents` - Parents with the IDs of their descendants
+///
+/// # Returns
+///
+/// One rollup `TimelineTask` per parent with at least one started child.
+pub fn build_rollup_tasks(tasks: &[TimelineTask], parents: &[RollupParent]) -> Vec<TimelineTask> {
+    let by_id: HashMap<&str, &TimelineTask> = tasks
+        .iter()
+        .filter(|t| !t.is_rollup)
+        .map(|t| (t.id.as_str(), t))
+        .collect();
+
+    parents
+        .iter()
+        .filter_map(|parent| {
+            let children: Vec<&TimelineTask> = parent
+                .child_ids
+                .iter()
+                .filter_map(|id| by_id.get(id.as_str()).copied())
+                .collect();
+
+            compute_rollup_span(&children).map(|(started_at, completed_at)| TimelineTask {
+                id: parent.id.clone(),
+                title: parent.title.clone(),
+                status: parent.status.clone(),
+                started_at,
+                completed_at,
+                has_dependencies: false,
+                dependency_group: None,
+                is_rollup: true,
+                depends_on: Vec::new(),
+                tags: Vec::new(),
+            })
+        })
+        .collect()
+}
+
+/// Merge rollup bars into the task list, sorted by start time.
+///
+/// Rollup bars sort before tasks that start at the same instant so an
+/// epic's bar appears directly above its earliest child.
+pub fn merge_rollup_tasks(
+    mut tasks: Vec<TimelineTask>,
+    rollups: Vec<TimelineTask>,
+) -> Vec<TimelineTask> {
+    tasks.extend(rollups);
+    tasks.sort_by_key(|t| (t.started_at, !t.is_rollup));
+    tasks
+}
+
 /// Configuration for the timeline display.
 struct TimelineConfig {
     /// Start date of the visible timeline.
     start_date: NaiveDate,
-    /// End date of the visible timeline (used during column calculation).
-    #[cfg_attr(not(test), allow(dead_code))]
+    /// End date of the visible timeline.
     end_date: NaiveDate,
     /// Number of days visible.
     days: i64,
@@ -95,6 +295,40 @@ struct TimelineConfig {
     columns: Vec<ColumnInfo>,
 }
 
+/// First and last day the timeline spans, and the number of days between.
+///
+/// With no tasks and no window this is the week ending today.
+fn date_span(
+    tasks: &[TimelineTask],
+    window_start: Option<NaiveDate>,
+    window_end: Option<NaiveDate>,
+) -> (NaiveDate, NaiveDate, i64) {
+    if tasks.is_empty() && window_start.is_none() && window_end.is_none() {
+        let today = Local::now().date_naive();
+        return (today - Duration::days(7), today, 7);
+    }
+
+    // Find the date range
+    let min_start = tasks
+        .iter()
+        .map(|t| t.started_at)
+        .min()
+        .unwrap_or_else(Utc::now);
+
+    let max_end = tasks
+        .iter()
+        .map(|t| t.end_time())
+        .max()
+        .unwrap_or_else(Utc::now);
+
+    let start_date = window_start.unwrap_or_else(|| min_start.with_timezone(&Local).date_naive());
+    let end_date = window_end.unwrap_or_else(|| max_end.with_timezone(&Local).date_naive());
+
+    // Ensure at least 1 day is shown
+    let days = (end_date - start_date).num_days().max(1);
+    (start_date, end_date, days)
+}
+
 /// Information about a single column in the timeline header.
 #[derive(Debug, Clone)]
 struct ColumnInfo {
@@ -108,49 +342,29 @@ struct ColumnInfo {
 
 impl TimelineConfig {
     /// Create a new timeline config from tasks and available area.
+    #[cfg(test)]
     fn from_tasks(tasks: &[TimelineTask], area_width: u16) -> Self {
+        Self::for_window(tasks, area_width, None, None, None)
+    }
+
+    /// Create a timeline config, optionally pinned to a date window.
+    ///
+    /// Either end of the window that is `None` falls back to the earliest
+    /// start or latest end among `tasks`. Tasks reaching past the window are
+    /// clipped to its edges when drawn. The zoom level follows the span of
+    /// the dates unless `forced_zoom` overrides it.
+    fn for_window(
+        tasks: &[TimelineTask],
+        area_width: u16,
+        window_start: Option<NaiveDate>,
+        window_end: Option<NaiveDate>,
+        forced_zoom: Option<ZoomLevel>,
+    ) -> Self {
         let label_width = 30u16.min(area_width / 3);
         let bar_width = area_width.saturating_sub(label_width).saturating_sub(1);
 
-        if tasks.is_empty() {
-            let today = Local::now().date_naive();
-            let start_date = today - Duration::days(7);
-            let end_date = today;
-            let days = 7;
-            let zoom_level = ZoomLevel::Days;
-            let columns = Self::calculate_columns(start_date, end_date, bar_width, zoom_level);
-            return Self {
-                start_date,
-                end_date,
-                days,
-                bar_width,
-                label_width,
-                zoom_level,
-                columns,
-            };
-        }
-
-        // Find the date range
-        let min_start = tasks
-            .iter()
-            .map(|t| t.started_at)
-            .min()
-            .unwrap_or_else(Utc::now);
-
-        let max_end = tasks
-            .iter()
-            .map(|t| t.end_time())
-            .max()
-            .unwrap_or_else(Utc::now);
-
-        let start_date = min_start.with_timezone(&Local).date_naive();
-        let end_date = max_end.with_timezone(&Local).date_naive();
-
-        // Ensure at least 1 day is shown
-        let days = (end_date - start_date).num_days().max(1);
-
-        // Determine zoom level based on date range
-        let zoom_level = ZoomLevel::from_days(days);
+        let (start_date, end_date, days) = date_span(tasks, window_start, window_end);
+        let zoom_level = forced_zoom.unwrap_or_else(|| ZoomLevel::from_days(days));
 
         // Calculate column boundaries
         let columns = Self::calculate_columns(start_date, end_date, bar_width, zoom_level);
@@ -325,6 +539,151 @@ impl TimelineConfig {
         let ratio = days_from_start as f64 / self.days as f64;
         (ratio * self.bar_width as f64).clamp(0.0, self.bar_width as f64 - 1.0) as u16
     }
+
+    /// Visible bar column of today, or `None` when today is outside the
+    /// timeline or scrolled out of view.
+    fn today_column(&self, horizontal_offset: u16) -> Option<usize> {
+        let today = Local::now().date_naive();
+        if today < self.start_date || today > self.end_date {
+            return None;
+        }
+        let bar_width = self.bar_width as usize;
+        let scroll_chars = ((horizontal_offset as usize) * bar_width) / 100;
+        (self.date_to_column(Utc::now()) as usize)
+            .checked_sub(scroll_chars)
+            .filter(|column| *column < bar_width)
+    }
+}
+
+/// Character drawn on the header row at today's column.
+const TODAY_HEADER_CHAR: char = '\u{25BC}'; // ▼
+
+/// Character drawn on the separator and task rows at today's column.
+const TODAY_LINE_CHAR: char = '\u{2502}'; // │
+
+/// Replace the character at `position` in `line` with `marker` in `style`,
+/// splitting the span it falls in.
+fn mark_column(line: Line<'static>, position: usize, marker: char, style: Style) -> Line<'static> {
+    let mut spans = Vec::with_capacity(line.spans.len() + 2);
+    let mut offset = 0;
+    for span in line.spans {
+        let len = span.content.chars().count();
+        if (offset..offset + len).contains(&position) {
+            let at = position - offset;
+            let before: String = span.content.chars().take(at).collect();
+            let after: String = span.content.chars().skip(at + 1).collect();
+            if !before.is_empty() {
+                spans.push(Span::styled(before, span.style));
+            }
+            spans.push(Span::styled(marker.to_string(), style));
+            if !after.is_empty() {
+                spans.push(Span::styled(after, span.style));
+            }
+        } else {
+            spans.push(span);
+        }
+        offset += len;
+    }
+    Line::from(spans)
+}
+
+/// The character at `position` in `line`, if the line is that long.
+fn char_at(line: &Line<'_>, position: usize) -> Option<char> {
+    line.spans
+        .iter()
+        .flat_map(|span| span.content.chars())
+        .nth(position)
+}
+
+/// Dependency edges between the given tasks, from dependent to blocker.
+fn timeline_edges(tasks: &[TimelineTask]) -> Vec<DependencyEdge> {
+    tasks
+        .iter()
+        .flat_map(|task| {
+            task.depends_on.iter().map(|blocker| DependencyEdge {
+                from_id: task.id.clone(),
+                to_id: blocker.clone(),
+            })
+        })
+        .collect()
+}
+
+/// Draw connectors from a blocker's bar end to its dependent's bar start.
+///
+/// `rows` holds one line per task, in the same order as `tasks`. Only
+/// blockers and dependents on adjacent rows are connected, and only when
+/// the dependent starts after the blocker's bar ends; the connector turns
+/// the corner on the blocker's row and runs along the dependent's row to
+/// an arrowhead before its bar. Cells already drawn on are left alone.
+fn draw_dependency_arrows(
+    rows: &mut [Line<'static>],
+    tasks: &[TimelineTask],
+    config: &TimelineConfig,
+    horizontal_offset: u16,
+    theme: &Theme,
+) {
+    let bar_width = config.bar_width as usize;
+    let scroll_chars = ((horizontal_offset as usize) * bar_width) / 100;
+    let index: HashMap<&str, usize> = tasks
+        .iter()
+        .enumerate()
+        .filter(|(_, task)| !task.is_rollup)
+        .map(|(i, task)| (task.id.as_str(), i))
+        .collect();
+
+    for edge in timeline_edges(tasks) {
+        let (Some(&dependent), Some(&blocker)) = (
+            index.get(edge.from_id.as_str()),
+            index.get(edge.to_id.as_str()),
+        ) else {
+            continue;
+        };
+        if dependent.abs_diff(blocker) != 1 {
+            continue;
+        }
+
+        let blocker_task = &tasks[blocker];
+        let dependent_task = &tasks[dependent];
+        // First cell after the blocker's bar, as drawn by build_task_line
+        let start_col = config.date_to_column(blocker_task.started_at) as usize;
+        let corner = (config.date_to_column(blocker_task.end_time()) as usize).max(start_col + 1);
+        let target = config.date_to_column(dependent_task.started_at) as usize;
+        if target <= corner {
+            continue;
+        }
+
+        let style = Style::default().fg(dependent_task
+            .dependency_group
+            .map_or(Color::DarkGray, |group| {
+                get_dependency_group_color(group, theme)
+            }));
+        let below = dependent > blocker;
+        let (blocker_corner, dependent_corner) = if below {
+            ('\u{2510}', '\u{2514}') // ┐ └
+        } else {
+            ('\u{2518}', '\u{250C}') // ┘ ┌
+        };
+
+        let mut cells = vec![
+            (blocker, corner, blocker_corner),
+            (dependent, corner, dependent_corner),
+        ];
+        cells.extend((corner + 1..target - 1).map(|col| (dependent, col, '\u{2500}'))); // ─
+        if target - 1 > corner {
+            cells.push((dependent, target - 1, '\u{25BA}')); // ►
+        }
+
+        for (row, col, glyph) in cells {
+            let Some(visible) = col.checked_sub(scroll_chars).filter(|c| *c < bar_width) else {
+                continue;
+            };
+            let position = config.label_width as usize + visible;
+            if char_at(&rows[row], position) == Some(' ') {
+                let line = std::mem::take(&mut rows[row]);
+                rows[row] = mark_column(line, position, glyph, style);
+            }
+        }
+    }
 }
 
 /// Color palette for dependency groups.
@@ -346,8 +705,133 @@ const DEPENDENCY_GROUP_COLORS: [Color; 8] = [
 /// Get the color for a dependency group.
 ///
 /// Colors cycle through the palette if there are more groups than colors.
-fn get_dependency_group_color(group_id: usize) -> Color {
-    DEPENDENCY_GROUP_COLORS[group_id % DEPENDENCY_GROUP_COLORS.len()]
+/// A theme palette replaces the built-in one.
+fn get_dependency_group_color(group_id: usize, theme: &Theme) -> Color {
+    let palette = theme
+        .dependency_palette()
+        .unwrap_or(&DEPENDENCY_GROUP_COLORS);
+    palette[group_id % palette.len()]
+}
+
+/// One row of the dependency-group color key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupLegendEntry {
+    /// The dependency group this entry describes.
+    pub group: usize,
+    /// The color bars in this group are drawn with.
+    pub color: Color,
+    /// Title of a representative task from the group.
+    pub title: String,
+}
+
+/// Build the color key for the dependency groups present in `tasks`.
+///
+/// Groups are listed in the order they first appear, and each uses the
+/// title of its first task as the representative. Tasks without a group
+/// (including rollup bars) are not part of the key.
+pub fn group_legend_entries(tasks: &[TimelineTask], theme: &Theme) -> Vec<GroupLegendEntry> {
+    let mut seen = HashSet::new();
+
+    tasks
+        .iter()
+        .filter(|task| !task.is_rollup)
+        .filter_map(|task| {
+            let group = task.dependency_group?;
+            seen.insert(group).then(|| GroupLegendEntry {
+                group,
+                color: get_dependency_group_color(group, theme),
+                title: task.title.clone(),
+            })
+        })
+        .collect()
+}
+
+/// Longest representative title shown in the color key before truncation.
+const LEGEND_TITLE_WIDTH: usize = 24;
+
+/// Narrowest the bars may get when the key is drawn beside them.
+const MIN_BARS_WIDTH_WITH_LEGEND: u16 = 40;
+
+/// Title of the color key box.
+const LEGEND_TITLE: &str = " Groups ";
+
+/// Where the color key is drawn inside the timeline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LegendPlacement {
+    /// In the bottom-right corner, in rows the bars don't use.
+    Below(Rect),
+    /// In the bottom-right corner, with the bars narrowed to make room.
+    Beside(Rect),
+}
+
+/// Decide where a `width` x `height` color key fits without covering bars.
+///
+/// Prefers empty rows below the bars; otherwise takes a column on the right
+/// if the bars keep at least `MIN_BARS_WIDTH_WITH_LEGEND` columns. Returns
+/// `None` when neither fits, in which case the key is not drawn.
+fn place_legend(inner: Rect, used_rows: u16, width: u16, height: u16) -> Option<LegendPlacement> {
+    if width > inner.width || height > inner.height {
+        return None;
+    }
+
+    let corner = Rect::new(
+        inner.right() - width,
+        inner.bottom() - height,
+        width,
+        height,
+    );
+
+    if inner.height.saturating_sub(used_rows) >= height {
+        Some(LegendPlacement::Below(corner))
+    } else if inner.width >= width + 1 + MIN_BARS_WIDTH_WITH_LEGEND {
+        Some(LegendPlacement::Beside(corner))
+    } else {
+        None
+    }
+}
+
+/// Size of the boxed color key for `entries`, including borders.
+fn legend_size(entries: &[GroupLegendEntry]) -> (u16, u16) {
+    let title_width = entries
+        .iter()
+        .map(|entry| entry.title.chars().count().min(LEGEND_TITLE_WIDTH))
+        .max()
+        .unwrap_or(0);
+    // "■ " marker plus the title, or the box title if that is wider
+    let width = (title_width + 2).max(LEGEND_TITLE.len()) + 2;
+    (width as u16, entries.len() as u16 + 2)
+}
+
+/// Build the lines of the color key: a colored marker and the title.
+fn build_legend_lines(entries: &[GroupLegendEntry]) -> Vec<Line<'static>> {
+    entries
+        .iter()
+        .map(|entry| {
+            let title = if entry.title.chars().count() > LEGEND_TITLE_WIDTH {
+                let kept: String = entry.title.chars().take(LEGEND_TITLE_WIDTH - 3).collect();
+                format!("{}...", kept)
+            } else {
+                entry.title.clone()
+            };
+            Line::from(vec![
+                Span::styled("\u{25A0} ", Style::default().fg(entry.color)),
+                Span::styled(title, Style::default().fg(Color::White)),
+            ])
+        })
+        .collect()
+}
+
+/// Render the boxed color key into `area`, clearing what was there.
+fn render_legend(frame: &mut Frame, area: Rect, entries: &[GroupLegendEntry]) {
+    let block = Block::default()
+        .title(LEGEND_TITLE)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::DarkGray));
+    frame.render_widget(Clear, area);
+    frame.render_widget(
+        Paragraph::new(build_legend_lines(entries)).block(block),
+        area,
+    );
 }
 
 /// Dependency edge type for building the dependency graph.
@@ -453,7 +937,7 @@ pub fn compute_dependency_groups(
 }
 
 /// State for the timeline view, encapsulating scroll and selection.
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct TimelineViewState {
     /// Whether this panel currently has focus.
     pub is_focused: bool,
@@ -463,6 +947,118 @@ pub struct TimelineViewState {
     pub horizontal_offset: u16,
     /// Index of the selected task for highlighting.
     pub selected_index: usize,
+    /// First day shown, instead of the earliest task start.
+    pub window_start: Option<NaiveDate>,
+    /// Last day shown, instead of the latest task end.
+    pub window_end: Option<NaiveDate>,
+    /// Zoom level chosen with the keyboard, instead of one fitting the span.
+    pub forced_zoom: Option<ZoomLevel>,
+    /// Which tasks are shown by status.
+    pub status_filter: StatusFilter,
+    /// Only show tasks with this tag.
+    pub tag_filter: Option<String>,
+    /// Whether to draw connectors between dependent tasks on adjacent rows.
+    pub show_dependency_arrows: bool,
+}
+
+impl TimelineViewState {
+    /// Limit the view to a preset window ending on `today`.
+    ///
+    /// [`TimelineWindow::All`] clears the window.
+    pub fn set_window(&mut self, window: TimelineWindow, today: NaiveDate) {
+        let range = window.range(today);
+        self.window_start = range.map(|(start, _)| start);
+        self.window_end = range.map(|(_, end)| end);
+    }
+
+    /// The zoom level `tasks` are drawn at: the forced one, or the one
+    /// fitting their span within the window.
+    pub fn zoom_level(&self, tasks: &[TimelineTask]) -> ZoomLevel {
+        self.forced_zoom.unwrap_or_else(|| {
+            let (_, _, days) = date_span(tasks, self.window_start, self.window_end);
+            ZoomLevel::from_days(days)
+        })
+    }
+
+    /// Force the next finer zoom level than the current one.
+    pub fn zoom_in(&mut self, tasks: &[TimelineTask]) {
+        self.forced_zoom = Some(self.zoom_level(tasks).finer());
+    }
+
+    /// Force the next coarser zoom level than the current one.
+    pub fn zoom_out(&mut self, tasks: &[TimelineTask]) {
+        self.forced_zoom = Some(self.zoom_level(tasks).coarser());
+    }
+
+    /// Go back to picking the zoom level from the date span.
+    pub fn auto_zoom(&mut self) {
+        self.forced_zoom = None;
+    }
+
+    /// Keep the tasks passing the status and tag filters.
+    ///
+    /// Dependency groups are recomputed over what is left, so group colors
+    /// are assigned only among the tasks actually shown.
+    pub fn filter_tasks(&self, tasks: &[TimelineTask]) -> Vec<TimelineTask> {
+        let mut shown: Vec<TimelineTask> = tasks
+            .iter()
+            .filter(|task| self.status_filter.matches(&task.status))
+            .filter(|task| {
+                self.tag_filter
+                    .as_ref()
+                    .is_none_or(|tag| task.tags.contains(tag))
+            })
+            .cloned()
+            .collect();
+
+        let task_ids: Vec<String> = shown
+            .iter()
+            .filter(|task| !task.is_rollup)
+            .map(|task| task.id.clone())
+            .collect();
+        let edges = timeline_edges(&shown);
+        let groups = compute_dependency_groups(&task_ids, &edges);
+        for task in shown.iter_mut().filter(|task| !task.is_rollup) {
+            task.dependency_group = groups.get(&task.id).copied();
+        }
+        shown
+    }
+
+    /// Scroll horizontally so `date` sits in the middle of the bars, as far
+    /// as the scroll range allows.
+    ///
+    /// Returns `false`, leaving the scroll unchanged, when `date` is outside
+    /// the timeline.
+    pub fn center_on(&mut self, tasks: &[TimelineTask], date: NaiveDate) -> bool {
+        let (start, end, days) = date_span(tasks, self.window_start, self.window_end);
+        if date < start || date > end {
+            return false;
+        }
+        let percent = (date - start).num_days() * 100 / days - 50;
+        self.horizontal_offset = percent.clamp(0, 100) as u16;
+        true
+    }
+}
+
+/// Keep only the tasks that overlap the window.
+///
+/// A task overlaps when it ends on or after `window_start` and starts on or
+/// before `window_end`; an open end of the window matches everything.
+pub fn filter_to_window(
+    tasks: &[TimelineTask],
+    window_start: Option<NaiveDate>,
+    window_end: Option<NaiveDate>,
+) -> Vec<TimelineTask> {
+    tasks
+        .iter()
+        .filter(|task| {
+            let started = task.started_at.with_timezone(&Local).date_naive();
+            let ended = task.end_time().with_timezone(&Local).date_naive();
+            window_start.is_none_or(|start| ended >= start)
+                && window_end.is_none_or(|end| started <= end)
+        })
+        .cloned()
+        .collect()
 }
 
 /// Render the timeline view showing tasks on a horizontal timeline.
@@ -471,28 +1067,56 @@ pub struct TimelineViewState {
 ///
 /// * `frame` - The frame to render to
 /// * `area` - The area to render within
-/// * `tasks` - Tasks with timeline data (must have started_at set)
+/// * `tasks` - Tasks with timeline data (must have started_at set), already
+///   limited to the state's window with [`filter_to_window`]
 /// * `empty_message` - Message to show when no tasks have been started
-/// * `state` - Timeline view state (focus, scroll, selection)
-#[allow(clippy::too_many_arguments)]
+/// * `state` - Timeline view state (focus, scroll, selection, date window)
+/// * `show_legend` - Whether to draw the dependency-group color key
+/// * `theme` - Color overrides
 pub fn render_timeline_view(
     frame: &mut Frame,
     area: Rect,
     tasks: &[TimelineTask],
     empty_message: Option<&str>,
-    is_focused: bool,
-    scroll_offset: usize,
-    horizontal_offset: u16,
-    selected_index: usize,
+    state: &TimelineViewState,
+    show_legend: bool,
+    theme: &Theme,
 ) {
-    let border_color = if is_focused {
-        Color::Yellow
-    } else {
-        Color::Cyan
+    let TimelineViewState {
+        is_focused,
+        scroll_offset,
+        horizontal_offset,
+        selected_index,
+        window_start,
+        window_end,
+        forced_zoom,
+        status_filter,
+        ref tag_filter,
+        show_dependency_arrows,
+    } = *state;
+
+    let border_color = theme.border(is_focused);
+
+    let mut title = match (window_start, window_end) {
+        (None, None) => " Timeline ".to_string(),
+        (start, end) => format!(
+            " Timeline {} - {} ",
+            start.map_or("...".to_string(), |d| d.format("%Y-%m-%d").to_string()),
+            end.map_or("...".to_string(), |d| d.format("%Y-%m-%d").to_string()),
+        ),
     };
+    if let Some(zoom) = forced_zoom {
+        title.push_str(&format!("[{}] ", zoom.label()));
+    }
+    if let Some(label) = status_filter.label() {
+        title.push_str(&format!("[{}] ", label));
+    }
+    if let Some(tag) = tag_filter {
+        title.push_str(&format!("[#{}] ", tag));
+    }
 
     let block = Block::default()
-        .title(" Timeline ")
+        .title(title)
         .borders(Borders::ALL)
         .border_style(Style::default().fg(border_color));
 
@@ -505,9 +1129,34 @@ pub fn render_timeline_view(
         return;
     }
 
-    // Calculate inner area
-    let inner_width = area.width.saturating_sub(2); // Account for borders
-    let config = TimelineConfig::from_tasks(tasks, inner_width);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    // Header and separator rows plus one row per task, minus what's scrolled off
+    let used_rows = (tasks.len() + 2).saturating_sub(scroll_offset) as u16;
+
+    let legend = if show_legend {
+        let entries = group_legend_entries(tasks, theme);
+        let (width, height) = legend_size(&entries);
+        (!entries.is_empty())
+            .then(|| place_legend(inner, used_rows, width, height))
+            .flatten()
+            .map(|placement| (placement, entries))
+    } else {
+        None
+    };
+
+    // Narrow the bars when the key takes a column beside them
+    let content = match legend {
+        Some((LegendPlacement::Beside(rect), _)) => Rect {
+            width: inner.width - rect.width - 1,
+            ..inner
+        },
+        _ => inner,
+    };
+
+    let config =
+        TimelineConfig::for_window(tasks, content.width, window_start, window_end, forced_zoom);
 
     let mut lines = Vec::new();
 
@@ -523,13 +1172,38 @@ pub fn render_timeline_view(
             &config,
             is_selected,
             horizontal_offset,
+            theme,
         ));
     }
 
-    let paragraph = Paragraph::new(lines)
-        .block(block)
-        .scroll((scroll_offset as u16, 0));
-    frame.render_widget(paragraph, area);
+    if show_dependency_arrows {
+        draw_dependency_arrows(&mut lines[2..], tasks, &config, horizontal_offset, theme);
+    }
+
+    // Draw the today line over every row
+    if let Some(column) = config.today_column(horizontal_offset) {
+        let today_style = Style::default().fg(Color::Red).add_modifier(Modifier::BOLD);
+        let position = config.label_width as usize + column;
+        lines = lines
+            .into_iter()
+            .enumerate()
+            .map(|(row, line)| {
+                let marker = if row == 0 {
+                    TODAY_HEADER_CHAR
+                } else {
+                    TODAY_LINE_CHAR
+                };
+                mark_column(line, position, marker, today_style)
+            })
+            .collect();
+    }
+
+    let paragraph = Paragraph::new(lines).scroll((scroll_offset as u16, 0));
+    frame.render_widget(paragraph, content);
+
+    if let Some((LegendPlacement::Below(rect) | LegendPlacement::Beside(rect), entries)) = legend {
+        render_legend(frame, rect, &entries);
+    }
 }
 
 /// Build the date header line showing the time scale with centered labels.
@@ -656,16 +1330,19 @@ fn build_separator(config: &TimelineConfig, horizontal_offset: u16) -> Line<'sta
 /// * `config` - The timeline configuration
 /// * `is_selected` - Whether this task is currently selected
 /// * `horizontal_offset` - Horizontal scroll offset (0-100 percentage)
+/// * `theme` - Color overrides
 fn build_task_line(
     task: &TimelineTask,
     config: &TimelineConfig,
     is_selected: bool,
     horizontal_offset: u16,
+    theme: &Theme,
 ) -> Line<'static> {
     let mut spans = Vec::new();
 
-    // Build label: [status] truncated_title
+    // Build label: [status] truncated_title (rollup bars use a fixed marker)
     let status_indicator = match task.status {
+        _ if task.is_rollup => "[=]",
         Status::Done => "[x]",
         Status::InProgress => "[>]",
         Status::PendingReview => "[?]",
@@ -674,7 +1351,7 @@ fn build_task_line(
         Status::Todo => "[ ]",
     };
 
-    let status_color = get_status_color(&task.status);
+    let status_color = theme.status(&task.status, get_status_color(&task.status));
 
     // Calculate available space for title
     let status_len = status_indicator.len() + 1; // +1 for space
@@ -735,7 +1412,9 @@ fn build_task_line(
 
     // Fill the bar with appropriate character based on completion status (accounting for scroll)
     // Completed tasks get solid blocks, in-progress tasks get striped/hatched pattern
-    let bar_char = if task.completed_at.is_some() {
+    let bar_char = if task.is_rollup {
+        '\u{2550}' // Double horizontal line for parent rollup bars
+    } else if task.completed_at.is_some() {
         '\u{2588}' // Full block character for completed tasks
     } else {
         '\u{2592}' // Medium shade character for in-progress tasks (striped appearance)
@@ -759,7 +1438,7 @@ fn build_task_line(
     // Tasks with a dependency group get their group color
     // Tasks without dependencies use status-based colors
     let bar_color = match task.dependency_group {
-        Some(group_id) => get_dependency_group_color(group_id),
+        Some(group_id) => get_dependency_group_color(group_id, theme),
         None => status_color,
     };
 
@@ -779,6 +1458,11 @@ fn build_task_line(
         Style::default()
             .fg(bar_color)
             .add_modifier(Modifier::BOLD | Modifier::REVERSED)
+    } else if task.is_rollup {
+        // Rollup bars: neutral color so they don't read as a dependency group
+        Style::default()
+            .fg(Color::LightMagenta)
+            .add_modifier(Modifier::BOLD)
     } else {
         match (task.completed_at.is_some(), task.dependency_group.is_some()) {
             // In-progress with dependency group: bold
@@ -833,6 +1517,9 @@ mod tests {
             completed_at,
             has_dependencies: false,
             dependency_group: None,
+            is_rollup: false,
+            depends_on: Vec::new(),
+            tags: Vec::new(),
         }
     }
 
@@ -852,6 +1539,9 @@ mod tests {
             completed_at,
             has_dependencies: group.is_some(),
             dependency_group: group,
+            is_rollup: false,
+            depends_on: Vec::new(),
+            tags: Vec::new(),
         }
     }
 
@@ -909,6 +1599,89 @@ mod tests {
         assert!(config.days >= 4);
     }
 
+    #[test]
+    fn test_timeline_window_ranges() {
+        let today = NaiveDate::from_ymd_opt(2025, 3, 31).unwrap();
+        assert_eq!(TimelineWindow::All.range(today), None);
+        assert_eq!(
+            TimelineWindow::LastWeek.range(today),
+            Some((NaiveDate::from_ymd_opt(2025, 3, 24).unwrap(), today))
+        );
+        assert_eq!(
+            TimelineWindow::LastMonth.range(today),
+            Some((NaiveDate::from_ymd_opt(2025, 3, 1).unwrap(), today))
+        );
+        assert_eq!(TimelineWindow::LastQuarter.days(), Some(90));
+
+        let mut state = TimelineViewState::default();
+        state.set_window(TimelineWindow::LastWeek, today);
+        assert_eq!(state.window_start, NaiveDate::from_ymd_opt(2025, 3, 24));
+        assert_eq!(state.window_end, Some(today));
+        state.set_window(TimelineWindow::All, today);
+        assert_eq!((state.window_start, state.window_end), (None, None));
+    }
+
+    #[test]
+    fn test_window_changes_config_days() {
+        let tasks = vec![make_task(
+            "t1",
+            "Task",
+            Status::Done,
+            Utc.with_ymd_and_hms(2024, 6, 1, 12, 0, 0).unwrap(),
+            Some(Utc.with_ymd_and_hms(2025, 3, 30, 12, 0, 0).unwrap()),
+        )];
+        let full = TimelineConfig::from_tasks(&tasks, 100);
+        assert!(full.days > 300);
+        assert_eq!(full.zoom_level, ZoomLevel::Months);
+
+        let today = NaiveDate::from_ymd_opt(2025, 3, 31).unwrap();
+        let (start, end) = TimelineWindow::LastWeek.range(today).unwrap();
+        let config = TimelineConfig::for_window(&tasks, 100, Some(start), Some(end), None);
+        assert_eq!(config.days, 7);
+        assert_eq!(config.start_date, start);
+        assert_eq!(config.zoom_level, ZoomLevel::Days);
+        // Columns are recomputed for the window: one per day
+        assert_eq!(config.columns.len(), 8);
+        assert_eq!(config.columns[0].label, "03/24");
+
+        // The long task is clipped to the window's first column
+        assert_eq!(config.date_to_column(tasks[0].started_at), 0);
+    }
+
+    #[test]
+    fn test_window_without_tasks_still_spans_window() {
+        let start = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2025, 1, 31).unwrap();
+        let config = TimelineConfig::for_window(&[], 100, Some(start), Some(end), None);
+        assert_eq!(config.days, 30);
+        assert_eq!(config.zoom_level, ZoomLevel::Weeks);
+    }
+
+    #[test]
+    fn test_filter_to_window_excludes_tasks_outside() {
+        let at = |m, d| Utc.with_ymd_and_hms(2025, m, d, 12, 0, 0).unwrap();
+        let tasks = vec![
+            make_task("old", "Old", Status::Done, at(1, 1), Some(at(1, 5))),
+            make_task("span", "Span", Status::Done, at(2, 1), Some(at(3, 10))),
+            make_task("in", "In", Status::Done, at(3, 5), Some(at(3, 6))),
+            make_task("late", "Late", Status::Done, at(4, 2), Some(at(4, 3))),
+        ];
+        let start = NaiveDate::from_ymd_opt(2025, 3, 1);
+        let end = NaiveDate::from_ymd_opt(2025, 3, 31);
+
+        let ids =
+            |tasks: Vec<TimelineTask>| -> Vec<String> { tasks.into_iter().map(|t| t.id).collect() };
+        assert_eq!(
+            ids(filter_to_window(&tasks, start, end)),
+            vec!["span", "in"]
+        );
+        assert_eq!(
+            ids(filter_to_window(&tasks, start, None)),
+            vec!["span", "in", "late"]
+        );
+        assert_eq!(filter_to_window(&tasks, None, None).len(), 4);
+    }
+
     #[test]
     fn test_timeline_config_date_to_column() {
         let started = Utc.with_ymd_and_hms(2025, 1, 1, 10, 0, 0).unwrap();
@@ -969,7 +1742,7 @@ mod tests {
         let tasks = vec![task.clone()];
 
         let config = TimelineConfig::from_tasks(&tasks, 80);
-        let line = build_task_line(&task, &config, false, 0);
+        let line = build_task_line(&task, &config, false, 0, &Theme::default());
 
         // Line should have spans
         assert!(!line.spans.is_empty());
@@ -994,7 +1767,7 @@ mod tests {
         let tasks = vec![task.clone()];
 
         let config = TimelineConfig::from_tasks(&tasks, 80);
-        let line = build_task_line(&task, &config, false, 0);
+        let line = build_task_line(&task, &config, false, 0, &Theme::default());
 
         // Line should have spans with bold modifier for task with dependencies
         assert!(!line.spans.is_empty());
@@ -1021,7 +1794,7 @@ mod tests {
         let tasks = vec![task.clone()];
 
         let config = TimelineConfig::from_tasks(&tasks, 60);
-        let line = build_task_line(&task, &config, false, 0);
+        let line = build_task_line(&task, &config, false, 0, &Theme::default());
 
         // Should contain truncated text with ellipsis
         let text: String = line.spans.iter().map(|s| s.content.to_string()).collect();
@@ -1072,6 +1845,186 @@ mod tests {
         assert!(config.columns.len() >= 6 && config.columns.len() <= 8);
     }
 
+    #[test]
+    fn test_today_line_marks_visible_column_only() {
+        let now = Utc::now();
+        let tasks = vec![make_task(
+            "t1",
+            "Task",
+            Status::InProgress,
+            now - Duration::days(10),
+            None,
+        )];
+        let config = TimelineConfig::from_tasks(&tasks, 100);
+        let column = config.today_column(0).unwrap();
+        assert_eq!(column, config.date_to_column(now) as usize);
+        // Scrolled past today
+        assert_eq!(config.today_column(100), None);
+
+        let line = build_separator(&config, 0);
+        let position = config.label_width as usize + column;
+        let today_style = Style::default().fg(Color::Red);
+        let marked = mark_column(line, position, TODAY_LINE_CHAR, today_style);
+        let text: String = marked.spans.iter().map(|s| s.content.to_string()).collect();
+        assert_eq!(text.chars().nth(position), Some(TODAY_LINE_CHAR));
+        assert_eq!(
+            text.chars().count(),
+            position + config.bar_width as usize - column
+        );
+        let today = marked
+            .spans
+            .iter()
+            .find(|s| s.content == TODAY_LINE_CHAR.to_string())
+            .unwrap();
+        assert_eq!(today.style.fg, Some(Color::Red));
+
+        // A window that ended before today has no today line
+        let start = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2025, 1, 31).unwrap();
+        let config = TimelineConfig::for_window(&tasks, 100, Some(start), Some(end), None);
+        assert_eq!(config.today_column(0), None);
+    }
+
+    #[test]
+    fn test_center_on_scrolls_date_to_middle() {
+        let start = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2025, 4, 11).unwrap(); // 100 days
+        let mut state = TimelineViewState {
+            window_start: Some(start),
+            window_end: Some(end),
+            ..TimelineViewState::default()
+        };
+
+        assert!(state.center_on(&[], start + Duration::days(80)));
+        assert_eq!(state.horizontal_offset, 30);
+        // Near the start there is nothing to scroll
+        assert!(state.center_on(&[], start + Duration::days(10)));
+        assert_eq!(state.horizontal_offset, 0);
+        // Outside the timeline the scroll stays put
+        state.horizontal_offset = 20;
+        assert!(!state.center_on(&[], end + Duration::days(1)));
+        assert_eq!(state.horizontal_offset, 20);
+    }
+
+    #[test]
+    fn test_dependency_arrows_join_adjacent_rows_only() {
+        let day = |d: u32| Utc.with_ymd_and_hms(2025, 1, d, 0, 0, 0).unwrap();
+        let mut tasks = vec![
+            make_task("a", "A", Status::Done, day(1), Some(day(5))),
+            make_task("b", "B", Status::Done, day(10), Some(day(15))),
+            make_task("c", "C", Status::Done, day(20), Some(day(31))),
+        ];
+        tasks[1].depends_on = vec!["a".to_string()];
+        // Not adjacent to its blocker
+        tasks[2].depends_on = vec!["a".to_string()];
+        let config = TimelineConfig::from_tasks(&tasks, 100);
+        let text =
+            |line: &Line| -> String { line.spans.iter().map(|s| s.content.to_string()).collect() };
+
+        let mut rows: Vec<Line<'static>> = tasks
+            .iter()
+            .map(|task| build_task_line(task, &config, false, 0, &Theme::default()))
+            .collect();
+        let before: Vec<String> = rows.iter().map(text).collect();
+        draw_dependency_arrows(&mut rows, &tasks, &config, 0, &Theme::default());
+
+        let label = config.label_width as usize;
+        let corner = label + config.date_to_column(day(5)) as usize;
+        let target = label + config.date_to_column(day(10)) as usize;
+        let a: Vec<char> = text(&rows[0]).chars().collect();
+        let b: Vec<char> = text(&rows[1]).chars().collect();
+        assert_eq!(a[corner], '\u{2510}');
+        assert_eq!(b[corner], '\u{2514}');
+        assert!(b[corner + 1..target - 1].iter().all(|c| *c == '\u{2500}'));
+        assert_eq!(b[target - 1], '\u{25BA}');
+        assert_eq!(b[target], '\u{2588}');
+        // c's blocker is two rows up, so only the color links them
+        assert_eq!(text(&rows[2]), before[2]);
+
+        // A dependent starting before its blocker ends gets no connector
+        let mut rows: Vec<Line<'static>> = tasks
+            .iter()
+            .map(|task| build_task_line(task, &config, false, 0, &Theme::default()))
+            .collect();
+        tasks[1].started_at = day(3);
+        draw_dependency_arrows(&mut rows, &tasks, &config, 0, &Theme::default());
+        assert_eq!(text(&rows[0]), before[0]);
+    }
+
+    #[test]
+    fn test_filter_tasks_by_status_and_tag_regroups() {
+        let started = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let completed = Some(Utc.with_ymd_and_hms(2025, 1, 5, 0, 0, 0).unwrap());
+        let mut tasks = vec![
+            make_task("a", "A", Status::Done, started, completed),
+            make_task("b", "B", Status::InProgress, started, None),
+            make_task("c", "C", Status::InProgress, started, None),
+            make_task("d", "D", Status::InProgress, started, None),
+        ];
+        // a <- b and c <- d are two separate chains
+        tasks[1].depends_on = vec!["a".to_string()];
+        tasks[3].depends_on = vec!["c".to_string()];
+        tasks[2].tags = vec!["api".to_string()];
+        tasks[3].tags = vec!["api".to_string()];
+
+        let mut state = TimelineViewState::default();
+        assert_eq!(state.filter_tasks(&tasks).len(), 4);
+
+        state.status_filter = StatusFilter::HideDone;
+        let shown = state.filter_tasks(&tasks);
+        let ids: Vec<&str> = shown.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(ids, vec!["b", "c", "d"]);
+        // b still depends on a, which is hidden, so it keeps a group of its own
+        let groups: Vec<Option<usize>> = shown.iter().map(|t| t.dependency_group).collect();
+        assert_eq!(groups, vec![Some(0), Some(1), Some(1)]);
+
+        state.status_filter = StatusFilter::DoneOnly;
+        assert_eq!(state.filter_tasks(&tasks).len(), 1);
+
+        state.status_filter = StatusFilter::All;
+        state.tag_filter = Some("api".to_string());
+        let shown = state.filter_tasks(&tasks);
+        let ids: Vec<&str> = shown.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(ids, vec!["c", "d"]);
+        // Groups are numbered among the shown tasks only
+        assert_eq!(shown[0].dependency_group, Some(0));
+
+        assert_eq!(StatusFilter::All.next().next().next(), StatusFilter::All);
+    }
+
+    #[test]
+    fn test_forced_zoom_overrides_span() {
+        let started = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let completed = Utc.with_ymd_and_hms(2025, 1, 31, 0, 0, 0).unwrap();
+        let tasks = vec![make_task(
+            "t1",
+            "Task",
+            Status::Done,
+            started,
+            Some(completed),
+        )];
+
+        let mut state = TimelineViewState::default();
+        assert_eq!(state.zoom_level(&tasks), ZoomLevel::Weeks);
+
+        state.zoom_in(&tasks);
+        assert_eq!(state.forced_zoom, Some(ZoomLevel::Days));
+        state.zoom_in(&tasks);
+        assert_eq!(state.forced_zoom, Some(ZoomLevel::Days));
+        let config = TimelineConfig::for_window(&tasks, 100, None, None, state.forced_zoom);
+        assert_eq!(config.zoom_level, ZoomLevel::Days);
+        // One column per day instead of per week
+        assert!(config.columns.len() > 5, "got {}", config.columns.len());
+
+        state.zoom_out(&tasks);
+        state.zoom_out(&tasks);
+        state.zoom_out(&tasks);
+        assert_eq!(state.forced_zoom, Some(ZoomLevel::Months));
+
+        state.auto_zoom();
+        assert_eq!(state.zoom_level(&tasks), ZoomLevel::Weeks);
+    }
+
     #[test]
     fn test_timeline_config_30_days_uses_week_zoom() {
         let started = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
@@ -1300,8 +2253,9 @@ mod tests {
         let tasks_for_config = vec![completed_task.clone()];
         let config = TimelineConfig::from_tasks(&tasks_for_config, 100);
 
-        let completed_line = build_task_line(&completed_task, &config, false, 0);
-        let in_progress_line = build_task_line(&in_progress_task, &config, false, 0);
+        let completed_line = build_task_line(&completed_task, &config, false, 0, &Theme::default());
+        let in_progress_line =
+            build_task_line(&in_progress_task, &config, false, 0, &Theme::default());
 
         // Extract the bar portions (the spans containing block characters)
         let get_bar_content = |line: &Line| -> String {
@@ -1344,7 +2298,7 @@ mod tests {
         let tasks = vec![task.clone()];
 
         let config = TimelineConfig::from_tasks(&tasks, 100);
-        let line = build_task_line(&task, &config, false, 0);
+        let line = build_task_line(&task, &config, false, 0, &Theme::default());
 
         // Extract full text from line
         let text: String = line.spans.iter().map(|s| s.content.to_string()).collect();
@@ -1385,8 +2339,8 @@ mod tests {
         let config = TimelineConfig::from_tasks(&tasks, 100);
 
         // Each task generates its own line, so they're naturally on different rows
-        let line1 = build_task_line(&task1, &config, false, 0);
-        let line2 = build_task_line(&task2, &config, false, 0);
+        let line1 = build_task_line(&task1, &config, false, 0, &Theme::default());
+        let line2 = build_task_line(&task2, &config, false, 0, &Theme::default());
 
         // Extract task IDs to verify they're different lines
         let text1: String = line1.spans.iter().map(|s| s.content.to_string()).collect();
@@ -1412,7 +2366,7 @@ mod tests {
         let tasks = vec![task.clone()];
 
         let config = TimelineConfig::from_tasks(&tasks, 100);
-        let line = build_task_line(&task, &config, false, 0);
+        let line = build_task_line(&task, &config, false, 0, &Theme::default());
 
         let bar_content: String = line
             .spans
@@ -1437,8 +2391,8 @@ mod tests {
         let task = make_task("t1", "Working", Status::InProgress, started, None);
 
         // Need a config with a reasonable width
-        let config = TimelineConfig::from_tasks(&[task.clone()], 100);
-        let line = build_task_line(&task, &config, false, 0);
+        let config = TimelineConfig::from_tasks(std::slice::from_ref(&task), 100);
+        let line = build_task_line(&task, &config, false, 0, &Theme::default());
 
         let bar_content: String = line
             .spans
@@ -1462,8 +2416,8 @@ mod tests {
         let started = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
         let task = make_task("t1", "Working", Status::InProgress, started, None);
 
-        let config = TimelineConfig::from_tasks(&[task.clone()], 100);
-        let line = build_task_line(&task, &config, false, 0);
+        let config = TimelineConfig::from_tasks(std::slice::from_ref(&task), 100);
+        let line = build_task_line(&task, &config, false, 0, &Theme::default());
 
         // Find the span with the bar (shade character)
         let bar_span = line
@@ -1495,11 +2449,19 @@ mod tests {
     #[test]
     fn test_dependency_group_colors_cycle() {
         // Colors should cycle for groups beyond palette size
-        let color_0 = get_dependency_group_color(0);
-        let color_8 = get_dependency_group_color(8);
+        let color_0 = get_dependency_group_color(0, &Theme::default());
+        let color_8 = get_dependency_group_color(8, &Theme::default());
         assert_eq!(color_0, color_8, "Colors should cycle after palette size");
     }
 
+    #[test]
+    fn test_dependency_group_colors_use_theme_palette() {
+        let theme = Theme::parse("[dependencies]\npalette = [\"blue\", \"red\"]").unwrap();
+        assert_eq!(get_dependency_group_color(0, &theme), Color::Blue);
+        assert_eq!(get_dependency_group_color(1, &theme), Color::Red);
+        assert_eq!(get_dependency_group_color(2, &theme), Color::Blue);
+    }
+
     #[test]
     fn test_compute_dependency_groups_empty() {
         // Empty inputs should return empty map
@@ -1662,8 +2624,8 @@ mod tests {
             Some(0),
         );
 
-        let config = TimelineConfig::from_tasks(&[task.clone()], 100);
-        let line = build_task_line(&task, &config, false, 0);
+        let config = TimelineConfig::from_tasks(std::slice::from_ref(&task), 100);
+        let line = build_task_line(&task, &config, false, 0, &Theme::default());
 
         // Find the bar span (contains block character)
         let bar_span = line
@@ -1695,8 +2657,8 @@ mod tests {
             Some(completed),
         );
 
-        let config = TimelineConfig::from_tasks(&[task.clone()], 100);
-        let line = build_task_line(&task, &config, false, 0);
+        let config = TimelineConfig::from_tasks(std::slice::from_ref(&task), 100);
+        let line = build_task_line(&task, &config, false, 0, &Theme::default());
 
         // Find the bar span
         let bar_span = line
@@ -1733,8 +2695,8 @@ mod tests {
         let tasks = vec![task1.clone(), task2.clone()];
         let config = TimelineConfig::from_tasks(&tasks, 100);
 
-        let line1 = build_task_line(&task1, &config, false, 0);
-        let line2 = build_task_line(&task2, &config, false, 0);
+        let line1 = build_task_line(&task1, &config, false, 0, &Theme::default());
+        let line2 = build_task_line(&task2, &config, false, 0, &Theme::default());
 
         // Get bar colors
         let get_bar_color = |line: &Line| -> Option<Color> {
@@ -1780,8 +2742,8 @@ mod tests {
         let tasks = vec![task1.clone(), task2.clone()];
         let config = TimelineConfig::from_tasks(&tasks, 100);
 
-        let line1 = build_task_line(&task1, &config, false, 0);
-        let line2 = build_task_line(&task2, &config, false, 0);
+        let line1 = build_task_line(&task1, &config, false, 0, &Theme::default());
+        let line2 = build_task_line(&task2, &config, false, 0, &Theme::default());
 
         // Get bar colors
         let get_bar_color = |line: &Line| -> Option<Color> {
@@ -1819,12 +2781,12 @@ mod tests {
             Some(completed),
         );
 
-        let config = TimelineConfig::from_tasks(&[task.clone()], 100);
+        let config = TimelineConfig::from_tasks(std::slice::from_ref(&task), 100);
 
         // Build line for selected task
-        let selected_line = build_task_line(&task, &config, true, 0);
+        let selected_line = build_task_line(&task, &config, true, 0, &Theme::default());
         // Build line for non-selected task
-        let normal_line = build_task_line(&task, &config, false, 0);
+        let normal_line = build_task_line(&task, &config, false, 0, &Theme::default());
 
         // Extract text to verify selection indicator
         let selected_text: String = selected_line
@@ -1857,8 +2819,8 @@ mod tests {
         let completed = Utc.with_ymd_and_hms(2025, 1, 5, 0, 0, 0).unwrap();
         let task = make_task("t1", "Task", Status::Done, started, Some(completed));
 
-        let config = TimelineConfig::from_tasks(&[task.clone()], 100);
-        let selected_line = build_task_line(&task, &config, true, 0);
+        let config = TimelineConfig::from_tasks(std::slice::from_ref(&task), 100);
+        let selected_line = build_task_line(&task, &config, true, 0, &Theme::default());
 
         // Find the bar span (contains block character)
         let bar_span = selected_line
@@ -1881,11 +2843,11 @@ mod tests {
         let completed = Utc.with_ymd_and_hms(2025, 1, 10, 0, 0, 0).unwrap();
         let task = make_task("t1", "Task", Status::Done, started, Some(completed));
 
-        let config = TimelineConfig::from_tasks(&[task.clone()], 100);
+        let config = TimelineConfig::from_tasks(std::slice::from_ref(&task), 100);
 
         // Build lines with different scroll offsets
-        let line_no_scroll = build_task_line(&task, &config, false, 0);
-        let line_scrolled = build_task_line(&task, &config, false, 50);
+        let line_no_scroll = build_task_line(&task, &config, false, 0, &Theme::default());
+        let line_scrolled = build_task_line(&task, &config, false, 50, &Theme::default());
 
         // Both should have bars, but in different positions
         let count_bar_chars = |line: &Line| -> usize {
@@ -1975,4 +2937,224 @@ mod tests {
             "Scrolled separator should have spans"
         );
     }
+
+    fn make_parent(id: &str, child_ids: &[&str]) -> RollupParent {
+        RollupParent {
+            id: id.to_string(),
+            title: format!("Epic {}", id),
+            status: Status::InProgress,
+            child_ids: child_ids.iter().map(|c| c.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_compute_rollup_span_empty() {
+        assert!(compute_rollup_span(&[]).is_none());
+    }
+
+    #[test]
+    fn test_compute_rollup_span_all_completed() {
+        let t1 = make_task(
+            "a",
+            "A",
+            Status::Done,
+            Utc.with_ymd_and_hms(2025, 1, 3, 0, 0, 0).unwrap(),
+            Some(Utc.with_ymd_and_hms(2025, 1, 5, 0, 0, 0).unwrap()),
+        );
+        let t2 = make_task(
+            "b",
+            "B",
+            Status::Done,
+            Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap(),
+            Some(Utc.with_ymd_and_hms(2025, 1, 4, 0, 0, 0).unwrap()),
+        );
+        let t3 = make_task(
+            "c",
+            "C",
+            Status::Done,
+            Utc.with_ymd_and_hms(2025, 1, 2, 0, 0, 0).unwrap(),
+            Some(Utc.with_ymd_and_hms(2025, 1, 10, 0, 0, 0).unwrap()),
+        );
+
+        let (start, end) = compute_rollup_span(&[&t1, &t2, &t3]).unwrap();
+        assert_eq!(start, Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap());
+        assert_eq!(
+            end,
+            Some(Utc.with_ymd_and_hms(2025, 1, 10, 0, 0, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_compute_rollup_span_open_when_child_in_progress() {
+        let done = make_task(
+            "a",
+            "A",
+            Status::Done,
+            Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap(),
+            Some(Utc.with_ymd_and_hms(2025, 1, 5, 0, 0, 0).unwrap()),
+        );
+        let running = make_task(
+            "b",
+            "B",
+            Status::InProgress,
+            Utc.with_ymd_and_hms(2025, 1, 4, 0, 0, 0).unwrap(),
+            None,
+        );
+
+        let (start, end) = compute_rollup_span(&[&done, &running]).unwrap();
+        assert_eq!(start, Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap());
+        assert!(end.is_none(), "Span should stay open while a child runs");
+    }
+
+    #[test]
+    fn test_build_rollup_tasks_skips_parents_without_started_children() {
+        let tasks = vec![make_task(
+            "a",
+            "A",
+            Status::Done,
+            Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap(),
+            Some(Utc.with_ymd_and_hms(2025, 1, 2, 0, 0, 0).unwrap()),
+        )];
+        let parents = vec![make_parent("e1", &["a"]), make_parent("e2", &["zzz"])];
+
+        let rollups = build_rollup_tasks(&tasks, &parents);
+        assert_eq!(rollups.len(), 1);
+        assert_eq!(rollups[0].id, "e1");
+        assert!(rollups[0].is_rollup);
+        assert_eq!(rollups[0].status, Status::InProgress);
+    }
+
+    #[test]
+    fn test_merge_rollup_tasks_places_rollup_before_first_child() {
+        let tasks = vec![
+            make_task(
+                "a",
+                "A",
+                Status::Done,
+                Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap(),
+                Some(Utc.with_ymd_and_hms(2025, 1, 2, 0, 0, 0).unwrap()),
+            ),
+            make_task(
+                "b",
+                "B",
+                Status::InProgress,
+                Utc.with_ymd_and_hms(2025, 1, 3, 0, 0, 0).unwrap(),
+                None,
+            ),
+        ];
+        let rollups = build_rollup_tasks(&tasks, &[make_parent("e1", &["a", "b"])]);
+        let merged = merge_rollup_tasks(tasks, rollups);
+
+        let ids: Vec<&str> = merged.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(ids, vec!["e1", "a", "b"]);
+    }
+
+    #[test]
+    fn test_build_task_line_rollup_uses_double_line() {
+        let start = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let mut task = make_task(
+            "e1",
+            "Epic",
+            Status::InProgress,
+            start,
+            Some(start + Duration::days(3)),
+        );
+        task.is_rollup = true;
+        let config = TimelineConfig::from_tasks(std::slice::from_ref(&task), 100);
+
+        let line = build_task_line(&task, &config, false, 0, &Theme::default());
+        let text: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
+        assert!(text.contains("[=]"));
+        assert!(text.contains('\u{2550}'));
+        assert!(!text.contains('\u{2588}'));
+    }
+
+    #[test]
+    fn test_group_legend_entries_one_per_group() {
+        let start = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let mut rollup = make_task("e1", "Epic", Status::InProgress, start, None);
+        rollup.is_rollup = true;
+        let tasks = vec![
+            rollup,
+            make_task_with_group("a1", "Schema", Status::Done, start, Some(start), Some(2)),
+            make_task("solo", "Isolated", Status::InProgress, start, None),
+            make_task_with_group("b1", "Login", Status::Done, start, Some(start), Some(0)),
+            make_task_with_group("a2", "Migrations", Status::InProgress, start, None, Some(2)),
+            make_task_with_group("b2", "Logout", Status::InProgress, start, None, Some(0)),
+        ];
+
+        let entries = group_legend_entries(&tasks, &Theme::default());
+
+        assert_eq!(
+            entries,
+            vec![
+                GroupLegendEntry {
+                    group: 2,
+                    color: get_dependency_group_color(2, &Theme::default()),
+                    title: "Schema".to_string(),
+                },
+                GroupLegendEntry {
+                    group: 0,
+                    color: get_dependency_group_color(0, &Theme::default()),
+                    title: "Login".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_group_legend_entries_empty_without_groups() {
+        let start = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let tasks = vec![make_task("t1", "Task", Status::InProgress, start, None)];
+        assert!(group_legend_entries(&tasks, &Theme::default()).is_empty());
+    }
+
+    #[test]
+    fn test_build_legend_lines_truncates_long_titles() {
+        let entries = vec![GroupLegendEntry {
+            group: 0,
+            color: Color::Cyan,
+            title: "A very long representative task title".to_string(),
+        }];
+
+        let lines = build_legend_lines(&entries);
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].spans[0].style.fg, Some(Color::Cyan));
+        assert_eq!(
+            lines[0].spans[1].content.chars().count(),
+            LEGEND_TITLE_WIDTH
+        );
+
+        let (width, height) = legend_size(&entries);
+        assert_eq!(width as usize, LEGEND_TITLE_WIDTH + 4);
+        assert_eq!(height, 3);
+    }
+
+    #[test]
+    fn test_place_legend_prefers_free_rows_below_bars() {
+        let inner = Rect::new(1, 1, 100, 20);
+        let placement = place_legend(inner, 10, 20, 5);
+        assert_eq!(
+            placement,
+            Some(LegendPlacement::Below(Rect::new(81, 16, 20, 5)))
+        );
+    }
+
+    #[test]
+    fn test_place_legend_moves_beside_bars_when_rows_are_full() {
+        let inner = Rect::new(1, 1, 100, 20);
+        let placement = place_legend(inner, 18, 20, 5);
+        assert_eq!(
+            placement,
+            Some(LegendPlacement::Beside(Rect::new(81, 16, 20, 5)))
+        );
+    }
+
+    #[test]
+    fn test_place_legend_hidden_when_space_is_tight() {
+        // No free rows and too narrow to give up a column
+        assert_eq!(place_legend(Rect::new(0, 0, 50, 20), 20, 20, 5), None);
+        // Taller than the whole view
+        assert_eq!(place_legend(Rect::new(0, 0, 100, 4), 0, 20, 5), None);
+    }
 }
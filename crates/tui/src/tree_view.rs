@@ -13,6 +13,7 @@ use ratatui::{
 use vertebrae_db::{Level, Status};
 
 use crate::navigation::TreeNode;
+use crate::theme::Theme;
 
 /// Visual prefix characters for tree structure.
 mod tree_chars {
@@ -49,6 +50,7 @@ mod level_icons {
 /// * `empty_message` - Message to show when no tasks exist
 /// * `is_focused` - Whether this panel currently has focus
 /// * `scroll_offset` - Vertical scroll offset for the content
+/// * `theme` - Color overrides
 pub fn render_tree_view(
     frame: &mut Frame,
     area: Rect,
@@ -56,12 +58,9 @@ pub fn render_tree_view(
     empty_message: Option<&str>,
     is_focused: bool,
     scroll_offset: usize,
+    theme: &Theme,
 ) {
-    let border_color = if is_focused {
-        Color::Yellow
-    } else {
-        Color::Cyan
-    };
+    let border_color = theme.border(is_focused);
 
     let block = Block::default()
         .title(" Tree ")
@@ -78,7 +77,7 @@ pub fn render_tree_view(
     }
 
     // Build the tree lines
-    let lines = build_tree_lines(tree_roots);
+    let lines = build_tree_lines(tree_roots, theme);
 
     let paragraph = Paragraph::new(lines)
         .block(block)
@@ -89,12 +88,12 @@ pub fn render_tree_view(
 }
 
 /// Build all lines for the tree view.
-fn build_tree_lines(roots: &[TreeNode]) -> Vec<Line<'static>> {
+fn build_tree_lines(roots: &[TreeNode], theme: &Theme) -> Vec<Line<'static>> {
     let mut lines = Vec::new();
 
     for (i, root) in roots.iter().enumerate() {
         let is_last = i == roots.len() - 1;
-        build_node_lines(&mut lines, root, Vec::new(), is_last);
+        build_node_lines(&mut lines, root, Vec::new(), is_last, theme);
     }
 
     lines
@@ -108,17 +107,19 @@ fn build_tree_lines(roots: &[TreeNode]) -> Vec<Line<'static>> {
 /// * `node` - The current node to render
 /// * `prefix_parts` - The prefix parts for indentation (each part is either VERTICAL or SPACE)
 /// * `is_last` - Whether this node is the last sibling
+/// * `theme` - Color overrides
 fn build_node_lines(
     lines: &mut Vec<Line<'static>>,
     node: &TreeNode,
     prefix_parts: Vec<bool>,
     is_last: bool,
+    theme: &Theme,
 ) {
     // Build the prefix string from parts
     let prefix = build_prefix(&prefix_parts, is_last);
 
     // Build the node line
-    let line = build_node_line(&prefix, node);
+    let line = build_node_line(&prefix, node, theme);
     lines.push(line);
 
     // Process children
@@ -132,7 +133,7 @@ fn build_node_lines(
             // Add whether current node is NOT last (determines if we draw vertical line)
             child_prefix_parts.push(!is_last);
 
-            build_node_lines(lines, child, child_prefix_parts, child_is_last);
+            build_node_lines(lines, child, child_prefix_parts, child_is_last, theme);
         }
     }
 }
@@ -165,7 +166,7 @@ fn build_prefix(prefix_parts: &[bool], is_last: bool) -> String {
 }
 
 /// Build a single node line with styling.
-fn build_node_line(prefix: &str, node: &TreeNode) -> Line<'static> {
+fn build_node_line(prefix: &str, node: &TreeNode, theme: &Theme) -> Line<'static> {
     let mut spans = Vec::new();
 
     // Prefix (tree structure)
@@ -198,7 +199,7 @@ fn build_node_line(prefix: &str, node: &TreeNode) -> Line<'static> {
 
     spans.push(Span::styled(
         format!("{} ", status_icon),
-        Style::default().fg(status_color),
+        Style::default().fg(theme.status(&node.status, status_color)),
     ));
 
     // Title with level-based styling
@@ -296,7 +297,7 @@ mod tests {
     #[test]
     fn test_build_node_line_epic() {
         let node = TreeNode::new("epic1", "Epic Title", Level::Epic);
-        let line = build_node_line("", &node);
+        let line = build_node_line("", &node, &Theme::default());
 
         // Should have spans for: icon, status, title
         assert!(line.spans.len() >= 3);
@@ -308,7 +309,7 @@ mod tests {
             .with_child(TreeNode::new("child1", "Child 1", Level::Ticket))
             .with_child(TreeNode::new("child2", "Child 2", Level::Ticket));
 
-        let line = build_node_line("", &node);
+        let line = build_node_line("", &node, &Theme::default());
 
         // Should include child count
         let line_text: String = line.spans.iter().map(|s| s.content.to_string()).collect();
@@ -318,7 +319,7 @@ mod tests {
     #[test]
     fn test_build_node_line_status_done() {
         let node = TreeNode::new("task1", "Task", Level::Task).with_status(Status::Done);
-        let line = build_node_line("", &node);
+        let line = build_node_line("", &node, &Theme::default());
 
         let line_text: String = line.spans.iter().map(|s| s.content.to_string()).collect();
         assert!(line_text.contains("[x]"));
@@ -327,7 +328,7 @@ mod tests {
     #[test]
     fn test_build_node_line_status_in_progress() {
         let node = TreeNode::new("task1", "Task", Level::Task).with_status(Status::InProgress);
-        let line = build_node_line("", &node);
+        let line = build_node_line("", &node, &Theme::default());
 
         let line_text: String = line.spans.iter().map(|s| s.content.to_string()).collect();
         assert!(line_text.contains("[>]"));
@@ -336,7 +337,7 @@ mod tests {
     #[test]
     fn test_build_node_line_status_rejected() {
         let node = TreeNode::new("task1", "Task", Level::Task).with_status(Status::Rejected);
-        let line = build_node_line("", &node);
+        let line = build_node_line("", &node, &Theme::default());
 
         let line_text: String = line.spans.iter().map(|s| s.content.to_string()).collect();
         assert!(line_text.contains("[-]"));
@@ -345,7 +346,7 @@ mod tests {
     #[test]
     fn test_build_node_line_status_pending_review() {
         let node = TreeNode::new("task1", "Task", Level::Task).with_status(Status::PendingReview);
-        let line = build_node_line("", &node);
+        let line = build_node_line("", &node, &Theme::default());
 
         let line_text: String = line.spans.iter().map(|s| s.content.to_string()).collect();
         assert!(line_text.contains("[?]"));
@@ -354,7 +355,7 @@ mod tests {
     #[test]
     fn test_build_node_line_status_backlog() {
         let node = TreeNode::new("task1", "Task", Level::Task).with_status(Status::Backlog);
-        let line = build_node_line("", &node);
+        let line = build_node_line("", &node, &Theme::default());
 
         let line_text: String = line.spans.iter().map(|s| s.content.to_string()).collect();
         assert!(line_text.contains("[.]"));
@@ -363,7 +364,7 @@ mod tests {
     #[test]
     fn test_build_node_line_status_todo() {
         let node = TreeNode::new("task1", "Task", Level::Task).with_status(Status::Todo);
-        let line = build_node_line("", &node);
+        let line = build_node_line("", &node, &Theme::default());
 
         let line_text: String = line.spans.iter().map(|s| s.content.to_string()).collect();
         assert!(line_text.contains("[ ]"));
@@ -375,14 +376,14 @@ mod tests {
 
     #[test]
     fn test_build_tree_lines_empty() {
-        let lines = build_tree_lines(&[]);
+        let lines = build_tree_lines(&[], &Theme::default());
         assert!(lines.is_empty());
     }
 
     #[test]
     fn test_build_tree_lines_single_root() {
         let roots = vec![TreeNode::new("epic1", "Epic 1", Level::Epic)];
-        let lines = build_tree_lines(&roots);
+        let lines = build_tree_lines(&roots, &Theme::default());
 
         assert_eq!(lines.len(), 1);
     }
@@ -394,7 +395,7 @@ mod tests {
             TreeNode::new("epic2", "Epic 2", Level::Epic),
             TreeNode::new("epic3", "Epic 3", Level::Epic),
         ];
-        let lines = build_tree_lines(&roots);
+        let lines = build_tree_lines(&roots, &Theme::default());
 
         assert_eq!(lines.len(), 3);
     }
@@ -408,7 +409,7 @@ mod tests {
             ]),
         ];
 
-        let lines = build_tree_lines(&roots);
+        let lines = build_tree_lines(&roots, &Theme::default());
 
         // Should have: epic + 2 tickets = 3 lines
         assert_eq!(lines.len(), 3);
@@ -424,7 +425,7 @@ mod tests {
             )),
         )];
 
-        let lines = build_tree_lines(&roots);
+        let lines = build_tree_lines(&roots, &Theme::default());
 
         // Should have: epic + ticket + task = 3 lines
         assert_eq!(lines.len(), 3);
@@ -446,7 +447,7 @@ mod tests {
                 ]),
         ];
 
-        let lines = build_tree_lines(&roots);
+        let lines = build_tree_lines(&roots, &Theme::default());
         // 1 epic + 5 children = 6 lines
         assert_eq!(lines.len(), 6);
     }
@@ -488,7 +489,7 @@ mod tests {
             .with_child(TreeNode::new("child1", "Child 1", Level::Ticket))
             .with_progress(Progress::new(2, 3));
 
-        let line = build_node_line("", &node);
+        let line = build_node_line("", &node, &Theme::default());
 
         let line_text: String = line.spans.iter().map(|s| s.content.to_string()).collect();
         // Should show progress instead of child count
@@ -504,7 +505,7 @@ mod tests {
             .with_child(TreeNode::new("child1", "Child 1", Level::Ticket))
             .with_progress(Progress::new(3, 3));
 
-        let line = build_node_line("", &node);
+        let line = build_node_line("", &node, &Theme::default());
 
         let line_text: String = line.spans.iter().map(|s| s.content.to_string()).collect();
         assert!(line_text.contains("[3/3]"));
@@ -517,7 +518,7 @@ mod tests {
             .with_child(TreeNode::new("c1", "Child 1", Level::Ticket))
             .with_child(TreeNode::new("c2", "Child 2", Level::Ticket));
 
-        let line = build_node_line("", &node);
+        let line = build_node_line("", &node, &Theme::default());
 
         let line_text: String = line.spans.iter().map(|s| s.content.to_string()).collect();
         // Should show children count when no progress
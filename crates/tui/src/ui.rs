@@ -6,22 +6,93 @@ use ratatui::{
     Frame,
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
-    text::Line,
-    widgets::{Block, Borders, Paragraph, Tabs},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Tabs},
 };
 
+use crate::add_task::render_add_task;
 use crate::app::{ActiveTab, App};
+use crate::board::render_board_view;
 use crate::details::render_details_view;
+use crate::event::AppMode;
 use crate::navigation::render_nav_panel;
-use crate::timeline::render_timeline_view;
+use crate::palette::{centered_rect, render_palette};
+use crate::theme::Theme;
+use crate::timeline::{TimelineWindow, render_timeline_view};
 use crate::tree_view::render_tree_view;
 
 /// Tab titles for the right panel.
-const TAB_TITLES: [&str; 3] = ["Details", "Tree", "Timeline"];
+const TAB_TITLES: [&str; 4] = ["Details", "Tree", "Timeline", "Board"];
 
 /// Legend text for keyboard shortcuts.
-const LEGEND: &str =
-    " [j/k] Navigate  [h/l] Switch panel  [Tab] Switch view  [Enter] Select  [q] Quit ";
+const LEGEND: &str = " [j/k] Navigate  [h/l] Switch panel  [Tab] Switch view  [Enter] Select  [:] Commands  [?] Help  [q] Quit ";
+
+/// Every keybinding, grouped by the panel it works in, as shown by `?`.
+///
+/// Update this list along with the key handling in `App::handle_key`.
+const KEYBINDINGS: &[(&str, &[(&str, &str)])] = &[
+    (
+        "Global",
+        &[
+            ("Tab", "Switch view"),
+            (":", "Command palette"),
+            ("?", "Show or hide this help"),
+            ("R", "Reload tasks"),
+            ("q / Ctrl+C", "Quit"),
+        ],
+    ),
+    (
+        "Navigation",
+        &[
+            ("j/k", "Move selection"),
+            ("g / G", "First / last task"),
+            ("Ctrl-d/u", "Half page down / up"),
+            ("Enter", "Expand or collapse"),
+            ("l / Right", "Focus the view"),
+            ("E / C", "Expand / collapse all"),
+            ("/", "Filter by title"),
+            ("Esc", "Clear the filter"),
+            ("a", "Add a task"),
+            ("e", "Edit the title"),
+            ("x", "Export as JSON"),
+            ("s / r / d", "Start / review / done"),
+        ],
+    ),
+    (
+        "Details and Tree",
+        &[
+            ("j/k", "Scroll"),
+            ("Ctrl-d/u", "Scroll half a page"),
+            ("g / G", "Top / bottom"),
+            ("b", "Next blocker (Details)"),
+            ("Enter", "Go to blocker (Details)"),
+            ("h / Left", "Back to navigation"),
+        ],
+    ),
+    (
+        "Timeline",
+        &[
+            ("j/k", "Select task"),
+            ("h/l", "Scroll left / right"),
+            ("0-3", "All / week / month / quarter"),
+            ("+ / -", "Zoom in / out"),
+            ("a", "Automatic zoom"),
+            ("t", "Center on today"),
+            ("f", "Cycle status filter"),
+            ("#", "Cycle tag filter"),
+            ("e", "Dependency arrows"),
+            ("c", "Color key"),
+        ],
+    ),
+    (
+        "Board",
+        &[
+            ("h/l", "Switch column"),
+            ("j/k", "Select card"),
+            ("< / >", "Move card"),
+        ],
+    ),
+];
 
 /// Draw the entire UI.
 pub fn draw(frame: &mut Frame, app: &App) {
@@ -33,8 +104,85 @@ pub fn draw(frame: &mut Frame, app: &App) {
     // Draw right content area
     draw_content_area(frame, chunks[1], app);
 
-    // Draw legend at bottom
-    draw_legend(frame, chunks[2]);
+    // Draw legend at bottom, or the latest notification in its place
+    draw_legend(frame, chunks[2], app.notification());
+
+    // Draw the command palette over everything else
+    if let Some(palette) = app.palette() {
+        render_palette(frame, frame.area(), palette);
+    }
+    if let Some(form) = app.add_task_form() {
+        render_add_task(frame, frame.area(), form);
+    }
+    if app.mode() == AppMode::Help {
+        render_help(frame, frame.area());
+    }
+}
+
+/// Lines for a run of keybinding sections: a heading, then one line per
+/// binding, with a blank line between sections.
+fn help_lines(sections: &[(&str, &[(&str, &str)])]) -> Vec<Line<'static>> {
+    let key_width = KEYBINDINGS
+        .iter()
+        .flat_map(|(_, bindings)| bindings.iter())
+        .map(|(key, _)| key.len())
+        .max()
+        .unwrap_or(0);
+
+    let mut lines = Vec::new();
+    for (i, (title, bindings)) in sections.iter().enumerate() {
+        if i > 0 {
+            lines.push(Line::from(""));
+        }
+        lines.push(Line::from(Span::styled(
+            title.to_string(),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )));
+        for (key, description) in bindings.iter() {
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("  {:<width$}  ", key, width = key_width),
+                    Style::default().fg(Color::Cyan),
+                ),
+                Span::raw(description.to_string()),
+            ]));
+        }
+    }
+    lines
+}
+
+/// Number of sections in the left column of the help, chosen so the two
+/// columns come out about the same height.
+fn help_split() -> usize {
+    let height = |sections: &[(&str, &[(&str, &str)])]| help_lines(sections).len();
+    (1..KEYBINDINGS.len())
+        .min_by_key(|&split| height(&KEYBINDINGS[..split]).abs_diff(height(&KEYBINDINGS[split..])))
+        .unwrap_or(KEYBINDINGS.len())
+}
+
+/// Draw the keybinding help in two columns, centered over `area`.
+fn render_help(frame: &mut Frame, area: Rect) {
+    let (left, right) = KEYBINDINGS.split_at(help_split());
+    let (left, right) = (help_lines(left), help_lines(right));
+    let height = left.len().max(right.len()) as u16 + 2;
+    let popup = centered_rect(area, 80, height);
+    frame.render_widget(Clear, popup);
+
+    let block = Block::default()
+        .title(" Keybindings ([?] or [Esc] to close) ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow));
+    let inner = block.inner(popup);
+    frame.render_widget(block, popup);
+
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(inner);
+    frame.render_widget(Paragraph::new(left), columns[0]);
+    frame.render_widget(Paragraph::new(right), columns[1]);
 }
 
 /// Create the main three-part layout: nav panel, content area, legend.
@@ -66,16 +214,36 @@ fn create_main_layout(area: Rect) -> Vec<Rect> {
         .collect()
 }
 
+/// Area of the navigation panel, border included, when the terminal is
+/// `area`.
+pub fn nav_panel_area(area: Rect) -> Rect {
+    create_main_layout(area)[0]
+}
+
 /// Draw the left navigation panel using the tree widget.
 fn draw_nav_panel(frame: &mut Frame, area: Rect, app: &App) {
     let is_focused = app.focused_panel().is_navigation();
+    let typing = app.mode() == AppMode::Filter;
+    let notice = match app.filter() {
+        Some(query) => Some(format!("/{}{}", query, if typing { "_" } else { "" })),
+        None if typing => Some("/_".to_string()),
+        None => app
+            .is_lazy_tree()
+            .then(|| "large tree, lazy mode".to_string()),
+    };
+    let empty_message = if app.filter().is_some() {
+        "No matching tasks"
+    } else {
+        "No tasks found"
+    };
     render_nav_panel(
         frame,
         area,
         app.visible_nodes(),
         app.selected_index(),
-        Some("No tasks found"),
-        is_focused,
+        Some(empty_message),
+        notice.as_deref(),
+        app.theme().border(is_focused),
     );
 }
 
@@ -83,31 +251,37 @@ fn draw_nav_panel(frame: &mut Frame, area: Rect, app: &App) {
 fn draw_content_area(frame: &mut Frame, area: Rect, app: &App) {
     let is_focused = app.focused_panel().is_content();
 
-    // Split into tabs header and content
-    let chunks = Layout::default()
+    let chunks = split_tabs(area);
+
+    // Draw tabs with focus indicator
+    draw_tabs(frame, chunks[0], app.active_tab(), is_focused, app.theme());
+
+    // Draw content based on active tab with scroll offset
+    draw_tab_content(frame, chunks[1], app, is_focused);
+}
+
+/// Split the content area into the tab bar and the active view.
+fn split_tabs(area: Rect) -> std::rc::Rc<[Rect]> {
+    Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(3), // Tab bar
             Constraint::Min(0),    // Content area
         ])
-        .split(area);
-
-    // Draw tabs with focus indicator
-    draw_tabs(frame, chunks[0], app.active_tab(), is_focused);
+        .split(area)
+}
 
-    // Draw content based on active tab with scroll offset
-    draw_tab_content(frame, chunks[1], app, is_focused);
+/// Area of the active view below the tab bar, border included, when the
+/// terminal is `area`.
+pub fn tab_content_area(area: Rect) -> Rect {
+    split_tabs(create_main_layout(area)[1])[1]
 }
 
 /// Draw the tab bar.
-fn draw_tabs(frame: &mut Frame, area: Rect, active: ActiveTab, is_focused: bool) {
+fn draw_tabs(frame: &mut Frame, area: Rect, active: ActiveTab, is_focused: bool, theme: &Theme) {
     let titles: Vec<Line> = TAB_TITLES.iter().map(|t| Line::from(*t)).collect();
 
-    let border_color = if is_focused {
-        Color::Yellow
-    } else {
-        Color::Cyan
-    };
+    let border_color = theme.border(is_focused);
 
     let tabs = Tabs::new(titles)
         .block(
@@ -138,6 +312,8 @@ fn draw_tab_content(frame: &mut Frame, area: Rect, app: &App, is_focused: bool)
                 app.selected_task_details(),
                 is_focused,
                 scroll_offset,
+                app.selected_blocker(),
+                app.theme(),
             );
         }
         ActiveTab::Tree => {
@@ -148,6 +324,7 @@ fn draw_tab_content(frame: &mut Frame, area: Rect, app: &App, is_focused: bool)
                 Some("No tasks found"),
                 is_focused,
                 scroll_offset,
+                app.theme(),
             );
         }
         ActiveTab::Timeline => {
@@ -155,19 +332,32 @@ fn draw_tab_content(frame: &mut Frame, area: Rect, app: &App, is_focused: bool)
                 frame,
                 area,
                 app.timeline_tasks(),
-                Some("No started tasks found"),
-                is_focused,
-                scroll_offset,
-                app.timeline_horizontal_offset(),
-                app.selected_timeline_index(),
+                Some(if app.timeline_window() == TimelineWindow::All {
+                    "No started tasks found"
+                } else {
+                    "No started tasks in this window"
+                }),
+                &app.timeline_view_state(is_focused, scroll_offset),
+                app.show_timeline_legend(),
+                app.theme(),
             );
         }
+        ActiveTab::Board => {
+            render_board_view(frame, area, app.board(), is_focused, app.theme());
+        }
     }
 }
 
 /// Draw the legend bar at the bottom.
-fn draw_legend(frame: &mut Frame, area: Rect) {
-    let legend = Paragraph::new(LEGEND).style(Style::default().fg(Color::Black).bg(Color::Cyan));
+///
+/// A notification, such as a refused board move, replaces the key legend
+/// until the next key press.
+fn draw_legend(frame: &mut Frame, area: Rect, notification: Option<&str>) {
+    let legend = match notification {
+        Some(message) => Paragraph::new(format!(" {} ", message))
+            .style(Style::default().fg(Color::Black).bg(Color::Yellow)),
+        None => Paragraph::new(LEGEND).style(Style::default().fg(Color::Black).bg(Color::Cyan)),
+    };
 
     frame.render_widget(legend, area);
 }
@@ -178,7 +368,23 @@ mod tests {
 
     #[test]
     fn test_tab_titles_count() {
-        assert_eq!(TAB_TITLES.len(), 3);
+        assert_eq!(TAB_TITLES.len(), 4);
+    }
+
+    #[test]
+    fn test_tab_content_area_sits_below_tabs() {
+        assert_eq!(
+            tab_content_area(Rect::new(0, 0, 100, 30)),
+            Rect::new(25, 3, 75, 26)
+        );
+    }
+
+    #[test]
+    fn test_nav_panel_area_excludes_legend() {
+        assert_eq!(
+            nav_panel_area(Rect::new(0, 0, 100, 30)),
+            Rect::new(0, 0, 25, 29)
+        );
     }
 
     #[test]
@@ -186,6 +392,23 @@ mod tests {
         assert!(!LEGEND.is_empty());
     }
 
+    #[test]
+    fn test_help_lists_every_section_in_balanced_columns() {
+        assert!(KEYBINDINGS.iter().all(|(_, bindings)| !bindings.is_empty()));
+
+        let split = help_split();
+        assert!(split > 0 && split < KEYBINDINGS.len());
+        let left = help_lines(&KEYBINDINGS[..split]);
+        let right = help_lines(&KEYBINDINGS[split..]);
+        let bindings: usize = KEYBINDINGS.iter().map(|(_, b)| b.len()).sum();
+        // A heading per section, a line per binding, blanks between sections
+        assert_eq!(
+            left.len() + right.len(),
+            bindings + KEYBINDINGS.len() * 2 - 2
+        );
+        assert!(left.len().abs_diff(right.len()) < 10);
+    }
+
     #[test]
     fn test_create_main_layout_produces_three_chunks() {
         // Create a reasonably sized test area